@@ -0,0 +1,49 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Skeleton throughput on a few corpora, both through the `skeleton_chars`
+//! iterator and through `skeleton`, which has a fast path for ASCII. Compare
+//! the confusables lookups with `cargo bench` and `cargo bench --features phf`.
+
+extern crate unicode_skeleton;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use unicode_skeleton::{skeleton, UnicodeSkeleton};
+
+// Words of several scripts, including many characters that the confusables
+// table maps and many that it does not.
+const MIXED: &str = "Рассказ ℝ𝓊𝓈𝓉 παράδειγμα 東京都 ΑΒΓ paypal аррӏе 𝐇𝐞𝐥𝐥𝐨 ﬁnancial \
+                     ⅰⅱⅲ Ꮪⅽrірt नमस्ते ⓒⓞⓟⓨ ｆｕｌｌｗｉｄｔｈ مرحبا שלום ";
+const LATIN: &str = "The quick brown fox jumps over the lazy dog. Ünïcödé àccénts ";
+const ASCII: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod. ";
+const CYRILLIC: &str = "Съешь же ещё этих мягких французских булок, да выпей чаю. ";
+
+fn bench<F: Fn(&str) -> usize>(name: &str, corpus: &str, f: F) {
+    let text = corpus.repeat(64);
+    let mut iterations = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(1) {
+        for _ in 0..10 {
+            black_box(f(black_box(&text[..])));
+        }
+        iterations += 10;
+    }
+    let per_iteration = start.elapsed() / iterations;
+    let mb_per_s = text.len() as f64 / per_iteration.as_secs_f64() / 1e6;
+    println!("{:<20} {:>10.2?}/iter {:>8.1} MB/s", name, per_iteration, mb_per_s);
+}
+
+fn main() {
+    for &(name, corpus) in [("mixed", MIXED), ("latin", LATIN), ("ascii", ASCII), ("cyrillic", CYRILLIC)].iter() {
+        bench(&format!("{} chars", name), corpus, |s| s.skeleton_chars().count());
+        bench(&format!("{} skeleton", name), corpus, |s| skeleton(s).len());
+    }
+}
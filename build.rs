@@ -0,0 +1,52 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// When UNICODE_SKELETON_CONFUSABLES is set to the path of a confusables.txt
+// file, the compiled-in confusables table is generated from it, in the same
+// format as `cargo xtask` produces, instead of using
+// src/data/confusables.rs. The file must have a "# Version: X.Y.Z" line.
+
+extern crate unicode_normalization;
+#[cfg(feature = "node")]
+extern crate napi_build;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Shared with xtask, which generates the bundled tables.
+#[path = "build/confusables.rs"]
+mod confusables;
+
+const CONFUSABLES_VAR: &str = "UNICODE_SKELETON_CONFUSABLES";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/confusables.rs");
+    println!("cargo:rerun-if-env-changed={}", CONFUSABLES_VAR);
+    println!("cargo:rustc-check-cfg=cfg(unicode_skeleton_custom_confusables)");
+    #[cfg(feature = "node")]
+    napi_build::setup();
+
+    let path = match env::var_os(CONFUSABLES_VAR) {
+        Some(path) => path,
+        None => return,
+    };
+    println!("cargo:rerun-if-changed={}", Path::new(&path).display());
+
+    let confusables = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {} ({}): {}", CONFUSABLES_VAR, Path::new(&path).display(), e));
+
+    let mut table = confusables::generate_table(&confusables);
+    table.push_str(&confusables::generate_char_ranges(&confusables));
+    let out_path = Path::new(&env::var_os("OUT_DIR").expect("OUT_DIR is not set")).join("confusables.rs");
+    fs::write(&out_path, table).expect("Failed to write generated confusables table");
+
+    println!("cargo:rustc-cfg=unicode_skeleton_custom_confusables");
+}
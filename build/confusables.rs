@@ -0,0 +1,203 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Generation of the packed confusables tables from a confusables.txt file.
+// This is included both by build.rs, for UNICODE_SKELETON_CONFUSABLES, and by
+// xtask, for the tables in src/data, so that the two cannot drift apart.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use unicode_normalization::char::decompose_canonical;
+use unicode_normalization::UnicodeNormalization;
+
+// The number of low bits of each entry that hold the index of its prototype.
+// This must match `PROTOTYPE_INDEX_BITS` in src/data/mod.rs.
+pub const PROTOTYPE_INDEX_BITS: u32 = 14;
+
+fn parse_code_point(hex: &str) -> char {
+    u32::from_str_radix(hex, 16).ok()
+        .and_then(char::from_u32)
+        .unwrap_or_else(|| panic!("Invalid code point {:?} in the confusables file", hex))
+}
+
+// The hash of the perfect hash table. This must match `phf_hash` in
+// src/data/mod.rs.
+pub fn phf_hash(key: u32, salt: u32, n: usize) -> usize {
+    let y = key.wrapping_add(salt).wrapping_mul(0x9e37_79b9) ^ key.wrapping_mul(0x3141_5926);
+    ((y as u64 * n as u64) >> 32) as usize
+}
+
+// Build a minimal perfect hash over the sources of `entries`, returning a
+// salt for each slot and the entries in their hashed positions. A source `c`
+// is found at `phf_hash(c, salts[phf_hash(c, 0, n)], n)`.
+pub fn perfect_hash(entries: &[u32]) -> (Vec<u16>, Vec<u32>) {
+    let n = entries.len();
+    let mut buckets = vec![Vec::new(); n];
+    for &entry in entries {
+        let key = entry >> PROTOTYPE_INDEX_BITS;
+        buckets[phf_hash(key, 0, n)].push(entry);
+    }
+    // Place the largest buckets first, while the most slots are free.
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&bucket| (usize::MAX - buckets[bucket].len(), bucket));
+
+    let mut salts = vec![0u16; n];
+    let mut hashed_entries = vec![None; n];
+    for bucket in order {
+        if buckets[bucket].is_empty() {
+            break;
+        }
+        let salt = (1..=u16::MAX).find(|&salt| {
+            let mut slots: Vec<usize> = buckets[bucket].iter()
+                .map(|entry| phf_hash(entry >> PROTOTYPE_INDEX_BITS, salt as u32, n))
+                .collect();
+            slots.sort();
+            slots.dedup();
+            slots.len() == buckets[bucket].len() && slots.iter().all(|&slot| hashed_entries[slot].is_none())
+        }).expect("Failed to find a salt for the confusables perfect hash");
+        salts[bucket] = salt;
+        for &entry in buckets[bucket].iter() {
+            hashed_entries[phf_hash(entry >> PROTOTYPE_INDEX_BITS, salt as u32, n)] = Some(entry);
+        }
+    }
+    (salts, hashed_entries.into_iter().map(|entry| entry.expect("Confusables perfect hash is not minimal")).collect())
+}
+
+// Lay out sorted entries in Eytzinger order: as a complete binary search
+// tree, in breadth-first order. This must match `eytzinger` in
+// src/data/mod.rs.
+pub fn eytzinger(sorted: &[u32]) -> Vec<u32> {
+    fn fill(sorted: &[u32], out: &mut [u32], next: &mut usize, node: usize) {
+        if node <= out.len() {
+            fill(sorted, out, next, 2 * node);
+            out[node - 1] = sorted[*next];
+            *next += 1;
+            fill(sorted, out, next, 2 * node + 1);
+        }
+    }
+    let mut out = vec![0; sorted.len()];
+    fill(sorted, &mut out, &mut 0, 1);
+    out
+}
+
+// Parse a confusables file into its version and the prototype of each
+// source, in NFD.
+pub fn parse(confusables: &str) -> (Vec<u8>, BTreeMap<u32, Vec<char>>) {
+    let confusables = confusables.trim_start_matches('\u{feff}');
+
+    let version = confusables.lines()
+        .filter_map(|line| line.trim_start_matches('#').trim().strip_prefix("Version:"))
+        .next()
+        .expect("The confusables file has no \"# Version: X.Y.Z\" line");
+    let version: Vec<u8> = version.trim().split('.')
+        .map(|part| part.parse().expect("Failed to parse the confusables file's version"))
+        .collect();
+    assert_eq!(version.len(), 3, "The confusables file's version is not of the form X.Y.Z");
+
+    // First, put all the lines into a BTreeMap to get them unique and sorted
+    let mut inputs_to_outputs: BTreeMap<u32, Vec<char>> = BTreeMap::new();
+    for line in confusables.lines() {
+        let line = match line.find('#') {
+            Some(comment_begin) => &line[..comment_begin],
+            None => line,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split(';').map(str::trim);
+        let from = parse_code_point(fields.next().unwrap_or(""));
+        let tos: Vec<char> = fields.next().unwrap_or("").split_whitespace().map(parse_code_point).collect();
+        assert!(!tos.is_empty(), "Missing prototype for U+{:04X}", from as u32);
+        let old = inputs_to_outputs.insert(from as u32, tos);
+        assert!(old.is_none(), "Duplicate entry for U+{:04X}", from as u32);
+    }
+
+    // Ensure that no prototype contains characters that themselves need to be translated to another prototype
+    for output in inputs_to_outputs.values().flatten() {
+        assert!(!inputs_to_outputs.contains_key(&(*output as u32)), "U+{:04X} is in a prototype but has its own prototype", *output as u32);
+    }
+
+    // Prototypes are stored in NFD, so that skeletons need not decompose
+    // them. This is done after the check above: the decomposition of a
+    // prototype may contain a source, as with U+01CF, whose prototype Ĭ
+    // decomposes to I and a combining breve, but the skeleton algorithm
+    // does not map the characters of prototypes again.
+    for tos in inputs_to_outputs.values_mut() {
+        *tos = tos.iter().cloned().nfd().collect();
+    }
+
+    (version, inputs_to_outputs)
+}
+
+// Generate the packed table of a confusables file: its version, entries,
+// prototypes and perfect hash.
+pub fn generate_table(confusables: &str) -> String {
+    let (version, inputs_to_outputs) = parse(confusables);
+
+    // Each distinct prototype is stored once, in order, with the entries
+    // referring to it by index.
+    let mut prototypes: Vec<&Vec<char>> = inputs_to_outputs.values().collect();
+    prototypes.sort();
+    prototypes.dedup();
+    assert!(prototypes.len() <= 1 << PROTOTYPE_INDEX_BITS, "Too many distinct prototypes");
+
+    let mut prototype_starts = vec![0u16];
+    let mut prototype_chars = Vec::new();
+    for prototype in prototypes.iter() {
+        prototype_chars.extend_from_slice(prototype);
+        assert!(prototype_chars.len() <= 0xffff, "Too many prototype characters");
+        prototype_starts.push(prototype_chars.len() as u16);
+    }
+
+    // Each entry packs the code point above the index of its prototype.
+    let mut entries = Vec::new();
+    for (from, tos) in inputs_to_outputs.iter() {
+        assert!(*from < 1 << (32 - PROTOTYPE_INDEX_BITS), "U+{:04X} is not below U+40000", from);
+        let index = prototypes.binary_search(&tos).unwrap() as u32;
+        entries.push(from << PROTOTYPE_INDEX_BITS | index);
+    }
+
+    let (salts, hashed_entries) = perfect_hash(&entries);
+    let entries = eytzinger(&entries);
+
+    let mut out = String::new();
+    writeln!(out, "pub const VERSION: (u8, u8, u8) = ({}, {}, {});", version[0], version[1], version[2]).unwrap();
+    writeln!(out, "pub static ENTRIES: [u32; {}] = {:?};", entries.len(), entries).unwrap();
+    writeln!(out, "pub static PROTOTYPE_STARTS: [u16; {}] = {:?};", prototype_starts.len(), prototype_starts).unwrap();
+    writeln!(out, "pub static PROTOTYPE_CHARS: [char; {}] = {:?};", prototype_chars.len(), prototype_chars).unwrap();
+    writeln!(out, "pub static SALTS: [u16; {}] = {:?};", salts.len(), salts).unwrap();
+    writeln!(out, "pub static HASHED_ENTRIES: [u32; {}] = {:?};", hashed_entries.len(), hashed_entries).unwrap();
+    out
+}
+
+// Generate the ranges of characters that the skeleton algorithm replaces,
+// directly or through a character of their canonical decomposition, from the
+// contents of a confusables file. This must match `CONFUSABLE_CHAR_RANGES` in
+// src/data/mod.rs.
+pub fn generate_char_ranges(confusables: &str) -> String {
+    let (_, inputs_to_outputs) = parse(confusables);
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        let mut confusable = false;
+        decompose_canonical(c, |d| confusable = confusable || inputs_to_outputs.contains_key(&(d as u32)));
+        if !confusable {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(range) if range.1 + 1 == c as u32 => range.1 = c as u32,
+            _ => ranges.push((c as u32, c as u32)),
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(out, "pub static CONFUSABLE_CHAR_RANGES: [(u32, u32); {}] = {:?};", ranges.len(), ranges).unwrap();
+    out
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright 2017 Peter Reid. See the COPYRIGHT
+ * directory of this distribution.
+ *
+ * Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+ * option. This file may not be copied, modified, or distributed
+ * except according to those terms.
+ */
+
+/*
+ * The C interface of unicode_skeleton, built with the `ffi` feature:
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * Strings are passed as a pointer to UTF-8 and a length in bytes, and need
+ * not be NUL-terminated. A pointer may only be null if its length is zero.
+ * Functions return a USK_* status code and write their results through
+ * out-parameters. See src/ffi.rs for details.
+ */
+
+#ifndef UNICODE_SKELETON_H
+#define UNICODE_SKELETON_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define USK_OK 0
+#define USK_NULL_POINTER 1
+#define USK_INVALID_UTF8 2
+#define USK_BUFFER_TOO_SMALL 3
+#define USK_INVALID_ARGUMENT 4
+
+/* Bits of a set of checks, as in `Checks`. */
+#define USK_CHECK_CONFUSABLE (1u << 0)
+#define USK_CHECK_MIXED_SCRIPT (1u << 1)
+#define USK_CHECK_INVISIBLE (1u << 2)
+#define USK_CHECK_RESTRICTION_LEVEL (1u << 3)
+#define USK_CHECK_MIXED_NUMBER (1u << 4)
+#define USK_CHECK_CHAR_LIMIT (1u << 5)
+#define USK_CHECK_DEFAULT_IGNORABLE (1u << 6)
+#define USK_CHECK_BIDI_CONTROL (1u << 7)
+#define USK_CHECK_EXCESSIVE_MARKS (1u << 8)
+#define USK_CHECK_VARIATION_SELECTOR (1u << 9)
+#define USK_CHECK_TAG_CHARACTER (1u << 10)
+#define USK_CHECK_WHOLE_SCRIPT_CONFUSABLE (1u << 11)
+
+/* Restriction levels, from most to least restrictive. */
+#define USK_RESTRICTION_ASCII_ONLY 0
+#define USK_RESTRICTION_SINGLE_SCRIPT 1
+#define USK_RESTRICTION_HIGHLY_RESTRICTIVE 2
+#define USK_RESTRICTION_MODERATELY_RESTRICTIVE 3
+#define USK_RESTRICTION_MINIMALLY_RESTRICTIVE 4
+#define USK_RESTRICTION_UNRESTRICTED 5
+
+typedef struct UskChecker UskChecker;
+
+/*
+ * Write the skeleton of `s` to `out`, and its length in bytes to `out_len`.
+ * If the skeleton is longer than `out_cap`, nothing is written to `out` and
+ * USK_BUFFER_TOO_SMALL is returned. The skeleton is not NUL-terminated.
+ */
+int usk_skeleton_utf8(const uint8_t *s, size_t len, uint8_t *out, size_t out_cap, size_t *out_len);
+
+/* Test whether `a` and `b` are confusable. */
+int usk_confusable(const uint8_t *a, size_t a_len, const uint8_t *b, size_t b_len, bool *result);
+
+/* Create a checker with every check enabled. Free it with usk_checker_free. */
+UskChecker *usk_checker_new(void);
+void usk_checker_free(UskChecker *checker);
+
+int usk_checker_enable(UskChecker *checker, uint32_t checks);
+int usk_checker_disable(UskChecker *checker, uint32_t checks);
+int usk_checker_set_restriction_level(UskChecker *checker, int level);
+
+/* Write the USK_CHECK_* bits of the checks that `s` fails to `failed`. */
+int usk_checker_check(const UskChecker *checker, const uint8_t *s, size_t len, uint32_t *failed);
+
+/* Test whether `a` and `b` are confusable under the checker's configuration. */
+int usk_checker_are_confusable(const UskChecker *checker, const uint8_t *a, size_t a_len, const uint8_t *b, size_t b_len, bool *result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
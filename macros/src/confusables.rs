@@ -0,0 +1,3 @@
+// Generated by `cargo xtask` from data/confusables.txt
+
+pub static PROTOTYPES: [(char, &str); 6355] = [('"', "''"), ('%', "º/₀"), ('0', "O"), ('1', "l"), ('I', "l"), ('`', "'"), ('m', "rn"), ('|', "l"), ('\u{a0}', " "), ('¢', "c\u{338}"), ('¥', "Y\u{335}"), ('¯', "ˉ"), ('´', "'"), ('µ', "μ"), ('¸', ","), ('Æ', "AE"), ('Ç', "C\u{326}"), ('Ð', "D\u{335}"), ('×', "x"), ('Ø', "O\u{338}"), ('æ', "ae"), ('ç', "c\u{326}"), ('ð', "∂\u{335}"), ('ö', "ة"), ('ø', "o\u{338}"), ('Đ', "D\u{335}"), ('đ', "d\u{335}"), ('Ě', "E\u{306}"), ('ě', "e\u{306}"), ('Ħ', "H\u{335}"), ('ħ', "h\u{335}"), ('ı', "i"), ('Ĳ', "lJ"), ('ĳ', "ij"), ('Ŀ', "l·"), ('ŀ', "l·"), ('Ł', "L\u{338}"), ('ł', "l\u{338}"), ('ņ', "ɲ"), ('ŉ', "'n"), ('Ő', "O\u{308}"), ('Œ', "OE"), ('œ', "oe"), ('ţ', "ƫ"), ('Ŧ', "T\u{335}"), ('ŧ', "t\u{335}"), ('ſ', "f"), ('ƀ', "b\u{335}"), ('Ɓ', "'B"), ('Ƃ', "b\u{304}"), ('ƃ', "b\u{304}"), ('Ƅ', "b"), ('Ƈ', "C'"), ('Ɖ', "D\u{335}"), ('Ɗ', "'D"), ('ƌ', "d\u{304}"), ('ƍ', "g"), ('Ƒ', "F\u{326}"), ('ƒ', "f\u{326}"), ('Ɠ', "G'"), ('Ɩ', "l"), ('Ɨ', "l\u{335}"), ('Ƙ', "K'"), ('ƙ', "k\u{314}"), ('ƚ', "l\u{335}"), ('ƛ', "λ\u{338}"), ('Ɲ', "N\u{326}"), ('ƞ', "n\u{329}"), ('Ɵ', "O\u{335}"), ('Ơ', "O'"), ('ơ', "o'"), ('Ƥ', "'P"), ('ƥ', "p\u{314}"), ('Ʀ', "R"), ('Ƨ', "2"), ('Ƭ', "'T"), ('ƭ', "t\u{314}"), ('Ʈ', "T\u{328}"), ('Ƴ', "'Y"), ('ƴ', "y\u{314}"), ('Ƶ', "Z\u{335}"), ('ƶ', "z\u{335}"), ('Ʒ', "3"), ('ƻ', "2\u{335}"), ('Ƽ', "5"), ('ƽ', "s"), ('ƿ', "þ"), ('ǀ', "l"), ('ǁ', "ll"), ('ǃ', "!"), ('Ǆ', "DZ\u{30c}"), ('ǅ', "Dz\u{30c}"), ('ǆ', "dz\u{30c}"), ('Ǉ', "LJ"), ('ǈ', "Lj"), ('ǉ', "lj"), ('Ǌ', "NJ"), ('ǋ', "Nj"), ('ǌ', "nj"), ('Ǎ', "A\u{306}"), ('ǎ', "a\u{306}"), ('Ǐ', "I\u{306}"), ('ǐ', "i\u{306}"), ('Ǒ', "O\u{306}"), ('ǒ', "o\u{306}"), ('Ǔ', "U\u{306}"), ('ǔ', "u\u{306}"), ('Ǥ', "G\u{335}"), ('ǥ', "g\u{335}"), ('Ǧ', "G\u{306}"), ('ǧ', "g\u{306}"), ('Ǳ', "DZ"), ('ǲ', "Dz"), ('ǳ', "dz"), ('ǵ', "g\u{327}"), ('Ǿ', "O\u{338}\u{301}"), ('Ț', "T\u{327}"), ('ț', "ƫ"), ('Ȝ', "3"), ('Ȣ', "8"), ('ȣ', "8"), ('Ȥ', "Z\u{326}"), ('ȥ', "z\u{326}"), ('Ȧ', "A\u{30a}"), ('ȧ', "a\u{30a}"), ('ȼ', "c\u{338}"), ('Ⱦ', "T\u{338}"), ('Ɂ', "?"), ('Ʉ', "U\u{335}"), ('Ɇ', "E\u{338}"), ('ɇ', "e\u{338}"), ('Ɉ', "J\u{335}"), ('ɉ', "j\u{335}"), ('ɍ', "r\u{335}"), ('Ɏ', "Y\u{335}"), ('ɏ', "y\u{335}"), ('ɑ', "a"), ('ɓ', "b\u{314}"), ('ɖ', "d\u{328}"), ('ɗ', "d\u{314}"), ('ə', "ǝ"), ('ɚ', "ǝ˞"), ('ɛ', "ꞓ"), ('ɠ', "g\u{314}"), ('ɡ', "g"), ('ɣ', "y"), ('ɦ', "h\u{314}"), ('ɨ', "i\u{335}"), ('ɩ', "i"), ('ɪ', "i"), ('ɫ', "l\u{334}"), ('ɭ', "l\u{328}"), ('ɮ', "lȝ"), ('ɯ', "w"), ('ɱ', "rn\u{326}"), ('ɳ', "n\u{328}"), ('ɵ', "o\u{335}"), ('ɶ', "oᴇ"), ('ɼ', "r\u{329}"), ('ɽ', "r\u{328}"), ('ʂ', "s\u{328}"), ('ʋ', "u"), ('ʏ', "y"), ('ʐ', "z\u{328}"), ('ʒ', "ȝ"), ('ʔ', "?"), ('ʠ', "q\u{314}"), ('ʣ', "dz"), ('ʤ', "dȝ"), ('ʥ', "dʑ"), ('ʦ', "ts"), ('ʧ', "tʃ"), ('ʨ', "tɕ"), ('ʩ', "fŋ"), ('ʪ', "ls"), ('ʫ', "lz"), ('ʳ', "ᣴ"), ('ʹ', "'"), ('ʺ', "''"), ('ʻ', "'"), ('ʼ', "'"), ('ʽ', "'"), ('ʾ', "'"), ('ʿ', "ՙ"), ('˂', "<"), ('˃', ">"), ('˄', "^"), ('ˆ', "^"), ('ˈ', "'"), ('ˊ', "'"), ('ˋ', "'"), ('ː', ":"), ('˓', "ՙ"), ('˗', "-"), ('˘', "ˇ"), ('˙', "ॱ"), ('˚', "°"), ('˛', "i"), ('˜', "~"), ('˝', "''"), ('ˡ', "ᣳ"), ('ˢ', "ᣵ"), ('ˤ', "ˁ"), ('ˮ', "''"), ('˴', "'"), ('˶', "''"), ('˸', ":"), ('˻', "˪"), ('\u{305}', "\u{304}"), ('\u{30c}', "\u{306}"), ('\u{30d}', "\u{670}"), ('\u{310}', "\u{306}\u{307}"), ('\u{311}', "\u{302}"), ('\u{315}', "\u{313}"), ('\u{317}', "\u{650}"), ('\u{320}', "\u{331}"), ('\u{321}', "\u{326}"), ('\u{322}', "\u{328}"), ('\u{327}', "\u{326}"), ('\u{336}', "\u{335}"), ('\u{337}', "\u{338}"), ('\u{339}', "\u{326}"), ('\u{340}', "\u{300}"), ('\u{341}', "\u{301}"), ('\u{342}', "\u{303}"), ('\u{343}', "\u{313}"), ('\u{345}', "\u{328}"), ('\u{347}', "\u{333}"), ('\u{357}', "\u{350}"), ('\u{358}', "\u{307}"), ('\u{366}', "\u{30a}"), ('\u{36e}', "\u{306}"), ('Ͱ', "Ⱶ"), ('ʹ', "'"), ('͵', "ˏ"), ('Ͷ', "И"), ('ͷ', "ᴎ"), ('ͺ', "i"), ('ͻ', "ɔ"), ('ͽ', "ꜿ"), (';', ";"), ('Ϳ', "J"), ('΄', "'"), ('·', "·"), ('Α', "A"), ('Β', "B"), ('Ε', "E"), ('Ζ', "Z"), ('Η', "H"), ('Θ', "O\u{335}"), ('Ι', "l"), ('Κ', "K"), ('Λ', "Ʌ"), ('Μ', "M"), ('Ν', "N"), ('Ο', "O"), ('Ρ', "P"), ('Σ', "Ʃ"), ('Τ', "T"), ('Υ', "Y"), ('Χ', "X"), ('α', "a"), ('β', "ß"), ('γ', "y"), ('δ', "ẟ"), ('ε', "ꞓ"), ('η', "n\u{329}"), ('θ', "O\u{335}"), ('ι', "i"), ('κ', "ĸ"), ('ν', "v"), ('ο', "o"), ('ρ', "p"), ('σ', "o"), ('τ', "ᴛ"), ('υ', "u"), ('φ', "ɸ"), ('ϐ', "ß"), ('ϑ', "O\u{335}"), ('ϒ', "Y"), ('ϕ', "ɸ"), ('ϖ', "π"), ('ϛ', "ς"), ('Ϝ', "F"), ('Ϩ', "2"), ('ϩ', "ƨ"), ('ϰ', "ĸ"), ('ϱ', "p"), ('ϲ', "c"), ('ϳ', "j"), ('ϴ', "O\u{335}"), ('ϵ', "ꞓ"), ('Ϸ', "Þ"), ('ϸ', "þ"), ('Ϲ', "C"), ('Ϻ', "M"), ('Ͻ', "Ɔ"), ('Ͽ', "Ꜿ"), ('Є', "Ꞓ"), ('Ѕ', "S"), ('І', "l"), ('Ј', "J"), ('А', "A"), ('Б', "b\u{304}"), ('В', "B"), ('Г', "Γ"), ('Е', "E"), ('З', "3"), ('Й', "И\u{300}"), ('К', "K"), ('Л', "Ʌ"), ('М', "M"), ('Н', "H"), ('О', "O"), ('П', "Π"), ('Р', "P"), ('С', "C"), ('Т', "T"), ('У', "Y"), ('Ф', "Φ"), ('Х', "X"), ('Ы', "bl"), ('Ь', "b"), ('Ю', "lO"), ('а', "a"), ('б', "6"), ('в', "ʙ"), ('г', "r"), ('е', "e"), ('з', "ɜ"), ('и', "ᴎ"), ('к', "ĸ"), ('м', "ʍ"), ('н', "ʜ"), ('о', "o"), ('п', "π"), ('р', "p"), ('с', "c"), ('т', "ᴛ"), ('у', "y"), ('ф', "ɸ"), ('х', "x"), ('ъ', "ˉb"), ('ы', "ƅi"), ('ь', "ƅ"), ('я', "ᴙ"), ('є', "ꞓ"), ('ѕ', "s"), ('і', "i"), ('ј', "j"), ('ћ', "h\u{335}"), ('ѝ', "и\u{306}"), ('ѡ', "w"), ('Ѣ', "b\u{335}"), ('ѣ', "b\u{335}"), ('Ѱ', "Ψ"), ('ѱ', "ψ"), ('Ѳ', "O\u{335}"), ('ѳ', "o\u{335}"), ('Ѵ', "V"), ('ѵ', "v"), ('Ѽ', "Ѡ\u{486}\u{487}"), ('ѽ', "w\u{486}\u{487}"), ('Ҋ', "И\u{326}\u{300}"), ('ҋ', "и\u{326}\u{306}"), ('Ҍ', "b\u{335}"), ('ҍ', "b\u{335}"), ('Ґ', "Γ'"), ('ґ', "r'"), ('Ғ', "Γ\u{335}"), ('ғ', "r\u{335}"), ('Җ', "Ж\u{329}"), ('җ', "ж\u{329}"), ('Ҙ', "3\u{326}"), ('ҙ', "ɜ\u{326}"), ('Қ', "K\u{329}"), ('қ', "ĸ\u{329}"), ('Ҟ', "K\u{335}"), ('ҟ', "ĸ\u{335}"), ('Ң', "H\u{329}"), ('ң', "ʜ\u{329}"), ('Ҫ', "C\u{326}"), ('ҫ', "c\u{326}"), ('Ҭ', "T\u{329}"), ('ҭ', "ᴛ\u{329}"), ('Ү', "Y"), ('ү', "y"), ('Ұ', "Y\u{335}"), ('ұ', "y\u{335}"), ('Ҳ', "X\u{329}"), ('һ', "h"), ('ҽ', "e"), ('Ҿ', "Ҽ\u{328}"), ('ҿ', "e\u{328}"), ('Ӏ', "l"), ('Ӆ', "Ʌ\u{326}"), ('ӆ', "л\u{326}"), ('Ӈ', "H\u{326}"), ('ӈ', "ʜ\u{326}"), ('Ӊ', "H\u{326}"), ('ӊ', "ʜ\u{326}"), ('Ӌ', "Ҷ"), ('ӌ', "ҷ"), ('Ӎ', "M\u{326}"), ('ӎ', "ʍ\u{326}"), ('ӏ', "i"), ('Ӕ', "AE"), ('ӕ', "ae"), ('Ә', "Ə"), ('ә', "ǝ"), ('Ӡ', "3"), ('ӡ', "ȝ"), ('Ө', "O\u{335}"), ('ө', "o\u{335}"), ('ԁ', "d"), ('Ԋ', "Ƕ"), ('Ԍ', "G"), ('ԍ', "ɢ"), ('Ԑ', "Ɛ"), ('ԑ', "ꞓ"), ('ԛ', "q"), ('Ԝ', "W"), ('ԝ', "w"), ('Ի', "ኮ"), ('Մ', "ሆ"), ('Պ', "ጣ"), ('Ռ', "ቡ"), ('Ս', "U"), ('Տ', "S"), ('Փ', "Φ"), ('Օ', "O"), ('՚', "'"), ('՝', "'"), ('ա', "w"), ('գ', "q"), ('զ', "q"), ('ծ', "ẟ"), ('հ', "h"), ('յ', "ȷ"), ('ո', "n"), ('պ', "ɰ"), ('ռ', "n"), ('ս', "u"), ('ց', "g"), ('ք', "f"), ('օ', "o"), ('և', "եւ"), ('։', ":"), ('\u{59c}', "\u{301}"), ('\u{59d}', "\u{301}"), ('\u{5a4}', "\u{59a}"), ('\u{5a8}', "\u{599}"), ('\u{5ad}', "\u{596}"), ('\u{5ae}', "\u{598}"), ('\u{5af}', "\u{30a}"), ('\u{5b4}', "\u{323}"), ('\u{5b9}', "\u{307}"), ('\u{5ba}', "\u{307}"), ('׀', "l"), ('\u{5c1}', "\u{307}"), ('\u{5c2}', "\u{307}"), ('׃', ":"), ('\u{5c4}', "\u{307}"), ('\u{5c5}', "\u{323}"), ('ו', "l"), ('ט', "v"), ('י', "'"), ('ן', "l"), ('ס', "o"), ('װ', "ll"), ('ױ', "l'"), ('ײ', "''"), ('׳', "'"), ('״', "''"), ('؉', "º/₀₀"), ('؊', "º/₀₀₀"), ('؍', ","), ('؏', "ع"), ('\u{618}', "\u{301}"), ('\u{619}', "\u{313}"), ('\u{61a}', "\u{650}"), ('أ', "lٴ"), ('ؤ', "وٴ"), ('إ', "l\u{655}"), ('ئ', "ىٴ"), ('ا', "l"), ('ث', "ى\u{6db}"), ('ش', "س\u{6db}"), ('ؽ', "ى\u{302}"), ('ؿ', "ى\u{6db}"), ('ه', "o"), ('ي', "ى"), ('\u{64b}', "\u{30b}"), ('\u{64e}', "\u{301}"), ('\u{64f}', "\u{313}"), ('\u{652}', "\u{30a}"), ('\u{653}', "\u{303}"), ('\u{656}', "\u{329}"), ('\u{657}', "\u{312}"), ('\u{658}', "\u{306}"), ('\u{659}', "\u{304}"), ('\u{65a}', "\u{306}"), ('\u{65b}', "\u{302}"), ('\u{65c}', "\u{323}"), ('\u{65d}', "\u{314}"), ('\u{65f}', "\u{655}"), ('٠', "."), ('١', "l"), ('٥', "o"), ('٧', "V"), ('٨', "Ʌ"), ('٪', "º/₀"), ('٫', ","), ('٬', "،"), ('٭', "*"), ('ٮ', "ى"), ('ٯ', "ڡ"), ('ٲ', "lٴ"), ('ٳ', "l\u{655}"), ('ٵ', "lٴ"), ('ٶ', "وٴ"), ('ٷ', "و\u{313}ٴ"), ('ٸ', "ىٴ"), ('ٹ', "ى\u{615}"), ('پ', "ى\u{6db}"), ('ځ', "ح\u{654}"), ('څ', "ح\u{6db}"), ('ڈ', "د\u{615}"), ('ڋ', "ڊ\u{615}"), ('ڎ', "د\u{6db}"), ('ڑ', "ر\u{615}"), ('ڒ', "ر\u{306}"), ('ژ', "ر\u{6db}"), ('ڞ', "ص\u{6db}"), ('ڟ', "ط\u{6db}"), ('ڤ', "ڡ\u{6db}"), ('ڧ', "ف"), ('ڨ', "ڡ\u{6db}"), ('ک', "ك"), ('ڪ', "ك"), ('ڭ', "ك\u{6db}"), ('ڴ', "گ\u{6db}"), ('ڵ', "ل\u{306}"), ('ڷ', "ل\u{6db}"), ('ں', "ى"), ('ڻ', "ى\u{615}"), ('ڽ', "ى\u{6db}"), ('ھ', "o"), ('ہ', "o"), ('ۂ', "ە\u{654}"), ('ۃ', "ة"), ('ۆ', "و\u{306}"), ('ۇ', "و\u{313}"), ('ۈ', "و\u{670}"), ('ۉ', "و\u{302}"), ('ۋ', "و\u{6db}"), ('ی', "ى"), ('ێ', "ى\u{306}"), ('ې', "ٻ"), ('ۑ', "ى\u{6db}"), ('ے', "ى"), ('۔', "-"), ('ە', "o"), ('\u{6df}', "\u{30a}"), ('\u{6e8}', "\u{306}\u{307}"), ('\u{6ec}', "\u{307}"), ('ۮ', "د\u{302}"), ('ۯ', "ر\u{302}"), ('۰', "."), ('۱', "l"), ('۲', "٢"), ('۳', "٣"), ('۴', "٤"), ('۵', "o"), ('۶', "٦"), ('۷', "V"), ('۸', "Ʌ"), ('۹', "٩"), ('۽', "ء\u{348}"), ('۾', "م\u{348}"), ('ۿ', "o\u{302}"), ('܁', "."), ('܂', "."), ('܃', ":"), ('܄', ":"), ('\u{740}', "\u{307}"), ('\u{741}', "\u{307}"), ('\u{742}', "\u{73c}"), ('\u{747}', "\u{301}"), ('ݑ', "ب\u{6db}"), ('ݖ', "ى\u{306}"), ('ݢ', "ڬ"), ('ݣ', "ك\u{6db}"), ('ݧ', "ݔ"), ('ݨ', "ن\u{615}"), ('ݩ', "ن\u{306}"), ('ݬ', "ر\u{654}"), ('ݱ', "ڗ\u{615}"), ('ݲ', "ح\u{654}"), ('ݾ', "س\u{302}"), ('߀', "O"), ('ߊ', "l"), ('\u{7eb}', "\u{304}"), ('\u{7ed}', "\u{307}"), ('\u{7ee}', "\u{302}"), ('\u{7f3}', "\u{308}"), ('ߴ', "'"), ('ߵ', "'"), ('ߺ', "_"), ('ࢡ', "ب\u{654}"), ('ࢤ', "ڢ\u{6db}"), ('ࢧ', "م\u{6db}"), ('ࢨ', "ى\u{654}"), ('ࢩ', "ݔ"), ('ࢮ', "د\u{324}\u{323}"), ('ࢯ', "ص\u{324}\u{323}"), ('ࢰ', "گ"), ('ࢱ', "و"), ('ࢲ', "ز\u{302}"), ('ࢶ', "ب\u{6e2}"), ('ࢷ', "ى\u{6db}\u{6e2}"), ('ࢹ', "ر\u{306}\u{307}"), ('ࢺ', "ى\u{306}\u{307}"), ('ࢻ', "ڡ"), ('ࢼ', "ڡ"), ('ࢽ', "ى"), ('\u{8e5}', "\u{64c}"), ('\u{8e8}', "\u{64c}"), ('\u{8ea}', "\u{307}"), ('\u{8eb}', "\u{308}"), ('\u{8ed}', "\u{323}"), ('\u{8ee}', "\u{324}"), ('\u{8f0}', "\u{30b}"), ('\u{8f1}', "\u{64c}"), ('\u{8f2}', "\u{64d}"), ('\u{8f3}', "\u{313}"), ('\u{8f8}', "\u{350}"), ('\u{8f9}', "\u{354}"), ('\u{8fa}', "\u{355}"), ('\u{8ff}', "\u{350}"), ('\u{900}', "\u{352}"), ('\u{901}', "\u{306}\u{307}"), ('\u{902}', "\u{307}"), ('ः', ":"), ('ऄ', "अ\u{946}"), ('आ', "अा"), ('ई', "र\u{94d}इ"), ('ऍ', "ए\u{945}"), ('ऎ', "ए\u{946}"), ('ऐ', "ए\u{947}"), ('ऑ', "अॉ"), ('ऒ', "अा\u{946}"), ('ओ', "अा\u{947}"), ('औ', "अा\u{948}"), ('\u{93c}', "\u{323}"), ('\u{952}', "\u{331}"), ('\u{953}', "\u{300}"), ('\u{954}', "\u{301}"), ('॥', "।।"), ('०', "o"), ('१', "٩"), ('ॽ', "?"), ('\u{981}', "\u{306}\u{307}"), ('আ', "অ\u{9be}"), ('\u{9bc}', "\u{323}"), ('ৠ', "ঋ\u{9c3}"), ('ৡ', "ঋ\u{9c3}"), ('০', "O"), ('৪', "8"), ('৭', "9"), ('\u{a02}', "\u{307}"), ('ਃ', "ঃ"), ('ਆ', "ਅਾ"), ('ਇ', "ੲਿ"), ('ਈ', "ੲੀ"), ('ਉ', "ੳ\u{a41}"), ('ਊ', "ੳ\u{a42}"), ('ਏ', "ੲ\u{a47}"), ('ਐ', "ਅ\u{a48}"), ('ਔ', "ਅ\u{a4c}"), ('\u{a3c}', "\u{323}"), ('\u{a4b}', "\u{946}"), ('\u{a4d}', "\u{94d}"), ('੦', "o"), ('੧', "9"), ('੪', "8"), ('\u{a81}', "\u{306}\u{307}"), ('\u{a82}', "\u{307}"), ('ઃ', ":"), ('આ', "અા"), ('ઍ', "અ\u{ac5}"), ('એ', "અ\u{ac7}"), ('ઐ', "અ\u{ac8}"), ('ઑ', "અા\u{ac5}"), ('ઓ', "અા\u{ac7}"), ('ઔ', "અા\u{ac8}"), ('\u{abc}', "\u{323}"), ('ઽ', "ऽ"), ('\u{ac1}', "\u{941}"), ('\u{ac2}', "\u{942}"), ('\u{acd}', "\u{94d}"), ('૦', "o"), ('૨', "२"), ('૩', "३"), ('૪', "४"), ('૮', "८"), ('૰', "॰"), ('\u{b01}', "\u{306}\u{307}"), ('ଃ', "8"), ('ଆ', "ଅ\u{b3e}"), ('ଠ', "O"), ('\u{b3c}', "\u{323}"), ('୦', "O"), ('୨', "9"), ('\u{b82}', "\u{30a}"), ('ஊ', "உள"), ('ஜ', "ஐ"), ('ர', "ஈ"), ('\u{bbe}', "ஈ"), ('ை', "ன"), ('ொ', "ெஈ"), ('ோ', "ேஈ"), ('ௌ', "ெள"), ('\u{bcd}', "\u{307}"), ('\u{bd7}', "ள"), ('௦', "o"), ('௧', "க"), ('௨', "உ"), ('௪', "ச"), ('௫', "ஈு"), ('௬', "சு"), ('௭', "எ"), ('௮', "அ"), ('௰', "ய"), ('௲', "சூ"), ('௴', "ம\u{bc0}"), ('௵', "௳"), ('௷', "எவ"), ('௸', "ஷ"), ('௺', "ந\u{bc0}"), ('\u{c00}', "\u{306}\u{307}"), ('ం', "o"), ('ః', "ঃ"), ('ఓ', "ఒ\u{c55}"), ('ఔ', "ఒ\u{c4c}"), ('ఠ', "ర\u{5bc}"), ('ఢ', "డ\u{323}"), ('థ', "ధ\u{5bc}"), ('భ', "బ\u{323}"), ('మ', "వు"), ('ష', "వ\u{323}"), ('హ', "వ\u{c3e}"), ('ూ', "ు\u{c3e}"), ('ౄ', "ృ\u{c3e}"), ('ౠ', "ఋ\u{c3e}"), ('ౡ', "ఌ\u{c3e}"), ('౦', "o"), ('\u{c81}', "\u{306}\u{307}"), ('ಂ', "o"), ('ಃ', "ঃ"), ('ಅ', "అ"), ('ಆ', "ఆ"), ('ಇ', "ఇ"), ('ಒ', "ఒ"), ('ಓ', "ఒ\u{c55}"), ('ಔ', "ఒ\u{c4c}"), ('ಜ', "జ"), ('ಞ', "ఞ"), ('ಣ', "ణ"), ('ಯ', "య"), ('ಱ', "ఱ"), ('ಲ', "ల"), ('ೡ', "ಌಾ"), ('೦', "o"), ('೧', "౧"), ('೨', "౨"), ('೯', "౯"), ('\u{d01}', "\u{306}\u{307}"), ('ം', "o"), ('ഃ', "ঃ"), ('ഈ', "ഇ\u{d57}"), ('ഉ', "உ"), ('ഊ', "உ\u{d57}"), ('ഌ', "ന\u{d41}"), ('ഐ', "എെ"), ('ഓ', "ഒ\u{d3e}"), ('ഔ', "ഒ\u{d57}"), ('ങ', "ന\u{d41}"), ('ജ', "ஐ"), ('ഠ', "o"), ('ണ', "ண"), ('റ', "ര"), ('ഴ', "ழ"), ('ശ', "ஶ"), ('ഺ', "டி"), ('ി', "ி"), ('ീ', "ி"), ('\u{d42}', "\u{d41}"), ('\u{d43}', "\u{d41}"), ('ൈ', "െെ"), ('ൎ', "ॱ"), ('൚', "ന\u{d4d}മ"), ('ൟ', "oരo"), ('ൡ', "ഞ"), ('൦', "o"), ('൪', "ര\u{d4d}"), ('൫', "ദ\u{d4d}ര"), ('൬', "ന\u{d4d}ന"), ('൭', "9"), ('൮', "വ\u{d4d}ര"), ('൯', "ന\u{d4d}"), ('൶', "ഹ\u{d4d}മ"), ('൹', "ന\u{d41}"), ('ൻ', "ന\u{d4d}"), ('ർ', "ര\u{d4d}"), ('ං', "o"), ('ඃ', "ঃ"), ('෩', "෨\u{dcf}"), ('෪', "ජ"), ('෫', "ද"), ('෯', "෨\u{dd3}"), ('ฃ', "ข"), ('ซ', "ช"), ('ฏ', "ฎ"), ('ด', "ค"), ('ต', "ค"), ('ท', "ฑ"), ('ม', "ฆ"), ('ฦ', "ภ"), ('ำ', "\u{30a}า"), ('แ', "เเ"), ('ๅ', "า"), ('\u{e4d}', "\u{30a}"), ('๐', "o"), ('ຈ', "จ"), ('ຍ', "ย"), ('ບ', "บ"), ('ປ', "ป"), ('ຝ', "ฝ"), ('ພ', "พ"), ('ຟ', "ฟ"), ('ຳ', "\u{30a}າ"), ('\u{eb8}', "\u{e38}"), ('\u{eb9}', "\u{e39}"), ('\u{ec8}', "\u{e48}"), ('\u{ec9}', "\u{e49}"), ('\u{eca}', "\u{e4a}"), ('\u{ecb}', "\u{e4b}"), ('\u{ecd}', "\u{30a}"), ('໐', "o"), ('ໜ', "ຫນ"), ('ໝ', "ຫມ"), ('ༀ', "ཨ\u{f7c}\u{f7e}"), ('༂', "འ\u{f74}\u{f82}ཿ"), ('༃', "འ\u{f74}\u{f82}༔"), ('༌', "་"), ('༎', "།།"), ('༛', "༚༚"), ('༞', "༝༝"), ('༟', "༚༝"), ('\u{f37}', "\u{325}"), ('ཪ', "ར"), ('\u{f77}', "\u{fb2}\u{f71}\u{f80}"), ('\u{f79}', "\u{fb3}\u{f71}\u{f80}"), ('࿎', "༝༚"), ('࿕', "卐"), ('࿖', "卍"), ('က', "ဂာ"), ('တ', "oာ"), ('ဝ', "o"), ('ဟ', "ပာ"), ('ဩ', "သြ"), ('ဪ', "သြော\u{103a}"), ('\u{1036}', "\u{30a}"), ('း', "ঃ"), ('၀', "o"), ('။', "၊၊"), ('ၥ', "၁"), ('ၦ', "ပ\u{103e}"), ('ၯ', "ပာ\u{103e}"), ('ၰ', "ဃ\u{103e}"), ('ၾ', "ၽ\u{103e}"), ('ႁ', "ဂ\u{103e}"), ('႞', "ႃ\u{30a}"), ('Ⴀ', "Ꞇ"), ('ყ', "y"), ('ჳ', "ȝ"), ('ჿ', "o"), ('ᄁ', "ᄀᄀ"), ('ᄄ', "ᄃᄃ"), ('ᄈ', "ᄇᄇ"), ('ᄊ', "ᄉᄉ"), ('ᄍ', "ᄌᄌ"), ('ᄓ', "ᄂᄀ"), ('ᄔ', "ᄂᄂ"), ('ᄕ', "ᄂᄃ"), ('ᄖ', "ᄂᄇ"), ('ᄗ', "ᄃᄀ"), ('ᄘ', "ᄅᄂ"), ('ᄙ', "ᄅᄅ"), ('ᄚ', "ᄅᄒ"), ('ᄛ', "ᄅᄋ"), ('ᄜ', "ᄆᄇ"), ('ᄝ', "ᄆᄋ"), ('ᄞ', "ᄇᄀ"), ('ᄟ', "ᄇᄂ"), ('ᄠ', "ᄇᄃ"), ('ᄡ', "ᄇᄉ"), ('ᄢ', "ᄇᄉᄀ"), ('ᄣ', "ᄇᄉᄃ"), ('ᄤ', "ᄇᄉᄇ"), ('ᄥ', "ᄇᄉᄉ"), ('ᄦ', "ᄇᄉᄌ"), ('ᄧ', "ᄇᄌ"), ('ᄨ', "ᄇᄎ"), ('ᄩ', "ᄇᄐ"), ('ᄪ', "ᄇᄑ"), ('ᄫ', "ᄇᄋ"), ('ᄬ', "ᄇᄇᄋ"), ('ᄭ', "ᄉᄀ"), ('ᄮ', "ᄉᄂ"), ('ᄯ', "ᄉᄃ"), ('ᄰ', "ᄉᄅ"), ('ᄱ', "ᄉᄆ"), ('ᄲ', "ᄉᄇ"), ('ᄳ', "ᄉᄇᄀ"), ('ᄴ', "ᄉᄉᄉ"), ('ᄵ', "ᄉᄋ"), ('ᄶ', "ᄉᄌ"), ('ᄷ', "ᄉᄎ"), ('ᄸ', "ᄉᄏ"), ('ᄹ', "ᄉᄐ"), ('ᄺ', "ᄉᄑ"), ('ᄻ', "ᄅᄒ"), ('ᄽ', "ᄼᄼ"), ('ᄿ', "ᄾᄾ"), ('ᅁ', "ᄋᄀ"), ('ᅂ', "ᄋᄃ"), ('ᅃ', "ᄋᄆ"), ('ᅄ', "ᄋᄇ"), ('ᅅ', "ᄋᄉ"), ('ᅆ', "ᄋᅀ"), ('ᅇ', "ᄋᄋ"), ('ᅈ', "ᄋᄌ"), ('ᅉ', "ᄋᄎ"), ('ᅊ', "ᄋᄐ"), ('ᅋ', "ᄋᄑ"), ('ᅍ', "ᄌᄋ"), ('ᅏ', "ᅎᅎ"), ('ᅑ', "ᅐᅐ"), ('ᅒ', "ᄎᄏ"), ('ᅓ', "ᄎᄒ"), ('ᅖ', "ᄑᄇ"), ('ᅗ', "ᄑᄋ"), ('ᅘ', "ᄒᄒ"), ('ᅚ', "ᄀᄃ"), ('ᅛ', "ᄂᄉ"), ('ᅜ', "ᄂᄌ"), ('ᅝ', "ᄂᄒ"), ('ᅞ', "ᄃᄅ"), ('ᅢ', "ᅡ丨"), ('ᅤ', "ᅣ丨"), ('ᅦ', "ᅥ丨"), ('ᅨ', "ᅧ丨"), ('ᅪ', "ᅩᅡ"), ('ᅫ', "ᅩᅡ丨"), ('ᅬ', "ᅩ丨"), ('ᅯ', "ᅮᅥ"), ('ᅰ', "ᅮᅥ丨"), ('ᅱ', "ᅮ丨"), ('ᅳ', "ー"), ('ᅴ', "ー丨"), ('ᅵ', "丨"), ('ᅶ', "ᅡᅩ"), ('ᅷ', "ᅡᅮ"), ('ᅸ', "ᅣᅩ"), ('ᅹ', "ᅣᅭ"), ('ᅺ', "ᅥᅩ"), ('ᅻ', "ᅥᅮ"), ('ᅼ', "ᅥー"), ('ᅽ', "ᅧᅩ"), ('ᅾ', "ᅧᅮ"), ('ᅿ', "ᅩᅥ"), ('ᆀ', "ᅩᅥ丨"), ('ᆁ', "ᅩᅧ丨"), ('ᆂ', "ᅩᅩ"), ('ᆃ', "ᅩᅮ"), ('ᆄ', "ᅭᅣ"), ('ᆅ', "ᅭᅣ丨"), ('ᆆ', "ᅭᅣ"), ('ᆇ', "ᅭᅩ"), ('ᆈ', "ᅭ丨"), ('ᆉ', "ᅮᅡ"), ('ᆊ', "ᅮᅡ丨"), ('ᆋ', "ᅮᅥー"), ('ᆌ', "ᅮᅧ丨"), ('ᆍ', "ᅮᅮ"), ('ᆎ', "ᅲᅡ"), ('ᆏ', "ᅲᅥ"), ('ᆐ', "ᅲᅥ丨"), ('ᆑ', "ᅲᅧ"), ('ᆒ', "ᅲᅧ丨"), ('ᆓ', "ᅲᅮ"), ('ᆔ', "ᅲ丨"), ('ᆕ', "ーᅮ"), ('ᆖ', "ーー"), ('ᆗ', "ー丨ᅮ"), ('ᆘ', "丨ᅡ"), ('ᆙ', "丨ᅣ"), ('ᆚ', "丨ᅩ"), ('ᆛ', "丨ᅮ"), ('ᆜ', "丨ー"), ('ᆝ', "丨ᆞ"), ('ᆟ', "ᆞᅥ"), ('ᆠ', "ᆞᅮ"), ('ᆡ', "ᆞ丨"), ('ᆢ', "ᆞᆞ"), ('ᆣ', "ᅡー"), ('ᆤ', "ᅣᅮ"), ('ᆥ', "ᅧᅣ"), ('ᆦ', "ᅩᅣ"), ('ᆧ', "ᅩᅣ丨"), ('ᆨ', "ᄀ"), ('ᆩ', "ᄀᄀ"), ('ᆪ', "ᄀᄉ"), ('ᆫ', "ᄂ"), ('ᆬ', "ᄂᄌ"), ('ᆭ', "ᄂᄒ"), ('ᆮ', "ᄃ"), ('ᆯ', "ᄅ"), ('ᆰ', "ᄅᄀ"), ('ᆱ', "ᄅᄆ"), ('ᆲ', "ᄅᄇ"), ('ᆳ', "ᄅᄉ"), ('ᆴ', "ᄅᄐ"), ('ᆵ', "ᄅᄑ"), ('ᆶ', "ᄅᄒ"), ('ᆷ', "ᄆ"), ('ᆸ', "ᄇ"), ('ᆹ', "ᄇᄉ"), ('ᆺ', "ᄉ"), ('ᆻ', "ᄉᄉ"), ('ᆼ', "ᄋ"), ('ᆽ', "ᄌ"), ('ᆾ', "ᄎ"), ('ᆿ', "ᄏ"), ('ᇀ', "ᄐ"), ('ᇁ', "ᄑ"), ('ᇂ', "ᄒ"), ('ᇃ', "ᄀᄅ"), ('ᇄ', "ᄀᄉᄀ"), ('ᇅ', "ᄂᄀ"), ('ᇆ', "ᄂᄃ"), ('ᇇ', "ᄂᄉ"), ('ᇈ', "ᄂᅀ"), ('ᇉ', "ᄂᄐ"), ('ᇊ', "ᄃᄀ"), ('ᇋ', "ᄃᄅ"), ('ᇌ', "ᄅᄀᄉ"), ('ᇍ', "ᄅᄂ"), ('ᇎ', "ᄅᄃ"), ('ᇏ', "ᄅᄃᄒ"), ('ᇐ', "ᄅᄅ"), ('ᇑ', "ᄅᄆᄀ"), ('ᇒ', "ᄅᄆᄉ"), ('ᇓ', "ᄅᄇᄉ"), ('ᇔ', "ᄅᄇᄒ"), ('ᇕ', "ᄅᄇᄋ"), ('ᇖ', "ᄅᄉᄉ"), ('ᇗ', "ᄅᅀ"), ('ᇘ', "ᄅᄏ"), ('ᇙ', "ᄅᅙ"), ('ᇚ', "ᄆᄀ"), ('ᇛ', "ᄆᄅ"), ('ᇜ', "ᄆᄇ"), ('ᇝ', "ᄆᄉ"), ('ᇞ', "ᄆᄉᄉ"), ('ᇟ', "ᄆᅀ"), ('ᇠ', "ᄆᄎ"), ('ᇡ', "ᄆᄒ"), ('ᇢ', "ᄆᄋ"), ('ᇣ', "ᄇᄅ"), ('ᇤ', "ᄇᄑ"), ('ᇥ', "ᄇᄒ"), ('ᇦ', "ᄇᄋ"), ('ᇧ', "ᄉᄀ"), ('ᇨ', "ᄉᄃ"), ('ᇩ', "ᄉᄅ"), ('ᇪ', "ᄉᄇ"), ('ᇫ', "ᅀ"), ('ᇬ', "ᄋᄀ"), ('ᇭ', "ᄋᄀᄀ"), ('ᇮ', "ᄋᄋ"), ('ᇯ', "ᄋᄏ"), ('ᇰ', "ᅌ"), ('ᇱ', "ᄋᄉ"), ('ᇲ', "ᄋᅀ"), ('ᇳ', "ᄑᄇ"), ('ᇴ', "ᄑᄋ"), ('ᇵ', "ᄒᄂ"), ('ᇶ', "ᄒᄅ"), ('ᇷ', "ᄒᄆ"), ('ᇸ', "ᄒᄇ"), ('ᇹ', "ᅙ"), ('ᇺ', "ᄀᄂ"), ('ᇻ', "ᄀᄇ"), ('ᇼ', "ᄀᄎ"), ('ᇽ', "ᄀᄏ"), ('ᇾ', "ᄀᄒ"), ('ᇿ', "ᄂᄂ"), ('ሀ', "U"), ('ሣ', "ɰ"), ('ቀ', "Φ"), ('በ', "Ո"), ('ኔ', "ձ"), ('ዐ', "O"), ('Ꭰ', "D"), ('Ꭱ', "R"), ('Ꭲ', "T"), ('Ꭴ', "O'"), ('Ꭵ', "i"), ('Ꭸ', "Ⱶ"), ('Ꭹ', "Y"), ('Ꭺ', "A"), ('Ꭻ', "J"), ('Ꭼ', "E"), ('Ꭾ', "?"), ('Ꮀ', "Ⱶ"), ('Ꮁ', "Γ"), ('Ꮃ', "W"), ('Ꮇ', "M"), ('Ꮋ', "H"), ('Ꮍ', "Y"), ('Ꮎ', "O\u{335}"), ('Ꮏ', "ƫ"), ('Ꮐ', "G"), ('Ꮒ', "h"), ('Ꮓ', "Z"), ('Ꮗ', "Ѡ"), ('Ꮛ', "Ɛ"), ('Ꮜ', "U\u{335}"), ('Ꮞ', "4"), ('Ꮟ', "b"), ('Ꮢ', "R"), ('Ꮤ', "W"), ('Ꮥ', "S"), ('Ꮩ', "V"), ('Ꮪ', "S"), ('Ꮮ', "L"), ('Ꮯ', "C"), ('Ꮲ', "P"), ('Ꮶ', "K"), ('Ꮷ', "d"), ('Ꮻ', "O\u{335}"), ('Ꮾ', "6"), ('Ᏸ', "ß"), ('Ᏺ', "h\u{314}"), ('Ᏻ', "G"), ('Ᏼ', "B"), ('ᏻ', "ɢ"), ('ᏼ', "ʙ"), ('᐀', "="), ('ᐃ', "Δ"), ('ᐌ', "·ᐁ"), ('ᐍ', "ᐁ·"), ('ᐎ', "·Δ"), ('ᐏ', "Δ·"), ('ᐐ', "·ᐄ"), ('ᐑ', "ᐄ·"), ('ᐒ', "·ᐅ"), ('ᐓ', "ᐅ·"), ('ᐔ', "·ᐆ"), ('ᐕ', "ᐆ·"), ('ᐗ', "·ᐊ"), ('ᐘ', "ᐊ·"), ('ᐙ', "·ᐋ"), ('ᐚ', "ᐋ·"), ('ᐧ', "·"), ('ᐫ', "ᐁᐠ"), ('ᐬ', "Δᐠ"), ('ᐭ', "ᐅᐠ"), ('ᐮ', "ᐊᐠ"), ('ᐯ', "V"), ('ᐱ', "Ʌ"), ('ᐳ', ">"), ('ᐷ', "·>"), ('ᐸ', "<"), ('ᐺ', "·V"), ('ᐻ', "V·"), ('ᐼ', "·Ʌ"), ('ᐽ', "Ʌ·"), ('ᐾ', "·ᐲ"), ('ᐿ', "ᐲ·"), ('ᑀ', "·>"), ('ᑁ', ">·"), ('ᑂ', "·ᐴ"), ('ᑃ', "ᐴ·"), ('ᑄ', "·<"), ('ᑅ', "<·"), ('ᑆ', "·ᐹ"), ('ᑇ', "ᐹ·"), ('ᑊ', "'"), ('ᑌ', "U"), ('ᑎ', "Ո"), ('ᑔ', "·ᑐ"), ('ᑗ', "·U"), ('ᑘ', "U·"), ('ᑙ', "·Ո"), ('ᑚ', "Ո·"), ('ᑛ', "·ᑏ"), ('ᑜ', "ᑏ·"), ('ᑝ', "·ᑐ"), ('ᑞ', "ᑐ·"), ('ᑟ', "·ᑑ"), ('ᑠ', "ᑑ·"), ('ᑡ', "·ᑕ"), ('ᑢ', "ᑕ·"), ('ᑣ', "·ᑖ"), ('ᑤ', "ᑖ·"), ('ᑧ', "U'"), ('ᑨ', "Ո'"), ('ᑩ', "ᑐ'"), ('ᑪ', "ᑕ'"), ('ᑭ', "P"), ('ᑯ', "d"), ('ᑲ', "b"), ('ᑳ', "b\u{307}"), ('ᑴ', "·ᑫ"), ('ᑵ', "ᑫ·"), ('ᑶ', "·P"), ('ᑷ', "p·"), ('ᑸ', "·ᑮ"), ('ᑹ', "ᑮ·"), ('ᑺ', "·d"), ('ᑻ', "d·"), ('ᑼ', "·ᑰ"), ('ᑽ', "ᑰ·"), ('ᑾ', "·b"), ('ᑿ', "b·"), ('ᒀ', "·b\u{307}"), ('ᒁ', "b\u{307}·"), ('ᒅ', "ᑫ'"), ('ᒆ', "P'"), ('ᒇ', "d'"), ('ᒈ', "b'"), ('ᒍ', "J"), ('ᒒ', "·ᒉ"), ('ᒓ', "ᒉ·"), ('ᒔ', "·ᒋ"), ('ᒕ', "ᒋ·"), ('ᒖ', "·ᒌ"), ('ᒗ', "ᒌ·"), ('ᒘ', "·J"), ('ᒙ', "J·"), ('ᒚ', "·ᒎ"), ('ᒛ', "ᒎ·"), ('ᒜ', "·ᒐ"), ('ᒝ', "ᒐ·"), ('ᒞ', "·ᒑ"), ('ᒟ', "ᒑ·"), ('ᒥ', "Γ"), ('ᒪ', "L"), ('ᒬ', "·ᒣ"), ('ᒭ', "ᒣ·"), ('ᒮ', "·Γ"), ('ᒯ', "Γ·"), ('ᒰ', "·ᒦ"), ('ᒱ', "ᒦ·"), ('ᒲ', "·ᒧ"), ('ᒳ', "ᒧ·"), ('ᒴ', "·ᒨ"), ('ᒵ', "ᒨ·"), ('ᒶ', "·L"), ('ᒷ', "l·"), ('ᒸ', "·ᒫ"), ('ᒹ', "ᒫ·"), ('ᒿ', "2"), ('ᓉ', "·ᓀ"), ('ᓊ', "ᓀ·"), ('ᓋ', "·ᓇ"), ('ᓌ', "ᓇ·"), ('ᓍ', "·ᓈ"), ('ᓎ', "ᓈ·"), ('ᓑ', "ᐡ"), ('ᓜ', "·ᓓ"), ('ᓝ', "ᓓ·"), ('ᓞ', "·ᓕ"), ('ᓟ', "ᓕ·"), ('ᓠ', "·ᓖ"), ('ᓡ', "ᓖ·"), ('ᓢ', "·ᓗ"), ('ᓣ', "ᓗ·"), ('ᓤ', "·ᓘ"), ('ᓥ', "ᓘ·"), ('ᓦ', "·ᓚ"), ('ᓧ', "ᓚ·"), ('ᓨ', "·ᓛ"), ('ᓩ', "ᓛ·"), ('ᓶ', "·ᓭ"), ('ᓷ', "ᓭ·"), ('ᓸ', "·ᓯ"), ('ᓹ', "ᓯ·"), ('ᓺ', "·ᓰ"), ('ᓻ', "ᓰ·"), ('ᓼ', "·ᓱ"), ('ᓽ', "ᓱ·"), ('ᓾ', "·ᓲ"), ('ᓿ', "ᓲ·"), ('ᔀ', "·ᓴ"), ('ᔁ', "ᓴ·"), ('ᔂ', "·ᓵ"), ('ᔃ', "ᓵ·"), ('ᔌ', "ᔋ<"), ('ᔍ', "ᔋᑕ"), ('ᔎ', "ᔋb"), ('ᔏ', "ᔋᒐ"), ('ᔗ', "·ᔐ"), ('ᔘ', "ᔐ·"), ('ᔙ', "·ᔑ"), ('ᔚ', "ᔑ·"), ('ᔛ', "·ᔒ"), ('ᔜ', "ᔒ·"), ('ᔝ', "·ᔓ"), ('ᔞ', "ᔓ·"), ('ᔟ', "·ᔔ"), ('ᔠ', "ᔔ·"), ('ᔡ', "·ᔕ"), ('ᔢ', "ᔕ·"), ('ᔣ', "·ᔖ"), ('ᔤ', "ᔖ·"), ('ᔯ', "·4"), ('ᔰ', "4·"), ('ᔱ', "·ᔨ"), ('ᔲ', "ᔨ·"), ('ᔳ', "·ᔩ"), ('ᔴ', "ᔩ·"), ('ᔵ', "·ᔪ"), ('ᔶ', "ᔪ·"), ('ᔷ', "·ᔫ"), ('ᔸ', "ᔫ·"), ('ᔹ', "·ᔭ"), ('ᔺ', "ᔭ·"), ('ᔻ', "·ᔮ"), ('ᔼ', "ᔮ·"), ('ᕀ', "ᐩ"), ('ᕁ', "x"), ('ᕎ', "·ᕌ"), ('ᕏ', "ᕌ·"), ('ᕛ', "·ᕚ"), ('ᕜ', "ᕚ·"), ('ᕨ', "·ᕧ"), ('ᕩ', "ᕧ·"), ('ᕷ', "ẟ"), ('ᕼ', "H"), ('ᕽ', "x"), ('ᕾ', "ᕐᑬ"), ('ᕿ', "ᕐP"), ('ᖀ', "ᕐᑮ"), ('ᖁ', "ᕐd"), ('ᖂ', "ᕐᑰ"), ('ᖃ', "ᕐb"), ('ᖄ', "ᕐb\u{307}"), ('ᖅ', "ᕐᒃ"), ('ᖇ', "R"), ('ᖎ', "ᖕᒊ"), ('ᖏ', "ᖕᒋ"), ('ᖐ', "ᖕᒌ"), ('ᖑ', "ᖕJ"), ('ᖒ', "ᖕᒎ"), ('ᖓ', "ᖕᒐ"), ('ᖔ', "ᖕᒑ"), ('ᖯ', "b"), ('ᖴ', "F"), ('ᖵ', "Ⅎ"), ('ᖷ', "ꟻ"), ('ᗄ', "Ɐ"), ('ᗅ', "A"), ('ᗞ', "D"), ('ᗪ', "D"), ('ᗯ', "Ѡ"), ('ᗰ', "M"), ('ᗷ', "B"), ('ᘂ', "ᒐ"), ('ᘃ', "ᒉ"), ('ᘄ', "ᓓ"), ('ᘇ', "ᓚ"), ('ᘢ', "ᕃ"), ('ᘣ', "ᕆ"), ('ᘤ', "ᕊ"), ('ᘮ', "Ʊ"), ('ᘯ', "Ω"), ('ᘴ', "Ʊ"), ('ᘵ', "Ω"), ('᙭', "X"), ('᙮', "x"), ('ᙯ', "ᕐᑫ"), ('ᙰ', "ᖕᒉ"), ('ᙱ', "ᖖᒋ"), ('ᙲ', "ᖖᒌ"), ('ᙳ', "ᖖJ"), ('ᙴ', "ᖖᒎ"), ('ᙵ', "ᖖᒐ"), ('ᙶ', "ᖖᒑ"), ('ᙷ', "ᖧ·"), ('ᙸ', "ᖨ·"), ('ᙹ', "ᖩ·"), ('ᙺ', "ᖪ·"), ('ᙻ', "ᖫ·"), ('ᙼ', "ᖬ·"), ('ᙽ', "ᖭ·"), ('\u{1680}', " "), ('ᚲ', "<"), ('ᚷ', "X"), ('ᛁ', "l"), ('ᛂ', "ᚽ"), ('ᛌ', "'"), ('ᛕ', "K"), ('ᛖ', "M"), ('ᛘ', "Ψ"), ('ᛡ', "ᚼ"), ('᛫', "·"), ('᛬', ":"), ('᛭', "+"), ('ᛰ', "Φ"), ('\u{1734}', "\u{1715}"), ('᜵', "/"), ('ឣ', "អ"), ('\u{17b7}', "\u{e34}"), ('\u{17b8}', "\u{e35}"), ('\u{17b9}', "\u{e36}"), ('\u{17ba}', "\u{e37}"), ('\u{17c6}', "\u{30a}"), ('\u{17cb}', "\u{e48}"), ('\u{17d3}', "\u{30a}"), ('។', "ฯ"), ('៕', "๚"), ('៙', "๏"), ('៚', "๛"), ('᠃', ":"), ('᠉', ":"), ('ᡕ', "ᠵ"), ('ᢖ', "ᡜ"), ('ᢳ', "·ᢱ"), ('ᢶ', "·ᢴ"), ('ᢹ', "·ᢸ"), ('ᣂ', "·ᣀ"), ('ᣆ', "·ᓂ"), ('ᣇ', "ᓂ·"), ('ᣈ', "·ᓃ"), ('ᣉ', "ᓃ·"), ('ᣊ', "·ᓄ"), ('ᣋ', "ᓄ·"), ('ᣌ', "·ᓅ"), ('ᣍ', "ᓅ·"), ('ᣎ', "·ᕃ"), ('ᣏ', "·ᕆ"), ('ᣐ', "·ᕇ"), ('ᣑ', "·ᕈ"), ('ᣒ', "·ᕉ"), ('ᣓ', "·ᕋ"), ('ᣛ', "ᣵ"), ('ᣜ', "ᣟᐞ"), ('ᣝ', "ᐞᣟ"), ('ᣠ', "ᕃ·"), ('ᣣ', "ᕞ·"), ('ᣤ', "ᕦ·"), ('ᣥ', "ᕫ·"), ('ᣨ', "ᖆ·"), ('ᣪ', "ᖗ·"), ('ᣭ', "Ѡ·"), ('ᣰ', "ᗴ·"), ('ᣲ', "ᘛ·"), ('᧐', "ᦞ"), ('᧑', "ᦱ"), ('᪀', "ᩅ"), ('᪐', "ᩅ"), ('᪩', "᪨᪨"), ('᪫', "᪪᪨"), ('\u{1ab4}', "\u{6db}"), ('\u{1ab7}', "\u{328}"), ('᭒', "ᬍ"), ('᭓', "ᬑ"), ('᭘', "ᬨ"), ('᭜', "᭐"), ('᭟', "᭞᭞"), ('᰼', "᰻᰻"), ('᱿', "᱾᱾"), ('\u{1cd0}', "\u{302}"), ('\u{1cd2}', "\u{304}"), ('᳓', "''"), ('\u{1cd5}', "\u{32b}"), ('\u{1cd8}', "\u{32e}"), ('\u{1cd9}', "\u{32d}"), ('\u{1cda}', "\u{30e}"), ('\u{1cdc}', "\u{329}"), ('\u{1cdd}', "\u{323}"), ('\u{1cde}', "\u{324}"), ('\u{1ced}', "\u{316}"), ('ᴄ', "c"), ('ᴈ', "ɜ"), ('ᴋ', "ĸ"), ('ᴍ', "ʍ"), ('ᴏ', "o"), ('ᴐ', "ɔ"), ('ᴑ', "o"), ('ᴔ', "ǝo"), ('ᴜ', "u"), ('ᴠ', "v"), ('ᴡ', "w"), ('ᴢ', "z"), ('ᴤ', "ƨ"), ('ᴦ', "r"), ('ᴧ', "ʌ"), ('ᴨ', "π"), ('ᴩ', "ᴘ"), ('ᴫ', "л"), ('ᴾ', "ᣖ"), ('ᵒ', "º"), ('ᵫ', "ue"), ('ᵮ', "f\u{334}"), ('ᵯ', "rn\u{334}"), ('ᵰ', "n\u{334}"), ('ᵲ', "r\u{334}"), ('ᵳ', "ɾ\u{334}"), ('ᵴ', "s\u{334}"), ('ᵵ', "t\u{334}"), ('ᵶ', "z\u{334}"), ('ᵸ', "ᴴ"), ('ᵻ', "i\u{335}"), ('ᵼ', "i\u{335}"), ('ᵽ', "p\u{335}"), ('ᵾ', "u\u{335}"), ('ᵿ', "ʊ\u{335}"), ('ᶃ', "g"), ('ᶌ', "y"), ('ᶐ', "ɋ"), ('ᶟ', "ᵋ"), ('ᶢ', "ᵍ"), ('ᶺ', "ᣔ"), ('ᶻ', "ᙆ"), ('\u{1dee}', "\u{2dec}"), ('ṃ', "ꭑ"), ('ẚ', "a\u{309}"), ('ẝ', "f"), ('ẞ', "ß"), ('ỿ', "y"), ('ώ', "ω\u{301}\u{345}"), ('᾽', "'"), ('ι', "i"), ('᾿', "'"), ('῀', "~"), ('`', "'"), ('ῶ', "Ꮿ"), ('´', "'"), ('῾', "'"), ('\u{2000}', " "), ('\u{2001}', " "), ('\u{2002}', " "), ('\u{2003}', " "), ('\u{2004}', " "), ('\u{2005}', " "), ('\u{2006}', " "), ('\u{2007}', " "), ('\u{2008}', " "), ('\u{2009}', " "), ('\u{200a}', " "), ('‐', "-"), ('‑', "-"), ('‒', "-"), ('–', "-"), ('—', "ー"), ('―', "ー"), ('‖', "ll"), ('‘', "'"), ('’', "'"), ('‚', ","), ('‛', "'"), ('“', "''"), ('”', "''"), ('‟', "''"), ('•', "·"), ('․', "."), ('‥', ".."), ('…', "..."), ('‧', "·"), ('\u{2028}', " "), ('\u{2029}', " "), ('\u{202f}', " "), ('‰', "º/₀₀"), ('‱', "º/₀₀₀"), ('′', "'"), ('″', "''"), ('‴', "'''"), ('‵', "'"), ('‶', "''"), ('‷', "'''"), ('‹', "<"), ('›', ">"), ('‼', "!!"), ('‾', "ˉ"), ('⁁', "/"), ('⁃', "-"), ('⁄', "/"), ('⁇', "??"), ('⁈', "?!"), ('⁉', "!?"), ('⁎', "*"), ('⁒', "º/₀"), ('⁓', "~"), ('⁗', "''''"), ('⁚', ":"), ('⁝', "ⵗ"), ('⁞', "ⵂ"), ('\u{205f}', " "), ('⁰', "º"), ('⁹', "ꝰ"), ('₡', "C\u{20eb}"), ('₤', "£"), ('₥', "rn\u{338}"), ('₨', "Rs"), ('₩', "W\u{335}"), ('₫', "d\u{335}\u{331}"), ('€', "Ꞓ"), ('₭', "K\u{335}"), ('₮', "T\u{20eb}"), ('₶', "lt"), ('₽', "Ք"), ('\u{20db}', "\u{6db}"), ('℀', "a/c"), ('℁', "a/s"), ('ℂ', "C"), ('℃', "°C"), ('℅', "c/o"), ('℆', "c/u"), ('ℇ', "Ɛ"), ('℈', "Э"), ('℉', "°F"), ('ℊ', "g"), ('ℋ', "H"), ('ℌ', "H"), ('ℍ', "H"), ('ℎ', "h"), ('ℏ', "h\u{335}"), ('ℐ', "l"), ('ℑ', "l"), ('ℒ', "L"), ('ℓ', "l"), ('ℕ', "N"), ('№', "No"), ('ℙ', "P"), ('ℚ', "Q"), ('ℛ', "R"), ('ℜ', "R"), ('ℝ', "R"), ('℡', "TEL"), ('ℤ', "Z"), ('Ω', "Ω"), ('℧', "Ʊ"), ('ℨ', "Z"), ('℩', "ɿ"), ('K', "K"), ('ℬ', "B"), ('ℭ', "C"), ('℮', "e"), ('ℯ', "e"), ('ℰ', "E"), ('ℱ', "F"), ('ℳ', "M"), ('ℴ', "o"), ('ℵ', "א"), ('ℶ', "ב"), ('ℷ', "ג"), ('ℸ', "ד"), ('ℹ', "i"), ('℻', "FAX"), ('ℼ', "π"), ('ℽ', "y"), ('ℾ', "Γ"), ('ℿ', "Π"), ('⅀', "Ʃ"), ('⅁', "ꓨ"), ('⅂', "ꓶ"), ('⅃', "𖼀"), ('ⅅ', "D"), ('ⅆ', "d"), ('ⅇ', "e"), ('ⅈ', "i"), ('ⅉ', "j"), ('Ⅰ', "l"), ('Ⅱ', "ll"), ('Ⅲ', "lll"), ('Ⅳ', "lV"), ('Ⅴ', "V"), ('Ⅵ', "Vl"), ('Ⅶ', "Vll"), ('Ⅷ', "Vlll"), ('Ⅸ', "lX"), ('Ⅹ', "X"), ('Ⅺ', "Xl"), ('Ⅻ', "Xll"), ('Ⅼ', "L"), ('Ⅽ', "C"), ('Ⅾ', "D"), ('Ⅿ', "M"), ('ⅰ', "i"), ('ⅱ', "ii"), ('ⅲ', "iii"), ('ⅳ', "iv"), ('ⅴ', "v"), ('ⅵ', "vi"), ('ⅶ', "vii"), ('ⅷ', "viii"), ('ⅸ', "ix"), ('ⅹ', "x"), ('ⅺ', "xi"), ('ⅻ', "xii"), ('ⅼ', "l"), ('ⅽ', "c"), ('ⅾ', "d"), ('ⅿ', "rn"), ('Ↄ', "Ɔ"), ('ↄ', "ɔ"), ('↑', "ᛏ"), ('↕', "ᛨ"), ('↵', "↲"), ('↺', "🄎"), ('↾', "ᛚ"), ('↿', "ᛐ"), ('∀', "Ɐ"), ('∃', "Ǝ"), ('∆', "Δ"), ('∏', "Π"), ('∑', "Ʃ"), ('−', "-"), ('∔', "+\u{307}"), ('∕', "/"), ('∖', "\\"), ('∗', "*"), ('∘', "°"), ('∙', "·"), ('∞', "oo"), ('∣', "l"), ('∥', "ll"), ('∨', "v"), ('∩', "Ո"), ('∪', "U"), ('∫', "ʃ"), ('∬', "ʃʃ"), ('∭', "ʃʃʃ"), ('∯', "∮∮"), ('∰', "∮∮∮"), ('∶', ":"), ('∸', "-\u{307}"), ('∼', "~"), ('≐', "=\u{307}"), ('≑', "=\u{323}\u{307}"), ('≗', "=\u{30a}"), ('≙', "=\u{302}"), ('≚', "=\u{306}"), ('≞', "=\u{36b}"), ('≣', "≡"), ('≪', "<<"), ('≫', ">>"), ('⊂', "ᑕ"), ('⊃', "ᑐ"), ('⊕', "𐊨"), ('⊖', "O\u{335}"), ('⊙', "ʘ"), ('⊝', "O\u{335}"), ('⊤', "T"), ('⊥', "ꓕ"), ('⋀', "∧"), ('⋁', "v"), ('⋂', "Ո"), ('⋃', "U"), ('⋄', "ᛜ"), ('⋅', "·"), ('⋈', "ᛞ"), ('⋖', "<·"), ('⋗', "·>"), ('⋘', "<<<"), ('⋙', ">>>"), ('⋮', "ⵗ"), ('⋯', "···"), ('⋴', "ꞓ"), ('⋿', "E"), ('⌀', "∅"), ('⌥', "⌤"), ('〈', "❬"), ('〉', "❭"), ('⍁', "〼"), ('⍙', "Δ\u{332}"), ('⍚', "ᛜ\u{332}"), ('⍜', "°\u{332}"), ('⍟', "⊛"), ('⍡', "T\u{308}"), ('⍢', "∇\u{308}"), ('⍣', "⋆\u{308}"), ('⍤', "°\u{308}"), ('⍥', "ة"), ('⍨', "~\u{308}"), ('⍩', "ᐵ"), ('⍫', "∇\u{334}"), ('⍬', "O\u{335}"), ('⍳', "i"), ('⍴', "p"), ('⍵', "ω"), ('⍶', "a\u{332}"), ('⍷', "ꞓ\u{332}"), ('⍸', "i\u{332}"), ('⍹', "ω\u{332}"), ('⍺', "a"), ('⍿', "ᚽ"), ('⎜', "丨"), ('⎟', "丨"), ('⎢', "丨"), ('⎥', "丨"), ('⎪', "丨"), ('⎮', "丨"), ('⏁', "⍕"), ('⏂', "⍎"), ('⏃', "⍋"), ('⏆', "⍭"), ('⏨', "₁₀"), ('⏼', "⏻"), ('⏽', "l"), ('⏾', "☾"), ('⑊', "\\\\"), ('①', "➀"), ('②', "➁"), ('③', "➂"), ('④', "➃"), ('⑤', "➄"), ('⑥', "➅"), ('⑦', "➆"), ('⑧', "➇"), ('⑨', "➈"), ('⑩', "➉"), ('⑴', "(l)"), ('⑵', "(2)"), ('⑶', "(3)"), ('⑷', "(4)"), ('⑸', "(5)"), ('⑹', "(6)"), ('⑺', "(7)"), ('⑻', "(8)"), ('⑼', "(9)"), ('⑽', "(lO)"), ('⑾', "(ll)"), ('⑿', "(l2)"), ('⒀', "(l3)"), ('⒁', "(l4)"), ('⒂', "(l5)"), ('⒃', "(l6)"), ('⒄', "(l7)"), ('⒅', "(l8)"), ('⒆', "(l9)"), ('⒇', "(2O)"), ('⒈', "l."), ('⒉', "2."), ('⒊', "3."), ('⒋', "4."), ('⒌', "5."), ('⒍', "6."), ('⒎', "7."), ('⒏', "8."), ('⒐', "9."), ('⒑', "lO."), ('⒒', "ll."), ('⒓', "l2."), ('⒔', "l3."), ('⒕', "l4."), ('⒖', "l5."), ('⒗', "l6."), ('⒘', "l7."), ('⒙', "l8."), ('⒚', "l9."), ('⒛', "2O."), ('⒜', "(a)"), ('⒝', "(b)"), ('⒞', "(c)"), ('⒟', "(d)"), ('⒠', "(e)"), ('⒡', "(f)"), ('⒢', "(g)"), ('⒣', "(h)"), ('⒤', "(i)"), ('⒥', "(j)"), ('⒦', "(k)"), ('⒧', "(l)"), ('⒨', "(rn)"), ('⒩', "(n)"), ('⒪', "(o)"), ('⒫', "(p)"), ('⒬', "(q)"), ('⒭', "(r)"), ('⒮', "(s)"), ('⒯', "(t)"), ('⒰', "(u)"), ('⒱', "(v)"), ('⒲', "(w)"), ('⒳', "(x)"), ('⒴', "(y)"), ('⒵', "(z)"), ('Ⓒ', "©"), ('Ⓟ', "℗"), ('Ⓡ', "®"), ('ⓛ', "Ⓘ"), ('⓪', "🄍"), ('─', "ー"), ('━', "ー"), ('┃', "│"), ('┏', "┌"), ('┣', "├"), ('╱', "/"), ('╳', "X"), ('█', "∎"), ('▐', "▌"), ('▔', "ˉ"), ('▗', "▖"), ('▝', "▘"), ('■', "∎"), ('▱', "⏥"), ('△', "Δ"), ('▷', "⊳"), ('▸', "▶"), ('►', "▶"), ('▽', "𐊼"), ('◁', "⊲"), ('◇', "ᛜ"), ('◊', "ᛜ"), ('○', "°"), ('◎', "⌾"), ('◠', "⌒"), ('◦', "°"), ('☉', "ʘ"), ('☐', "□"), ('☥', "𐦞"), ('☰', "Ⲷ"), ('☸', "⎈"), ('♎', "≏"), ('♢', "ᛜ"), ('♩', "𝅘\u{1d165}"), ('♪', "𝅘\u{1d165}\u{1d16e}"), ('⚬', "॰"), ('❨', "("), ('❩', ")"), ('❮', "<"), ('❯', ">"), ('❲', "("), ('❳', ")"), ('❴', "{"), ('❵', "}"), ('➕', "+"), ('➖', "-"), ('➗', "÷"), ('⟂', "ꓕ"), ('⟈', "\\ᑕ"), ('⟉', "ᑐ/"), ('⟋', "/"), ('⟍', "\\"), ('⟙', "T"), ('⟨', "❬"), ('⟩', "❭"), ('⤫', "x"), ('⤬', "x"), ('⥣', "ᛐᛚ"), ('⥥', "⇃⇂"), ('⥮', "ᛐ⇂"), ('⥯', "⇃ᛚ"), ('⦙', "ⵂ"), ('⦰', "⍉"), ('⦾', "⌾"), ('⧄', "〼"), ('⧅', "⍂"), ('⧇', "⌻"), ('⧖', "𐋀"), ('⧙', "⦚"), ('⧴', ":→"), ('⧵', "\\"), ('⧶', "/\u{304}"), ('⧸', "/"), ('⧹', "\\"), ('⨀', "ʘ"), ('⨁', "𐊨"), ('⨂', "⊗"), ('⨃', "⊍"), ('⨄', "⊎"), ('⨅', "⊓"), ('⨆', "⊔"), ('⨌', "ʃʃʃʃ"), ('⨝', "ᛞ"), ('⨠', ">>"), ('⨡', "ᛚ"), ('⨢', "+\u{30a}"), ('⨣', "+\u{302}"), ('⨤', "+\u{303}"), ('⨥', "+\u{323}"), ('⨦', "+\u{330}"), ('⨧', "+₂"), ('⨩', "-\u{313}"), ('⨪', "-\u{323}"), ('⨯', "x"), ('⨰', "x\u{307}"), ('⨽', "⌙"), ('⨾', "⨟"), ('⨿', "∐"), ('⩪', "~\u{307}"), ('⩮', "=\u{20f0}"), ('⩴', "::="), ('⩵', "=="), ('⩶', "==="), ('⪥', "><"), ('⪪', "ᗕ"), ('⪫', "ᗒ"), ('⫗', "ᑐᑕ"), ('⫻', "///"), ('⫽', "//"), ('⯬', "↞"), ('⯭', "↟"), ('⯮', "↠"), ('⯯', "↡"), ('Ⱨ', "H\u{329}"), ('Ⱪ', "K\u{329}"), ('Ⲅ', "Γ"), ('ⲅ', "r"), ('Ⲇ', "Δ"), ('Ⲉ', "Ꞓ"), ('ⲉ', "ꞓ"), ('Ⲏ', "H"), ('Ⲓ', "l"), ('Ⲕ', "K"), ('ⲕ', "ĸ"), ('Ⲗ', "λ"), ('Ⲙ', "M"), ('Ⲛ', "N"), ('Ⲟ', "O"), ('ⲟ', "o"), ('Ⲡ', "Π"), ('Ⲣ', "P"), ('ⲣ', "p"), ('Ⲥ', "C"), ('ⲥ', "c"), ('Ⲧ', "T"), ('Ⲩ', "Y"), ('Ⲫ', "Φ"), ('ⲫ', "ɸ"), ('Ⲭ', "X"), ('ⲭ', "χ"), ('Ⲯ', "Ψ"), ('ⲱ', "ω"), ('Ⲵ', "<·"), ('Ⲻ', "-"), ('Ⲽ', "Ш"), ('ⲽ', "ш"), ('Ⳇ', "/"), ('Ⳋ', "9"), ('Ⳍ', "3"), ('ⳍ', "ȝ"), ('Ⳑ', "L"), ('ⳑ', "ʟ"), ('Ⳓ', "6"), ('Ⳝ', "Ϭ"), ('ⳤ', "ϗ"), ('⳩', "☧"), ('⳹', "\\\\"), ('ⴱ', "O\u{335}"), ('ⴷ', "Ʌ"), ('ⴸ', "V"), ('ⴹ', "E"), ('ⴺ', "Ǝ"), ('ⵁ', "O\u{338}"), ('ⵈ', "···"), ('ⵉ', "Ʃ"), ('ⵏ', "l"), ('ⵑ', "!"), ('ⵔ', "O"), ('ⵕ', "Q"), ('ⵙ', "ʘ"), ('ⵝ', "X"), ('ⵠ', "Δ"), ('ⵣ', "ᛯ"), ('\u{2de8}', "\u{1ddf}"), ('\u{2dea}', "\u{30a}"), ('\u{2ded}', "\u{368}"), ('\u{2def}', "\u{36f}"), ('\u{2df6}', "\u{363}"), ('\u{2df7}', "\u{364}"), ('⸚', "-\u{308}"), ('⸞', "~\u{307}"), ('⸟', "~\u{323}"), ('⸦', "ᑕ"), ('⸧', "ᑐ"), ('⸨', "(("), ('⸩', "))"), ('⸪', "∵"), ('⸫', "∴"), ('⸬', "∷"), ('⸮', "؟"), ('⸰', "°"), ('⸱', "·"), ('⸲', "،"), ('⸵', "؛"), ('⸹', "ẟ"), ('⸽', "ⵂ"), ('⸿', "¶"), ('⹀', "="), ('⺂', "乛"), ('⺃', "乚"), ('⺅', "亻"), ('⺉', "刂"), ('⺋', "㔾"), ('⺎', "兀"), ('⺏', "尣"), ('⺐', "尢"), ('⺒', "巳"), ('⺓', "幺"), ('⺔', "彑"), ('⺖', "忄"), ('⺗', "㣺"), ('⺘', "扌"), ('⺙', "攵"), ('⺛', "旡"), ('⺞', "歺"), ('⺟', "母"), ('⺠', "民"), ('⺡', "氵"), ('⺢', "氺"), ('⺣', "灬"), ('⺤', "爫"), ('⺦', "丬"), ('⺨', "犭"), ('⺫', "罒"), ('⺭', "礻"), ('⺯', "糹"), ('⺱', "罓"), ('⺲', "罒"), ('⺹', "耂"), ('⺺', "肀"), ('⺾', "艹"), ('⺿', "艹"), ('⻀', "艹"), ('⻁', "虎"), ('⻂', "衤"), ('⻃', "覀"), ('⻄', "西"), ('⻅', "见"), ('⻈', "讠"), ('⻉', "贝"), ('⻋', "车"), ('⻌', "辶"), ('⻍', "辶"), ('⻏', "阝"), ('⻐', "钅"), ('⻑', "長"), ('⻒', "镸"), ('⻓', "长"), ('⻔', "门"), ('⻖', "阝"), ('⻘', "青"), ('⻙', "韦"), ('⻚', "页"), ('⻛', "风"), ('⻜', "飞"), ('⻝', "食"), ('⻟', "飠"), ('⻠', "饣"), ('⻢', "马"), ('⻤', "鬼"), ('⻥', "鱼"), ('⻨', "麦"), ('⻩', "黄"), ('⻫', "斉"), ('⻬', "齐"), ('⻭', "歯"), ('⻮', "齿"), ('⻯', "竜"), ('⻰', "龙"), ('⻲', "亀"), ('⻳', "龟"), ('⼀', "ー"), ('⼁', "丨"), ('⼂', "\\"), ('⼃', "/"), ('⼄', "乙"), ('⼅', "亅"), ('⼆', "二"), ('⼇', "亠"), ('⼈', "人"), ('⼉', "儿"), ('⼊', "入"), ('⼋', "八"), ('⼌', "冂"), ('⼍', "冖"), ('⼎', "冫"), ('⼏', "几"), ('⼐', "凵"), ('⼑', "刀"), ('⼒', "力"), ('⼓', "勹"), ('⼔', "匕"), ('⼕', "匚"), ('⼖', "匸"), ('⼗', "十"), ('⼘', "卜"), ('⼙', "卩"), ('⼚', "厂"), ('⼛', "厶"), ('⼜', "又"), ('⼝', "口"), ('⼞', "口"), ('⼟', "土"), ('⼠', "土"), ('⼡', "夂"), ('⼢', "夊"), ('⼣', "夕"), ('⼤', "大"), ('⼥', "女"), ('⼦', "子"), ('⼧', "宀"), ('⼨', "寸"), ('⼩', "小"), ('⼪', "尢"), ('⼫', "尸"), ('⼬', "屮"), ('⼭', "山"), ('⼮', "巛"), ('⼯', "工"), ('⼰', "己"), ('⼱', "巾"), ('⼲', "干"), ('⼳', "幺"), ('⼴', "广"), ('⼵', "廴"), ('⼶', "廾"), ('⼷', "弋"), ('⼸', "弓"), ('⼹', "彐"), ('⼺', "彡"), ('⼻', "彳"), ('⼼', "心"), ('⼽', "戈"), ('⼾', "戶"), ('⼿', "手"), ('⽀', "支"), ('⽁', "攴"), ('⽂', "文"), ('⽃', "斗"), ('⽄', "斤"), ('⽅', "方"), ('⽆', "无"), ('⽇', "日"), ('⽈', "曰"), ('⽉', "月"), ('⽊', "木"), ('⽋', "欠"), ('⽌', "止"), ('⽍', "歹"), ('⽎', "殳"), ('⽏', "毋"), ('⽐', "比"), ('⽑', "毛"), ('⽒', "氏"), ('⽓', "气"), ('⽔', "水"), ('⽕', "火"), ('⽖', "爪"), ('⽗', "父"), ('⽘', "爻"), ('⽙', "爿"), ('⽚', "片"), ('⽛', "牙"), ('⽜', "牛"), ('⽝', "犬"), ('⽞', "玄"), ('⽟', "玉"), ('⽠', "瓜"), ('⽡', "瓦"), ('⽢', "甘"), ('⽣', "生"), ('⽤', "用"), ('⽥', "田"), ('⽦', "疋"), ('⽧', "疒"), ('⽨', "癶"), ('⽩', "白"), ('⽪', "皮"), ('⽫', "皿"), ('⽬', "目"), ('⽭', "矛"), ('⽮', "矢"), ('⽯', "石"), ('⽰', "示"), ('⽱', "禸"), ('⽲', "禾"), ('⽳', "穴"), ('⽴', "立"), ('⽵', "竹"), ('⽶', "米"), ('⽷', "糸"), ('⽸', "缶"), ('⽹', "网"), ('⽺', "羊"), ('⽻', "羽"), ('⽼', "老"), ('⽽', "而"), ('⽾', "耒"), ('⽿', "耳"), ('⾀', "聿"), ('⾁', "肉"), ('⾂', "臣"), ('⾃', "自"), ('⾄', "至"), ('⾅', "臼"), ('⾆', "舌"), ('⾇', "舛"), ('⾈', "舟"), ('⾉', "艮"), ('⾊', "色"), ('⾋', "艸"), ('⾌', "虍"), ('⾍', "虫"), ('⾎', "血"), ('⾏', "行"), ('⾐', "衣"), ('⾑', "襾"), ('⾒', "見"), ('⾓', "角"), ('⾔', "言"), ('⾕', "谷"), ('⾖', "豆"), ('⾗', "豕"), ('⾘', "豸"), ('⾙', "貝"), ('⾚', "赤"), ('⾛', "走"), ('⾜', "足"), ('⾝', "身"), ('⾞', "車"), ('⾟', "辛"), ('⾠', "辰"), ('⾡', "辵"), ('⾢', "邑"), ('⾣', "酉"), ('⾤', "釆"), ('⾥', "里"), ('⾦', "金"), ('⾧', "長"), ('⾨', "門"), ('⾩', "阜"), ('⾪', "隶"), ('⾫', "隹"), ('⾬', "雨"), ('⾭', "靑"), ('⾮', "非"), ('⾯', "面"), ('⾰', "革"), ('⾱', "韋"), ('⾲', "韭"), ('⾳', "音"), ('⾴', "頁"), ('⾵', "風"), ('⾶', "飛"), ('⾷', "食"), ('⾸', "首"), ('⾹', "香"), ('⾺', "馬"), ('⾻', "骨"), ('⾼', "高"), ('⾽', "髟"), ('⾾', "鬥"), ('⾿', "鬯"), ('⿀', "鬲"), ('⿁', "鬼"), ('⿂', "魚"), ('⿃', "鳥"), ('⿄', "鹵"), ('⿅', "鹿"), ('⿆', "麥"), ('⿇', "麻"), ('⿈', "黃"), ('⿉', "黍"), ('⿊', "黑"), ('⿋', "黹"), ('⿌', "黽"), ('⿍', "鼎"), ('⿎', "鼓"), ('⿏', "鼠"), ('⿐', "鼻"), ('⿑', "齊"), ('⿒', "齒"), ('⿓', "龍"), ('⿔', "龜"), ('⿕', "龠"), ('。', "˳"), ('〃', "''"), ('〇', "O"), ('〈', "❬"), ('〉', "❭"), ('〒', "₸"), ('〔', "("), ('〕', ")"), ('〚', "⟦"), ('〛', "⟧"), ('\u{302c}', "\u{309}"), ('\u{302d}', "\u{325}"), ('〳', "/"), ('〶', "₸"), ('〸', "十"), ('〹', "卄"), ('〺', "卅"), ('く', "❬"), ('\u{309a}', "\u{30a}"), ('゛', "\u{ff9e}"), ('゜', "\u{ff9f}"), ('゠', "="), ('イ', "亻"), ('エ', "工"), ('カ', "力"), ('タ', "夕"), ('ト', "卜"), ('ニ', "二"), ('ノ', "/"), ('ハ', "八"), ('ヘ', "へ"), ('ロ', "口"), ('・', "·"), ('ㄦ', "儿"), ('ㄱ', "ᄀ"), ('ㄲ', "ᄀᄀ"), ('ㄳ', "ᄀᄉ"), ('ㄴ', "ᄂ"), ('ㄵ', "ᄂᄌ"), ('ㄶ', "ᄂᄒ"), ('ㄷ', "ᄃ"), ('ㄸ', "ᄃᄃ"), ('ㄹ', "ᄅ"), ('ㄺ', "ᄅᄀ"), ('ㄻ', "ᄅᄆ"), ('ㄼ', "ᄅᄇ"), ('ㄽ', "ᄅᄉ"), ('ㄾ', "ᄅᄐ"), ('ㄿ', "ᄅᄑ"), ('ㅀ', "ᄅᄒ"), ('ㅁ', "ᄆ"), ('ㅂ', "ᄇ"), ('ㅃ', "ᄇᄇ"), ('ㅄ', "ᄇᄉ"), ('ㅅ', "ᄉ"), ('ㅆ', "ᄉᄉ"), ('ㅇ', "ᄋ"), ('ㅈ', "ᄌ"), ('ㅉ', "ᄌᄌ"), ('ㅊ', "ᄎ"), ('ㅋ', "ᄏ"), ('ㅌ', "ᄐ"), ('ㅍ', "ᄑ"), ('ㅎ', "ᄒ"), ('ㅏ', "ᅡ"), ('ㅐ', "ᅡ丨"), ('ㅑ', "ᅣ"), ('ㅒ', "ᅣ丨"), ('ㅓ', "ᅥ"), ('ㅔ', "ᅥ丨"), ('ㅕ', "ᅧ"), ('ㅖ', "ᅧ丨"), ('ㅗ', "ᅩ"), ('ㅘ', "ᅩᅡ"), ('ㅙ', "ᅩᅡ丨"), ('ㅚ', "ᅩ丨"), ('ㅛ', "ᅭ"), ('ㅜ', "ᅮ"), ('ㅝ', "ᅮᅥ"), ('ㅞ', "ᅮᅥ丨"), ('ㅟ', "ᅮ丨"), ('ㅠ', "ᅲ"), ('ㅡ', "ー"), ('ㅢ', "ー丨"), ('ㅣ', "丨"), ('ㅤ', "ᅠ"), ('ㅥ', "ᄂᄂ"), ('ㅦ', "ᄂᄃ"), ('ㅧ', "ᄂᄉ"), ('ㅨ', "ᄂᅀ"), ('ㅩ', "ᄅᄀᄉ"), ('ㅪ', "ᄅᄃ"), ('ㅫ', "ᄅᄇᄉ"), ('ㅬ', "ᄅᅀ"), ('ㅭ', "ᄅᅙ"), ('ㅮ', "ᄆᄇ"), ('ㅯ', "ᄆᄉ"), ('ㅰ', "ᄆᅀ"), ('ㅱ', "ᄆᄋ"), ('ㅲ', "ᄇᄀ"), ('ㅳ', "ᄇᄃ"), ('ㅴ', "ᄇᄉᄀ"), ('ㅵ', "ᄇᄉᄃ"), ('ㅶ', "ᄇᄌ"), ('ㅷ', "ᄇᄐ"), ('ㅸ', "ᄇᄋ"), ('ㅹ', "ᄇᄇᄋ"), ('ㅺ', "ᄉᄀ"), ('ㅻ', "ᄉᄂ"), ('ㅼ', "ᄉᄃ"), ('ㅽ', "ᄉᄇ"), ('ㅾ', "ᄉᄌ"), ('ㅿ', "ᅀ"), ('ㆀ', "ᄋᄋ"), ('ㆁ', "ᅌ"), ('ㆂ', "ᄋᄉ"), ('ㆃ', "ᄋᅀ"), ('ㆄ', "ᄑᄋ"), ('ㆅ', "ᄒᄒ"), ('ㆆ', "ᅙ"), ('ㆇ', "ᅭᅣ"), ('ㆈ', "ᅭᅣ丨"), ('ㆉ', "ᅭ丨"), ('ㆊ', "ᅲᅧ"), ('ㆋ', "ᅲᅧ丨"), ('ㆌ', "ᅲ丨"), ('ㆍ', "ᆞ"), ('ㆎ', "ᆞ丨"), ('㇐', "ー"), ('㇑', "丨"), ('㇓', "/"), ('㇔', "\\"), ('㇖', "乛"), ('㇚', "亅"), ('㇛', "❬"), ('㇟', "乚"), ('㇠', "乙"), ('㈀', "(ᄀ)"), ('㈁', "(ᄂ)"), ('㈂', "(ᄃ)"), ('㈃', "(ᄅ)"), ('㈄', "(ᄆ)"), ('㈅', "(ᄇ)"), ('㈆', "(ᄉ)"), ('㈇', "(ᄋ)"), ('㈈', "(ᄌ)"), ('㈉', "(ᄎ)"), ('㈊', "(ᄏ)"), ('㈋', "(ᄐ)"), ('㈌', "(ᄑ)"), ('㈍', "(ᄒ)"), ('㈎', "(가)"), ('㈏', "(나)"), ('㈐', "(다)"), ('㈑', "(라)"), ('㈒', "(마)"), ('㈓', "(바)"), ('㈔', "(사)"), ('㈕', "(아)"), ('㈖', "(자)"), ('㈗', "(차)"), ('㈘', "(카)"), ('㈙', "(타)"), ('㈚', "(파)"), ('㈛', "(하)"), ('㈜', "(주)"), ('㈝', "(오전)"), ('㈞', "(오후)"), ('㈠', "(ー)"), ('㈡', "(二)"), ('㈢', "(三)"), ('㈣', "(四)"), ('㈤', "(五)"), ('㈥', "(六)"), ('㈦', "(七)"), ('㈧', "(八)"), ('㈨', "(九)"), ('㈩', "(十)"), ('㈪', "(月)"), ('㈫', "(火)"), ('㈬', "(水)"), ('㈭', "(木)"), ('㈮', "(金)"), ('㈯', "(土)"), ('㈰', "(日)"), ('㈱', "(株)"), ('㈲', "(有)"), ('㈳', "(社)"), ('㈴', "(名)"), ('㈵', "(特)"), ('㈶', "(財)"), ('㈷', "(祝)"), ('㈸', "(労)"), ('㈹', "(代)"), ('㈺', "(呼)"), ('㈻', "(学)"), ('㈼', "(監)"), ('㈽', "(企)"), ('㈾', "(資)"), ('㈿', "(協)"), ('㉀', "(祭)"), ('㉁', "(休)"), ('㉂', "(自)"), ('㉃', "(至)"), ('㋀', "l月"), ('㋁', "2月"), ('㋂', "3月"), ('㋃', "4月"), ('㋄', "5月"), ('㋅', "6月"), ('㋆', "7月"), ('㋇', "8月"), ('㋈', "9月"), ('㋉', "lO月"), ('㋊', "ll月"), ('㋋', "l2月"), ('㍘', "O点"), ('㍙', "l点"), ('㍚', "2点"), ('㍛', "3点"), ('㍜', "4点"), ('㍝', "5点"), ('㍞', "6点"), ('㍟', "7点"), ('㍠', "8点"), ('㍡', "9点"), ('㍢', "lO点"), ('㍣', "ll点"), ('㍤', "l2点"), ('㍥', "l3点"), ('㍦', "l4点"), ('㍧', "l5点"), ('㍨', "l6点"), ('㍩', "l7点"), ('㍪', "l8点"), ('㍫', "l9点"), ('㍬', "2O点"), ('㍭', "2l点"), ('㍮', "22点"), ('㍯', "23点"), ('㍰', "24点"), ('㏠', "l日"), ('㏡', "2日"), ('㏢', "3日"), ('㏣', "4日"), ('㏤', "5日"), ('㏥', "6日"), ('㏦', "7日"), ('㏧', "8日"), ('㏨', "9日"), ('㏩', "lO日"), ('㏪', "ll日"), ('㏫', "l2日"), ('㏬', "l3日"), ('㏭', "l4日"), ('㏮', "l5日"), ('㏯', "l6日"), ('㏰', "l7日"), ('㏱', "l8日"), ('㏲', "l9日"), ('㏳', "2O日"), ('㏴', "2l日"), ('㏵', "22日"), ('㏶', "23日"), ('㏷', "24日"), ('㏸', "25日"), ('㏹', "26日"), ('㏺', "27日"), ('㏻', "28日"), ('㏼', "29日"), ('㏽', "3O日"), ('㏾', "3l日"), ('㦳', "㘽"), ('䎛', "㖈"), ('䐠', "㬻"), ('一', "ー"), ('丶', "\\"), ('丿', "/"), ('倂', "併"), ('值', "値"), ('啟', "啓"), ('囗', "口"), ('填', "塡"), ('士', "土"), ('壿', "墫"), ('嬀', "媯"), ('帲', "帡"), ('幐', "㬺"), ('戸', "戶"), ('搉', "㩁"), ('晣', "䀿"), ('晩', "晚"), ('曶', "㫚"), ('朦', "䑃"), ('柿', "杮"), ('槩', "㮣"), ('樧', "榝"), ('潙', "溈"), ('硏', "研"), ('絶', "絕"), ('肦', "朌"), ('胊', "朐"), ('胐', "朏"), ('胶', "㬵"), ('脁', "朓"), ('脧', "朘"), ('腁', "胼"), ('膧', "朣"), ('蔿', "蒍"), ('虁', "蘷"), ('訞', "䚶"), ('詽', "訮"), ('讏', "讆"), ('豣', "豜"), ('趆', "赿"), ('跺', "跥"), ('躛', "躗"), ('輧', "軿"), ('郞', "郎"), ('鎮', "鎭"), ('隸', "隷"), ('鹃', "鹂"), ('黒', "黑"), ('鿃', "䀹"), ('꒔', "ꋍ"), ('꒜', "ꃀ"), ('꒞', "ꁊ"), ('꒧', "ꑘ"), ('꒨', "ꄲ"), ('꒬', "ꁐ"), ('꒰', "ꏂ"), ('꒺', "ꎿ"), ('꒾', "ꊱ"), ('꒿', "ꉙ"), ('꓀', "ꎫ"), ('꓂', "ꎵ"), ('ꓐ', "B"), ('ꓑ', "P"), ('ꓒ', "d"), ('ꓓ', "D"), ('ꓔ', "T"), ('ꓖ', "G"), ('ꓗ', "K"), ('ꓙ', "J"), ('ꓚ', "C"), ('ꓛ', "Ɔ"), ('ꓜ', "Z"), ('ꓝ', "F"), ('ꓞ', "Ⅎ"), ('ꓟ', "M"), ('ꓠ', "N"), ('ꓡ', "L"), ('ꓢ', "S"), ('ꓣ', "R"), ('ꓥ', "Ʌ"), ('ꓦ', "V"), ('ꓧ', "H"), ('ꓪ', "W"), ('ꓫ', "X"), ('ꓬ', "Y"), ('ꓭ', "ᙠ"), ('ꓮ', "A"), ('ꓯ', "Ɐ"), ('ꓰ', "E"), ('ꓱ', "Ǝ"), ('ꓲ', "l"), ('ꓳ', "O"), ('ꓴ', "U"), ('ꓵ', "Ո"), ('ꓷ', "ᗡ"), ('ꓸ', "."), ('ꓹ', ","), ('ꓺ', ".."), ('ꓻ', ".,"), ('ꓽ', ":"), ('꓾', "-."), ('꓿', "="), ('꘎', "."), ('Ꙅ', "2"), ('ꙅ', "ƨ"), ('ꙇ', "i"), ('ꙍ', "ω"), ('Ꙑ', "Ъl"), ('ꙑ', "ˉbi"), ('Ꙩ', "ʘ"), ('\u{a66f}', "\u{20e9}"), ('\u{a67c}', "\u{306}"), ('꙾', "ˇ"), ('ꚕ', "h\u{314}"), ('Ꚙ', "OO"), ('ꚙ', "oo"), ('Ꚛ', "𐊨"), ('ꚡ', "И"), ('ꚰ', "ᚹ"), ('ꚱ', "Ⱶ"), ('ꛍ', "ʡ"), ('ꛎ', "Ʌ"), ('ꛛ', "Π"), ('ꛟ', "V"), ('ꛫ', "?"), ('ꛯ', "2"), ('\u{a6f0}', "\u{302}"), ('\u{a6f1}', "\u{304}"), ('꛴', "꛳꛳"), ('꜔', "˫"), ('꜖', "˪"), ('Ꜩ', "T3"), ('ꜩ', "tȝ"), ('ꜱ', "s"), ('Ꜳ', "AA"), ('ꜳ', "aa"), ('Ꜵ', "AO"), ('ꜵ', "ao"), ('Ꜷ', "AU"), ('ꜷ', "au"), ('Ꜹ', "AV"), ('ꜹ', "av"), ('Ꜻ', "AV"), ('ꜻ', "av"), ('Ꜽ', "AY"), ('ꜽ', "ay"), ('Ꝁ', "K\u{335}"), ('Ꝋ', "O\u{335}"), ('ꝋ', "o\u{335}"), ('Ꝏ', "OO"), ('ꝏ', "oo"), ('Ꝛ', "2"), ('ꝡ', "w\u{326}"), ('Ꝫ', "3"), ('ꝫ', "ȝ"), ('Ꝯ', "9"), ('ꝷ', "tf"), ('ꝸ', "&"), ('ꝺ', "Ꝺ"), ('꞉', ":"), ('ꞌ', "'"), ('ꞏ', "·"), ('ꞕ', "ꜧ"), ('Ꞙ', "F"), ('ꞙ', "f"), ('Ꞛ', "𐐒"), ('ꞛ', "𐐺"), ('ꞝ', "ʚ"), ('Ꞟ', "ꓤ"), ('ꞟ', "u"), ('Ɜ', "3"), ('Ʇ', "ꓕ"), ('Ʝ', "J"), ('Ꭓ', "X"), ('Ꞵ', "B"), ('ꞵ', "ß"), ('Ꞷ', "Ꙍ"), ('ꞷ', "ω"), ('Ꟗ', "ß"), ('Ꟛ', "Ʌ"), ('ꟛ', "λ"), ('Ƛ', "Ʌ\u{338}"), ('ꟷ', "ー"), ('꠰', "।"), ('ꥠ', "ᄃᄆ"), ('ꥡ', "ᄃᄇ"), ('ꥢ', "ᄃᄉ"), ('ꥣ', "ᄃᄌ"), ('ꥤ', "ᄅᄀ"), ('ꥥ', "ᄅᄀᄀ"), ('ꥦ', "ᄅᄃ"), ('ꥧ', "ᄅᄃᄃ"), ('ꥨ', "ᄅᄆ"), ('ꥩ', "ᄅᄇ"), ('ꥪ', "ᄅᄇᄇ"), ('ꥫ', "ᄅᄇᄋ"), ('ꥬ', "ᄅᄉ"), ('ꥭ', "ᄅᄌ"), ('ꥮ', "ᄅᄏ"), ('ꥯ', "ᄆᄀ"), ('ꥰ', "ᄆᄃ"), ('ꥱ', "ᄆᄉ"), ('ꥲ', "ᄇᄉᄐ"), ('ꥳ', "ᄇᄏ"), ('ꥴ', "ᄇᄒ"), ('ꥵ', "ᄉᄉᄇ"), ('ꥶ', "ᄋᄅ"), ('ꥷ', "ᄋᄒ"), ('ꥸ', "ᄌᄌᄒ"), ('ꥹ', "ᄐᄐ"), ('ꥺ', "ᄑᄒ"), ('ꥻ', "ᄒᄉ"), ('ꥼ', "ᅙᅙ"), ('ꦒ', "ⰿ"), ('ꦣ', "ꦝ"), ('꧆', "꧐"), ('ꧏ', "٢"), ('꩓', "ꨁ"), ('꩖', "ꨣ"), ('ꬲ', "e"), ('ꬵ', "f"), ('ꬽ', "o"), ('ꬾ', "o\u{338}"), ('ꬿ', "ɔ\u{338}"), ('ꭁ', "ǝo\u{338}"), ('ꭂ', "ǝo\u{335}"), ('ꭇ', "r"), ('ꭈ', "r"), ('ꭍ', "ʃ"), ('ꭎ', "u"), ('ꭒ', "u"), ('ꭓ', "χ"), ('ꭕ', "χ"), ('ꭚ', "y"), ('ꭠ', "љ"), ('ꭢ', "ɔe"), ('ꭣ', "uo"), ('ꭰ', "ᴅ"), ('ꭱ', "ʀ"), ('ꭲ', "ᴛ"), ('ꭴ', "o\u{31b}"), ('ꭵ', "i"), ('ꭺ', "ᴀ"), ('ꭻ', "ᴊ"), ('ꭼ', "ᴇ"), ('ꭾ', "ɂ"), ('ꮀ', "ⱶ"), ('ꮁ', "r"), ('ꮃ', "w"), ('ꮇ', "ʍ"), ('ꮋ', "ʜ"), ('ꮎ', "o\u{335}"), ('ꮐ', "ɢ"), ('ꮓ', "z"), ('ꮛ', "ꞓ"), ('ꮜ', "u\u{335}"), ('ꮟ', "ƅ"), ('ꮢ', "ʀ"), ('ꮩ', "v"), ('ꮪ', "s"), ('ꮮ', "ʟ"), ('ꮯ', "c"), ('ꮲ', "ᴘ"), ('ꮶ', "ĸ"), ('ꮻ', "o\u{335}"), ('ힰ', "ᅩᅧ"), ('ힱ', "ᅩᅩ丨"), ('ힲ', "ᅭᅡ"), ('ힳ', "ᅭᅡ丨"), ('ힴ', "ᅭᅥ"), ('ힵ', "ᅮᅧ"), ('ힶ', "ᅮ丨丨"), ('ힷ', "ᅲᅡ丨"), ('ힸ', "ᅲᅩ"), ('ힹ', "ーᅡ"), ('ힺ', "ーᅥ"), ('ힻ', "ーᅥ丨"), ('ힼ', "ーᅩ"), ('ힽ', "丨ᅣᅩ"), ('ힾ', "丨ᅣ丨"), ('ힿ', "丨ᅧ"), ('ퟀ', "丨ᅧ丨"), ('ퟁ', "丨ᅩ丨"), ('ퟂ', "丨ᅭ"), ('ퟃ', "丨ᅲ"), ('ퟄ', "丨丨"), ('ퟅ', "ᆞᅡ"), ('ퟆ', "ᆞᅥ丨"), ('ퟋ', "ᄂᄅ"), ('ퟌ', "ᄂᄎ"), ('ퟍ', "ᄃᄃ"), ('ퟎ', "ᄃᄃᄇ"), ('ퟏ', "ᄃᄇ"), ('ퟐ', "ᄃᄉ"), ('ퟑ', "ᄃᄉᄀ"), ('ퟒ', "ᄃᄌ"), ('ퟓ', "ᄃᄎ"), ('ퟔ', "ᄃᄐ"), ('ퟕ', "ᄅᄀᄀ"), ('ퟖ', "ᄅᄀᄒ"), ('ퟗ', "ᄅᄅᄏ"), ('ퟘ', "ᄅᄆᄒ"), ('ퟙ', "ᄅᄇᄃ"), ('ퟚ', "ᄅᄇᄑ"), ('ퟛ', "ᄅᅌ"), ('ퟜ', "ᄅᅙᄒ"), ('ퟝ', "ᄅᄋ"), ('ퟞ', "ᄆᄂ"), ('ퟟ', "ᄆᄂᄂ"), ('ퟠ', "ᄆᄆ"), ('ퟡ', "ᄆᄇᄉ"), ('ퟢ', "ᄆᄌ"), ('ퟣ', "ᄇᄃ"), ('ퟤ', "ᄇᄅᄑ"), ('ퟥ', "ᄇᄆ"), ('ퟦ', "ᄇᄇ"), ('ퟧ', "ᄇᄉᄃ"), ('ퟨ', "ᄇᄌ"), ('ퟩ', "ᄇᄎ"), ('ퟪ', "ᄉᄆ"), ('ퟫ', "ᄉᄇᄋ"), ('ퟬ', "ᄉᄉᄀ"), ('ퟭ', "ᄉᄉᄃ"), ('ퟮ', "ᄉᅀ"), ('ퟯ', "ᄉᄌ"), ('ퟰ', "ᄉᄎ"), ('ퟱ', "ᄉᄐ"), ('ퟲ', "ᄅᄒ"), ('ퟳ', "ᅀᄇ"), ('ퟴ', "ᅀᄇᄋ"), ('ퟵ', "ᅌᄆ"), ('ퟶ', "ᅌᄒ"), ('ퟷ', "ᄌᄇ"), ('ퟸ', "ᄌᄇᄇ"), ('ퟹ', "ᄌᄌ"), ('ퟺ', "ᄑᄉ"), ('ퟻ', "ᄑᄐ"), ('豈', "豈"), ('更', "更"), ('車', "車"), ('賈', "賈"), ('滑', "滑"), ('串', "串"), ('句', "句"), ('龜', "龜"), ('龜', "龜"), ('契', "契"), ('金', "金"), ('喇', "喇"), ('奈', "奈"), ('懶', "懶"), ('癩', "癩"), ('羅', "羅"), ('蘿', "蘿"), ('螺', "螺"), ('裸', "裸"), ('邏', "邏"), ('樂', "樂"), ('洛', "洛"), ('烙', "烙"), ('珞', "珞"), ('落', "落"), ('酪', "酪"), ('駱', "駱"), ('亂', "亂"), ('卵', "卵"), ('欄', "欄"), ('爛', "爛"), ('蘭', "蘭"), ('鸞', "鸞"), ('嵐', "嵐"), ('濫', "濫"), ('藍', "藍"), ('襤', "襤"), ('拉', "拉"), ('臘', "臘"), ('蠟', "蠟"), ('廊', "廊"), ('朗', "朗"), ('浪', "浪"), ('狼', "狼"), ('郎', "郎"), ('來', "來"), ('冷', "冷"), ('勞', "勞"), ('擄', "擄"), ('櫓', "櫓"), ('爐', "爐"), ('盧', "盧"), ('老', "老"), ('蘆', "蘆"), ('虜', "虜"), ('路', "路"), ('露', "露"), ('魯', "魯"), ('鷺', "鷺"), ('碌', "碌"), ('祿', "祿"), ('綠', "綠"), ('菉', "菉"), ('錄', "錄"), ('鹿', "鹿"), ('論', "論"), ('壟', "壟"), ('弄', "弄"), ('籠', "籠"), ('聾', "聾"), ('牢', "牢"), ('磊', "磊"), ('賂', "賂"), ('雷', "雷"), ('壘', "壘"), ('屢', "屢"), ('樓', "樓"), ('淚', "淚"), ('漏', "漏"), ('累', "累"), ('縷', "縷"), ('陋', "陋"), ('勒', "勒"), ('肋', "肋"), ('凜', "凜"), ('凌', "凌"), ('稜', "稜"), ('綾', "綾"), ('菱', "菱"), ('陵', "陵"), ('讀', "讀"), ('拏', "拏"), ('樂', "樂"), ('諾', "諾"), ('丹', "丹"), ('寧', "寧"), ('怒', "怒"), ('率', "率"), ('異', "異"), ('北', "北"), ('磻', "磻"), ('便', "便"), ('復', "復"), ('不', "不"), ('泌', "泌"), ('數', "數"), ('索', "索"), ('參', "參"), ('塞', "塞"), ('省', "省"), ('葉', "葉"), ('說', "說"), ('殺', "殺"), ('辰', "辰"), ('沈', "沈"), ('拾', "拾"), ('若', "若"), ('掠', "掠"), ('略', "略"), ('亮', "亮"), ('兩', "兩"), ('凉', "凉"), ('梁', "梁"), ('糧', "糧"), ('良', "良"), ('諒', "諒"), ('量', "量"), ('勵', "勵"), ('呂', "呂"), ('女', "女"), ('廬', "廬"), ('旅', "旅"), ('濾', "濾"), ('礪', "礪"), ('閭', "閭"), ('驪', "驪"), ('麗', "麗"), ('黎', "黎"), ('力', "力"), ('曆', "曆"), ('歷', "歷"), ('轢', "轢"), ('年', "年"), ('憐', "憐"), ('戀', "戀"), ('撚', "撚"), ('漣', "漣"), ('煉', "煉"), ('璉', "璉"), ('秊', "秊"), ('練', "練"), ('聯', "聯"), ('輦', "輦"), ('蓮', "蓮"), ('連', "連"), ('鍊', "鍊"), ('列', "列"), ('劣', "劣"), ('咽', "咽"), ('烈', "烈"), ('裂', "裂"), ('說', "說"), ('廉', "廉"), ('念', "念"), ('捻', "捻"), ('殮', "殮"), ('簾', "簾"), ('獵', "獵"), ('令', "令"), ('囹', "囹"), ('寧', "寧"), ('嶺', "嶺"), ('怜', "怜"), ('玲', "玲"), ('瑩', "瑩"), ('羚', "羚"), ('聆', "聆"), ('鈴', "鈴"), ('零', "零"), ('靈', "靈"), ('領', "領"), ('例', "例"), ('禮', "禮"), ('醴', "醴"), ('隸', "隷"), ('惡', "惡"), ('了', "了"), ('僚', "僚"), ('寮', "寮"), ('尿', "尿"), ('料', "料"), ('樂', "樂"), ('燎', "燎"), ('療', "療"), ('蓼', "蓼"), ('遼', "遼"), ('龍', "龍"), ('暈', "暈"), ('阮', "阮"), ('劉', "劉"), ('杻', "杻"), ('柳', "柳"), ('流', "流"), ('溜', "溜"), ('琉', "琉"), ('留', "留"), ('硫', "硫"), ('紐', "紐"), ('類', "類"), ('六', "六"), ('戮', "戮"), ('陸', "陸"), ('倫', "倫"), ('崙', "崙"), ('淪', "淪"), ('輪', "輪"), ('律', "律"), ('慄', "慄"), ('栗', "栗"), ('率', "率"), ('隆', "隆"), ('利', "利"), ('吏', "吏"), ('履', "履"), ('易', "易"), ('李', "李"), ('梨', "梨"), ('泥', "泥"), ('理', "理"), ('痢', "痢"), ('罹', "罹"), ('裏', "裏"), ('裡', "裡"), ('里', "里"), ('離', "離"), ('匿', "匿"), ('溺', "溺"), ('吝', "吝"), ('燐', "燐"), ('璘', "璘"), ('藺', "藺"), ('隣', "隣"), ('鱗', "鱗"), ('麟', "麟"), ('林', "林"), ('淋', "淋"), ('臨', "臨"), ('立', "立"), ('笠', "笠"), ('粒', "粒"), ('狀', "狀"), ('炙', "炙"), ('識', "識"), ('什', "什"), ('茶', "茶"), ('刺', "刺"), ('切', "切"), ('度', "度"), ('拓', "拓"), ('糖', "糖"), ('宅', "宅"), ('洞', "洞"), ('暴', "暴"), ('輻', "輻"), ('行', "行"), ('降', "降"), ('見', "見"), ('廓', "廓"), ('兀', "兀"), ('嗀', "嗀"), ('塚', "塚"), ('晴', "晴"), ('凞', "凞"), ('猪', "猪"), ('益', "益"), ('礼', "礼"), ('神', "神"), ('祥', "祥"), ('福', "福"), ('靖', "靖"), ('精', "精"), ('羽', "羽"), ('蘒', "蘒"), ('諸', "諸"), ('逸', "逸"), ('都', "都"), ('飯', "飯"), ('飼', "飼"), ('館', "館"), ('鶴', "鶴"), ('郞', "郎"), ('隷', "隷"), ('侮', "侮"), ('僧', "僧"), ('免', "免"), ('勉', "勉"), ('勤', "勤"), ('卑', "卑"), ('喝', "喝"), ('嘆', "嘆"), ('器', "器"), ('塀', "塀"), ('墨', "墨"), ('層', "層"), ('屮', "屮"), ('悔', "悔"), ('慨', "慨"), ('憎', "憎"), ('懲', "懲"), ('敏', "敏"), ('既', "既"), ('暑', "暑"), ('梅', "梅"), ('海', "海"), ('渚', "渚"), ('漢', "漢"), ('煮', "煮"), ('爫', "爫"), ('琢', "琢"), ('碑', "碑"), ('社', "社"), ('祉', "祉"), ('祈', "祈"), ('祐', "祐"), ('祖', "祖"), ('祝', "祝"), ('禍', "禍"), ('禎', "禎"), ('穀', "穀"), ('突', "突"), ('節', "節"), ('練', "練"), ('縉', "縉"), ('繁', "繁"), ('署', "署"), ('者', "者"), ('臭', "臭"), ('艹', "艹"), ('艹', "艹"), ('著', "著"), ('褐', "褐"), ('視', "視"), ('謁', "謁"), ('謹', "謹"), ('賓', "賓"), ('贈', "贈"), ('辶', "辶"), ('逸', "逸"), ('難', "難"), ('響', "響"), ('頻', "頻"), ('恵', "恵"), ('𤋮', "𤋮"), ('舘', "舘"), ('並', "並"), ('况', "况"), ('全', "全"), ('侀', "侀"), ('充', "充"), ('冀', "冀"), ('勇', "勇"), ('勺', "勺"), ('喝', "喝"), ('啕', "啕"), ('喙', "喙"), ('嗢', "嗢"), ('塚', "塚"), ('墳', "墳"), ('奄', "奄"), ('奔', "奔"), ('婢', "婢"), ('嬨', "嬨"), ('廒', "廒"), ('廙', "廙"), ('彩', "彩"), ('徭', "徭"), ('惘', "惘"), ('慎', "慎"), ('愈', "愈"), ('憎', "憎"), ('慠', "慠"), ('懲', "懲"), ('戴', "戴"), ('揄', "揄"), ('搜', "搜"), ('摒', "摒"), ('敖', "敖"), ('晴', "晴"), ('朗', "朗"), ('望', "望"), ('杖', "杖"), ('歹', "歹"), ('殺', "殺"), ('流', "流"), ('滛', "滛"), ('滋', "滋"), ('漢', "漢"), ('瀞', "瀞"), ('煮', "煮"), ('瞧', "瞧"), ('爵', "爵"), ('犯', "犯"), ('猪', "猪"), ('瑱', "瑱"), ('甆', "甆"), ('画', "画"), ('瘝', "瘝"), ('瘟', "瘟"), ('益', "益"), ('盛', "盛"), ('直', "直"), ('睊', "睊"), ('着', "着"), ('磌', "磌"), ('窱', "窱"), ('節', "節"), ('类', "类"), ('絛', "絛"), ('練', "練"), ('缾', "缾"), ('者', "者"), ('荒', "荒"), ('華', "華"), ('蝹', "蝹"), ('襁', "襁"), ('覆', "覆"), ('視', "視"), ('調', "調"), ('諸', "諸"), ('請', "請"), ('謁', "謁"), ('諾', "諾"), ('諭', "諭"), ('謹', "謹"), ('變', "變"), ('贈', "贈"), ('輸', "輸"), ('遲', "遲"), ('醙', "醙"), ('鉶', "鉶"), ('陼', "陼"), ('難', "難"), ('靖', "靖"), ('韛', "韛"), ('響', "響"), ('頋', "頋"), ('頻', "頻"), ('鬒', "鬒"), ('龜', "龜"), ('𢡊', "𢡊"), ('𢡄', "𢡄"), ('𣏕', "𣏕"), ('㮝', "㮝"), ('䀘', "䀘"), ('䀹', "䀹"), ('𥉉', "𥉉"), ('𥳐', "𥳐"), ('𧻓', "𧻓"), ('齃', "齃"), ('龎', "龎"), ('ﬀ', "ff"), ('ﬁ', "fi"), ('ﬂ', "fl"), ('ﬃ', "ffi"), ('ﬄ', "ffl"), ('ﬆ', "st"), ('ﬓ', "մն"), ('ﬔ', "մե"), ('ﬕ', "մի"), ('ﬖ', "վն"), ('ﬗ', "մխ"), ('ﬠ', "ע"), ('ﬡ', "א"), ('ﬢ', "ד"), ('ﬣ', "ה"), ('ﬤ', "כ"), ('ﬥ', "ל"), ('ﬦ', "ם"), ('ﬧ', "ר"), ('ﬨ', "ת"), ('﬩', "-\u{307}"), ('שׂ', "ש\u{5c1}"), ('שּׂ', "ש\u{5bc}\u{5c1}"), ('אָ', "א\u{5b7}"), ('אּ', "א\u{5b7}"), ('יּ', "י\u{5b4}"), ('שּ', "ש\u{5c1}"), ('ﭏ', "אל"), ('ﭐ', "ٱ"), ('ﭑ', "ٱ"), ('ﭒ', "ٻ"), ('ﭓ', "ٻ"), ('ﭔ', "ٻ"), ('ﭕ', "ٻ"), ('ﭖ', "ى\u{6db}"), ('ﭗ', "ى\u{6db}"), ('ﭘ', "ى\u{6db}"), ('ﭙ', "ى\u{6db}"), ('ﭚ', "ڀ"), ('ﭛ', "ڀ"), ('ﭜ', "ڀ"), ('ﭝ', "ڀ"), ('ﭞ', "ٺ"), ('ﭟ', "ٺ"), ('ﭠ', "ٺ"), ('ﭡ', "ٺ"), ('ﭢ', "ٿ"), ('ﭣ', "ٿ"), ('ﭤ', "ٿ"), ('ﭥ', "ٿ"), ('ﭦ', "ى\u{615}"), ('ﭧ', "ى\u{615}"), ('ﭨ', "ى\u{615}"), ('ﭩ', "ى\u{615}"), ('ﭪ', "ڡ\u{6db}"), ('ﭫ', "ڡ\u{6db}"), ('ﭬ', "ڡ\u{6db}"), ('ﭭ', "ڡ\u{6db}"), ('ﭮ', "ڦ"), ('ﭯ', "ڦ"), ('ﭰ', "ڦ"), ('ﭱ', "ڦ"), ('ﭲ', "ڄ"), ('ﭳ', "ڄ"), ('ﭴ', "ڄ"), ('ﭵ', "ڄ"), ('ﭶ', "ڃ"), ('ﭷ', "ڃ"), ('ﭸ', "ڃ"), ('ﭹ', "ڃ"), ('ﭺ', "چ"), ('ﭻ', "چ"), ('ﭼ', "چ"), ('ﭽ', "چ"), ('ﭾ', "ڇ"), ('ﭿ', "ڇ"), ('ﮀ', "ڇ"), ('ﮁ', "ڇ"), ('ﮂ', "ڍ"), ('ﮃ', "ڍ"), ('ﮄ', "ڌ"), ('ﮅ', "ڌ"), ('ﮆ', "د\u{6db}"), ('ﮇ', "د\u{6db}"), ('ﮈ', "د\u{615}"), ('ﮉ', "د\u{615}"), ('ﮊ', "ر\u{6db}"), ('ﮋ', "ر\u{6db}"), ('ﮌ', "ر\u{615}"), ('ﮍ', "ر\u{615}"), ('ﮎ', "ك"), ('ﮏ', "ك"), ('ﮐ', "ك"), ('ﮑ', "ك"), ('ﮒ', "گ"), ('ﮓ', "گ"), ('ﮔ', "گ"), ('ﮕ', "گ"), ('ﮖ', "ڳ"), ('ﮗ', "ڳ"), ('ﮘ', "ڳ"), ('ﮙ', "ڳ"), ('ﮚ', "ڱ"), ('ﮛ', "ڱ"), ('ﮜ', "ڱ"), ('ﮝ', "ڱ"), ('ﮞ', "ى"), ('ﮟ', "ى"), ('ﮠ', "ى\u{615}"), ('ﮡ', "ى\u{615}"), ('ﮢ', "ى\u{615}"), ('ﮣ', "ى\u{615}"), ('ﮤ', "ە\u{654}"), ('ﮥ', "ە\u{654}"), ('ﮦ', "o"), ('ﮧ', "o"), ('ﮨ', "o"), ('ﮩ', "o"), ('ﮪ', "o"), ('ﮫ', "o"), ('ﮬ', "o"), ('ﮭ', "o"), ('ﮮ', "ى"), ('ﮯ', "ى"), ('ﮰ', "ے\u{654}"), ('ﮱ', "ے\u{654}"), ('ﯓ', "ك\u{6db}"), ('ﯔ', "ك\u{6db}"), ('ﯕ', "ك\u{6db}"), ('ﯖ', "ك\u{6db}"), ('ﯗ', "و\u{313}"), ('ﯘ', "و\u{313}"), ('ﯙ', "و\u{306}"), ('ﯚ', "و\u{306}"), ('ﯛ', "و\u{670}"), ('ﯜ', "و\u{670}"), ('ﯝ', "و\u{313}ٴ"), ('ﯞ', "و\u{6db}"), ('ﯟ', "و\u{6db}"), ('ﯠ', "ۅ"), ('ﯡ', "ۅ"), ('ﯢ', "و\u{302}"), ('ﯣ', "و\u{302}"), ('ﯤ', "ٻ"), ('ﯥ', "ٻ"), ('ﯦ', "ٻ"), ('ﯧ', "ٻ"), ('ﯨ', "ى"), ('ﯩ', "ى"), ('ﯪ', "ىٴl"), ('ﯫ', "ىٴl"), ('ﯬ', "ىٴo"), ('ﯭ', "ىٴo"), ('ﯮ', "ىٴو"), ('ﯯ', "ىٴو"), ('ﯰ', "ىٴو\u{313}"), ('ﯱ', "ىٴو\u{313}"), ('ﯲ', "ىٴو\u{306}"), ('ﯳ', "ىٴو\u{306}"), ('ﯴ', "ىٴو\u{670}"), ('ﯵ', "ىٴو\u{670}"), ('ﯶ', "ىٴٻ"), ('ﯷ', "ىٴٻ"), ('ﯸ', "ىٴٻ"), ('ﯹ', "ىٴى"), ('ﯺ', "ىٴى"), ('ﯻ', "ىٴى"), ('ﯼ', "ى"), ('ﯽ', "ى"), ('ﯾ', "ى"), ('ﯿ', "ى"), ('ﰀ', "ىٴج"), ('ﰁ', "ىٴح"), ('ﰂ', "ىٴم"), ('ﰃ', "ىٴى"), ('ﰄ', "ىٴى"), ('ﰅ', "بج"), ('ﰆ', "بح"), ('ﰇ', "بخ"), ('ﰈ', "بم"), ('ﰉ', "بى"), ('ﰊ', "بى"), ('ﰋ', "تج"), ('ﰌ', "تح"), ('ﰍ', "تخ"), ('ﰎ', "تم"), ('ﰏ', "تى"), ('ﰐ', "تى"), ('ﰑ', "ى\u{6db}ج"), ('ﰒ', "ى\u{6db}م"), ('ﰓ', "ى\u{6db}ى"), ('ﰔ', "ى\u{6db}ى"), ('ﰕ', "جح"), ('ﰖ', "جم"), ('ﰗ', "حج"), ('ﰘ', "حم"), ('ﰙ', "خج"), ('ﰚ', "خح"), ('ﰛ', "خم"), ('ﰜ', "سج"), ('ﰝ', "سح"), ('ﰞ', "سخ"), ('ﰟ', "سم"), ('ﰠ', "صح"), ('ﰡ', "صم"), ('ﰢ', "ضج"), ('ﰣ', "ضح"), ('ﰤ', "ضخ"), ('ﰥ', "ضم"), ('ﰦ', "طح"), ('ﰧ', "طم"), ('ﰨ', "ظم"), ('ﰩ', "عج"), ('ﰪ', "عم"), ('ﰫ', "غج"), ('ﰬ', "غم"), ('ﰭ', "فج"), ('ﰮ', "فح"), ('ﰯ', "فخ"), ('ﰰ', "فم"), ('ﰱ', "فى"), ('ﰲ', "فى"), ('ﰳ', "قح"), ('ﰴ', "قم"), ('ﰵ', "قى"), ('ﰶ', "قى"), ('ﰷ', "كl"), ('ﰸ', "كج"), ('ﰹ', "كح"), ('ﰺ', "كخ"), ('ﰻ', "كل"), ('ﰼ', "كم"), ('ﰽ', "كى"), ('ﰾ', "كى"), ('ﰿ', "لج"), ('ﱀ', "لح"), ('ﱁ', "لخ"), ('ﱂ', "لم"), ('ﱃ', "لى"), ('ﱄ', "لى"), ('ﱅ', "مج"), ('ﱆ', "مح"), ('ﱇ', "مخ"), ('ﱈ', "مم"), ('ﱉ', "مى"), ('ﱊ', "مى"), ('ﱋ', "بخ"), ('ﱌ', "نح"), ('ﱍ', "نخ"), ('ﱎ', "نم"), ('ﱏ', "نى"), ('ﱐ', "نى"), ('ﱑ', "oج"), ('ﱒ', "oم"), ('ﱓ', "oى"), ('ﱔ', "oى"), ('ﱕ', "ىج"), ('ﱖ', "ىح"), ('ﱗ', "ىخ"), ('ﱘ', "ىم"), ('ﱙ', "ىى"), ('ﱚ', "ىى"), ('ﱛ', "ذ\u{670}"), ('ﱜ', "ر\u{670}"), ('ﱝ', "ى\u{670}"), ('ﱞ', "ﹲ\u{651}"), ('ﱟ', "ﹴ\u{651}"), ('ﱠ', "ﹶ\u{651}"), ('ﱡ', "ﹸ\u{651}"), ('ﱢ', "ﹺ\u{651}"), ('ﱣ', "ﹼ\u{670}"), ('ﱤ', "ىٴر"), ('ﱥ', "ىٴز"), ('ﱦ', "ىٴم"), ('ﱧ', "ىٴن"), ('ﱨ', "ىٴى"), ('ﱩ', "ىٴى"), ('ﱪ', "بر"), ('ﱫ', "بز"), ('ﱬ', "بم"), ('ﱭ', "بن"), ('ﱮ', "بى"), ('ﱯ', "بى"), ('ﱰ', "تر"), ('ﱱ', "تز"), ('ﱲ', "تم"), ('ﱳ', "تن"), ('ﱴ', "تى"), ('ﱵ', "تى"), ('ﱶ', "ى\u{6db}ر"), ('ﱷ', "ى\u{6db}ز"), ('ﱸ', "ى\u{6db}م"), ('ﱹ', "ى\u{6db}ن"), ('ﱺ', "ى\u{6db}ى"), ('ﱻ', "ى\u{6db}ى"), ('ﱼ', "فى"), ('ﱽ', "فى"), ('ﱾ', "قى"), ('ﱿ', "قى"), ('ﲀ', "كl"), ('ﲁ', "كل"), ('ﲂ', "كم"), ('ﲃ', "كى"), ('ﲄ', "كى"), ('ﲅ', "لم"), ('ﲆ', "لى"), ('ﲇ', "لى"), ('ﲈ', "مl"), ('ﲉ', "مم"), ('ﲊ', "نر"), ('ﲋ', "نز"), ('ﲌ', "نم"), ('ﲍ', "نن"), ('ﲎ', "نى"), ('ﲏ', "نى"), ('ﲐ', "ى\u{670}"), ('ﲑ', "ىر"), ('ﲒ', "ىز"), ('ﲓ', "ىم"), ('ﲔ', "ىن"), ('ﲕ', "ىى"), ('ﲖ', "ىى"), ('ﲗ', "ىٴج"), ('ﲘ', "ىٴح"), ('ﲙ', "ىٴخ"), ('ﲚ', "ىٴم"), ('ﲛ', "ىٴo"), ('ﲜ', "بج"), ('ﲝ', "بح"), ('ﲞ', "بخ"), ('ﲟ', "بم"), ('ﲠ', "بo"), ('ﲡ', "تج"), ('ﲢ', "تح"), ('ﲣ', "تخ"), ('ﲤ', "تم"), ('ﲥ', "تo"), ('ﲦ', "ى\u{6db}م"), ('ﲧ', "جح"), ('ﲨ', "جم"), ('ﲩ', "حج"), ('ﲪ', "حم"), ('ﲫ', "خج"), ('ﲬ', "خم"), ('ﲭ', "سج"), ('ﲮ', "سح"), ('ﲯ', "سخ"), ('ﲰ', "سم"), ('ﲱ', "صح"), ('ﲲ', "صخ"), ('ﲳ', "صم"), ('ﲴ', "ضج"), ('ﲵ', "ضح"), ('ﲶ', "ضخ"), ('ﲷ', "ضم"), ('ﲸ', "طح"), ('ﲹ', "ظم"), ('ﲺ', "عج"), ('ﲻ', "عم"), ('ﲼ', "غج"), ('ﲽ', "غم"), ('ﲾ', "فج"), ('ﲿ', "فح"), ('ﳀ', "فخ"), ('ﳁ', "فم"), ('ﳂ', "قح"), ('ﳃ', "قم"), ('ﳄ', "كج"), ('ﳅ', "كح"), ('ﳆ', "كخ"), ('ﳇ', "كل"), ('ﳈ', "كم"), ('ﳉ', "لج"), ('ﳊ', "لح"), ('ﳋ', "لخ"), ('ﳌ', "لم"), ('ﳍ', "لo"), ('ﳎ', "مج"), ('ﳏ', "مح"), ('ﳐ', "مخ"), ('ﳑ', "مم"), ('ﳒ', "بخ"), ('ﳓ', "نح"), ('ﳔ', "نخ"), ('ﳕ', "نم"), ('ﳖ', "نo"), ('ﳗ', "oج"), ('ﳘ', "oم"), ('ﳙ', "o\u{670}"), ('ﳚ', "ىج"), ('ﳛ', "ىح"), ('ﳜ', "ىخ"), ('ﳝ', "ىم"), ('ﳞ', "ىo"), ('ﳟ', "ىٴم"), ('ﳠ', "ىٴo"), ('ﳡ', "بم"), ('ﳢ', "بo"), ('ﳣ', "تم"), ('ﳤ', "تo"), ('ﳥ', "ى\u{6db}م"), ('ﳦ', "ى\u{6db}o"), ('ﳧ', "سم"), ('ﳨ', "سo"), ('ﳩ', "س\u{6db}م"), ('ﳪ', "س\u{6db}o"), ('ﳫ', "كل"), ('ﳬ', "كم"), ('ﳭ', "لم"), ('ﳮ', "نم"), ('ﳯ', "نo"), ('ﳰ', "ىم"), ('ﳱ', "ىo"), ('ﳲ', "ﹷ\u{651}"), ('ﳳ', "ﹹ\u{651}"), ('ﳴ', "ﹻ\u{651}"), ('ﳵ', "طى"), ('ﳶ', "طى"), ('ﳷ', "عى"), ('ﳸ', "عى"), ('ﳹ', "غى"), ('ﳺ', "غى"), ('ﳻ', "سى"), ('ﳼ', "سى"), ('ﳽ', "س\u{6db}ى"), ('ﳾ', "س\u{6db}ى"), ('ﳿ', "حى"), ('ﴀ', "حى"), ('ﴁ', "جى"), ('ﴂ', "جى"), ('ﴃ', "خى"), ('ﴄ', "خى"), ('ﴅ', "صى"), ('ﴆ', "صى"), ('ﴇ', "ضى"), ('ﴈ', "ضى"), ('ﴉ', "س\u{6db}ج"), ('ﴊ', "س\u{6db}ح"), ('ﴋ', "س\u{6db}خ"), ('ﴌ', "س\u{6db}م"), ('ﴍ', "س\u{6db}ر"), ('ﴎ', "سر"), ('ﴏ', "صر"), ('ﴐ', "ضر"), ('ﴑ', "طى"), ('ﴒ', "طى"), ('ﴓ', "عى"), ('ﴔ', "عى"), ('ﴕ', "غى"), ('ﴖ', "غى"), ('ﴗ', "سى"), ('ﴘ', "سى"), ('ﴙ', "س\u{6db}ى"), ('ﴚ', "س\u{6db}ى"), ('ﴛ', "حى"), ('ﴜ', "حى"), ('ﴝ', "جى"), ('ﴞ', "جى"), ('ﴟ', "خى"), ('ﴠ', "خى"), ('ﴡ', "صى"), ('ﴢ', "صى"), ('ﴣ', "ضى"), ('ﴤ', "ضى"), ('ﴥ', "س\u{6db}ج"), ('ﴦ', "س\u{6db}ح"), ('ﴧ', "س\u{6db}خ"), ('ﴨ', "س\u{6db}م"), ('ﴩ', "س\u{6db}ر"), ('ﴪ', "سر"), ('ﴫ', "صر"), ('ﴬ', "ضر"), ('ﴭ', "س\u{6db}ج"), ('ﴮ', "س\u{6db}ح"), ('ﴯ', "س\u{6db}خ"), ('ﴰ', "س\u{6db}م"), ('ﴱ', "سo"), ('ﴲ', "س\u{6db}o"), ('ﴳ', "طم"), ('ﴴ', "سج"), ('ﴵ', "سح"), ('ﴶ', "سخ"), ('ﴷ', "س\u{6db}ج"), ('ﴸ', "س\u{6db}ح"), ('ﴹ', "س\u{6db}خ"), ('ﴺ', "طم"), ('ﴻ', "ظم"), ('ﴼ', "l\u{30b}"), ('ﴽ', "l\u{30b}"), ('﴾', "("), ('﴿', ")"), ('ﵐ', "تجم"), ('ﵑ', "تحج"), ('ﵒ', "تحج"), ('ﵓ', "تحم"), ('ﵔ', "تخم"), ('ﵕ', "تمج"), ('ﵖ', "تمح"), ('ﵗ', "تمخ"), ('ﵘ', "جمح"), ('ﵙ', "جمح"), ('ﵚ', "حمى"), ('ﵛ', "حمى"), ('ﵜ', "سحج"), ('ﵝ', "سجح"), ('ﵞ', "سجى"), ('ﵟ', "سمح"), ('ﵠ', "سمح"), ('ﵡ', "سمج"), ('ﵢ', "سمم"), ('ﵣ', "سمم"), ('ﵤ', "صحح"), ('ﵥ', "صحح"), ('ﵦ', "صمم"), ('ﵧ', "س\u{6db}حم"), ('ﵨ', "س\u{6db}حم"), ('ﵩ', "س\u{6db}جى"), ('ﵪ', "س\u{6db}مخ"), ('ﵫ', "س\u{6db}مخ"), ('ﵬ', "س\u{6db}مم"), ('ﵭ', "س\u{6db}مم"), ('ﵮ', "ضحى"), ('ﵯ', "ضخم"), ('ﵰ', "ضخم"), ('ﵱ', "طمح"), ('ﵲ', "طمح"), ('ﵳ', "طمم"), ('ﵴ', "طمى"), ('ﵵ', "عجم"), ('ﵶ', "عمم"), ('ﵷ', "عمم"), ('ﵸ', "عمى"), ('ﵹ', "غمم"), ('ﵺ', "غمى"), ('ﵻ', "غمى"), ('ﵼ', "فخم"), ('ﵽ', "فخم"), ('ﵾ', "قمح"), ('ﵿ', "قمم"), ('ﶀ', "لحم"), ('ﶁ', "لحى"), ('ﶂ', "لحى"), ('ﶃ', "لجج"), ('ﶄ', "لجج"), ('ﶅ', "لخم"), ('ﶆ', "لخم"), ('ﶇ', "لمح"), ('ﶈ', "لمح"), ('ﶉ', "محج"), ('ﶊ', "محم"), ('ﶋ', "محى"), ('ﶌ', "مجح"), ('ﶍ', "مجم"), ('ﶎ', "مخج"), ('ﶏ', "مخم"), ('ﶒ', "مجخ"), ('ﶓ', "oمج"), ('ﶔ', "oمم"), ('ﶕ', "نحم"), ('ﶖ', "نحى"), ('ﶗ', "نجم"), ('ﶘ', "نجم"), ('ﶙ', "نجى"), ('ﶚ', "نمى"), ('ﶛ', "نمى"), ('ﶜ', "ىمم"), ('ﶝ', "ىمم"), ('ﶞ', "بخى"), ('ﶟ', "تجى"), ('ﶠ', "تجى"), ('ﶡ', "تخى"), ('ﶢ', "تخى"), ('ﶣ', "تمى"), ('ﶤ', "تمى"), ('ﶥ', "جمى"), ('ﶦ', "جحى"), ('ﶧ', "جمى"), ('ﶨ', "سخى"), ('ﶩ', "صحى"), ('ﶪ', "س\u{6db}حى"), ('ﶫ', "ضحى"), ('ﶬ', "لجى"), ('ﶭ', "لمى"), ('ﶮ', "ىحى"), ('ﶯ', "ىجى"), ('ﶰ', "ىمى"), ('ﶱ', "ممى"), ('ﶲ', "قمى"), ('ﶳ', "نحى"), ('ﶴ', "قمح"), ('ﶵ', "لحم"), ('ﶶ', "عمى"), ('ﶷ', "كمى"), ('ﶸ', "نجح"), ('ﶹ', "مخى"), ('ﶺ', "لجم"), ('ﶻ', "كمم"), ('ﶼ', "لجم"), ('ﶽ', "نجح"), ('ﶾ', "جحى"), ('ﶿ', "حجى"), ('ﷀ', "مجى"), ('ﷁ', "فمى"), ('ﷂ', "بحى"), ('ﷃ', "كمم"), ('ﷄ', "عجم"), ('ﷅ', "صمم"), ('ﷆ', "سخى"), ('ﷇ', "نجى"), ('ﷰ', "صلى"), ('ﷱ', "قلى"), ('ﷲ', "lلل\u{651}\u{670}o"), ('ﷳ', "lكبر"), ('ﷴ', "محمد"), ('ﷵ', "صلعم"), ('ﷶ', "رسول"), ('ﷷ', "علىo"), ('ﷸ', "وسلم"), ('ﷹ', "صلى"), ('ﷺ', "صلى lللo علىo وسلم"), ('ﷻ', "جل جلlلo"), ('﷼', "رىlل"), ('︙', "ⵗ"), ('︰', ":"), ('︱', "│"), ('︴', "⌇"), ('︵', "⏜"), ('︶', "⏝"), ('︷', "⏞"), ('︸', "⏟"), ('︹', "⏠"), ('︺', "⏡"), ('﹉', "ˉ"), ('﹊', "ˉ"), ('﹋', "ˉ"), ('﹌', "ˉ"), ('﹍', "_"), ('﹎', "_"), ('﹏', "_"), ('﹘', "-"), ('﹨', "\\"), ('ﺀ', "ء"), ('ﺁ', "ا\u{653}"), ('ﺂ', "ا\u{653}"), ('ﺃ', "lٴ"), ('ﺄ', "lٴ"), ('ﺅ', "وٴ"), ('ﺆ', "وٴ"), ('ﺇ', "l\u{655}"), ('ﺈ', "l\u{655}"), ('ﺉ', "ىٴ"), ('ﺊ', "ىٴ"), ('ﺋ', "ىٴ"), ('ﺌ', "ىٴ"), ('ﺍ', "l"), ('ﺎ', "l"), ('ﺏ', "ب"), ('ﺐ', "ب"), ('ﺑ', "ب"), ('ﺒ', "ب"), ('ﺓ', "ة"), ('ﺔ', "ة"), ('ﺕ', "ت"), ('ﺖ', "ت"), ('ﺗ', "ت"), ('ﺘ', "ت"), ('ﺙ', "ى\u{6db}"), ('ﺚ', "ى\u{6db}"), ('ﺛ', "ى\u{6db}"), ('ﺜ', "ى\u{6db}"), ('ﺝ', "ج"), ('ﺞ', "ج"), ('ﺟ', "ج"), ('ﺠ', "ج"), ('ﺡ', "ح"), ('ﺢ', "ح"), ('ﺣ', "ح"), ('ﺤ', "ح"), ('ﺥ', "خ"), ('ﺦ', "خ"), ('ﺧ', "خ"), ('ﺨ', "خ"), ('ﺩ', "د"), ('ﺪ', "د"), ('ﺫ', "ذ"), ('ﺬ', "ذ"), ('ﺭ', "ر"), ('ﺮ', "ر"), ('ﺯ', "ز"), ('ﺰ', "ز"), ('ﺱ', "س"), ('ﺲ', "س"), ('ﺳ', "س"), ('ﺴ', "س"), ('ﺵ', "س\u{6db}"), ('ﺶ', "س\u{6db}"), ('ﺷ', "س\u{6db}"), ('ﺸ', "س\u{6db}"), ('ﺹ', "ص"), ('ﺺ', "ص"), ('ﺻ', "ص"), ('ﺼ', "ص"), ('ﺽ', "ض"), ('ﺾ', "ض"), ('ﺿ', "ض"), ('ﻀ', "ض"), ('ﻁ', "ط"), ('ﻂ', "ط"), ('ﻃ', "ط"), ('ﻄ', "ط"), ('ﻅ', "ظ"), ('ﻆ', "ظ"), ('ﻇ', "ظ"), ('ﻈ', "ظ"), ('ﻉ', "ع"), ('ﻊ', "ع"), ('ﻋ', "ع"), ('ﻌ', "ع"), ('ﻍ', "غ"), ('ﻎ', "غ"), ('ﻏ', "غ"), ('ﻐ', "غ"), ('ﻑ', "ف"), ('ﻒ', "ف"), ('ﻓ', "ف"), ('ﻔ', "ف"), ('ﻕ', "ق"), ('ﻖ', "ق"), ('ﻗ', "ق"), ('ﻘ', "ق"), ('ﻙ', "ك"), ('ﻚ', "ك"), ('ﻛ', "ك"), ('ﻜ', "ك"), ('ﻝ', "ل"), ('ﻞ', "ل"), ('ﻟ', "ل"), ('ﻠ', "ل"), ('ﻡ', "م"), ('ﻢ', "م"), ('ﻣ', "م"), ('ﻤ', "م"), ('ﻥ', "ن"), ('ﻦ', "ن"), ('ﻧ', "ن"), ('ﻨ', "ن"), ('ﻩ', "o"), ('ﻪ', "o"), ('ﻫ', "o"), ('ﻬ', "o"), ('ﻭ', "و"), ('ﻮ', "و"), ('ﻯ', "ى"), ('ﻰ', "ى"), ('ﻱ', "ى"), ('ﻲ', "ى"), ('ﻳ', "ى"), ('ﻴ', "ى"), ('ﻵ', "لا\u{653}"), ('ﻶ', "لا\u{653}"), ('ﻷ', "لlٴ"), ('ﻸ', "لlٴ"), ('ﻹ', "لl\u{655}"), ('ﻺ', "لl\u{655}"), ('ﻻ', "لl"), ('ﻼ', "لl"), ('！', "!"), ('＂', "''"), ('＇', "'"), ('－', "ー"), ('：', ":"), ('Ａ', "A"), ('Ｂ', "B"), ('Ｃ', "C"), ('Ｅ', "E"), ('Ｈ', "H"), ('Ｉ', "l"), ('Ｊ', "J"), ('Ｋ', "K"), ('Ｍ', "M"), ('Ｎ', "N"), ('Ｏ', "O"), ('Ｐ', "P"), ('Ｓ', "S"), ('Ｔ', "T"), ('Ｘ', "X"), ('Ｙ', "Y"), ('Ｚ', "Z"), ('［', "("), ('＼', "\\"), ('］', ")"), ('＾', "︿"), ('｀', "'"), ('ａ', "a"), ('ｃ', "c"), ('ｅ', "e"), ('ｇ', "g"), ('ｈ', "h"), ('ｉ', "i"), ('ｊ', "j"), ('ｌ', "l"), ('ｏ', "o"), ('ｐ', "p"), ('ｓ', "s"), ('ｖ', "v"), ('ｘ', "x"), ('ｙ', "y"), ('｜', "│"), ('～', "〜"), ('･', "·"), ('￣', "ˉ"), ('￨', "l"), ('￭', "▪"), ('𐄁', "·"), ('𐆎', "N\u{30a}"), ('𐆖', "X\u{335}"), ('𐆗', "V\u{335}"), ('𐆘', "l\u{335}l\u{335}S\u{335}"), ('𐆙', "l\u{335}l\u{335}"), ('𐆠', "⳨"), ('𐊂', "B"), ('𐊅', "Δ"), ('𐊆', "E"), ('𐊇', "F"), ('𐊊', "l"), ('𐊍', "Ʌ"), ('𐊐', "X"), ('𐊒', "O"), ('𐊔', "ᛜ"), ('𐊕', "P"), ('𐊖', "S"), ('𐊗', "T"), ('𐊛', "+"), ('𐊠', "A"), ('𐊡', "B"), ('𐊢', "C"), ('𐊣', "Δ"), ('𐊥', "F"), ('𐊫', "O"), ('𐊭', "Ϙ"), ('𐊰', "M"), ('𐊱', "T"), ('𐊲', "Y"), ('𐊳', "Φ"), ('𐊴', "X"), ('𐊵', "Ψ"), ('𐊶', "Ω"), ('𐊸', "ⵀ"), ('𐋏', "H"), ('𐋡', "د"), ('𐋤', "و"), ('𐋨', "ط"), ('𐋲', "ص"), ('𐋵', "Z"), ('𐌁', "B"), ('𐌂', "C"), ('𐌉', "l"), ('𐌑', "M"), ('𐌒', "Ϙ"), ('𐌕', "T"), ('𐌗', "X"), ('𐌚', "8"), ('𐌟', "*"), ('𐌠', "l"), ('𐌢', "X"), ('𐏑', "𐎂"), ('𐏓', "𐎓"), ('𐐁', "Ɛ"), ('𐐄', "O"), ('𐐑', "ꓶ"), ('𐐕', "C"), ('𐐛', "L"), ('𐐟', "Ɒ"), ('𐐠', "S"), ('𐐣', "Ɔ"), ('𐐥', "И"), ('𐐩', "ꞓ"), ('𐐪', "ʚ"), ('𐐬', "o"), ('𐐽', "c"), ('𐐿', "ɷ"), ('𐑂', "ɞ"), ('𐑃', "ʟ"), ('𐑈', "s"), ('𐑋', "ɔ"), ('𐑍', "ᴎ"), ('𐒠', "𐒆"), ('𐒰', "Ʌ"), ('𐒴', "R"), ('𐒼', "Ӄ"), ('𐓂', "O"), ('𐓃', "ʘ"), ('𐓄', "Þ"), ('𐓍', "Ћ"), ('𐓎', "U"), ('𐓐', "ᛦ"), ('𐓑', "Ψ"), ('𐓒', "7"), ('𐓘', "ʌ"), ('𐓛', "λ"), ('𐓪', "o"), ('𐓫', "ꙩ"), ('𐓶', "u"), ('𐓹', "ψ"), ('𐔓', "N"), ('𐔖', "O"), ('𐔘', "K"), ('𐔜', "C"), ('𐔝', "V"), ('𐔥', "F"), ('𐔦', "L"), ('𐔧', "X"), ('\u{10a3a}', "\u{323}"), ('𐩐', "."), ('𐩗', "𐩖𐩖"), ('𐳺', "𐲥"), ('𐳼', "𐲂"), ('𑂻', "॰"), ('𑇇', "॰"), ('\u{111ca}', "\u{323}"), ('\u{111cb}', "\u{93a}"), ('𑇛', "꣼"), ('𑇜', "ꣻ"), ('𑇞', "≈"), ('\u{11300}', "\u{30a}"), ('𑐓', "𑐴\u{11442}𑐒"), ('𑐙', "𑐴\u{11442}𑐘"), ('𑐤', "𑐴\u{11442}𑐣"), ('𑐪', "𑐴\u{11442}𑐩"), ('𑐭', "𑐴\u{11442}𑐬"), ('𑐯', "𑐴\u{11442}𑐮"), ('𑑌', "𑑋𑑋"), ('𑒒', "ঘ"), ('𑒔', "চ"), ('𑒖', "জ"), ('𑒘', "ঞ"), ('𑒙', "ট"), ('𑒛', "ড"), ('𑒝', "ল"), ('𑒞', "ত"), ('𑒟', "থ"), ('𑒠', "দ"), ('𑒡', "ধ"), ('𑒢', "ন"), ('𑒣', "প"), ('𑒧', "ম"), ('𑒨', "য"), ('𑒩', "ব"), ('𑒪', "ণ"), ('𑒫', "র"), ('𑒭', "ষ"), ('𑒮', "স"), ('\u{114b0}', "\u{9be}"), ('𑒱', "ি"), ('𑒹', "ে"), ('𑒼', "ে\u{9be}"), ('\u{114bd}', "\u{9d7}"), ('𑒾', "ে\u{9d7}"), ('\u{114bf}', "\u{306}\u{307}"), ('𑓁', "ঃ"), ('\u{114c2}', "\u{9cd}"), ('\u{114c3}', "\u{323}"), ('𑓄', "ঽ"), ('𑓅', "w\u{307}"), ('𑓐', "O"), ('𑓑', "১"), ('𑓒', "২"), ('𑓖', "৬"), ('𑗘', "𑖂"), ('𑗙', "𑖂"), ('𑗚', "𑖃"), ('𑗛', "𑖄"), ('\u{115dc}', "\u{115b2}"), ('\u{115dd}', "\u{115b3}"), ('𑙂', "𑙁𑙁"), ('𑜀', "rn"), ('𑜆', "v"), ('𑜊', "w"), ('𑜎', "w"), ('𑜏', "w"), ('𑢠', "V"), ('𑢢', "F"), ('𑢣', "L"), ('𑢤', "Y"), ('𑢦', "E"), ('𑢨', "∇"), ('𑢩', "Z"), ('𑢬', "9"), ('𑢮', "E"), ('𑢯', "4"), ('𑢲', "L"), ('𑢵', "O"), ('𑢷', "ᛜ"), ('𑢸', "U"), ('𑢻', "5"), ('𑢼', "T"), ('𑣀', "v"), ('𑣁', "s"), ('𑣂', "F"), ('𑣃', "i"), ('𑣄', "z"), ('𑣆', "7"), ('𑣈', "o"), ('𑣊', "3"), ('𑣌', "9"), ('𑣎', "ꞓ"), ('𑣕', "6"), ('𑣖', "9"), ('𑣗', "o"), ('𑣘', "u"), ('𑣜', "y"), ('𑣠', "O"), ('𑣣', "rn"), ('𑣤', "٩"), ('𑣥', "Z"), ('𑣦', "W"), ('𑣩', "C"), ('𑣬', "X"), ('𑣯', "W"), ('𑣲', "C"), ('𑫦', "𑫥𑫯"), ('𑫧', "𑫥𑫰"), ('𑫨', "𑫥𑫥"), ('𑫩', "𑫥𑫥𑫯"), ('𑫪', "𑫥𑫥𑫰"), ('𑫬', "𑫫𑫯"), ('𑫭', "𑫫𑫫"), ('𑫮', "𑫫𑫫𑫯"), ('𑫴', "𑫳𑫯"), ('𑫵', "𑫳𑫰"), ('𑫶', "𑫳𑫳"), ('𑫷', "𑫳𑫳𑫯"), ('𑫸', "𑫳𑫳𑫰"), ('𑱂', "𑱁𑱁"), ('\u{11cb2}', "\u{11caa}"), ('𒀸', "𐎚"), ('𓋹', "𐦞"), ('𖼇', "Γ"), ('𖼈', "V"), ('𖼊', "T"), ('𖼖', "L"), ('𖼚', "Δ"), ('𖼜', "Ꙙ"), ('𖼦', "ꓶ"), ('𖼨', "l"), ('𖼭', "Ɛ"), ('𖼵', "R"), ('𖼺', "S"), ('𖼻', "3"), ('𖼽', "Ʌ"), ('𖼿', ">"), ('𖽀', "A"), ('𖽂', "U"), ('𖽃', "Y"), ('𖽑', "'"), ('𖽒', "'"), ('𜳖', "A"), ('𜳗', "B"), ('𜳘', "C"), ('𜳙', "D"), ('𜳚', "E"), ('𜳛', "F"), ('𜳜', "G"), ('𜳝', "H"), ('𜳞', "l"), ('𜳟', "J"), ('𜳠', "K"), ('𜳡', "L"), ('𜳢', "M"), ('𜳣', "N"), ('𜳤', "O"), ('𜳥', "P"), ('𜳦', "Q"), ('𜳧', "R"), ('𜳨', "S"), ('𜳩', "T"), ('𜳪', "U"), ('𜳫', "V"), ('𜳬', "W"), ('𜳭', "X"), ('𜳮', "Y"), ('𜳯', "Z"), ('𜳰', "O"), ('𜳱', "l"), ('𜳲', "2"), ('𜳳', "3"), ('𜳴', "4"), ('𜳵', "5"), ('𜳶', "6"), ('𜳷', "7"), ('𜳸', "8"), ('𜳹', "9"), ('𝄔', "{"), ('\u{1d16d}', "."), ('𝈂', "Ӿ"), ('𝈆', "3"), ('𝈋', "И"), ('𝈍', "V"), ('𝈏', "\\"), ('𝈒', "7"), ('𝈓', "F"), ('𝈔', "𐊼"), ('𝈕', "ꓶ"), ('𝈖', "R"), ('𝈗', "Ɐ"), ('𝈚', "O\u{335}"), ('𝈛', "⅄"), ('𝈜', "ꓕ"), ('𝈡', "Ɛ"), ('𝈢', "Ѡ"), ('𝈪', "L"), ('𝈫', "ꓶ"), ('𝈰', "ꟻ"), ('𝈶', "<"), ('𝈷', ">"), ('𝈸', "⊏"), ('𝈹', "⊐"), ('𝈺', "/"), ('𝈻', "\\"), ('𝈿', "ᛋ"), ('𝉅', "Ո"), ('𝐀', "A"), ('𝐁', "B"), ('𝐂', "C"), ('𝐃', "D"), ('𝐄', "E"), ('𝐅', "F"), ('𝐆', "G"), ('𝐇', "H"), ('𝐈', "l"), ('𝐉', "J"), ('𝐊', "K"), ('𝐋', "L"), ('𝐌', "M"), ('𝐍', "N"), ('𝐎', "O"), ('𝐏', "P"), ('𝐐', "Q"), ('𝐑', "R"), ('𝐒', "S"), ('𝐓', "T"), ('𝐔', "U"), ('𝐕', "V"), ('𝐖', "W"), ('𝐗', "X"), ('𝐘', "Y"), ('𝐙', "Z"), ('𝐚', "a"), ('𝐛', "b"), ('𝐜', "c"), ('𝐝', "d"), ('𝐞', "e"), ('𝐟', "f"), ('𝐠', "g"), ('𝐡', "h"), ('𝐢', "i"), ('𝐣', "j"), ('𝐤', "k"), ('𝐥', "l"), ('𝐦', "rn"), ('𝐧', "n"), ('𝐨', "o"), ('𝐩', "p"), ('𝐪', "q"), ('𝐫', "r"), ('𝐬', "s"), ('𝐭', "t"), ('𝐮', "u"), ('𝐯', "v"), ('𝐰', "w"), ('𝐱', "x"), ('𝐲', "y"), ('𝐳', "z"), ('𝐴', "A"), ('𝐵', "B"), ('𝐶', "C"), ('𝐷', "D"), ('𝐸', "E"), ('𝐹', "F"), ('𝐺', "G"), ('𝐻', "H"), ('𝐼', "l"), ('𝐽', "J"), ('𝐾', "K"), ('𝐿', "L"), ('𝑀', "M"), ('𝑁', "N"), ('𝑂', "O"), ('𝑃', "P"), ('𝑄', "Q"), ('𝑅', "R"), ('𝑆', "S"), ('𝑇', "T"), ('𝑈', "U"), ('𝑉', "V"), ('𝑊', "W"), ('𝑋', "X"), ('𝑌', "Y"), ('𝑍', "Z"), ('𝑎', "a"), ('𝑏', "b"), ('𝑐', "c"), ('𝑑', "d"), ('𝑒', "e"), ('𝑓', "f"), ('𝑔', "g"), ('𝑖', "i"), ('𝑗', "j"), ('𝑘', "k"), ('𝑙', "l"), ('𝑚', "rn"), ('𝑛', "n"), ('𝑜', "o"), ('𝑝', "p"), ('𝑞', "q"), ('𝑟', "r"), ('𝑠', "s"), ('𝑡', "t"), ('𝑢', "u"), ('𝑣', "v"), ('𝑤', "w"), ('𝑥', "x"), ('𝑦', "y"), ('𝑧', "z"), ('𝑨', "A"), ('𝑩', "B"), ('𝑪', "C"), ('𝑫', "D"), ('𝑬', "E"), ('𝑭', "F"), ('𝑮', "G"), ('𝑯', "H"), ('𝑰', "l"), ('𝑱', "J"), ('𝑲', "K"), ('𝑳', "L"), ('𝑴', "M"), ('𝑵', "N"), ('𝑶', "O"), ('𝑷', "P"), ('𝑸', "Q"), ('𝑹', "R"), ('𝑺', "S"), ('𝑻', "T"), ('𝑼', "U"), ('𝑽', "V"), ('𝑾', "W"), ('𝑿', "X"), ('𝒀', "Y"), ('𝒁', "Z"), ('𝒂', "a"), ('𝒃', "b"), ('𝒄', "c"), ('𝒅', "d"), ('𝒆', "e"), ('𝒇', "f"), ('𝒈', "g"), ('𝒉', "h"), ('𝒊', "i"), ('𝒋', "j"), ('𝒌', "k"), ('𝒍', "l"), ('𝒎', "rn"), ('𝒏', "n"), ('𝒐', "o"), ('𝒑', "p"), ('𝒒', "q"), ('𝒓', "r"), ('𝒔', "s"), ('𝒕', "t"), ('𝒖', "u"), ('𝒗', "v"), ('𝒘', "w"), ('𝒙', "x"), ('𝒚', "y"), ('𝒛', "z"), ('𝒜', "A"), ('𝒞', "C"), ('𝒟', "D"), ('𝒢', "G"), ('𝒥', "J"), ('𝒦', "K"), ('𝒩', "N"), ('𝒪', "O"), ('𝒫', "P"), ('𝒬', "Q"), ('𝒮', "S"), ('𝒯', "T"), ('𝒰', "U"), ('𝒱', "V"), ('𝒲', "W"), ('𝒳', "X"), ('𝒴', "Y"), ('𝒵', "Z"), ('𝒶', "a"), ('𝒷', "b"), ('𝒸', "c"), ('𝒹', "d"), ('𝒻', "f"), ('𝒽', "h"), ('𝒾', "i"), ('𝒿', "j"), ('𝓀', "k"), ('𝓁', "l"), ('𝓂', "rn"), ('𝓃', "n"), ('𝓅', "p"), ('𝓆', "q"), ('𝓇', "r"), ('𝓈', "s"), ('𝓉', "t"), ('𝓊', "u"), ('𝓋', "v"), ('𝓌', "w"), ('𝓍', "x"), ('𝓎', "y"), ('𝓏', "z"), ('𝓐', "A"), ('𝓑', "B"), ('𝓒', "C"), ('𝓓', "D"), ('𝓔', "E"), ('𝓕', "F"), ('𝓖', "G"), ('𝓗', "H"), ('𝓘', "l"), ('𝓙', "J"), ('𝓚', "K"), ('𝓛', "L"), ('𝓜', "M"), ('𝓝', "N"), ('𝓞', "O"), ('𝓟', "P"), ('𝓠', "Q"), ('𝓡', "R"), ('𝓢', "S"), ('𝓣', "T"), ('𝓤', "U"), ('𝓥', "V"), ('𝓦', "W"), ('𝓧', "X"), ('𝓨', "Y"), ('𝓩', "Z"), ('𝓪', "a"), ('𝓫', "b"), ('𝓬', "c"), ('𝓭', "d"), ('𝓮', "e"), ('𝓯', "f"), ('𝓰', "g"), ('𝓱', "h"), ('𝓲', "i"), ('𝓳', "j"), ('𝓴', "k"), ('𝓵', "l"), ('𝓶', "rn"), ('𝓷', "n"), ('𝓸', "o"), ('𝓹', "p"), ('𝓺', "q"), ('𝓻', "r"), ('𝓼', "s"), ('𝓽', "t"), ('𝓾', "u"), ('𝓿', "v"), ('𝔀', "w"), ('𝔁', "x"), ('𝔂', "y"), ('𝔃', "z"), ('𝔄', "A"), ('𝔅', "B"), ('𝔇', "D"), ('𝔈', "E"), ('𝔉', "F"), ('𝔊', "G"), ('𝔍', "J"), ('𝔎', "K"), ('𝔏', "L"), ('𝔐', "M"), ('𝔑', "N"), ('𝔒', "O"), ('𝔓', "P"), ('𝔔', "Q"), ('𝔖', "S"), ('𝔗', "T"), ('𝔘', "U"), ('𝔙', "V"), ('𝔚', "W"), ('𝔛', "X"), ('𝔜', "Y"), ('𝔞', "a"), ('𝔟', "b"), ('𝔠', "c"), ('𝔡', "d"), ('𝔢', "e"), ('𝔣', "f"), ('𝔤', "g"), ('𝔥', "h"), ('𝔦', "i"), ('𝔧', "j"), ('𝔨', "k"), ('𝔩', "l"), ('𝔪', "rn"), ('𝔫', "n"), ('𝔬', "o"), ('𝔭', "p"), ('𝔮', "q"), ('𝔯', "r"), ('𝔰', "s"), ('𝔱', "t"), ('𝔲', "u"), ('𝔳', "v"), ('𝔴', "w"), ('𝔵', "x"), ('𝔶', "y"), ('𝔷', "z"), ('𝔸', "A"), ('𝔹', "B"), ('𝔻', "D"), ('𝔼', "E"), ('𝔽', "F"), ('𝔾', "G"), ('𝕀', "l"), ('𝕁', "J"), ('𝕂', "K"), ('𝕃', "L"), ('𝕄', "M"), ('𝕆', "O"), ('𝕊', "S"), ('𝕋', "T"), ('𝕌', "U"), ('𝕍', "V"), ('𝕎', "W"), ('𝕏', "X"), ('𝕐', "Y"), ('𝕒', "a"), ('𝕓', "b"), ('𝕔', "c"), ('𝕕', "d"), ('𝕖', "e"), ('𝕗', "f"), ('𝕘', "g"), ('𝕙', "h"), ('𝕚', "i"), ('𝕛', "j"), ('𝕜', "k"), ('𝕝', "l"), ('𝕞', "rn"), ('𝕟', "n"), ('𝕠', "o"), ('𝕡', "p"), ('𝕢', "q"), ('𝕣', "r"), ('𝕤', "s"), ('𝕥', "t"), ('𝕦', "u"), ('𝕧', "v"), ('𝕨', "w"), ('𝕩', "x"), ('𝕪', "y"), ('𝕫', "z"), ('𝕬', "A"), ('𝕭', "B"), ('𝕮', "C"), ('𝕯', "D"), ('𝕰', "E"), ('𝕱', "F"), ('𝕲', "G"), ('𝕳', "H"), ('𝕴', "l"), ('𝕵', "J"), ('𝕶', "K"), ('𝕷', "L"), ('𝕸', "M"), ('𝕹', "N"), ('𝕺', "O"), ('𝕻', "P"), ('𝕼', "Q"), ('𝕽', "R"), ('𝕾', "S"), ('𝕿', "T"), ('𝖀', "U"), ('𝖁', "V"), ('𝖂', "W"), ('𝖃', "X"), ('𝖄', "Y"), ('𝖅', "Z"), ('𝖆', "a"), ('𝖇', "b"), ('𝖈', "c"), ('𝖉', "d"), ('𝖊', "e"), ('𝖋', "f"), ('𝖌', "g"), ('𝖍', "h"), ('𝖎', "i"), ('𝖏', "j"), ('𝖐', "k"), ('𝖑', "l"), ('𝖒', "rn"), ('𝖓', "n"), ('𝖔', "o"), ('𝖕', "p"), ('𝖖', "q"), ('𝖗', "r"), ('𝖘', "s"), ('𝖙', "t"), ('𝖚', "u"), ('𝖛', "v"), ('𝖜', "w"), ('𝖝', "x"), ('𝖞', "y"), ('𝖟', "z"), ('𝖠', "A"), ('𝖡', "B"), ('𝖢', "C"), ('𝖣', "D"), ('𝖤', "E"), ('𝖥', "F"), ('𝖦', "G"), ('𝖧', "H"), ('𝖨', "l"), ('𝖩', "J"), ('𝖪', "K"), ('𝖫', "L"), ('𝖬', "M"), ('𝖭', "N"), ('𝖮', "O"), ('𝖯', "P"), ('𝖰', "Q"), ('𝖱', "R"), ('𝖲', "S"), ('𝖳', "T"), ('𝖴', "U"), ('𝖵', "V"), ('𝖶', "W"), ('𝖷', "X"), ('𝖸', "Y"), ('𝖹', "Z"), ('𝖺', "a"), ('𝖻', "b"), ('𝖼', "c"), ('𝖽', "d"), ('𝖾', "e"), ('𝖿', "f"), ('𝗀', "g"), ('𝗁', "h"), ('𝗂', "i"), ('𝗃', "j"), ('𝗄', "k"), ('𝗅', "l"), ('𝗆', "rn"), ('𝗇', "n"), ('𝗈', "o"), ('𝗉', "p"), ('𝗊', "q"), ('𝗋', "r"), ('𝗌', "s"), ('𝗍', "t"), ('𝗎', "u"), ('𝗏', "v"), ('𝗐', "w"), ('𝗑', "x"), ('𝗒', "y"), ('𝗓', "z"), ('𝗔', "A"), ('𝗕', "B"), ('𝗖', "C"), ('𝗗', "D"), ('𝗘', "E"), ('𝗙', "F"), ('𝗚', "G"), ('𝗛', "H"), ('𝗜', "l"), ('𝗝', "J"), ('𝗞', "K"), ('𝗟', "L"), ('𝗠', "M"), ('𝗡', "N"), ('𝗢', "O"), ('𝗣', "P"), ('𝗤', "Q"), ('𝗥', "R"), ('𝗦', "S"), ('𝗧', "T"), ('𝗨', "U"), ('𝗩', "V"), ('𝗪', "W"), ('𝗫', "X"), ('𝗬', "Y"), ('𝗭', "Z"), ('𝗮', "a"), ('𝗯', "b"), ('𝗰', "c"), ('𝗱', "d"), ('𝗲', "e"), ('𝗳', "f"), ('𝗴', "g"), ('𝗵', "h"), ('𝗶', "i"), ('𝗷', "j"), ('𝗸', "k"), ('𝗹', "l"), ('𝗺', "rn"), ('𝗻', "n"), ('𝗼', "o"), ('𝗽', "p"), ('𝗾', "q"), ('𝗿', "r"), ('𝘀', "s"), ('𝘁', "t"), ('𝘂', "u"), ('𝘃', "v"), ('𝘄', "w"), ('𝘅', "x"), ('𝘆', "y"), ('𝘇', "z"), ('𝘈', "A"), ('𝘉', "B"), ('𝘊', "C"), ('𝘋', "D"), ('𝘌', "E"), ('𝘍', "F"), ('𝘎', "G"), ('𝘏', "H"), ('𝘐', "l"), ('𝘑', "J"), ('𝘒', "K"), ('𝘓', "L"), ('𝘔', "M"), ('𝘕', "N"), ('𝘖', "O"), ('𝘗', "P"), ('𝘘', "Q"), ('𝘙', "R"), ('𝘚', "S"), ('𝘛', "T"), ('𝘜', "U"), ('𝘝', "V"), ('𝘞', "W"), ('𝘟', "X"), ('𝘠', "Y"), ('𝘡', "Z"), ('𝘢', "a"), ('𝘣', "b"), ('𝘤', "c"), ('𝘥', "d"), ('𝘦', "e"), ('𝘧', "f"), ('𝘨', "g"), ('𝘩', "h"), ('𝘪', "i"), ('𝘫', "j"), ('𝘬', "k"), ('𝘭', "l"), ('𝘮', "rn"), ('𝘯', "n"), ('𝘰', "o"), ('𝘱', "p"), ('𝘲', "q"), ('𝘳', "r"), ('𝘴', "s"), ('𝘵', "t"), ('𝘶', "u"), ('𝘷', "v"), ('𝘸', "w"), ('𝘹', "x"), ('𝘺', "y"), ('𝘻', "z"), ('𝘼', "A"), ('𝘽', "B"), ('𝘾', "C"), ('𝘿', "D"), ('𝙀', "E"), ('𝙁', "F"), ('𝙂', "G"), ('𝙃', "H"), ('𝙄', "l"), ('𝙅', "J"), ('𝙆', "K"), ('𝙇', "L"), ('𝙈', "M"), ('𝙉', "N"), ('𝙊', "O"), ('𝙋', "P"), ('𝙌', "Q"), ('𝙍', "R"), ('𝙎', "S"), ('𝙏', "T"), ('𝙐', "U"), ('𝙑', "V"), ('𝙒', "W"), ('𝙓', "X"), ('𝙔', "Y"), ('𝙕', "Z"), ('𝙖', "a"), ('𝙗', "b"), ('𝙘', "c"), ('𝙙', "d"), ('𝙚', "e"), ('𝙛', "f"), ('𝙜', "g"), ('𝙝', "h"), ('𝙞', "i"), ('𝙟', "j"), ('𝙠', "k"), ('𝙡', "l"), ('𝙢', "rn"), ('𝙣', "n"), ('𝙤', "o"), ('𝙥', "p"), ('𝙦', "q"), ('𝙧', "r"), ('𝙨', "s"), ('𝙩', "t"), ('𝙪', "u"), ('𝙫', "v"), ('𝙬', "w"), ('𝙭', "x"), ('𝙮', "y"), ('𝙯', "z"), ('𝙰', "A"), ('𝙱', "B"), ('𝙲', "C"), ('𝙳', "D"), ('𝙴', "E"), ('𝙵', "F"), ('𝙶', "G"), ('𝙷', "H"), ('𝙸', "l"), ('𝙹', "J"), ('𝙺', "K"), ('𝙻', "L"), ('𝙼', "M"), ('𝙽', "N"), ('𝙾', "O"), ('𝙿', "P"), ('𝚀', "Q"), ('𝚁', "R"), ('𝚂', "S"), ('𝚃', "T"), ('𝚄', "U"), ('𝚅', "V"), ('𝚆', "W"), ('𝚇', "X"), ('𝚈', "Y"), ('𝚉', "Z"), ('𝚊', "a"), ('𝚋', "b"), ('𝚌', "c"), ('𝚍', "d"), ('𝚎', "e"), ('𝚏', "f"), ('𝚐', "g"), ('𝚑', "h"), ('𝚒', "i"), ('𝚓', "j"), ('𝚔', "k"), ('𝚕', "l"), ('𝚖', "rn"), ('𝚗', "n"), ('𝚘', "o"), ('𝚙', "p"), ('𝚚', "q"), ('𝚛', "r"), ('𝚜', "s"), ('𝚝', "t"), ('𝚞', "u"), ('𝚟', "v"), ('𝚠', "w"), ('𝚡', "x"), ('𝚢', "y"), ('𝚣', "z"), ('𝚤', "i"), ('𝚥', "ȷ"), ('𝚨', "A"), ('𝚩', "B"), ('𝚪', "Γ"), ('𝚫', "Δ"), ('𝚬', "E"), ('𝚭', "Z"), ('𝚮', "H"), ('𝚯', "O\u{335}"), ('𝚰', "l"), ('𝚱', "K"), ('𝚲', "Ʌ"), ('𝚳', "M"), ('𝚴', "N"), ('𝚵', "Ξ"), ('𝚶', "O"), ('𝚷', "Π"), ('𝚸', "P"), ('𝚹', "O\u{335}"), ('𝚺', "Ʃ"), ('𝚻', "T"), ('𝚼', "Y"), ('𝚽', "Φ"), ('𝚾', "X"), ('𝚿', "Ψ"), ('𝛀', "Ω"), ('𝛁', "∇"), ('𝛂', "a"), ('𝛃', "ß"), ('𝛄', "y"), ('𝛅', "ẟ"), ('𝛆', "ꞓ"), ('𝛇', "ζ"), ('𝛈', "n\u{329}"), ('𝛉', "O\u{335}"), ('𝛊', "i"), ('𝛋', "ĸ"), ('𝛌', "λ"), ('𝛍', "μ"), ('𝛎', "v"), ('𝛏', "ξ"), ('𝛐', "o"), ('𝛑', "π"), ('𝛒', "p"), ('𝛓', "ς"), ('𝛔', "o"), ('𝛕', "ᴛ"), ('𝛖', "u"), ('𝛗', "ɸ"), ('𝛘', "χ"), ('𝛙', "ψ"), ('𝛚', "ω"), ('𝛛', "∂"), ('𝛜', "ꞓ"), ('𝛝', "O\u{335}"), ('𝛞', "ĸ"), ('𝛟', "ɸ"), ('𝛠', "p"), ('𝛡', "π"), ('𝛢', "A"), ('𝛣', "B"), ('𝛤', "Γ"), ('𝛥', "Δ"), ('𝛦', "E"), ('𝛧', "Z"), ('𝛨', "H"), ('𝛩', "O\u{335}"), ('𝛪', "l"), ('𝛫', "K"), ('𝛬', "Ʌ"), ('𝛭', "M"), ('𝛮', "N"), ('𝛯', "Ξ"), ('𝛰', "O"), ('𝛱', "Π"), ('𝛲', "P"), ('𝛳', "O\u{335}"), ('𝛴', "Ʃ"), ('𝛵', "T"), ('𝛶', "Y"), ('𝛷', "Φ"), ('𝛸', "X"), ('𝛹', "Ψ"), ('𝛺', "Ω"), ('𝛻', "∇"), ('𝛼', "a"), ('𝛽', "ß"), ('𝛾', "y"), ('𝛿', "ẟ"), ('𝜀', "ꞓ"), ('𝜁', "ζ"), ('𝜂', "n\u{329}"), ('𝜃', "O\u{335}"), ('𝜄', "i"), ('𝜅', "ĸ"), ('𝜆', "λ"), ('𝜇', "μ"), ('𝜈', "v"), ('𝜉', "ξ"), ('𝜊', "o"), ('𝜋', "π"), ('𝜌', "p"), ('𝜍', "ς"), ('𝜎', "o"), ('𝜏', "ᴛ"), ('𝜐', "u"), ('𝜑', "ɸ"), ('𝜒', "χ"), ('𝜓', "ψ"), ('𝜔', "ω"), ('𝜕', "∂"), ('𝜖', "ꞓ"), ('𝜗', "O\u{335}"), ('𝜘', "ĸ"), ('𝜙', "ɸ"), ('𝜚', "p"), ('𝜛', "π"), ('𝜜', "A"), ('𝜝', "B"), ('𝜞', "Γ"), ('𝜟', "Δ"), ('𝜠', "E"), ('𝜡', "Z"), ('𝜢', "H"), ('𝜣', "O\u{335}"), ('𝜤', "l"), ('𝜥', "K"), ('𝜦', "Ʌ"), ('𝜧', "M"), ('𝜨', "N"), ('𝜩', "Ξ"), ('𝜪', "O"), ('𝜫', "Π"), ('𝜬', "P"), ('𝜭', "O\u{335}"), ('𝜮', "Ʃ"), ('𝜯', "T"), ('𝜰', "Y"), ('𝜱', "Φ"), ('𝜲', "X"), ('𝜳', "Ψ"), ('𝜴', "Ω"), ('𝜵', "∇"), ('𝜶', "a"), ('𝜷', "ß"), ('𝜸', "y"), ('𝜹', "ẟ"), ('𝜺', "ꞓ"), ('𝜻', "ζ"), ('𝜼', "n\u{329}"), ('𝜽', "O\u{335}"), ('𝜾', "i"), ('𝜿', "ĸ"), ('𝝀', "λ"), ('𝝁', "μ"), ('𝝂', "v"), ('𝝃', "ξ"), ('𝝄', "o"), ('𝝅', "π"), ('𝝆', "p"), ('𝝇', "ς"), ('𝝈', "o"), ('𝝉', "ᴛ"), ('𝝊', "u"), ('𝝋', "ɸ"), ('𝝌', "χ"), ('𝝍', "ψ"), ('𝝎', "ω"), ('𝝏', "∂"), ('𝝐', "ꞓ"), ('𝝑', "O\u{335}"), ('𝝒', "ĸ"), ('𝝓', "ɸ"), ('𝝔', "p"), ('𝝕', "π"), ('𝝖', "A"), ('𝝗', "B"), ('𝝘', "Γ"), ('𝝙', "Δ"), ('𝝚', "E"), ('𝝛', "Z"), ('𝝜', "H"), ('𝝝', "O\u{335}"), ('𝝞', "l"), ('𝝟', "K"), ('𝝠', "Ʌ"), ('𝝡', "M"), ('𝝢', "N"), ('𝝣', "Ξ"), ('𝝤', "O"), ('𝝥', "Π"), ('𝝦', "P"), ('𝝧', "O\u{335}"), ('𝝨', "Ʃ"), ('𝝩', "T"), ('𝝪', "Y"), ('𝝫', "Φ"), ('𝝬', "X"), ('𝝭', "Ψ"), ('𝝮', "Ω"), ('𝝯', "∇"), ('𝝰', "a"), ('𝝱', "ß"), ('𝝲', "y"), ('𝝳', "ẟ"), ('𝝴', "ꞓ"), ('𝝵', "ζ"), ('𝝶', "n\u{329}"), ('𝝷', "O\u{335}"), ('𝝸', "i"), ('𝝹', "ĸ"), ('𝝺', "λ"), ('𝝻', "μ"), ('𝝼', "v"), ('𝝽', "ξ"), ('𝝾', "o"), ('𝝿', "π"), ('𝞀', "p"), ('𝞁', "ς"), ('𝞂', "o"), ('𝞃', "ᴛ"), ('𝞄', "u"), ('𝞅', "ɸ"), ('𝞆', "χ"), ('𝞇', "ψ"), ('𝞈', "ω"), ('𝞉', "∂"), ('𝞊', "ꞓ"), ('𝞋', "O\u{335}"), ('𝞌', "ĸ"), ('𝞍', "ɸ"), ('𝞎', "p"), ('𝞏', "π"), ('𝞐', "A"), ('𝞑', "B"), ('𝞒', "Γ"), ('𝞓', "Δ"), ('𝞔', "E"), ('𝞕', "Z"), ('𝞖', "H"), ('𝞗', "O\u{335}"), ('𝞘', "l"), ('𝞙', "K"), ('𝞚', "Ʌ"), ('𝞛', "M"), ('𝞜', "N"), ('𝞝', "Ξ"), ('𝞞', "O"), ('𝞟', "Π"), ('𝞠', "P"), ('𝞡', "O\u{335}"), ('𝞢', "Ʃ"), ('𝞣', "T"), ('𝞤', "Y"), ('𝞥', "Φ"), ('𝞦', "X"), ('𝞧', "Ψ"), ('𝞨', "Ω"), ('𝞩', "∇"), ('𝞪', "a"), ('𝞫', "ß"), ('𝞬', "y"), ('𝞭', "ẟ"), ('𝞮', "ꞓ"), ('𝞯', "ζ"), ('𝞰', "n\u{329}"), ('𝞱', "O\u{335}"), ('𝞲', "i"), ('𝞳', "ĸ"), ('𝞴', "λ"), ('𝞵', "μ"), ('𝞶', "v"), ('𝞷', "ξ"), ('𝞸', "o"), ('𝞹', "π"), ('𝞺', "p"), ('𝞻', "ς"), ('𝞼', "o"), ('𝞽', "ᴛ"), ('𝞾', "u"), ('𝞿', "ɸ"), ('𝟀', "χ"), ('𝟁', "ψ"), ('𝟂', "ω"), ('𝟃', "∂"), ('𝟄', "ꞓ"), ('𝟅', "O\u{335}"), ('𝟆', "ĸ"), ('𝟇', "ɸ"), ('𝟈', "p"), ('𝟉', "π"), ('𝟊', "F"), ('𝟋', "ϝ"), ('𝟎', "O"), ('𝟏', "l"), ('𝟐', "2"), ('𝟑', "3"), ('𝟒', "4"), ('𝟓', "5"), ('𝟔', "6"), ('𝟕', "7"), ('𝟖', "8"), ('𝟗', "9"), ('𝟘', "O"), ('𝟙', "l"), ('𝟚', "2"), ('𝟛', "3"), ('𝟜', "4"), ('𝟝', "5"), ('𝟞', "6"), ('𝟟', "7"), ('𝟠', "8"), ('𝟡', "9"), ('𝟢', "O"), ('𝟣', "l"), ('𝟤', "2"), ('𝟥', "3"), ('𝟦', "4"), ('𝟧', "5"), ('𝟨', "6"), ('𝟩', "7"), ('𝟪', "8"), ('𝟫', "9"), ('𝟬', "O"), ('𝟭', "l"), ('𝟮', "2"), ('𝟯', "3"), ('𝟰', "4"), ('𝟱', "5"), ('𝟲', "6"), ('𝟳', "7"), ('𝟴', "8"), ('𝟵', "9"), ('𝟶', "O"), ('𝟷', "l"), ('𝟸', "2"), ('𝟹', "3"), ('𝟺', "4"), ('𝟻', "5"), ('𝟼', "6"), ('𝟽', "7"), ('𝟾', "8"), ('𝟿', "9"), ('𞣇', "l"), ('𞣈', "∠"), ('𞣉', "٣"), ('𞣋', "8"), ('𞣌', "∂"), ('𞣍', "∂\u{335}"), ('𞸀', "l"), ('𞸁', "ب"), ('𞸂', "ج"), ('𞸃', "د"), ('𞸅', "و"), ('𞸆', "ز"), ('𞸇', "ح"), ('𞸈', "ط"), ('𞸉', "ى"), ('𞸊', "ك"), ('𞸋', "ل"), ('𞸌', "م"), ('𞸍', "ن"), ('𞸎', "س"), ('𞸏', "ع"), ('𞸐', "ف"), ('𞸑', "ص"), ('𞸒', "ق"), ('𞸓', "ر"), ('𞸔', "س\u{6db}"), ('𞸕', "ت"), ('𞸖', "ى\u{6db}"), ('𞸗', "خ"), ('𞸘', "ذ"), ('𞸙', "ض"), ('𞸚', "ظ"), ('𞸛', "غ"), ('𞸜', "ى"), ('𞸝', "ى"), ('𞸞', "ڡ"), ('𞸟', "ڡ"), ('𞸡', "ب"), ('𞸢', "ج"), ('𞸤', "o"), ('𞸧', "ح"), ('𞸩', "ى"), ('𞸪', "ك"), ('𞸫', "ل"), ('𞸬', "م"), ('𞸭', "ن"), ('𞸮', "س"), ('𞸯', "ع"), ('𞸰', "ف"), ('𞸱', "ص"), ('𞸲', "ق"), ('𞸴', "س\u{6db}"), ('𞸵', "ت"), ('𞸶', "ى\u{6db}"), ('𞸷', "خ"), ('𞸹', "ض"), ('𞸻', "غ"), ('𞹂', "ج"), ('𞹇', "ح"), ('𞹉', "ى"), ('𞹋', "ل"), ('𞹍', "ن"), ('𞹎', "س"), ('𞹏', "ع"), ('𞹑', "ص"), ('𞹒', "ق"), ('𞹔', "س\u{6db}"), ('𞹗', "خ"), ('𞹙', "ض"), ('𞹛', "غ"), ('𞹝', "ى"), ('𞹟', "ڡ"), ('𞹡', "ب"), ('𞹢', "ج"), ('𞹤', "o"), ('𞹧', "ح"), ('𞹨', "ط"), ('𞹩', "ى"), ('𞹪', "ك"), ('𞹬', "م"), ('𞹭', "ن"), ('𞹮', "س"), ('𞹯', "ع"), ('𞹰', "ف"), ('𞹱', "ص"), ('𞹲', "ق"), ('𞹴', "س\u{6db}"), ('𞹵', "ت"), ('𞹶', "ى\u{6db}"), ('𞹷', "خ"), ('𞹹', "ض"), ('𞹺', "ظ"), ('𞹻', "غ"), ('𞹼', "ى"), ('𞹾', "ڡ"), ('𞺀', "l"), ('𞺁', "ب"), ('𞺂', "ج"), ('𞺃', "د"), ('𞺄', "o"), ('𞺅', "و"), ('𞺆', "ز"), ('𞺇', "ح"), ('𞺈', "ط"), ('𞺉', "ى"), ('𞺋', "ل"), ('𞺌', "م"), ('𞺍', "ن"), ('𞺎', "س"), ('𞺏', "ع"), ('𞺐', "ف"), ('𞺑', "ص"), ('𞺒', "ق"), ('𞺓', "ر"), ('𞺔', "س\u{6db}"), ('𞺕', "ت"), ('𞺖', "ى\u{6db}"), ('𞺗', "خ"), ('𞺘', "ذ"), ('𞺙', "ض"), ('𞺚', "ظ"), ('𞺛', "غ"), ('𞺡', "ب"), ('𞺢', "ج"), ('𞺣', "د"), ('𞺥', "و"), ('𞺦', "ز"), ('𞺧', "ح"), ('𞺨', "ط"), ('𞺩', "ى"), ('𞺫', "ل"), ('𞺬', "م"), ('𞺭', "ن"), ('𞺮', "س"), ('𞺯', "ع"), ('𞺰', "ف"), ('𞺱', "ص"), ('𞺲', "ق"), ('𞺳', "ر"), ('𞺴', "س\u{6db}"), ('𞺵', "ت"), ('𞺶', "ى\u{6db}"), ('𞺷', "خ"), ('𞺸', "ذ"), ('𞺹', "ض"), ('𞺺', "ظ"), ('𞺻', "غ"), ('🄀', "O."), ('🄁', "O,"), ('🄂', "l,"), ('🄃', "2,"), ('🄄', "3,"), ('🄅', "4,"), ('🄆', "5,"), ('🄇', "6,"), ('🄈', "7,"), ('🄉', "8,"), ('🄊', "9,"), ('🄏', "$\u{20e0}"), ('🄐', "(A)"), ('🄑', "(B)"), ('🄒', "(C)"), ('🄓', "(D)"), ('🄔', "(E)"), ('🄕', "(F)"), ('🄖', "(G)"), ('🄗', "(H)"), ('🄘', "(l)"), ('🄙', "(J)"), ('🄚', "(K)"), ('🄛', "(L)"), ('🄜', "(M)"), ('🄝', "(N)"), ('🄞', "(O)"), ('🄟', "(P)"), ('🄠', "(Q)"), ('🄡', "(R)"), ('🄢', "(S)"), ('🄣', "(T)"), ('🄤', "(U)"), ('🄥', "(V)"), ('🄦', "(W)"), ('🄧', "(X)"), ('🄨', "(Y)"), ('🄩', "(Z)"), ('🄪', "(S)"), ('🅭', "㏄\t\u{20dd}"), ('🅮', "C\u{20e0}"), ('🉀', "(本)"), ('🉁', "(三)"), ('🉂', "(二)"), ('🉃', "(安)"), ('🉄', "(点)"), ('🉅', "(打)"), ('🉆', "(盗)"), ('🉇', "(勝)"), ('🉈', "(敗)"), ('🌒', "☽"), ('🌘', "☾"), ('🌙', "☽"), ('🜀', "QE"), ('🜁', "Ꙙ"), ('🜂', "Δ"), ('🜄', "𐊼"), ('🜇', "AR"), ('🜈', "V\u{1de4}"), ('🜊', "☩"), ('🜔', "O\u{335}"), ('🜨', "𐊨"), ('🜺', "⧟"), ('🝌', "C"), ('🝔', "ᛜ"), ('🝕', "⊡"), ('🝜', "sss"), ('🝞', "≏"), ('🝨', "T"), ('🝫', "MB"), ('🝬', "VB"), ('🝱', "⊠"), ('🯰', "O"), ('🯱', "l"), ('🯲', "2"), ('🯳', "3"), ('🯴', "4"), ('🯵', "5"), ('🯶', "6"), ('🯷', "7"), ('🯸', "8"), ('🯹', "9"), ('𡿨', "❬"), ('丽', "丽"), ('丸', "丸"), ('乁', "乁"), ('𠄢', "𠄢"), ('你', "你"), ('侮', "侮"), ('侻', "侻"), ('倂', "併"), ('偺', "偺"), ('備', "備"), ('僧', "僧"), ('像', "像"), ('㒞', "㒞"), ('𠘺', "𠘺"), ('免', "免"), ('兔', "兔"), ('兤', "兤"), ('具', "具"), ('𠔜', "𠔜"), ('㒹', "㒹"), ('內', "內"), ('再', "再"), ('𠕋', "𠕋"), ('冗', "冗"), ('冤', "冤"), ('仌', "仌"), ('冬', "冬"), ('况', "况"), ('𩇟', "𩇟"), ('凵', "凵"), ('刃', "刃"), ('㓟', "㓟"), ('刻', "刻"), ('剆', "剆"), ('割', "割"), ('剷', "剷"), ('㔕', "㔕"), ('勇', "勇"), ('勉', "勉"), ('勤', "勤"), ('勺', "勺"), ('包', "包"), ('匆', "匆"), ('北', "北"), ('卉', "卉"), ('卑', "卑"), ('博', "博"), ('即', "即"), ('卽', "卽"), ('卿', "卿"), ('卿', "卿"), ('卿', "卿"), ('𠨬', "𠨬"), ('灰', "灰"), ('及', "及"), ('叟', "叟"), ('𠭣', "𠭣"), ('叫', "叫"), ('叱', "叱"), ('吆', "吆"), ('咞', "咞"), ('吸', "吸"), ('呈', "呈"), ('周', "周"), ('咢', "咢"), ('哶', "哶"), ('唐', "唐"), ('啓', "啓"), ('啣', "啣"), ('善', "善"), ('善', "善"), ('喙', "喙"), ('喫', "喫"), ('喳', "喳"), ('嗂', "嗂"), ('圖', "圖"), ('嘆', "嘆"), ('圗', "圗"), ('噑', "噑"), ('噴', "噴"), ('切', "切"), ('壮', "壮"), ('城', "城"), ('埴', "埴"), ('堍', "堍"), ('型', "型"), ('堲', "堲"), ('報', "報"), ('墬', "墬"), ('𡓤', "𡓤"), ('売', "売"), ('壷', "壷"), ('夆', "夆"), ('多', "多"), ('夢', "夢"), ('奢', "奢"), ('𡚨', "𡚨"), ('𡛪', "𡛪"), ('姬', "姬"), ('娛', "娛"), ('娧', "娧"), ('姘', "姘"), ('婦', "婦"), ('㛮', "㛮"), ('㛼', "㛼"), ('嬈', "嬈"), ('嬾', "嬾"), ('嬾', "嬾"), ('𡧈', "𡧈"), ('寃', "寃"), ('寘', "寘"), ('寧', "寧"), ('寳', "寳"), ('𡬘', "𡬘"), ('寿', "寿"), ('将', "将"), ('当', "当"), ('尢', "尢"), ('㞁', "㞁"), ('屠', "屠"), ('屮', "屮"), ('峀', "峀"), ('岍', "岍"), ('𡷤', "𡷤"), ('嵃', "嵃"), ('𡷦', "𡷦"), ('嵮', "嵮"), ('嵫', "嵫"), ('嵼', "嵼"), ('巡', "巡"), ('巢', "巢"), ('㠯', "㠯"), ('巽', "巽"), ('帨', "帨"), ('帽', "帽"), ('幩', "幩"), ('㡢', "㡢"), ('𢆃', "𢆃"), ('㡼', "㡼"), ('庰', "庰"), ('庳', "庳"), ('庶', "庶"), ('廊', "廊"), ('𪎒', "𪎒"), ('廾', "廾"), ('𢌱', "𢌱"), ('𢌱', "𢌱"), ('舁', "舁"), ('弢', "弢"), ('弢', "弢"), ('㣇', "㣇"), ('𣊸', "𣊸"), ('𦇚', "𦇚"), ('形', "形"), ('彫', "彫"), ('㣣', "㣣"), ('徚', "徚"), ('忍', "忍"), ('志', "志"), ('忹', "忹"), ('悁', "悁"), ('㤺', "㤺"), ('㤜', "㤜"), ('悔', "悔"), ('𢛔', "𢛔"), ('惇', "惇"), ('慈', "慈"), ('慌', "慌"), ('慎', "慎"), ('慌', "慌"), ('慺', "慺"), ('憎', "憎"), ('憲', "憲"), ('憤', "憤"), ('憯', "憯"), ('懞', "懞"), ('懲', "懲"), ('懶', "懶"), ('成', "成"), ('戛', "戛"), ('扝', "扝"), ('抱', "抱"), ('拔', "拔"), ('捐', "捐"), ('𢬌', "𢬌"), ('挽', "挽"), ('拼', "拼"), ('捨', "捨"), ('掃', "掃"), ('揤', "揤"), ('𢯱', "𢯱"), ('搢', "搢"), ('揅', "揅"), ('掩', "掩"), ('㨮', "㨮"), ('摩', "摩"), ('摾', "摾"), ('撝', "撝"), ('摷', "摷"), ('㩬', "㩬"), ('敏', "敏"), ('敬', "敬"), ('𣀊', "𣀊"), ('旣', "旣"), ('書', "書"), ('晉', "晉"), ('㬙', "㬙"), ('暑', "暑"), ('㬈', "㬈"), ('㫤', "㫤"), ('冒', "冒"), ('冕', "冕"), ('最', "最"), ('暜', "暜"), ('肭', "肭"), ('䏙', "䏙"), ('朗', "朗"), ('望', "望"), ('朡', "朡"), ('杞', "杞"), ('杓', "杓"), ('𣏃', "𣏃"), ('㭉', "㭉"), ('柺', "柺"), ('枅', "枅"), ('桒', "桒"), ('梅', "梅"), ('𣑭', "𣑭"), ('梎', "梎"), ('栟', "栟"), ('椔', "椔"), ('㮝', "㮝"), ('楂', "楂"), ('榣', "榣"), ('槪', "槪"), ('檨', "檨"), ('𣚣', "𣚣"), ('櫛', "櫛"), ('㰘', "㰘"), ('次', "次"), ('𣢧', "𣢧"), ('歔', "歔"), ('㱎', "㱎"), ('歲', "歲"), ('殟', "殟"), ('殺', "殺"), ('殻', "殻"), ('𣪍', "𣪍"), ('𡴋', "𡴋"), ('𣫺', "𣫺"), ('汎', "汎"), ('𣲼', "𣲼"), ('沿', "沿"), ('泍', "泍"), ('汧', "汧"), ('洖', "洖"), ('派', "派"), ('海', "海"), ('流', "流"), ('浩', "浩"), ('浸', "浸"), ('涅', "涅"), ('𣴞', "𣴞"), ('洴', "洴"), ('港', "港"), ('湮', "湮"), ('㴳', "㴳"), ('滋', "滋"), ('滇', "滇"), ('𣻑', "𣻑"), ('淹', "淹"), ('潮', "潮"), ('𣽞', "𣽞"), ('𣾎', "𣾎"), ('濆', "濆"), ('瀹', "瀹"), ('瀞', "瀞"), ('瀛', "瀛"), ('㶖', "㶖"), ('灊', "灊"), ('災', "災"), ('灷', "灷"), ('炭', "炭"), ('𠔥', "𠔥"), ('煅', "煅"), ('𤉣', "𤉣"), ('熜', "熜"), ('𤎫', "𤎫"), ('爨', "爨"), ('爵', "爵"), ('牐', "牐"), ('𤘈', "𤘈"), ('犀', "犀"), ('犕', "犕"), ('𤜵', "𤜵"), ('𤠔', "𤠔"), ('獺', "獺"), ('王', "王"), ('㺬', "㺬"), ('玥', "玥"), ('㺸', "㺸"), ('㺸', "㺸"), ('瑇', "瑇"), ('瑜', "瑜"), ('瑱', "瑱"), ('璅', "璅"), ('瓊', "瓊"), ('㼛', "㼛"), ('甤', "甤"), ('𤰶', "𤰶"), ('甾', "甾"), ('𤲒', "𤲒"), ('異', "異"), ('𢆟', "𢆟"), ('瘐', "瘐"), ('𤾡', "𤾡"), ('𤾸', "𤾸"), ('𥁄', "𥁄"), ('㿼', "㿼"), ('䀈', "䀈"), ('直', "直"), ('𥃳', "𥃳"), ('𥃲', "𥃲"), ('𥄙', "𥄙"), ('𥄳', "𥄳"), ('眞', "眞"), ('真', "真"), ('真', "真"), ('睊', "睊"), ('䀹', "䀹"), ('瞋', "瞋"), ('䁆', "䁆"), ('䂖', "䂖"), ('𥐝', "𥐝"), ('硎', "硎"), ('碌', "碌"), ('磌', "磌"), ('䃣', "䃣"), ('𥘦', "𥘦"), ('祖', "祖"), ('𥚚', "𥚚"), ('𥛅', "𥛅"), ('福', "福"), ('秫', "秫"), ('䄯', "䄯"), ('穀', "穀"), ('穊', "穊"), ('穏', "穏"), ('𥥼', "𥥼"), ('𥪧', "𥪧"), ('𥪧', "𥪧"), ('竮', "竮"), ('䈂', "䈂"), ('𥮫', "𥮫"), ('篆', "篆"), ('築', "築"), ('䈧', "䈧"), ('𥲀', "𥲀"), ('糒', "糒"), ('䊠', "䊠"), ('糨', "糨"), ('糣', "糣"), ('紀', "紀"), ('𥾆', "𥾆"), ('絣', "絣"), ('䌁', "䌁"), ('緇', "緇"), ('縂', "縂"), ('繅', "繅"), ('䌴', "䌴"), ('𦈨', "𦈨"), ('𦉇', "𦉇"), ('䍙', "䍙"), ('𦋙', "𦋙"), ('罺', "罺"), ('𦌾', "𦌾"), ('羕', "羕"), ('翺', "翺"), ('者', "者"), ('𦓚', "𦓚"), ('𦔣', "𦔣"), ('聠', "聠"), ('𦖨', "𦖨"), ('聰', "聰"), ('𣍟', "𣍟"), ('䏕', "䏕"), ('育', "育"), ('脃', "脃"), ('䐋', "䐋"), ('脾', "脾"), ('媵', "媵"), ('𦞧', "𦞧"), ('𦞵', "𦞵"), ('𣎓', "𣎓"), ('𣎜', "𣎜"), ('舁', "舁"), ('舄', "舄"), ('辞', "辞"), ('䑫', "䑫"), ('芑', "芑"), ('芋', "芋"), ('芝', "芝"), ('劳', "劳"), ('花', "花"), ('芳', "芳"), ('芽', "芽"), ('苦', "苦"), ('𦬼', "𦬼"), ('若', "若"), ('茝', "茝"), ('荣', "荣"), ('莭', "莭"), ('茣', "茣"), ('莽', "莽"), ('菧', "菧"), ('著', "著"), ('荓', "荓"), ('菊', "菊"), ('菌', "菌"), ('菜', "菜"), ('𦰶', "𦰶"), ('𦵫', "𦵫"), ('𦳕', "𦳕"), ('䔫', "䔫"), ('蓱', "蓱"), ('蓳', "蓳"), ('蔖', "蔖"), ('𧏊', "𧏊"), ('蕤', "蕤"), ('𦼬', "𦼬"), ('䕝', "䕝"), ('䕡', "䕡"), ('𦾱', "𦾱"), ('𧃒', "𧃒"), ('䕫', "䕫"), ('虐', "虐"), ('虜', "虜"), ('虧', "虧"), ('虩', "虩"), ('蚩', "蚩"), ('蚈', "蚈"), ('蜎', "蜎"), ('蛢', "蛢"), ('蝹', "蝹"), ('蜨', "蜨"), ('蝫', "蝫"), ('螆', "螆"), ('䗗', "䗗"), ('蟡', "蟡"), ('蠁', "蠁"), ('䗹', "䗹"), ('衠', "衠"), ('衣', "衣"), ('𧙧', "𧙧"), ('裗', "裗"), ('裞', "裞"), ('䘵', "䘵"), ('裺', "裺"), ('㒻', "㒻"), ('𧢮', "𧢮"), ('𧥦', "𧥦"), ('䚾', "䚾"), ('䛇', "䛇"), ('誠', "誠"), ('諭', "諭"), ('變', "變"), ('豕', "豕"), ('𧲨', "𧲨"), ('貫', "貫"), ('賁', "賁"), ('贛', "贛"), ('起', "起"), ('𧼯', "𧼯"), ('𠠄', "𠠄"), ('跋', "跋"), ('趼', "趼"), ('跰', "跰"), ('𠣞', "𠣞"), ('軔', "軔"), ('輸', "輸"), ('𨗒', "𨗒"), ('𨗭', "𨗭"), ('邔', "邔"), ('郱', "郱"), ('鄑', "鄑"), ('𨜮', "𨜮"), ('鄛', "鄛"), ('鈸', "鈸"), ('鋗', "鋗"), ('鋘', "鋘"), ('鉼', "鉼"), ('鏹', "鏹"), ('鐕', "鐕"), ('𨯺', "𨯺"), ('開', "開"), ('䦕', "䦕"), ('閷', "閷"), ('𨵷', "𨵷"), ('䧦', "䧦"), ('雃', "雃"), ('嶲', "嶲"), ('霣', "霣"), ('𩅅', "𩅅"), ('𩈚', "𩈚"), ('䩮', "䩮"), ('䩶', "䩶"), ('韠', "韠"), ('𩐊', "𩐊"), ('䪲', "䪲"), ('𩒖', "𩒖"), ('頋', "頋"), ('頋', "頋"), ('頩', "頩"), ('𩖶', "𩖶"), ('飢', "飢"), ('䬳', "䬳"), ('餩', "餩"), ('馧', "馧"), ('駂', "駂"), ('駾', "駾"), ('䯎', "䯎"), ('𩬰', "𩬰"), ('鬒', "鬒"), ('鱀', "鱀"), ('鳽', "鳽"), ('䳎', "䳎"), ('䳭', "䳭"), ('鵧', "鵧"), ('𪃎', "𪃎"), ('䳸', "䳸"), ('𪄅', "𪄅"), ('𪈎', "𪈎"), ('𪊑', "𪊑"), ('麻', "麻"), ('䵖', "䵖"), ('黹', "黹"), ('黾', "黾"), ('鼅', "鼅"), ('鼏', "鼏"), ('鼖', "鼖"), ('鼻', "鼻"), ('𪘀', "𪘀")];
@@ -0,0 +1,84 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `skeleton!` macro, re-exported by unicode_skeleton with its `macros`
+//! feature.
+//!
+//! This crate cannot depend on unicode_skeleton, which depends on it, so it
+//! computes skeletons itself from its own copy of the confusables table,
+//! generated by `cargo xtask` from the same data. Its tests check that it
+//! agrees with unicode_skeleton.
+
+extern crate proc_macro;
+extern crate quote;
+extern crate syn;
+extern crate unicode_normalization;
+
+mod confusables;
+
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::LitStr;
+use unicode_normalization::UnicodeNormalization;
+
+use confusables::PROTOTYPES;
+
+// The skeleton of `s`, as `unicode_skeleton::skeleton` computes it with the
+// latest bundled data.
+fn skeleton_of(s: &str) -> String {
+    let mut mapped = String::new();
+    for c in s.nfd() {
+        match PROTOTYPES.binary_search_by_key(&c, |&(source, _)| source) {
+            Ok(i) => mapped.push_str(PROTOTYPES[i].1),
+            Err(_) => mapped.push(c),
+        }
+    }
+    mapped.nfd().collect()
+}
+
+/// Expand to the skeleton of a string literal, as a string literal.
+///
+/// ```Rust
+/// const RUST: &str = skeleton!("ℝ𝓊𝓈𝓉"); // "Rust"
+/// ```
+///
+/// The skeleton is computed from the confusables table bundled with the
+/// crate, so it is an error to use this macro while building with
+/// `UNICODE_SKELETON_CONFUSABLES`.
+#[proc_macro]
+pub fn skeleton(input: TokenStream) -> TokenStream {
+    let literal = match syn::parse::<LitStr>(input) {
+        Ok(literal) => literal,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    if std::env::var_os("UNICODE_SKELETON_CONFUSABLES").is_some() {
+        let message = "skeleton! does not support UNICODE_SKELETON_CONFUSABLES";
+        return syn::Error::new(literal.span(), message).to_compile_error().into();
+    }
+    LitStr::new(&skeleton_of(&literal.value()), literal.span()).into_token_stream().into()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate unicode_skeleton;
+
+    use super::skeleton_of;
+    use confusables::PROTOTYPES;
+
+    #[test]
+    fn agrees_with_the_crate() {
+        for s in &["", "Rust", "ℝ𝓊𝓈𝓉", "pаypаl", "Ǐ", "ǏI", "rn", "ﬁnancial", "e\u{301}\u{327}"] {
+            assert_eq!(skeleton_of(s), unicode_skeleton::skeleton(*s), "{}", s);
+        }
+        for &(source, _) in PROTOTYPES.iter() {
+            let s = format!("a{}b", source);
+            assert_eq!(skeleton_of(&s), unicode_skeleton::skeleton(&s[..]), "{:?}", source);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests of `SkeletonNormalized` against a real database, kept out of the
+//! main crate so that its own tests do not build SQLite and tokio. They are
+//! in `tests`, and run with `cargo test -p sql-tests`.
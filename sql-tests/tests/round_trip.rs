@@ -0,0 +1,55 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate diesel;
+extern crate sqlx;
+extern crate tokio;
+extern crate unicode_skeleton;
+
+use unicode_skeleton::SkeletonNormalized;
+
+#[test]
+fn sqlx_unique_skeletons() {
+    use sqlx::{Connection, SqliteConnection};
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let mut db = runtime.block_on(SqliteConnection::connect("sqlite::memory:")).unwrap();
+    runtime.block_on(sqlx::query("CREATE TABLE users (name TEXT, name_skeleton TEXT UNIQUE)").execute(&mut db)).unwrap();
+    let mut insert = |name: &str| {
+        let name = SkeletonNormalized::new(name.to_string());
+        let query = sqlx::query("INSERT INTO users (name, name_skeleton) VALUES (?, ?)")
+            .bind(name.original().clone())
+            .bind(name);
+        runtime.block_on(query.execute(&mut db))
+    };
+    insert("Rust").unwrap();
+    assert!(insert("ℝ𝓊𝓈𝓉").is_err());
+    let query = sqlx::query_as("SELECT name, name_skeleton FROM users");
+    let stored: (String, String) = runtime.block_on(query.fetch_one(&mut db)).unwrap();
+    assert_eq!(stored, ("Rust".to_string(), "Rust".to_string()));
+}
+
+#[test]
+fn diesel_unique_skeletons() {
+    use diesel::prelude::*;
+    use diesel::sql_types::Text;
+
+    let mut db = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE users (name TEXT, name_skeleton TEXT UNIQUE)").execute(&mut db).unwrap();
+    let mut insert = |name: &str| {
+        let name = SkeletonNormalized::new(name.to_string());
+        diesel::sql_query("INSERT INTO users (name, name_skeleton) VALUES (?, ?)")
+            .bind::<Text, _>(name.original())
+            .bind::<Text, _>(&name)
+            .execute(&mut db)
+    };
+    insert("pаypаl").unwrap();
+    assert!(insert("paypal").is_err());
+    assert!(insert("Rust").is_ok());
+}
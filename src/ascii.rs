@@ -0,0 +1,210 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The fast path for computing the skeletons of mostly-ASCII strings.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::convert::TryInto;
+
+use data::{self, PrototypeTable};
+use {PrototypeCharsIterator, UnicodeSkeleton};
+
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+// Above this many mapped ASCII characters, comparing each word against every
+// one of them costs more than checking the bytes one at a time.
+const MAX_WORD_SOURCES: usize = 16;
+
+/// The ASCII characters of a confusables table, for finding the parts of a
+/// string that can skip normalization and lookup.
+///
+/// An ASCII character whose skeleton is ASCII has a canonical combining class
+/// of zero, as does its skeleton, so normalization never moves anything
+/// across it. The skeleton of a string is therefore the skeletons of the
+/// pieces between these characters, with their own skeletons in between.
+struct AsciiSkeletons {
+    table: PrototypeTable<'static>,
+    // The ASCII characters that the table maps.
+    sources: u128,
+    // The ASCII characters that the table maps to something that is not ASCII.
+    complex: u128,
+    // Each mapped ASCII character, repeated in every byte of a word, with its
+    // prototype, when there are few enough.
+    words: [(u64, &'static [char]); MAX_WORD_SOURCES],
+    word_count: usize,
+}
+
+impl AsciiSkeletons {
+    fn new(table: PrototypeTable<'static>) -> AsciiSkeletons {
+        let mut ascii = AsciiSkeletons { table, sources: 0, complex: 0, words: [(0, &[]); MAX_WORD_SOURCES], word_count: 0 };
+        // Entries are in order, so the ASCII ones come first.
+        for (c, _) in table.entries() {
+            if !c.is_ascii() {
+                break;
+            }
+            // A single-script table may leave a source unmapped.
+            let prototype = match table.prototype_of(c) {
+                Some(prototype) => prototype,
+                None => continue,
+            };
+            ascii.sources |= 1 << c as u32;
+            if !prototype.iter().all(char::is_ascii) {
+                ascii.complex |= 1 << c as u32;
+            }
+            if ascii.word_count < MAX_WORD_SOURCES {
+                ascii.words[ascii.word_count] = (LOW_BITS * c as u64, prototype);
+            }
+            ascii.word_count += 1;
+        }
+        if ascii.word_count > MAX_WORD_SOURCES {
+            ascii.word_count = 0;
+        }
+        ascii
+    }
+
+    /// Whether `b` is an ASCII character that is its own skeleton.
+    fn is_plain(&self, b: u8) -> bool {
+        b < 0x80 && self.sources & (1 << b) == 0
+    }
+
+    /// Whether `b` is an ASCII character whose skeleton is ASCII.
+    fn is_boundary(&self, b: u8) -> bool {
+        b < 0x80 && self.complex & (1 << b) == 0
+    }
+
+    /// The skeleton of `b`, if it is a mapped ASCII character whose skeleton
+    /// is ASCII.
+    fn mapped_skeleton(&self, b: u8) -> Option<&'static [char]> {
+        if !self.is_boundary(b) || self.is_plain(b) {
+            return None;
+        }
+        let word = LOW_BITS * b as u64;
+        match self.words[..self.word_count].iter().find(|&&(mapped, _)| mapped == word) {
+            Some(&(_, prototype)) => Some(prototype),
+            None => self.table.prototype_of(b as char),
+        }
+    }
+
+    /// The length of the run of plain bytes that `bytes` begins with.
+    ///
+    /// This checks eight bytes at a time while it can: a word is plain if no
+    /// byte has its high bit set, and no byte equals a mapped character.
+    fn plain_len(&self, bytes: &[u8]) -> usize {
+        let mut len = 0;
+        if self.word_count > 0 || self.sources == 0 {
+            for chunk in bytes.chunks_exact(8) {
+                let word = u64::from_le_bytes(chunk.try_into().unwrap());
+                if word & HIGH_BITS != 0 || self.words[..self.word_count].iter().any(|&(mapped, _)| has_zero_byte(word ^ mapped)) {
+                    break;
+                }
+                len += 8;
+            }
+        }
+        len + bytes[len..].iter().position(|&b| !self.is_plain(b)).unwrap_or(bytes.len() - len)
+    }
+}
+
+// Whether any byte of `word` is zero, given that no byte has its high bit
+// set. A borrow can only start at a zero byte, so there are no false
+// positives.
+fn has_zero_byte(word: u64) -> bool {
+    word.wrapping_sub(LOW_BITS) & !word & HIGH_BITS != 0
+}
+
+/// Append the skeleton of `s` to `out`, copying runs of unmapped ASCII
+/// characters straight through, and only running the parts of `s` that are
+/// not ASCII through normalization and lookup.
+pub fn push_skeleton_str(s: &str, out: &mut String) {
+    let ascii = AsciiSkeletons::new(data::CONFUSABLES);
+    let bytes = s.as_bytes();
+    out.reserve(s.len());
+    let mut start = 0;
+    while start < bytes.len() {
+        let plain_end = start + ascii.plain_len(&bytes[start..]);
+        out.push_str(&s[start..plain_end]);
+        start = plain_end;
+        if start == bytes.len() {
+            break;
+        }
+        if let Some(skeleton) = ascii.mapped_skeleton(bytes[start]) {
+            out.extend(skeleton);
+            start += 1;
+            continue;
+        }
+        let end = bytes[start + 1..].iter().position(|&b| ascii.is_boundary(b)).map_or(bytes.len(), |len| start + 1 + len);
+        if end == start + 1 && bytes[start] < 0x80 {
+            // A lone mapped ASCII character needs no normalization.
+            let c = bytes[start] as char;
+            out.extend(PrototypeCharsIterator::new(c, ascii.table.prototype_of(c)));
+        } else {
+            out.extend(s[start..end].skeleton_chars());
+        }
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsciiSkeletons;
+    use data::{PrototypeTable, PROTOTYPE_INDEX_BITS};
+
+    // In Eytzinger order, with the middle entry first.
+    static ENTRIES: [u32; 3] = [
+        ('0' as u32) << PROTOTYPE_INDEX_BITS,
+        ('%' as u32) << PROTOTYPE_INDEX_BITS | 2,
+        ('m' as u32) << PROTOTYPE_INDEX_BITS | 1,
+    ];
+    static PROTOTYPE_STARTS: [u16; 4] = [0, 1, 3, 4];
+    static PROTOTYPE_CHARS: [char; 4] = ['O', 'r', 'n', 'º'];
+
+    fn table() -> PrototypeTable<'static> {
+        PrototypeTable {
+            entries: &ENTRIES,
+            prototype_starts: &PROTOTYPE_STARTS,
+            prototype_chars: &PROTOTYPE_CHARS,
+            salts: &[],
+            hashed_entries: &[],
+            single_script: false,
+        }
+    }
+
+    #[test]
+    fn classifies_characters() {
+        let ascii = AsciiSkeletons::new(table());
+        assert_eq!(ascii.word_count, 3);
+        assert!(ascii.is_plain(b'a') && ascii.is_boundary(b'a'));
+        assert!(!ascii.is_plain(b'm') && ascii.is_boundary(b'm'));
+        assert!(!ascii.is_plain(b'%') && !ascii.is_boundary(b'%'));
+        assert!(!ascii.is_plain(0xc3) && !ascii.is_boundary(0xc3));
+        assert_eq!(ascii.mapped_skeleton(b'm'), Some(&['r', 'n'][..]));
+        assert_eq!(ascii.mapped_skeleton(b'0'), Some(&['O'][..]));
+        assert_eq!(ascii.mapped_skeleton(b'%'), None);
+        assert_eq!(ascii.mapped_skeleton(b'a'), None);
+    }
+
+    #[test]
+    fn plain_lengths() {
+        let ascii = AsciiSkeletons::new(table());
+        assert_eq!(ascii.plain_len(b""), 0);
+        assert_eq!(ascii.plain_len(b"abc"), 3);
+        assert_eq!(ascii.plain_len(b"abcdefghijklm"), 12);
+        assert_eq!(ascii.plain_len(b"abcdefghijklnopqrstuvwxyz0"), 25);
+        assert_eq!(ascii.plain_len("abcdefgh\u{e9}ijklnop".as_bytes()), 8);
+        assert_eq!(ascii.plain_len("abcdefghijk\u{e9}".as_bytes()), 11);
+        // Mapped characters are found in every position of a word.
+        for i in 0..16 {
+            let mut bytes = vec![b'a'; 16];
+            bytes[i] = b'%';
+            assert_eq!(ascii.plain_len(&bytes), i);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+/// An occurrence of a bidirectional formatting character in a string. This
+/// is retrieved via `bidi_controls`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BidiControl {
+    /// The byte offset of the character.
+    pub byte_offset: usize,
+    /// The character itself.
+    pub control: char,
+    /// Whether the character opens an embedding, override or isolate that is
+    /// not closed on the same line, or closes one that was never opened.
+    /// Unbalanced controls can reorder text beyond where they appear, as in
+    /// the "Trojan Source" attacks of CVE-2021-42574.
+    pub unbalanced: bool,
+}
+
+/// Test whether a character is a bidirectional formatting character: an
+/// embedding, override or isolate initiator or terminator, or a directional
+/// mark.
+pub fn is_bidi_control(c: char) -> bool {
+    matches!(c,
+        '\u{61C}' | '\u{200E}' | '\u{200F}' |
+        '\u{202A}'..='\u{202E}' |
+        '\u{2066}'..='\u{2069}')
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    // LRE, RLE, LRO and RLO, closed by PDF
+    Embedding,
+    // LRI, RLI and FSI, closed by PDI
+    Isolate,
+    PopEmbedding,
+    PopIsolate,
+    Mark,
+}
+
+fn kind(c: char) -> Kind {
+    match c {
+        '\u{202A}' | '\u{202B}' | '\u{202D}' | '\u{202E}' => Kind::Embedding,
+        '\u{2066}' | '\u{2067}' | '\u{2068}' => Kind::Isolate,
+        '\u{202C}' => Kind::PopEmbedding,
+        '\u{2069}' => Kind::PopIsolate,
+        _ => Kind::Mark,
+    }
+}
+
+// Bidi state does not carry across paragraph separators.
+fn is_paragraph_separator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{1C}'..='\u{1E}' | '\u{85}' | '\u{2029}')
+}
+
+/// List the bidirectional formatting characters of a string, with their
+/// positions and whether each is balanced.
+///
+/// Matching follows UAX #9: a PDF closes the innermost embedding or override
+/// if no isolate was opened after it, and a PDI closes the innermost isolate
+/// along with any embeddings inside it, which count as unbalanced. Anything
+/// still open at the end of a line is unbalanced.
+///
+/// ```Rust
+/// let controls = bidi_controls("if (admin\u{202E} \u{2066}// check\u{2069}\u{2066})");
+/// controls.iter().filter(|control| control.unbalanced).count() // 2
+/// ```
+pub fn bidi_controls(s: &str) -> Vec<BidiControl> {
+    let mut controls: Vec<BidiControl> = Vec::new();
+    // Indices into `controls` of the openers not yet closed on this line
+    let mut open: Vec<usize> = Vec::new();
+    for (byte_offset, c) in s.char_indices() {
+        if is_paragraph_separator(c) {
+            open.clear();
+            continue;
+        }
+        if !is_bidi_control(c) {
+            continue;
+        }
+        let index = controls.len();
+        let mut unbalanced = false;
+        match kind(c) {
+            Kind::Embedding | Kind::Isolate => {
+                open.push(index);
+                // Assume unbalanced until closed
+                unbalanced = true;
+            }
+            Kind::PopEmbedding => {
+                match open.last() {
+                    Some(&last) if kind(controls[last].control) == Kind::Embedding => {
+                        controls[last].unbalanced = false;
+                        open.pop();
+                    }
+                    _ => unbalanced = true,
+                }
+            }
+            Kind::PopIsolate => {
+                match open.iter().rposition(|&i| kind(controls[i].control) == Kind::Isolate) {
+                    Some(position) => {
+                        controls[open[position]].unbalanced = false;
+                        open.truncate(position);
+                    }
+                    None => unbalanced = true,
+                }
+            }
+            Kind::Mark => {}
+        }
+        controls.push(BidiControl { byte_offset, control: c, unbalanced });
+    }
+    controls
+}
+
+/// Test whether a string has any unbalanced bidirectional formatting
+/// characters; see `bidi_controls`.
+pub fn has_unbalanced_bidi(s: &str) -> bool {
+    bidi_controls(s).iter().any(|control| control.unbalanced)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{bidi_controls, has_unbalanced_bidi, is_bidi_control, BidiControl};
+
+    fn unbalanced(s: &str) -> Vec<usize> {
+        bidi_controls(s).into_iter().filter(|control| control.unbalanced).map(|control| control.byte_offset).collect()
+    }
+
+    #[test]
+    fn recognizes_controls() {
+        assert!(is_bidi_control('\u{202E}'));
+        assert!(is_bidi_control('\u{2066}'));
+        assert!(is_bidi_control('\u{200F}'));
+        assert!(!is_bidi_control('\u{200B}'));
+        assert!(!is_bidi_control('a'));
+    }
+
+    #[test]
+    fn balanced() {
+        assert_eq!(bidi_controls("a\u{202E}b\u{202C}c"), vec![
+            BidiControl { byte_offset: 1, control: '\u{202E}', unbalanced: false },
+            BidiControl { byte_offset: 5, control: '\u{202C}', unbalanced: false },
+        ]);
+        assert!(!has_unbalanced_bidi("\u{2067}\u{202B}x\u{202C}\u{2069}"));
+        assert!(!has_unbalanced_bidi("a\u{200F}b"));
+        assert!(!has_unbalanced_bidi("plain"));
+    }
+
+    #[test]
+    fn unbalanced_controls() {
+        assert_eq!(unbalanced("a\u{202E}b"), vec![1]);
+        assert_eq!(unbalanced("a\u{202C}b"), vec![1]);
+        assert_eq!(unbalanced("\u{2069}"), vec![0]);
+        // A PDF cannot close an embedding from outside the current isolate.
+        assert_eq!(unbalanced("\u{202B}\u{2066}\u{202C}\u{2069}"), vec![0, 6]);
+        // A PDI implicitly closes embeddings inside its isolate.
+        assert_eq!(unbalanced("\u{2066}\u{202B}\u{2069}"), vec![3]);
+        // Lines are matched separately.
+        assert_eq!(unbalanced("\u{202E}\n\u{202C}"), vec![0, 4]);
+    }
+}
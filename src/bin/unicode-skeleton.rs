@@ -0,0 +1,197 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `unicode-skeleton` command, built with the `cli` feature.
+//!
+//! ```sh
+//! unicode-skeleton skeleton ℝ𝓊𝓈𝓉          # Rust
+//! echo ℝ𝓊𝓈𝓉 | unicode-skeleton skeleton   # Rust, for each line of stdin
+//! unicode-skeleton confusable ℝ𝓊𝓈𝓉 Rust   # true, exiting with 0
+//! unicode-skeleton scan src/*.rs          # findings, exiting with 1 if any
+//! ```
+//!
+//! With `--json`, given before the command, each result is printed as a JSON
+//! object on its own line.
+//! The exit status is 2 for usage and I/O errors.
+
+extern crate unicode_skeleton;
+
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use unicode_skeleton::source_scan::{scan, Context, FindingKind};
+use unicode_skeleton::{confusable, skeleton};
+
+const USAGE: &str = "\
+usage: unicode-skeleton [--json] skeleton [TEXT...]
+       unicode-skeleton [--json] confusable A B
+       unicode-skeleton [--json] scan FILE...
+
+skeleton    Print the skeleton of each TEXT, or of each line of stdin.
+confusable  Print whether A and B are confusable, exiting with 1 if not.
+scan        Report confusable identifiers, bidi controls and invisible
+            characters in source files, exiting with 1 if there are any.";
+
+// Quote a string as a JSON string.
+fn json(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => { let _ = write!(quoted, "\\u{:04x}", c as u32); }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn context_name(context: Context) -> &'static str {
+    match context {
+        Context::Code => "code",
+        Context::Comment => "comment",
+        Context::String => "string",
+    }
+}
+
+fn print_skeleton(out: &mut dyn Write, text: &str, as_json: bool) -> io::Result<()> {
+    if as_json {
+        writeln!(out, "{{\"text\":{},\"skeleton\":{}}}", json(text), json(&skeleton(text)))
+    } else {
+        writeln!(out, "{}", skeleton(text))
+    }
+}
+
+fn run_skeleton(texts: &[String], as_json: bool) -> io::Result<i32> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    if texts.is_empty() {
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            print_skeleton(&mut out, &line?, as_json)?;
+        }
+    } else {
+        for text in texts {
+            print_skeleton(&mut out, text, as_json)?;
+        }
+    }
+    Ok(0)
+}
+
+fn run_confusable(a: &str, b: &str, as_json: bool) -> io::Result<i32> {
+    let result = confusable(a, b);
+    if as_json {
+        println!("{{\"a\":{},\"b\":{},\"confusable\":{}}}", json(a), json(b), result);
+    } else {
+        println!("{}", result);
+    }
+    Ok(if result { 0 } else { 1 })
+}
+
+fn run_scan(paths: &[String], as_json: bool) -> io::Result<i32> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut found = false;
+    for path in paths {
+        let source = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path, e)))?;
+        for finding in scan(&source) {
+            found = true;
+            let code_point = finding.text.chars().next().map_or(0, |c| c as u32);
+            if as_json {
+                let kind = match finding.kind {
+                    FindingKind::ConfusableIdentifier { ref ascii } =>
+                        format!("\"kind\":\"confusable_identifier\",\"ascii\":{}", json(ascii)),
+                    FindingKind::BidiControl { context, unbalanced } =>
+                        format!("\"kind\":\"bidi_control\",\"context\":\"{}\",\"unbalanced\":{}", context_name(context), unbalanced),
+                    FindingKind::Invisible { context } =>
+                        format!("\"kind\":\"invisible\",\"context\":\"{}\"", context_name(context)),
+                };
+                writeln!(out, "{{\"path\":{},\"line\":{},\"column\":{},{},\"text\":{}}}",
+                         json(path), finding.line, finding.column, kind, json(finding.text))?;
+            } else {
+                write!(out, "{}:{}:{}: ", path, finding.line, finding.column)?;
+                match finding.kind {
+                    FindingKind::ConfusableIdentifier { ref ascii } =>
+                        writeln!(out, "identifier {} is confusable with {}", finding.text, ascii)?,
+                    FindingKind::BidiControl { context, unbalanced } =>
+                        writeln!(out, "{}bidi control U+{:04X} in {}",
+                                 if unbalanced { "unbalanced " } else { "" }, code_point, context_name(context))?,
+                    FindingKind::Invisible { context } =>
+                        writeln!(out, "invisible character U+{:04X} in {}", code_point, context_name(context))?,
+                }
+            }
+        }
+    }
+    Ok(if found { 1 } else { 0 })
+}
+
+fn run(args: &[String]) -> Result<i32, String> {
+    // Options come before the command, so that the arguments after it are
+    // taken literally, even when they look like options.
+    let as_json = args.first().is_some_and(|arg| arg == "--json");
+    let args = if as_json { &args[1..] } else { args };
+    let result = match args.split_first() {
+        Some((command, rest)) if command == "skeleton" => run_skeleton(rest, as_json),
+        Some((command, rest)) if command == "confusable" && rest.len() == 2 => run_confusable(&rest[0], &rest[1], as_json),
+        Some((command, rest)) if command == "scan" && !rest.is_empty() => run_scan(rest, as_json),
+        Some((command, _)) if command == "--help" || command == "-h" => {
+            println!("{}", USAGE);
+            return Ok(0);
+        }
+        _ => return Err(USAGE.to_string()),
+    };
+    result.map_err(|e| format!("unicode-skeleton: {}", e))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let status = run(&args).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        2
+    });
+    process::exit(status);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json, run};
+
+    #[test]
+    fn json_strings() {
+        assert_eq!(json("Rust"), "\"Rust\"");
+        assert_eq!(json("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+        assert_eq!(json("ℝ𝓊𝓈𝓉"), "\"ℝ𝓊𝓈𝓉\"");
+    }
+
+    #[test]
+    fn usage_errors() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert!(run(&args(&[])).is_err());
+        assert!(run(&args(&["confusable", "a"])).is_err());
+        assert!(run(&args(&["scan", "--json"])).is_err());
+        assert!(run(&args(&["frobnicate"])).is_err());
+        assert!(run(&args(&["confusable", "--json"])).is_err());
+    }
+
+    #[test]
+    fn arguments_after_the_command_are_literal() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(run(&args(&["confusable", "--json", "--json"])), Ok(0));
+        assert_eq!(run(&args(&["--json", "confusable", "--json", "x"])), Ok(1));
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A string that remembers its skeleton.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+
+use Skeleton;
+
+/// A string whose skeleton is computed the first time it is needed, and then
+/// kept for later comparisons.
+///
+/// Equality, ordering and hashing are by skeleton, so two `CachedSkeleton`s
+/// are equal exactly when their strings are confusable. This makes it
+/// suitable as a key of sets and maps, while still keeping the original
+/// string for display. (Clippy's `mutable_key_type` lint flags such keys
+/// because of the cell inside, but the cell only ever holds the skeleton of
+/// the string, which cannot change.)
+///
+/// # Examples
+/// ```Rust
+/// let name = CachedSkeleton::new("ℝ𝓊𝓈𝓉");
+/// name == CachedSkeleton::new("Rust") // true, computing both skeletons
+/// name.is_confusable_with("Rust") // true, reusing the skeleton of `name`
+/// name.as_str() // "ℝ𝓊𝓈𝓉"
+/// ```
+#[derive(Clone, Default)]
+pub struct CachedSkeleton {
+    s: String,
+    skeleton: OnceLock<Skeleton>,
+}
+
+impl CachedSkeleton {
+    /// Wrap `s`, without computing its skeleton yet.
+    pub fn new<S: Into<String>>(s: S) -> CachedSkeleton {
+        CachedSkeleton { s: s.into(), skeleton: OnceLock::new() }
+    }
+
+    /// The original string.
+    pub fn as_str(&self) -> &str {
+        &self.s
+    }
+
+    /// The skeleton of the string, computing it if this is the first use.
+    pub fn skeleton(&self) -> &Skeleton {
+        self.skeleton.get_or_init(|| Skeleton::of(&self.s))
+    }
+
+    /// Test whether the skeleton has been computed yet.
+    pub fn is_computed(&self) -> bool {
+        self.skeleton.get().is_some()
+    }
+
+    /// Test if `other` is confusable with the string, computing the skeleton
+    /// of `other` but reusing this one's.
+    pub fn is_confusable_with(&self, other: &str) -> bool {
+        *self.skeleton() == Skeleton::of(other)
+    }
+
+    /// Unwrap the original string.
+    pub fn into_string(self) -> String {
+        self.s
+    }
+}
+
+impl From<String> for CachedSkeleton {
+    fn from(s: String) -> CachedSkeleton {
+        CachedSkeleton::new(s)
+    }
+}
+
+impl<'a> From<&'a str> for CachedSkeleton {
+    fn from(s: &'a str) -> CachedSkeleton {
+        CachedSkeleton::new(s)
+    }
+}
+
+impl AsRef<str> for CachedSkeleton {
+    fn as_ref(&self) -> &str {
+        &self.s
+    }
+}
+
+impl PartialEq for CachedSkeleton {
+    fn eq(&self, other: &CachedSkeleton) -> bool {
+        self.skeleton() == other.skeleton()
+    }
+}
+
+impl Eq for CachedSkeleton {}
+
+impl PartialOrd for CachedSkeleton {
+    fn partial_cmp(&self, other: &CachedSkeleton) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by skeleton, consistently with `skeleton_cmp`.
+impl Ord for CachedSkeleton {
+    fn cmp(&self, other: &CachedSkeleton) -> Ordering {
+        self.skeleton().cmp(other.skeleton())
+    }
+}
+
+/// Hashes the skeleton, so that confusable strings hash identically.
+impl Hash for CachedSkeleton {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.skeleton().hash(state);
+    }
+}
+
+/// Shows the original string, and the skeleton if it has been computed.
+impl fmt::Debug for CachedSkeleton {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CachedSkeleton")
+            .field("s", &self.s)
+            .field("skeleton", &self.skeleton.get().map(Skeleton::as_str))
+            .finish()
+    }
+}
+
+/// Shows the original string.
+impl fmt::Display for CachedSkeleton {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedSkeleton;
+    use std::collections::HashSet;
+    use Skeleton;
+
+    #[test]
+    fn computes_once_on_demand() {
+        let name = CachedSkeleton::new("ℝ𝓊𝓈𝓉");
+        assert!(!name.is_computed());
+        assert_eq!(name.to_string(), "ℝ𝓊𝓈𝓉");
+        assert!(!name.is_computed());
+        assert!(name.is_confusable_with("Rust"));
+        assert!(name.is_computed());
+        assert!(!name.is_confusable_with("Rest"));
+        assert_eq!(*name.skeleton(), Skeleton::of("Rust"));
+        assert_eq!(format!("{:?}", name), "CachedSkeleton { s: \"ℝ𝓊𝓈𝓉\", skeleton: Some(\"Rust\") }");
+        assert_eq!(name.into_string(), "ℝ𝓊𝓈𝓉");
+    }
+
+    #[test]
+    fn compares_by_skeleton() {
+        let a = CachedSkeleton::from("pаypаl");
+        let b = CachedSkeleton::from("paypal".to_string());
+        assert_eq!(a, b);
+        assert!(CachedSkeleton::new("abc") < CachedSkeleton::new("abd"));
+
+        // The cell only ever holds the skeleton of the immutable string, so
+        // the hash cannot change.
+        #[allow(clippy::mutable_key_type)]
+        let mut names = HashSet::new();
+        assert!(names.insert(a));
+        assert!(!names.insert(b));
+        assert!(names.contains(&CachedSkeleton::new("𝔭𝒶ỿ𝕡𝕒ℓ")));
+    }
+}
@@ -0,0 +1,1416 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A configurable spoof checker, modeled on ICU's `USpoofChecker`.
+//!
+//! ```Rust
+//! let checker = SpoofChecker::new();
+//! checker.quick_check("pаypаl") // {MixedScript, RestrictionLevel}, the 'а's are Cyrillic
+//! checker.check("pаypаl").findings[0] // Finding { check: Check::MixedScript, text: "а", byte_range: 1..3 }
+//! checker.are_confusable("paypal", "pаypаl") // true
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::iter;
+use std::ops::{BitAnd, BitOr, BitOrAssign, Range, RangeInclusive};
+use std::str::CharIndices;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tinyvec::TinyVec;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::{canonical_combining_class, is_combining_mark};
+
+use bidi::{bidi_controls, is_bidi_control};
+use {confusability, confusable, confusable_leet, whole_script_confusables, ConfusableKind};
+use data::{self, Script};
+use identifier::{identifier_status, identifier_types, IdentifierStatus, IdentifierType};
+use scripts::{dominant_script, is_covered_by, resolved_script_set, ScriptSet};
+
+/// A test that `SpoofChecker` can apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Check {
+    /// Two strings have the same skeleton. This is only consulted by
+    /// `SpoofChecker::are_confusable`.
+    Confusable,
+    /// The string mixes characters from more than one script, as determined
+    /// by its resolved script set. Common and Inherited characters, such as
+    /// digits and combining accents, are compatible with every script.
+    MixedScript,
+    /// The same combining mark appears more than once on one base character
+    /// after NFD, which renders no differently from a single mark.
+    Invisible,
+    /// The string's `RestrictionLevel` is less restrictive than the level
+    /// configured with `SpoofChecker::restriction_level`.
+    RestrictionLevel,
+    /// The string contains decimal digits from more than one numbering
+    /// system; see `is_mixed_number`.
+    MixedNumber,
+    /// The string contains characters outside the set configured with
+    /// `SpoofChecker::allowed_chars`, or outside the scripts configured with
+    /// `SpoofChecker::allow_scripts` and `SpoofChecker::allow_locales`.
+    CharLimit,
+    /// The string contains default ignorable characters, which are normally
+    /// invisible; see `is_default_ignorable`.
+    DefaultIgnorable,
+    /// The string contains bidirectional formatting characters, which can
+    /// visually reorder text; see `bidi_controls`.
+    BidiControl,
+    /// A character carries more combining marks than the `MarkLimits`
+    /// configured with `SpoofChecker::mark_limits` allow, as in "zalgo"
+    /// text; see `excessive_marks`.
+    ExcessiveMarks,
+    /// The string contains variation selectors; see `is_variation_selector`.
+    VariationSelector,
+    /// The string contains tag characters; see `is_tag_character`.
+    TagCharacter,
+    /// The string is written in a script other than Latin, but every one of
+    /// its letters has a Latin lookalike, as with Cyrillic "ѕсоре" for
+    /// "scope"; see `whole_script_confusables`. Such strings pass the other
+    /// checks, since they use a single script. This is off by default, as it
+    /// also catches ordinary words, and on in the `Registrar` and
+    /// `HighSecurity` presets.
+    WholeScriptConfusable,
+}
+
+// In order of discriminant
+const CHECKS: [Check; 12] = [
+    Check::Confusable, Check::MixedScript, Check::Invisible,
+    Check::RestrictionLevel, Check::MixedNumber, Check::CharLimit,
+    Check::DefaultIgnorable, Check::BidiControl, Check::ExcessiveMarks,
+    Check::VariationSelector, Check::TagCharacter, Check::WholeScriptConfusable,
+];
+
+impl Check {
+    /// The name of the check in snake case, such as `"mixed_script"`, as the
+    /// Python and Node.js bindings report it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Check::Confusable => "confusable",
+            Check::MixedScript => "mixed_script",
+            Check::Invisible => "invisible",
+            Check::RestrictionLevel => "restriction_level",
+            Check::MixedNumber => "mixed_number",
+            Check::CharLimit => "char_limit",
+            Check::DefaultIgnorable => "default_ignorable",
+            Check::BidiControl => "bidi_control",
+            Check::ExcessiveMarks => "excessive_marks",
+            Check::VariationSelector => "variation_selector",
+            Check::TagCharacter => "tag_character",
+            Check::WholeScriptConfusable => "whole_script_confusable",
+        }
+    }
+
+    /// The checks whose bits, as in `Checks::bits`, are set in `bits`, as the
+    /// C and JavaScript bindings pass sets of checks. Unknown bits are ignored.
+    #[cfg(any(feature = "ffi", feature = "wasm"))]
+    pub(crate) fn all_in(bits: u32) -> impl Iterator<Item=Check> {
+        Checks::all().iter().filter(move |&check| bits & Checks::from(check).bits() != 0)
+    }
+}
+
+/// A set of checks, such as the checks that a string failed. This is
+/// returned by `SpoofChecker::quick_check`.
+///
+/// ```Rust
+/// let failed = SpoofChecker::new().quick_check("pаypаl");
+/// failed.contains(Checks::MIXED_SCRIPT) // true
+/// failed == Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL // true
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Checks(u32);
+
+impl Checks {
+    /// `Check::Confusable`.
+    pub const CONFUSABLE: Checks = Checks(1 << Check::Confusable as u32);
+    /// `Check::MixedScript`.
+    pub const MIXED_SCRIPT: Checks = Checks(1 << Check::MixedScript as u32);
+    /// `Check::Invisible`.
+    pub const INVISIBLE: Checks = Checks(1 << Check::Invisible as u32);
+    /// `Check::RestrictionLevel`.
+    pub const RESTRICTION_LEVEL: Checks = Checks(1 << Check::RestrictionLevel as u32);
+    /// `Check::MixedNumber`.
+    pub const MIXED_NUMBER: Checks = Checks(1 << Check::MixedNumber as u32);
+    /// `Check::CharLimit`.
+    pub const CHAR_LIMIT: Checks = Checks(1 << Check::CharLimit as u32);
+    /// `Check::DefaultIgnorable`.
+    pub const DEFAULT_IGNORABLE: Checks = Checks(1 << Check::DefaultIgnorable as u32);
+    /// `Check::BidiControl`.
+    pub const BIDI_CONTROL: Checks = Checks(1 << Check::BidiControl as u32);
+    /// `Check::ExcessiveMarks`.
+    pub const EXCESSIVE_MARKS: Checks = Checks(1 << Check::ExcessiveMarks as u32);
+    /// `Check::VariationSelector`.
+    pub const VARIATION_SELECTOR: Checks = Checks(1 << Check::VariationSelector as u32);
+    /// `Check::TagCharacter`.
+    pub const TAG_CHARACTER: Checks = Checks(1 << Check::TagCharacter as u32);
+    /// `Check::WholeScriptConfusable`.
+    pub const WHOLE_SCRIPT_CONFUSABLE: Checks = Checks(1 << Check::WholeScriptConfusable as u32);
+
+    /// The set of no checks.
+    pub fn empty() -> Checks {
+        Checks(0)
+    }
+
+    /// The set of every check.
+    pub fn all() -> Checks {
+        CHECKS.iter().map(|&check| Checks::from(check)).fold(Checks::empty(), BitOr::bitor)
+    }
+
+    /// The raw bits of the set.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Test whether every check in `other` is in this set.
+    pub fn contains(&self, other: Checks) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Test whether the set contains no checks.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Add the checks of `other` to the set.
+    pub fn insert(&mut self, other: Checks) {
+        self.0 |= other.0;
+    }
+
+    /// Remove the checks of `other` from the set.
+    pub fn remove(&mut self, other: Checks) {
+        self.0 &= !other.0;
+    }
+
+    /// Iterate over the checks in the set, in the order they are declared in
+    /// `Check`.
+    pub fn iter(&self) -> ChecksIter {
+        ChecksIter { checks: *self, next: 0 }
+    }
+}
+
+impl From<Check> for Checks {
+    fn from(check: Check) -> Checks {
+        Checks(1 << check as u32)
+    }
+}
+
+impl BitOr for Checks {
+    type Output = Checks;
+
+    fn bitor(self, other: Checks) -> Checks {
+        Checks(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Checks {
+    fn bitor_assign(&mut self, other: Checks) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAnd for Checks {
+    type Output = Checks;
+
+    fn bitand(self, other: Checks) -> Checks {
+        Checks(self.0 & other.0)
+    }
+}
+
+impl IntoIterator for Checks {
+    type Item = Check;
+    type IntoIter = ChecksIter;
+
+    fn into_iter(self) -> ChecksIter {
+        self.iter()
+    }
+}
+
+/// With the `serde` feature, a set of checks serializes as a sequence of
+/// `Check`s.
+#[cfg(feature = "serde")]
+impl Serialize for Checks {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Checks {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Checks, D::Error> {
+        let mut checks = Checks::empty();
+        for check in Vec::<Check>::deserialize(deserializer)? {
+            checks |= check.into();
+        }
+        Ok(checks)
+    }
+}
+
+impl fmt::Debug for Checks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the checks of a `Checks` set. This is retrieved via
+/// `Checks::iter`.
+#[derive(Clone, Debug)]
+pub struct ChecksIter {
+    checks: Checks,
+    next: usize,
+}
+
+impl Iterator for ChecksIter {
+    type Item = Check;
+
+    fn next(&mut self) -> Option<Check> {
+        while self.next < CHECKS.len() {
+            let check = CHECKS[self.next];
+            self.next += 1;
+            if self.checks.contains(check.into()) {
+                return Some(check);
+            }
+        }
+        None
+    }
+}
+
+/// The outcome of `SpoofChecker::check`: which checks failed, and the parts
+/// of the string responsible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpoofReport<'a> {
+    /// The checks that failed.
+    pub failed: Checks,
+    /// The characters or combining sequences that caused checks to fail, in
+    /// order of check and then of position.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub findings: Vec<Finding<'a>>,
+}
+
+impl<'a> SpoofReport<'a> {
+    /// Test whether every check passed.
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// The findings of one check.
+    pub fn findings_for(&self, check: Check) -> Vec<&Finding<'a>> {
+        self.findings.iter().filter(|finding| finding.check == check).collect()
+    }
+}
+
+/// A part of a string that caused a check to fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Finding<'a> {
+    /// The check that failed.
+    pub check: Check,
+    /// The offending text. It is borrowed from the checked string, and only
+    /// owned when deserialized from text that escapes it.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub text: Cow<'a, str>,
+    /// The location of `text` in the checked string.
+    pub byte_range: Range<usize>,
+}
+
+/// How freely a string mixes scripts, as defined in UTS #39 section 5.2.
+/// Levels are ordered from most to least restrictive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RestrictionLevel {
+    /// Every character is printable ASCII.
+    AsciiOnly,
+    /// Every character is allowed and the string uses a single script.
+    SingleScript,
+    /// Every character is allowed and the string uses a single script, or
+    /// one of the combinations Latin + Han + Hiragana + Katakana,
+    /// Latin + Han + Bopomofo, or Latin + Han + Hangul.
+    HighlyRestrictive,
+    /// Every character is allowed and the string uses Latin plus at most one
+    /// other Recommended script, which may not be Cyrillic or Greek.
+    ModeratelyRestrictive,
+    /// Every character is allowed, in any mix of scripts.
+    MinimallyRestrictive,
+    /// Some character is not allowed.
+    Unrestricted,
+}
+
+impl RestrictionLevel {
+    /// The level numbered `level` by the C and JavaScript bindings, from 0
+    /// for `AsciiOnly` to 5 for `Unrestricted`.
+    #[cfg(any(feature = "ffi", feature = "wasm"))]
+    pub(crate) fn from_number(level: i64) -> Option<RestrictionLevel> {
+        match level {
+            0 => Some(RestrictionLevel::AsciiOnly),
+            1 => Some(RestrictionLevel::SingleScript),
+            2 => Some(RestrictionLevel::HighlyRestrictive),
+            3 => Some(RestrictionLevel::ModeratelyRestrictive),
+            4 => Some(RestrictionLevel::MinimallyRestrictive),
+            5 => Some(RestrictionLevel::Unrestricted),
+            _ => None,
+        }
+    }
+}
+
+const HIGHLY_RESTRICTIVE_COMBINATIONS: [&[Script]; 3] = [
+    &[Script::Latin, Script::Han, Script::Hiragana, Script::Katakana],
+    &[Script::Latin, Script::Han, Script::Bopomofo],
+    &[Script::Latin, Script::Han, Script::Hangul],
+];
+
+// The Recommended scripts of UAX #31 table 5, other than Latin, Cyrillic and
+// Greek, which may accompany Latin at the moderately restrictive level.
+const MODERATELY_RESTRICTIVE_SCRIPTS: [Script; 26] = [
+    Script::Arabic, Script::Armenian, Script::Bengali, Script::Bopomofo,
+    Script::Devanagari, Script::Ethiopic, Script::Georgian, Script::Gujarati,
+    Script::Gurmukhi, Script::Han, Script::Hangul, Script::Hebrew,
+    Script::Hiragana, Script::Kannada, Script::Katakana, Script::Khmer,
+    Script::Lao, Script::Malayalam, Script::Myanmar, Script::Oriya,
+    Script::Sinhala, Script::Tamil, Script::Telugu, Script::Thaana,
+    Script::Thai, Script::Tibetan,
+];
+
+/// Compute the restriction level of a string. `allowed` decides which
+/// characters belong to the identifier profile; any character it rejects
+/// makes the string `Unrestricted`.
+///
+/// ```Rust
+/// restriction_level("paypal", |_| true) // RestrictionLevel::AsciiOnly
+/// restriction_level("pаypаl", |_| true) // RestrictionLevel::MinimallyRestrictive
+/// ```
+pub fn restriction_level<F: Fn(char) -> bool>(s: &str, allowed: F) -> RestrictionLevel {
+    if !s.chars().all(allowed) {
+        return RestrictionLevel::Unrestricted;
+    }
+    if s.chars().all(|c| (' '..='~').contains(&c)) {
+        return RestrictionLevel::AsciiOnly;
+    }
+    if !resolved_script_set(s).is_empty() {
+        return RestrictionLevel::SingleScript;
+    }
+    if HIGHLY_RESTRICTIVE_COMBINATIONS.iter().any(|&scripts| is_covered_by(s, ScriptSet::of_scripts(scripts))) {
+        return RestrictionLevel::HighlyRestrictive;
+    }
+    if MODERATELY_RESTRICTIVE_SCRIPTS.iter().any(|&script| is_covered_by(s, ScriptSet::of_scripts(&[Script::Latin, script]))) {
+        return RestrictionLevel::ModeratelyRestrictive;
+    }
+    RestrictionLevel::MinimallyRestrictive
+}
+
+/// Test whether a string contains decimal digits from more than one
+/// numbering system, such as Bengali '৪', which resembles '8', alongside
+/// ASCII digits. This is the mixed-number detection of UTS #39 section 5.3.
+///
+/// ```Rust
+/// is_mixed_number("1৪5") // true
+/// is_mixed_number("৪৫") // false
+/// ```
+pub fn is_mixed_number(s: &str) -> bool {
+    let mut zeros = s.chars().filter_map(digit_zero);
+    match zeros.next() {
+        Some(first) => zeros.any(|zero| zero != first),
+        None => false,
+    }
+}
+
+// The zero of the sequence of ten decimal digits that a character belongs to.
+fn digit_zero(c: char) -> Option<u32> {
+    data::range_containing(&data::DECIMAL_DIGIT_RANGES, c).map(|(start, _)| c as u32 - (c as u32 - start) % 10)
+}
+
+/// How many combining marks a single character may carry before
+/// `excessive_marks` flags it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarkLimits {
+    /// The most combining marks a character may carry, after NFD.
+    pub max_marks: usize,
+    /// The most combining marks a character may carry in the same position,
+    /// such as above or below it. Marks stacked beyond this grow into the
+    /// lines and glyphs around the character.
+    pub max_stacked: usize,
+}
+
+/// The default limits allow four marks, at most two in any one position,
+/// which is enough for every letter of the world's orthographies that is in
+/// common use.
+impl Default for MarkLimits {
+    fn default() -> MarkLimits {
+        MarkLimits { max_marks: 4, max_stacked: 2 }
+    }
+}
+
+/// Find the combining sequences of a string, each a character along with the
+/// combining marks that follow it, that carry more marks than `limits`
+/// allows. These are used in "zalgo" text to obscure or impersonate names.
+///
+/// ```Rust
+/// excessive_marks("ok z\u{30D}\u{30E}\u{304}\u{305}\u{33F}", MarkLimits::default()) // [3..14]
+/// ```
+pub fn excessive_marks(s: &str, limits: MarkLimits) -> Vec<Range<usize>> {
+    CombiningSequences::new(s).filter(|range| exceeds_mark_limits(&s[range.clone()], limits)).collect()
+}
+
+fn exceeds_mark_limits(sequence: &str, limits: MarkLimits) -> bool {
+    let mut marks = 0;
+    // Counts of marks by nonzero combining class
+    let mut positions: TinyVec<[(u8, usize); 4]> = TinyVec::new();
+    for c in sequence.nfd().filter(|&c| is_combining_mark(c)) {
+        marks += 1;
+        let class = canonical_combining_class(c);
+        if class == 0 {
+            continue;
+        }
+        let stacked = match positions.iter_mut().find(|position| position.0 == class) {
+            Some(position) => { position.1 += 1; position.1 }
+            None => { positions.push((class, 1)); 1 }
+        };
+        if stacked > limits.max_stacked {
+            return true;
+        }
+    }
+    marks > limits.max_marks
+}
+
+/// Test whether a character is a variation selector, U+FE00 to U+FE0F or
+/// U+E0100 to U+E01EF. These select a glyph variant of the preceding
+/// character, such as emoji or text presentation.
+pub fn is_variation_selector(c: char) -> bool {
+    matches!(c, '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}')
+}
+
+/// Test whether a character is in the Tags block, U+E0000 to U+E007F. Tag
+/// characters mirror ASCII invisibly, so they can smuggle a hidden payload
+/// through text that looks innocent.
+pub fn is_tag_character(c: char) -> bool {
+    matches!(c, '\u{E0000}'..='\u{E007F}')
+}
+
+/// Remove the variation selectors and tag characters from a string. Apply
+/// this before taking skeletons to compare strings regardless of them.
+///
+/// ```Rust
+/// strip_variation_and_tags("a\u{FE0F}b\u{E0061}") // "ab"
+/// ```
+pub fn strip_variation_and_tags<'a>(s: &'a str) -> Cow<'a, str> {
+    let hidden = |c: char| is_variation_selector(c) || is_tag_character(c);
+    if s.chars().any(hidden) {
+        Cow::Owned(s.chars().filter(|&c| !hidden(c)).collect())
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+/// Test whether a character has the Default_Ignorable_Code_Point property.
+/// These characters, such as zero width spaces and joiners, soft hyphens and
+/// byte order marks, are normally rendered invisibly.
+pub fn is_default_ignorable(c: char) -> bool {
+    data::range_containing(&data::DEFAULT_IGNORABLE_RANGES, c).is_some()
+}
+
+/// The default ignorable characters of a string, with their byte offsets.
+/// Skeletons keep these characters, so "admin\u{200B}" is not confusable
+/// with "admin" even though the two look the same.
+///
+/// ```Rust
+/// default_ignorables("admin\u{200B}") // [(5, '\u{200B}')]
+/// ```
+pub fn default_ignorables(s: &str) -> Vec<(usize, char)> {
+    s.char_indices().filter(|&(_, c)| is_default_ignorable(c)).collect()
+}
+
+// The scripts used to write each language, keyed by language subtag.
+const LANGUAGE_SCRIPTS: [(&str, &[Script]); 71] = [
+    ("am", &[Script::Ethiopic]), ("ar", &[Script::Arabic]), ("be", &[Script::Cyrillic]),
+    ("bg", &[Script::Cyrillic]), ("bn", &[Script::Bengali]), ("ca", &[Script::Latin]),
+    ("cs", &[Script::Latin]), ("cy", &[Script::Latin]), ("da", &[Script::Latin]),
+    ("de", &[Script::Latin]), ("el", &[Script::Greek]), ("en", &[Script::Latin]),
+    ("es", &[Script::Latin]), ("et", &[Script::Latin]), ("eu", &[Script::Latin]),
+    ("fa", &[Script::Arabic]), ("fi", &[Script::Latin]), ("fil", &[Script::Latin]),
+    ("fr", &[Script::Latin]), ("ga", &[Script::Latin]), ("gl", &[Script::Latin]),
+    ("gu", &[Script::Gujarati]), ("he", &[Script::Hebrew]), ("hi", &[Script::Devanagari]),
+    ("hr", &[Script::Latin]), ("hu", &[Script::Latin]), ("hy", &[Script::Armenian]),
+    ("id", &[Script::Latin]), ("is", &[Script::Latin]), ("it", &[Script::Latin]),
+    ("ja", &[Script::Han, Script::Hiragana, Script::Katakana]), ("ka", &[Script::Georgian]),
+    ("kk", &[Script::Cyrillic]), ("km", &[Script::Khmer]), ("kn", &[Script::Kannada]),
+    ("ko", &[Script::Hangul, Script::Han]), ("lo", &[Script::Lao]), ("lt", &[Script::Latin]),
+    ("lv", &[Script::Latin]), ("mk", &[Script::Cyrillic]), ("ml", &[Script::Malayalam]),
+    ("mn", &[Script::Cyrillic]), ("mr", &[Script::Devanagari]), ("ms", &[Script::Latin]),
+    ("my", &[Script::Myanmar]), ("nb", &[Script::Latin]), ("ne", &[Script::Devanagari]),
+    ("nl", &[Script::Latin]), ("no", &[Script::Latin]), ("or", &[Script::Oriya]),
+    ("pa", &[Script::Gurmukhi]), ("pl", &[Script::Latin]), ("pt", &[Script::Latin]),
+    ("ro", &[Script::Latin]), ("ru", &[Script::Cyrillic]), ("si", &[Script::Sinhala]),
+    ("sk", &[Script::Latin]), ("sl", &[Script::Latin]), ("sq", &[Script::Latin]),
+    ("sr", &[Script::Cyrillic]), ("sv", &[Script::Latin]), ("sw", &[Script::Latin]),
+    ("ta", &[Script::Tamil]), ("te", &[Script::Telugu]), ("th", &[Script::Thai]),
+    ("tr", &[Script::Latin]), ("uk", &[Script::Cyrillic]), ("ur", &[Script::Arabic]),
+    ("uz", &[Script::Latin]), ("vi", &[Script::Latin]), ("zh", &[Script::Han, Script::Bopomofo]),
+];
+
+/// A character property that `AllowedSet` can admit characters by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CharProperty {
+    /// Characters with the Alphabetic property.
+    Alphabetic,
+    /// Characters with the Lowercase property.
+    Lowercase,
+    /// Characters with the Uppercase property.
+    Uppercase,
+    /// Characters of a numeric general category.
+    Numeric,
+    /// Decimal digits of any numbering system.
+    DecimalDigit,
+    /// Characters with the White_Space property.
+    Whitespace,
+    /// Characters allowed by the General Security Profile for identifiers.
+    IdentifierAllowed,
+    /// Characters with the given identifier type.
+    IdentifierType(IdentifierType),
+}
+
+impl CharProperty {
+    fn matches(&self, c: char) -> bool {
+        match *self {
+            CharProperty::Alphabetic => c.is_alphabetic(),
+            CharProperty::Lowercase => c.is_lowercase(),
+            CharProperty::Uppercase => c.is_uppercase(),
+            CharProperty::Numeric => c.is_numeric(),
+            CharProperty::DecimalDigit => digit_zero(c).is_some(),
+            CharProperty::Whitespace => c.is_whitespace(),
+            CharProperty::IdentifierAllowed => identifier_status(c) == IdentifierStatus::Allowed,
+            CharProperty::IdentifierType(identifier_type) => identifier_types(c).contains(identifier_type),
+        }
+    }
+}
+
+/// A set of characters that strings are permitted to contain, built up from
+/// ranges, properties and explicit characters.
+///
+/// ```Rust
+/// let registry = AllowedSet::new().range('a'..='z').range('0'..='9').chars("-");
+/// registry.disallowed("my-nаme") // [(4, 'а')]
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AllowedSet {
+    ranges: Vec<RangeInclusive<char>>,
+    properties: Vec<CharProperty>,
+}
+
+impl AllowedSet {
+    /// Create an empty set.
+    pub fn new() -> AllowedSet {
+        AllowedSet { ranges: Vec::new(), properties: Vec::new() }
+    }
+
+    /// Admit every character in a range.
+    pub fn range(mut self, range: RangeInclusive<char>) -> AllowedSet {
+        self.ranges.push(range);
+        self
+    }
+
+    /// Admit every character of a string.
+    pub fn chars(mut self, chars: &str) -> AllowedSet {
+        self.ranges.extend(chars.chars().map(|c| c..=c));
+        self
+    }
+
+    /// Admit every character with a property.
+    pub fn property(mut self, property: CharProperty) -> AllowedSet {
+        self.properties.push(property);
+        self
+    }
+
+    /// Test whether a character is in the set.
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|range| range.contains(&c)) ||
+            self.properties.iter().any(|property| property.matches(c))
+    }
+
+    /// The characters of a string that are not in the set, with their byte
+    /// offsets.
+    pub fn disallowed(&self, s: &str) -> Vec<(usize, char)> {
+        s.char_indices().filter(|&(_, c)| !self.contains(c)).collect()
+    }
+}
+
+/// A ready-made policy for `SpoofChecker::preset`, bundling a restriction
+/// level, the characters and scripts allowed, and which confusable pairs to
+/// report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Preset {
+    /// For free-form names, such as display names, which may mix scripts:
+    /// every character and mix of scripts is allowed, and confusables within
+    /// one script, such as "rn" and "m", are not reported. Invisible and
+    /// reordering characters are still caught.
+    Permissive,
+    /// For registries of user names or domain labels: only characters
+    /// allowed by the General Security Profile, at the highly restrictive
+    /// level, with every kind of confusable reported and intentionally
+    /// confusable variants, such as Cyrillic "а" for Latin "a", scored 1.0.
+    Registrar,
+    /// Like `Registrar`, but requiring a single script, as ICANN's guidelines
+    /// for IDN registries do, and also reporting ASCII lookalikes such as
+    /// "g00gle" for "google".
+    HighSecurity,
+}
+
+/// Runs a configurable set of UTS #39 checks against strings.
+///
+/// All checks but `WholeScriptConfusable` are enabled by default, with a
+/// restriction level of `HighlyRestrictive`.
+///
+/// With the `serde` feature, the configuration of a checker can be
+/// serialized, for example to share a policy between services as JSON.
+/// Settings missing when deserializing take their defaults.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SpoofChecker {
+    enabled: Checks,
+    restriction_level: RestrictionLevel,
+    allowed: Option<AllowedSet>,
+    allowed_scripts: Option<ScriptSet>,
+    mark_limits: MarkLimits,
+    escalate_intentional: bool,
+    leetspeak: bool,
+    confusable_kinds: Option<Vec<ConfusableKind>>,
+}
+
+impl Default for SpoofChecker {
+    fn default() -> SpoofChecker {
+        SpoofChecker::new()
+    }
+}
+
+impl SpoofChecker {
+    /// Create a checker with every check but `WholeScriptConfusable` enabled.
+    pub fn new() -> SpoofChecker {
+        let mut enabled = Checks::all();
+        enabled.remove(Checks::WHOLE_SCRIPT_CONFUSABLE);
+        SpoofChecker {
+            enabled,
+            restriction_level: RestrictionLevel::HighlyRestrictive,
+            allowed: None,
+            allowed_scripts: None,
+            mark_limits: MarkLimits::default(),
+            escalate_intentional: false,
+            leetspeak: false,
+            confusable_kinds: None,
+        }
+    }
+
+    /// Create a checker configured by a preset policy. Settings can still be
+    /// changed afterwards, such as to permit the scripts a registry serves
+    /// with `allow_locales`.
+    pub fn preset(preset: Preset) -> SpoofChecker {
+        match preset {
+            Preset::Permissive => SpoofChecker::new()
+                .disable(Check::MixedScript)
+                .restriction_level(RestrictionLevel::MinimallyRestrictive)
+                .confusable_kinds(&[ConfusableKind::Identical, ConfusableKind::MixedScript, ConfusableKind::WholeScript]),
+            Preset::Registrar => SpoofChecker::new()
+                .enable(Check::WholeScriptConfusable)
+                .allowed_chars(AllowedSet::new().property(CharProperty::IdentifierAllowed))
+                .escalate_intentional(true),
+            Preset::HighSecurity => SpoofChecker::new()
+                .enable(Check::WholeScriptConfusable)
+                .allowed_chars(AllowedSet::new().property(CharProperty::IdentifierAllowed))
+                .restriction_level(RestrictionLevel::SingleScript)
+                .escalate_intentional(true)
+                .leetspeak(true),
+        }
+    }
+
+    /// Turn on a check.
+    pub fn enable(mut self, check: Check) -> SpoofChecker {
+        self.enabled.insert(check.into());
+        self
+    }
+
+    /// Turn off a check.
+    pub fn disable(mut self, check: Check) -> SpoofChecker {
+        self.enabled.remove(check.into());
+        self
+    }
+
+    /// Set the least restrictive level that `Check::RestrictionLevel` accepts.
+    pub fn restriction_level(mut self, level: RestrictionLevel) -> SpoofChecker {
+        self.restriction_level = level;
+        self
+    }
+
+    /// Set the limits on combining marks that `Check::ExcessiveMarks`
+    /// enforces. `MarkLimits::default()` is used until this is called.
+    pub fn mark_limits(mut self, limits: MarkLimits) -> SpoofChecker {
+        self.mark_limits = limits;
+        self
+    }
+
+    /// Give pairs of strings that differ only by intentionally confusable
+    /// characters, such as Latin 'a' and Cyrillic 'а', the highest score of
+    /// 1.0 in `confusable_score`. Registrar policies often block such variants
+    /// outright, while sending other confusables for review. Off by default.
+    /// See `is_intentionally_confusable` for where the pairs come from.
+    pub fn escalate_intentional(mut self, escalate: bool) -> SpoofChecker {
+        self.escalate_intentional = escalate;
+        self
+    }
+
+    /// Also treat strings as confusable in `are_confusable` if they only
+    /// differ by case or by ASCII lookalikes such as "0" for "o", as in
+    /// `confusable_leet`. This suits aggressive vetting of usernames and
+    /// domain names. Off by default.
+    pub fn leetspeak(mut self, leetspeak: bool) -> SpoofChecker {
+        self.leetspeak = leetspeak;
+        self
+    }
+
+    /// Only treat pairs of strings as confusable in `are_confusable` and
+    /// `confusable_score` if they are confusable in one of the given ways,
+    /// as with ICU's single-script, mixed-script and whole-script checks.
+    /// Until this is called, every kind is reported. Pairs confusable only
+    /// through `leetspeak` are reported regardless.
+    pub fn confusable_kinds(mut self, kinds: &[ConfusableKind]) -> SpoofChecker {
+        self.confusable_kinds = Some(kinds.to_vec());
+        self
+    }
+
+    /// Restrict strings to the characters of a set. Until this is called,
+    /// every character is allowed. The set is also the identifier profile
+    /// that restriction levels are computed against.
+    pub fn allowed_chars(mut self, allowed: AllowedSet) -> SpoofChecker {
+        self.allowed = Some(allowed);
+        self
+    }
+
+    /// Permit characters of the given scripts. Until this or `allow_locales`
+    /// is called, every script is permitted; afterwards, only characters of
+    /// permitted scripts, or of Common and Inherited, pass `Check::CharLimit`.
+    /// Repeated calls add to the permitted scripts.
+    pub fn allow_scripts(mut self, scripts: &[Script]) -> SpoofChecker {
+        self.allowed_scripts.get_or_insert_with(ScriptSet::empty).union_with(ScriptSet::of_scripts(scripts));
+        self
+    }
+
+    /// Permit the scripts used to write the languages of the given locales,
+    /// such as `"en-US"` or `"ja"`. Locales are matched by their language
+    /// subtag; unrecognized languages add no scripts.
+    pub fn allow_locales(self, locales: &[&str]) -> SpoofChecker {
+        let mut checker = self.allow_scripts(&[]);
+        for locale in locales {
+            let language = locale.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+            if let Ok(index) = LANGUAGE_SCRIPTS.binary_search_by_key(&language.as_str(), |entry| entry.0) {
+                checker = checker.allow_scripts(LANGUAGE_SCRIPTS[index].1);
+            }
+        }
+        checker
+    }
+
+    /// Test whether a check is enabled.
+    pub fn is_enabled(&self, check: Check) -> bool {
+        self.enabled.contains(check.into())
+    }
+
+    /// Run the enabled single-string checks, reporting the ones that failed
+    /// along with the characters responsible:
+    ///
+    /// * `MixedScript`: the characters not written in the string's dominant
+    ///   script.
+    /// * `Invisible`: the combining sequences with a repeated mark.
+    /// * `RestrictionLevel`: the characters outside the allowed set, or if
+    ///   there are none, those not written in the dominant script.
+    /// * `MixedNumber`: the digits from a different numbering system than
+    ///   the first digit.
+    /// * `CharLimit`: the characters outside the allowed set or scripts.
+    /// * `DefaultIgnorable`: the default ignorable characters.
+    /// * `BidiControl`: the bidirectional formatting characters.
+    /// * `ExcessiveMarks`: the combining sequences with too many marks.
+    /// * `VariationSelector` and `TagCharacter`: the characters themselves.
+    /// * `WholeScriptConfusable`: the whole string.
+    pub fn check<'a>(&self, s: &'a str) -> SpoofReport<'a> {
+        let failed = self.quick_check(s);
+        let mut findings = Vec::new();
+        for check in failed.iter() {
+            let ranges = match check {
+                Check::MixedScript => script_outliers(s),
+                Check::Invisible => repeated_mark_sequences(s),
+                Check::RestrictionLevel => {
+                    let disallowed = self.disallowed_ranges(s);
+                    if disallowed.is_empty() { script_outliers(s) } else { disallowed }
+                }
+                Check::MixedNumber => mixed_digits(s),
+                Check::CharLimit => self.disallowed_ranges(s),
+                Check::DefaultIgnorable => default_ignorables(s).into_iter().map(|(i, c)| i..i + c.len_utf8()).collect(),
+                Check::BidiControl => bidi_controls(s).into_iter()
+                    .map(|control| control.byte_offset..control.byte_offset + control.control.len_utf8())
+                    .collect(),
+                Check::ExcessiveMarks => excessive_marks(s, self.mark_limits),
+                Check::VariationSelector => char_ranges(s, is_variation_selector),
+                Check::TagCharacter => char_ranges(s, is_tag_character),
+                Check::WholeScriptConfusable => iter::once(0..s.len()).collect(),
+                Check::Confusable => Vec::new(),
+            };
+            findings.extend(ranges.into_iter().map(|byte_range| Finding {
+                check,
+                text: Cow::Borrowed(&s[byte_range.clone()]),
+                byte_range,
+            }));
+        }
+        SpoofReport { failed, findings }
+    }
+
+    /// Run the enabled single-string checks, returning the set that failed,
+    /// without allocating.
+    pub fn quick_check(&self, s: &str) -> Checks {
+        let mut failed = Checks::empty();
+        if self.is_enabled(Check::MixedScript) && resolved_script_set(s).is_empty() {
+            failed |= Checks::MIXED_SCRIPT;
+        }
+        if self.is_enabled(Check::Invisible) && has_repeated_mark(s) {
+            failed |= Checks::INVISIBLE;
+        }
+        if self.is_enabled(Check::RestrictionLevel) && restriction_level(s, |c| self.allows(c)) > self.restriction_level {
+            failed |= Checks::RESTRICTION_LEVEL;
+        }
+        if self.is_enabled(Check::MixedNumber) && is_mixed_number(s) {
+            failed |= Checks::MIXED_NUMBER;
+        }
+        if self.is_enabled(Check::CharLimit) && !s.chars().all(|c| self.allows(c)) {
+            failed |= Checks::CHAR_LIMIT;
+        }
+        if self.is_enabled(Check::DefaultIgnorable) && s.chars().any(is_default_ignorable) {
+            failed |= Checks::DEFAULT_IGNORABLE;
+        }
+        if self.is_enabled(Check::BidiControl) && s.chars().any(is_bidi_control) {
+            failed |= Checks::BIDI_CONTROL;
+        }
+        if self.is_enabled(Check::ExcessiveMarks) &&
+            CombiningSequences::new(s).any(|range| exceeds_mark_limits(&s[range], self.mark_limits)) {
+            failed |= Checks::EXCESSIVE_MARKS;
+        }
+        if self.is_enabled(Check::VariationSelector) && s.chars().any(is_variation_selector) {
+            failed |= Checks::VARIATION_SELECTOR;
+        }
+        if self.is_enabled(Check::TagCharacter) && s.chars().any(is_tag_character) {
+            failed |= Checks::TAG_CHARACTER;
+        }
+        if self.is_enabled(Check::WholeScriptConfusable) && whole_script_confusables(s).contains(Script::Latin) {
+            failed |= Checks::WHOLE_SCRIPT_CONFUSABLE;
+        }
+        failed
+    }
+
+    /// The characters of a string that are outside the allowed set or the
+    /// permitted scripts, with their byte offsets.
+    pub fn disallowed_chars(&self, s: &str) -> Vec<(usize, char)> {
+        s.char_indices().filter(|&(_, c)| !self.allows(c)).collect()
+    }
+
+    fn disallowed_ranges(&self, s: &str) -> Vec<Range<usize>> {
+        self.disallowed_chars(s).into_iter().map(|(i, c)| i..i + c.len_utf8()).collect()
+    }
+
+    fn allows(&self, c: char) -> bool {
+        self.allowed.as_ref().is_none_or(|allowed| allowed.contains(c)) &&
+            self.allowed_scripts.is_none_or(|scripts| ScriptSet::of_char(c).intersects(scripts))
+    }
+
+    /// Test whether two strings are confusable. Always returns `false` when
+    /// `Check::Confusable` is disabled.
+    pub fn are_confusable(&self, a: &str, b: &str) -> bool {
+        self.is_enabled(Check::Confusable) && (self.reports(a, b) || (self.leetspeak && confusable_leet(a, b)))
+    }
+
+    fn reports(&self, a: &str, b: &str) -> bool {
+        match self.confusable_kinds {
+            Some(ref kinds) => confusability(a, b).is_some_and(|result| kinds.contains(&result.kind)),
+            None => confusable(a, b),
+        }
+    }
+
+    /// Score how suspicious a pair of confusable strings is, from 0.0 to 1.0,
+    /// as with `Confusability::score`, but with intentionally confusable pairs
+    /// scoring 1.0 if `escalate_intentional` is on. Returns `None` if the
+    /// strings are not confusable in one of the `confusable_kinds`, or
+    /// `Check::Confusable` is disabled.
+    pub fn confusable_score(&self, a: &str, b: &str) -> Option<f64> {
+        if !self.is_enabled(Check::Confusable) {
+            return None;
+        }
+        let kinds = &self.confusable_kinds;
+        confusability(a, b).filter(|result| kinds.as_ref().is_none_or(|kinds| kinds.contains(&result.kind))).map(|result| {
+            if self.escalate_intentional && result.intentional { 1.0 } else { result.score() }
+        })
+    }
+}
+
+fn char_ranges<F: Fn(char) -> bool>(s: &str, predicate: F) -> Vec<Range<usize>> {
+    s.char_indices().filter(|&(_, c)| predicate(c)).map(|(i, c)| i..i + c.len_utf8()).collect()
+}
+
+fn script_outliers(s: &str) -> Vec<Range<usize>> {
+    let dominant = match dominant_script(s) {
+        Some(dominant) => dominant,
+        None => return Vec::new(),
+    };
+    s.char_indices()
+        .filter(|&(_, c)| !ScriptSet::of_char(c).contains(dominant))
+        .map(|(i, c)| i..i + c.len_utf8())
+        .collect()
+}
+
+fn mixed_digits(s: &str) -> Vec<Range<usize>> {
+    let mut first_zero = None;
+    let mut ranges = Vec::new();
+    for (i, c) in s.char_indices() {
+        if let Some(zero) = digit_zero(c) {
+            if *first_zero.get_or_insert(zero) != zero {
+                ranges.push(i..i + c.len_utf8());
+            }
+        }
+    }
+    ranges
+}
+
+// Each combining sequence, a character that is not a combining mark along
+// with the marks that follow it, that has a repeated mark.
+fn repeated_mark_sequences(s: &str) -> Vec<Range<usize>> {
+    CombiningSequences::new(s).filter(|range| has_repeated_mark(&s[range.clone()])).collect()
+}
+
+// Splits a string into combining sequences: characters that are not combining
+// marks, each with the marks that follow it.
+struct CombiningSequences<'a> {
+    chars: CharIndices<'a>,
+    start: usize,
+    len: usize,
+}
+
+impl<'a> CombiningSequences<'a> {
+    fn new(s: &'a str) -> CombiningSequences<'a> {
+        let mut chars = s.char_indices();
+        chars.next();
+        CombiningSequences { chars, start: 0, len: s.len() }
+    }
+}
+
+impl<'a> Iterator for CombiningSequences<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        if self.start == self.len {
+            return None;
+        }
+        let start = self.start;
+        self.start = self.len;
+        for (i, c) in self.chars.by_ref() {
+            if !is_combining_mark(c) {
+                self.start = i;
+                break;
+            }
+        }
+        Some(start..self.start)
+    }
+}
+
+fn has_repeated_mark(s: &str) -> bool {
+    let mut marks: TinyVec<[char; 8]> = TinyVec::new();
+    for c in s.nfd() {
+        if !is_combining_mark(c) {
+            marks.clear();
+        } else if marks.contains(&c) {
+            return true;
+        } else {
+            marks.push(c);
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_ignorables, excessive_marks, is_default_ignorable, is_tag_character, is_variation_selector, strip_variation_and_tags, MarkLimits, is_mixed_number, restriction_level, AllowedSet, Check, Checks, CharProperty, Finding, Preset, RestrictionLevel, SpoofChecker};
+    use ConfusableKind;
+    use std::borrow::Cow;
+
+    use identifier::IdentifierType;
+    use data::Script;
+
+    #[test]
+    fn clean_strings_pass() {
+        let checker = SpoofChecker::new();
+        assert_eq!(checker.quick_check("paypal"), Checks::empty());
+        assert_eq!(checker.quick_check("παράδειγμα"), Checks::empty());
+        assert_eq!(checker.quick_check("abc123"), Checks::empty());
+        assert_eq!(checker.quick_check("東京カタカナ"), Checks::empty());
+    }
+
+    #[test]
+    fn mixed_script() {
+        let checker = SpoofChecker::new();
+        assert_eq!(checker.quick_check("pаypаl"), Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL);
+        assert_eq!(checker.disable(Check::MixedScript).quick_check("pаypаl"), Checks::RESTRICTION_LEVEL);
+    }
+
+    #[test]
+    fn invisible() {
+        let checker = SpoofChecker::new();
+        assert_eq!(checker.quick_check("a\u{301}\u{301}"), Checks::INVISIBLE);
+        // Precomposed á followed by another acute decomposes to a repeat.
+        assert_eq!(checker.quick_check("\u{e1}\u{301}"), Checks::INVISIBLE);
+        assert_eq!(checker.quick_check("a\u{301}b\u{301}"), Checks::empty());
+        assert_eq!(checker.quick_check("a\u{301}\u{300}"), Checks::empty());
+    }
+
+    #[test]
+    fn mixed_number() {
+        assert!(is_mixed_number("1৪5"));
+        assert!(is_mixed_number("𝟘𝟙0"));
+        assert!(!is_mixed_number("৪৫"));
+        assert!(!is_mixed_number("abc123"));
+        assert!(!is_mixed_number("abc"));
+        // Mathematical digits come in several consecutive sequences of ten.
+        assert!(is_mixed_number("𝟎𝟘"));
+        assert!(!is_mixed_number("𝟗𝟎"));
+
+        let checker = SpoofChecker::new().disable(Check::RestrictionLevel);
+        assert_eq!(checker.quick_check("1৪5"), Checks::MIXED_NUMBER);
+        assert_eq!(checker.disable(Check::MixedNumber).quick_check("1৪5"), Checks::empty());
+    }
+
+    #[test]
+    fn default_ignorable() {
+        for &c in &['\u{200B}', '\u{200C}', '\u{200D}', '\u{FEFF}', '\u{AD}', '\u{2060}', '\u{E0001}'] {
+            assert!(is_default_ignorable(c));
+        }
+        assert!(!is_default_ignorable(' '));
+        assert!(!is_default_ignorable('a'));
+        assert_eq!(default_ignorables("ad\u{AD}min\u{200B}"), vec![(2, '\u{AD}'), (7, '\u{200B}')]);
+
+        let checker = SpoofChecker::new();
+        assert_eq!(checker.quick_check("admin\u{200B}"), Checks::DEFAULT_IGNORABLE);
+        assert_eq!(checker.check("admin\u{200B}").findings_for(Check::DefaultIgnorable), vec![
+            &Finding { check: Check::DefaultIgnorable, text: Cow::Borrowed("\u{200B}"), byte_range: 5..8 },
+        ]);
+        assert_eq!(checker.disable(Check::DefaultIgnorable).quick_check("admin\u{200B}"), Checks::empty());
+    }
+
+    #[test]
+    fn bidi_control() {
+        let checker = SpoofChecker::new().disable(Check::DefaultIgnorable);
+        assert_eq!(checker.quick_check("admin\u{202E}nimda"), Checks::BIDI_CONTROL);
+        assert_eq!(checker.check("admin\u{202E}").findings, vec![
+            Finding { check: Check::BidiControl, text: Cow::Borrowed("\u{202E}"), byte_range: 5..8 },
+        ]);
+        assert_eq!(checker.disable(Check::BidiControl).quick_check("admin\u{202E}"), Checks::empty());
+    }
+
+    #[test]
+    fn excessive_combining_marks() {
+        let limits = MarkLimits::default();
+        let zalgo = "ok z\u{30D}\u{30E}\u{304}\u{305}\u{33F}!";
+        assert_eq!(excessive_marks(zalgo, limits), vec![3..14]);
+        // Vietnamese stacks one mark above and one below.
+        assert!(excessive_marks("Nguyễn Việt", limits).is_empty());
+        // Three marks above stack too high, though there are few in total.
+        assert_eq!(excessive_marks("a\u{301}\u{300}\u{302}", limits), vec![0..7]);
+        assert!(excessive_marks("a\u{301}\u{300}\u{302}", MarkLimits { max_marks: 4, max_stacked: 3 }).is_empty());
+        assert_eq!(excessive_marks("a\u{301}\u{316}\u{300}\u{317}\u{302}", MarkLimits { max_marks: 4, max_stacked: 5 }), vec![0..11]);
+        assert!(excessive_marks("", limits).is_empty());
+        assert_eq!(excessive_marks("\u{301}\u{301}\u{301}", limits), vec![0..6]);
+
+        let checker = SpoofChecker::new().disable(Check::Invisible);
+        assert_eq!(checker.quick_check(zalgo), Checks::EXCESSIVE_MARKS);
+        assert_eq!(checker.check(zalgo).findings, vec![
+            Finding { check: Check::ExcessiveMarks, text: Cow::Borrowed(&zalgo[3..14]), byte_range: 3..14 },
+        ]);
+        let lenient = checker.mark_limits(MarkLimits { max_marks: 10, max_stacked: 10 });
+        assert_eq!(lenient.quick_check(zalgo), Checks::empty());
+    }
+
+    #[test]
+    fn variation_and_tags() {
+        assert!(is_variation_selector('\u{FE0F}'));
+        assert!(is_variation_selector('\u{E0100}'));
+        assert!(!is_variation_selector('\u{FE10}'));
+        assert!(is_tag_character('\u{E0001}'));
+        assert!(is_tag_character('\u{E007F}'));
+        assert!(!is_tag_character('\u{E0100}'));
+
+        assert_eq!(strip_variation_and_tags("a\u{FE0F}b\u{E0061}\u{E007F}"), "ab");
+        assert!(matches!(strip_variation_and_tags("ab"), Cow::Borrowed("ab")));
+
+        let checker = SpoofChecker::new().disable(Check::DefaultIgnorable);
+        assert_eq!(checker.quick_check("a\u{FE0F}b\u{E0061}"), Checks::VARIATION_SELECTOR | Checks::TAG_CHARACTER);
+        assert_eq!(checker.check("a\u{FE0F}b").findings, vec![
+            Finding { check: Check::VariationSelector, text: Cow::Borrowed("\u{FE0F}"), byte_range: 1..4 },
+        ]);
+    }
+
+    #[test]
+    fn restriction_levels() {
+        assert_eq!(restriction_level("paypal", |_| true), RestrictionLevel::AsciiOnly);
+        assert_eq!(restriction_level("παράδειγμα", |_| true), RestrictionLevel::SingleScript);
+        assert_eq!(restriction_level("東京abcカタカナ", |_| true), RestrictionLevel::HighlyRestrictive);
+        assert_eq!(restriction_level("abcअबक", |_| true), RestrictionLevel::ModeratelyRestrictive);
+        assert_eq!(restriction_level("pаypаl", |_| true), RestrictionLevel::MinimallyRestrictive);
+        assert_eq!(restriction_level("abcअबकカタカナ", |_| true), RestrictionLevel::MinimallyRestrictive);
+        assert_eq!(restriction_level("paypal", |c| c != 'y'), RestrictionLevel::Unrestricted);
+    }
+
+    #[test]
+    fn restriction_level_check() {
+        let checker = SpoofChecker::new().disable(Check::MixedScript);
+        assert_eq!(checker.quick_check("東京abcカタカナ"), Checks::empty());
+        assert_eq!(checker.quick_check("abcअबक"), Checks::RESTRICTION_LEVEL);
+        let checker = checker.restriction_level(RestrictionLevel::ModeratelyRestrictive);
+        assert_eq!(checker.quick_check("abcअबक"), Checks::empty());
+        let checker = checker.restriction_level(RestrictionLevel::AsciiOnly);
+        assert_eq!(checker.quick_check("naïve"), Checks::RESTRICTION_LEVEL);
+    }
+
+    #[test]
+    fn allowed_sets() {
+        let registry = AllowedSet::new().range('a'..='z').range('0'..='9').chars("-");
+        assert!(registry.contains('q'));
+        assert!(registry.contains('-'));
+        assert!(!registry.contains('_'));
+        assert_eq!(registry.disallowed("my-nаme"), vec![(4, 'а')]);
+        assert_eq!(registry.disallowed("my-name"), vec![]);
+
+        let letters = AllowedSet::new().property(CharProperty::Alphabetic);
+        assert_eq!(letters.disallowed("ab1c"), vec![(2, '1')]);
+        let recommended = AllowedSet::new().property(CharProperty::IdentifierType(IdentifierType::Recommended));
+        assert_eq!(recommended.disallowed("aſb"), vec![(1, 'ſ')]);
+        assert!(AllowedSet::new().property(CharProperty::DecimalDigit).contains('৪'));
+    }
+
+    #[test]
+    fn char_limit() {
+        let checker = SpoofChecker::new();
+        assert_eq!(checker.quick_check("my_name"), Checks::empty());
+        assert_eq!(checker.disallowed_chars("my_name"), vec![]);
+
+        let checker = checker.allowed_chars(AllowedSet::new().range('a'..='z').chars("-"));
+        assert_eq!(checker.quick_check("my-name"), Checks::empty());
+        assert_eq!(checker.quick_check("my_name"), Checks::RESTRICTION_LEVEL | Checks::CHAR_LIMIT);
+        assert_eq!(checker.disallowed_chars("my_name"), vec![(2, '_')]);
+        assert_eq!(checker.disable(Check::CharLimit).quick_check("my_name"), Checks::RESTRICTION_LEVEL);
+    }
+
+    #[test]
+    fn allowed_scripts() {
+        let checker = SpoofChecker::new().disable(Check::MixedScript).disable(Check::RestrictionLevel)
+            .allow_scripts(&[Script::Latin]);
+        assert_eq!(checker.quick_check("paypal-1"), Checks::empty());
+        assert_eq!(checker.quick_check("pаypаl"), Checks::CHAR_LIMIT);
+        assert_eq!(checker.disallowed_chars("pаypаl"), vec![(1, 'а'), (5, 'а')]);
+
+        let checker = checker.allow_scripts(&[Script::Cyrillic]);
+        assert_eq!(checker.quick_check("pаypаl"), Checks::empty());
+    }
+
+    #[test]
+    fn allowed_locales() {
+        let checker = SpoofChecker::new().disable(Check::MixedScript).disable(Check::RestrictionLevel)
+            .allow_locales(&["en-US", "ja_JP"]);
+        assert_eq!(checker.quick_check("東京abcカタカナ"), Checks::empty());
+        assert_eq!(checker.disallowed_chars("abcбв"), vec![(3, 'б'), (5, 'в')]);
+
+        // A locale that is not recognized still restricts the permitted scripts.
+        let checker = SpoofChecker::new().allow_locales(&["xx"]);
+        assert_eq!(checker.quick_check("abc"), Checks::RESTRICTION_LEVEL | Checks::CHAR_LIMIT);
+    }
+
+    #[test]
+    fn language_table_is_sorted() {
+        assert!(super::LANGUAGE_SCRIPTS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn quick_check() {
+        let checker = SpoofChecker::new();
+        assert_eq!(checker.quick_check("paypal"), Checks::empty());
+        let failed = checker.quick_check("pаypаl");
+        assert_eq!(failed, Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL);
+        assert!(failed.contains(Checks::MIXED_SCRIPT));
+        assert!(!failed.contains(Checks::MIXED_SCRIPT | Checks::INVISIBLE));
+        assert_eq!(failed & Checks::INVISIBLE, Checks::empty());
+        assert_eq!(failed, checker.check("pаypаl").failed);
+        assert_eq!(format!("{:?}", failed), "{MixedScript, RestrictionLevel}");
+    }
+
+    #[test]
+    fn report() {
+        let checker = SpoofChecker::new();
+        let report = checker.check("paypal");
+        assert!(report.is_clean());
+        assert_eq!(report.findings, vec![]);
+
+        let report = checker.check("pаypаl");
+        assert_eq!(report.failed, Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL);
+        assert_eq!(report.findings_for(Check::MixedScript), vec![
+            &Finding { check: Check::MixedScript, text: Cow::Borrowed("а"), byte_range: 1..3 },
+            &Finding { check: Check::MixedScript, text: Cow::Borrowed("а"), byte_range: 5..7 },
+        ]);
+        assert_eq!(report.findings_for(Check::RestrictionLevel).len(), 2);
+    }
+
+    #[test]
+    fn report_spans() {
+        let checker = SpoofChecker::new().disable(Check::RestrictionLevel);
+        assert_eq!(checker.check("xa\u{301}\u{301}y").findings, vec![
+            Finding { check: Check::Invisible, text: Cow::Borrowed("a\u{301}\u{301}"), byte_range: 1..6 },
+        ]);
+        assert_eq!(checker.check("1৪5৪").findings, vec![
+            Finding { check: Check::MixedNumber, text: Cow::Borrowed("৪"), byte_range: 1..4 },
+            Finding { check: Check::MixedNumber, text: Cow::Borrowed("৪"), byte_range: 5..8 },
+        ]);
+
+        let checker = checker.allowed_chars(AllowedSet::new().range('a'..='z'));
+        assert_eq!(checker.check("my_name").findings, vec![
+            Finding { check: Check::CharLimit, text: Cow::Borrowed("_"), byte_range: 2..3 },
+        ]);
+        let checker = SpoofChecker::new().allowed_chars(AllowedSet::new().range('a'..='z'));
+        assert_eq!(checker.check("my_name").findings_for(Check::RestrictionLevel), vec![
+            &Finding { check: Check::RestrictionLevel, text: Cow::Borrowed("_"), byte_range: 2..3 },
+        ]);
+    }
+
+    #[test]
+    fn check_sets() {
+        let mut checks = Checks::from(Check::Invisible);
+        checks.insert(Checks::CHAR_LIMIT);
+        assert_eq!(checks.bits(), Checks::INVISIBLE.bits() | Checks::CHAR_LIMIT.bits());
+        checks.remove(Checks::INVISIBLE);
+        assert_eq!(checks, Checks::CHAR_LIMIT);
+        assert_eq!(Checks::all().iter().count(), 12);
+        assert!(Checks::empty().is_empty());
+        assert_eq!(Check::MixedScript.name(), "mixed_script");
+    }
+
+    #[test]
+    fn confusable_pairs() {
+        let checker = SpoofChecker::new();
+        assert!(checker.are_confusable("paypal", "pаypаl"));
+        assert!(!checker.are_confusable("paypal", "paypai"));
+        assert!(!checker.clone().disable(Check::Confusable).are_confusable("paypal", "pаypаl"));
+        assert!(checker.disable(Check::Confusable).enable(Check::Confusable).is_enabled(Check::Confusable));
+    }
+
+    #[test]
+    fn intentional_scores() {
+        let checker = SpoofChecker::new();
+        let escalating = SpoofChecker::new().escalate_intentional(true);
+        assert_eq!(checker.confusable_score("paypal", "paypai"), None);
+        assert!(checker.confusable_score("paypal", "pаypаl").unwrap() < 1.0);
+        assert_eq!(escalating.confusable_score("paypal", "pаypаl"), Some(1.0));
+        assert_eq!(escalating.confusable_score("rn", "m"), checker.confusable_score("rn", "m"));
+        assert_eq!(escalating.disable(Check::Confusable).confusable_score("paypal", "pаypаl"), None);
+    }
+
+    #[test]
+    fn leetspeak() {
+        let checker = SpoofChecker::new();
+        assert!(!checker.are_confusable("g00gle", "google"));
+        assert!(checker.clone().leetspeak(true).are_confusable("g00gle", "google"));
+        assert!(checker.clone().leetspeak(true).are_confusable("pаypаl", "paypal"));
+        assert!(!checker.leetspeak(true).disable(Check::Confusable).are_confusable("g00gle", "google"));
+    }
+
+    #[test]
+    fn confusable_kinds() {
+        let checker = SpoofChecker::new().confusable_kinds(&[ConfusableKind::WholeScript]);
+        assert!(checker.are_confusable("scope", "ѕсоре"));
+        assert!(!checker.are_confusable("paypal", "pаypаl"));
+        assert!(!checker.are_confusable("rn", "m"));
+        assert!(checker.confusable_score("scope", "ѕсоре").unwrap() >= 0.75);
+        assert_eq!(checker.confusable_score("rn", "m"), None);
+        assert!(checker.leetspeak(true).are_confusable("g00gle", "google"));
+    }
+
+    #[test]
+    fn presets() {
+        let permissive = SpoofChecker::preset(Preset::Permissive);
+        assert_eq!(permissive.quick_check("pаypаl"), Checks::empty());
+        assert_eq!(permissive.quick_check("admin\u{200B}"), Checks::DEFAULT_IGNORABLE);
+        assert!(!permissive.are_confusable("rn", "m"));
+        assert!(permissive.are_confusable("paypal", "pаypаl"));
+
+        let registrar = SpoofChecker::preset(Preset::Registrar);
+        assert_eq!(registrar.quick_check("paypal"), Checks::empty());
+        assert_eq!(registrar.quick_check("東京カタカナ"), Checks::empty());
+        assert!(registrar.quick_check("pаypаl").contains(Checks::RESTRICTION_LEVEL));
+        // Historic scripts are not allowed.
+        assert!(registrar.quick_check("ᚠᚢᚦ").contains(Checks::CHAR_LIMIT));
+        assert!(registrar.are_confusable("rn", "m"));
+        assert_eq!(registrar.confusable_score("paypal", "pаypаl"), Some(1.0));
+        assert!(!registrar.are_confusable("g00gle", "google"));
+        // All Cyrillic, but it looks like Latin "scope".
+        assert_eq!(registrar.quick_check("ѕсоре"), Checks::WHOLE_SCRIPT_CONFUSABLE);
+        assert_eq!(SpoofChecker::new().quick_check("ѕсоре"), Checks::empty());
+
+        let strict = SpoofChecker::preset(Preset::HighSecurity);
+        assert_eq!(strict.quick_check("paypal"), Checks::empty());
+        assert_eq!(strict.quick_check("東京カタカナ"), Checks::empty());
+        assert!(strict.quick_check("abc東京").contains(Checks::RESTRICTION_LEVEL));
+        assert!(strict.quick_check("ѕсоре").contains(Checks::WHOLE_SCRIPT_CONFUSABLE));
+        assert!(!registrar.quick_check("abc東京").contains(Checks::RESTRICTION_LEVEL));
+        assert!(strict.are_confusable("g00gle", "google"));
+        assert!(strict.allow_locales(&["ru"]).quick_check("ᚠᚢᚦ").contains(Checks::CHAR_LIMIT));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        use serde_json;
+        use scripts::ScriptSet;
+
+        let checks = Checks::MIXED_SCRIPT | Checks::BIDI_CONTROL;
+        assert_eq!(serde_json::to_string(&checks).unwrap(), r#"["MixedScript","BidiControl"]"#);
+        assert_eq!(serde_json::from_str::<Checks>(r#"["BidiControl","MixedScript"]"#).unwrap(), checks);
+        let scripts: ScriptSet = [Script::Latin, Script::Greek].iter().cloned().collect();
+        assert_eq!(serde_json::to_string(&scripts).unwrap(), r#"["Greek","Latin"]"#);
+
+        let checker = SpoofChecker::new()
+            .disable(Check::ExcessiveMarks)
+            .restriction_level(RestrictionLevel::ModeratelyRestrictive)
+            .allowed_chars(AllowedSet::new().range('a'..='z').property(CharProperty::IdentifierType(IdentifierType::Recommended)))
+            .allow_scripts(&[Script::Latin, Script::Cyrillic])
+            .leetspeak(true)
+            .confusable_kinds(&[ConfusableKind::WholeScript]);
+        let json = serde_json::to_string(&checker).unwrap();
+        assert_eq!(serde_json::from_str::<SpoofChecker>(&json).unwrap(), checker);
+        // Missing settings take their defaults.
+        assert_eq!(serde_json::from_str::<SpoofChecker>(r#"{"leetspeak":true}"#).unwrap(), SpoofChecker::new().leetspeak(true));
+
+        let report = SpoofChecker::new().check("pаypаl");
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<super::SpoofReport>(&json).unwrap(), report);
+
+        // Findings whose text JSON escapes are deserialized into owned text.
+        let report = SpoofChecker::new()
+            .enable(Check::CharLimit)
+            .allowed_chars(AllowedSet::new().range('a'..='z'))
+            .check("ab\"c\\d\n\u{1}");
+        let json = serde_json::to_string(&report).unwrap();
+        let round_tripped = serde_json::from_str::<super::SpoofReport>(&json).unwrap();
+        assert_eq!(round_tripped, report);
+        let texts: Vec<&str> = round_tripped.findings_for(Check::CharLimit).iter().map(|finding| &*finding.text).collect();
+        assert_eq!(texts, ["\"", "\\", "\n", "\u{1}"]);
+    }
+}
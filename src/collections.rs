@@ -0,0 +1,874 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Collections that treat confusable strings as the same key.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::hash_map;
+use std::io::{self, Read, Write};
+
+use {Skeleton, UnicodeSkeleton, UNICODE_VERSION};
+
+/// A set of strings in which confusable strings are considered equal.
+///
+/// ```Rust
+/// let mut names = SkeletonSet::new();
+/// names.insert("Rust");
+/// names.contains("ℝ𝓊𝓈𝓉") // true
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SkeletonSet {
+    skeletons: HashSet<Skeleton>,
+}
+
+impl SkeletonSet {
+    /// Create an empty set.
+    pub fn new() -> SkeletonSet {
+        SkeletonSet { skeletons: HashSet::new() }
+    }
+
+    /// Add a string to the set. Returns `false` if a string confusable with
+    /// it was already present.
+    pub fn insert(&mut self, s: &str) -> bool {
+        self.skeletons.insert(Skeleton::of(s))
+    }
+
+    /// Test whether the set contains a string confusable with `s`.
+    pub fn contains(&self, s: &str) -> bool {
+        self.skeletons.contains(&Skeleton::of(s))
+    }
+
+    /// Remove the string confusable with `s`, returning whether one was present.
+    pub fn remove(&mut self, s: &str) -> bool {
+        self.skeletons.remove(&Skeleton::of(s))
+    }
+
+    /// The number of distinct skeletons in the set.
+    pub fn len(&self) -> usize {
+        self.skeletons.len()
+    }
+
+    /// Test whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.skeletons.is_empty()
+    }
+}
+
+/// A map from strings to values in which confusable strings are the same key.
+///
+/// The map remembers the original string that first claimed each skeleton, so
+/// a conflicting insertion can report which existing name it collided with.
+///
+/// ```Rust
+/// let mut owners = SkeletonMap::new();
+/// owners.insert("Rust", 1);
+/// match owners.entry("ℝ𝓊𝓈𝓉") {
+///     Entry::Occupied(e) => println!("already taken by {}", e.key()), // "Rust"
+///     Entry::Vacant(e) => { e.insert(2); }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkeletonMap<V> {
+    entries: HashMap<Skeleton, (String, V)>,
+}
+
+impl<V> Default for SkeletonMap<V> {
+    fn default() -> SkeletonMap<V> {
+        SkeletonMap::new()
+    }
+}
+
+impl<V> SkeletonMap<V> {
+    /// Create an empty map.
+    pub fn new() -> SkeletonMap<V> {
+        SkeletonMap { entries: HashMap::new() }
+    }
+
+    /// Associate `value` with `key`. If a key confusable with `key` was already
+    /// present, its value is replaced and returned, and the original key is kept.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut e) => Some(e.insert(value)),
+            Entry::Vacant(e) => {
+                e.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Get the value associated with a key confusable with `key`.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Get a mutable reference to the value associated with a key confusable with `key`.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.entries.get_mut(&Skeleton::of(key)).map(|entry| &mut entry.1)
+    }
+
+    /// Get the original key and the value associated with a key confusable with `key`.
+    pub fn get_key_value(&self, key: &str) -> Option<(&str, &V)> {
+        self.entries.get(&Skeleton::of(key)).map(|entry| (&entry.0[..], &entry.1))
+    }
+
+    /// Test whether the map contains a key confusable with `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.entries.contains_key(&Skeleton::of(key))
+    }
+
+    /// Remove the entry whose key is confusable with `key`, returning its
+    /// original key and value.
+    pub fn remove(&mut self, key: &str) -> Option<(String, V)> {
+        self.entries.remove(&Skeleton::of(key))
+    }
+
+    /// Get the entry for `key`'s skeleton for in-place manipulation.
+    pub fn entry<'a>(&'a mut self, key: &'a str) -> Entry<'a, V> {
+        match self.entries.entry(Skeleton::of(key)) {
+            hash_map::Entry::Occupied(inner) => Entry::Occupied(OccupiedEntry { inner }),
+            hash_map::Entry::Vacant(inner) => Entry::Vacant(VacantEntry { key, inner }),
+        }
+    }
+
+    /// The number of distinct skeletons in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test whether the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// A view into a single skeleton's entry in a `SkeletonMap`.
+pub enum Entry<'a, V: 'a> {
+    /// A key confusable with the requested one is already present.
+    Occupied(OccupiedEntry<'a, V>),
+    /// No key confusable with the requested one is present.
+    Vacant(VacantEntry<'a, V>),
+}
+
+impl<'a, V> Entry<'a, V> {
+    /// Insert `default` if the entry is vacant, and return the entry's value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(default),
+        }
+    }
+
+    /// The original key that claimed this skeleton if occupied, or the
+    /// requested key if vacant.
+    pub fn key(&self) -> &str {
+        match *self {
+            Entry::Occupied(ref e) => e.key(),
+            Entry::Vacant(ref e) => e.key(),
+        }
+    }
+}
+
+/// An occupied entry of a `SkeletonMap`.
+pub struct OccupiedEntry<'a, V: 'a> {
+    inner: hash_map::OccupiedEntry<'a, Skeleton, (String, V)>,
+}
+
+impl<'a, V> OccupiedEntry<'a, V> {
+    /// The original key that first claimed this skeleton.
+    pub fn key(&self) -> &str {
+        &self.inner.get().0
+    }
+
+    /// The skeleton shared by this entry's key and the requested key.
+    pub fn skeleton(&self) -> &Skeleton {
+        self.inner.key()
+    }
+
+    /// The entry's value.
+    pub fn get(&self) -> &V {
+        &self.inner.get().1
+    }
+
+    /// A mutable reference to the entry's value.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.inner.get_mut().1
+    }
+
+    /// Convert into a mutable reference to the value with the map's lifetime.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.inner.into_mut().1
+    }
+
+    /// Replace the entry's value, keeping the original key, and return the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        ::std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Remove the entry, returning its original key and value.
+    pub fn remove(self) -> (String, V) {
+        self.inner.remove()
+    }
+}
+
+/// A vacant entry of a `SkeletonMap`.
+pub struct VacantEntry<'a, V: 'a> {
+    key: &'a str,
+    inner: hash_map::VacantEntry<'a, Skeleton, (String, V)>,
+}
+
+impl<'a, V> VacantEntry<'a, V> {
+    /// The key that was requested.
+    pub fn key(&self) -> &str {
+        self.key
+    }
+
+    /// Claim the skeleton for the requested key with the given value.
+    pub fn insert(self, value: V) -> &'a mut V {
+        &mut self.inner.insert((self.key.to_owned(), value)).1
+    }
+}
+
+/// An index of names for finding those confusable with a new one, such as
+/// the existing usernames when a user registers.
+///
+/// Unlike `SkeletonSet`, every name inserted is kept, even when it is
+/// confusable with another, so `query` can report all of them. Names are
+/// grouped by a 64-bit hash of their skeleton rather than by the skeleton
+/// itself, which is computed only while inserting and querying. Lookups
+/// take constant time on average; the names with the queried hash are
+/// checked against the queried skeleton, so hash collisions never produce
+/// false matches.
+///
+/// An index can be saved with `write_to` and loaded with `read_from`, so a
+/// service can build it offline and load it at startup without computing
+/// every skeleton again.
+///
+/// ```Rust
+/// let mut index = ConfusableIndex::new();
+/// index.insert("rust-lang");
+/// index.insert("𝓇𝓊𝓈𝓉-lang");
+/// index.query("ru𝓼t-lang") // ["rust-lang", "𝓇𝓊𝓈𝓉-lang"]
+/// index.query("go-lang") // []
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfusableIndex {
+    buckets: HashMap<u64, Vec<String>>,
+    len: usize,
+}
+
+// A 64-bit FNV-1a hash of the UTF-8 encoding of the characters of a
+// skeleton. Unlike the hashers of `HashMap`, it is the same in every run.
+fn hash_skeleton<I: Iterator<Item=char>>(skeleton: I) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut buf = [0u8; 4];
+    for c in skeleton {
+        for &byte in c.encode_utf8(&mut buf).as_bytes() {
+            hash = (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+impl ConfusableIndex {
+    /// Create an empty index.
+    pub fn new() -> ConfusableIndex {
+        ConfusableIndex::default()
+    }
+
+    /// Add a name to the index. Returns `false`, leaving the index unchanged,
+    /// if the same name was already present.
+    pub fn insert(&mut self, name: &str) -> bool {
+        let bucket = self.buckets.entry(hash_skeleton(name.skeleton_chars())).or_default();
+        if bucket.iter().any(|existing| existing == name) {
+            return false;
+        }
+        bucket.push(name.to_string());
+        self.len += 1;
+        true
+    }
+
+    /// Remove a name from the index, returning whether it was present. Only
+    /// the name itself is removed, not names confusable with it.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let hash = hash_skeleton(name.skeleton_chars());
+        let bucket = match self.buckets.get_mut(&hash) {
+            Some(bucket) => bucket,
+            None => return false,
+        };
+        let position = match bucket.iter().position(|existing| existing == name) {
+            Some(position) => position,
+            None => return false,
+        };
+        bucket.remove(position);
+        if bucket.is_empty() {
+            self.buckets.remove(&hash);
+        }
+        self.len -= 1;
+        true
+    }
+
+    /// The names in the index that are confusable with `s`, in the order they
+    /// were inserted. This includes `s` itself, if it is present.
+    pub fn query(&self, s: &str) -> Vec<&str> {
+        let skeleton = Skeleton::of(s);
+        let hash = hash_skeleton(skeleton.as_str().chars());
+        match self.buckets.get(&hash) {
+            Some(bucket) => bucket.iter().map(|name| &name[..]).filter(|name| skeleton.matches(*name)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Test whether the index contains a name confusable with `s`.
+    pub fn contains_confusable(&self, s: &str) -> bool {
+        !self.query(s).is_empty()
+    }
+
+    /// The number of names in the index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Save the index to `out`, in a versioned binary format that
+    /// `read_from` loads. The same index is always saved as the same bytes.
+    ///
+    /// The format is the magic bytes `USKI`, the format version as a `u32`,
+    /// the `UNICODE_VERSION` the skeletons were computed with as three
+    /// bytes, and the number of groups as a `u64`. Then, for each group in
+    /// order of hash, come its skeleton hash as a `u64` and its number of
+    /// names as a `u32`, and for each name its length in bytes as a `u32`
+    /// and its UTF-8. Integers are little-endian.
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let mut buckets: Vec<(&u64, &Vec<String>)> = self.buckets.iter().collect();
+        buckets.sort_by_key(|&(hash, _)| *hash);
+        out.write_all(INDEX_MAGIC)?;
+        out.write_all(&INDEX_FORMAT_VERSION.to_le_bytes())?;
+        out.write_all(&[UNICODE_VERSION.0, UNICODE_VERSION.1, UNICODE_VERSION.2])?;
+        out.write_all(&(buckets.len() as u64).to_le_bytes())?;
+        for (hash, names) in buckets {
+            out.write_all(&hash.to_le_bytes())?;
+            out.write_all(&(names.len() as u32).to_le_bytes())?;
+            for name in names {
+                out.write_all(&(name.len() as u32).to_le_bytes())?;
+                out.write_all(name.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Load an index saved by `write_to`, without computing any skeletons.
+    ///
+    /// Fails with `io::ErrorKind::InvalidData` if the data is not an index
+    /// in a format version this crate reads, or if its skeletons were
+    /// computed with a different `UNICODE_VERSION`, since they may have
+    /// changed; such an index must be rebuilt from its names.
+    pub fn read_from<R: Read>(input: &mut R) -> io::Result<ConfusableIndex> {
+        let mut magic = [0u8; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != INDEX_MAGIC {
+            return Err(invalid_data("not a confusable index"));
+        }
+        let format_version = u32::from_le_bytes(read_array(input)?);
+        if format_version != INDEX_FORMAT_VERSION {
+            return Err(invalid_data(format!("unsupported confusable index format version {}", format_version)));
+        }
+        let [major, minor, update] = read_array(input)?;
+        if (major, minor, update) != UNICODE_VERSION {
+            return Err(invalid_data(format!("confusable index was built with Unicode {}.{}.{}", major, minor, update)));
+        }
+
+        let mut index = ConfusableIndex::new();
+        let bucket_count = u64::from_le_bytes(read_array(input)?);
+        for _ in 0..bucket_count {
+            let hash = u64::from_le_bytes(read_array(input)?);
+            let name_count = u32::from_le_bytes(read_array(input)?);
+            // The counts are not trusted to size allocations up front.
+            let mut names = Vec::new();
+            for _ in 0..name_count {
+                let len = u32::from_le_bytes(read_array(input)?) as u64;
+                let mut name = Vec::new();
+                if input.take(len).read_to_end(&mut name)? as u64 != len {
+                    return Err(io::ErrorKind::UnexpectedEof.into());
+                }
+                names.push(String::from_utf8(name).map_err(|_| invalid_data("confusable index name is not UTF-8"))?);
+            }
+            index.len += names.len();
+            if !names.is_empty() && index.buckets.insert(hash, names).is_some() {
+                return Err(invalid_data("confusable index has a repeated hash"));
+            }
+        }
+        Ok(index)
+    }
+}
+
+const INDEX_MAGIC: &[u8; 4] = b"USKI";
+const INDEX_FORMAT_VERSION: u32 = 1;
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn read_array<R: Read, const N: usize>(input: &mut R) -> io::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    input.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// A dictionary of names, for suggesting the existing names nearest to a new
+/// one, as with "did you mean" messages.
+///
+/// Names are ranked by `skeleton_distance` to the query, so names confusable
+/// with it come first, at distance 0, followed by near-misses. A registry can
+/// refuse new names at distance 0 and warn about those at distance 1.
+/// Suggesting compares the query with every distinct skeleton, skipping those
+/// whose length alone puts them too far away.
+///
+/// ```Rust
+/// let mut names = SuggestionIndex::new();
+/// names.insert("serde");
+/// names.insert("serd");
+/// names.insert("tokio");
+/// names.suggest("𝓈𝑒𝓇𝒹𝑒", 2) // [("serde", 0), ("serd", 1)]
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SuggestionIndex {
+    names: HashMap<Skeleton, Vec<String>>,
+    len: usize,
+}
+
+impl SuggestionIndex {
+    /// Create an empty index.
+    pub fn new() -> SuggestionIndex {
+        SuggestionIndex::default()
+    }
+
+    /// Add a name to the index. Returns `false`, leaving the index unchanged,
+    /// if the same name was already present.
+    pub fn insert(&mut self, name: &str) -> bool {
+        let names = self.names.entry(Skeleton::of(name)).or_default();
+        if names.iter().any(|existing| existing == name) {
+            return false;
+        }
+        names.push(name.to_string());
+        self.len += 1;
+        true
+    }
+
+    /// Remove a name from the index, returning whether it was present. Only
+    /// the name itself is removed, not names confusable with it.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let skeleton = Skeleton::of(name);
+        let names = match self.names.get_mut(&skeleton) {
+            Some(names) => names,
+            None => return false,
+        };
+        let position = match names.iter().position(|existing| existing == name) {
+            Some(position) => position,
+            None => return false,
+        };
+        names.remove(position);
+        if names.is_empty() {
+            self.names.remove(&skeleton);
+        }
+        self.len -= 1;
+        true
+    }
+
+    /// The `k` names in the index nearest to `query`, with their skeleton
+    /// distances from it, nearest first. Names at the same distance are in
+    /// order of their UTF-8 bytes.
+    pub fn suggest(&self, query: &str, k: usize) -> Vec<(&str, usize)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let query: Vec<char> = query.skeleton_chars().collect();
+        let mut nearest: Vec<(usize, &str)> = Vec::with_capacity(k);
+        let mut skeleton_chars = Vec::new();
+        for (skeleton, names) in &self.names {
+            skeleton_chars.clear();
+            skeleton_chars.extend(skeleton.as_str().chars());
+            // The distance is at least the difference in length.
+            if nearest.len() == k && skeleton_chars.len().abs_diff(query.len()) > nearest[k - 1].0 {
+                continue;
+            }
+            let distance = ::levenshtein(&query, &skeleton_chars);
+            for name in names {
+                let candidate = (distance, &name[..]);
+                let position = nearest.binary_search(&candidate).unwrap_or_else(|position| position);
+                if position < k {
+                    nearest.truncate(k - 1);
+                    nearest.insert(position, candidate);
+                }
+            }
+        }
+        nearest.into_iter().map(|(distance, name)| (name, distance)).collect()
+    }
+
+    /// The number of names in the index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// A set of names supporting prefix queries by skeleton, for autocompletion
+/// and reserving namespaces.
+///
+/// Every name inserted is kept, even when it is confusable with another.
+/// Names are stored in a trie keyed by the characters of their skeletons,
+/// so queries take time proportional to the length of the queried skeleton
+/// and the number of names found, however many names are stored.
+///
+/// ```Rust
+/// let mut reserved = SkeletonTrie::new();
+/// reserved.insert("admin");
+/// reserved.insert("admin-tools");
+/// reserved.find_prefixes("𝒶𝒹𝓂𝒾𝓃-tools-v2") // ["admin", "admin-tools"]
+/// reserved.find_with_prefix("𝒶𝒹𝓂") // ["admin", "admin-tools"]
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkeletonTrie {
+    // The root is `nodes[0]`.
+    nodes: Vec<TrieNode>,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    // The names whose skeleton ends at this node, in the order inserted.
+    names: Vec<String>,
+}
+
+impl Default for SkeletonTrie {
+    fn default() -> SkeletonTrie {
+        SkeletonTrie { nodes: vec![TrieNode::default()], len: 0 }
+    }
+}
+
+impl SkeletonTrie {
+    /// Create an empty trie.
+    pub fn new() -> SkeletonTrie {
+        SkeletonTrie::default()
+    }
+
+    /// Add a name to the trie. Returns `false`, leaving the trie unchanged,
+    /// if the same name was already present.
+    pub fn insert(&mut self, name: &str) -> bool {
+        let mut node = 0;
+        for c in name.skeleton_chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(TrieNode::default());
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        let names = &mut self.nodes[node].names;
+        if names.iter().any(|existing| existing == name) {
+            return false;
+        }
+        names.push(name.to_string());
+        self.len += 1;
+        true
+    }
+
+    // The node reached by following the skeleton of `s`, if there is one.
+    fn find_node(&self, s: &str) -> Option<usize> {
+        let mut node = 0;
+        for c in s.skeleton_chars() {
+            node = *self.nodes[node].children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    /// Test whether the trie contains a name confusable with `s`.
+    pub fn contains(&self, s: &str) -> bool {
+        self.find_node(s).is_some_and(|node| !self.nodes[node].names.is_empty())
+    }
+
+    /// The names in the trie whose skeletons are prefixes of the skeleton of
+    /// `query`, shortest first. This includes names confusable with `query`.
+    pub fn find_prefixes(&self, query: &str) -> Vec<&str> {
+        let mut found: Vec<&str> = self.nodes[0].names.iter().map(|name| &name[..]).collect();
+        let mut node = 0;
+        for c in query.skeleton_chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => break,
+            };
+            found.extend(self.nodes[node].names.iter().map(|name| &name[..]));
+        }
+        found
+    }
+
+    /// The names in the trie whose skeletons begin with the skeleton of
+    /// `prefix`, in order of their skeletons. This includes names confusable
+    /// with `prefix`.
+    pub fn find_with_prefix(&self, prefix: &str) -> Vec<&str> {
+        let mut found = Vec::new();
+        let mut stack: Vec<usize> = self.find_node(prefix).into_iter().collect();
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            found.extend(node.names.iter().map(|name| &name[..]));
+            stack.extend(node.children.values().rev());
+        }
+        found
+    }
+
+    /// The number of names in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test whether the trie is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Group strings that share a skeleton.
+///
+/// Every input appears in exactly one group. Groups are ordered by the first
+/// appearance of their skeleton, and members keep their input order, so
+/// groups with more than one member are the sets of mutually confusable inputs.
+///
+/// ```Rust
+/// group_by_skeleton(vec!["Rust", "Go", "ℝ𝓊𝓈𝓉"]) // [["Rust", "ℝ𝓊𝓈𝓉"], ["Go"]]
+/// ```
+pub fn group_by_skeleton<I, T>(items: I) -> Vec<Vec<T>>
+    where I: IntoIterator<Item=T>, T: AsRef<str>
+{
+    let mut group_indices = HashMap::new();
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    for item in items {
+        let next_index = groups.len();
+        let index = *group_indices.entry(Skeleton::of(item.as_ref())).or_insert(next_index);
+        if index == next_index {
+            groups.push(Vec::new());
+        }
+        groups[index].push(item);
+    }
+    groups
+}
+
+/// The result of `dedup_confusables`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deduplicated<T> {
+    /// The first input with each skeleton, in input order.
+    pub kept: Vec<T>,
+    /// The inputs that were confusable with an earlier input, in input order.
+    pub dropped: Vec<Dropped<T>>,
+}
+
+/// An input dropped by `dedup_confusables`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dropped<T> {
+    /// The dropped input.
+    pub item: T,
+    /// The index in `Deduplicated::kept` of the earlier input it was confusable with.
+    pub kept_index: usize,
+}
+
+/// Keep only the first of each set of confusable strings, and report which
+/// inputs were dropped in favor of which earlier ones.
+///
+/// ```Rust
+/// let result = dedup_confusables(vec!["Rust", "ℝ𝓊𝓈𝓉", "Go"]);
+/// result.kept // ["Rust", "Go"]
+/// result.dropped // [Dropped { item: "ℝ𝓊𝓈𝓉", kept_index: 0 }]
+/// ```
+pub fn dedup_confusables<I, T>(items: I) -> Deduplicated<T>
+    where I: IntoIterator<Item=T>, T: AsRef<str>
+{
+    let mut kept_indices = HashMap::new();
+    let mut result = Deduplicated { kept: Vec::new(), dropped: Vec::new() };
+    for item in items {
+        match kept_indices.entry(Skeleton::of(item.as_ref())) {
+            hash_map::Entry::Occupied(e) => {
+                result.dropped.push(Dropped { item, kept_index: *e.get() });
+            }
+            hash_map::Entry::Vacant(e) => {
+                e.insert(result.kept.len());
+                result.kept.push(item);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use super::{ConfusableIndex, Entry, SkeletonMap, SkeletonSet, SkeletonTrie, SuggestionIndex, Dropped, group_by_skeleton, dedup_confusables};
+
+    #[test]
+    fn set_membership() {
+        let mut set = SkeletonSet::new();
+        assert!(set.insert("Rust"));
+        assert!(!set.insert("ℝ𝓊𝓈𝓉"));
+        assert!(set.contains("ℝ𝓊𝓈𝓉"));
+        assert!(!set.contains("Rast"));
+        assert_eq!(set.len(), 1);
+
+        assert!(set.remove("ℝ𝓊𝓈𝓉"));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn map_lookup() {
+        let mut map = SkeletonMap::new();
+        assert_eq!(map.insert("Rust", 1), None);
+        assert_eq!(map.get("ℝ𝓊𝓈𝓉"), Some(&1));
+        assert_eq!(map.insert("ℝ𝓊𝓈𝓉", 2), Some(1));
+        assert_eq!(map.get_key_value("Rust"), Some(("Rust", &2)));
+        assert_eq!(map.get("Rast"), None);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn map_entry_reports_original_key() {
+        let mut map = SkeletonMap::new();
+        map.insert("paypal", ());
+        match map.entry("𝔭𝒶ỿ𝕡𝕒ℓ") {
+            Entry::Occupied(e) => assert_eq!(e.key(), "paypal"),
+            Entry::Vacant(_) => panic!("expected a conflict"),
+        }
+        match map.entry("Rust") {
+            Entry::Occupied(_) => panic!("expected no conflict"),
+            Entry::Vacant(e) => { e.insert(()); }
+        }
+        assert_eq!(map.get_key_value("ℝ𝓊𝓈𝓉"), Some(("Rust", &())));
+    }
+
+    #[test]
+    fn index_queries() {
+        let mut index = ConfusableIndex::new();
+        assert!(index.insert("rust-lang"));
+        assert!(index.insert("𝓇𝓊𝓈𝓉-lang"));
+        assert!(index.insert("go-lang"));
+        assert!(!index.insert("rust-lang"));
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.query("ru𝓼t-lang"), vec!["rust-lang", "𝓇𝓊𝓈𝓉-lang"]);
+        assert_eq!(index.query("𝔤o-lang"), vec!["go-lang"]);
+        assert!(index.query("rest-lang").is_empty());
+        assert!(index.contains_confusable("rust-Iang"));
+
+        assert!(index.remove("rust-lang"));
+        assert!(!index.remove("rust-lang"));
+        assert!(!index.remove("ru𝓼t-lang"));
+        assert_eq!(index.query("rust-lang"), vec!["𝓇𝓊𝓈𝓉-lang"]);
+        assert!(index.remove("𝓇𝓊𝓈𝓉-lang"));
+        assert!(!index.contains_confusable("rust-lang"));
+        assert!(index.remove("go-lang"));
+        assert!(index.is_empty());
+    }
+
+    #[test]
+    fn index_persistence() {
+        let mut index = ConfusableIndex::new();
+        for name in &["rust-lang", "𝓇𝓊𝓈𝓉-lang", "go-lang", "ℂ"] {
+            index.insert(name);
+        }
+        let mut saved = Vec::new();
+        index.write_to(&mut saved).unwrap();
+        let mut saved_again = Vec::new();
+        index.clone().write_to(&mut saved_again).unwrap();
+        assert_eq!(saved, saved_again);
+
+        let loaded = ConfusableIndex::read_from(&mut &saved[..]).unwrap();
+        assert_eq!(loaded.len(), 4);
+        assert_eq!(loaded.query("ru𝓼t-lang"), vec!["rust-lang", "𝓇𝓊𝓈𝓉-lang"]);
+        assert_eq!(loaded.query("C"), vec!["ℂ"]);
+
+        assert_eq!(ConfusableIndex::read_from(&mut &saved[..saved.len() - 1]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        let mut corrupt = saved.clone();
+        corrupt[0] = b'X';
+        assert_eq!(ConfusableIndex::read_from(&mut &corrupt[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+        let mut old_data = saved.clone();
+        old_data[8] = 10;
+        assert_eq!(ConfusableIndex::read_from(&mut &old_data[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+        let mut empty = Vec::new();
+        ConfusableIndex::new().write_to(&mut empty).unwrap();
+        assert!(ConfusableIndex::read_from(&mut &empty[..]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn suggestions() {
+        let mut names = SuggestionIndex::new();
+        for name in &["serde", "serd", "𝓈𝑒𝓇𝒹𝑒", "serde_json", "tokio", "sered"] {
+            assert!(names.insert(name));
+        }
+        assert!(!names.insert("serde"));
+        assert_eq!(names.len(), 6);
+        assert_eq!(names.suggest("serde", 3), vec![("serde", 0), ("𝓈𝑒𝓇𝒹𝑒", 0), ("serd", 1)]);
+        assert_eq!(names.suggest("ser", 2), vec![("serd", 1), ("serde", 2)]);
+        assert_eq!(names.suggest("tokio", 0), vec![]);
+        assert_eq!(names.suggest("tokio", 10).len(), 6);
+        assert_eq!(names.suggest("tokio", 10)[0], ("tokio", 0));
+
+        assert!(names.remove("serde"));
+        assert!(!names.remove("serde"));
+        assert_eq!(names.suggest("serde", 1), vec![("𝓈𝑒𝓇𝒹𝑒", 0)]);
+        assert!(SuggestionIndex::new().suggest("serde", 3).is_empty());
+    }
+
+    #[test]
+    fn trie_prefixes() {
+        let mut trie = SkeletonTrie::new();
+        for name in &["admin", "admin-tools", "𝒶𝒹𝓂𝒾𝓃", "adm", "support"] {
+            assert!(trie.insert(name));
+        }
+        assert!(!trie.insert("admin"));
+        assert_eq!(trie.len(), 5);
+        assert!(trie.contains("𝒶𝒹𝓂𝒾𝓃"));
+        assert!(!trie.contains("admi"));
+        assert!(!trie.contains("administrator"));
+
+        assert_eq!(trie.find_prefixes("𝒶𝒹𝓂𝒾𝓃-tools-v2"), vec!["adm", "admin", "𝒶𝒹𝓂𝒾𝓃", "admin-tools"]);
+        assert_eq!(trie.find_prefixes("ad"), Vec::<&str>::new());
+        assert_eq!(trie.find_with_prefix("𝒶𝒹𝓂"), vec!["adm", "admin", "𝒶𝒹𝓂𝒾𝓃", "admin-tools"]);
+        assert_eq!(trie.find_with_prefix("").len(), 5);
+        assert_eq!(trie.find_with_prefix("root"), Vec::<&str>::new());
+
+        // "m" has the skeleton "rn", so it is found under that prefix.
+        trie.insert("rnodule");
+        assert_eq!(trie.find_with_prefix("mod"), vec!["rnodule"]);
+        assert!(SkeletonTrie::new().find_prefixes("admin").is_empty());
+    }
+
+    #[test]
+    fn grouping() {
+        let groups = group_by_skeleton(vec!["Rust", "Go", "ℝ𝓊𝓈𝓉", "𝔾ℴ", "C"]);
+        assert_eq!(groups, vec![vec!["Rust", "ℝ𝓊𝓈𝓉"], vec!["Go", "𝔾ℴ"], vec!["C"]]);
+    }
+
+    #[test]
+    fn deduplication() {
+        let result = dedup_confusables(vec!["Rust", "Go", "ℝ𝓊𝓈𝓉", "𝔾ℴ", "Rust"]);
+        assert_eq!(result.kept, vec!["Rust", "Go"]);
+        assert_eq!(result.dropped, vec![
+            Dropped { item: "ℝ𝓊𝓈𝓉", kept_index: 0 },
+            Dropped { item: "𝔾ℴ", kept_index: 1 },
+            Dropped { item: "Rust", kept_index: 0 },
+        ]);
+    }
+}
@@ -1,2 +0,0 @@
-pub static INPUT_AND_OUTPUT_INDICES: [(u32, u16); 6294] = [(34, 0), (37, 2), (48, 5), (49, 6), (73, 7), (96, 8), (109, 9), (124, 11), (160, 12), (162, 13), (165, 15), (175, 17), (180, 18), (181, 19), (184, 20), (198, 21), (199, 23), (208, 25), (214, 27), (215, 28), (216, 29), (230, 31), (231, 33), (240, 35), (246, 37), (248, 38), (272, 40), (273, 42), (282, 44), (283, 45), (294, 46), (295, 48), (305, 50), (306, 51), (307, 53), (319, 55), (320, 57), (321, 59), (322, 61), (326, 63), (329, 64), (336, 66), (338, 67), (339, 69), (355, 71), (358, 72), (359, 74), (383, 76), (384, 77), (385, 79), (386, 81), (387, 83), (388, 85), (391, 86), (393, 88), (394, 90), (396, 92), (397, 94), (401, 95), (402, 97), (403, 99), (406, 101), (407, 102), (408, 104), (409, 106), (410, 108), (413, 110), (414, 112), (415, 114), (416, 116), (417, 118), (420, 120), (421, 122), (422, 124), (423, 125), (428, 126), (429, 128), (430, 130), (435, 132), (436, 134), (437, 136), (438, 138), (439, 140), (443, 141), (444, 143), (445, 144), (447, 145), (448, 146), (449, 147), (451, 149), (452, 150), (453, 152), (454, 154), (455, 156), (456, 158), (457, 160), (458, 162), (459, 164), (460, 166), (461, 168), (462, 169), (463, 170), (464, 171), (465, 172), (466, 173), (467, 174), (468, 175), (484, 176), (485, 178), (486, 180), (487, 181), (497, 182), (498, 184), (499, 186), (501, 188), (510, 189), (538, 192), (539, 193), (540, 194), (546, 195), (547, 196), (548, 197), (549, 199), (550, 201), (551, 202), (572, 203), (574, 205), (577, 207), (580, 208), (582, 210), (583, 212), (584, 214), (585, 216), (589, 218), (590, 220), (591, 222), (593, 224), (595, 225), (598, 227), (599, 229), (601, 231), (602, 232), (603, 234), (608, 235), (609, 237), (611, 238), (614, 239), (616, 241), (617, 243), (618, 244), (619, 245), (621, 247), (622, 249), (623, 251), (625, 252), (627, 255), (629, 257), (630, 259), (636, 261), (637, 263), (642, 265), (651, 267), (655, 268), (656, 269), (658, 271), (660, 272), (672, 273), (675, 275), (676, 277), (677, 279), (678, 281), (679, 283), (680, 285), (681, 287), (682, 289), (683, 291), (691, 293), (697, 294), (698, 295), (699, 297), (700, 298), (701, 299), (702, 300), (703, 301), (706, 302), (707, 303), (708, 304), (710, 305), (712, 306), (714, 307), (715, 308), (720, 309), (723, 310), (727, 311), (728, 312), (729, 313), (730, 314), (731, 315), (732, 316), (733, 317), (737, 319), (738, 320), (740, 321), (750, 322), (756, 324), (758, 325), (760, 327), (763, 328), (773, 329), (780, 330), (781, 331), (784, 332), (785, 334), (789, 335), (791, 336), (800, 337), (801, 338), (802, 339), (807, 340), (822, 341), (823, 342), (825, 343), (832, 344), (833, 345), (834, 346), (835, 347), (837, 348), (839, 349), (855, 350), (856, 351), (870, 352), (878, 353), (880, 354), (884, 355), (885, 356), (886, 357), (887, 358), (890, 359), (891, 360), (893, 361), (894, 362), (895, 363), (900, 364), (903, 365), (913, 366), (914, 367), (917, 368), (918, 369), (919, 370), (920, 371), (921, 373), (922, 374), (923, 375), (924, 376), (925, 377), (927, 378), (929, 379), (931, 380), (932, 381), (933, 382), (935, 383), (945, 384), (946, 385), (947, 386), (948, 387), (949, 388), (951, 389), (952, 391), (953, 393), (954, 394), (957, 395), (959, 396), (961, 397), (963, 398), (964, 399), (965, 400), (966, 401), (976, 402), (977, 403), (978, 405), (981, 406), (982, 407), (987, 408), (988, 409), (1000, 410), (1001, 411), (1008, 412), (1009, 413), (1010, 414), (1011, 415), (1012, 416), (1013, 418), (1015, 419), (1016, 420), (1017, 421), (1018, 422), (1021, 423), (1023, 424), (1028, 425), (1029, 426), (1030, 427), (1032, 428), (1040, 429), (1041, 430), (1042, 432), (1043, 433), (1045, 434), (1047, 435), (1049, 436), (1050, 437), (1051, 438), (1052, 439), (1053, 440), (1054, 441), (1055, 442), (1056, 443), (1057, 444), (1058, 445), (1059, 446), (1060, 447), (1061, 448), (1067, 449), (1068, 451), (1070, 452), (1072, 454), (1073, 455), (1074, 456), (1075, 457), (1077, 458), (1079, 459), (1080, 460), (1082, 461), (1084, 462), (1085, 463), (1086, 464), (1087, 465), (1088, 466), (1089, 467), (1090, 468), (1091, 469), (1092, 470), (1093, 471), (1098, 472), (1099, 474), (1100, 476), (1103, 477), (1108, 478), (1109, 479), (1110, 480), (1112, 481), (1115, 482), (1117, 484), (1121, 485), (1122, 486), (1123, 488), (1136, 490), (1137, 491), (1138, 492), (1139, 494), (1140, 496), (1141, 497), (1148, 498), (1149, 501), (1162, 504), (1163, 506), (1164, 508), (1165, 510), (1168, 512), (1169, 514), (1170, 516), (1171, 518), (1174, 520), (1175, 522), (1176, 524), (1177, 526), (1178, 528), (1179, 530), (1182, 532), (1183, 534), (1186, 536), (1187, 538), (1194, 540), (1195, 542), (1196, 544), (1197, 546), (1198, 548), (1199, 549), (1200, 550), (1201, 552), (1202, 554), (1211, 556), (1213, 557), (1214, 558), (1215, 560), (1216, 562), (1221, 563), (1222, 565), (1223, 567), (1224, 569), (1225, 571), (1226, 573), (1227, 575), (1228, 576), (1229, 577), (1230, 579), (1231, 581), (1236, 582), (1237, 584), (1240, 586), (1241, 587), (1248, 588), (1249, 589), (1256, 590), (1257, 592), (1281, 594), (1290, 595), (1292, 596), (1293, 597), (1296, 598), (1297, 599), (1307, 600), (1308, 601), (1309, 602), (1339, 603), (1348, 604), (1354, 605), (1356, 606), (1357, 607), (1359, 608), (1363, 609), (1365, 610), (1370, 611), (1373, 612), (1377, 613), (1379, 614), (1382, 615), (1390, 616), (1392, 617), (1397, 618), (1400, 619), (1402, 620), (1404, 621), (1405, 622), (1409, 623), (1412, 624), (1413, 625), (1415, 626), (1417, 628), (1436, 629), (1437, 630), (1444, 631), (1448, 632), (1453, 633), (1454, 634), (1455, 635), (1460, 636), (1465, 637), (1466, 638), (1472, 639), (1473, 640), (1474, 641), (1475, 642), (1476, 643), (1477, 644), (1493, 645), (1496, 646), (1497, 647), (1503, 648), (1505, 649), (1520, 650), (1521, 652), (1522, 654), (1523, 656), (1524, 657), (1545, 659), (1546, 663), (1549, 668), (1551, 669), (1560, 670), (1561, 671), (1562, 672), (1571, 673), (1572, 675), (1573, 677), (1574, 679), (1575, 681), (1579, 682), (1588, 684), (1597, 686), (1599, 688), (1607, 690), (1610, 691), (1611, 692), (1614, 693), (1615, 694), (1618, 695), (1619, 696), (1622, 697), (1623, 698), (1624, 699), (1625, 700), (1626, 701), (1627, 702), (1628, 703), (1629, 704), (1631, 705), (1632, 706), (1633, 707), (1637, 708), (1639, 709), (1640, 710), (1642, 711), (1643, 714), (1644, 715), (1645, 716), (1646, 717), (1647, 718), (1650, 719), (1651, 721), (1653, 723), (1654, 725), (1655, 727), (1656, 730), (1657, 732), (1662, 734), (1665, 736), (1669, 738), (1672, 740), (1675, 742), (1678, 744), (1681, 746), (1682, 748), (1688, 750), (1694, 752), (1695, 754), (1700, 756), (1703, 758), (1704, 759), (1705, 761), (1706, 762), (1709, 763), (1716, 765), (1717, 767), (1719, 769), (1722, 771), (1723, 772), (1725, 774), (1726, 776), (1729, 777), (1730, 778), (1731, 779), (1734, 780), (1735, 782), (1736, 784), (1737, 786), (1739, 788), (1740, 790), (1742, 791), (1744, 793), (1745, 794), (1746, 796), (1748, 797), (1749, 798), (1759, 799), (1768, 800), (1772, 802), (1774, 803), (1775, 805), (1776, 807), (1777, 808), (1778, 809), (1779, 810), (1780, 811), (1781, 812), (1782, 813), (1783, 814), (1784, 815), (1785, 816), (1789, 817), (1790, 819), (1791, 821), (1793, 823), (1794, 824), (1795, 825), (1796, 826), (1856, 827), (1857, 828), (1858, 829), (1863, 830), (1873, 831), (1878, 833), (1890, 835), (1891, 836), (1895, 838), (1896, 839), (1897, 841), (1900, 843), (1905, 845), (1906, 847), (1918, 849), (1984, 851), (1994, 852), (2027, 853), (2029, 854), (2030, 855), (2035, 856), (2036, 857), (2037, 858), (2042, 859), (2209, 860), (2212, 862), (2215, 864), (2216, 866), (2217, 868), (2222, 869), (2223, 872), (2224, 875), (2225, 876), (2226, 877), (2230, 879), (2231, 881), (2233, 884), (2234, 887), (2235, 890), (2236, 891), (2237, 892), (2277, 893), (2280, 894), (2282, 895), (2283, 896), (2285, 897), (2286, 898), (2288, 899), (2289, 900), (2290, 901), (2291, 902), (2296, 903), (2297, 904), (2298, 905), (2303, 906), (2304, 907), (2305, 908), (2306, 910), (2307, 911), (2308, 912), (2310, 914), (2312, 916), (2317, 919), (2318, 921), (2320, 923), (2321, 925), (2322, 927), (2323, 930), (2324, 933), (2364, 936), (2386, 937), (2387, 938), (2388, 939), (2405, 940), (2406, 942), (2407, 943), (2429, 944), (2433, 945), (2438, 947), (2492, 949), (2528, 950), (2529, 952), (2534, 954), (2538, 955), (2541, 956), (2562, 957), (2563, 958), (2566, 959), (2567, 961), (2568, 963), (2569, 965), (2570, 967), (2575, 969), (2576, 971), (2580, 973), (2620, 975), (2635, 976), (2637, 977), (2662, 978), (2663, 979), (2666, 980), (2689, 981), (2690, 983), (2691, 984), (2694, 985), (2701, 987), (2703, 989), (2704, 991), (2705, 993), (2707, 996), (2708, 999), (2748, 1002), (2749, 1003), (2753, 1004), (2754, 1005), (2765, 1006), (2790, 1007), (2792, 1008), (2793, 1009), (2794, 1010), (2798, 1011), (2800, 1012), (2817, 1013), (2819, 1015), (2822, 1016), (2848, 1018), (2876, 1019), (2918, 1020), (2920, 1021), (2946, 1022), (2954, 1023), (2972, 1025), (2992, 1026), (3006, 1027), (3016, 1028), (3018, 1029), (3019, 1031), (3020, 1033), (3021, 1035), (3031, 1036), (3046, 1037), (3047, 1038), (3048, 1039), (3050, 1040), (3051, 1041), (3052, 1043), (3053, 1045), (3054, 1046), (3056, 1047), (3058, 1048), (3060, 1050), (3061, 1052), (3063, 1053), (3064, 1055), (3066, 1056), (3072, 1058), (3074, 1060), (3075, 1061), (3091, 1062), (3092, 1064), (3104, 1066), (3106, 1068), (3109, 1070), (3117, 1072), (3118, 1074), (3127, 1076), (3129, 1078), (3138, 1080), (3140, 1082), (3168, 1084), (3169, 1086), (3174, 1088), (3201, 1089), (3202, 1091), (3203, 1092), (3205, 1093), (3206, 1094), (3207, 1095), (3218, 1096), (3219, 1097), (3220, 1099), (3228, 1101), (3230, 1102), (3235, 1103), (3247, 1104), (3249, 1105), (3250, 1106), (3297, 1107), (3302, 1109), (3303, 1110), (3304, 1111), (3311, 1112), (3329, 1113), (3330, 1115), (3331, 1116), (3336, 1117), (3337, 1119), (3338, 1120), (3340, 1122), (3344, 1124), (3347, 1126), (3348, 1128), (3353, 1130), (3356, 1132), (3360, 1133), (3363, 1134), (3377, 1135), (3380, 1136), (3382, 1137), (3386, 1138), (3391, 1140), (3392, 1141), (3394, 1142), (3395, 1143), (3400, 1144), (3406, 1146), (3418, 1147), (3423, 1150), (3425, 1153), (3430, 1154), (3434, 1155), (3435, 1157), (3436, 1160), (3437, 1163), (3438, 1164), (3439, 1167), (3446, 1169), (3449, 1172), (3451, 1174), (3452, 1176), (3458, 1178), (3459, 1179), (3561, 1180), (3562, 1182), (3563, 1183), (3567, 1184), (3587, 1186), (3595, 1187), (3599, 1188), (3604, 1189), (3605, 1190), (3607, 1191), (3617, 1192), (3622, 1193), (3635, 1194), (3649, 1196), (3653, 1198), (3661, 1199), (3664, 1200), (3720, 1201), (3725, 1202), (3738, 1203), (3739, 1204), (3741, 1205), (3742, 1206), (3743, 1207), (3763, 1208), (3768, 1210), (3769, 1211), (3784, 1212), (3785, 1213), (3786, 1214), (3787, 1215), (3789, 1216), (3792, 1217), (3804, 1218), (3805, 1220), (3840, 1222), (3842, 1225), (3843, 1229), (3852, 1233), (3854, 1234), (3867, 1236), (3870, 1238), (3871, 1240), (3895, 1242), (3946, 1243), (3959, 1244), (3961, 1247), (4046, 1250), (4053, 1252), (4054, 1253), (4096, 1254), (4112, 1256), (4125, 1258), (4127, 1259), (4137, 1261), (4138, 1263), (4150, 1268), (4152, 1269), (4160, 1270), (4171, 1271), (4197, 1273), (4198, 1274), (4207, 1276), (4208, 1279), (4222, 1281), (4225, 1283), (4254, 1285), (4256, 1287), (4327, 1288), (4339, 1289), (4351, 1290), (4353, 1291), (4356, 1293), (4360, 1295), (4362, 1297), (4365, 1299), (4371, 1301), (4372, 1303), (4373, 1305), (4374, 1307), (4375, 1309), (4376, 1311), (4377, 1313), (4378, 1315), (4379, 1317), (4380, 1319), (4381, 1321), (4382, 1323), (4383, 1325), (4384, 1327), (4385, 1329), (4386, 1331), (4387, 1334), (4388, 1337), (4389, 1340), (4390, 1343), (4391, 1346), (4392, 1348), (4393, 1350), (4394, 1352), (4395, 1354), (4396, 1356), (4397, 1359), (4398, 1361), (4399, 1363), (4400, 1365), (4401, 1367), (4402, 1369), (4403, 1371), (4404, 1374), (4405, 1377), (4406, 1379), (4407, 1381), (4408, 1383), (4409, 1385), (4410, 1387), (4411, 1389), (4413, 1391), (4415, 1393), (4417, 1395), (4418, 1397), (4419, 1399), (4420, 1401), (4421, 1403), (4422, 1405), (4423, 1407), (4424, 1409), (4425, 1411), (4426, 1413), (4427, 1415), (4429, 1417), (4431, 1419), (4433, 1421), (4434, 1423), (4435, 1425), (4438, 1427), (4439, 1429), (4440, 1431), (4442, 1433), (4443, 1435), (4444, 1437), (4445, 1439), (4446, 1441), (4450, 1443), (4452, 1445), (4454, 1447), (4456, 1449), (4458, 1451), (4459, 1453), (4460, 1456), (4463, 1458), (4464, 1460), (4465, 1463), (4467, 1465), (4468, 1466), (4469, 1468), (4470, 1469), (4471, 1471), (4472, 1473), (4473, 1475), (4474, 1477), (4475, 1479), (4476, 1481), (4477, 1483), (4478, 1485), (4479, 1487), (4480, 1489), (4481, 1492), (4482, 1495), (4483, 1497), (4484, 1499), (4485, 1501), (4486, 1504), (4487, 1506), (4488, 1508), (4489, 1510), (4490, 1512), (4491, 1515), (4492, 1518), (4493, 1521), (4494, 1523), (4495, 1525), (4496, 1527), (4497, 1530), (4498, 1532), (4499, 1535), (4500, 1537), (4501, 1539), (4502, 1541), (4503, 1543), (4504, 1546), (4505, 1548), (4506, 1550), (4507, 1552), (4508, 1554), (4509, 1556), (4511, 1558), (4512, 1560), (4513, 1562), (4514, 1564), (4515, 1566), (4516, 1568), (4517, 1570), (4518, 1572), (4519, 1574), (4520, 1577), (4521, 1578), (4522, 1580), (4523, 1582), (4524, 1583), (4525, 1585), (4526, 1587), (4527, 1588), (4528, 1589), (4529, 1591), (4530, 1593), (4531, 1595), (4532, 1597), (4533, 1599), (4534, 1601), (4535, 1603), (4536, 1604), (4537, 1605), (4538, 1607), (4539, 1608), (4540, 1610), (4541, 1611), (4542, 1612), (4543, 1613), (4544, 1614), (4545, 1615), (4546, 1616), (4547, 1617), (4548, 1619), (4549, 1622), (4550, 1624), (4551, 1626), (4552, 1628), (4553, 1630), (4554, 1632), (4555, 1634), (4556, 1636), (4557, 1639), (4558, 1641), (4559, 1643), (4560, 1646), (4561, 1648), (4562, 1651), (4563, 1654), (4564, 1657), (4565, 1660), (4566, 1663), (4567, 1666), (4568, 1668), (4569, 1670), (4570, 1672), (4571, 1674), (4572, 1676), (4573, 1678), (4574, 1680), (4575, 1683), (4576, 1685), (4577, 1687), (4578, 1689), (4579, 1691), (4580, 1693), (4581, 1695), (4582, 1697), (4583, 1699), (4584, 1701), (4585, 1703), (4586, 1705), (4587, 1707), (4588, 1708), (4589, 1710), (4590, 1713), (4591, 1715), (4592, 1717), (4593, 1718), (4594, 1720), (4595, 1722), (4596, 1724), (4597, 1726), (4598, 1728), (4599, 1730), (4600, 1732), (4601, 1734), (4602, 1735), (4603, 1737), (4604, 1739), (4605, 1741), (4606, 1743), (4607, 1745), (4608, 1747), (4643, 1748), (4672, 1749), (4704, 1750), (4756, 1751), (4816, 1752), (5024, 1753), (5025, 1754), (5026, 1755), (5028, 1756), (5029, 1758), (5032, 1759), (5033, 1760), (5034, 1761), (5035, 1762), (5036, 1763), (5038, 1764), (5040, 1765), (5041, 1766), (5043, 1767), (5047, 1768), (5051, 1769), (5053, 1770), (5054, 1771), (5055, 1773), (5056, 1774), (5058, 1775), (5059, 1776), (5063, 1777), (5067, 1778), (5068, 1779), (5070, 1781), (5071, 1782), (5074, 1783), (5076, 1784), (5077, 1785), (5081, 1786), (5082, 1787), (5086, 1788), (5087, 1789), (5090, 1790), (5094, 1791), (5095, 1792), (5099, 1793), (5102, 1795), (5104, 1796), (5106, 1797), (5107, 1799), (5108, 1800), (5115, 1801), (5116, 1802), (5120, 1803), (5123, 1804), (5132, 1805), (5133, 1807), (5134, 1809), (5135, 1811), (5136, 1813), (5137, 1815), (5138, 1817), (5139, 1819), (5140, 1821), (5141, 1823), (5143, 1825), (5144, 1827), (5145, 1829), (5146, 1831), (5159, 1833), (5163, 1834), (5164, 1836), (5165, 1838), (5166, 1840), (5167, 1842), (5169, 1843), (5171, 1844), (5175, 1845), (5176, 1847), (5178, 1848), (5179, 1850), (5180, 1852), (5181, 1854), (5182, 1856), (5183, 1858), (5184, 1860), (5185, 1862), (5186, 1864), (5187, 1866), (5188, 1868), (5189, 1870), (5190, 1872), (5191, 1874), (5194, 1876), (5196, 1877), (5198, 1878), (5204, 1879), (5207, 1881), (5208, 1883), (5209, 1885), (5210, 1887), (5211, 1889), (5212, 1891), (5213, 1893), (5214, 1895), (5215, 1897), (5216, 1899), (5217, 1901), (5218, 1903), (5219, 1905), (5220, 1907), (5223, 1909), (5224, 1911), (5225, 1913), (5226, 1915), (5229, 1917), (5231, 1918), (5236, 1919), (5237, 1921), (5238, 1923), (5239, 1925), (5240, 1927), (5241, 1929), (5242, 1931), (5243, 1933), (5244, 1935), (5245, 1937), (5246, 1939), (5247, 1941), (5248, 1943), (5249, 1945), (5253, 1947), (5254, 1949), (5255, 1951), (5256, 1953), (5261, 1955), (5266, 1956), (5267, 1958), (5268, 1960), (5269, 1962), (5270, 1964), (5271, 1966), (5272, 1968), (5273, 1970), (5274, 1972), (5275, 1974), (5276, 1976), (5277, 1978), (5278, 1980), (5279, 1982), (5285, 1984), (5290, 1985), (5292, 1986), (5293, 1988), (5294, 1990), (5295, 1992), (5296, 1994), (5297, 1996), (5298, 1998), (5299, 2000), (5300, 2002), (5301, 2004), (5302, 2006), (5303, 2008), (5304, 2010), (5305, 2012), (5311, 2014), (5321, 2015), (5322, 2017), (5323, 2019), (5324, 2021), (5325, 2023), (5326, 2025), (5329, 2027), (5340, 2028), (5341, 2030), (5342, 2032), (5343, 2034), (5344, 2036), (5345, 2038), (5346, 2040), (5347, 2042), (5348, 2044), (5349, 2046), (5350, 2048), (5351, 2050), (5352, 2052), (5353, 2054), (5366, 2056), (5367, 2058), (5368, 2060), (5369, 2062), (5370, 2064), (5371, 2066), (5372, 2068), (5373, 2070), (5374, 2072), (5375, 2074), (5376, 2076), (5377, 2078), (5378, 2080), (5379, 2082), (5388, 2084), (5389, 2086), (5390, 2088), (5391, 2090), (5399, 2092), (5400, 2094), (5401, 2096), (5402, 2098), (5403, 2100), (5404, 2102), (5405, 2104), (5406, 2106), (5407, 2108), (5408, 2110), (5409, 2112), (5410, 2114), (5411, 2116), (5412, 2118), (5423, 2120), (5424, 2122), (5425, 2124), (5426, 2126), (5427, 2128), (5428, 2130), (5429, 2132), (5430, 2134), (5431, 2136), (5432, 2138), (5433, 2140), (5434, 2142), (5435, 2144), (5436, 2146), (5440, 2148), (5441, 2149), (5454, 2150), (5455, 2152), (5467, 2154), (5468, 2156), (5480, 2158), (5481, 2160), (5495, 2162), (5500, 2163), (5501, 2164), (5502, 2165), (5503, 2167), (5504, 2169), (5505, 2171), (5506, 2173), (5507, 2175), (5508, 2177), (5509, 2179), (5511, 2181), (5518, 2182), (5519, 2184), (5520, 2186), (5521, 2188), (5522, 2190), (5523, 2192), (5524, 2194), (5551, 2196), (5556, 2197), (5557, 2198), (5559, 2199), (5572, 2200), (5573, 2201), (5598, 2202), (5610, 2203), (5615, 2204), (5616, 2205), (5623, 2206), (5634, 2207), (5635, 2208), (5636, 2209), (5639, 2210), (5666, 2211), (5667, 2212), (5668, 2213), (5678, 2214), (5679, 2215), (5684, 2216), (5685, 2217), (5741, 2218), (5742, 2219), (5743, 2220), (5744, 2222), (5745, 2224), (5746, 2226), (5747, 2228), (5748, 2230), (5749, 2232), (5750, 2234), (5751, 2236), (5752, 2238), (5753, 2240), (5754, 2242), (5755, 2244), (5756, 2246), (5757, 2248), (5760, 2250), (5810, 2251), (5815, 2252), (5825, 2253), (5826, 2254), (5836, 2255), (5845, 2256), (5846, 2257), (5848, 2258), (5857, 2259), (5867, 2260), (5868, 2261), (5869, 2262), (5872, 2263), (5941, 2264), (6051, 2265), (6071, 2266), (6072, 2267), (6073, 2268), (6074, 2269), (6086, 2270), (6091, 2271), (6099, 2272), (6100, 2273), (6101, 2274), (6105, 2275), (6106, 2276), (6147, 2277), (6153, 2278), (6229, 2279), (6294, 2280), (6323, 2281), (6326, 2283), (6329, 2285), (6338, 2287), (6342, 2289), (6343, 2291), (6344, 2293), (6345, 2295), (6346, 2297), (6347, 2299), (6348, 2301), (6349, 2303), (6350, 2305), (6351, 2307), (6352, 2309), (6353, 2311), (6354, 2313), (6355, 2315), (6363, 2317), (6364, 2318), (6365, 2320), (6368, 2322), (6371, 2324), (6372, 2326), (6373, 2328), (6376, 2330), (6378, 2332), (6381, 2334), (6384, 2336), (6386, 2338), (6608, 2340), (6609, 2341), (6784, 2342), (6800, 2343), (6825, 2344), (6827, 2346), (6836, 2348), (6839, 2349), (6994, 2350), (6995, 2351), (7000, 2352), (7004, 2353), (7007, 2354), (7228, 2356), (7295, 2358), (7376, 2360), (7378, 2361), (7379, 2362), (7381, 2364), (7384, 2365), (7385, 2366), (7386, 2367), (7388, 2368), (7389, 2369), (7390, 2370), (7405, 2371), (7428, 2372), (7432, 2373), (7435, 2374), (7437, 2375), (7439, 2376), (7440, 2377), (7441, 2378), (7444, 2379), (7452, 2381), (7456, 2382), (7457, 2383), (7458, 2384), (7460, 2385), (7462, 2386), (7463, 2387), (7464, 2388), (7465, 2389), (7467, 2390), (7486, 2391), (7506, 2392), (7531, 2393), (7534, 2395), (7535, 2397), (7536, 2400), (7538, 2402), (7539, 2404), (7540, 2406), (7541, 2408), (7542, 2410), (7544, 2412), (7547, 2413), (7548, 2415), (7549, 2417), (7550, 2419), (7551, 2421), (7555, 2423), (7564, 2424), (7568, 2425), (7583, 2426), (7586, 2427), (7610, 2428), (7611, 2429), (7662, 2430), (7747, 2431), (7834, 2432), (7837, 2433), (7935, 2434), (8061, 2435), (8125, 2436), (8126, 2437), (8127, 2438), (8128, 2439), (8175, 2440), (8182, 2441), (8189, 2442), (8190, 2443), (8192, 2444), (8193, 2445), (8194, 2446), (8195, 2447), (8196, 2448), (8197, 2449), (8198, 2450), (8199, 2451), (8200, 2452), (8201, 2453), (8202, 2454), (8208, 2455), (8209, 2456), (8210, 2457), (8211, 2458), (8212, 2459), (8213, 2460), (8214, 2461), (8216, 2463), (8217, 2464), (8218, 2465), (8219, 2466), (8220, 2467), (8221, 2469), (8223, 2471), (8226, 2473), (8228, 2474), (8229, 2475), (8230, 2477), (8231, 2480), (8232, 2481), (8233, 2482), (8239, 2483), (8240, 2484), (8241, 2488), (8242, 2493), (8243, 2494), (8244, 2496), (8245, 2499), (8246, 2500), (8247, 2502), (8249, 2505), (8250, 2506), (8252, 2507), (8254, 2509), (8257, 2510), (8259, 2511), (8260, 2512), (8263, 2513), (8264, 2515), (8265, 2517), (8270, 2519), (8274, 2520), (8275, 2523), (8279, 2524), (8282, 2528), (8285, 2529), (8286, 2530), (8287, 2531), (8304, 2532), (8313, 2533), (8353, 2534), (8356, 2536), (8357, 2537), (8360, 2540), (8361, 2542), (8363, 2544), (8364, 2547), (8365, 2548), (8366, 2550), (8374, 2552), (8381, 2554), (8411, 2555), (8448, 2556), (8449, 2559), (8450, 2562), (8451, 2563), (8453, 2565), (8454, 2568), (8455, 2571), (8456, 2572), (8457, 2573), (8458, 2575), (8459, 2576), (8460, 2577), (8461, 2578), (8462, 2579), (8463, 2580), (8464, 2582), (8465, 2583), (8466, 2584), (8467, 2585), (8469, 2586), (8470, 2587), (8473, 2589), (8474, 2590), (8475, 2591), (8476, 2592), (8477, 2593), (8481, 2594), (8484, 2597), (8486, 2598), (8487, 2599), (8488, 2600), (8489, 2601), (8490, 2602), (8492, 2603), (8493, 2604), (8494, 2605), (8495, 2606), (8496, 2607), (8497, 2608), (8499, 2609), (8500, 2610), (8501, 2611), (8502, 2612), (8503, 2613), (8504, 2614), (8505, 2615), (8507, 2616), (8508, 2619), (8509, 2620), (8510, 2621), (8511, 2622), (8512, 2623), (8513, 2624), (8514, 2625), (8515, 2626), (8517, 2627), (8518, 2628), (8519, 2629), (8520, 2630), (8521, 2631), (8544, 2632), (8545, 2633), (8546, 2635), (8547, 2638), (8548, 2640), (8549, 2641), (8550, 2643), (8551, 2646), (8552, 2650), (8553, 2652), (8554, 2653), (8555, 2655), (8556, 2658), (8557, 2659), (8558, 2660), (8559, 2661), (8560, 2662), (8561, 2663), (8562, 2665), (8563, 2668), (8564, 2670), (8565, 2671), (8566, 2673), (8567, 2676), (8568, 2680), (8569, 2682), (8570, 2683), (8571, 2685), (8572, 2688), (8573, 2689), (8574, 2690), (8575, 2691), (8579, 2693), (8580, 2694), (8593, 2695), (8597, 2696), (8629, 2697), (8638, 2698), (8639, 2699), (8704, 2700), (8707, 2701), (8710, 2702), (8719, 2703), (8721, 2704), (8722, 2705), (8724, 2706), (8725, 2708), (8726, 2709), (8727, 2710), (8728, 2711), (8729, 2712), (8734, 2713), (8739, 2715), (8741, 2716), (8744, 2718), (8745, 2719), (8746, 2720), (8747, 2721), (8748, 2722), (8749, 2724), (8751, 2727), (8752, 2729), (8758, 2732), (8760, 2733), (8764, 2735), (8784, 2736), (8785, 2738), (8791, 2741), (8793, 2743), (8794, 2745), (8798, 2747), (8803, 2749), (8810, 2750), (8811, 2752), (8834, 2754), (8835, 2755), (8853, 2756), (8854, 2757), (8857, 2759), (8861, 2760), (8868, 2762), (8869, 2763), (8896, 2764), (8897, 2765), (8898, 2766), (8899, 2767), (8900, 2768), (8901, 2769), (8904, 2770), (8918, 2771), (8919, 2773), (8920, 2775), (8921, 2778), (8942, 2781), (8943, 2782), (8948, 2785), (8959, 2786), (8960, 2787), (8997, 2788), (9001, 2789), (9002, 2790), (9025, 2791), (9049, 2792), (9050, 2794), (9052, 2796), (9055, 2798), (9057, 2799), (9058, 2801), (9059, 2803), (9060, 2805), (9064, 2807), (9065, 2809), (9067, 2810), (9068, 2812), (9075, 2814), (9076, 2815), (9077, 2816), (9078, 2817), (9079, 2819), (9080, 2821), (9081, 2823), (9082, 2825), (9087, 2826), (9116, 2827), (9119, 2828), (9122, 2829), (9125, 2830), (9130, 2831), (9134, 2832), (9153, 2833), (9154, 2834), (9155, 2835), (9158, 2836), (9192, 2837), (9212, 2839), (9213, 2840), (9214, 2841), (9290, 2842), (9312, 2844), (9313, 2845), (9314, 2846), (9315, 2847), (9316, 2848), (9317, 2849), (9318, 2850), (9319, 2851), (9320, 2852), (9321, 2853), (9332, 2854), (9333, 2857), (9334, 2860), (9335, 2863), (9336, 2866), (9337, 2869), (9338, 2872), (9339, 2875), (9340, 2878), (9341, 2881), (9342, 2885), (9343, 2889), (9344, 2893), (9345, 2897), (9346, 2901), (9347, 2905), (9348, 2909), (9349, 2913), (9350, 2917), (9351, 2921), (9352, 2925), (9353, 2927), (9354, 2929), (9355, 2931), (9356, 2933), (9357, 2935), (9358, 2937), (9359, 2939), (9360, 2941), (9361, 2943), (9362, 2946), (9363, 2949), (9364, 2952), (9365, 2955), (9366, 2958), (9367, 2961), (9368, 2964), (9369, 2967), (9370, 2970), (9371, 2973), (9372, 2976), (9373, 2979), (9374, 2982), (9375, 2985), (9376, 2988), (9377, 2991), (9378, 2994), (9379, 2997), (9380, 3000), (9381, 3003), (9382, 3006), (9383, 3009), (9384, 3012), (9385, 3016), (9386, 3019), (9387, 3022), (9388, 3025), (9389, 3028), (9390, 3031), (9391, 3034), (9392, 3037), (9393, 3040), (9394, 3043), (9395, 3046), (9396, 3049), (9397, 3052), (9400, 3055), (9413, 3056), (9415, 3057), (9435, 3058), (9472, 3059), (9473, 3060), (9475, 3061), (9487, 3062), (9507, 3063), (9585, 3064), (9587, 3065), (9608, 3066), (9616, 3067), (9620, 3068), (9623, 3069), (9629, 3070), (9632, 3071), (9649, 3072), (9651, 3073), (9655, 3074), (9656, 3075), (9658, 3076), (9661, 3077), (9665, 3078), (9671, 3079), (9674, 3080), (9675, 3081), (9678, 3082), (9696, 3083), (9702, 3084), (9737, 3085), (9744, 3086), (9765, 3087), (9776, 3088), (9784, 3089), (9806, 3090), (9826, 3091), (9833, 3092), (9834, 3094), (9900, 3097), (10088, 3098), (10089, 3099), (10094, 3100), (10095, 3101), (10098, 3102), (10099, 3103), (10100, 3104), (10101, 3105), (10133, 3106), (10134, 3107), (10135, 3108), (10178, 3109), (10184, 3110), (10185, 3112), (10187, 3114), (10189, 3115), (10201, 3116), (10216, 3117), (10217, 3118), (10539, 3119), (10540, 3120), (10595, 3121), (10597, 3123), (10606, 3125), (10607, 3127), (10649, 3129), (10672, 3130), (10686, 3131), (10692, 3132), (10693, 3133), (10695, 3134), (10710, 3135), (10713, 3136), (10740, 3137), (10741, 3139), (10742, 3140), (10744, 3142), (10745, 3143), (10752, 3144), (10753, 3145), (10754, 3146), (10755, 3147), (10756, 3148), (10757, 3149), (10758, 3150), (10764, 3151), (10781, 3155), (10784, 3156), (10785, 3158), (10786, 3159), (10787, 3161), (10788, 3163), (10789, 3165), (10790, 3167), (10791, 3169), (10793, 3171), (10794, 3173), (10799, 3175), (10800, 3176), (10813, 3178), (10814, 3179), (10815, 3180), (10858, 3181), (10862, 3183), (10868, 3185), (10869, 3188), (10870, 3190), (10917, 3193), (10922, 3195), (10923, 3196), (10967, 3197), (11003, 3199), (11005, 3202), (11244, 3204), (11245, 3205), (11246, 3206), (11247, 3207), (11367, 3208), (11369, 3210), (11396, 3212), (11397, 3213), (11398, 3214), (11400, 3215), (11401, 3216), (11406, 3217), (11410, 3218), (11412, 3219), (11413, 3220), (11414, 3221), (11416, 3222), (11418, 3223), (11422, 3224), (11423, 3225), (11424, 3226), (11426, 3227), (11427, 3228), (11428, 3229), (11429, 3230), (11430, 3231), (11432, 3232), (11434, 3233), (11435, 3234), (11436, 3235), (11437, 3236), (11438, 3237), (11441, 3238), (11444, 3239), (11450, 3241), (11452, 3242), (11453, 3243), (11462, 3244), (11466, 3245), (11468, 3246), (11469, 3247), (11472, 3248), (11473, 3249), (11474, 3250), (11484, 3251), (11492, 3252), (11497, 3253), (11513, 3254), (11569, 3256), (11575, 3258), (11576, 3259), (11577, 3260), (11578, 3261), (11585, 3262), (11592, 3264), (11593, 3267), (11599, 3268), (11601, 3269), (11604, 3270), (11605, 3271), (11609, 3272), (11613, 3273), (11616, 3274), (11619, 3275), (11752, 3276), (11754, 3277), (11757, 3278), (11759, 3279), (11766, 3280), (11767, 3281), (11802, 3282), (11806, 3284), (11807, 3286), (11814, 3288), (11815, 3289), (11816, 3290), (11817, 3292), (11818, 3294), (11819, 3295), (11820, 3296), (11822, 3297), (11824, 3298), (11825, 3299), (11826, 3300), (11829, 3301), (11833, 3302), (11837, 3303), (11839, 3304), (11840, 3305), (11906, 3306), (11907, 3307), (11909, 3308), (11913, 3309), (11915, 3310), (11918, 3311), (11919, 3312), (11920, 3313), (11922, 3314), (11923, 3315), (11924, 3316), (11926, 3317), (11927, 3318), (11928, 3319), (11929, 3320), (11931, 3321), (11934, 3322), (11935, 3323), (11936, 3324), (11937, 3325), (11938, 3326), (11939, 3327), (11940, 3328), (11942, 3329), (11944, 3330), (11947, 3331), (11949, 3332), (11951, 3333), (11953, 3334), (11954, 3335), (11961, 3336), (11962, 3337), (11966, 3338), (11967, 3339), (11968, 3340), (11969, 3341), (11970, 3342), (11971, 3343), (11972, 3344), (11973, 3345), (11976, 3346), (11977, 3347), (11979, 3348), (11980, 3349), (11981, 3350), (11983, 3351), (11984, 3352), (11985, 3353), (11986, 3354), (11987, 3355), (11988, 3356), (11990, 3357), (11992, 3358), (11993, 3359), (11994, 3360), (11995, 3361), (11996, 3362), (11997, 3363), (11999, 3364), (12000, 3365), (12002, 3366), (12004, 3367), (12005, 3368), (12008, 3369), (12009, 3370), (12011, 3371), (12012, 3372), (12013, 3373), (12014, 3374), (12015, 3375), (12016, 3376), (12018, 3377), (12019, 3378), (12032, 3379), (12033, 3380), (12034, 3381), (12035, 3382), (12036, 3383), (12037, 3384), (12038, 3385), (12039, 3386), (12040, 3387), (12041, 3388), (12042, 3389), (12043, 3390), (12044, 3391), (12045, 3392), (12046, 3393), (12047, 3394), (12048, 3395), (12049, 3396), (12050, 3397), (12051, 3398), (12052, 3399), (12053, 3400), (12054, 3401), (12055, 3402), (12056, 3403), (12057, 3404), (12058, 3405), (12059, 3406), (12060, 3407), (12061, 3408), (12062, 3409), (12063, 3410), (12064, 3411), (12065, 3412), (12066, 3413), (12067, 3414), (12068, 3415), (12069, 3416), (12070, 3417), (12071, 3418), (12072, 3419), (12073, 3420), (12074, 3421), (12075, 3422), (12076, 3423), (12077, 3424), (12078, 3425), (12079, 3426), (12080, 3427), (12081, 3428), (12082, 3429), (12083, 3430), (12084, 3431), (12085, 3432), (12086, 3433), (12087, 3434), (12088, 3435), (12089, 3436), (12090, 3437), (12091, 3438), (12092, 3439), (12093, 3440), (12094, 3441), (12095, 3442), (12096, 3443), (12097, 3444), (12098, 3445), (12099, 3446), (12100, 3447), (12101, 3448), (12102, 3449), (12103, 3450), (12104, 3451), (12105, 3452), (12106, 3453), (12107, 3454), (12108, 3455), (12109, 3456), (12110, 3457), (12111, 3458), (12112, 3459), (12113, 3460), (12114, 3461), (12115, 3462), (12116, 3463), (12117, 3464), (12118, 3465), (12119, 3466), (12120, 3467), (12121, 3468), (12122, 3469), (12123, 3470), (12124, 3471), (12125, 3472), (12126, 3473), (12127, 3474), (12128, 3475), (12129, 3476), (12130, 3477), (12131, 3478), (12132, 3479), (12133, 3480), (12134, 3481), (12135, 3482), (12136, 3483), (12137, 3484), (12138, 3485), (12139, 3486), (12140, 3487), (12141, 3488), (12142, 3489), (12143, 3490), (12144, 3491), (12145, 3492), (12146, 3493), (12147, 3494), (12148, 3495), (12149, 3496), (12150, 3497), (12151, 3498), (12152, 3499), (12153, 3500), (12154, 3501), (12155, 3502), (12156, 3503), (12157, 3504), (12158, 3505), (12159, 3506), (12160, 3507), (12161, 3508), (12162, 3509), (12163, 3510), (12164, 3511), (12165, 3512), (12166, 3513), (12167, 3514), (12168, 3515), (12169, 3516), (12170, 3517), (12171, 3518), (12172, 3519), (12173, 3520), (12174, 3521), (12175, 3522), (12176, 3523), (12177, 3524), (12178, 3525), (12179, 3526), (12180, 3527), (12181, 3528), (12182, 3529), (12183, 3530), (12184, 3531), (12185, 3532), (12186, 3533), (12187, 3534), (12188, 3535), (12189, 3536), (12190, 3537), (12191, 3538), (12192, 3539), (12193, 3540), (12194, 3541), (12195, 3542), (12196, 3543), (12197, 3544), (12198, 3545), (12199, 3546), (12200, 3547), (12201, 3548), (12202, 3549), (12203, 3550), (12204, 3551), (12205, 3552), (12206, 3553), (12207, 3554), (12208, 3555), (12209, 3556), (12210, 3557), (12211, 3558), (12212, 3559), (12213, 3560), (12214, 3561), (12215, 3562), (12216, 3563), (12217, 3564), (12218, 3565), (12219, 3566), (12220, 3567), (12221, 3568), (12222, 3569), (12223, 3570), (12224, 3571), (12225, 3572), (12226, 3573), (12227, 3574), (12228, 3575), (12229, 3576), (12230, 3577), (12231, 3578), (12232, 3579), (12233, 3580), (12234, 3581), (12235, 3582), (12236, 3583), (12237, 3584), (12238, 3585), (12239, 3586), (12240, 3587), (12241, 3588), (12242, 3589), (12243, 3590), (12244, 3591), (12245, 3592), (12290, 3593), (12291, 3594), (12295, 3596), (12296, 3597), (12297, 3598), (12306, 3599), (12308, 3600), (12309, 3601), (12314, 3602), (12315, 3603), (12332, 3604), (12333, 3605), (12339, 3606), (12342, 3607), (12344, 3608), (12345, 3609), (12346, 3610), (12367, 3611), (12442, 3612), (12443, 3613), (12444, 3614), (12448, 3615), (12452, 3616), (12456, 3617), (12459, 3618), (12479, 3619), (12488, 3620), (12491, 3621), (12494, 3622), (12495, 3623), (12504, 3624), (12525, 3625), (12539, 3626), (12593, 3627), (12594, 3628), (12595, 3630), (12596, 3632), (12597, 3633), (12598, 3635), (12599, 3637), (12600, 3638), (12601, 3640), (12602, 3641), (12603, 3643), (12604, 3645), (12605, 3647), (12606, 3649), (12607, 3651), (12608, 3653), (12609, 3655), (12610, 3656), (12611, 3657), (12612, 3659), (12613, 3661), (12614, 3662), (12615, 3664), (12616, 3665), (12617, 3666), (12618, 3668), (12619, 3669), (12620, 3670), (12621, 3671), (12622, 3672), (12623, 3673), (12624, 3674), (12625, 3676), (12626, 3677), (12627, 3679), (12628, 3680), (12629, 3682), (12630, 3683), (12631, 3685), (12632, 3686), (12633, 3688), (12634, 3691), (12635, 3693), (12636, 3694), (12637, 3695), (12638, 3697), (12639, 3700), (12640, 3702), (12641, 3703), (12642, 3704), (12643, 3706), (12644, 3707), (12645, 3708), (12646, 3710), (12647, 3712), (12648, 3714), (12649, 3716), (12650, 3719), (12651, 3721), (12652, 3724), (12653, 3726), (12654, 3728), (12655, 3730), (12656, 3732), (12657, 3734), (12658, 3736), (12659, 3738), (12660, 3740), (12661, 3743), (12662, 3746), (12663, 3748), (12664, 3750), (12665, 3752), (12666, 3755), (12667, 3757), (12668, 3759), (12669, 3761), (12670, 3763), (12671, 3765), (12672, 3766), (12673, 3768), (12674, 3769), (12675, 3771), (12676, 3773), (12677, 3775), (12678, 3777), (12679, 3778), (12680, 3780), (12681, 3783), (12682, 3785), (12683, 3787), (12684, 3790), (12685, 3792), (12686, 3793), (12752, 3795), (12753, 3796), (12755, 3797), (12756, 3798), (12758, 3799), (12762, 3800), (12763, 3801), (12767, 3802), (12768, 3803), (12800, 3804), (12801, 3807), (12802, 3810), (12803, 3813), (12804, 3816), (12805, 3819), (12806, 3822), (12807, 3825), (12808, 3828), (12809, 3831), (12810, 3834), (12811, 3837), (12812, 3840), (12813, 3843), (12814, 3846), (12815, 3849), (12816, 3852), (12817, 3855), (12818, 3858), (12819, 3861), (12820, 3864), (12821, 3867), (12822, 3870), (12823, 3873), (12824, 3876), (12825, 3879), (12826, 3882), (12827, 3885), (12828, 3888), (12829, 3891), (12830, 3895), (12832, 3899), (12833, 3902), (12834, 3905), (12835, 3908), (12836, 3911), (12837, 3914), (12838, 3917), (12839, 3920), (12840, 3923), (12841, 3926), (12842, 3929), (12843, 3932), (12844, 3935), (12845, 3938), (12846, 3941), (12847, 3944), (12848, 3947), (12849, 3950), (12850, 3953), (12851, 3956), (12852, 3959), (12853, 3962), (12854, 3965), (12855, 3968), (12856, 3971), (12857, 3974), (12858, 3977), (12859, 3980), (12860, 3983), (12861, 3986), (12862, 3989), (12863, 3992), (12864, 3995), (12865, 3998), (12866, 4001), (12867, 4004), (12992, 4007), (12993, 4009), (12994, 4011), (12995, 4013), (12996, 4015), (12997, 4017), (12998, 4019), (12999, 4021), (13000, 4023), (13001, 4025), (13002, 4028), (13003, 4031), (13144, 4034), (13145, 4036), (13146, 4038), (13147, 4040), (13148, 4042), (13149, 4044), (13150, 4046), (13151, 4048), (13152, 4050), (13153, 4052), (13154, 4054), (13155, 4057), (13156, 4060), (13157, 4063), (13158, 4066), (13159, 4069), (13160, 4072), (13161, 4075), (13162, 4078), (13163, 4081), (13164, 4084), (13165, 4087), (13166, 4090), (13167, 4093), (13168, 4096), (13280, 4099), (13281, 4101), (13282, 4103), (13283, 4105), (13284, 4107), (13285, 4109), (13286, 4111), (13287, 4113), (13288, 4115), (13289, 4117), (13290, 4120), (13291, 4123), (13292, 4126), (13293, 4129), (13294, 4132), (13295, 4135), (13296, 4138), (13297, 4141), (13298, 4144), (13299, 4147), (13300, 4150), (13301, 4153), (13302, 4156), (13303, 4159), (13304, 4162), (13305, 4165), (13306, 4168), (13307, 4171), (13308, 4174), (13309, 4177), (13310, 4180), (14771, 4183), (17307, 4184), (17440, 4185), (19968, 4186), (20022, 4187), (20031, 4188), (20482, 4189), (20540, 4190), (21855, 4191), (22231, 4192), (22635, 4193), (22763, 4194), (22783, 4195), (23296, 4196), (24114, 4197), (24144, 4198), (25144, 4199), (25609, 4200), (26211, 4201), (26217, 4202), (26358, 4203), (26406, 4204), (26623, 4205), (27113, 4206), (27175, 4207), (28505, 4208), (30799, 4209), (32118, 4210), (32934, 4211), (32970, 4212), (32976, 4213), (33014, 4214), (33025, 4215), (33063, 4216), (33089, 4217), (33191, 4218), (34111, 4219), (34369, 4220), (35358, 4221), (35453, 4222), (35727, 4223), (35939, 4224), (36230, 4225), (36346, 4226), (36507, 4227), (36647, 4228), (37086, 4229), (37806, 4230), (38584, 4231), (40515, 4232), (40658, 4233), (40899, 4234), (42132, 4235), (42140, 4236), (42142, 4237), (42151, 4238), (42152, 4239), (42156, 4240), (42160, 4241), (42170, 4242), (42174, 4243), (42175, 4244), (42176, 4245), (42178, 4246), (42192, 4247), (42193, 4248), (42194, 4249), (42195, 4250), (42196, 4251), (42198, 4252), (42199, 4253), (42201, 4254), (42202, 4255), (42203, 4256), (42204, 4257), (42205, 4258), (42206, 4259), (42207, 4260), (42208, 4261), (42209, 4262), (42210, 4263), (42211, 4264), (42213, 4265), (42214, 4266), (42215, 4267), (42218, 4268), (42219, 4269), (42220, 4270), (42221, 4271), (42222, 4272), (42223, 4273), (42224, 4274), (42225, 4275), (42226, 4276), (42227, 4277), (42228, 4278), (42229, 4279), (42231, 4280), (42232, 4281), (42233, 4282), (42234, 4283), (42235, 4285), (42237, 4287), (42238, 4288), (42239, 4290), (42510, 4291), (42564, 4292), (42565, 4293), (42567, 4294), (42573, 4295), (42576, 4296), (42577, 4298), (42600, 4301), (42607, 4302), (42620, 4303), (42622, 4304), (42645, 4305), (42648, 4307), (42649, 4309), (42650, 4311), (42657, 4312), (42672, 4313), (42673, 4314), (42701, 4315), (42702, 4316), (42715, 4317), (42719, 4318), (42731, 4319), (42735, 4320), (42736, 4321), (42737, 4322), (42740, 4323), (42772, 4325), (42774, 4326), (42792, 4327), (42793, 4329), (42801, 4331), (42802, 4332), (42803, 4334), (42804, 4336), (42805, 4338), (42806, 4340), (42807, 4342), (42808, 4344), (42809, 4346), (42810, 4348), (42811, 4350), (42812, 4352), (42813, 4354), (42816, 4356), (42826, 4358), (42827, 4360), (42830, 4362), (42831, 4364), (42842, 4366), (42849, 4367), (42858, 4369), (42859, 4370), (42862, 4371), (42871, 4372), (42872, 4374), (42874, 4375), (42889, 4376), (42892, 4377), (42895, 4378), (42901, 4379), (42904, 4380), (42905, 4381), (42906, 4382), (42907, 4383), (42909, 4384), (42910, 4385), (42911, 4386), (42923, 4387), (42929, 4388), (42930, 4389), (42931, 4390), (42932, 4391), (42933, 4392), (42934, 4393), (42935, 4394), (42999, 4395), (43056, 4396), (43360, 4397), (43361, 4399), (43362, 4401), (43363, 4403), (43364, 4405), (43365, 4407), (43366, 4410), (43367, 4412), (43368, 4415), (43369, 4417), (43370, 4419), (43371, 4422), (43372, 4425), (43373, 4427), (43374, 4429), (43375, 4431), (43376, 4433), (43377, 4435), (43378, 4437), (43379, 4440), (43380, 4442), (43381, 4444), (43382, 4447), (43383, 4449), (43384, 4451), (43385, 4454), (43386, 4456), (43387, 4458), (43388, 4460), (43410, 4462), (43427, 4463), (43462, 4464), (43471, 4465), (43603, 4466), (43606, 4467), (43826, 4468), (43829, 4469), (43837, 4470), (43838, 4471), (43839, 4473), (43841, 4475), (43842, 4478), (43847, 4481), (43848, 4482), (43853, 4483), (43854, 4484), (43858, 4485), (43859, 4486), (43861, 4487), (43866, 4488), (43872, 4489), (43874, 4490), (43875, 4492), (43888, 4494), (43889, 4495), (43890, 4496), (43892, 4497), (43893, 4499), (43898, 4500), (43899, 4501), (43900, 4502), (43902, 4503), (43904, 4504), (43905, 4505), (43907, 4506), (43911, 4507), (43915, 4508), (43918, 4509), (43920, 4511), (43923, 4512), (43931, 4513), (43932, 4514), (43935, 4516), (43938, 4517), (43945, 4518), (43946, 4519), (43950, 4520), (43951, 4521), (43954, 4522), (43958, 4523), (43963, 4524), (55216, 4526), (55217, 4528), (55218, 4531), (55219, 4533), (55220, 4536), (55221, 4538), (55222, 4540), (55223, 4543), (55224, 4546), (55225, 4548), (55226, 4550), (55227, 4552), (55228, 4555), (55229, 4557), (55230, 4560), (55231, 4563), (55232, 4565), (55233, 4568), (55234, 4571), (55235, 4573), (55236, 4575), (55237, 4577), (55238, 4579), (55243, 4582), (55244, 4584), (55245, 4586), (55246, 4588), (55247, 4591), (55248, 4593), (55249, 4595), (55250, 4598), (55251, 4600), (55252, 4602), (55253, 4604), (55254, 4607), (55255, 4610), (55256, 4613), (55257, 4616), (55258, 4619), (55259, 4622), (55260, 4624), (55261, 4627), (55262, 4629), (55263, 4631), (55264, 4634), (55265, 4636), (55266, 4639), (55267, 4641), (55268, 4643), (55269, 4646), (55270, 4648), (55271, 4650), (55272, 4653), (55273, 4655), (55274, 4657), (55275, 4659), (55276, 4662), (55277, 4665), (55278, 4668), (55279, 4670), (55280, 4672), (55281, 4674), (55282, 4676), (55283, 4678), (55284, 4680), (55285, 4683), (55286, 4685), (55287, 4687), (55288, 4689), (55289, 4692), (55290, 4694), (55291, 4696), (63744, 4698), (63745, 4699), (63746, 4700), (63747, 4701), (63748, 4702), (63749, 4703), (63750, 4704), (63751, 4705), (63752, 4706), (63753, 4707), (63754, 4708), (63755, 4709), (63756, 4710), (63757, 4711), (63758, 4712), (63759, 4713), (63760, 4714), (63761, 4715), (63762, 4716), (63763, 4717), (63764, 4718), (63765, 4719), (63766, 4720), (63767, 4721), (63768, 4722), (63769, 4723), (63770, 4724), (63771, 4725), (63772, 4726), (63773, 4727), (63774, 4728), (63775, 4729), (63776, 4730), (63777, 4731), (63778, 4732), (63779, 4733), (63780, 4734), (63781, 4735), (63782, 4736), (63783, 4737), (63784, 4738), (63785, 4739), (63786, 4740), (63787, 4741), (63788, 4742), (63789, 4743), (63790, 4744), (63791, 4745), (63792, 4746), (63793, 4747), (63794, 4748), (63795, 4749), (63796, 4750), (63797, 4751), (63798, 4752), (63799, 4753), (63800, 4754), (63801, 4755), (63802, 4756), (63803, 4757), (63804, 4758), (63805, 4759), (63806, 4760), (63807, 4761), (63808, 4762), (63809, 4763), (63810, 4764), (63811, 4765), (63812, 4766), (63813, 4767), (63814, 4768), (63815, 4769), (63816, 4770), (63817, 4771), (63818, 4772), (63819, 4773), (63820, 4774), (63821, 4775), (63822, 4776), (63823, 4777), (63824, 4778), (63825, 4779), (63826, 4780), (63827, 4781), (63828, 4782), (63829, 4783), (63830, 4784), (63831, 4785), (63832, 4786), (63833, 4787), (63834, 4788), (63835, 4789), (63836, 4790), (63837, 4791), (63838, 4792), (63839, 4793), (63840, 4794), (63841, 4795), (63842, 4796), (63843, 4797), (63844, 4798), (63845, 4799), (63846, 4800), (63847, 4801), (63848, 4802), (63849, 4803), (63850, 4804), (63851, 4805), (63852, 4806), (63853, 4807), (63854, 4808), (63855, 4809), (63856, 4810), (63857, 4811), (63858, 4812), (63859, 4813), (63860, 4814), (63861, 4815), (63862, 4816), (63863, 4817), (63864, 4818), (63865, 4819), (63866, 4820), (63867, 4821), (63868, 4822), (63869, 4823), (63870, 4824), (63871, 4825), (63872, 4826), (63873, 4827), (63874, 4828), (63875, 4829), (63876, 4830), (63877, 4831), (63878, 4832), (63879, 4833), (63880, 4834), (63881, 4835), (63882, 4836), (63883, 4837), (63884, 4838), (63885, 4839), (63886, 4840), (63887, 4841), (63888, 4842), (63889, 4843), (63890, 4844), (63891, 4845), (63892, 4846), (63893, 4847), (63894, 4848), (63895, 4849), (63896, 4850), (63897, 4851), (63898, 4852), (63899, 4853), (63900, 4854), (63901, 4855), (63902, 4856), (63903, 4857), (63904, 4858), (63905, 4859), (63906, 4860), (63907, 4861), (63908, 4862), (63909, 4863), (63910, 4864), (63911, 4865), (63912, 4866), (63913, 4867), (63914, 4868), (63915, 4869), (63916, 4870), (63917, 4871), (63918, 4872), (63919, 4873), (63920, 4874), (63921, 4875), (63922, 4876), (63923, 4877), (63924, 4878), (63925, 4879), (63926, 4880), (63927, 4881), (63928, 4882), (63929, 4883), (63930, 4884), (63931, 4885), (63932, 4886), (63933, 4887), (63934, 4888), (63935, 4889), (63936, 4890), (63937, 4891), (63938, 4892), (63939, 4893), (63940, 4894), (63941, 4895), (63942, 4896), (63943, 4897), (63944, 4898), (63945, 4899), (63946, 4900), (63947, 4901), (63948, 4902), (63949, 4903), (63950, 4904), (63951, 4905), (63952, 4906), (63953, 4907), (63954, 4908), (63955, 4909), (63956, 4910), (63957, 4911), (63958, 4912), (63959, 4913), (63960, 4914), (63961, 4915), (63962, 4916), (63963, 4917), (63964, 4918), (63965, 4919), (63966, 4920), (63967, 4921), (63968, 4922), (63969, 4923), (63970, 4924), (63971, 4925), (63972, 4926), (63973, 4927), (63974, 4928), (63975, 4929), (63976, 4930), (63977, 4931), (63978, 4932), (63979, 4933), (63980, 4934), (63981, 4935), (63982, 4936), (63983, 4937), (63984, 4938), (63985, 4939), (63986, 4940), (63987, 4941), (63988, 4942), (63989, 4943), (63990, 4944), (63991, 4945), (63992, 4946), (63993, 4947), (63994, 4948), (63995, 4949), (63996, 4950), (63997, 4951), (63998, 4952), (63999, 4953), (64000, 4954), (64001, 4955), (64002, 4956), (64003, 4957), (64004, 4958), (64005, 4959), (64006, 4960), (64007, 4961), (64008, 4962), (64009, 4963), (64010, 4964), (64011, 4965), (64012, 4966), (64013, 4967), (64016, 4968), (64018, 4969), (64021, 4970), (64022, 4971), (64023, 4972), (64024, 4973), (64025, 4974), (64026, 4975), (64027, 4976), (64028, 4977), (64029, 4978), (64030, 4979), (64032, 4980), (64034, 4981), (64037, 4982), (64038, 4983), (64042, 4984), (64043, 4985), (64044, 4986), (64045, 4987), (64046, 4988), (64047, 4989), (64048, 4990), (64049, 4991), (64050, 4992), (64051, 4993), (64052, 4994), (64053, 4995), (64054, 4996), (64055, 4997), (64056, 4998), (64057, 4999), (64058, 5000), (64059, 5001), (64060, 5002), (64061, 5003), (64062, 5004), (64063, 5005), (64064, 5006), (64065, 5007), (64066, 5008), (64067, 5009), (64068, 5010), (64069, 5011), (64070, 5012), (64071, 5013), (64072, 5014), (64073, 5015), (64074, 5016), (64075, 5017), (64076, 5018), (64077, 5019), (64078, 5020), (64079, 5021), (64080, 5022), (64081, 5023), (64082, 5024), (64083, 5025), (64084, 5026), (64085, 5027), (64086, 5028), (64087, 5029), (64088, 5030), (64089, 5031), (64090, 5032), (64091, 5033), (64092, 5034), (64093, 5035), (64094, 5036), (64095, 5037), (64096, 5038), (64097, 5039), (64098, 5040), (64099, 5041), (64100, 5042), (64101, 5043), (64102, 5044), (64103, 5045), (64104, 5046), (64105, 5047), (64106, 5048), (64107, 5049), (64108, 5050), (64109, 5051), (64112, 5052), (64113, 5053), (64114, 5054), (64115, 5055), (64116, 5056), (64117, 5057), (64118, 5058), (64119, 5059), (64120, 5060), (64121, 5061), (64122, 5062), (64123, 5063), (64124, 5064), (64125, 5065), (64126, 5066), (64127, 5067), (64128, 5068), (64129, 5069), (64130, 5070), (64131, 5071), (64132, 5072), (64133, 5073), (64134, 5074), (64135, 5075), (64136, 5076), (64137, 5077), (64138, 5078), (64139, 5079), (64140, 5080), (64141, 5081), (64142, 5082), (64143, 5083), (64144, 5084), (64145, 5085), (64146, 5086), (64147, 5087), (64148, 5088), (64149, 5089), (64150, 5090), (64151, 5091), (64152, 5092), (64153, 5093), (64154, 5094), (64155, 5095), (64156, 5096), (64157, 5097), (64158, 5098), (64159, 5099), (64160, 5100), (64161, 5101), (64162, 5102), (64163, 5103), (64164, 5104), (64165, 5105), (64166, 5106), (64167, 5107), (64168, 5108), (64169, 5109), (64170, 5110), (64171, 5111), (64172, 5112), (64173, 5113), (64174, 5114), (64175, 5115), (64176, 5116), (64177, 5117), (64178, 5118), (64179, 5119), (64180, 5120), (64181, 5121), (64182, 5122), (64183, 5123), (64184, 5124), (64185, 5125), (64186, 5126), (64187, 5127), (64188, 5128), (64189, 5129), (64190, 5130), (64191, 5131), (64192, 5132), (64193, 5133), (64194, 5134), (64195, 5135), (64196, 5136), (64197, 5137), (64198, 5138), (64199, 5139), (64200, 5140), (64201, 5141), (64202, 5142), (64203, 5143), (64204, 5144), (64205, 5145), (64206, 5146), (64207, 5147), (64208, 5148), (64209, 5149), (64210, 5150), (64211, 5151), (64212, 5152), (64213, 5153), (64214, 5154), (64215, 5155), (64216, 5156), (64217, 5157), (64256, 5158), (64257, 5160), (64258, 5162), (64259, 5164), (64260, 5167), (64262, 5170), (64275, 5172), (64276, 5174), (64277, 5176), (64278, 5178), (64279, 5180), (64288, 5182), (64289, 5183), (64290, 5184), (64291, 5185), (64292, 5186), (64293, 5187), (64294, 5188), (64295, 5189), (64296, 5190), (64297, 5191), (64299, 5193), (64301, 5194), (64303, 5195), (64304, 5196), (64313, 5197), (64329, 5198), (64335, 5199), (64336, 5201), (64337, 5202), (64338, 5203), (64339, 5204), (64340, 5205), (64341, 5206), (64342, 5207), (64343, 5209), (64344, 5211), (64345, 5213), (64346, 5215), (64347, 5216), (64348, 5217), (64349, 5218), (64350, 5219), (64351, 5220), (64352, 5221), (64353, 5222), (64354, 5223), (64355, 5224), (64356, 5225), (64357, 5226), (64358, 5227), (64359, 5229), (64360, 5231), (64361, 5233), (64362, 5235), (64363, 5237), (64364, 5239), (64365, 5241), (64366, 5243), (64367, 5244), (64368, 5245), (64369, 5246), (64370, 5247), (64371, 5248), (64372, 5249), (64373, 5250), (64374, 5251), (64375, 5252), (64376, 5253), (64377, 5254), (64378, 5255), (64379, 5256), (64380, 5257), (64381, 5258), (64382, 5259), (64383, 5260), (64384, 5261), (64385, 5262), (64386, 5263), (64387, 5264), (64388, 5265), (64389, 5266), (64390, 5267), (64391, 5269), (64392, 5271), (64393, 5273), (64394, 5275), (64395, 5277), (64396, 5279), (64397, 5281), (64398, 5283), (64399, 5284), (64400, 5285), (64401, 5286), (64402, 5287), (64403, 5288), (64404, 5289), (64405, 5290), (64406, 5291), (64407, 5292), (64408, 5293), (64409, 5294), (64410, 5295), (64411, 5296), (64412, 5297), (64413, 5298), (64414, 5299), (64415, 5300), (64416, 5301), (64417, 5303), (64418, 5305), (64419, 5307), (64420, 5309), (64421, 5310), (64422, 5311), (64423, 5312), (64424, 5313), (64425, 5314), (64426, 5315), (64427, 5316), (64428, 5317), (64429, 5318), (64430, 5319), (64431, 5320), (64432, 5321), (64433, 5322), (64467, 5323), (64468, 5325), (64469, 5327), (64470, 5329), (64471, 5331), (64472, 5333), (64473, 5335), (64474, 5337), (64475, 5339), (64476, 5341), (64477, 5343), (64478, 5346), (64479, 5348), (64480, 5350), (64481, 5351), (64482, 5352), (64483, 5354), (64484, 5356), (64485, 5357), (64486, 5358), (64487, 5359), (64488, 5360), (64489, 5361), (64490, 5362), (64491, 5365), (64492, 5368), (64493, 5371), (64494, 5374), (64495, 5377), (64496, 5380), (64497, 5384), (64498, 5388), (64499, 5392), (64500, 5396), (64501, 5400), (64502, 5404), (64503, 5407), (64504, 5410), (64505, 5413), (64506, 5416), (64507, 5419), (64508, 5422), (64509, 5423), (64510, 5424), (64511, 5425), (64512, 5426), (64513, 5429), (64514, 5432), (64515, 5435), (64516, 5438), (64517, 5441), (64518, 5443), (64519, 5445), (64520, 5447), (64521, 5449), (64522, 5451), (64523, 5453), (64524, 5455), (64525, 5457), (64526, 5459), (64527, 5461), (64528, 5463), (64529, 5465), (64530, 5468), (64531, 5471), (64532, 5474), (64533, 5477), (64534, 5479), (64535, 5481), (64536, 5483), (64537, 5485), (64538, 5487), (64539, 5489), (64540, 5491), (64541, 5493), (64542, 5495), (64543, 5497), (64544, 5499), (64545, 5501), (64546, 5503), (64547, 5505), (64548, 5507), (64549, 5509), (64550, 5511), (64551, 5513), (64552, 5515), (64553, 5517), (64554, 5519), (64555, 5521), (64556, 5523), (64557, 5525), (64558, 5527), (64559, 5529), (64560, 5531), (64561, 5533), (64562, 5535), (64563, 5537), (64564, 5539), (64565, 5541), (64566, 5543), (64567, 5545), (64568, 5547), (64569, 5549), (64570, 5551), (64571, 5553), (64572, 5555), (64573, 5557), (64574, 5559), (64575, 5561), (64576, 5563), (64577, 5565), (64578, 5567), (64579, 5569), (64580, 5571), (64581, 5573), (64582, 5575), (64583, 5577), (64584, 5579), (64585, 5581), (64586, 5583), (64587, 5585), (64588, 5587), (64589, 5589), (64590, 5591), (64591, 5593), (64592, 5595), (64593, 5597), (64594, 5599), (64595, 5601), (64596, 5603), (64597, 5605), (64598, 5607), (64599, 5609), (64600, 5611), (64601, 5613), (64602, 5615), (64603, 5617), (64604, 5619), (64605, 5621), (64606, 5623), (64607, 5625), (64608, 5627), (64609, 5629), (64610, 5631), (64611, 5633), (64612, 5635), (64613, 5638), (64614, 5641), (64615, 5644), (64616, 5647), (64617, 5650), (64618, 5653), (64619, 5655), (64620, 5657), (64621, 5659), (64622, 5661), (64623, 5663), (64624, 5665), (64625, 5667), (64626, 5669), (64627, 5671), (64628, 5673), (64629, 5675), (64630, 5677), (64631, 5680), (64632, 5683), (64633, 5686), (64634, 5689), (64635, 5692), (64636, 5695), (64637, 5697), (64638, 5699), (64639, 5701), (64640, 5703), (64641, 5705), (64642, 5707), (64643, 5709), (64644, 5711), (64645, 5713), (64646, 5715), (64647, 5717), (64648, 5719), (64649, 5721), (64650, 5723), (64651, 5725), (64652, 5727), (64653, 5729), (64654, 5731), (64655, 5733), (64656, 5735), (64657, 5737), (64658, 5739), (64659, 5741), (64660, 5743), (64661, 5745), (64662, 5747), (64663, 5749), (64664, 5752), (64665, 5755), (64666, 5758), (64667, 5761), (64668, 5764), (64669, 5766), (64670, 5768), (64671, 5770), (64672, 5772), (64673, 5774), (64674, 5776), (64675, 5778), (64676, 5780), (64677, 5782), (64678, 5784), (64679, 5787), (64680, 5789), (64681, 5791), (64682, 5793), (64683, 5795), (64684, 5797), (64685, 5799), (64686, 5801), (64687, 5803), (64688, 5805), (64689, 5807), (64690, 5809), (64691, 5811), (64692, 5813), (64693, 5815), (64694, 5817), (64695, 5819), (64696, 5821), (64697, 5823), (64698, 5825), (64699, 5827), (64700, 5829), (64701, 5831), (64702, 5833), (64703, 5835), (64704, 5837), (64705, 5839), (64706, 5841), (64707, 5843), (64708, 5845), (64709, 5847), (64710, 5849), (64711, 5851), (64712, 5853), (64713, 5855), (64714, 5857), (64715, 5859), (64716, 5861), (64717, 5863), (64718, 5865), (64719, 5867), (64720, 5869), (64721, 5871), (64722, 5873), (64723, 5875), (64724, 5877), (64725, 5879), (64726, 5881), (64727, 5883), (64728, 5885), (64729, 5887), (64730, 5889), (64731, 5891), (64732, 5893), (64733, 5895), (64734, 5897), (64735, 5899), (64736, 5902), (64737, 5905), (64738, 5907), (64739, 5909), (64740, 5911), (64741, 5913), (64742, 5916), (64743, 5919), (64744, 5921), (64745, 5923), (64746, 5926), (64747, 5929), (64748, 5931), (64749, 5933), (64750, 5935), (64751, 5937), (64752, 5939), (64753, 5941), (64754, 5943), (64755, 5945), (64756, 5947), (64757, 5949), (64758, 5951), (64759, 5953), (64760, 5955), (64761, 5957), (64762, 5959), (64763, 5961), (64764, 5963), (64765, 5965), (64766, 5968), (64767, 5971), (64768, 5973), (64769, 5975), (64770, 5977), (64771, 5979), (64772, 5981), (64773, 5983), (64774, 5985), (64775, 5987), (64776, 5989), (64777, 5991), (64778, 5994), (64779, 5997), (64780, 6000), (64781, 6003), (64782, 6006), (64783, 6008), (64784, 6010), (64785, 6012), (64786, 6014), (64787, 6016), (64788, 6018), (64789, 6020), (64790, 6022), (64791, 6024), (64792, 6026), (64793, 6028), (64794, 6031), (64795, 6034), (64796, 6036), (64797, 6038), (64798, 6040), (64799, 6042), (64800, 6044), (64801, 6046), (64802, 6048), (64803, 6050), (64804, 6052), (64805, 6054), (64806, 6057), (64807, 6060), (64808, 6063), (64809, 6066), (64810, 6069), (64811, 6071), (64812, 6073), (64813, 6075), (64814, 6078), (64815, 6081), (64816, 6084), (64817, 6087), (64818, 6089), (64819, 6092), (64820, 6094), (64821, 6096), (64822, 6098), (64823, 6100), (64824, 6103), (64825, 6106), (64826, 6109), (64827, 6111), (64828, 6113), (64829, 6115), (64830, 6117), (64831, 6118), (64848, 6119), (64849, 6122), (64850, 6125), (64851, 6128), (64852, 6131), (64853, 6134), (64854, 6137), (64855, 6140), (64856, 6143), (64857, 6146), (64858, 6149), (64859, 6152), (64860, 6155), (64861, 6158), (64862, 6161), (64863, 6164), (64864, 6167), (64865, 6170), (64866, 6173), (64867, 6176), (64868, 6179), (64869, 6182), (64870, 6185), (64871, 6188), (64872, 6192), (64873, 6196), (64874, 6200), (64875, 6204), (64876, 6208), (64877, 6212), (64878, 6216), (64879, 6219), (64880, 6222), (64881, 6225), (64882, 6228), (64883, 6231), (64884, 6234), (64885, 6237), (64886, 6240), (64887, 6243), (64888, 6246), (64889, 6249), (64890, 6252), (64891, 6255), (64892, 6258), (64893, 6261), (64894, 6264), (64895, 6267), (64896, 6270), (64897, 6273), (64898, 6276), (64899, 6279), (64900, 6282), (64901, 6285), (64902, 6288), (64903, 6291), (64904, 6294), (64905, 6297), (64906, 6300), (64907, 6303), (64908, 6306), (64909, 6309), (64910, 6312), (64911, 6315), (64914, 6318), (64915, 6321), (64916, 6324), (64917, 6327), (64918, 6330), (64919, 6333), (64920, 6336), (64921, 6339), (64922, 6342), (64923, 6345), (64924, 6348), (64925, 6351), (64926, 6354), (64927, 6357), (64928, 6360), (64929, 6363), (64930, 6366), (64931, 6369), (64932, 6372), (64933, 6375), (64934, 6378), (64935, 6381), (64936, 6384), (64937, 6387), (64938, 6390), (64939, 6394), (64940, 6397), (64941, 6400), (64942, 6403), (64943, 6406), (64944, 6409), (64945, 6412), (64946, 6415), (64947, 6418), (64948, 6421), (64949, 6424), (64950, 6427), (64951, 6430), (64952, 6433), (64953, 6436), (64954, 6439), (64955, 6442), (64956, 6445), (64957, 6448), (64958, 6451), (64959, 6454), (64960, 6457), (64961, 6460), (64962, 6463), (64963, 6466), (64964, 6469), (64965, 6472), (64966, 6475), (64967, 6478), (65008, 6481), (65009, 6484), (65010, 6487), (65011, 6493), (65012, 6497), (65013, 6501), (65014, 6505), (65015, 6509), (65016, 6513), (65017, 6517), (65018, 6520), (65019, 6538), (65020, 6546), (65049, 6550), (65072, 6551), (65073, 6552), (65076, 6553), (65077, 6554), (65078, 6555), (65079, 6556), (65080, 6557), (65081, 6558), (65082, 6559), (65097, 6560), (65098, 6561), (65099, 6562), (65100, 6563), (65101, 6564), (65102, 6565), (65103, 6566), (65112, 6567), (65128, 6568), (65152, 6569), (65153, 6570), (65154, 6571), (65155, 6572), (65156, 6574), (65157, 6576), (65158, 6578), (65159, 6580), (65160, 6582), (65161, 6584), (65162, 6586), (65163, 6588), (65164, 6590), (65165, 6592), (65166, 6593), (65167, 6594), (65168, 6595), (65169, 6596), (65170, 6597), (65171, 6598), (65172, 6599), (65173, 6600), (65174, 6601), (65175, 6602), (65176, 6603), (65177, 6604), (65178, 6606), (65179, 6608), (65180, 6610), (65181, 6612), (65182, 6613), (65183, 6614), (65184, 6615), (65185, 6616), (65186, 6617), (65187, 6618), (65188, 6619), (65189, 6620), (65190, 6621), (65191, 6622), (65192, 6623), (65193, 6624), (65194, 6625), (65195, 6626), (65196, 6627), (65197, 6628), (65198, 6629), (65199, 6630), (65200, 6631), (65201, 6632), (65202, 6633), (65203, 6634), (65204, 6635), (65205, 6636), (65206, 6638), (65207, 6640), (65208, 6642), (65209, 6644), (65210, 6645), (65211, 6646), (65212, 6647), (65213, 6648), (65214, 6649), (65215, 6650), (65216, 6651), (65217, 6652), (65218, 6653), (65219, 6654), (65220, 6655), (65221, 6656), (65222, 6657), (65223, 6658), (65224, 6659), (65225, 6660), (65226, 6661), (65227, 6662), (65228, 6663), (65229, 6664), (65230, 6665), (65231, 6666), (65232, 6667), (65233, 6668), (65234, 6669), (65235, 6670), (65236, 6671), (65237, 6672), (65238, 6673), (65239, 6674), (65240, 6675), (65241, 6676), (65242, 6677), (65243, 6678), (65244, 6679), (65245, 6680), (65246, 6681), (65247, 6682), (65248, 6683), (65249, 6684), (65250, 6685), (65251, 6686), (65252, 6687), (65253, 6688), (65254, 6689), (65255, 6690), (65256, 6691), (65257, 6692), (65258, 6693), (65259, 6694), (65260, 6695), (65261, 6696), (65262, 6697), (65263, 6698), (65264, 6699), (65265, 6700), (65266, 6701), (65267, 6702), (65268, 6703), (65269, 6704), (65270, 6706), (65271, 6708), (65272, 6711), (65273, 6714), (65274, 6717), (65275, 6720), (65276, 6722), (65281, 6724), (65282, 6725), (65287, 6727), (65293, 6728), (65306, 6729), (65313, 6730), (65314, 6731), (65315, 6732), (65317, 6733), (65320, 6734), (65321, 6735), (65322, 6736), (65323, 6737), (65325, 6738), (65326, 6739), (65327, 6740), (65328, 6741), (65331, 6742), (65332, 6743), (65336, 6744), (65337, 6745), (65338, 6746), (65339, 6747), (65340, 6748), (65341, 6749), (65342, 6750), (65344, 6751), (65345, 6752), (65347, 6753), (65349, 6754), (65351, 6755), (65352, 6756), (65353, 6757), (65354, 6758), (65356, 6759), (65359, 6760), (65360, 6761), (65363, 6762), (65366, 6763), (65368, 6764), (65369, 6765), (65372, 6766), (65374, 6767), (65381, 6768), (65507, 6769), (65512, 6770), (65517, 6771), (65793, 6772), (65934, 6773), (65942, 6775), (65943, 6777), (65944, 6779), (65945, 6785), (65952, 6789), (66178, 6790), (66181, 6791), (66182, 6792), (66183, 6793), (66186, 6794), (66189, 6795), (66192, 6796), (66194, 6797), (66196, 6798), (66197, 6799), (66198, 6800), (66199, 6801), (66203, 6802), (66208, 6803), (66209, 6804), (66210, 6805), (66211, 6806), (66213, 6807), (66219, 6808), (66221, 6809), (66224, 6810), (66225, 6811), (66226, 6812), (66227, 6813), (66228, 6814), (66229, 6815), (66230, 6816), (66232, 6817), (66255, 6818), (66273, 6819), (66276, 6820), (66280, 6821), (66290, 6822), (66293, 6823), (66305, 6824), (66306, 6825), (66313, 6826), (66321, 6827), (66322, 6828), (66325, 6829), (66327, 6830), (66330, 6831), (66335, 6832), (66336, 6833), (66338, 6834), (66513, 6835), (66515, 6836), (66561, 6837), (66564, 6838), (66577, 6839), (66581, 6840), (66587, 6841), (66591, 6842), (66592, 6843), (66595, 6844), (66597, 6845), (66601, 6846), (66602, 6847), (66604, 6848), (66621, 6849), (66623, 6850), (66626, 6851), (66627, 6852), (66632, 6853), (66635, 6854), (66637, 6855), (66720, 6856), (66736, 6857), (66740, 6858), (66748, 6859), (66754, 6860), (66755, 6861), (66756, 6862), (66765, 6863), (66766, 6864), (66768, 6865), (66769, 6866), (66770, 6867), (66776, 6868), (66779, 6869), (66794, 6870), (66795, 6871), (66806, 6872), (66809, 6873), (66835, 6874), (66838, 6875), (66840, 6876), (66844, 6877), (66845, 6878), (66853, 6879), (66854, 6880), (66855, 6881), (68154, 6882), (68176, 6883), (68183, 6884), (68858, 6886), (68860, 6887), (69819, 6888), (70087, 6889), (70090, 6890), (70091, 6891), (70107, 6892), (70108, 6893), (70110, 6894), (70400, 6895), (70675, 6896), (70681, 6899), (70692, 6902), (70698, 6905), (70701, 6908), (70703, 6911), (70732, 6914), (70802, 6916), (70804, 6917), (70806, 6918), (70808, 6919), (70809, 6920), (70811, 6921), (70813, 6922), (70814, 6923), (70815, 6924), (70816, 6925), (70817, 6926), (70818, 6927), (70819, 6928), (70823, 6929), (70824, 6930), (70825, 6931), (70826, 6932), (70827, 6933), (70829, 6934), (70830, 6935), (70832, 6936), (70833, 6937), (70841, 6938), (70844, 6939), (70845, 6940), (70846, 6941), (70847, 6942), (70849, 6944), (70850, 6945), (70851, 6946), (70852, 6947), (70853, 6948), (70864, 6950), (70865, 6951), (70866, 6952), (70870, 6953), (71128, 6954), (71129, 6955), (71130, 6956), (71131, 6957), (71132, 6958), (71133, 6959), (71234, 6960), (71424, 6962), (71430, 6964), (71434, 6965), (71438, 6966), (71439, 6967), (71840, 6968), (71842, 6969), (71843, 6970), (71844, 6971), (71846, 6972), (71848, 6973), (71849, 6974), (71852, 6975), (71854, 6976), (71855, 6977), (71858, 6978), (71861, 6979), (71863, 6980), (71864, 6981), (71867, 6982), (71868, 6983), (71872, 6984), (71873, 6985), (71874, 6986), (71875, 6987), (71876, 6988), (71878, 6989), (71880, 6990), (71882, 6991), (71884, 6992), (71886, 6993), (71893, 6994), (71894, 6995), (71895, 6996), (71896, 6997), (71900, 6998), (71904, 6999), (71907, 7000), (71908, 7002), (71909, 7003), (71910, 7004), (71913, 7005), (71916, 7006), (71919, 7007), (71922, 7008), (72422, 7009), (72423, 7011), (72424, 7013), (72425, 7015), (72426, 7018), (72428, 7021), (72429, 7023), (72430, 7025), (72436, 7028), (72437, 7030), (72438, 7032), (72439, 7034), (72440, 7037), (72770, 7040), (72882, 7042), (73784, 7043), (78585, 7044), (93959, 7045), (93960, 7046), (93962, 7047), (93974, 7048), (93978, 7049), (93980, 7050), (93990, 7051), (93992, 7052), (93997, 7053), (94005, 7054), (94010, 7055), (94011, 7056), (94013, 7057), (94015, 7058), (94016, 7059), (94018, 7060), (94019, 7061), (94033, 7062), (94034, 7063), (119060, 7064), (119149, 7065), (119298, 7066), (119302, 7067), (119307, 7068), (119309, 7069), (119311, 7070), (119314, 7071), (119315, 7072), (119316, 7073), (119317, 7074), (119318, 7075), (119319, 7076), (119322, 7077), (119323, 7079), (119324, 7080), (119329, 7081), (119330, 7082), (119338, 7083), (119339, 7084), (119344, 7085), (119350, 7086), (119351, 7087), (119352, 7088), (119353, 7089), (119354, 7090), (119355, 7091), (119359, 7092), (119365, 7093), (119808, 7094), (119809, 7095), (119810, 7096), (119811, 7097), (119812, 7098), (119813, 7099), (119814, 7100), (119815, 7101), (119816, 7102), (119817, 7103), (119818, 7104), (119819, 7105), (119820, 7106), (119821, 7107), (119822, 7108), (119823, 7109), (119824, 7110), (119825, 7111), (119826, 7112), (119827, 7113), (119828, 7114), (119829, 7115), (119830, 7116), (119831, 7117), (119832, 7118), (119833, 7119), (119834, 7120), (119835, 7121), (119836, 7122), (119837, 7123), (119838, 7124), (119839, 7125), (119840, 7126), (119841, 7127), (119842, 7128), (119843, 7129), (119844, 7130), (119845, 7131), (119846, 7132), (119847, 7134), (119848, 7135), (119849, 7136), (119850, 7137), (119851, 7138), (119852, 7139), (119853, 7140), (119854, 7141), (119855, 7142), (119856, 7143), (119857, 7144), (119858, 7145), (119859, 7146), (119860, 7147), (119861, 7148), (119862, 7149), (119863, 7150), (119864, 7151), (119865, 7152), (119866, 7153), (119867, 7154), (119868, 7155), (119869, 7156), (119870, 7157), (119871, 7158), (119872, 7159), (119873, 7160), (119874, 7161), (119875, 7162), (119876, 7163), (119877, 7164), (119878, 7165), (119879, 7166), (119880, 7167), (119881, 7168), (119882, 7169), (119883, 7170), (119884, 7171), (119885, 7172), (119886, 7173), (119887, 7174), (119888, 7175), (119889, 7176), (119890, 7177), (119891, 7178), (119892, 7179), (119894, 7180), (119895, 7181), (119896, 7182), (119897, 7183), (119898, 7184), (119899, 7186), (119900, 7187), (119901, 7188), (119902, 7189), (119903, 7190), (119904, 7191), (119905, 7192), (119906, 7193), (119907, 7194), (119908, 7195), (119909, 7196), (119910, 7197), (119911, 7198), (119912, 7199), (119913, 7200), (119914, 7201), (119915, 7202), (119916, 7203), (119917, 7204), (119918, 7205), (119919, 7206), (119920, 7207), (119921, 7208), (119922, 7209), (119923, 7210), (119924, 7211), (119925, 7212), (119926, 7213), (119927, 7214), (119928, 7215), (119929, 7216), (119930, 7217), (119931, 7218), (119932, 7219), (119933, 7220), (119934, 7221), (119935, 7222), (119936, 7223), (119937, 7224), (119938, 7225), (119939, 7226), (119940, 7227), (119941, 7228), (119942, 7229), (119943, 7230), (119944, 7231), (119945, 7232), (119946, 7233), (119947, 7234), (119948, 7235), (119949, 7236), (119950, 7237), (119951, 7239), (119952, 7240), (119953, 7241), (119954, 7242), (119955, 7243), (119956, 7244), (119957, 7245), (119958, 7246), (119959, 7247), (119960, 7248), (119961, 7249), (119962, 7250), (119963, 7251), (119964, 7252), (119966, 7253), (119967, 7254), (119970, 7255), (119973, 7256), (119974, 7257), (119977, 7258), (119978, 7259), (119979, 7260), (119980, 7261), (119982, 7262), (119983, 7263), (119984, 7264), (119985, 7265), (119986, 7266), (119987, 7267), (119988, 7268), (119989, 7269), (119990, 7270), (119991, 7271), (119992, 7272), (119993, 7273), (119995, 7274), (119997, 7275), (119998, 7276), (119999, 7277), (120000, 7278), (120001, 7279), (120002, 7280), (120003, 7282), (120005, 7283), (120006, 7284), (120007, 7285), (120008, 7286), (120009, 7287), (120010, 7288), (120011, 7289), (120012, 7290), (120013, 7291), (120014, 7292), (120015, 7293), (120016, 7294), (120017, 7295), (120018, 7296), (120019, 7297), (120020, 7298), (120021, 7299), (120022, 7300), (120023, 7301), (120024, 7302), (120025, 7303), (120026, 7304), (120027, 7305), (120028, 7306), (120029, 7307), (120030, 7308), (120031, 7309), (120032, 7310), (120033, 7311), (120034, 7312), (120035, 7313), (120036, 7314), (120037, 7315), (120038, 7316), (120039, 7317), (120040, 7318), (120041, 7319), (120042, 7320), (120043, 7321), (120044, 7322), (120045, 7323), (120046, 7324), (120047, 7325), (120048, 7326), (120049, 7327), (120050, 7328), (120051, 7329), (120052, 7330), (120053, 7331), (120054, 7332), (120055, 7334), (120056, 7335), (120057, 7336), (120058, 7337), (120059, 7338), (120060, 7339), (120061, 7340), (120062, 7341), (120063, 7342), (120064, 7343), (120065, 7344), (120066, 7345), (120067, 7346), (120068, 7347), (120069, 7348), (120071, 7349), (120072, 7350), (120073, 7351), (120074, 7352), (120077, 7353), (120078, 7354), (120079, 7355), (120080, 7356), (120081, 7357), (120082, 7358), (120083, 7359), (120084, 7360), (120086, 7361), (120087, 7362), (120088, 7363), (120089, 7364), (120090, 7365), (120091, 7366), (120092, 7367), (120094, 7368), (120095, 7369), (120096, 7370), (120097, 7371), (120098, 7372), (120099, 7373), (120100, 7374), (120101, 7375), (120102, 7376), (120103, 7377), (120104, 7378), (120105, 7379), (120106, 7380), (120107, 7382), (120108, 7383), (120109, 7384), (120110, 7385), (120111, 7386), (120112, 7387), (120113, 7388), (120114, 7389), (120115, 7390), (120116, 7391), (120117, 7392), (120118, 7393), (120119, 7394), (120120, 7395), (120121, 7396), (120123, 7397), (120124, 7398), (120125, 7399), (120126, 7400), (120128, 7401), (120129, 7402), (120130, 7403), (120131, 7404), (120132, 7405), (120134, 7406), (120138, 7407), (120139, 7408), (120140, 7409), (120141, 7410), (120142, 7411), (120143, 7412), (120144, 7413), (120146, 7414), (120147, 7415), (120148, 7416), (120149, 7417), (120150, 7418), (120151, 7419), (120152, 7420), (120153, 7421), (120154, 7422), (120155, 7423), (120156, 7424), (120157, 7425), (120158, 7426), (120159, 7428), (120160, 7429), (120161, 7430), (120162, 7431), (120163, 7432), (120164, 7433), (120165, 7434), (120166, 7435), (120167, 7436), (120168, 7437), (120169, 7438), (120170, 7439), (120171, 7440), (120172, 7441), (120173, 7442), (120174, 7443), (120175, 7444), (120176, 7445), (120177, 7446), (120178, 7447), (120179, 7448), (120180, 7449), (120181, 7450), (120182, 7451), (120183, 7452), (120184, 7453), (120185, 7454), (120186, 7455), (120187, 7456), (120188, 7457), (120189, 7458), (120190, 7459), (120191, 7460), (120192, 7461), (120193, 7462), (120194, 7463), (120195, 7464), (120196, 7465), (120197, 7466), (120198, 7467), (120199, 7468), (120200, 7469), (120201, 7470), (120202, 7471), (120203, 7472), (120204, 7473), (120205, 7474), (120206, 7475), (120207, 7476), (120208, 7477), (120209, 7478), (120210, 7479), (120211, 7481), (120212, 7482), (120213, 7483), (120214, 7484), (120215, 7485), (120216, 7486), (120217, 7487), (120218, 7488), (120219, 7489), (120220, 7490), (120221, 7491), (120222, 7492), (120223, 7493), (120224, 7494), (120225, 7495), (120226, 7496), (120227, 7497), (120228, 7498), (120229, 7499), (120230, 7500), (120231, 7501), (120232, 7502), (120233, 7503), (120234, 7504), (120235, 7505), (120236, 7506), (120237, 7507), (120238, 7508), (120239, 7509), (120240, 7510), (120241, 7511), (120242, 7512), (120243, 7513), (120244, 7514), (120245, 7515), (120246, 7516), (120247, 7517), (120248, 7518), (120249, 7519), (120250, 7520), (120251, 7521), (120252, 7522), (120253, 7523), (120254, 7524), (120255, 7525), (120256, 7526), (120257, 7527), (120258, 7528), (120259, 7529), (120260, 7530), (120261, 7531), (120262, 7532), (120263, 7534), (120264, 7535), (120265, 7536), (120266, 7537), (120267, 7538), (120268, 7539), (120269, 7540), (120270, 7541), (120271, 7542), (120272, 7543), (120273, 7544), (120274, 7545), (120275, 7546), (120276, 7547), (120277, 7548), (120278, 7549), (120279, 7550), (120280, 7551), (120281, 7552), (120282, 7553), (120283, 7554), (120284, 7555), (120285, 7556), (120286, 7557), (120287, 7558), (120288, 7559), (120289, 7560), (120290, 7561), (120291, 7562), (120292, 7563), (120293, 7564), (120294, 7565), (120295, 7566), (120296, 7567), (120297, 7568), (120298, 7569), (120299, 7570), (120300, 7571), (120301, 7572), (120302, 7573), (120303, 7574), (120304, 7575), (120305, 7576), (120306, 7577), (120307, 7578), (120308, 7579), (120309, 7580), (120310, 7581), (120311, 7582), (120312, 7583), (120313, 7584), (120314, 7585), (120315, 7587), (120316, 7588), (120317, 7589), (120318, 7590), (120319, 7591), (120320, 7592), (120321, 7593), (120322, 7594), (120323, 7595), (120324, 7596), (120325, 7597), (120326, 7598), (120327, 7599), (120328, 7600), (120329, 7601), (120330, 7602), (120331, 7603), (120332, 7604), (120333, 7605), (120334, 7606), (120335, 7607), (120336, 7608), (120337, 7609), (120338, 7610), (120339, 7611), (120340, 7612), (120341, 7613), (120342, 7614), (120343, 7615), (120344, 7616), (120345, 7617), (120346, 7618), (120347, 7619), (120348, 7620), (120349, 7621), (120350, 7622), (120351, 7623), (120352, 7624), (120353, 7625), (120354, 7626), (120355, 7627), (120356, 7628), (120357, 7629), (120358, 7630), (120359, 7631), (120360, 7632), (120361, 7633), (120362, 7634), (120363, 7635), (120364, 7636), (120365, 7637), (120366, 7638), (120367, 7640), (120368, 7641), (120369, 7642), (120370, 7643), (120371, 7644), (120372, 7645), (120373, 7646), (120374, 7647), (120375, 7648), (120376, 7649), (120377, 7650), (120378, 7651), (120379, 7652), (120380, 7653), (120381, 7654), (120382, 7655), (120383, 7656), (120384, 7657), (120385, 7658), (120386, 7659), (120387, 7660), (120388, 7661), (120389, 7662), (120390, 7663), (120391, 7664), (120392, 7665), (120393, 7666), (120394, 7667), (120395, 7668), (120396, 7669), (120397, 7670), (120398, 7671), (120399, 7672), (120400, 7673), (120401, 7674), (120402, 7675), (120403, 7676), (120404, 7677), (120405, 7678), (120406, 7679), (120407, 7680), (120408, 7681), (120409, 7682), (120410, 7683), (120411, 7684), (120412, 7685), (120413, 7686), (120414, 7687), (120415, 7688), (120416, 7689), (120417, 7690), (120418, 7691), (120419, 7693), (120420, 7694), (120421, 7695), (120422, 7696), (120423, 7697), (120424, 7698), (120425, 7699), (120426, 7700), (120427, 7701), (120428, 7702), (120429, 7703), (120430, 7704), (120431, 7705), (120432, 7706), (120433, 7707), (120434, 7708), (120435, 7709), (120436, 7710), (120437, 7711), (120438, 7712), (120439, 7713), (120440, 7714), (120441, 7715), (120442, 7716), (120443, 7717), (120444, 7718), (120445, 7719), (120446, 7720), (120447, 7721), (120448, 7722), (120449, 7723), (120450, 7724), (120451, 7725), (120452, 7726), (120453, 7727), (120454, 7728), (120455, 7729), (120456, 7730), (120457, 7731), (120458, 7732), (120459, 7733), (120460, 7734), (120461, 7735), (120462, 7736), (120463, 7737), (120464, 7738), (120465, 7739), (120466, 7740), (120467, 7741), (120468, 7742), (120469, 7743), (120470, 7744), (120471, 7746), (120472, 7747), (120473, 7748), (120474, 7749), (120475, 7750), (120476, 7751), (120477, 7752), (120478, 7753), (120479, 7754), (120480, 7755), (120481, 7756), (120482, 7757), (120483, 7758), (120484, 7759), (120485, 7760), (120488, 7761), (120489, 7762), (120490, 7763), (120491, 7764), (120492, 7765), (120493, 7766), (120494, 7767), (120495, 7768), (120496, 7770), (120497, 7771), (120498, 7772), (120499, 7773), (120500, 7774), (120501, 7775), (120502, 7776), (120503, 7777), (120504, 7778), (120505, 7779), (120506, 7781), (120507, 7782), (120508, 7783), (120509, 7784), (120510, 7785), (120511, 7786), (120512, 7787), (120513, 7788), (120514, 7789), (120515, 7790), (120516, 7791), (120517, 7792), (120518, 7793), (120519, 7794), (120520, 7795), (120521, 7797), (120522, 7799), (120523, 7800), (120524, 7801), (120525, 7802), (120526, 7803), (120527, 7804), (120528, 7805), (120529, 7806), (120530, 7807), (120531, 7808), (120532, 7809), (120533, 7810), (120534, 7811), (120535, 7812), (120536, 7813), (120537, 7814), (120538, 7815), (120539, 7816), (120540, 7817), (120541, 7818), (120542, 7820), (120543, 7821), (120544, 7822), (120545, 7823), (120546, 7824), (120547, 7825), (120548, 7826), (120549, 7827), (120550, 7828), (120551, 7829), (120552, 7830), (120553, 7831), (120554, 7833), (120555, 7834), (120556, 7835), (120557, 7836), (120558, 7837), (120559, 7838), (120560, 7839), (120561, 7840), (120562, 7841), (120563, 7842), (120564, 7844), (120565, 7845), (120566, 7846), (120567, 7847), (120568, 7848), (120569, 7849), (120570, 7850), (120571, 7851), (120572, 7852), (120573, 7853), (120574, 7854), (120575, 7855), (120576, 7856), (120577, 7857), (120578, 7858), (120579, 7860), (120580, 7862), (120581, 7863), (120582, 7864), (120583, 7865), (120584, 7866), (120585, 7867), (120586, 7868), (120587, 7869), (120588, 7870), (120589, 7871), (120590, 7872), (120591, 7873), (120592, 7874), (120593, 7875), (120594, 7876), (120595, 7877), (120596, 7878), (120597, 7879), (120598, 7880), (120599, 7881), (120600, 7883), (120601, 7884), (120602, 7885), (120603, 7886), (120604, 7887), (120605, 7888), (120606, 7889), (120607, 7890), (120608, 7891), (120609, 7892), (120610, 7893), (120611, 7894), (120612, 7896), (120613, 7897), (120614, 7898), (120615, 7899), (120616, 7900), (120617, 7901), (120618, 7902), (120619, 7903), (120620, 7904), (120621, 7905), (120622, 7907), (120623, 7908), (120624, 7909), (120625, 7910), (120626, 7911), (120627, 7912), (120628, 7913), (120629, 7914), (120630, 7915), (120631, 7916), (120632, 7917), (120633, 7918), (120634, 7919), (120635, 7920), (120636, 7921), (120637, 7923), (120638, 7925), (120639, 7926), (120640, 7927), (120641, 7928), (120642, 7929), (120643, 7930), (120644, 7931), (120645, 7932), (120646, 7933), (120647, 7934), (120648, 7935), (120649, 7936), (120650, 7937), (120651, 7938), (120652, 7939), (120653, 7940), (120654, 7941), (120655, 7942), (120656, 7943), (120657, 7944), (120658, 7946), (120659, 7947), (120660, 7948), (120661, 7949), (120662, 7950), (120663, 7951), (120664, 7952), (120665, 7953), (120666, 7954), (120667, 7955), (120668, 7956), (120669, 7957), (120670, 7959), (120671, 7960), (120672, 7961), (120673, 7962), (120674, 7963), (120675, 7964), (120676, 7965), (120677, 7966), (120678, 7967), (120679, 7968), (120680, 7970), (120681, 7971), (120682, 7972), (120683, 7973), (120684, 7974), (120685, 7975), (120686, 7976), (120687, 7977), (120688, 7978), (120689, 7979), (120690, 7980), (120691, 7981), (120692, 7982), (120693, 7983), (120694, 7984), (120695, 7986), (120696, 7988), (120697, 7989), (120698, 7990), (120699, 7991), (120700, 7992), (120701, 7993), (120702, 7994), (120703, 7995), (120704, 7996), (120705, 7997), (120706, 7998), (120707, 7999), (120708, 8000), (120709, 8001), (120710, 8002), (120711, 8003), (120712, 8004), (120713, 8005), (120714, 8006), (120715, 8007), (120716, 8009), (120717, 8010), (120718, 8011), (120719, 8012), (120720, 8013), (120721, 8014), (120722, 8015), (120723, 8016), (120724, 8017), (120725, 8018), (120726, 8019), (120727, 8020), (120728, 8022), (120729, 8023), (120730, 8024), (120731, 8025), (120732, 8026), (120733, 8027), (120734, 8028), (120735, 8029), (120736, 8030), (120737, 8031), (120738, 8033), (120739, 8034), (120740, 8035), (120741, 8036), (120742, 8037), (120743, 8038), (120744, 8039), (120745, 8040), (120746, 8041), (120747, 8042), (120748, 8043), (120749, 8044), (120750, 8045), (120751, 8046), (120752, 8047), (120753, 8049), (120754, 8051), (120755, 8052), (120756, 8053), (120757, 8054), (120758, 8055), (120759, 8056), (120760, 8057), (120761, 8058), (120762, 8059), (120763, 8060), (120764, 8061), (120765, 8062), (120766, 8063), (120767, 8064), (120768, 8065), (120769, 8066), (120770, 8067), (120771, 8068), (120772, 8069), (120773, 8070), (120774, 8072), (120775, 8073), (120776, 8074), (120777, 8075), (120778, 8076), (120779, 8077), (120782, 8078), (120783, 8079), (120784, 8080), (120785, 8081), (120786, 8082), (120787, 8083), (120788, 8084), (120789, 8085), (120790, 8086), (120791, 8087), (120792, 8088), (120793, 8089), (120794, 8090), (120795, 8091), (120796, 8092), (120797, 8093), (120798, 8094), (120799, 8095), (120800, 8096), (120801, 8097), (120802, 8098), (120803, 8099), (120804, 8100), (120805, 8101), (120806, 8102), (120807, 8103), (120808, 8104), (120809, 8105), (120810, 8106), (120811, 8107), (120812, 8108), (120813, 8109), (120814, 8110), (120815, 8111), (120816, 8112), (120817, 8113), (120818, 8114), (120819, 8115), (120820, 8116), (120821, 8117), (120822, 8118), (120823, 8119), (120824, 8120), (120825, 8121), (120826, 8122), (120827, 8123), (120828, 8124), (120829, 8125), (120830, 8126), (120831, 8127), (125127, 8128), (125128, 8129), (125129, 8130), (125131, 8131), (125132, 8132), (125133, 8133), (126464, 8135), (126465, 8136), (126466, 8137), (126467, 8138), (126469, 8139), (126470, 8140), (126471, 8141), (126472, 8142), (126473, 8143), (126474, 8144), (126475, 8145), (126476, 8146), (126477, 8147), (126478, 8148), (126479, 8149), (126480, 8150), (126481, 8151), (126482, 8152), (126483, 8153), (126484, 8154), (126485, 8156), (126486, 8157), (126487, 8159), (126488, 8160), (126489, 8161), (126490, 8162), (126491, 8163), (126492, 8164), (126493, 8165), (126494, 8166), (126495, 8167), (126497, 8168), (126498, 8169), (126500, 8170), (126503, 8171), (126505, 8172), (126506, 8173), (126507, 8174), (126508, 8175), (126509, 8176), (126510, 8177), (126511, 8178), (126512, 8179), (126513, 8180), (126514, 8181), (126516, 8182), (126517, 8184), (126518, 8185), (126519, 8187), (126521, 8188), (126523, 8189), (126530, 8190), (126535, 8191), (126537, 8192), (126539, 8193), (126541, 8194), (126542, 8195), (126543, 8196), (126545, 8197), (126546, 8198), (126548, 8199), (126551, 8201), (126553, 8202), (126555, 8203), (126557, 8204), (126559, 8205), (126561, 8206), (126562, 8207), (126564, 8208), (126567, 8209), (126568, 8210), (126569, 8211), (126570, 8212), (126572, 8213), (126573, 8214), (126574, 8215), (126575, 8216), (126576, 8217), (126577, 8218), (126578, 8219), (126580, 8220), (126581, 8222), (126582, 8223), (126583, 8225), (126585, 8226), (126586, 8227), (126587, 8228), (126588, 8229), (126590, 8230), (126592, 8231), (126593, 8232), (126594, 8233), (126595, 8234), (126596, 8235), (126597, 8236), (126598, 8237), (126599, 8238), (126600, 8239), (126601, 8240), (126603, 8241), (126604, 8242), (126605, 8243), (126606, 8244), (126607, 8245), (126608, 8246), (126609, 8247), (126610, 8248), (126611, 8249), (126612, 8250), (126613, 8252), (126614, 8253), (126615, 8255), (126616, 8256), (126617, 8257), (126618, 8258), (126619, 8259), (126625, 8260), (126626, 8261), (126627, 8262), (126629, 8263), (126630, 8264), (126631, 8265), (126632, 8266), (126633, 8267), (126635, 8268), (126636, 8269), (126637, 8270), (126638, 8271), (126639, 8272), (126640, 8273), (126641, 8274), (126642, 8275), (126643, 8276), (126644, 8277), (126645, 8279), (126646, 8280), (126647, 8282), (126648, 8283), (126649, 8284), (126650, 8285), (126651, 8286), (127232, 8287), (127233, 8289), (127234, 8291), (127235, 8293), (127236, 8295), (127237, 8297), (127238, 8299), (127239, 8301), (127240, 8303), (127241, 8305), (127242, 8307), (127248, 8309), (127249, 8312), (127250, 8315), (127251, 8318), (127252, 8321), (127253, 8324), (127254, 8327), (127255, 8330), (127256, 8333), (127257, 8336), (127258, 8339), (127259, 8342), (127260, 8345), (127261, 8348), (127262, 8351), (127263, 8354), (127264, 8357), (127265, 8360), (127266, 8363), (127267, 8366), (127268, 8369), (127269, 8372), (127270, 8375), (127271, 8378), (127272, 8381), (127273, 8384), (127274, 8387), (127552, 8390), (127553, 8393), (127554, 8396), (127555, 8399), (127556, 8402), (127557, 8405), (127558, 8408), (127559, 8411), (127560, 8414), (127762, 8417), (127768, 8418), (127769, 8419), (128768, 8420), (128769, 8422), (128770, 8423), (128772, 8424), (128775, 8425), (128776, 8427), (128778, 8429), (128788, 8430), (128808, 8432), (128826, 8433), (128844, 8434), (128852, 8435), (128853, 8436), (128860, 8437), (128862, 8440), (128872, 8441), (128875, 8442), (128876, 8444), (128881, 8446), (139240, 8447), (194560, 8448), (194561, 8449), (194562, 8450), (194563, 8451), (194564, 8452), (194565, 8453), (194566, 8454), (194567, 8455), (194568, 8456), (194569, 8457), (194570, 8458), (194571, 8459), (194572, 8460), (194573, 8461), (194574, 8462), (194575, 8463), (194576, 8464), (194577, 8465), (194578, 8466), (194579, 8467), (194580, 8468), (194581, 8469), (194582, 8470), (194583, 8471), (194584, 8472), (194585, 8473), (194586, 8474), (194587, 8475), (194588, 8476), (194589, 8477), (194590, 8478), (194591, 8479), (194592, 8480), (194593, 8481), (194594, 8482), (194595, 8483), (194596, 8484), (194597, 8485), (194598, 8486), (194599, 8487), (194600, 8488), (194601, 8489), (194602, 8490), (194603, 8491), (194604, 8492), (194605, 8493), (194606, 8494), (194607, 8495), (194608, 8496), (194609, 8497), (194610, 8498), (194611, 8499), (194612, 8500), (194613, 8501), (194614, 8502), (194615, 8503), (194616, 8504), (194617, 8505), (194618, 8506), (194619, 8507), (194620, 8508), (194621, 8509), (194622, 8510), (194623, 8511), (194624, 8512), (194625, 8513), (194626, 8514), (194627, 8515), (194628, 8516), (194629, 8517), (194630, 8518), (194631, 8519), (194632, 8520), (194633, 8521), (194634, 8522), (194635, 8523), (194636, 8524), (194637, 8525), (194638, 8526), (194639, 8527), (194640, 8528), (194641, 8529), (194642, 8530), (194643, 8531), (194644, 8532), (194645, 8533), (194646, 8534), (194647, 8535), (194648, 8536), (194649, 8537), (194650, 8538), (194651, 8539), (194652, 8540), (194653, 8541), (194654, 8542), (194655, 8543), (194656, 8544), (194657, 8545), (194658, 8546), (194659, 8547), (194660, 8548), (194661, 8549), (194662, 8550), (194663, 8551), (194664, 8552), (194665, 8553), (194666, 8554), (194667, 8555), (194668, 8556), (194669, 8557), (194670, 8558), (194671, 8559), (194672, 8560), (194673, 8561), (194674, 8562), (194675, 8563), (194676, 8564), (194677, 8565), (194678, 8566), (194679, 8567), (194680, 8568), (194681, 8569), (194682, 8570), (194683, 8571), (194684, 8572), (194685, 8573), (194686, 8574), (194687, 8575), (194688, 8576), (194689, 8577), (194690, 8578), (194691, 8579), (194692, 8580), (194693, 8581), (194694, 8582), (194695, 8583), (194696, 8584), (194697, 8585), (194698, 8586), (194699, 8587), (194700, 8588), (194701, 8589), (194702, 8590), (194703, 8591), (194704, 8592), (194705, 8593), (194706, 8594), (194707, 8595), (194708, 8596), (194709, 8597), (194710, 8598), (194711, 8599), (194712, 8600), (194713, 8601), (194714, 8602), (194715, 8603), (194716, 8604), (194717, 8605), (194718, 8606), (194719, 8607), (194720, 8608), (194721, 8609), (194722, 8610), (194723, 8611), (194724, 8612), (194725, 8613), (194726, 8614), (194727, 8615), (194728, 8616), (194729, 8617), (194730, 8618), (194731, 8619), (194732, 8620), (194733, 8621), (194734, 8622), (194735, 8623), (194736, 8624), (194737, 8625), (194738, 8626), (194739, 8627), (194740, 8628), (194741, 8629), (194742, 8630), (194743, 8631), (194744, 8632), (194745, 8633), (194746, 8634), (194747, 8635), (194748, 8636), (194749, 8637), (194750, 8638), (194751, 8639), (194752, 8640), (194753, 8641), (194754, 8642), (194755, 8643), (194756, 8644), (194757, 8645), (194758, 8646), (194759, 8647), (194760, 8648), (194761, 8649), (194762, 8650), (194763, 8651), (194764, 8652), (194765, 8653), (194766, 8654), (194767, 8655), (194768, 8656), (194769, 8657), (194770, 8658), (194771, 8659), (194772, 8660), (194773, 8661), (194774, 8662), (194775, 8663), (194776, 8664), (194777, 8665), (194778, 8666), (194779, 8667), (194780, 8668), (194781, 8669), (194782, 8670), (194783, 8671), (194784, 8672), (194785, 8673), (194786, 8674), (194787, 8675), (194788, 8676), (194789, 8677), (194790, 8678), (194791, 8679), (194792, 8680), (194793, 8681), (194794, 8682), (194795, 8683), (194796, 8684), (194797, 8685), (194798, 8686), (194799, 8687), (194800, 8688), (194801, 8689), (194802, 8690), (194803, 8691), (194804, 8692), (194805, 8693), (194806, 8694), (194807, 8695), (194808, 8696), (194809, 8697), (194810, 8698), (194811, 8699), (194812, 8700), (194813, 8701), (194814, 8702), (194815, 8703), (194816, 8704), (194817, 8705), (194818, 8706), (194819, 8707), (194820, 8708), (194821, 8709), (194822, 8710), (194823, 8711), (194824, 8712), (194825, 8713), (194826, 8714), (194827, 8715), (194828, 8716), (194829, 8717), (194830, 8718), (194831, 8719), (194832, 8720), (194833, 8721), (194834, 8722), (194835, 8723), (194836, 8724), (194837, 8725), (194838, 8726), (194839, 8727), (194840, 8728), (194841, 8729), (194842, 8730), (194843, 8731), (194844, 8732), (194845, 8733), (194846, 8734), (194847, 8735), (194848, 8736), (194849, 8737), (194850, 8738), (194851, 8739), (194852, 8740), (194853, 8741), (194854, 8742), (194855, 8743), (194856, 8744), (194857, 8745), (194858, 8746), (194859, 8747), (194860, 8748), (194861, 8749), (194862, 8750), (194863, 8751), (194864, 8752), (194865, 8753), (194866, 8754), (194867, 8755), (194868, 8756), (194869, 8757), (194870, 8758), (194871, 8759), (194872, 8760), (194873, 8761), (194874, 8762), (194875, 8763), (194876, 8764), (194877, 8765), (194878, 8766), (194879, 8767), (194880, 8768), (194881, 8769), (194882, 8770), (194883, 8771), (194884, 8772), (194885, 8773), (194886, 8774), (194887, 8775), (194888, 8776), (194889, 8777), (194890, 8778), (194891, 8779), (194892, 8780), (194893, 8781), (194894, 8782), (194895, 8783), (194896, 8784), (194897, 8785), (194898, 8786), (194899, 8787), (194900, 8788), (194901, 8789), (194902, 8790), (194903, 8791), (194904, 8792), (194905, 8793), (194906, 8794), (194907, 8795), (194908, 8796), (194909, 8797), (194910, 8798), (194911, 8799), (194912, 8800), (194913, 8801), (194914, 8802), (194915, 8803), (194916, 8804), (194917, 8805), (194918, 8806), (194919, 8807), (194920, 8808), (194921, 8809), (194922, 8810), (194923, 8811), (194924, 8812), (194925, 8813), (194926, 8814), (194927, 8815), (194928, 8816), (194929, 8817), (194930, 8818), (194931, 8819), (194932, 8820), (194933, 8821), (194934, 8822), (194935, 8823), (194936, 8824), (194937, 8825), (194938, 8826), (194939, 8827), (194940, 8828), (194941, 8829), (194942, 8830), (194943, 8831), (194944, 8832), (194945, 8833), (194946, 8834), (194947, 8835), (194948, 8836), (194949, 8837), (194950, 8838), (194951, 8839), (194952, 8840), (194953, 8841), (194954, 8842), (194955, 8843), (194956, 8844), (194957, 8845), (194958, 8846), (194959, 8847), (194960, 8848), (194961, 8849), (194962, 8850), (194963, 8851), (194964, 8852), (194965, 8853), (194966, 8854), (194967, 8855), (194968, 8856), (194969, 8857), (194970, 8858), (194971, 8859), (194972, 8860), (194973, 8861), (194974, 8862), (194975, 8863), (194976, 8864), (194977, 8865), (194978, 8866), (194979, 8867), (194980, 8868), (194981, 8869), (194982, 8870), (194983, 8871), (194984, 8872), (194985, 8873), (194986, 8874), (194987, 8875), (194988, 8876), (194989, 8877), (194990, 8878), (194991, 8879), (194992, 8880), (194993, 8881), (194994, 8882), (194995, 8883), (194996, 8884), (194997, 8885), (194998, 8886), (194999, 8887), (195000, 8888), (195001, 8889), (195002, 8890), (195003, 8891), (195004, 8892), (195005, 8893), (195006, 8894), (195007, 8895), (195008, 8896), (195009, 8897), (195010, 8898), (195011, 8899), (195012, 8900), (195013, 8901), (195014, 8902), (195015, 8903), (195016, 8904), (195017, 8905), (195018, 8906), (195019, 8907), (195020, 8908), (195021, 8909), (195022, 8910), (195023, 8911), (195024, 8912), (195025, 8913), (195026, 8914), (195027, 8915), (195028, 8916), (195029, 8917), (195030, 8918), (195031, 8919), (195032, 8920), (195033, 8921), (195034, 8922), (195035, 8923), (195036, 8924), (195037, 8925), (195038, 8926), (195039, 8927), (195040, 8928), (195041, 8929), (195042, 8930), (195043, 8931), (195044, 8932), (195045, 8933), (195046, 8934), (195047, 8935), (195048, 8936), (195049, 8937), (195050, 8938), (195051, 8939), (195052, 8940), (195053, 8941), (195054, 8942), (195055, 8943), (195056, 8944), (195057, 8945), (195058, 8946), (195059, 8947), (195060, 8948), (195061, 8949), (195062, 8950), (195063, 8951), (195064, 8952), (195065, 8953), (195066, 8954), (195067, 8955), (195068, 8956), (195069, 8957), (195070, 8958), (195071, 8959), (195072, 8960), (195073, 8961), (195074, 8962), (195075, 8963), (195076, 8964), (195077, 8965), (195078, 8966), (195079, 8967), (195080, 8968), (195081, 8969), (195082, 8970), (195083, 8971), (195084, 8972), (195085, 8973), (195086, 8974), (195087, 8975), (195088, 8976), (195089, 8977), (195090, 8978), (195091, 8979), (195092, 8980), (195093, 8981), (195094, 8982), (195095, 8983), (195096, 8984), (195097, 8985), (195098, 8986), (195099, 8987), (195100, 8988), (195101, 8989)];
-pub static OUTPUTS: [char; 8990] = ['\'', '\'', 'º', '/', '₀', 'O', 'l', 'l', '\'', 'r', 'n', 'l', ' ', 'c', '̸', 'Y', '̵', 'ˉ', '\'', 'μ', ',', 'A', 'E', 'C', '̦', 'D', '̵', '⍥', 'x', 'O', '̸', 'a', 'e', 'c', '̦', '∂', '̵', 'ة', 'o', '̸', 'D', '̵', 'd', '̵', 'Ĕ', 'ĕ', 'H', '̵', 'h', '̵', 'i', 'l', 'J', 'i', 'j', 'l', '·', 'l', '·', 'L', '̸', 'l', '̸', 'ɲ', '\'', 'n', '⍥', 'O', 'E', 'o', 'e', 'ƫ', 'T', '̵', 't', '̵', 'f', 'b', '̵', '\'', 'B', 'b', '̄', 'b', '̄', 'b', 'C', '\'', 'D', '̵', '\'', 'D', 'd', '̄', 'g', 'F', '̦', 'f', '̦', 'G', '\'', 'l', 'l', '̵', 'K', '\'', 'k', '̔', 'l', '̵', 'N', '̦', 'n', '̩', 'O', '̵', 'O', '\'', 'o', '\'', '\'', 'P', 'p', '̔', 'R', '2', '\'', 'T', 't', '̔', 'T', '̨', '\'', 'Y', 'y', '̔', 'Z', '̵', 'z', '̵', '3', '2', '̵', '5', 's', 'þ', 'l', 'l', 'l', '!', 'D', 'Ž', 'D', 'ž', 'd', 'ž', 'L', 'J', 'L', 'j', 'l', 'j', 'N', 'J', 'N', 'j', 'n', 'j', 'Ă', 'ă', 'Ĭ', 'ĭ', 'Ŏ', 'ŏ', 'Ŭ', 'ŭ', 'G', '̵', 'g', '̵', 'Ğ', 'ğ', 'D', 'Z', 'D', 'z', 'd', 'z', 'ģ', 'O', '̸', '́', 'Ţ', 'ƫ', '3', '8', '8', 'Z', '̦', 'z', '̦', 'Å', 'å', 'c', '̸', 'T', '̸', '?', 'U', '̵', 'E', '̸', 'e', '̸', 'J', '̵', 'j', '̵', 'r', '̵', 'Y', '̵', 'y', '̵', 'a', 'b', '̔', 'd', '̨', 'd', '̔', 'ǝ', 'ǝ', '˞', 'ꞓ', 'g', '̔', 'g', 'y', 'h', '̔', 'i', '̵', 'i', 'i', 'l', '̴', 'l', '̨', 'l', 'ȝ', 'w', 'r', 'n', '̦', 'n', '̨', 'o', '̵', 'o', 'ᴇ', 'r', '̩', 'r', '̨', 's', '̨', 'u', 'y', 'z', '̨', 'ȝ', '?', 'q', '̔', 'd', 'z', 'd', 'ȝ', 'd', 'ʑ', 't', 's', 't', 'ʃ', 't', 'ɕ', 'f', 'ŋ', 'l', 's', 'l', 'z', 'ᣴ', '\'', '\'', '\'', '\'', '\'', '\'', '\'', 'ՙ', '<', '>', '^', '^', '\'', '\'', '\'', ':', 'ՙ', '-', 'ˇ', 'ॱ', '°', 'i', '~', '\'', '\'', 'ᣳ', 'ᣵ', 'ˁ', '\'', '\'', '\'', '\'', '\'', ':', '˪', '̄', '̆', 'ٰ', '̆', '̇', '̂', '̓', 'ِ', '̱', '̦', '̨', '̦', '̵', '̸', '̦', '̀', '́', '̃', '̓', '̨', '̳', '͐', '̇', '̊', '̆', 'Ⱶ', '\'', 'ˏ', 'И', 'ᴎ', 'i', 'ɔ', 'ꜿ', ';', 'J', '\'', '·', 'A', 'B', 'E', 'Z', 'H', 'O', '̵', 'l', 'K', 'Ʌ', 'M', 'N', 'O', 'P', 'Ʃ', 'T', 'Y', 'X', 'a', 'ß', 'y', 'ẟ', 'ꞓ', 'n', '̩', 'O', '̵', 'i', 'ĸ', 'v', 'o', 'p', 'o', 'ᴛ', 'u', 'ɸ', 'ß', 'O', '̵', 'Y', 'ɸ', 'π', 'ς', 'F', '2', 'ƨ', 'ĸ', 'p', 'c', 'j', 'O', '̵', 'ꞓ', 'Þ', 'þ', 'C', 'M', 'Ɔ', 'Ꜿ', 'Ꞓ', 'S', 'l', 'J', 'A', 'b', '̄', 'B', 'Γ', 'E', '3', 'Ѝ', 'K', 'Ʌ', 'M', 'H', 'O', 'Π', 'P', 'C', 'T', 'Y', 'Φ', 'X', 'b', 'l', 'b', 'l', 'O', 'a', '6', 'ʙ', 'r', 'e', 'ɜ', 'ᴎ', 'ĸ', 'ʍ', 'ʜ', 'o', 'π', 'p', 'c', 'ᴛ', 'y', 'ɸ', 'x', 'ˉ', 'b', 'ƅ', 'i', 'ƅ', 'ᴙ', 'ꞓ', 's', 'i', 'j', 'h', '̵', 'й', 'w', 'b', '̵', 'b', '̵', 'Ψ', 'ψ', 'O', '̵', 'o', '̵', 'V', 'v', 'Ѡ', '҆', '҇', 'w', '҆', '҇', 'Ѝ', '̦', 'й', '̦', 'b', '̵', 'b', '̵', 'Γ', '\'', 'r', '\'', 'Γ', '̵', 'r', '̵', 'Ж', '̩', 'ж', '̩', '3', '̦', 'ɜ', '̦', 'K', '̩', 'ĸ', '̩', 'K', '̵', 'ĸ', '̵', 'H', '̩', 'ʜ', '̩', 'C', '̦', 'c', '̦', 'T', '̩', 'ᴛ', '̩', 'Y', 'y', 'Y', '̵', 'y', '̵', 'X', '̩', 'h', 'e', 'Ҽ', '̨', 'e', '̨', 'l', 'Ʌ', '̦', 'л', '̦', 'H', '̦', 'ʜ', '̦', 'H', '̦', 'ʜ', '̦', 'Ҷ', 'ҷ', 'M', '̦', 'ʍ', '̦', 'i', 'A', 'E', 'a', 'e', 'Ə', 'ǝ', '3', 'ȝ', 'O', '̵', 'o', '̵', 'd', 'Ƕ', 'G', 'ɢ', 'Ɛ', 'ꞓ', 'q', 'W', 'w', 'ኮ', 'ሆ', 'ጣ', 'ቡ', 'U', 'S', 'Φ', 'O', '\'', '\'', 'w', 'q', 'q', 'ẟ', 'h', 'ȷ', 'n', 'ɰ', 'n', 'u', 'g', 'f', 'o', 'ե', 'ւ', ':', '́', '́', '֚', '֙', '֖', '֘', '̊', '̣', '̇', '̇', 'l', '̇', '̇', ':', '̇', '̣', 'l', 'v', '\'', 'l', 'o', 'l', 'l', 'l', '\'', '\'', '\'', '\'', '\'', '\'', 'º', '/', '₀', '₀', 'º', '/', '₀', '₀', '₀', ',', 'ع', '́', '̓', 'ِ', 'l', 'ٴ', 'و', 'ٴ', 'l', 'ٕ', 'ى', 'ٴ', 'l', 'ى', 'ۛ', 'س', 'ۛ', 'ى', '̂', 'ى', 'ۛ', 'o', 'ى', '̋', '́', '̓', '̊', '̃', '̩', '̒', '̆', '̄', '̆', '̂', '̣', '̔', 'ٕ', '.', 'l', 'o', 'V', 'Ʌ', 'º', '/', '₀', ',', '،', '*', 'ى', 'ڡ', 'l', 'ٴ', 'l', 'ٕ', 'l', 'ٴ', 'و', 'ٴ', 'و', '̓', 'ٴ', 'ى', 'ٴ', 'ى', 'ؕ', 'ى', 'ۛ', 'ح', 'ٔ', 'ح', 'ۛ', 'د', 'ؕ', 'ڊ', 'ؕ', 'د', 'ۛ', 'ر', 'ؕ', 'ر', '̆', 'ر', 'ۛ', 'ص', 'ۛ', 'ط', 'ۛ', 'ڡ', 'ۛ', 'ف', 'ڡ', 'ۛ', 'ك', 'ك', 'ك', 'ۛ', 'گ', 'ۛ', 'ل', '̆', 'ل', 'ۛ', 'ى', 'ى', 'ؕ', 'ى', 'ۛ', 'o', 'o', 'ۀ', 'ة', 'و', '̆', 'و', '̓', 'و', 'ٰ', 'و', '̂', 'و', 'ۛ', 'ى', 'ى', '̆', 'ٻ', 'ى', 'ۛ', 'ى', '-', 'o', '̊', '̆', '̇', '̇', 'د', '̂', 'ر', '̂', '.', 'l', '٢', '٣', '٤', 'o', '٦', 'V', 'Ʌ', '٩', 'ء', '͈', 'م', '͈', 'o', '̂', '.', '.', ':', ':', '̇', '̇', 'ܼ', '́', 'ب', 'ۛ', 'ى', '̆', 'ڬ', 'ك', 'ۛ', 'ݔ', 'ن', 'ؕ', 'ن', '̆', 'ر', 'ٔ', 'ڗ', 'ؕ', 'ح', 'ٔ', 'س', '̂', 'O', 'l', '̄', '̇', '̂', '̈', '\'', '\'', '_', 'ب', 'ٔ', 'ڢ', 'ۛ', 'م', 'ۛ', 'ى', 'ٔ', 'ݔ', 'د', '̤', '̣', 'ص', '̤', '̣', 'گ', 'و', 'ز', '̂', 'ب', 'ۢ', 'ى', 'ۛ', 'ۢ', 'ر', '̆', '̇', 'ى', '̆', '̇', 'ڡ', 'ڡ', 'ى', 'ٌ', 'ٌ', '̇', '̈', '̣', '̤', '̋', 'ٌ', 'ٍ', '̓', '͐', '͔', '͕', '͐', '͒', '̆', '̇', '̇', ':', 'अ', 'ॆ', 'अ', 'ा', 'र', '्', 'इ', 'ए', 'ॅ', 'ए', 'ॆ', 'ए', 'े', 'अ', 'ॉ', 'अ', 'ा', 'ॆ', 'अ', 'ा', 'े', 'अ', 'ा', 'ै', '̣', '̱', '̀', '́', '।', '।', 'o', '٩', '?', '̆', '̇', 'অ', 'া', '̣', 'ঋ', 'ৃ', 'ঋ', 'ৃ', 'O', '8', '9', '̇', 'ঃ', 'ਅ', 'ਾ', 'ੲ', 'ਿ', 'ੲ', 'ੀ', 'ੳ', 'ੁ', 'ੳ', 'ੂ', 'ੲ', 'ੇ', 'ਅ', 'ੈ', 'ਅ', 'ੌ', '̣', 'ॆ', '्', 'o', '9', '8', '̆', '̇', '̇', ':', 'અ', 'ા', 'અ', 'ૅ', 'અ', 'ે', 'અ', 'ૈ', 'અ', 'ા', 'ૅ', 'અ', 'ા', 'ે', 'અ', 'ા', 'ૈ', '̣', 'ऽ', 'ु', 'ू', '्', 'o', '२', '३', '४', '८', '॰', '̆', '̇', '8', 'ଅ', 'ା', 'O', '̣', 'O', '9', '̊', 'உ', 'ள', 'ஐ', 'ஈ', 'ஈ', 'ன', 'ெ', 'ஈ', 'ே', 'ஈ', 'ெ', 'ள', '̇', 'ள', 'o', 'க', 'உ', 'ச', 'ஈ', 'ு', 'ச', 'ு', 'எ', 'அ', 'ய', 'ச', 'ூ', 'ம', 'ீ', '௳', 'எ', 'வ', 'ஷ', 'ந', 'ீ', '̆', '̇', 'o', 'ঃ', 'ఒ', 'ౕ', 'ఒ', 'ౌ', 'ర', 'ּ', 'డ', '̣', 'ధ', 'ּ', 'బ', '̣', 'వ', 'ు', 'వ', '̣', 'వ', 'ా', 'ు', 'ా', 'ృ', 'ా', 'ఋ', 'ా', 'ఌ', 'ా', 'o', '̆', '̇', 'o', 'ঃ', 'అ', 'ఆ', 'ఇ', 'ఒ', 'ఒ', 'ౕ', 'ఒ', 'ౌ', 'జ', 'ఞ', 'ణ', 'య', 'ఱ', 'ల', 'ಌ', 'ಾ', 'o', '౧', '౨', '౯', '̆', '̇', 'o', 'ঃ', 'ഇ', 'ൗ', 'உ', 'உ', 'ൗ', 'ന', 'ു', 'എ', 'െ', 'ഒ', 'ാ', 'ഒ', 'ൗ', 'ന', 'ു', 'ஐ', 'o', 'ண', 'ര', 'ழ', 'ஶ', 'ட', 'ி', 'ி', 'ி', 'ു', 'ു', 'െ', 'െ', 'ॱ', 'ന', '്', 'മ', 'o', 'ര', 'o', 'ഞ', 'o', 'ര', '്', 'ദ', '്', 'ര', 'ന', '്', 'ന', '9', 'വ', '്', 'ര', 'ന', '്', 'ഹ', '്', 'മ', 'ന', 'ു', 'ന', '്', 'ര', '്', 'o', 'ঃ', '෨', 'ා', 'ජ', 'ද', '෨', 'ී', 'ข', 'ช', 'ฎ', 'ค', 'ค', 'ฑ', 'ฆ', 'ภ', '̊', 'า', 'เ', 'เ', 'า', '̊', 'o', 'จ', 'ย', 'บ', 'ป', 'ฝ', 'พ', 'ฟ', '̊', 'າ', 'ุ', 'ู', '่', '้', '๊', '๋', '̊', 'o', 'ຫ', 'ນ', 'ຫ', 'ມ', 'ཨ', 'ོ', 'ཾ', 'འ', 'ུ', 'ྂ', 'ཿ', 'འ', 'ུ', 'ྂ', '༔', '་', '།', '།', '༚', '༚', '༝', '༝', '༚', '༝', '̥', 'ར', 'ྲ', 'ཱ', 'ྀ', 'ླ', 'ཱ', 'ྀ', '༝', '༚', '卐', '卍', 'ဂ', 'ာ', 'o', 'ာ', 'o', 'ပ', 'ာ', 'သ', 'ြ', 'သ', 'ြ', 'ေ', 'ာ', '်', '̊', 'ঃ', 'o', '၊', '၊', '၁', 'ပ', 'ှ', 'ပ', 'ာ', 'ှ', 'ဃ', 'ှ', 'ၽ', 'ှ', 'ဂ', 'ှ', 'ႃ', '̊', 'Ꞇ', 'y', 'ȝ', 'o', 'ᄀ', 'ᄀ', 'ᄃ', 'ᄃ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄌ', 'ᄌ', 'ᄂ', 'ᄀ', 'ᄂ', 'ᄂ', 'ᄂ', 'ᄃ', 'ᄂ', 'ᄇ', 'ᄃ', 'ᄀ', 'ᄅ', 'ᄂ', 'ᄅ', 'ᄅ', 'ᄅ', 'ᄒ', 'ᄅ', 'ᄋ', 'ᄆ', 'ᄇ', 'ᄆ', 'ᄋ', 'ᄇ', 'ᄀ', 'ᄇ', 'ᄂ', 'ᄇ', 'ᄃ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄀ', 'ᄇ', 'ᄉ', 'ᄃ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄌ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄎ', 'ᄇ', 'ᄐ', 'ᄇ', 'ᄑ', 'ᄇ', 'ᄋ', 'ᄇ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄂ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄅ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄀ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄋ', 'ᄉ', 'ᄌ', 'ᄉ', 'ᄎ', 'ᄉ', 'ᄏ', 'ᄉ', 'ᄐ', 'ᄉ', 'ᄑ', 'ᄅ', 'ᄒ', 'ᄼ', 'ᄼ', 'ᄾ', 'ᄾ', 'ᄋ', 'ᄀ', 'ᄋ', 'ᄃ', 'ᄋ', 'ᄆ', 'ᄋ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄋ', 'ᅀ', 'ᄋ', 'ᄋ', 'ᄋ', 'ᄌ', 'ᄋ', 'ᄎ', 'ᄋ', 'ᄐ', 'ᄋ', 'ᄑ', 'ᄌ', 'ᄋ', 'ᅎ', 'ᅎ', 'ᅐ', 'ᅐ', 'ᄎ', 'ᄏ', 'ᄎ', 'ᄒ', 'ᄑ', 'ᄇ', 'ᄑ', 'ᄋ', 'ᄒ', 'ᄒ', 'ᄀ', 'ᄃ', 'ᄂ', 'ᄉ', 'ᄂ', 'ᄌ', 'ᄂ', 'ᄒ', 'ᄃ', 'ᄅ', 'ᅡ', '丨', 'ᅣ', '丨', 'ᅥ', '丨', 'ᅧ', '丨', 'ᅩ', 'ᅡ', 'ᅩ', 'ᅡ', '丨', 'ᅩ', '丨', 'ᅮ', 'ᅥ', 'ᅮ', 'ᅥ', '丨', 'ᅮ', '丨', 'ー', 'ー', '丨', '丨', 'ᅡ', 'ᅩ', 'ᅡ', 'ᅮ', 'ᅣ', 'ᅩ', 'ᅣ', 'ᅭ', 'ᅥ', 'ᅩ', 'ᅥ', 'ᅮ', 'ᅥ', 'ー', 'ᅧ', 'ᅩ', 'ᅧ', 'ᅮ', 'ᅩ', 'ᅥ', 'ᅩ', 'ᅥ', '丨', 'ᅩ', 'ᅧ', '丨', 'ᅩ', 'ᅩ', 'ᅩ', 'ᅮ', 'ᅭ', 'ᅣ', 'ᅭ', 'ᅣ', '丨', 'ᅭ', 'ᅣ', 'ᅭ', 'ᅩ', 'ᅭ', '丨', 'ᅮ', 'ᅡ', 'ᅮ', 'ᅡ', '丨', 'ᅮ', 'ᅥ', 'ー', 'ᅮ', 'ᅧ', '丨', 'ᅮ', 'ᅮ', 'ᅲ', 'ᅡ', 'ᅲ', 'ᅥ', 'ᅲ', 'ᅥ', '丨', 'ᅲ', 'ᅧ', 'ᅲ', 'ᅧ', '丨', 'ᅲ', 'ᅮ', 'ᅲ', '丨', 'ー', 'ᅮ', 'ー', 'ー', 'ー', '丨', 'ᅮ', '丨', 'ᅡ', '丨', 'ᅣ', '丨', 'ᅩ', '丨', 'ᅮ', '丨', 'ー', '丨', 'ᆞ', 'ᆞ', 'ᅥ', 'ᆞ', 'ᅮ', 'ᆞ', '丨', 'ᆞ', 'ᆞ', 'ᅡ', 'ー', 'ᅣ', 'ᅮ', 'ᅧ', 'ᅣ', 'ᅩ', 'ᅣ', 'ᅩ', 'ᅣ', '丨', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄉ', 'ᄂ', 'ᄂ', 'ᄌ', 'ᄂ', 'ᄒ', 'ᄃ', 'ᄅ', 'ᄅ', 'ᄀ', 'ᄅ', 'ᄆ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄉ', 'ᄅ', 'ᄐ', 'ᄅ', 'ᄑ', 'ᄅ', 'ᄒ', 'ᄆ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄋ', 'ᄌ', 'ᄎ', 'ᄏ', 'ᄐ', 'ᄑ', 'ᄒ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄉ', 'ᄀ', 'ᄂ', 'ᄀ', 'ᄂ', 'ᄃ', 'ᄂ', 'ᄉ', 'ᄂ', 'ᅀ', 'ᄂ', 'ᄐ', 'ᄃ', 'ᄀ', 'ᄃ', 'ᄅ', 'ᄅ', 'ᄀ', 'ᄉ', 'ᄅ', 'ᄂ', 'ᄅ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄒ', 'ᄅ', 'ᄅ', 'ᄅ', 'ᄆ', 'ᄀ', 'ᄅ', 'ᄆ', 'ᄉ', 'ᄅ', 'ᄇ', 'ᄉ', 'ᄅ', 'ᄇ', 'ᄒ', 'ᄅ', 'ᄇ', 'ᄋ', 'ᄅ', 'ᄉ', 'ᄉ', 'ᄅ', 'ᅀ', 'ᄅ', 'ᄏ', 'ᄅ', 'ᅙ', 'ᄆ', 'ᄀ', 'ᄆ', 'ᄅ', 'ᄆ', 'ᄇ', 'ᄆ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄉ', 'ᄆ', 'ᅀ', 'ᄆ', 'ᄎ', 'ᄆ', 'ᄒ', 'ᄆ', 'ᄋ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄑ', 'ᄇ', 'ᄒ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄅ', 'ᄉ', 'ᄇ', 'ᅀ', 'ᄋ', 'ᄀ', 'ᄋ', 'ᄀ', 'ᄀ', 'ᄋ', 'ᄋ', 'ᄋ', 'ᄏ', 'ᅌ', 'ᄋ', 'ᄉ', 'ᄋ', 'ᅀ', 'ᄑ', 'ᄇ', 'ᄑ', 'ᄋ', 'ᄒ', 'ᄂ', 'ᄒ', 'ᄅ', 'ᄒ', 'ᄆ', 'ᄒ', 'ᄇ', 'ᅙ', 'ᄀ', 'ᄂ', 'ᄀ', 'ᄇ', 'ᄀ', 'ᄎ', 'ᄀ', 'ᄏ', 'ᄀ', 'ᄒ', 'ᄂ', 'ᄂ', 'U', 'ɰ', 'Φ', 'Ո', 'ձ', 'O', 'D', 'R', 'T', 'O', '\'', 'i', 'Ⱶ', 'Y', 'A', 'J', 'E', '?', 'Ⱶ', 'Γ', 'W', 'M', 'H', 'Y', 'O', '̵', 'ƫ', 'G', 'h', 'Z', 'Ѡ', 'Ɛ', 'U', '̵', '4', 'b', 'R', 'W', 'S', 'V', 'S', 'L', 'C', 'P', 'K', 'd', 'O', '̵', '6', 'ß', 'h', '̔', 'G', 'B', 'ɢ', 'ʙ', '=', 'Δ', '·', 'ᐁ', 'ᐁ', '·', '·', 'Δ', 'Δ', '·', '·', 'ᐄ', 'ᐄ', '·', '·', 'ᐅ', 'ᐅ', '·', '·', 'ᐆ', 'ᐆ', '·', '·', 'ᐊ', 'ᐊ', '·', '·', 'ᐋ', 'ᐋ', '·', '·', 'ᐁ', 'ᐠ', 'Δ', 'ᐠ', 'ᐅ', 'ᐠ', 'ᐊ', 'ᐠ', 'V', 'Ʌ', '>', '·', '>', '<', '·', 'V', 'V', '·', '·', 'Ʌ', 'Ʌ', '·', '·', 'ᐲ', 'ᐲ', '·', '·', '>', '>', '·', '·', 'ᐴ', 'ᐴ', '·', '·', '<', '<', '·', '·', 'ᐹ', 'ᐹ', '·', '\'', 'U', 'Ո', '·', 'ᑐ', '·', 'U', 'U', '·', '·', 'Ո', 'Ո', '·', '·', 'ᑏ', 'ᑏ', '·', '·', 'ᑐ', 'ᑐ', '·', '·', 'ᑑ', 'ᑑ', '·', '·', 'ᑕ', 'ᑕ', '·', '·', 'ᑖ', 'ᑖ', '·', 'U', '\'', 'Ո', '\'', 'ᑐ', '\'', 'ᑕ', '\'', 'P', 'd', '·', 'ᑫ', 'ᑫ', '·', '·', 'P', 'p', '·', '·', 'ᑮ', 'ᑮ', '·', '·', 'd', 'd', '·', '·', 'ᑰ', 'ᑰ', '·', '·', 'ᑲ', 'ᑲ', '·', '·', 'ᑳ', 'ᑳ', '·', 'ᑫ', '\'', 'P', '\'', 'd', '\'', 'ᑲ', '\'', 'J', '·', 'ᒉ', 'ᒉ', '·', '·', 'ᒋ', 'ᒋ', '·', '·', 'ᒌ', 'ᒌ', '·', '·', 'J', 'J', '·', '·', 'ᒎ', 'ᒎ', '·', '·', 'ᒐ', 'ᒐ', '·', '·', 'ᒑ', 'ᒑ', '·', 'Γ', 'L', '·', 'ᒣ', 'ᒣ', '·', '·', 'Γ', 'Γ', '·', '·', 'ᒦ', 'ᒦ', '·', '·', 'ᒧ', 'ᒧ', '·', '·', 'ᒨ', 'ᒨ', '·', '·', 'L', 'l', '·', '·', 'ᒫ', 'ᒫ', '·', '2', '·', 'ᓀ', 'ᓀ', '·', '·', 'ᓇ', 'ᓇ', '·', '·', 'ᓈ', 'ᓈ', '·', 'ᐡ', '·', 'ᓓ', 'ᓓ', '·', '·', 'ᓕ', 'ᓕ', '·', '·', 'ᓖ', 'ᓖ', '·', '·', 'ᓗ', 'ᓗ', '·', '·', 'ᓘ', 'ᓘ', '·', '·', 'ᓚ', 'ᓚ', '·', '·', 'ᓛ', 'ᓛ', '·', '·', 'ᓭ', 'ᓭ', '·', '·', 'ᓯ', 'ᓯ', '·', '·', 'ᓰ', 'ᓰ', '·', '·', 'ᓱ', 'ᓱ', '·', '·', 'ᓲ', 'ᓲ', '·', '·', 'ᓴ', 'ᓴ', '·', '·', 'ᓵ', 'ᓵ', '·', 'ᔋ', '<', 'ᔋ', 'ᑕ', 'ᔋ', 'ᑲ', 'ᔋ', 'ᒐ', '·', 'ᔐ', 'ᔐ', '·', '·', 'ᔑ', 'ᔑ', '·', '·', 'ᔒ', 'ᔒ', '·', '·', 'ᔓ', 'ᔓ', '·', '·', 'ᔔ', 'ᔔ', '·', '·', 'ᔕ', 'ᔕ', '·', '·', 'ᔖ', 'ᔖ', '·', '·', '4', '4', '·', '·', 'ᔨ', 'ᔨ', '·', '·', 'ᔩ', 'ᔩ', '·', '·', 'ᔪ', 'ᔪ', '·', '·', 'ᔫ', 'ᔫ', '·', '·', 'ᔭ', 'ᔭ', '·', '·', 'ᔮ', 'ᔮ', '·', 'ᐩ', 'x', '·', 'ᕌ', 'ᕌ', '·', '·', 'ᕚ', 'ᕚ', '·', '·', 'ᕧ', 'ᕧ', '·', 'ẟ', 'H', 'x', 'ᕐ', 'ᑬ', 'ᕐ', 'P', 'ᕐ', 'ᑮ', 'ᕐ', 'd', 'ᕐ', 'ᑰ', 'ᕐ', 'ᑲ', 'ᕐ', 'ᑳ', 'ᕐ', 'ᒃ', 'R', 'ᖕ', 'ᒊ', 'ᖕ', 'ᒋ', 'ᖕ', 'ᒌ', 'ᖕ', 'J', 'ᖕ', 'ᒎ', 'ᖕ', 'ᒐ', 'ᖕ', 'ᒑ', 'b', 'F', 'Ⅎ', 'ꟻ', 'Ɐ', 'A', 'D', 'D', 'Ѡ', 'M', 'B', 'ᒐ', 'ᒉ', 'ᓓ', 'ᓚ', 'ᕃ', 'ᕆ', 'ᕊ', 'Ʊ', 'Ω', 'Ʊ', 'Ω', 'X', 'x', 'ᕐ', 'ᑫ', 'ᖕ', 'ᒉ', 'ᖖ', 'ᒋ', 'ᖖ', 'ᒌ', 'ᖖ', 'J', 'ᖖ', 'ᒎ', 'ᖖ', 'ᒐ', 'ᖖ', 'ᒑ', 'ᖧ', '·', 'ᖨ', '·', 'ᖩ', '·', 'ᖪ', '·', 'ᖫ', '·', 'ᖬ', '·', 'ᖭ', '·', ' ', '<', 'X', 'l', 'ᚽ', '\'', 'K', 'M', 'Ψ', 'ᚼ', '·', ':', '+', 'Φ', '/', 'អ', 'ิ', 'ี', 'ึ', 'ื', '̊', '่', '̊', 'ฯ', '๚', '๏', '๛', ':', ':', 'ᠵ', 'ᡜ', '·', 'ᢱ', '·', 'ᢴ', '·', 'ᢸ', '·', 'ᣀ', '·', 'ᓂ', 'ᓂ', '·', '·', 'ᓃ', 'ᓃ', '·', '·', 'ᓄ', 'ᓄ', '·', '·', 'ᓅ', 'ᓅ', '·', '·', 'ᕃ', '·', 'ᕆ', '·', 'ᕇ', '·', 'ᕈ', '·', 'ᕉ', '·', 'ᕋ', 'ᣵ', 'ᣟ', 'ᐞ', 'ᐞ', 'ᣟ', 'ᕃ', '·', 'ᕞ', '·', 'ᕦ', '·', 'ᕫ', '·', 'ᖆ', '·', 'ᖗ', '·', 'Ѡ', '·', 'ᗴ', '·', 'ᘛ', '·', 'ᦞ', 'ᦱ', 'ᩅ', 'ᩅ', '᪨', '᪨', '᪪', '᪨', 'ۛ', '̨', 'ᬍ', 'ᬑ', 'ᬨ', '᭐', '᭞', '᭞', '᰻', '᰻', '᱾', '᱾', '̂', '̄', '\'', '\'', '̫', '̮', '̭', '̎', '̩', '̣', '̤', '̖', 'c', 'ɜ', 'ĸ', 'ʍ', 'o', 'ɔ', 'o', 'ǝ', 'o', 'u', 'v', 'w', 'z', 'ƨ', 'r', 'ʌ', 'π', 'ᴘ', 'л', 'ᣖ', 'º', 'u', 'e', 'f', '̴', 'r', 'n', '̴', 'n', '̴', 'r', '̴', 'ɾ', '̴', 's', '̴', 't', '̴', 'z', '̴', 'ᴴ', 'i', '̵', 'i', '̵', 'p', '̵', 'u', '̵', 'ʊ', '̵', 'g', 'y', 'ɋ', 'ᵋ', 'ᵍ', 'ᣔ', 'ᙆ', 'ⷬ', 'ꭑ', 'ả', 'f', 'y', 'ῴ', '\'', 'i', '\'', '~', '\'', 'Ꮿ', '\'', '\'', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '-', '-', '-', '-', 'ー', 'ー', 'l', 'l', '\'', '\'', ',', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '·', '.', '.', '.', '.', '.', '.', '·', ' ', ' ', ' ', 'º', '/', '₀', '₀', 'º', '/', '₀', '₀', '₀', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '<', '>', '!', '!', 'ˉ', '/', '-', '/', '?', '?', '?', '!', '!', '?', '*', 'º', '/', '₀', '~', '\'', '\'', '\'', '\'', ':', 'ⵗ', 'ⵂ', ' ', 'º', 'ꝰ', 'C', '⃫', '£', 'r', 'n', '̸', 'R', 's', 'W', '̵', 'd', '̵', '̱', 'Ꞓ', 'K', '̵', 'T', '⃫', 'l', 't', 'Ք', 'ۛ', 'a', '/', 'c', 'a', '/', 's', 'C', '°', 'C', 'c', '/', 'o', 'c', '/', 'u', 'Ɛ', 'Э', '°', 'F', 'g', 'H', 'H', 'H', 'h', 'h', '̵', 'l', 'l', 'L', 'l', 'N', 'N', 'o', 'P', 'Q', 'R', 'R', 'R', 'T', 'E', 'L', 'Z', 'Ω', 'Ʊ', 'Z', 'ɿ', 'K', 'B', 'C', 'e', 'e', 'E', 'F', 'M', 'o', 'א', 'ב', 'ג', 'ד', 'i', 'F', 'A', 'X', 'π', 'y', 'Γ', 'Π', 'Ʃ', 'ꓨ', 'ꓶ', '𖼀', 'D', 'd', 'e', 'i', 'j', 'l', 'l', 'l', 'l', 'l', 'l', 'l', 'V', 'V', 'V', 'l', 'V', 'l', 'l', 'V', 'l', 'l', 'l', 'l', 'X', 'X', 'X', 'l', 'X', 'l', 'l', 'L', 'C', 'D', 'M', 'i', 'i', 'i', 'i', 'i', 'i', 'i', 'v', 'v', 'v', 'i', 'v', 'i', 'i', 'v', 'i', 'i', 'i', 'i', 'x', 'x', 'x', 'i', 'x', 'i', 'i', 'l', 'c', 'd', 'r', 'n', 'Ɔ', 'ɔ', 'ᛏ', 'ᛨ', '↲', 'ᛚ', 'ᛐ', 'Ɐ', 'Ǝ', 'Δ', 'Π', 'Ʃ', '-', '+', '̇', '/', '\\', '*', '°', '·', 'o', 'o', 'l', 'l', 'l', 'v', 'Ո', 'U', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', '∮', '∮', '∮', '∮', '∮', ':', '-', '̇', '~', '=', '̇', '=', '̇', '̣', '=', '̊', '=', '̂', '=', '̆', '=', 'ͫ', '≡', '<', '<', '>', '>', 'ᑕ', 'ᑐ', '𐊨', 'O', '̵', 'ʘ', 'O', '̵', 'T', 'ꓕ', '∧', 'v', 'Ո', 'U', 'ᛜ', '·', 'ᛞ', '<', '·', '·', '>', '<', '<', '<', '>', '>', '>', 'ⵗ', '·', '·', '·', 'ꞓ', 'E', '∅', '⌤', '❬', '❭', '〼', 'Δ', '̲', 'ᛜ', '̲', '°', '̲', '⊛', 'T', '̈', '∇', '̈', '⋆', '̈', '°', '̈', '~', '̈', 'ᐵ', '∇', '̴', 'O', '̵', 'i', 'p', 'ω', 'a', '̲', 'ꞓ', '̲', 'i', '̲', 'ω', '̲', 'a', 'ᚽ', '丨', '丨', '丨', '丨', '丨', '丨', '⍕', '⍎', '⍋', '⍭', '₁', '₀', '⏻', 'l', '☾', '\\', '\\', '➀', '➁', '➂', '➃', '➄', '➅', '➆', '➇', '➈', '➉', '(', 'l', ')', '(', '2', ')', '(', '3', ')', '(', '4', ')', '(', '5', ')', '(', '6', ')', '(', '7', ')', '(', '8', ')', '(', '9', ')', '(', 'l', 'O', ')', '(', 'l', 'l', ')', '(', 'l', '2', ')', '(', 'l', '3', ')', '(', 'l', '4', ')', '(', 'l', '5', ')', '(', 'l', '6', ')', '(', 'l', '7', ')', '(', 'l', '8', ')', '(', 'l', '9', ')', '(', '2', 'O', ')', 'l', '.', '2', '.', '3', '.', '4', '.', '5', '.', '6', '.', '7', '.', '8', '.', '9', '.', 'l', 'O', '.', 'l', 'l', '.', 'l', '2', '.', 'l', '3', '.', 'l', '4', '.', 'l', '5', '.', 'l', '6', '.', 'l', '7', '.', 'l', '8', '.', 'l', '9', '.', '2', 'O', '.', '(', 'a', ')', '(', 'b', ')', '(', 'c', ')', '(', 'd', ')', '(', 'e', ')', '(', 'f', ')', '(', 'g', ')', '(', 'h', ')', '(', 'i', ')', '(', 'j', ')', '(', 'k', ')', '(', 'l', ')', '(', 'r', 'n', ')', '(', 'n', ')', '(', 'o', ')', '(', 'p', ')', '(', 'q', ')', '(', 'r', ')', '(', 's', ')', '(', 't', ')', '(', 'u', ')', '(', 'v', ')', '(', 'w', ')', '(', 'x', ')', '(', 'y', ')', '(', 'z', ')', '©', '℗', '®', 'Ⓘ', 'ー', 'ー', '│', '┌', '├', '/', 'X', '∎', '▌', 'ˉ', '▖', '▘', '∎', '⏥', 'Δ', '⊳', '▶', '▶', '𐊼', '⊲', 'ᛜ', 'ᛜ', '°', '⌾', '⌒', '°', 'ʘ', '□', '𐦞', 'Ⲷ', '⎈', '≏', 'ᛜ', '𝅘', '𝅥', '𝅘', '𝅥', '𝅮', '॰', '(', ')', '<', '>', '(', ')', '{', '}', '+', '-', '÷', 'ꓕ', '\\', 'ᑕ', 'ᑐ', '/', '/', '\\', 'T', '❬', '❭', 'x', 'x', 'ᛐ', 'ᛚ', '⇃', '⇂', 'ᛐ', '⇂', '⇃', 'ᛚ', 'ⵂ', '⍉', '⌾', '〼', '⍂', '⌻', '𐋀', '⦚', ':', '→', '\\', '/', '̄', '/', '\\', 'ʘ', '𐊨', '⊗', '⊍', '⊎', '⊓', '⊔', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ᛞ', '>', '>', 'ᛚ', '+', '̊', '+', '̂', '+', '̃', '+', '̣', '+', '̰', '+', '₂', '-', '̓', '-', '̣', 'x', 'x', '̇', '⌙', '⨟', '∐', '~', '̇', '=', '⃰', ':', ':', '=', '=', '=', '=', '=', '=', '>', '<', 'ᗕ', 'ᗒ', 'ᑐ', 'ᑕ', '/', '/', '/', '/', '/', '↞', '↟', '↠', '↡', 'H', '̩', 'K', '̩', 'Γ', 'r', 'Δ', 'Ꞓ', 'ꞓ', 'H', 'l', 'K', 'ĸ', 'λ', 'M', 'N', 'O', 'o', 'Π', 'P', 'p', 'C', 'c', 'T', 'Y', 'Φ', 'ɸ', 'X', 'χ', 'Ψ', 'ω', '<', '·', '-', 'Ш', 'ш', '/', '9', '3', 'ȝ', 'L', 'ʟ', '6', 'Ϭ', 'ϗ', '☧', '\\', '\\', 'O', '̵', 'Ʌ', 'V', 'E', 'Ǝ', 'O', '̸', '·', '·', '·', 'Ʃ', 'l', '!', 'O', 'Q', 'ʘ', 'X', 'Δ', 'ᛯ', 'ᷟ', '̊', 'ͨ', 'ͯ', 'ͣ', 'ͤ', '-', '̈', '~', '̇', '~', '̣', 'ᑕ', 'ᑐ', '(', '(', ')', ')', '∵', '∴', '∷', '؟', '°', '·', '،', '؛', 'ẟ', 'ⵂ', '¶', '=', '乛', '乚', '亻', '刂', '㔾', '兀', '尣', '尢', '巳', '幺', '彑', '忄', '㣺', '扌', '攵', '旡', '歺', '母', '民', '氵', '氺', '灬', '爫', '丬', '犭', '罒', '礻', '糹', '罓', '罒', '耂', '肀', '艹', '艹', '艹', '虎', '衤', '覀', '西', '见', '讠', '贝', '车', '辶', '辶', '阝', '钅', '長', '镸', '长', '门', '阝', '青', '韦', '页', '风', '飞', '食', '飠', '饣', '马', '鬼', '鱼', '麦', '黄', '斉', '齐', '歯', '齿', '竜', '龙', '亀', '龟', 'ー', '丨', '\\', '/', '乙', '亅', '二', '亠', '人', '儿', '入', '八', '冂', '冖', '冫', '几', '凵', '刀', '力', '勹', '匕', '匚', '匸', '十', '卜', '卩', '厂', '厶', '又', '口', '口', '土', '土', '夂', '夊', '夕', '大', '女', '子', '宀', '寸', '小', '尢', '尸', '屮', '山', '巛', '工', '己', '巾', '干', '幺', '广', '廴', '廾', '弋', '弓', '彐', '彡', '彳', '心', '戈', '戶', '手', '支', '攴', '文', '斗', '斤', '方', '无', '日', '曰', '月', '木', '欠', '止', '歹', '殳', '毋', '比', '毛', '氏', '气', '水', '火', '爪', '父', '爻', '爿', '片', '牙', '牛', '犬', '玄', '玉', '瓜', '瓦', '甘', '生', '用', '田', '疋', '疒', '癶', '白', '皮', '皿', '目', '矛', '矢', '石', '示', '禸', '禾', '穴', '立', '竹', '米', '糸', '缶', '网', '羊', '羽', '老', '而', '耒', '耳', '聿', '肉', '臣', '自', '至', '臼', '舌', '舛', '舟', '艮', '色', '艸', '虍', '虫', '血', '行', '衣', '襾', '見', '角', '言', '谷', '豆', '豕', '豸', '貝', '赤', '走', '足', '身', '車', '辛', '辰', '辵', '邑', '酉', '釆', '里', '金', '長', '門', '阜', '隶', '隹', '雨', '靑', '非', '面', '革', '韋', '韭', '音', '頁', '風', '飛', '食', '首', '香', '馬', '骨', '高', '髟', '鬥', '鬯', '鬲', '鬼', '魚', '鳥', '鹵', '鹿', '麥', '麻', '黃', '黍', '黑', '黹', '黽', '鼎', '鼓', '鼠', '鼻', '齊', '齒', '龍', '龜', '龠', '˳', '\'', '\'', 'O', '❬', '❭', '₸', '(', ')', '⟦', '⟧', '̉', '̥', '/', '₸', '十', '卄', '卅', '❬', '̊', 'ﾞ', 'ﾟ', '=', '亻', '工', '力', '夕', '卜', '二', '/', '八', 'へ', '口', '·', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄉ', 'ᄂ', 'ᄂ', 'ᄌ', 'ᄂ', 'ᄒ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄅ', 'ᄅ', 'ᄀ', 'ᄅ', 'ᄆ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄉ', 'ᄅ', 'ᄐ', 'ᄅ', 'ᄑ', 'ᄅ', 'ᄒ', 'ᄆ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄋ', 'ᄌ', 'ᄌ', 'ᄌ', 'ᄎ', 'ᄏ', 'ᄐ', 'ᄑ', 'ᄒ', 'ᅡ', 'ᅡ', '丨', 'ᅣ', 'ᅣ', '丨', 'ᅥ', 'ᅥ', '丨', 'ᅧ', 'ᅧ', '丨', 'ᅩ', 'ᅩ', 'ᅡ', 'ᅩ', 'ᅡ', '丨', 'ᅩ', '丨', 'ᅭ', 'ᅮ', 'ᅮ', 'ᅥ', 'ᅮ', 'ᅥ', '丨', 'ᅮ', '丨', 'ᅲ', 'ー', 'ー', '丨', '丨', 'ᅠ', 'ᄂ', 'ᄂ', 'ᄂ', 'ᄃ', 'ᄂ', 'ᄉ', 'ᄂ', 'ᅀ', 'ᄅ', 'ᄀ', 'ᄉ', 'ᄅ', 'ᄃ', 'ᄅ', 'ᄇ', 'ᄉ', 'ᄅ', 'ᅀ', 'ᄅ', 'ᅙ', 'ᄆ', 'ᄇ', 'ᄆ', 'ᄉ', 'ᄆ', 'ᅀ', 'ᄆ', 'ᄋ', 'ᄇ', 'ᄀ', 'ᄇ', 'ᄃ', 'ᄇ', 'ᄉ', 'ᄀ', 'ᄇ', 'ᄉ', 'ᄃ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄐ', 'ᄇ', 'ᄋ', 'ᄇ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄂ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄌ', 'ᅀ', 'ᄋ', 'ᄋ', 'ᅌ', 'ᄋ', 'ᄉ', 'ᄋ', 'ᅀ', 'ᄑ', 'ᄋ', 'ᄒ', 'ᄒ', 'ᅙ', 'ᅭ', 'ᅣ', 'ᅭ', 'ᅣ', '丨', 'ᅭ', '丨', 'ᅲ', 'ᅧ', 'ᅲ', 'ᅧ', '丨', 'ᅲ', '丨', 'ᆞ', 'ᆞ', '丨', 'ー', '丨', '/', '\\', '乛', '亅', '❬', '乚', '乙', '(', 'ᄀ', ')', '(', 'ᄂ', ')', '(', 'ᄃ', ')', '(', 'ᄅ', ')', '(', 'ᄆ', ')', '(', 'ᄇ', ')', '(', 'ᄉ', ')', '(', 'ᄋ', ')', '(', 'ᄌ', ')', '(', 'ᄎ', ')', '(', 'ᄏ', ')', '(', 'ᄐ', ')', '(', 'ᄑ', ')', '(', 'ᄒ', ')', '(', '가', ')', '(', '나', ')', '(', '다', ')', '(', '라', ')', '(', '마', ')', '(', '바', ')', '(', '사', ')', '(', '아', ')', '(', '자', ')', '(', '차', ')', '(', '카', ')', '(', '타', ')', '(', '파', ')', '(', '하', ')', '(', '주', ')', '(', '오', '전', ')', '(', '오', '후', ')', '(', 'ー', ')', '(', '二', ')', '(', '三', ')', '(', '四', ')', '(', '五', ')', '(', '六', ')', '(', '七', ')', '(', '八', ')', '(', '九', ')', '(', '十', ')', '(', '月', ')', '(', '火', ')', '(', '水', ')', '(', '木', ')', '(', '金', ')', '(', '土', ')', '(', '日', ')', '(', '株', ')', '(', '有', ')', '(', '社', ')', '(', '名', ')', '(', '特', ')', '(', '財', ')', '(', '祝', ')', '(', '労', ')', '(', '代', ')', '(', '呼', ')', '(', '学', ')', '(', '監', ')', '(', '企', ')', '(', '資', ')', '(', '協', ')', '(', '祭', ')', '(', '休', ')', '(', '自', ')', '(', '至', ')', 'l', '月', '2', '月', '3', '月', '4', '月', '5', '月', '6', '月', '7', '月', '8', '月', '9', '月', 'l', 'O', '月', 'l', 'l', '月', 'l', '2', '月', 'O', '点', 'l', '点', '2', '点', '3', '点', '4', '点', '5', '点', '6', '点', '7', '点', '8', '点', '9', '点', 'l', 'O', '点', 'l', 'l', '点', 'l', '2', '点', 'l', '3', '点', 'l', '4', '点', 'l', '5', '点', 'l', '6', '点', 'l', '7', '点', 'l', '8', '点', 'l', '9', '点', '2', 'O', '点', '2', 'l', '点', '2', '2', '点', '2', '3', '点', '2', '4', '点', 'l', '日', '2', '日', '3', '日', '4', '日', '5', '日', '6', '日', '7', '日', '8', '日', '9', '日', 'l', 'O', '日', 'l', 'l', '日', 'l', '2', '日', 'l', '3', '日', 'l', '4', '日', 'l', '5', '日', 'l', '6', '日', 'l', '7', '日', 'l', '8', '日', 'l', '9', '日', '2', 'O', '日', '2', 'l', '日', '2', '2', '日', '2', '3', '日', '2', '4', '日', '2', '5', '日', '2', '6', '日', '2', '7', '日', '2', '8', '日', '2', '9', '日', '3', 'O', '日', '3', 'l', '日', '㘽', '㖈', '㬻', 'ー', '\\', '/', '併', '値', '啓', '口', '塡', '土', '墫', '媯', '帡', '㬺', '戶', '㩁', '䀿', '晚', '㫚', '䑃', '杮', '㮣', '榝', '溈', '研', '絕', '朌', '朐', '朏', '㬵', '朓', '朘', '胼', '朣', '蒍', '蘷', '䚶', '訮', '讆', '豜', '赿', '跥', '躗', '軿', '郎', '鎭', '隷', '鹂', '黑', '䀹', 'ꋍ', 'ꃀ', 'ꁊ', 'ꑘ', 'ꄲ', 'ꁐ', 'ꏂ', 'ꎿ', 'ꊱ', 'ꉙ', 'ꎫ', 'ꎵ', 'B', 'P', 'd', 'D', 'T', 'G', 'K', 'J', 'C', 'Ɔ', 'Z', 'F', 'Ⅎ', 'M', 'N', 'L', 'S', 'R', 'Ʌ', 'V', 'H', 'W', 'X', 'Y', 'ᙠ', 'A', 'Ɐ', 'E', 'Ǝ', 'l', 'O', 'U', 'Ո', 'ᗡ', '.', ',', '.', '.', '.', ',', ':', '-', '.', '=', '.', '2', 'ƨ', 'i', 'ω', 'Ъ', 'l', 'ˉ', 'b', 'i', 'ʘ', '⃩', '̆', 'ˇ', 'h', '̔', 'O', 'O', 'o', 'o', '𐊨', 'И', 'ᚹ', 'Ⱶ', 'ʡ', 'Ʌ', 'Π', 'V', '?', '2', '̂', '̄', '꛳', '꛳', '˫', '˪', 'T', '3', 't', 'ȝ', 's', 'A', 'A', 'a', 'a', 'A', 'O', 'a', 'o', 'A', 'U', 'a', 'u', 'A', 'V', 'a', 'v', 'A', 'V', 'a', 'v', 'A', 'Y', 'a', 'y', 'K', '̵', 'O', '̵', 'o', '̵', 'O', 'O', 'o', 'o', '2', 'w', '̦', '3', 'ȝ', '9', 't', 'f', '&', 'Ꝺ', ':', '\'', '·', 'ꜧ', 'F', 'f', '𐐒', '𐐺', 'ʚ', 'ꓤ', 'u', '3', 'ꓕ', 'J', 'X', 'B', 'ß', 'Ꙍ', 'ω', 'ー', '।', 'ᄃ', 'ᄆ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄉ', 'ᄃ', 'ᄌ', 'ᄅ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄀ', 'ᄅ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄃ', 'ᄅ', 'ᄆ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄋ', 'ᄅ', 'ᄉ', 'ᄅ', 'ᄌ', 'ᄅ', 'ᄏ', 'ᄆ', 'ᄀ', 'ᄆ', 'ᄃ', 'ᄆ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄐ', 'ᄇ', 'ᄏ', 'ᄇ', 'ᄒ', 'ᄉ', 'ᄉ', 'ᄇ', 'ᄋ', 'ᄅ', 'ᄋ', 'ᄒ', 'ᄌ', 'ᄌ', 'ᄒ', 'ᄐ', 'ᄐ', 'ᄑ', 'ᄒ', 'ᄒ', 'ᄉ', 'ᅙ', 'ᅙ', 'ⰿ', 'ꦝ', '꧐', '٢', 'ꨁ', 'ꨣ', 'e', 'f', 'o', 'o', '̸', 'ɔ', '̸', 'ǝ', 'o', '̸', 'ǝ', 'o', '̵', 'r', 'r', 'ʃ', 'u', 'u', 'χ', 'χ', 'y', 'љ', 'ɔ', 'e', 'u', 'o', 'ᴅ', 'ʀ', 'ᴛ', 'o', '̛', 'i', 'ᴀ', 'ᴊ', 'ᴇ', 'ɂ', 'ⱶ', 'r', 'w', 'ʍ', 'ʜ', 'o', '̵', 'ɢ', 'z', 'ꞓ', 'u', '̵', 'ƅ', 'ʀ', 'v', 's', 'ʟ', 'c', 'ᴘ', 'ĸ', 'o', '̵', 'ᅩ', 'ᅧ', 'ᅩ', 'ᅩ', '丨', 'ᅭ', 'ᅡ', 'ᅭ', 'ᅡ', '丨', 'ᅭ', 'ᅥ', 'ᅮ', 'ᅧ', 'ᅮ', '丨', '丨', 'ᅲ', 'ᅡ', '丨', 'ᅲ', 'ᅩ', 'ー', 'ᅡ', 'ー', 'ᅥ', 'ー', 'ᅥ', '丨', 'ー', 'ᅩ', '丨', 'ᅣ', 'ᅩ', '丨', 'ᅣ', '丨', '丨', 'ᅧ', '丨', 'ᅧ', '丨', '丨', 'ᅩ', '丨', '丨', 'ᅭ', '丨', 'ᅲ', '丨', '丨', 'ᆞ', 'ᅡ', 'ᆞ', 'ᅥ', '丨', 'ᄂ', 'ᄅ', 'ᄂ', 'ᄎ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄀ', 'ᄃ', 'ᄌ', 'ᄃ', 'ᄎ', 'ᄃ', 'ᄐ', 'ᄅ', 'ᄀ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄒ', 'ᄅ', 'ᄅ', 'ᄏ', 'ᄅ', 'ᄆ', 'ᄒ', 'ᄅ', 'ᄇ', 'ᄃ', 'ᄅ', 'ᄇ', 'ᄑ', 'ᄅ', 'ᅌ', 'ᄅ', 'ᅙ', 'ᄒ', 'ᄅ', 'ᄋ', 'ᄆ', 'ᄂ', 'ᄆ', 'ᄂ', 'ᄂ', 'ᄆ', 'ᄆ', 'ᄆ', 'ᄇ', 'ᄉ', 'ᄆ', 'ᄌ', 'ᄇ', 'ᄃ', 'ᄇ', 'ᄅ', 'ᄑ', 'ᄇ', 'ᄆ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄃ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄎ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᅀ', 'ᄉ', 'ᄌ', 'ᄉ', 'ᄎ', 'ᄉ', 'ᄐ', 'ᄅ', 'ᄒ', 'ᅀ', 'ᄇ', 'ᅀ', 'ᄇ', 'ᄋ', 'ᅌ', 'ᄆ', 'ᅌ', 'ᄒ', 'ᄌ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄇ', 'ᄌ', 'ᄌ', 'ᄑ', 'ᄉ', 'ᄑ', 'ᄐ', '豈', '更', '車', '賈', '滑', '串', '句', '龜', '龜', '契', '金', '喇', '奈', '懶', '癩', '羅', '蘿', '螺', '裸', '邏', '樂', '洛', '烙', '珞', '落', '酪', '駱', '亂', '卵', '欄', '爛', '蘭', '鸞', '嵐', '濫', '藍', '襤', '拉', '臘', '蠟', '廊', '朗', '浪', '狼', '郎', '來', '冷', '勞', '擄', '櫓', '爐', '盧', '老', '蘆', '虜', '路', '露', '魯', '鷺', '碌', '祿', '綠', '菉', '錄', '鹿', '論', '壟', '弄', '籠', '聾', '牢', '磊', '賂', '雷', '壘', '屢', '樓', '淚', '漏', '累', '縷', '陋', '勒', '肋', '凜', '凌', '稜', '綾', '菱', '陵', '讀', '拏', '樂', '諾', '丹', '寧', '怒', '率', '異', '北', '磻', '便', '復', '不', '泌', '數', '索', '參', '塞', '省', '葉', '說', '殺', '辰', '沈', '拾', '若', '掠', '略', '亮', '兩', '凉', '梁', '糧', '良', '諒', '量', '勵', '呂', '女', '廬', '旅', '濾', '礪', '閭', '驪', '麗', '黎', '力', '曆', '歷', '轢', '年', '憐', '戀', '撚', '漣', '煉', '璉', '秊', '練', '聯', '輦', '蓮', '連', '鍊', '列', '劣', '咽', '烈', '裂', '說', '廉', '念', '捻', '殮', '簾', '獵', '令', '囹', '寧', '嶺', '怜', '玲', '瑩', '羚', '聆', '鈴', '零', '靈', '領', '例', '禮', '醴', '隷', '惡', '了', '僚', '寮', '尿', '料', '樂', '燎', '療', '蓼', '遼', '龍', '暈', '阮', '劉', '杻', '柳', '流', '溜', '琉', '留', '硫', '紐', '類', '六', '戮', '陸', '倫', '崙', '淪', '輪', '律', '慄', '栗', '率', '隆', '利', '吏', '履', '易', '李', '梨', '泥', '理', '痢', '罹', '裏', '裡', '里', '離', '匿', '溺', '吝', '燐', '璘', '藺', '隣', '鱗', '麟', '林', '淋', '臨', '立', '笠', '粒', '狀', '炙', '識', '什', '茶', '刺', '切', '度', '拓', '糖', '宅', '洞', '暴', '輻', '行', '降', '見', '廓', '兀', '嗀', '塚', '晴', '凞', '猪', '益', '礼', '神', '祥', '福', '靖', '精', '羽', '蘒', '諸', '逸', '都', '飯', '飼', '館', '鶴', '郎', '隷', '侮', '僧', '免', '勉', '勤', '卑', '喝', '嘆', '器', '塀', '墨', '層', '屮', '悔', '慨', '憎', '懲', '敏', '既', '暑', '梅', '海', '渚', '漢', '煮', '爫', '琢', '碑', '社', '祉', '祈', '祐', '祖', '祝', '禍', '禎', '穀', '突', '節', '練', '縉', '繁', '署', '者', '臭', '艹', '艹', '著', '褐', '視', '謁', '謹', '賓', '贈', '辶', '逸', '難', '響', '頻', '恵', '𤋮', '舘', '並', '况', '全', '侀', '充', '冀', '勇', '勺', '喝', '啕', '喙', '嗢', '塚', '墳', '奄', '奔', '婢', '嬨', '廒', '廙', '彩', '徭', '惘', '慎', '愈', '憎', '慠', '懲', '戴', '揄', '搜', '摒', '敖', '晴', '朗', '望', '杖', '歹', '殺', '流', '滛', '滋', '漢', '瀞', '煮', '瞧', '爵', '犯', '猪', '瑱', '甆', '画', '瘝', '瘟', '益', '盛', '直', '睊', '着', '磌', '窱', '節', '类', '絛', '練', '缾', '者', '荒', '華', '蝹', '襁', '覆', '視', '調', '諸', '請', '謁', '諾', '諭', '謹', '變', '贈', '輸', '遲', '醙', '鉶', '陼', '難', '靖', '韛', '響', '頋', '頻', '鬒', '龜', '𢡊', '𢡄', '𣏕', '㮝', '䀘', '䀹', '𥉉', '𥳐', '𧻓', '齃', '龎', 'f', 'f', 'f', 'i', 'f', 'l', 'f', 'f', 'i', 'f', 'f', 'l', 's', 't', 'մ', 'ն', 'մ', 'ե', 'մ', 'ի', 'վ', 'ն', 'մ', 'խ', 'ע', 'א', 'ד', 'ה', 'כ', 'ל', 'ם', 'ר', 'ת', '-', '̇', 'שׁ', 'שּׁ', 'אַ', 'אַ', 'יִ', 'שׁ', 'א', 'ל', 'ٱ', 'ٱ', 'ٻ', 'ٻ', 'ٻ', 'ٻ', 'ى', 'ۛ', 'ى', 'ۛ', 'ى', 'ۛ', 'ى', 'ۛ', 'ڀ', 'ڀ', 'ڀ', 'ڀ', 'ٺ', 'ٺ', 'ٺ', 'ٺ', 'ٿ', 'ٿ', 'ٿ', 'ٿ', 'ى', 'ؕ', 'ى', 'ؕ', 'ى', 'ؕ', 'ى', 'ؕ', 'ڡ', 'ۛ', 'ڡ', 'ۛ', 'ڡ', 'ۛ', 'ڡ', 'ۛ', 'ڦ', 'ڦ', 'ڦ', 'ڦ', 'ڄ', 'ڄ', 'ڄ', 'ڄ', 'ڃ', 'ڃ', 'ڃ', 'ڃ', 'چ', 'چ', 'چ', 'چ', 'ڇ', 'ڇ', 'ڇ', 'ڇ', 'ڍ', 'ڍ', 'ڌ', 'ڌ', 'د', 'ۛ', 'د', 'ۛ', 'د', 'ؕ', 'د', 'ؕ', 'ر', 'ۛ', 'ر', 'ۛ', 'ر', 'ؕ', 'ر', 'ؕ', 'ك', 'ك', 'ك', 'ك', 'گ', 'گ', 'گ', 'گ', 'ڳ', 'ڳ', 'ڳ', 'ڳ', 'ڱ', 'ڱ', 'ڱ', 'ڱ', 'ى', 'ى', 'ى', 'ؕ', 'ى', 'ؕ', 'ى', 'ؕ', 'ى', 'ؕ', 'ۀ', 'ۀ', 'o', 'o', 'o', 'o', 'o', 'o', 'o', 'o', 'ى', 'ى', 'ۓ', 'ۓ', 'ك', 'ۛ', 'ك', 'ۛ', 'ك', 'ۛ', 'ك', 'ۛ', 'و', '̓', 'و', '̓', 'و', '̆', 'و', '̆', 'و', 'ٰ', 'و', 'ٰ', 'و', '̓', 'ٴ', 'و', 'ۛ', 'و', 'ۛ', 'ۅ', 'ۅ', 'و', '̂', 'و', '̂', 'ٻ', 'ٻ', 'ٻ', 'ٻ', 'ى', 'ى', 'ى', 'ٴ', 'l', 'ى', 'ٴ', 'l', 'ى', 'ٴ', 'o', 'ى', 'ٴ', 'o', 'ى', 'ٴ', 'و', 'ى', 'ٴ', 'و', 'ى', 'ٴ', 'و', '̓', 'ى', 'ٴ', 'و', '̓', 'ى', 'ٴ', 'و', '̆', 'ى', 'ٴ', 'و', '̆', 'ى', 'ٴ', 'و', 'ٰ', 'ى', 'ٴ', 'و', 'ٰ', 'ى', 'ٴ', 'ٻ', 'ى', 'ٴ', 'ٻ', 'ى', 'ٴ', 'ٻ', 'ى', 'ٴ', 'ى', 'ى', 'ٴ', 'ى', 'ى', 'ٴ', 'ى', 'ى', 'ى', 'ى', 'ى', 'ى', 'ٴ', 'ج', 'ى', 'ٴ', 'ح', 'ى', 'ٴ', 'م', 'ى', 'ٴ', 'ى', 'ى', 'ٴ', 'ى', 'ب', 'ج', 'ب', 'ح', 'ب', 'خ', 'ب', 'م', 'ب', 'ى', 'ب', 'ى', 'ت', 'ج', 'ت', 'ح', 'ت', 'خ', 'ت', 'م', 'ت', 'ى', 'ت', 'ى', 'ى', 'ۛ', 'ج', 'ى', 'ۛ', 'م', 'ى', 'ۛ', 'ى', 'ى', 'ۛ', 'ى', 'ج', 'ح', 'ج', 'م', 'ح', 'ج', 'ح', 'م', 'خ', 'ج', 'خ', 'ح', 'خ', 'م', 'س', 'ج', 'س', 'ح', 'س', 'خ', 'س', 'م', 'ص', 'ح', 'ص', 'م', 'ض', 'ج', 'ض', 'ح', 'ض', 'خ', 'ض', 'م', 'ط', 'ح', 'ط', 'م', 'ظ', 'م', 'ع', 'ج', 'ع', 'م', 'غ', 'ج', 'غ', 'م', 'ف', 'ج', 'ف', 'ح', 'ف', 'خ', 'ف', 'م', 'ف', 'ى', 'ف', 'ى', 'ق', 'ح', 'ق', 'م', 'ق', 'ى', 'ق', 'ى', 'ك', 'l', 'ك', 'ج', 'ك', 'ح', 'ك', 'خ', 'ك', 'ل', 'ك', 'م', 'ك', 'ى', 'ك', 'ى', 'ل', 'ج', 'ل', 'ح', 'ل', 'خ', 'ل', 'م', 'ل', 'ى', 'ل', 'ى', 'م', 'ج', 'م', 'ح', 'م', 'خ', 'م', 'م', 'م', 'ى', 'م', 'ى', 'ب', 'خ', 'ن', 'ح', 'ن', 'خ', 'ن', 'م', 'ن', 'ى', 'ن', 'ى', 'o', 'ج', 'o', 'م', 'o', 'ى', 'o', 'ى', 'ى', 'ج', 'ى', 'ح', 'ى', 'خ', 'ى', 'م', 'ى', 'ى', 'ى', 'ى', 'ذ', 'ٰ', 'ر', 'ٰ', 'ى', 'ٰ', 'ﹲ', 'ّ', 'ﹴ', 'ّ', 'ﹶ', 'ّ', 'ﹸ', 'ّ', 'ﹺ', 'ّ', 'ﹼ', 'ٰ', 'ى', 'ٴ', 'ر', 'ى', 'ٴ', 'ز', 'ى', 'ٴ', 'م', 'ى', 'ٴ', 'ن', 'ى', 'ٴ', 'ى', 'ى', 'ٴ', 'ى', 'ب', 'ر', 'ب', 'ز', 'ب', 'م', 'ب', 'ن', 'ب', 'ى', 'ب', 'ى', 'ت', 'ر', 'ت', 'ز', 'ت', 'م', 'ت', 'ن', 'ت', 'ى', 'ت', 'ى', 'ى', 'ۛ', 'ر', 'ى', 'ۛ', 'ز', 'ى', 'ۛ', 'م', 'ى', 'ۛ', 'ن', 'ى', 'ۛ', 'ى', 'ى', 'ۛ', 'ى', 'ف', 'ى', 'ف', 'ى', 'ق', 'ى', 'ق', 'ى', 'ك', 'l', 'ك', 'ل', 'ك', 'م', 'ك', 'ى', 'ك', 'ى', 'ل', 'م', 'ل', 'ى', 'ل', 'ى', 'م', 'l', 'م', 'م', 'ن', 'ر', 'ن', 'ز', 'ن', 'م', 'ن', 'ن', 'ن', 'ى', 'ن', 'ى', 'ى', 'ٰ', 'ى', 'ر', 'ى', 'ز', 'ى', 'م', 'ى', 'ن', 'ى', 'ى', 'ى', 'ى', 'ى', 'ٴ', 'ج', 'ى', 'ٴ', 'ح', 'ى', 'ٴ', 'خ', 'ى', 'ٴ', 'م', 'ى', 'ٴ', 'o', 'ب', 'ج', 'ب', 'ح', 'ب', 'خ', 'ب', 'م', 'ب', 'o', 'ت', 'ج', 'ت', 'ح', 'ت', 'خ', 'ت', 'م', 'ت', 'o', 'ى', 'ۛ', 'م', 'ج', 'ح', 'ج', 'م', 'ح', 'ج', 'ح', 'م', 'خ', 'ج', 'خ', 'م', 'س', 'ج', 'س', 'ح', 'س', 'خ', 'س', 'م', 'ص', 'ح', 'ص', 'خ', 'ص', 'م', 'ض', 'ج', 'ض', 'ح', 'ض', 'خ', 'ض', 'م', 'ط', 'ح', 'ظ', 'م', 'ع', 'ج', 'ع', 'م', 'غ', 'ج', 'غ', 'م', 'ف', 'ج', 'ف', 'ح', 'ف', 'خ', 'ف', 'م', 'ق', 'ح', 'ق', 'م', 'ك', 'ج', 'ك', 'ح', 'ك', 'خ', 'ك', 'ل', 'ك', 'م', 'ل', 'ج', 'ل', 'ح', 'ل', 'خ', 'ل', 'م', 'ل', 'o', 'م', 'ج', 'م', 'ح', 'م', 'خ', 'م', 'م', 'ب', 'خ', 'ن', 'ح', 'ن', 'خ', 'ن', 'م', 'ن', 'o', 'o', 'ج', 'o', 'م', 'o', 'ٰ', 'ى', 'ج', 'ى', 'ح', 'ى', 'خ', 'ى', 'م', 'ى', 'o', 'ى', 'ٴ', 'م', 'ى', 'ٴ', 'o', 'ب', 'م', 'ب', 'o', 'ت', 'م', 'ت', 'o', 'ى', 'ۛ', 'م', 'ى', 'ۛ', 'o', 'س', 'م', 'س', 'o', 'س', 'ۛ', 'م', 'س', 'ۛ', 'o', 'ك', 'ل', 'ك', 'م', 'ل', 'م', 'ن', 'م', 'ن', 'o', 'ى', 'م', 'ى', 'o', 'ﹷ', 'ّ', 'ﹹ', 'ّ', 'ﹻ', 'ّ', 'ط', 'ى', 'ط', 'ى', 'ع', 'ى', 'ع', 'ى', 'غ', 'ى', 'غ', 'ى', 'س', 'ى', 'س', 'ى', 'س', 'ۛ', 'ى', 'س', 'ۛ', 'ى', 'ح', 'ى', 'ح', 'ى', 'ج', 'ى', 'ج', 'ى', 'خ', 'ى', 'خ', 'ى', 'ص', 'ى', 'ص', 'ى', 'ض', 'ى', 'ض', 'ى', 'س', 'ۛ', 'ج', 'س', 'ۛ', 'ح', 'س', 'ۛ', 'خ', 'س', 'ۛ', 'م', 'س', 'ۛ', 'ر', 'س', 'ر', 'ص', 'ر', 'ض', 'ر', 'ط', 'ى', 'ط', 'ى', 'ع', 'ى', 'ع', 'ى', 'غ', 'ى', 'غ', 'ى', 'س', 'ى', 'س', 'ى', 'س', 'ۛ', 'ى', 'س', 'ۛ', 'ى', 'ح', 'ى', 'ح', 'ى', 'ج', 'ى', 'ج', 'ى', 'خ', 'ى', 'خ', 'ى', 'ص', 'ى', 'ص', 'ى', 'ض', 'ى', 'ض', 'ى', 'س', 'ۛ', 'ج', 'س', 'ۛ', 'ح', 'س', 'ۛ', 'خ', 'س', 'ۛ', 'م', 'س', 'ۛ', 'ر', 'س', 'ر', 'ص', 'ر', 'ض', 'ر', 'س', 'ۛ', 'ج', 'س', 'ۛ', 'ح', 'س', 'ۛ', 'خ', 'س', 'ۛ', 'م', 'س', 'o', 'س', 'ۛ', 'o', 'ط', 'م', 'س', 'ج', 'س', 'ح', 'س', 'خ', 'س', 'ۛ', 'ج', 'س', 'ۛ', 'ح', 'س', 'ۛ', 'خ', 'ط', 'م', 'ظ', 'م', 'l', '̋', 'l', '̋', '(', ')', 'ت', 'ج', 'م', 'ت', 'ح', 'ج', 'ت', 'ح', 'ج', 'ت', 'ح', 'م', 'ت', 'خ', 'م', 'ت', 'م', 'ج', 'ت', 'م', 'ح', 'ت', 'م', 'خ', 'ج', 'م', 'ح', 'ج', 'م', 'ح', 'ح', 'م', 'ى', 'ح', 'م', 'ى', 'س', 'ح', 'ج', 'س', 'ج', 'ح', 'س', 'ج', 'ى', 'س', 'م', 'ح', 'س', 'م', 'ح', 'س', 'م', 'ج', 'س', 'م', 'م', 'س', 'م', 'م', 'ص', 'ح', 'ح', 'ص', 'ح', 'ح', 'ص', 'م', 'م', 'س', 'ۛ', 'ح', 'م', 'س', 'ۛ', 'ح', 'م', 'س', 'ۛ', 'ج', 'ى', 'س', 'ۛ', 'م', 'خ', 'س', 'ۛ', 'م', 'خ', 'س', 'ۛ', 'م', 'م', 'س', 'ۛ', 'م', 'م', 'ض', 'ح', 'ى', 'ض', 'خ', 'م', 'ض', 'خ', 'م', 'ط', 'م', 'ح', 'ط', 'م', 'ح', 'ط', 'م', 'م', 'ط', 'م', 'ى', 'ع', 'ج', 'م', 'ع', 'م', 'م', 'ع', 'م', 'م', 'ع', 'م', 'ى', 'غ', 'م', 'م', 'غ', 'م', 'ى', 'غ', 'م', 'ى', 'ف', 'خ', 'م', 'ف', 'خ', 'م', 'ق', 'م', 'ح', 'ق', 'م', 'م', 'ل', 'ح', 'م', 'ل', 'ح', 'ى', 'ل', 'ح', 'ى', 'ل', 'ج', 'ج', 'ل', 'ج', 'ج', 'ل', 'خ', 'م', 'ل', 'خ', 'م', 'ل', 'م', 'ح', 'ل', 'م', 'ح', 'م', 'ح', 'ج', 'م', 'ح', 'م', 'م', 'ح', 'ى', 'م', 'ج', 'ح', 'م', 'ج', 'م', 'م', 'خ', 'ج', 'م', 'خ', 'م', 'م', 'ج', 'خ', 'o', 'م', 'ج', 'o', 'م', 'م', 'ن', 'ح', 'م', 'ن', 'ح', 'ى', 'ن', 'ج', 'م', 'ن', 'ج', 'م', 'ن', 'ج', 'ى', 'ن', 'م', 'ى', 'ن', 'م', 'ى', 'ى', 'م', 'م', 'ى', 'م', 'م', 'ب', 'خ', 'ى', 'ت', 'ج', 'ى', 'ت', 'ج', 'ى', 'ت', 'خ', 'ى', 'ت', 'خ', 'ى', 'ت', 'م', 'ى', 'ت', 'م', 'ى', 'ج', 'م', 'ى', 'ج', 'ح', 'ى', 'ج', 'م', 'ى', 'س', 'خ', 'ى', 'ص', 'ح', 'ى', 'س', 'ۛ', 'ح', 'ى', 'ض', 'ح', 'ى', 'ل', 'ج', 'ى', 'ل', 'م', 'ى', 'ى', 'ح', 'ى', 'ى', 'ج', 'ى', 'ى', 'م', 'ى', 'م', 'م', 'ى', 'ق', 'م', 'ى', 'ن', 'ح', 'ى', 'ق', 'م', 'ح', 'ل', 'ح', 'م', 'ع', 'م', 'ى', 'ك', 'م', 'ى', 'ن', 'ج', 'ح', 'م', 'خ', 'ى', 'ل', 'ج', 'م', 'ك', 'م', 'م', 'ل', 'ج', 'م', 'ن', 'ج', 'ح', 'ج', 'ح', 'ى', 'ح', 'ج', 'ى', 'م', 'ج', 'ى', 'ف', 'م', 'ى', 'ب', 'ح', 'ى', 'ك', 'م', 'م', 'ع', 'ج', 'م', 'ص', 'م', 'م', 'س', 'خ', 'ى', 'ن', 'ج', 'ى', 'ص', 'ل', 'ى', 'ق', 'ل', 'ى', 'l', 'ل', 'ل', 'ّ', 'ٰ', 'o', 'l', 'ك', 'ب', 'ر', 'م', 'ح', 'م', 'د', 'ص', 'ل', 'ع', 'م', 'ر', 'س', 'و', 'ل', 'ع', 'ل', 'ى', 'o', 'و', 'س', 'ل', 'م', 'ص', 'ل', 'ى', 'ص', 'ل', 'ى', ' ', 'l', 'ل', 'ل', 'o', ' ', 'ع', 'ل', 'ى', 'o', ' ', 'و', 'س', 'ل', 'م', 'ج', 'ل', ' ', 'ج', 'ل', 'l', 'ل', 'o', 'ر', 'ى', 'l', 'ل', 'ⵗ', ':', '│', '⌇', '⏜', '⏝', '⏞', '⏟', '⏠', '⏡', 'ˉ', 'ˉ', 'ˉ', 'ˉ', '_', '_', '_', '-', '\\', 'ء', 'آ', 'آ', 'l', 'ٴ', 'l', 'ٴ', 'و', 'ٴ', 'و', 'ٴ', 'l', 'ٕ', 'l', 'ٕ', 'ى', 'ٴ', 'ى', 'ٴ', 'ى', 'ٴ', 'ى', 'ٴ', 'l', 'l', 'ب', 'ب', 'ب', 'ب', 'ة', 'ة', 'ت', 'ت', 'ت', 'ت', 'ى', 'ۛ', 'ى', 'ۛ', 'ى', 'ۛ', 'ى', 'ۛ', 'ج', 'ج', 'ج', 'ج', 'ح', 'ح', 'ح', 'ح', 'خ', 'خ', 'خ', 'خ', 'د', 'د', 'ذ', 'ذ', 'ر', 'ر', 'ز', 'ز', 'س', 'س', 'س', 'س', 'س', 'ۛ', 'س', 'ۛ', 'س', 'ۛ', 'س', 'ۛ', 'ص', 'ص', 'ص', 'ص', 'ض', 'ض', 'ض', 'ض', 'ط', 'ط', 'ط', 'ط', 'ظ', 'ظ', 'ظ', 'ظ', 'ع', 'ع', 'ع', 'ع', 'غ', 'غ', 'غ', 'غ', 'ف', 'ف', 'ف', 'ف', 'ق', 'ق', 'ق', 'ق', 'ك', 'ك', 'ك', 'ك', 'ل', 'ل', 'ل', 'ل', 'م', 'م', 'م', 'م', 'ن', 'ن', 'ن', 'ن', 'o', 'o', 'o', 'o', 'و', 'و', 'ى', 'ى', 'ى', 'ى', 'ى', 'ى', 'ل', 'آ', 'ل', 'آ', 'ل', 'l', 'ٴ', 'ل', 'l', 'ٴ', 'ل', 'l', 'ٕ', 'ل', 'l', 'ٕ', 'ل', 'l', 'ل', 'l', '!', '\'', '\'', '\'', 'ー', ':', 'A', 'B', 'C', 'E', 'H', 'l', 'J', 'K', 'M', 'N', 'O', 'P', 'S', 'T', 'X', 'Y', 'Z', '(', '\\', ')', '︿', '\'', 'a', 'c', 'e', 'g', 'h', 'i', 'j', 'l', 'o', 'p', 's', 'v', 'x', 'y', '│', '〜', '·', 'ˉ', 'l', '▪', '·', 'N', '̊', 'X', '̵', 'V', '̵', 'l', '̵', 'l', '̵', 'S', '̵', 'l', '̵', 'l', '̵', '⳨', 'B', 'Δ', 'E', 'F', 'l', 'Ʌ', 'X', 'O', 'ᛜ', 'P', 'S', 'T', '+', 'A', 'B', 'C', 'Δ', 'F', 'O', 'Ϙ', 'M', 'T', 'Y', 'Φ', 'X', 'Ψ', 'Ω', 'ⵀ', 'H', 'د', 'و', 'ط', 'ص', 'Z', 'B', 'C', 'l', 'M', 'Ϙ', 'T', 'X', '8', '*', 'l', 'X', '𐎂', '𐎓', 'Ɛ', 'O', 'ꓶ', 'C', 'L', 'Ɒ', 'S', 'Ɔ', 'И', 'ꞓ', 'ʚ', 'o', 'c', 'ɷ', 'ɞ', 'ʟ', 's', 'ɔ', 'ᴎ', '𐒆', 'Ʌ', 'R', 'Ӄ', 'O', 'ʘ', 'Þ', 'Ћ', 'U', 'ᛦ', 'Ψ', '7', 'ʌ', 'λ', 'o', 'ꙩ', 'u', 'ψ', 'N', 'O', 'K', 'C', 'V', 'F', 'L', 'X', '̣', '.', '𐩖', '𐩖', '𐲥', '𐲂', '॰', '॰', '̣', 'ऺ', '꣼', 'ꣻ', '≈', '̊', '𑐴', '𑑂', '𑐒', '𑐴', '𑑂', '𑐘', '𑐴', '𑑂', '𑐣', '𑐴', '𑑂', '𑐩', '𑐴', '𑑂', '𑐬', '𑐴', '𑑂', '𑐮', '𑑋', '𑑋', 'ঘ', 'চ', 'জ', 'ঞ', 'ট', 'ড', 'ল', 'ত', 'থ', 'দ', 'ধ', 'ন', 'প', 'ম', 'য', 'ব', 'ণ', 'র', 'ষ', 'স', 'া', 'ি', 'ে', 'ো', 'ৗ', 'ৌ', '̆', '̇', 'ঃ', '্', '̣', 'ঽ', 'w', '̇', 'O', '১', '২', '৬', '𑖂', '𑖂', '𑖃', '𑖄', '𑖲', '𑖳', '𑙁', '𑙁', 'r', 'n', 'v', 'w', 'w', 'w', 'V', 'F', 'L', 'Y', 'E', '∇', 'Z', '9', 'E', '4', 'L', 'O', 'ᛜ', 'U', '5', 'T', 'v', 's', 'F', 'i', 'z', '7', 'o', '3', '9', 'ꞓ', '6', '9', 'o', 'u', 'y', 'O', 'r', 'n', '٩', 'Z', 'W', 'C', 'X', 'W', 'C', '𑫥', '𑫯', '𑫥', '𑫰', '𑫥', '𑫥', '𑫥', '𑫥', '𑫯', '𑫥', '𑫥', '𑫰', '𑫫', '𑫯', '𑫫', '𑫫', '𑫫', '𑫫', '𑫯', '𑫳', '𑫯', '𑫳', '𑫰', '𑫳', '𑫳', '𑫳', '𑫳', '𑫯', '𑫳', '𑫳', '𑫰', '𑱁', '𑱁', '𑲪', '𐎚', '𐦞', 'Γ', 'V', 'T', 'L', 'Δ', 'Ꙙ', 'ꓶ', 'l', 'Ɛ', 'R', 'S', '3', 'Ʌ', '>', 'A', 'U', 'Y', '\'', '\'', '{', '.', 'Ӿ', '3', 'И', 'V', '\\', '7', 'F', '𐊼', 'ꓶ', 'R', 'Ɐ', 'O', '̵', '⅄', 'ꓕ', 'Ɛ', 'Ѡ', 'L', 'ꓶ', 'ꟻ', '<', '>', '⊏', '⊐', '/', '\\', 'ᛋ', 'Ո', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'C', 'D', 'G', 'J', 'K', 'N', 'O', 'P', 'Q', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'f', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'D', 'E', 'F', 'G', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'D', 'E', 'F', 'G', 'l', 'J', 'K', 'L', 'M', 'O', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'l', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'r', 'n', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'i', 'ȷ', 'A', 'B', 'Γ', 'Δ', 'E', 'Z', 'H', 'O', '̵', 'l', 'K', 'Ʌ', 'M', 'N', 'Ξ', 'O', 'Π', 'P', 'O', '̵', 'Ʃ', 'T', 'Y', 'Φ', 'X', 'Ψ', 'Ω', '∇', 'a', 'ß', 'y', 'ẟ', 'ꞓ', 'ζ', 'n', '̩', 'O', '̵', 'i', 'ĸ', 'λ', 'μ', 'v', 'ξ', 'o', 'π', 'p', 'ς', 'o', 'ᴛ', 'u', 'ɸ', 'χ', 'ψ', 'ω', '∂', 'ꞓ', 'O', '̵', 'ĸ', 'ɸ', 'p', 'π', 'A', 'B', 'Γ', 'Δ', 'E', 'Z', 'H', 'O', '̵', 'l', 'K', 'Ʌ', 'M', 'N', 'Ξ', 'O', 'Π', 'P', 'O', '̵', 'Ʃ', 'T', 'Y', 'Φ', 'X', 'Ψ', 'Ω', '∇', 'a', 'ß', 'y', 'ẟ', 'ꞓ', 'ζ', 'n', '̩', 'O', '̵', 'i', 'ĸ', 'λ', 'μ', 'v', 'ξ', 'o', 'π', 'p', 'ς', 'o', 'ᴛ', 'u', 'ɸ', 'χ', 'ψ', 'ω', '∂', 'ꞓ', 'O', '̵', 'ĸ', 'ɸ', 'p', 'π', 'A', 'B', 'Γ', 'Δ', 'E', 'Z', 'H', 'O', '̵', 'l', 'K', 'Ʌ', 'M', 'N', 'Ξ', 'O', 'Π', 'P', 'O', '̵', 'Ʃ', 'T', 'Y', 'Φ', 'X', 'Ψ', 'Ω', '∇', 'a', 'ß', 'y', 'ẟ', 'ꞓ', 'ζ', 'n', '̩', 'O', '̵', 'i', 'ĸ', 'λ', 'μ', 'v', 'ξ', 'o', 'π', 'p', 'ς', 'o', 'ᴛ', 'u', 'ɸ', 'χ', 'ψ', 'ω', '∂', 'ꞓ', 'O', '̵', 'ĸ', 'ɸ', 'p', 'π', 'A', 'B', 'Γ', 'Δ', 'E', 'Z', 'H', 'O', '̵', 'l', 'K', 'Ʌ', 'M', 'N', 'Ξ', 'O', 'Π', 'P', 'O', '̵', 'Ʃ', 'T', 'Y', 'Φ', 'X', 'Ψ', 'Ω', '∇', 'a', 'ß', 'y', 'ẟ', 'ꞓ', 'ζ', 'n', '̩', 'O', '̵', 'i', 'ĸ', 'λ', 'μ', 'v', 'ξ', 'o', 'π', 'p', 'ς', 'o', 'ᴛ', 'u', 'ɸ', 'χ', 'ψ', 'ω', '∂', 'ꞓ', 'O', '̵', 'ĸ', 'ɸ', 'p', 'π', 'A', 'B', 'Γ', 'Δ', 'E', 'Z', 'H', 'O', '̵', 'l', 'K', 'Ʌ', 'M', 'N', 'Ξ', 'O', 'Π', 'P', 'O', '̵', 'Ʃ', 'T', 'Y', 'Φ', 'X', 'Ψ', 'Ω', '∇', 'a', 'ß', 'y', 'ẟ', 'ꞓ', 'ζ', 'n', '̩', 'O', '̵', 'i', 'ĸ', 'λ', 'μ', 'v', 'ξ', 'o', 'π', 'p', 'ς', 'o', 'ᴛ', 'u', 'ɸ', 'χ', 'ψ', 'ω', '∂', 'ꞓ', 'O', '̵', 'ĸ', 'ɸ', 'p', 'π', 'F', 'ϝ', 'O', 'l', '2', '3', '4', '5', '6', '7', '8', '9', 'O', 'l', '2', '3', '4', '5', '6', '7', '8', '9', 'O', 'l', '2', '3', '4', '5', '6', '7', '8', '9', 'O', 'l', '2', '3', '4', '5', '6', '7', '8', '9', 'O', 'l', '2', '3', '4', '5', '6', '7', '8', '9', 'l', '∠', '٣', '8', '∂', '∂', '̵', 'l', 'ب', 'ج', 'د', 'و', 'ز', 'ح', 'ط', 'ى', 'ك', 'ل', 'م', 'ن', 'س', 'ع', 'ف', 'ص', 'ق', 'ر', 'س', 'ۛ', 'ت', 'ى', 'ۛ', 'خ', 'ذ', 'ض', 'ظ', 'غ', 'ى', 'ى', 'ڡ', 'ڡ', 'ب', 'ج', 'o', 'ح', 'ى', 'ك', 'ل', 'م', 'ن', 'س', 'ع', 'ف', 'ص', 'ق', 'س', 'ۛ', 'ت', 'ى', 'ۛ', 'خ', 'ض', 'غ', 'ج', 'ح', 'ى', 'ل', 'ن', 'س', 'ع', 'ص', 'ق', 'س', 'ۛ', 'خ', 'ض', 'غ', 'ى', 'ڡ', 'ب', 'ج', 'o', 'ح', 'ط', 'ى', 'ك', 'م', 'ن', 'س', 'ع', 'ف', 'ص', 'ق', 'س', 'ۛ', 'ت', 'ى', 'ۛ', 'خ', 'ض', 'ظ', 'غ', 'ى', 'ڡ', 'l', 'ب', 'ج', 'د', 'o', 'و', 'ز', 'ح', 'ط', 'ى', 'ل', 'م', 'ن', 'س', 'ع', 'ف', 'ص', 'ق', 'ر', 'س', 'ۛ', 'ت', 'ى', 'ۛ', 'خ', 'ذ', 'ض', 'ظ', 'غ', 'ب', 'ج', 'د', 'و', 'ز', 'ح', 'ط', 'ى', 'ل', 'م', 'ن', 'س', 'ع', 'ف', 'ص', 'ق', 'ر', 'س', 'ۛ', 'ت', 'ى', 'ۛ', 'خ', 'ذ', 'ض', 'ظ', 'غ', 'O', '.', 'O', ',', 'l', ',', '2', ',', '3', ',', '4', ',', '5', ',', '6', ',', '7', ',', '8', ',', '9', ',', '(', 'A', ')', '(', 'B', ')', '(', 'C', ')', '(', 'D', ')', '(', 'E', ')', '(', 'F', ')', '(', 'G', ')', '(', 'H', ')', '(', 'l', ')', '(', 'J', ')', '(', 'K', ')', '(', 'L', ')', '(', 'M', ')', '(', 'N', ')', '(', 'O', ')', '(', 'P', ')', '(', 'Q', ')', '(', 'R', ')', '(', 'S', ')', '(', 'T', ')', '(', 'U', ')', '(', 'V', ')', '(', 'W', ')', '(', 'X', ')', '(', 'Y', ')', '(', 'Z', ')', '(', 'S', ')', '(', '本', ')', '(', '三', ')', '(', '二', ')', '(', '安', ')', '(', '点', ')', '(', '打', ')', '(', '盗', ')', '(', '勝', ')', '(', '敗', ')', '☽', '☾', '☽', 'Q', 'E', 'Ꙙ', 'Δ', '𐊼', 'A', 'R', 'V', 'ᷤ', '☩', 'O', '̵', '𐊨', '⧟', 'C', 'ᛜ', '⊡', 's', 's', 's', '≏', 'T', 'M', 'B', 'V', 'B', '⊠', '❬', '丽', '丸', '乁', '𠄢', '你', '侮', '侻', '併', '偺', '備', '僧', '像', '㒞', '𠘺', '免', '兔', '兤', '具', '𠔜', '㒹', '內', '再', '𠕋', '冗', '冤', '仌', '冬', '况', '𩇟', '凵', '刃', '㓟', '刻', '剆', '割', '剷', '㔕', '勇', '勉', '勤', '勺', '包', '匆', '北', '卉', '卑', '博', '即', '卽', '卿', '卿', '卿', '𠨬', '灰', '及', '叟', '𠭣', '叫', '叱', '吆', '咞', '吸', '呈', '周', '咢', '哶', '唐', '啓', '啣', '善', '善', '喙', '喫', '喳', '嗂', '圖', '嘆', '圗', '噑', '噴', '切', '壮', '城', '埴', '堍', '型', '堲', '報', '墬', '𡓤', '売', '壷', '夆', '多', '夢', '奢', '𡚨', '𡛪', '姬', '娛', '娧', '姘', '婦', '㛮', '㛼', '嬈', '嬾', '嬾', '𡧈', '寃', '寘', '寧', '寳', '𡬘', '寿', '将', '当', '尢', '㞁', '屠', '屮', '峀', '岍', '𡷤', '嵃', '𡷦', '嵮', '嵫', '嵼', '巡', '巢', '㠯', '巽', '帨', '帽', '幩', '㡢', '𢆃', '㡼', '庰', '庳', '庶', '廊', '𪎒', '廾', '𢌱', '𢌱', '舁', '弢', '弢', '㣇', '𣊸', '𦇚', '形', '彫', '㣣', '徚', '忍', '志', '忹', '悁', '㤺', '㤜', '悔', '𢛔', '惇', '慈', '慌', '慎', '慌', '慺', '憎', '憲', '憤', '憯', '懞', '懲', '懶', '成', '戛', '扝', '抱', '拔', '捐', '𢬌', '挽', '拼', '捨', '掃', '揤', '𢯱', '搢', '揅', '掩', '㨮', '摩', '摾', '撝', '摷', '㩬', '敏', '敬', '𣀊', '旣', '書', '晉', '㬙', '暑', '㬈', '㫤', '冒', '冕', '最', '暜', '肭', '䏙', '朗', '望', '朡', '杞', '杓', '𣏃', '㭉', '柺', '枅', '桒', '梅', '𣑭', '梎', '栟', '椔', '㮝', '楂', '榣', '槪', '檨', '𣚣', '櫛', '㰘', '次', '𣢧', '歔', '㱎', '歲', '殟', '殺', '殻', '𣪍', '𡴋', '𣫺', '汎', '𣲼', '沿', '泍', '汧', '洖', '派', '海', '流', '浩', '浸', '涅', '𣴞', '洴', '港', '湮', '㴳', '滋', '滇', '𣻑', '淹', '潮', '𣽞', '𣾎', '濆', '瀹', '瀞', '瀛', '㶖', '灊', '災', '灷', '炭', '𠔥', '煅', '𤉣', '熜', '𤎫', '爨', '爵', '牐', '𤘈', '犀', '犕', '𤜵', '𤠔', '獺', '王', '㺬', '玥', '㺸', '㺸', '瑇', '瑜', '瑱', '璅', '瓊', '㼛', '甤', '𤰶', '甾', '𤲒', '異', '𢆟', '瘐', '𤾡', '𤾸', '𥁄', '㿼', '䀈', '直', '𥃳', '𥃲', '𥄙', '𥄳', '眞', '真', '真', '睊', '䀹', '瞋', '䁆', '䂖', '𥐝', '硎', '碌', '磌', '䃣', '𥘦', '祖', '𥚚', '𥛅', '福', '秫', '䄯', '穀', '穊', '穏', '𥥼', '𥪧', '𥪧', '竮', '䈂', '𥮫', '篆', '築', '䈧', '𥲀', '糒', '䊠', '糨', '糣', '紀', '𥾆', '絣', '䌁', '緇', '縂', '繅', '䌴', '𦈨', '𦉇', '䍙', '𦋙', '罺', '𦌾', '羕', '翺', '者', '𦓚', '𦔣', '聠', '𦖨', '聰', '𣍟', '䏕', '育', '脃', '䐋', '脾', '媵', '𦞧', '𦞵', '𣎓', '𣎜', '舁', '舄', '辞', '䑫', '芑', '芋', '芝', '劳', '花', '芳', '芽', '苦', '𦬼', '若', '茝', '荣', '莭', '茣', '莽', '菧', '著', '荓', '菊', '菌', '菜', '𦰶', '𦵫', '𦳕', '䔫', '蓱', '蓳', '蔖', '𧏊', '蕤', '𦼬', '䕝', '䕡', '𦾱', '𧃒', '䕫', '虐', '虜', '虧', '虩', '蚩', '蚈', '蜎', '蛢', '蝹', '蜨', '蝫', '螆', '䗗', '蟡', '蠁', '䗹', '衠', '衣', '𧙧', '裗', '裞', '䘵', '裺', '㒻', '𧢮', '𧥦', '䚾', '䛇', '誠', '諭', '變', '豕', '𧲨', '貫', '賁', '贛', '起', '𧼯', '𠠄', '跋', '趼', '跰', '𠣞', '軔', '輸', '𨗒', '𨗭', '邔', '郱', '鄑', '𨜮', '鄛', '鈸', '鋗', '鋘', '鉼', '鏹', '鐕', '𨯺', '開', '䦕', '閷', '𨵷', '䧦', '雃', '嶲', '霣', '𩅅', '𩈚', '䩮', '䩶', '韠', '𩐊', '䪲', '𩒖', '頋', '頋', '頩', '𩖶', '飢', '䬳', '餩', '馧', '駂', '駾', '䯎', '𩬰', '鬒', '鱀', '鳽', '䳎', '䳭', '鵧', '𪃎', '䳸', '𪄅', '𪈎', '𪊑', '麻', '䵖', '黹', '黾', '鼅', '鼏', '鼖', '鼻', '𪘀'];
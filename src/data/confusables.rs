@@ -0,0 +1,9 @@
+// Generated by `cargo xtask` from data/confusables.txt
+
+pub const VERSION: (u8, u8, u8) = (16, 0, 0);
+pub static ENTRIES: [u32; 6355] = [1066272680, 187367808, 1975550490, 74073524, 1045449560, 1966932438, 3188164746, 26903249, 123994866, 210174060, 1055539740, 1954759030, 1971323454, 2050164601, 3192359469, 15188308, 52921685, 87212710, 148259656, 199150295, 701497670, 1049840272, 1060275290, 1091782498, 1964654885, 1969226097, 1973420386, 1977647644, 2074821753, 3190262104, 3194456851, 9453848, 19595629, 37733178, 71648705, 83460419, 94291759, 139166134, 157665202, 196840481, 206374334, 432637981, 904808411, 1047546477, 1051970717, 1058178167, 1062634444, 1069352083, 1178141896, 1963590172, 1965834614, 1968062757, 1970274755, 1972371761, 1974501775, 1976599121, 1978696536, 2073445395, 2089943181, 3189213430, 3191310337, 3193408710, 3195505535, 6701527, 11796737, 17367884, 23216385, 29213046, 45761785, 59852170, 72844750, 75122211, 85475985, 89065223, 104630070, 135234323, 141543230, 153174086, 176161545, 195217385, 198101328, 200199155, 207422994, 215564770, 691700094, 710837845, 1044400564, 1046497533, 1048594745, 1050921360, 1054491800, 1057129383, 1059226756, 1061323772, 1063716001, 1068303336, 1084310342, 1160430865, 1933033792, 1963065816, 1964114289, 1965179336, 1966407985, 1967522161, 1968701980, 1969750299, 1970798923, 1971847557, 1972896214, 1973944917, 1975025947, 1976074555, 1977123150, 1978172130, 1979252924, 2072495319, 2074166410, 2084913184, 2130591995, 3188689091, 3189737765, 3190787231, 3191835127, 3192883211, 3193933010, 3194981173, 3196029912, 4997579, 7487997, 10437178, 13648667, 16532014, 18301800, 21136106, 25576234, 28165219, 33374214, 41993470, 50136385, 55706998, 64898470, 72173101, 73533074, 74597831, 82379093, 84689646, 86296276, 88131318, 91293645, 100811009, 121897715, 134496430, 138461807, 140099962, 144146705, 150603628, 153698365, 165479013, 179685065, 190267776, 196119358, 197576885, 198625851, 199674739, 201623405, 206898808, 209094736, 210714750, 217907405, 690424874, 698745177, 703955669, 719226718, 905397742, 1044924707, 1045973577, 1047022420, 1048070594, 1049316355, 1050364663, 1051445952, 1053475732, 1055015896, 1056605352, 1057653802, 1058702243, 1059750859, 1060799481, 1061848050, 1063158834, 1064240216, 1067778987, 1068827700, 1070530954, 1086144903, 1143915772, 1177059636, 1540047584, 1933296007, 1955430661, 1963327877, 1963852081, 1964392920, 1964917092, 1965441625, 1966129687, 1966670193, 1967194395, 1967800792, 1968423296, 1968963889, 1969488342, 1970012514, 1970537045, 1971061157, 1971585496, 1972109852, 1972633969, 1973158171, 1973682627, 1974239982, 1974764078, 1975288160, 1975812950, 1976336768, 1976861400, 1977385565, 1977910082, 1978433995, 1978990825, 1979515125, 2072232998, 2072855468, 2073838597, 2074444983, 2084585814, 2085175343, 2111129492, 3187902572, 3188426925, 3188951255, 3189475597, 3189999934, 3190524270, 3191048189, 3191572484, 3192097291, 3192621650, 3193145987, 3193670340, 3194193950, 3194718240, 3195242536, 3195767728, 3196291121, 3244317, 6291876, 7144030, 8143148, 9961925, 11190790, 12501783, 14634046, 15712796, 17056160, 17810260, 19137347, 20070716, 22446086, 24199981, 26510116, 27395032, 28591287, 30967003, 36619479, 39093046, 44073761, 49415488, 51266911, 54723949, 56656412, 62047637, 68928939, 71910933, 72484413, 73270896, 73795639, 74335761, 74860022, 75384423, 82837815, 84148870, 85033613, 85852820, 86722267, 87672558, 88639232, 90212116, 93127396, 96207692, 104055492, 120849180, 123437506, 133939206, 134840503, 136203327, 138756430, 139461346, 140362324, 143262289, 145819458, 148734363, 152911890, 153436643, 153960535, 159531785, 173623160, 176799916, 186974531, 188285787, 193628038, 195594741, 196479947, 197314675, 197839127, 198363614, 198888050, 199412477, 199936960, 200461331, 203948297, 206636561, 207160937, 207685203, 209911923, 210452625, 213008889, 217645286, 328188088, 563104549, 691372343, 691978421, 700810007, 702893116, 710575591, 718389812, 719995473, 905135564, 905659946, 1044663094, 1045187035, 1045711799, 1046236041, 1046759828, 1047284640, 1047808116, 1048333245, 1048939077, 1049577823, 1050102526, 1050659067, 1051183682, 1051708253, 1052869184, 1054229687, 1054753999, 1055278303, 1056343180, 1056867509, 1057391570, 1057915976, 1058440356, 1058964670, 1059488943, 1060013095, 1060537497, 1061061683, 1061585924, 1062110238, 1062896642, 1063421038, 1063978073, 1065157743, 1067516435, 1068041160, 1068565532, 1069089892, 1070121254, 1070989913, 1084834100, 1090800695, 1094372410, 1160054018, 1161053469, 1177567806, 1186811004, 1932902693, 1933164900, 1933426927, 1954971996, 1962934555, 1963196770, 1963459011, 1963721301, 1963983179, 1964245413, 1964524099, 1964785984, 1965048199, 1965310510, 1965654339, 1965965738, 1966277205, 1966539083, 1966801317, 1967063614, 1967374663, 1967669674, 1967931971, 1968226631, 1968570819, 1968833109, 1969094987, 1969357221, 1969619518, 1969881560, 1970143621, 1970405916, 1970667825, 1970930033, 1971192278, 1971454235, 1971716450, 1971978691, 1972240981, 1972502859, 1972765093, 1973027390, 1973289432, 1973551493, 1973813788, 1974108994, 1974371042, 1974632907, 1974895448, 1975157208, 1975419726, 1975681564, 1975943726, 1976205652, 1976470595, 1976730188, 1976992049, 1977254277, 1977516880, 1977781315, 1978041070, 1978302863, 1978565166, 1978827060, 1979121884, 1979384148, 1979646185, 2072101832, 2072363984, 2072708196, 2073216079, 2073691164, 2074002478, 2074313704, 2074657749, 2074952620, 2084716790, 2085044263, 2089812114, 2109849887, 2130461144, 3187771488, 3188034698, 3188295832, 3188558008, 3188820173, 3189082339, 3189344515, 3189606382, 3189868851, 3190130674, 3190393189, 3190655361, 3190917522, 3191180008, 3191441875, 3191704043, 3191966893, 3192227847, 3192491189, 3192752739, 3193014906, 3193277081, 3193539252, 3193801426, 3194063109, 3194325766, 3194588375, 3194850092, 3195112257, 3195374448, 3195636631, 3195899112, 3196161006, 3196422195, 2032088, 4031403, 5390351, 6488497, 6881292, 7340504, 7618906, 8945909, 9683551, 10174986, 11059628, 11518842, 12009479, 13108022, 14385951, 15040903, 15516253, 16023941, 16679243, 17236299, 17613364, 18006748, 18678134, 19300677, 19874230, 20267411, 21939889, 22938135, 23921453, 24936455, 26018808, 26673965, 27083283, 27854040, 28411020, 29082589, 29409537, 31425514, 36422662, 37454638, 37962988, 39945471, 42272034, 44369195, 47121197, 49939763, 50644307, 52479230, 54134116, 55330161, 56296815, 58967424, 61310341, 63112603, 67782061, 71287324, 71779843, 72042016, 72336990, 72664652, 73123478, 73401988, 73664162, 73942702, 74204641, 74466896, 74728930, 74991110, 75253307, 76071674, 82577359, 83132772, 83804921, 84296330, 84902539, 85344900, 85672288, 85983870, 86427351, 86853342, 87541481, 88000242, 88508156, 88934147, 89802521, 90457884, 92325587, 94160676, 95617030, 99713828, 103925473, 104335113, 112050993, 121062189, 122257972, 123602791, 128401850, 134283264, 134643885, 135053319, 135561937, 137052486, 138608955, 139002247, 139314058, 139608532, 140230987, 140493366, 142950584, 143491329, 145113865, 146522752, 148456363, 149456950, 152618944, 153043016, 153305323, 153567285, 153829454, 155240366, 158288986, 161123460, 166903992, 175228828, 176637764, 177898088, 186270021, 187203936, 187793592, 189808989, 192791359, 193873771, 195414004, 195840719, 196299659, 196643826, 197181624, 197445789, 197708020, 197970231, 198232480, 198494706, 198756958, 199019171, 199281392, 199543632, 199805848, 200068053, 200330240, 200592418, 202213229, 204720243, 206505445, 206767694, 207029907, 207291892, 207554082, 207816359, 209780832, 210042981, 210321537, 210583703, 212877521, 215433448, 215695566, 217776609, 218038473, 381683972, 540182970, 600427392, 690998316, 691519819, 691831256, 697516888, 699843403, 701333792, 702202603, 703250643, 710444497, 710706711, 714443851, 718799725, 719504140, 904677000, 904939582, 905266648, 905528846, 905791075, 1044531450, 1044794265, 1045055932, 1045318652, 1045580742, 1045842873, 1046104477, 1046366338, 1046629393, 1046891290, 1047152858, 1047415138, 1047677386, 1047939428, 1048202141, 1048464050, 1048726456, 1049070295, 1049446572, 1049709061, 1049971364, 1050233711, 1050527902, 1050790247, 1051052546, 1051314804, 1051577164, 1051839427, 1052101857, 1053328267, 1054098634, 1054360779, 1054622938, 1054885074, 1055147076, 1055409304, 1055671521, 1056474251, 1056736434, 1056998575, 1057260732, 1057522708, 1057784891, 1058047071, 1058308646, 1058573396, 1058833337, 1059095626, 1059357855, 1059619761, 1059881994, 1060144189, 1060406177, 1060668317, 1060932689, 1061192647, 1061454882, 1061717009, 1061979129, 1062503346, 1062765558, 1063027743, 1063289948, 1063584296, 1063846845, 1064109120, 1064371240, 1065288674, 1066631566, 1067648167, 1067910327, 1068172250, 1068434437, 1068696614, 1068958788, 1069220380, 1069483088, 1070317908, 1070727624, 1077953140, 1084572000, 1085096781, 1086750965, 1091207947, 1093878623, 1095319860, 1157942374, 1160250634, 1160742170, 1165446257, 1177272540, 1177783363, 1186581623, 1539425090, 1540407685, 1932968244, 1933099342, 1933230454, 1933361363, 1952137396, 1954876506, 1955103598, 1955496120, 1963000113, 1963131211, 1963262321, 1963393445, 1963524566, 1963655742, 1963786523, 1963917784, 1964048738, 1964179845, 1964310979, 1964458542, 1964589657, 1964720436, 1964851534, 1964982646, 1965113770, 1965244888, 1965376067, 1965523238, 1965752674, 1965900167, 1966064084, 1966211646, 1966342427, 1966473688, 1966604642, 1966735749, 1966866883, 1966998044, 1967129173, 1967276340, 1967440224, 1967587717, 1967735240, 1967866414, 1967997529, 1968144695, 1968357735, 1968505253, 1968636374, 1968767550, 1968898331, 1969029592, 1969160546, 1969291653, 1969422787, 1969553948, 1969685077, 1969815857, 1969946955, 1970078065, 1970209189, 1970340310, 1970471486, 1970602267, 1970733528, 1970864482, 1970995589, 1971126723, 1971257884, 1971389013, 1971519793, 1971650891, 1971782001, 1971913125, 1972044246, 1972175422, 1972306203, 1972437464, 1972568418, 1972699525, 1972830659, 1972961820, 1973092949, 1973223729, 1973354827, 1973485937, 1973617061, 1973748182, 1973879358, 1974010315, 1974174011, 1974305108, 1974436224, 1974570051, 1974698577, 1974829644, 1974960856, 1975091505, 1975222606, 1975353733, 1975485021, 1975616336, 1975747100, 1975880771, 1976009538, 1976140526, 1976271586, 1976402319, 1976533451, 1976664622, 1976795992, 1976926491, 1977057752, 1977188704, 1977320270, 1977451034, 1977582108, 1977713494, 1977844270, 1977975099, 1978106196, 1978237312, 1978371139, 1978499665, 1978630732, 1978761944, 1978925244, 1979056468, 1979187445, 1979318505, 1979449564, 1979580604, 2050081240, 2072019904, 2072167503, 2072298460, 2072429614, 2072626120, 2072773684, 2072953902, 2073314309, 2073576348, 2073773177, 2073920695, 2074100636, 2074231955, 2074379324, 2074510372, 2074739740, 2074887173, 2075018259, 2084651229, 2084847644, 2084978723, 2085109803, 2085240883, 2089877654, 2109735267, 2110393433, 2111488332, 2130526435, 3187705930, 3187837032, 3187969160, 3188099199, 3188230289, 3188361376, 3188492467, 3188623549, 3188754633, 3188885716, 3189016799, 3189147887, 3189278975, 3189410056, 3189541138, 3189672223, 3189803307, 3189934065, 3190066330, 3190196559, 3190327285, 3190458730, 3190589813, 3190720898, 3190851575, 3190983840, 3191113849, 3191245247, 3191376336, 3191507415, 3191638021, 3191769590, 3191900674, 3192031238, 3192162842, 3192293927, 3192425018, 3192555528, 3192687193, 3192818282, 3192949948, 3193080445, 3193212098, 3193342625, 3193473708, 3193604795, 3193736396, 3193866971, 3193998057, 3194129572, 3194259606, 3194391308, 3194522385, 3194653470, 3194783778, 3194915632, 3195045924, 3195176934, 3195308902, 3195439992, 3195571090, 3195702179, 3195832362, 3195964370, 3196095460, 3196226550, 3196357872, 3196488728, 803288, 2867987, 3539293, 4620594, 5243399, 5817059, 6357404, 6603064, 6783514, 7012365, 7258319, 7422255, 7553315, 7930170, 8356190, 9011492, 9568578, 9814450, 10093011, 10273305, 10748514, 11125320, 11452422, 11633037, 11945213, 12288007, 12845856, 13468472, 13746999, 14517089, 14795380, 15106371, 15286661, 15597916, 15811148, 16187700, 16600131, 16859494, 17154259, 17301856, 17531382, 17728216, 17875549, 18170430, 18613069, 19039075, 19235680, 19431741, 19661190, 20005616, 20136269, 20464363, 21253187, 22233457, 22643250, 23085507, 23724932, 24134433, 24625624, 25330387, 25772562, 26379411, 26608415, 26755544, 26969235, 27149464, 27558878, 27952196, 28279324, 28492946, 28820260, 29148357, 29328486, 30442724, 31081595, 33243937, 36291704, 36537258, 37356736, 37536554, 37798145, 38044903, 39420444, 41517396, 42091814, 43614748, 44287277, 45122803, 46154528, 48399668, 49496865, 50021686, 50234687, 50939224, 51922256, 52725073, 53249373, 54576382, 54936941, 55559495, 56116587, 56460660, 58377592, 59262333, 61031816, 61752720, 62342554, 63423902, 67110311, 68157980, 69698994, 71468584, 71714271, 71845393, 71976476, 72107556, 72238641, 72418874, 72566343, 72779190, 73008765, 73205729, 73336439, 73467534, 73598618, 73729703, 73861187, 74008186, 74139080, 74270194, 74401348, 74532287, 74663387, 74794473, 74925569, 75056670, 75187766, 75318871, 75449788, 78905684, 82477339, 82755899, 83018464, 83263846, 83624661, 84083333, 84214408, 84607817, 84836988, 84968076, 85164918, 85410447, 85574002, 85787283, 85918357, 86098347, 86361814, 86492890, 86787804, 86918879, 87312062, 87607019, 87934704, 88065780, 88327930, 88573694, 88867039, 88999685, 89376524, 90146578, 90292580, 90947996, 91997038, 92849930, 94095121, 94226219, 95191301, 95962938, 99468683, 99960215, 103645901, 103991011, 104121032, 104466203, 111413076, 114755418, 120980277, 121766646, 122159697, 122340202, 123519742, 123699792, 124700471, 133120006, 134217728, 134348800, 134563802, 134725639, 134987776, 135118856, 135381274, 135743445, 136970597, 137807075, 138543974, 138674648, 138854756, 139084543, 139247947, 139395924, 139542827, 140034555, 140165506, 140296655, 140427867, 140855110, 142852939, 143016564, 143328002, 143933712, 144737995, 145753986, 146129527, 147408795, 148390250, 148570460, 148799887, 149964706, 152207755, 152684484, 152977431, 153108546, 153239742, 153371127, 153502191, 153632825, 153763914, 153894995, 154585005, 157073792, 158122822, 158515822, 160303012, 165380357, 166052566, 167397308, 174851999, 175980938, 176228236, 176734374, 176947804, 178094347, 184239987, 186780738, 187072846, 187269479, 187498760, 187957575, 189645550, 190070785, 192578340, 193562315, 193725038, 195070029, 195332398, 195529190, 195693121, 196053758, 196217698, 196381613, 196545505, 196758543, 196922406, 197249107, 197380230, 197511334, 197642461, 197773578, 197904684, 198035782, 198166906, 198298025, 198429164, 198560310, 198691402, 198822504, 198953601, 199084729, 199215838, 199346936, 199478075, 199609190, 199740295, 199871404, 200002510, 200133602, 200264696, 200395789, 200526875, 201375751, 201770952, 202622907, 204458228, 206145641, 206439884, 206570995, 206702148, 206833262, 206964351, 207095454, 207226313, 207357447, 207488536, 207619644, 207750799, 208947254, 209715288, 209846378, 209977437, 210108528, 210255995, 210387076, 210518163, 210649213, 210780287, 212943085, 215368214, 215499008, 215630318, 217580052, 217710846, 217842157, 217972933, 283559914, 364234938, 419579896, 467028495, 541706685, 588827495, 663800838, 690687017, 691290464, 691454685, 691585380, 691765531, 691914548, 696484020, 698074990, 699139897, 700187420, 701268252, 701399329, 701743647, 702450752, 702991456, 703398181, 704497626, 710510044, 710641136, 710772280, 711511112, 718242340, 718570060, 719078144, 719325136, 719768643, 720144227, 904742553, 904874041, 905004713, 905201106, 905332198, 905463296, 905594394, 905725489, 905856584, 1044465734, 1044597106, 1044728318, 1044859357, 1044990334, 1045121117, 1045252898, 1045383874, 1045514979, 1045645833, 1045776515, 1045908315, 1046038625, 1046170232, 1046301065, 1046432599, 1046563466, 1046694789, 1046825629, 1046956181, 1047087592, 1047218765, 1047349342, 1047480600, 1047611822, 1047743076, 1047873825, 1048004749, 1048136297, 1048266944, 1048398344, 1048528983, 1048660479, 1048791251, 1049004693, 1049201557, 1049380968, 1049512164, 1049643418, 1049774644, 1049905811, 1050036937, 1050168142, 1050299350, 1050462300, 1050593493, 1050724673, 1050855793, 1050986942, 1051118110, 1051249250, 1051380360, 1051511562, 1051642710, 1051773838, 1051904984, 1052035085, 1052787134, 1053131649, 1053393808, 1053705101, 1054164172, 1054295246, 1054426317, 1054557400, 1054688464, 1054819537, 1054950612, 1055081440, 1055212764, 1055343838, 1055474914, 1055605276, 1056277582, 1056408722, 1056539852, 1056670897, 1056801974, 1056933011, 1057063839, 1057194937, 1057326020, 1057457136, 1057588253, 1057719350, 1057850435, 1057981517, 1058112620, 1058243718, 1058374811, 1058507854, 1058636975, 1058767783, 1058899130, 1059030083, 1059161186, 1059292297, 1059423396, 1059554209, 1059685564, 1059816430, 1059947543, 1060078645, 1060209736, 1060340839, 1060471930, 1060603028, 1060734136, 1060865158, 1060996130, 1061127172, 1061258257, 1061389298, 1061520435, 1061651399, 1061782524, 1061913596, 1062044656, 1062174736, 1062568891, 1062700013, 1062831120, 1062962198, 1063093291, 1063224384, 1063355486, 1063486578, 1063650430, 1063781296, 1063912433, 1064043682, 1064174717, 1064305771, 1065092109, 1065223311, 1066207128, 1066566419, 1067451289, 1067581970, 1067713436, 1067844524, 1067975616, 1068106704, 1068237798, 1068368888, 1068499987, 1068631076, 1068762158, 1068893244, 1069024335, 1069155449, 1069286547, 1069417554, 1069762522, 1070235968, 1070465408, 1070612879, 1070842396, 1071202932, 1080427022, 1084441326, 1084752155, 1085030759, 1085817813, 1086603595, 1089752156, 1091043686, 1091601144, 1093600116, 1093959919, 1095074132, 1116995764, 1148636231, 1158368362, 1160168710, 1160365327, 1160529175, 1160823597, 1165380717, 1170375253, 1177159548, 1177420145, 1177649391, 1177944652, 1178272128, 1186663546, 1192266880, 1539736390, 1540309742, 1540653062, 1932935467, 1933001019, 1933066567, 1933132128, 1933197671, 1933263232, 1933328856, 1933394147, 1933459707, 1954644179, 1954840815, 1954906468, 1955007540, 1955253303, 1955465101, 1955579708, 1962967334, 1963032887, 1963098435, 1963163988, 1963229542, 1963295102, 1963360655, 1963426230, 1963491787, 1963557430, 1963622962, 1963688524, 1963754077, 1963819302, 1963884855, 1963950403, 1964015956, 1964081510, 1964147070, 1964212623, 1964278198, 1964360148, 1964425751, 1964491316, 1964556881, 1964622432, 1964687659, 1964753211, 1964818759, 1964884320, 1964949863, 1965015424, 1965080988, 1965146554, 1965212116, 1965277719, 1965343284, 1965408849, 1965474400, 1965588791, 1965719892, 1965801831, 1965867392, 1965932956, 1966031304, 1966096856, 1966178866, 1966244428, 1966309981, 1966375206, 1966440759, 1966506307, 1966571860, 1966637414, 1966702974, 1966768527, 1966834102, 1966899659, 1966965302, 1967030834, 1967096396, 1967161949, 1967243563, 1967341888, 1967407438, 1967489382, 1967554942, 1967636892, 1967702458, 1967768020, 1967833623, 1967899188, 1967964753, 1968030304, 1968111921, 1968193856, 1968275796, 1968390518, 1968472463, 1968538038, 1968603595, 1968669238, 1968734770, 1968800332, 1968865885, 1968931110, 1968996663, 1969062211, 1969127764, 1969193318, 1969258878, 1969324431, 1969390006, 1969455563, 1969521206, 1969586738, 1969652300, 1969717853, 1969783078, 1969848631, 1969914179, 1969979732, 1970045286, 1970110846, 1970176399, 1970241974, 1970307531, 1970373174, 1970438706, 1970504268, 1970569821, 1970635046, 1970700599, 1970766147, 1970831700, 1970897254, 1970962814, 1971028367, 1971093942, 1971159499, 1971225142, 1971290674, 1971356236, 1971421789, 1971487014, 1971552567, 1971618115, 1971683668, 1971749222, 1971814782, 1971880335, 1971945910, 1972011467, 1972077110, 1972142642, 1972208204, 1972273757, 1972338982, 1972404535, 1972470083, 1972535636, 1972601190, 1972666750, 1972732303, 1972797878, 1972863435, 1972929078, 1972994610, 1973060172, 1973125725, 1973190950, 1973256503, 1973322051, 1973387604, 1973453158, 1973518718, 1973584271, 1973649846, 1973715403, 1973781046, 1973846578, 1973912140, 1973977693, 1974075675, 1974141233, 1974206936, 1974272334, 1974337888, 1974403461, 1974469454, 1974534749, 1974600218, 1974666064, 1974731292, 1974796828, 1974862678, 1974930499, 1974993454, 1975059266, 1975124283, 1975190254, 1975255380, 1975321314, 1975386496, 1975452047, 1975520323, 1975583179, 1975648849, 1975714350, 1975779916, 1975845720, 1975911128, 1975976219, 1976041777, 1976107480, 1976172878, 1976238432, 1976304005, 1976369998, 1976435293, 1976500762, 1976566608, 1976631836, 1976697372, 1976763222, 1976831043, 1976893998, 1976959810, 1977024827, 1977090798, 1977155924, 1977221858, 1977287040, 1977352591, 1977420867, 1977483723, 1977549393, 1977614894, 1977680460, 1977746264, 1977811672, 1977876763, 1977942321, 1978008024, 1978073422, 1978138976, 1978204549, 1978270542, 1978335837, 1978401306, 1978467152, 1978532380, 1978597916, 1978663766, 1978731587, 1978794542, 1978892628, 1978958044, 1979023605, 1979089084, 1979154665, 1979220308, 1979285724, 1979351285, 1979416764, 1979482345, 1979547988, 1979613404, 1979678965, 2050114757, 2071986648, 2072069258, 2072134803, 2072200313, 2072265733, 2072331180, 2072396819, 2072462483, 2072544192, 2072675396, 2072740840, 2072806460, 2072888272, 2073150408, 2073265128, 2073363448, 2073511059, 2073625116, 2073723972, 2073805862, 2073887736, 2073969683, 2074051799, 2074133461, 2074198984, 2074281060, 2074346548, 2074412024, 2074477530, 2074624924, 2074706918, 2074788943, 2074854438, 2074919900, 2074985424, 2075051054, 2084618429, 2084684010, 2084814852, 2084880414, 2084945954, 2085011493, 2085077033, 2085142573, 2085208113, 2086356963, 2089844859, 2089910424, 2093352890, 2109768518, 2109900728, 2110980390, 2111276937, 2111588243, 2130493651, 2130559215, 3187673161, 3187738714, 3187804260, 3187869668, 3187935342, 3188000880, 3188066429, 3188133097, 3188197519, 3188262888, 3188328606, 3188394153, 3188459698, 3188526222, 3188591759, 3188656325, 3188721862, 3188787404, 3188852945, 3188918486, 3188983947, 3189049569, 3189115114, 3189180658, 3189247121, 3189311745, 3189377005, 3189443731, 3189508368, 3189573963, 3189639453, 3189704994, 3189770534, 3189836079, 3189901296, 3189967163, 3190032708, 3190098248, 3190164683, 3190229330, 3190294878, 3190361243, 3190425959, 3190491503, 3190557041, 3190622588, 3190688926, 3190753672, 3190819212, 3190884755, 3190950298, 3191015861, 3191080956, 3191146934, 3191212476, 3191278019, 3191343560, 3191409105, 3191474644, 3191540904, 3191606441, 3191671271, 3191737493, 3191802361, 3191867905, 3191933446, 3191998989, 3192064530, 3192130735, 3192195614, 3192261157, 3192326698, 3192392242, 3192457790, 3192523335, 3192588297, 3192654421, 3192719967, 3192785510, 3192851642, 3192916599, 3192982719, 3193047676, 3193112593, 3193178760, 3193244867, 3193309203, 3193375941, 3193440276, 3193505813, 3193572024, 3193637569, 3193703114, 3193767961, 3193834197, 3193900241, 3193965730, 3194030108, 3194096852, 3194161909, 3194227455, 3194292995, 3194358533, 3194424072, 3194489611, 3194555606, 3194620699, 3194686239, 3194752218, 3194817322, 3194882862, 3194948402, 3195013943, 3195079485, 3195144229, 3195210975, 3195276121, 3195341674, 3195407586, 3195472763, 3195538659, 3195603862, 3195669412, 3195734954, 3195800498, 3195865383, 3195930667, 3195996205, 3196062682, 3196128232, 3196192815, 3196259329, 3196325103, 3196390642, 3196455957, 3196521500, 606929, 1572870, 2654633, 2966354, 3408176, 3785128, 4456752, 4817214, 5030350, 5276175, 5538136, 5882443, 6324640, 6439216, 6570294, 6668812, 6734673, 6816085, 6914404, 7045484, 7176804, 7291454, 7389185, 7455048, 7520592, 7586111, 7651700, 7962937, 8176044, 8831715, 8978824, 9372073, 9535795, 9650749, 9748899, 9863913, 10011229, 10125771, 10207829, 10322477, 10666572, 10813720, 11092400, 11157952, 11419654, 11485190, 11583763, 11698182, 11911342, 11977163, 12093265, 12419079, 12780319, 12927786, 13140785, 13517616, 13681450, 14025505, 14483462, 14582219, 14664000, 14975269, 15073628, 15139147, 15254242, 15483279, 15551555, 15631064, 15778332, 15991509, 16089940, 16401121, 16564692, 16646871, 16763965, 16908608, 17089346, 17203523, 17269076, 17334631, 17482142, 17580265, 17679094, 17777420, 17842597, 17908313, 18073444, 18219476, 18383268, 18645340, 18809712, 19071396, 19170117, 19267800, 19366214, 19562792, 19628421, 19693955, 19907000, 20037948, 20104049, 20169163, 20333285, 20595235, 21185273, 21430654, 22185650, 22332236, 22561365, 22806984, 23003671, 23151132, 23544603, 23823235, 24019745, 24166657, 24511057, 24904190, 24969223, 25412646, 25739795, 25805272, 26199223, 26444571, 26575666, 26641183, 26723523, 26853750, 26936214, 27034131, 27116686, 27280334, 27493337, 27755538, 27919576, 28116189, 28230808, 28345570, 28443792, 28542102, 28639406, 29033249, 29114840, 29180444, 29246664, 29376692, 30409505, 30688169, 31048933, 31212758, 32670168, 33342242, 36193192, 36324581, 36455562, 36586463, 36652179, 37405474, 37504192, 37634876, 37765920, 37848294, 38012142, 38077673, 39125787, 39797016, 41420032, 41631995, 42059043, 42190117, 43173108, 43679989, 44139816, 44320041, 45040881, 45711900, 45843707, 46236975, 47841531, 49022257, 49464650, 49906204, 49988914, 50070850, 50185527, 50364956, 50857307, 51086688, 51447138, 52445984, 52528461, 52757842, 53200218, 54100508, 54543136, 54674739, 54838633, 55050780, 55412037, 55608693, 56001904, 56264050, 56329587, 56542574, 58344825, 58770811, 59065724, 59556645, 60031516, 61261187, 61653798, 62014867, 62128668, 62948770, 63358365, 64652707, 66406571, 67584540, 67994404, 68765103, 69174705, 70894173, 71370188, 71615935, 71681483, 71747054, 71812618, 71878163, 71943705, 72009240, 72074786, 72140326, 72205871, 72271347, 72386103, 72451651, 72517184, 72631910, 72730195, 72811973, 72943219, 73057920, 73156252, 73238123, 73303669, 73369212, 73434759, 73500302, 73565845, 73631391, 73696932, 73762784, 73828413, 73909930, 73975405, 74040962, 74106302, 74171862, 74237420, 74302968, 74368552, 74434125, 74499511, 74565060, 74630606, 74696158, 74761704, 74827252, 74892797, 74958343, 75023884, 75089440, 75155039, 75220543, 75286097, 75351641, 75417016, 75482560, 77071222, 82329956, 82446287, 82510129, 82624894, 82805084, 82887047, 83067100, 83181926, 83345702, 83542364, 83673399, 83886345, 84116099, 84181639, 84263561, 84525684, 84641467, 84787831, 84869761, 84935287, 85000822, 85098502, 85312123, 85377678, 85443216, 85508754, 85608134, 85754268, 85820026, 85885567, 85951101, 86066895, 86196544, 86329045, 86393153, 86460121, 86671687, 86754116, 86820573, 86884871, 87179937, 87245479, 87508712, 87574250, 87639788, 87705327, 87967473, 88033011, 88098549, 88295161, 88475387, 88540925, 88606463, 88672001, 88901378, 88966916, 89032454, 89145945, 89589526, 90112315, 90179347, 90244879, 90425119, 90490658, 91047792, 91717931, 92127525, 92391149, 93029092, 94060928, 94127909, 94193448, 94258989, 94324529, 95437272, 95781195, 96141569, 97337528, 99501453, 99926820, 100042136, 103122763, 103842511, 103958242, 104023780, 104088262, 104269646, 104400664, 104547183, 108283731, 111855446, 114607960, 118425436, 120897543, 121013032, 121324332, 121848583, 121963238, 122192480, 122291028, 122979024, 123470363, 123552330, 123666899, 123781571, 124290921, 126929996, 130007645, 133152774, 134168582, 134250496, 134316032, 134381568, 134529198, 134610950, 134676487, 134807732, 134873088, 135021267, 135086086, 135168275, 135315630, 135413763, 135643145, 135774208, 136905323, 137019829, 137200133, 138428817, 138510759, 138576323, 138641864, 138707271, 138821984, 138887524, 139051748, 139133221, 139198769, 139281286, 139346379, 139428674, 139496503, 139575734, 140001790, 140067192, 140132736, 140198182, 140263884, 140329554, 140395097, 140460453, 140575475, 141462662, 142656222, 142901422, 142983332, 143180248, 143294833, 143378307, 143589991, 144064780, 144343302, 145050713, 145293671, 145785718, 145884996, 146161942, 146784561, 147490748, 148308594, 148424599, 148522690, 148701742, 148767186, 149358646, 149588022, 149997472, 150946264, 152586174, 152651714, 152717254, 152944661, 153010201, 153075776, 153141316, 153206803, 153272542, 153338103, 153403868, 153469417, 153534666, 153600055, 153665595, 153731135, 153796684, 153862225, 153927765, 154224495, 155191258, 155764656, 157550513, 157812607, 158205878, 158451522, 158861209, 159992930, 160991042, 165281808, 165445648, 166019237, 166855052, 167133543, 172687961, 173786999, 175179736, 175523785, 176029880, 176195473, 176261008, 176703297, 176767146, 176849075, 177178571, 178061570, 178947891, 180306105, 184272757, 186729012, 186876219, 187007824, 187154972, 187236646, 187335500, 187401037, 187630436, 187891923, 187990249, 188629387, 189677873, 189940450, 190136674, 190367559, 192660289, 193430120, 193593361, 193660807, 193758102, 193970803, 195119190, 195283222, 195365194, 195447193, 195561969, 195627572, 195775116, 195971802, 196086526, 196152138, 196266886, 196348843, 196414387, 196512733, 196594659, 196692991, 196807711, 196873252, 197150774, 197216336, 197281897, 197347451, 197413000, 197478563, 197544111, 197609658, 197675249, 197740797, 197806356, 197871902, 197937456, 198003011, 198068553, 198134132, 198199704, 198265251, 198330807, 198396389, 198461935, 198527521, 198593080, 198658624, 198724187, 198789729, 198855280, 198920831, 198986395, 199051945, 199117517, 199183068, 199248614, 199314163, 199379706, 199445293, 199510857, 199576419, 199641961, 199707517, 199773066, 199838621, 199904180, 199969738, 200035280, 200100830, 200166379, 200231925, 200297466, 200363016, 200428560, 200494103, 200559646, 200625191, 201459643, 201670818, 202064687, 202262695, 203869271, 204081452, 204654674, 205211834, 206341556, 206407112, 206472663, 206538225, 206603784, 206669352, 206734922, 206800470, 206866035, 206931581, 206997130, 207062680, 207128545, 207193537, 207259100, 207324671, 207390217, 207455769, 207521312, 207586862, 207652411, 207717991, 207783587, 207849134, 208994698, 209176652, 209748060, 209813604, 209879151, 209944665, 210010209, 210075755, 210141300, 210206825, 210288776, 210354304, 210419861, 210485385, 210550938, 210616475, 210681994, 210747525, 210813086, 212910305, 212975865, 213041630, 215400667, 215466228, 215532035, 215597544, 215663092, 215728322, 217612505, 217678066, 217743873, 217809383, 217874931, 217940161, 218005703, 218071255, 327157722, 336529506, 372951261, 395577343, 429541804, 444221443, 526224063, 540903422, 543803840, 580864850, 595495801, 619416488, 670091278, 690605107, 690916401, 691047472, 691323179, 691421504, 691487028, 691552583, 691634550, 691732869, 691798321, 691863921, 691945645, 692027567, 697385697, 697582357, 698319634, 698780761, 699613968, 700104984, 700255291, 701121094, 701301022, 701366561, 701432098, 701678115, 702038615, 702399044, 702742534, 702955962, 703040565, 703365440, 703433784, 704037712, 710411727, 710477271, 710542817, 710608364, 710673916, 710739486, 710805065, 710870632, 712230084, 718094778, 718291688, 718488321, 718619478, 718848590, 719127074, 719259488, 719372885, 719586041, 719831771, 720077583, 720290339, 904709773, 904775328, 904841181, 904906811, 904972353, 905102786, 905168336, 905233876, 905299424, 905364981, 905430523, 905496075, 905561619, 905627175, 905692718, 905758304, 905823813, 905889364, 1044433773, 1044499493, 1044564174, 1044630227, 1044695952, 1044761166, 1044826191, 1044892452, 1044957984, 1045023545, 1045088836, 1045153946, 1045219957, 1045285754, 1045351045, 1045416870, 1045481797, 1045547655, 1045612826, 1045678782, 1045744359, 1045809859, 1045875071, 1045940494, 1046005922, 1046071348, 1046137014, 1046203220, 1046268291, 1046333524, 1046399671, 1046464668, 1046530468, 1046596593, 1046661554, 1046727020, 1046792747, 1046858465, 1046924199, 1046989352, 1047054681, 1047120454, 1047185704, 1047251670, 1047317449, 1047382939, 1047447655, 1047513560, 1047579535, 1047644307, 1047710224, 1047775932, 1047841722, 1047907202, 1047972425, 1048037660, 1048103420, 1048169280, 1048234149, 1048300120, 1048366090, 1048431270, 1048496678, 1048561806, 1048627893, 1048693636, 1048758592, 1048873389, 1048971917, 1049037772, 1049135962, 1049283558, 1049349055, 1049413784, 1049479382, 1049544987, 1049610603, 1049676207, 1049741847, 1049807494, 1049873041, 1049938584, 1050004165, 1050069723, 1050135320, 1050200925, 1050266509, 1050331485, 1050429568, 1050495094, 1050560715, 1050626283, 1050691847, 1050757460, 1050823019, 1050888587, 1050954157, 1051019749, 1051085331, 1051150974, 1051216469, 1051282028, 1051347580, 1051413163, 1051478732, 1051544372, 1051609941, 1051675483, 1051741039, 1051806626, 1051872209, 1051937782, 1052003494, 1052069056, 1052134435, 1052819900, 1053098877, 1053295505, 1053361038, 1053426581, 1053541255, 1054065544, 1054131404, 1054196919, 1054262478, 1054328011, 1054393549, 1054459032, 1054524632, 1054590170, 1054655696, 1054721231, 1054786769, 1054852306, 1054917845, 1054983129, 1055048677, 1055114308, 1055179996, 1055245535, 1055311070, 1055376531, 1055442072, 1055506972, 1055572508, 1055638675, 1056244814, 1056310413, 1056375952, 1056441568, 1056507084, 1056572563, 1056638121, 1056703667, 1056769204, 1056834742, 1056900243, 1056965802, 1057031349, 1057096613, 1057162161, 1057227711, 1057293502, 1057358795, 1057424363, 1057489927, 1057555479, 1057621029, 1057686576, 1057752121, 1057817663, 1057883206, 1057948746, 1058014298, 1058079842, 1058145397, 1058210944, 1058276489, 1058341417, 1058407584, 1058472932, 1058540624, 1058604205, 1058669749, 1058735013, 1058800567, 1058866111, 1058931900, 1058997307, 1059062853, 1059128397, 1059193957, 1059259526, 1059325094, 1059390627, 1059456171, 1059521438, 1059586973, 1059652537, 1059718084, 1059783635, 1059849203, 1059914772, 1059980317, 1060045871, 1060111415, 1060176966, 1060242506, 1060308063, 1060373617, 1060439171, 1060504102, 1060570269, 1060635817, 1060701101, 1060766697, 1060832330, 1060897952, 1060965461, 1061028909, 1061094391, 1061159885, 1061225428, 1061291035, 1061356545, 1061422105, 1061487661, 1061553143, 1061618637, 1061684180, 1061749787, 1061815297, 1061880857, 1061946369, 1062011883, 1062077436, 1062142473, 1062470575, 1062536117, 1062601669, 1062667247, 1062732789, 1062798344, 1062863869, 1062929411, 1062994968, 1063060513, 1063126060, 1063191608, 1063257179, 1063322711, 1063388256, 1063453800, 1063552105, 1063617666, 1063683207, 1063748514, 1063814070, 1063879618, 1063945214, 1064010908, 1064076354, 1064141868, 1064207448, 1064272834, 1064338336, 1064403953, 1065124369, 1065190369, 1065255950, 1066139905, 1066239910, 1066305450, 1066599187, 1066795182, 1067484059, 1067549841, 1067615399, 1067680216, 1067746204, 1067811756, 1067877559, 1067942848, 1068008392, 1068073936, 1068139477, 1068205020, 1068270568, 1068336120, 1068401669, 1068467219, 1068532764, 1068598308, 1068663846, 1068729390, 1068794932, 1068860476, 1068926020, 1068991567, 1069057124, 1069122681, 1069187612, 1069253770, 1069319315, 1069384789, 1069450321, 1069580295, 1070088475, 1070203195, 1070285131, 1070383462, 1070498183, 1070566477, 1070678454, 1070760404, 1070907966, 1071056814, 1073349080, 1080394116, 1080559570, 1084342580, 1084522836, 1084604775, 1084784934, 1084965724, 1085064012, 1085147091, 1085932572, 1086357798, 1086669159, 1086849496, 1090536160, 1090961735, 1091126113, 1091518885, 1091699262, 1093403374, 1093714697, 1093928773, 1094107984, 1094599511, 1095172390, 1095352704, 1128172645, 1148355373, 1148684168, 1158220904, 1158876268, 1160119556, 1160217864, 1160283404, 1160398094, 1160480020, 1160709401, 1160791294, 1160856150, 1161135391, 1165413487, 1170211382, 1170457173, 1177092485, 1177223419, 1177370964, 1177485671, 1177600459, 1177714899, 1177911547, 1178075476, 1178173822, 1178370342, 1186614388, 1186696313, 1186843774, 1208880222, 1539473767, 1539935287, 1540260198, 1540358427, 1540636678, 1932886299, 1932919078, 1932951857, 1932984631, 1933017560, 1933050179, 1933082955, 1933115732, 1933148514, 1933181286, 1933214065, 1933246846, 1933279621, 1933312340, 1933344956, 1933377756, 1933410537, 1933443317, 1950679653, 1954579317, 1954726753, 1954791818, 1954857268, 1954892855, 1954924493, 1954989937, 1955087072, 1955234119, 1955335237, 1955447059, 1955481486, 1955512714, 1955677046, 1962950949, 1962983723, 1963016500, 1963049275, 1963082048, 1963114823, 1963147598, 1963180384, 1963213156, 1963245927, 1963278710, 1963311488, 1963344263, 1963377052, 1963409834, 1963442618, 1963475400, 1963508180, 1963540952, 1963573783, 1963606574, 1963639348, 1963672131, 1963704913, 1963737689, 1963770464, 1963802917, 1963835691, 1963868468, 1963901243, 1963934016, 1963966791, 1963999566, 1964032352, 1964065124, 1964097895, 1964130678, 1964163456, 1964196231, 1964229020, 1964261802, 1964294586, 1964343755, 1964376534, 1964409398, 1964442140, 1964474930, 1964507710, 1964540492, 1964573269, 1964606045, 1964638491, 1964671270, 1964704049, 1964736823, 1964769752, 1964802371, 1964835147, 1964867924, 1964900706, 1964933478, 1964966257, 1964999038, 1965031813, 1965064591, 1965097381, 1965130166, 1965162947, 1965195723, 1965228502, 1965261366, 1965294108, 1965326898, 1965359678, 1965392460, 1965425237, 1965458013, 1965490459, 1965539627, 1965637952, 1965703502, 1965736288, 1965785446, 1965818225, 1965851006, 1965883781, 1965916559, 1965949349, 1965998522, 1966047691, 1966080470, 1966113334, 1966162478, 1966195252, 1966228035, 1966260817, 1966293593, 1966326368, 1966358821, 1966391595, 1966424372, 1966457147, 1966489920, 1966522695, 1966555470, 1966588256, 1966621028, 1966653799, 1966686582, 1966719360, 1966752135, 1966784924, 1966817706, 1966850490, 1966883272, 1966916052, 1966948824, 1966981655, 1967014446, 1967047220, 1967080003, 1967112785, 1967145561, 1967178336, 1967210789, 1967259953, 1967292727, 1967358275, 1967391051, 1967423828, 1967456610, 1967505767, 1967538550, 1967571328, 1967620495, 1967653285, 1967686070, 1967718851, 1967751627, 1967784406, 1967817270, 1967850012, 1967882802, 1967915582, 1967948364, 1967981141, 1968013917, 1968046363, 1968095531, 1968128308, 1968177624, 1968210243, 1968243019, 1968341350, 1968374129, 1968406910, 1968439685, 1968488860, 1968521642, 1968554426, 1968587208, 1968619988, 1968652760, 1968685591, 1968718382, 1968751156, 1968783939, 1968816721, 1968849497, 1968882272, 1968914725, 1968947499, 1968980276, 1969013051, 1969045824, 1969078599, 1969111374, 1969144160, 1969176932, 1969209703, 1969242486, 1969275264, 1969308039, 1969340828, 1969373610, 1969406394, 1969439176, 1969471956, 1969504728, 1969537559, 1969570350, 1969603124, 1969635907, 1969668689, 1969701465, 1969734240, 1969766693, 1969799467, 1969832244, 1969865019, 1969897792, 1969930567, 1969963342, 1969996128, 1970028900, 1970061671, 1970094454, 1970127232, 1970160007, 1970192796, 1970225578, 1970258362, 1970291144, 1970323924, 1970356696, 1970389527, 1970422318, 1970455092, 1970487875, 1970520657, 1970553433, 1970586208, 1970618661, 1970651435, 1970684212, 1970716987, 1970749760, 1970782535, 1970815310, 1970848096, 1970880868, 1970913639, 1970946422, 1970979200, 1971011975, 1971044764, 1971077546, 1971110330, 1971143112, 1971175892, 1971208664, 1971241495, 1971274286, 1971307060, 1971339843, 1971372625, 1971405401, 1971438176, 1971470629, 1971503403, 1971536180, 1971568955, 1971601728, 1971634503, 1971667278, 1971700064, 1971732836, 1971765607, 1971798390, 1971831168, 1971863943, 1971896732, 1971929514, 1971962298, 1971995080, 1972027860, 1972060632, 1972093463, 1972126254, 1972159028, 1972191811, 1972224593, 1972257369, 1972290144, 1972322597, 1972355371, 1972388148, 1972420923, 1972453696, 1972486471, 1972519246, 1972552032, 1972584804, 1972617575, 1972650358, 1972683136, 1972715911, 1972748700, 1972781482, 1972814266, 1972847048, 1972879828, 1972912600, 1972945431, 1972978222, 1973010996, 1973043779, 1973076561, 1973109337, 1973142112, 1973174565, 1973207339, 1973240116, 1973272891, 1973305664, 1973338439, 1973371214, 1973404000, 1973436772, 1973469543, 1973502326, 1973535104, 1973567879, 1973600668, 1973633450, 1973666234, 1973699016, 1973731796, 1973764568, 1973797399, 1973830190, 1973862964, 1973895747, 1973928529, 1973961305, 1973994080, 1974026988, 1974092069, 1974125382, 1974157703, 1974190428, 1974223171, 1974255947, 1974289226, 1974321995, 1974354268, 1974387047, 1974420300, 1974453069, 1974486908, 1974518485, 1974552427, 1974584143, 1974616412, 1974649560, 1974682450, 1974715219, 1974747988, 1974780757, 1974814565, 1974846205, 1974879063, 1974912889, 1974944092, 1974977277, 1975010132, 1975042341, 1975075654, 1975107975, 1975140700, 1975173443, 1975206219, 1975239498, 1975272267, 1975304540, 1975337319, 1975370572, 1975403341, 1975437180, 1975468757, 1975502699, 1975534415, 1975566684, 1975599832, 1975632722, 1975665491, 1975698260, 1975731029, 1975764837, 1975796477, 1975829335, 1975863161, 1975894364, 1975927549, 1975960404, 1975992613, 1976025926, 1976058247, 1976090972, 1976123715, 1976156491, 1976189770, 1976222539, 1976254812, 1976287591, 1976320844, 1976353613, 1976387452, 1976419029, 1976452971, 1976484687, 1976516956, 1976550104, 1976582994, 1976615763, 1976648532, 1976681301, 1976715109, 1976746749, 1976779607, 1976813433, 1976844636, 1976877821, 1976910676, 1976942885, 1976976198, 1977008519, 1977041244, 1977073987, 1977106763, 1977140042, 1977172811, 1977205084, 1977237863, 1977271116, 1977303885, 1977337724, 1977369301, 1977403243, 1977434959, 1977467228, 1977500376, 1977533266, 1977566035, 1977598804, 1977631573, 1977665381, 1977697021, 1977729879, 1977763705, 1977794908, 1977828093, 1977860948, 1977893157, 1977926470, 1977958791, 1977991516, 1978024259, 1978057035, 1978090314, 1978123083, 1978155356, 1978188135, 1978221388, 1978254157, 1978287996, 1978319573, 1978353515, 1978385231, 1978417500, 1978450648, 1978483538, 1978516307, 1978549076, 1978581845, 1978615653, 1978647293, 1978680151, 1978713977, 1978745180, 1978778365, 1978811220, 1978843997, 1978909144, 1978941651, 1978974435, 1979007215, 1979039995, 1979072984, 1979105491, 1979138275, 1979171055, 1979203835, 1979236824, 1979269331, 1979302115, 1979334895, 1979367675, 1979400664, 1979433171, 1979465955, 1979498735, 1979531515, 1979564504, 1979597011, 1979629795, 1979662575, 1979695355, 2050099073, 2050146549, 2050180986, 2072003484, 2072036309, 2072085478, 2072118300, 2072151108, 2072183908, 2072216552, 2072249396, 2072282172, 2072314872, 2072347831, 2072380378, 2072413220, 2072446099, 2072478935, 2072527772, 2072576540, 2072659091, 2072691791, 2072724601, 2072757286, 2072790021, 2072839160, 2072872119, 2072921107, 2073068480, 2073183379, 2073248889, 2073281574, 2073330748, 2073412560, 2073478190, 2073543895, 2073592768, 2073674696, 2073707667, 2073756772, 2073789416, 2073822260, 2073855036, 2073904044, 2073936848, 2073986084, 2074018963, 2074083800, 2074117056, 2074149404, 2074182630, 2074215452, 2074264655, 2074297465, 2074330150, 2074362885, 2074395612, 2074428332, 2074461136, 2074493971, 2074526766, 2074641344, 2074690698, 2074723272, 2074756243, 2074805348, 2074837992, 2074870836, 2074903612, 2074936312, 2074969271, 2075001818, 2075034660, 2084569431, 2084602330, 2084634836, 2084667620, 2084700400, 2084733180, 2084831259, 2084864029, 2084896799, 2084929569, 2084962367, 2084995108, 2085027878, 2085060648, 2085093418, 2085126188, 2085158958, 2085191728, 2085224498, 2085257260, 2086371625, 2089828473, 2089861259, 2089894028, 2089926787, 2093254585, 2093369273, 2109754425, 2109803610, 2109866365, 2110062940, 2110687178, 2111113026, 2111242815, 2111439207, 2111504759, 2130444628, 2130477244, 2130510044, 2130542825, 2130575605, 2281310139, 3187689543, 3187723399, 3187755103, 3187787867, 3187820645, 3187853414, 3187887243, 3187918957, 3187951733, 3187984357, 3188017272, 3188050044, 3188082776, 3188115584, 3188148359, 3188180967, 3188213904, 3188246674, 3188279447, 3188312219, 3188344991, 3188377762, 3188410540, 3188443312, 3188476083, 3188508851, 3188541987, 3188574393, 3188607164, 3188639934, 3188672705, 3188705476, 3188738248, 3188771018, 3188803789, 3188836559, 3188869330, 3188902107, 3188934876, 3188967641, 3189000430, 3189033184, 3189065950, 3189098722, 3189132432, 3189164272, 3189197045, 3189229820, 3189263506, 3189295360, 3189328126, 3189360620, 3189393670, 3189426440, 3189459212, 3189491982, 3189525652, 3189557523, 3189590293, 3189623065, 3189655840, 3189689494, 3189722263, 3189754148, 3189786922, 3189819375, 3189852466, 3189885236, 3189918872, 3189950778, 3189983548, 3190017267, 3190049946, 3190082292, 3190114632, 3190148257, 3190180173, 3190212595, 3190245719, 3190278490, 3190310902, 3190344031, 3190376800, 3190409574, 3190442342, 3190475115, 3190507885, 3190540656, 3190573426, 3190606198, 3190638973, 3190671749, 3190704516, 3190737286, 3190770061, 3190802831, 3190835594, 3190868369, 3190901141, 3190933497, 3190966684, 3190999464, 3191032235, 3191065007, 3191097339, 3191130234, 3191163312, 3191195675, 3191228862, 3191261637, 3191295141, 3191327179, 3191359950, 3191393447, 3191425485, 3191457794, 3191491030, 3191523802, 3191556572, 3191589343, 3191622112, 3191654883, 3191687658, 3191721130, 3191753899, 3191786668, 3191818747, 3191851517, 3191884293, 3191917059, 3191949831, 3191982592, 3192015374, 3192048147, 3192081582, 3192113689, 3192147120, 3192179231, 3192211997, 3192244768, 3192277540, 3192310921, 3192343729, 3192376499, 3192408629, 3192442036, 3192474175, 3192507574, 3192539723, 3192572492, 3192604681, 3192638035, 3192670806, 3192702986, 3192736951, 3192769720, 3192802457, 3192835257, 3192868027, 3192899596, 3192933565, 3192966334, 3192998521, 3193031290, 3193063438, 3193096208, 3193130177, 3193162373, 3193194514, 3193227922, 3193261252, 3193293470, 3193326240, 3193359010, 3193392326, 3193424552, 3193457863, 3193490093, 3193523400, 3193554966, 3193588406, 3193621706, 3193653271, 3193686726, 3193718808, 3193752781, 3193785550, 3193818319, 3193850584, 3193883856, 3193916128, 3193948898, 3193980954, 3194014443, 3194047212, 3194080467, 3194113187, 3194145524, 3194178440, 3194211072, 3194243841, 3194276610, 3194309380, 3194342613, 3194374919, 3194407693, 3194440462, 3194473240, 3194506000, 3194538770, 3194571992, 3194603551, 3194637084, 3194670300, 3194703065, 3194734625, 3194768603, 3194800937, 3194833707, 3194866479, 3194899249, 3194932020, 3194964787, 3194996771, 3195030328, 3195063100, 3195096285, 3195128642, 3195161413, 3195194590, 3195226151, 3195259731, 3195292513, 3195325664, 3195358059, 3195390836, 3195423884, 3195456375, 3195489421, 3195521923, 3195555044, 3195587476, 3195620581, 3195653025, 3195685797, 3195718569, 3195751654, 3195783209, 3195817191, 3195849665, 3195882439, 3195915498, 3195947052, 3195981035, 3196013804, 3196046296, 3196079341, 3196110894, 3196144620, 3196177392, 3196210414, 3196242941, 3196274736, 3196308482, 3196340274, 3196374257, 3196406797, 3196439571, 3196472342, 3196505114, 3196538100, 557063, 786772, 1196504, 1786422, 2621440, 2703750, 2949126, 3014829, 3260712, 3523161, 3768723, 3934074, 4063780, 4473268, 4637111, 4833738, 5014005, 5227015, 5259594, 5341947, 5505371, 5554718, 5865838, 6275514, 6307850, 6341024, 6406439, 6455307, 6504899, 6586817, 6652376, 6684996, 6717964, 6766931, 6799708, 6832669, 6898224, 6930620, 7029321, 7127054, 7160201, 7192787, 7274723, 7324375, 7356926, 7405869, 7438765, 7471433, 7504207, 7537176, 7569816, 7602641, 7635489, 7668303, 7946695, 7979460, 8159534, 8208838, 8814955, 8847571, 8962293, 8995425, 9027994, 9404783, 9503093, 9552313, 9585109, 9666950, 9716111, 9798067, 9847525, 9882691, 9978307, 10060233, 10109387, 10142219, 10191368, 10240567, 10306083, 10420795, 10519105, 10732125, 10781419, 11010611, 11076015, 11108933, 11141703, 11174404, 11323216, 11436039, 11468806, 11501574, 11567365, 11600269, 11665414, 11714566, 11846522, 11928338, 11960942, 11993703, 12076879, 12124945, 12386310, 12452097, 12665630, 12797129, 12862236, 12960962, 13124400, 13222704, 13484857, 13632282, 13665053, 13714225, 14009146, 14254884, 14419919, 14500630, 14534498, 14598899, 14647556, 14745606, 14958875, 15024433, 15057211, 15090136, 15123182, 15155534, 15221088, 15270247, 15319424, 15499989, 15533931, 15581722, 15614411, 15680081, 15745582, 15796069, 15827709, 16007516, 16073469, 16171861, 16384188, 16515800, 16548261, 16580956, 16630484, 16662822, 16728797, 16845890, 16875992, 17039643, 17072421, 17121585, 17187682, 17220334, 17252667, 17285963, 17318182, 17351045, 17383808, 17498524, 17564047, 17597194, 17646006, 17696610, 17761031, 17793564, 17826350, 17860453, 17892093, 17990420, 18023131, 18156611, 18186699, 18268618, 18367061, 18399652, 18629463, 18661923, 18694737, 18825816, 19055465, 19087780, 19153461, 19186237, 19252071, 19284727, 19317465, 19383002, 19448590, 19579304, 19613542, 19645021, 19677791, 19841480, 19891059, 19923416, 20022123, 20054797, 20087565, 20120434, 20153096, 20250909, 20316895, 20447443, 20578652, 20988330, 21168439, 21234400, 21414450, 21447253, 22087343, 22218416, 22266214, 22364500, 22495238, 22594098, 22775659, 22889196, 22971130, 23020108, 23134650, 23184251, 23528219, 23659397, 23806850, 23839524, 24003361, 24117720, 24150817, 24183585, 24461784, 24526854, 24658460, 24920537, 24952838, 25314002, 25378989, 25559835, 25593026, 25756817, 25789607, 25871543, 26166421, 26329628, 26395431, 26460970, 26526493, 26592041, 26624798, 26657564, 26690347, 26738868, 26821148, 26870510, 26919085, 26951844, 26985687, 27050514, 27100305, 27133095, 27231415, 27345871, 27444435, 27542496, 27657189, 27771939, 27903028, 27935812, 28001358, 28132436, 28214419, 28263607, 28328476, 28361643, 28427405, 28460171, 28509331, 28574924, 28607635, 28656156, 28967712, 29066198, 29098164, 29131972, 29164742, 29197511, 29229806, 29311898, 29344288, 29393076, 29425921, 30425889, 30524187, 30770326, 30983246, 31065212, 31130595, 31228878, 32506196, 33211166, 33260316, 33357830, 33456526, 36242649, 36308133, 36406231, 36439260, 36471783, 36553919, 36603031, 36635863, 37307584, 37389089, 37438253, 37487399, 37520577, 37618490, 37651261, 37749563, 37782305, 37815530, 37881071, 37979373, 38028523, 38061288, 38732589, 39109402, 39404791, 39437512, 39863072, 40829741, 41436416, 41582837, 41976609, 42042656, 42075428, 42108199, 42206497, 42926893, 43205877, 43630843, 44057376, 44089601, 44254508, 44303662, 44352810, 45024045, 45106418, 45303029, 45745400, 45778170, 45876476, 46186741, 46661972, 47808852, 48268068, 48694584, 49251633, 49448264, 49481033, 49661251, 49923385, 49972538, 50005307, 50038064, 50103612, 50152779, 50201926, 50332448, 50382078, 50660690, 50890070, 51070297, 51234142, 51414369, 51905871, 52003356, 52462108, 52512076, 52544846, 52741459, 52888916, 53003607, 53232988, 54019430, 54117731, 54248805, 54559260, 54658407, 54691125, 54789480, 54855018, 54986040, 55100734, 55379268, 55477565, 55575879, 55625077, 55805181, 56082987, 56197660, 56280428, 56312059, 56345966, 56509805, 56558962, 56673534, 58361207, 58443130, 58901887, 59049340, 59098497, 59344263, 59786641, 59982628, 60949886, 61244802, 61293956, 61326726, 61736335, 61998482, 62031252, 62079780, 62326169, 62916004, 62965153, 63145372, 63407520, 63816495, 64865701, 66291103, 66422954, 67371564, 67618218, 67798446, 68027646, 68339120, 68781484, 68945321, 69223848, 69733441, 71090923, 71321012, 71435791, 71517768, 71632320, 71665091, 71697883, 71730675, 71763455, 71796233, 71829003, 71861778, 71894548, 71927318, 71960090, 71992861, 72025616, 72058401, 72091171, 72123941, 72156716, 72189486, 72222256, 72255026, 72304221, 72369717, 72402489, 72435259, 72468028, 72500798, 72533569, 72599141, 72648267, 72713809, 72746588, 72795588, 72828360, 72910446, 72975992, 73041535, 73074314, 73139864, 73189338, 73222198, 73254508, 73287281, 73320054, 73352827, 73385603, 73418374, 73451145, 73483919, 73516689, 73549460, 73582231, 73615003, 73647777, 73680547, 73713318, 73746399, 73779170, 73812024, 73844800, 73877570, 73926316, 73959085, 73991794, 74024577, 74057139, 74089913, 74122693, 74155466, 74188247, 74221029, 74253809, 74286579, 74319368, 74352159, 74384948, 74417738, 74450510, 74483286, 74515898, 74548673, 74581449, 74614219, 74647001, 74679772, 74712543, 74745315, 74778091, 74810861, 74843632, 74876409, 74909183, 74941954, 74974725, 75007491, 75040285, 75073048, 75105826, 75138597, 75171381, 75204156, 75236962, 75269699, 75302483, 75335256, 75368026, 75400629, 75433403, 75466173, 75497841, 76546892, 77923196, 82313515, 82346343, 82395595, 82461061, 82493760, 82542872, 82592578, 82690379, 82788741, 82821859, 82870728, 82953070, 83034485, 83083676, 83165566, 83247478, 83329351, 83394912, 83476906, 83591401, 83657161, 83689765, 83821322, 83936070, 84100788, 84132679, 84166326, 84199095, 84231865, 84281018, 84313788, 84592309, 84625080, 84656502, 84721939, 84803845, 84853115, 84886255, 84920000, 84951319, 84985537, 85016840, 85051075, 85131633, 85262991, 85328243, 85361528, 85395140, 85427912, 85460682, 85493453, 85526222, 85590903, 85624524, 85705130, 85770657, 85804752, 85836335, 85870289, 85901742, 85935826, 85967263, 86000034, 86081889, 86114717, 86278806, 86311575, 86344344, 86377080, 86409881, 86442650, 86475419, 86590274, 86704796, 86737538, 86770333, 86803102, 86835871, 86868601, 86901408, 87015612, 87197408, 87230181, 87262950, 87491240, 87524009, 87556778, 87589547, 87622316, 87655085, 87687854, 87917231, 87950000, 87982769, 88015538, 88048307, 88081076, 88113845, 88278775, 88311544, 88457910, 88490679, 88523448, 88556217, 88588986, 88621755, 88654524, 88851061, 88883901, 88916670, 88949439, 88982208, 89014977, 89047746, 89130687, 89359049, 89572042, 89785035, 90031979, 90128985, 90162957, 90195728, 90228494, 90261269, 90408734, 90441504, 90474273, 90507043, 91029812, 91081797, 91308315, 91914539, 92012875, 92309208, 92341991, 92833544, 92866315, 93045582, 93143886, 94077529, 94111517, 94144294, 94177063, 94209833, 94242604, 94275374, 94308144, 94371840, 95273344, 95455035, 95764803, 95814477, 96125556, 96157861, 97322824, 99141449, 99485068, 99517838, 99796370, 99943817, 100025750, 100712705, 102057802, 103596748, 103695054, 103908002, 103940771, 103973540, 104006309, 104039107, 104071877, 104104647, 104253265, 104285885, 104384279, 104417050, 104498986, 104597301, 108267346, 111150932, 111822677, 112002275, 114591575, 114689881, 114804571, 119523165, 120881950, 120931123, 120996660, 121045810, 121078574, 121700773, 121815768, 121881116, 121913884, 122094156, 122176085, 122225377, 122274566, 122308451, 122652493, 123388493, 123454008, 123503164, 123535938, 123568739, 123650515, 123683377, 123716357, 123929181, 124241768, 124684108, 125536214, 128352665, 128418517, 132072281, 133136843, 133169767, 134055603, 134184966, 134234112, 134266880, 134299648, 134332416, 134365184, 134480046, 134512814, 134547418, 134578686, 134627334, 134660102, 134692871, 134775412, 134824118, 134857332, 134889472, 135004882, 135036934, 135069704, 135102471, 135151877, 135200770, 135282872, 135332024, 135397657, 135495844, 135578215, 135692545, 135759828, 136053456, 136855850, 136921657, 136987007, 137038914, 137068912, 137315193, 138412432, 138445094, 138494374, 138527456, 138560112, 138592571, 138625339, 138658250, 138691032, 138723800, 138772817, 138838370, 138871140, 138953065, 139035470, 139067783, 139100483, 139149606, 139182518, 139215156, 139264540, 139297673, 139330443, 139378997, 139412061, 139445067, 139480118, 139512962, 139559338, 139592139, 139985368, 140018176, 140050806, 140083577, 140116476, 140149121, 140181831, 140214571, 140247499, 140280269, 140313169, 140345939, 140378576, 140411482, 140444120, 140476842, 140559069, 140789565, 141379446, 141526849, 142608333, 142705478, 142885602, 142934184, 142967178, 143000174, 143098399, 143213054, 143278966, 143311617, 143344387, 143394692, 143524016, 143917326, 144032015, 144081165, 144230282, 144359701, 144754373, 145064284, 145178972, 145312820, 145769041, 145801585, 145834612, 146112776, 146145543, 146507733, 146607171, 146802555, 147474363, 147867608, 148277059, 148359058, 148408189, 148439665, 148505193, 148555646, 148685259, 148718424, 148753476, 148783962, 148883259, 149407798, 149506102, 149653558, 149981089, 150046627, 150931372, 150964154, 152569789, 152602559, 152635329, 152668099, 152700869, 152895551, 152928276, 152961046, 152993816, 153026586, 153059401, 153092161, 153124931, 153157701, 153190471, 153223643, 153256149, 153288933, 153321713, 153354493, 153387519, 153420256, 153453030, 153485804, 153518578, 153550900, 153583670, 153616440, 153649210, 153681980, 153714750, 153747535, 153780299, 153813069, 153845840, 153878610, 153911380, 153944150, 154010220, 154255981, 154832005, 155207642, 155436975, 157040824, 157419391, 157614867, 157763507, 158091179, 158189462, 158238646, 158353301, 158500674, 158566301, 158958190, 159647668, 160171985, 160663433, 161107075, 162202876, 165298338, 165396755, 165462178, 165495398, 166035630, 166759476, 166872775, 166936970, 167380923, 172671577, 173590335, 173770560, 174475220, 175081373, 175196062, 175475803, 175964419, 175997115, 176046474, 176180313, 176211851, 176244623, 176358148, 176685333, 176717993, 176750759, 176783531, 176832690, 176931417, 177162138, 177194880, 177963282, 178077962, 178864404, 178964274, 180273338, 184223602, 184256372, 186237245, 186712898, 186745670, 186797123, 186941912, 186991320, 187040075, 187138388, 187171659, 187220526, 187253157, 187302277, 187351805, 187384662, 187450200, 187596974, 187646828, 187859195, 187908843, 187974415, 188154718, 188368823, 189546844, 189661558, 189694686, 189923968, 190038488, 190120276, 190202633, 190317382, 192546666, 192627520, 192774974, 193364145, 193446506, 193578693, 193609891, 193644421, 193692568, 193741428, 193807255, 193939412, 193986825, 195086412, 195184777, 195266666, 195299605, 195348791, 195397974, 195430779, 195479974, 195545581, 195578356, 195611170, 195659845, 195742414, 195807930, 195857102, 195988197, 196070142, 196102952, 196135755, 196168527, 196234097, 196283275, 196332469, 196365228, 196397998, 196447157, 196496339, 196529119, 196561890, 196611049, 196676602, 196742156, 196790687, 196823522, 196856487, 196905038, 197134298, 197165450, 197199947, 197232722, 197265493, 197298287, 197331063, 197363838, 197396615, 197429396, 197462177, 197494948, 197527726, 197560500, 197593271, 197626042, 197658845, 197691635, 197724407, 197757193, 197789969, 197822741, 197855517, 197888297, 197921069, 197953845, 197986616, 198019396, 198052167, 198084940, 198117717, 198150521, 198183319, 198216094, 198248866, 198281637, 198314419, 198347201, 198380001, 198412777, 198445550, 198478320, 198511091, 198543923, 198576695, 198609465, 198642236, 198675016, 198707802, 198740573, 198773344, 198806119, 198838895, 198871665, 198904438, 198937216, 198969995, 199002780, 199035558, 199068336, 199101131, 199133908, 199166681, 199199453, 199232228, 199265006, 199297778, 199330550, 199363321, 199396092, 199428903, 199461690, 199494461, 199527245, 199560017, 199592804, 199625576, 199658354, 199691126, 199723902, 199756681, 199789457, 199822236, 199855007, 199887791, 199920574, 199953348, 199986125, 200018895, 200051668, 200084439, 200117216, 200149994, 200182765, 200215540, 200248311, 200281081, 200313851, 200346631, 200379403, 200412174, 200444946, 200477716, 200510489, 200543260, 200576032, 200608805, 201360153, 201441620, 201476028, 201654288, 201754567, 202048291, 202162360, 202246310, 202279080, 203850532, 203885656, 204015702, 204130452, 204605614, 204701880, 204867545, 205439604, 206325171, 206357945, 206390725, 206423498, 206456278, 206489057, 206521836, 206554610, 206587384, 206620175, 206652959, 206685748, 206718536, 206751309, 206784080, 206816874, 206849647, 206882420, 206915193, 206947966, 206980736, 207013515, 207046294, 207079068, 207112154, 207145014, 207177152, 207209924, 207242713, 207275496, 207308278, 207341057, 207373827, 207406603, 207439379, 207472156, 207504912, 207537697, 207570469, 207603295, 207636066, 207668803, 207701596, 207734414, 207767186, 207799972, 207832744, 208930778, 208978104, 209029197, 209110971, 209193035, 209731674, 209764446, 209797218, 209829990, 209862765, 209895537, 209928309, 209961051, 209993823, 210026595, 210059367, 210092142, 210124914, 210157686, 210190440, 210239607, 210272377, 210305148, 210337912, 210370682, 210403471, 210436244, 210469025, 210501774, 210534544, 210567302, 210600095, 210632834, 210665607, 210698393, 210731168, 210763932, 210796701, 212861461, 212893914, 212926695, 212959475, 212992255, 213025282, 215351647, 215384274, 215417058, 215449838, 215482618, 215515642, 215548383, 215581157, 215613931, 215646705, 215679180, 215711936, 215744708, 217596112, 217628896, 217661676, 217694456, 217727480, 217760221, 217792996, 217825770, 217858544, 217891019, 217923775, 217956547, 217989318, 218022088, 218054870, 242010091, 285739008, 328040842, 335579227, 358074570, 370854119, 373278953, 395086129, 411961721, 429443087, 431851514, 436193734, 445237717, 504613506, 539593144, 540281273, 541084091, 542132970, 558877465, 579307558, 585354080, 593595253, 598133628, 607619987, 632163263, 666143762, 690293806, 690457640, 690621483, 690752562, 690981933, 691014703, 691274021, 691306922, 691339623, 691388739, 691437862, 691470727, 691505008, 691536206, 691568998, 691618542, 691650875, 691716480, 691750712, 691783629, 691815134, 691847508, 691880822, 691929268, 691962038, 692011265, 692044041, 697368764, 697418187, 697566053, 697959177, 698286879, 698696137, 698761759, 698893153, 699156431, 699630318, 699908470, 700170428, 700203806, 700777240, 701104488, 701252158, 701284754, 701317524, 701350293, 701383062, 701415830, 701448599, 701661532, 701727065, 701923516, 702185683, 702251259, 702414853, 702693633, 702792308, 702939444, 702975071, 703021835, 703054412, 703351860, 703381888, 703414997, 703447896, 704021230, 704054001, 705430774, 710428112, 710460883, 710493656, 710526429, 710559205, 710591977, 710624751, 710657529, 710690305, 710723099, 710755883, 710788674, 710821455, 710854235, 711231436, 712084553, 714394698, 718045622, 718225948, 718258933, 718308071, 718406196, 718504524, 718586710, 718701149, 718832372, 719062879, 719095653, 719143371, 719243105, 719291117, 719340084, 719438599, 719553059, 719635040, 719782480, 719880960, 720011838, 720093605, 720208600, 904660613, 904693388, 904726160, 904758941, 904791717, 904824796, 904857566, 904890426, 904923196, 904955967, 904988740, 905021099, 905119174, 905151949, 905184721, 905217491, 905250261, 905283034, 905315812, 905348586, 905381367, 905414138, 905446910, 905479684, 905512461, 905545231, 905578009, 905610788, 905643561, 905676339, 905709103, 905741811, 905774689, 905807460, 905840198, 905872978, 1044384613, 1044417406, 1044449812, 1044482235, 1044515877, 1044548511, 1044580601, 1044613742, 1044646694, 1044679492, 1044711896, 1044744745, 1044777750, 1044810735, 1044842673, 1044875825, 1044909061, 1044941339, 1044974408, 1045007085, 1045039422, 1045072388, 1045105555, 1045137537, 1045170582, 1045203504, 1045236441, 1045269290, 1045302216, 1045335044, 1045367446, 1045400335, 1045433352, 1045465325, 1045498543, 1045531197, 1045564268, 1045596396, 1045629401, 1045662230, 1045695176, 1045727385, 1045760132, 1045793439, 1045826325, 1045859167, 1045891544, 1045923912, 1045956955, 1045989990, 1046022793, 1046055251, 1046088186, 1046121149, 1046153446, 1046186775, 1046219242, 1046252024, 1046285061, 1046317669, 1046349938, 1046383055, 1046416123, 1046449054, 1046481090, 1046513986, 1046546972, 1046580145, 1046613001, 1046644884, 1046677988, 1046710582, 1046743411, 1046776344, 1046809175, 1046842053, 1046875009, 1046907788, 1046939788, 1046972615, 1047006015, 1047038269, 1047071111, 1047104174, 1047136345, 1047169294, 1047202140, 1047235156, 1047268063, 1047301061, 1047333849, 1047366298, 1047399359, 1047431249, 1047464207, 1047497121, 1047530030, 1047563037, 1047596066, 1047628726, 1047660999, 1047693826, 1047726672, 1047759492, 1047792604, 1047824759, 1047857251, 1047890442, 1047923025, 1047955916, 1047989180, 1048021183, 1048054186, 1048086994, 1048119887, 1048152785, 1048185667, 1048218562, 1048250897, 1048283695, 1048316705, 1048349694, 1048381897, 1048414959, 1048447658, 1048480323, 1048513374, 1048546057, 1048578187, 1048611200, 1048643851, 1048676785, 1048709947, 1048742733, 1048774762, 1048840421, 1048922245, 1048955507, 1048988308, 1049021081, 1049053875, 1049103139, 1049185165, 1049267173, 1049299943, 1049332627, 1049364575, 1049397356, 1049430171, 1049462992, 1049495768, 1049528552, 1049561373, 1049594217, 1049626993, 1049659815, 1049692624, 1049725452, 1049758252, 1049791057, 1049823886, 1049856655, 1049889426, 1049922199, 1049954976, 1049987755, 1050020551, 1050053328, 1050086129, 1050118910, 1050151750, 1050184540, 1050217326, 1050250123, 1050282947, 1050315739, 1050348722, 1050413109, 1050445937, 1050478699, 1050511511, 1050544336, 1050577103, 1050609893, 1050642680, 1050675458, 1050708287, 1050741070, 1050773857, 1050806627, 1050839400, 1050872184, 1050904974, 1050937755, 1050970556, 1051003332, 1051036138, 1051068949, 1051101719, 1051134508, 1051167285, 1051200069, 1051232860, 1051265643, 1051298419, 1051331191, 1051363963, 1051396773, 1051429553, 1051462341, 1051495131, 1051527956, 1051560775, 1051593550, 1051626330, 1051659100, 1051691865, 1051724641, 1051757443, 1051790234, 1051823035, 1051855820, 1051888598, 1051921371, 1051954213, 1051987100, 1052018690, 1052051470, 1052085449, 1052118045, 1052770747, 1052803519, 1052836285, 1053082496, 1053115262, 1053148031, 1053311878, 1053344652, 1053377423, 1053410194, 1053442224, 1053508499, 1053557639, 1053967252, 1054082250, 1054115020, 1054147788, 1054180535, 1054213303, 1054246094, 1054278862, 1054311627, 1054344395, 1054377165, 1054409933, 1054442648, 1054475416, 1054508248, 1054541016, 1054573786, 1054606554, 1054639312, 1054672080, 1054704847, 1054737615, 1054770385, 1054803153, 1054835922, 1054868690, 1054901461, 1054934228, 1054966745, 1054999512, 1055032293, 1055065056, 1055097924, 1055130692, 1055163612, 1055196380, 1055229151, 1055261919, 1055294686, 1055327454, 1055360147, 1055392920, 1055425688, 1055458530, 1055490588, 1055523356, 1055556124, 1055588892, 1055622291, 1055655137, 1056228430, 1056261198, 1056294029, 1056326796, 1056359568, 1056392334, 1056425106, 1056457952, 1056490635, 1056523468, 1056556236, 1056588947, 1056621736, 1056654505, 1056687281, 1056720051, 1056752818, 1056785588, 1056818358, 1056851125, 1056883893, 1056916627, 1056949395, 1056982187, 1057014965, 1057047454, 1057080225, 1057112999, 1057145774, 1057178548, 1057211327, 1057244345, 1057277118, 1057309633, 1057342409, 1057375185, 1057407955, 1057440750, 1057473523, 1057506319, 1057539093, 1057571866, 1057604638, 1057637415, 1057670191, 1057702965, 1057735735, 1057768507, 1057801277, 1057834051, 1057866821, 1057899591, 1057932361, 1057965133, 1057997910, 1058030685, 1058063458, 1058096231, 1058129009, 1058161783, 1058194337, 1058227331, 1058260105, 1058292261, 1058325033, 1058358425, 1058391197, 1058423972, 1058456539, 1058489510, 1058524239, 1058557010, 1058589782, 1058620590, 1058653360, 1058686133, 1058718628, 1058751398, 1058784167, 1058816952, 1058849726, 1058882495, 1058915515, 1058948285, 1058981054, 1059013691, 1059046467, 1059079241, 1059112013, 1059144799, 1059177570, 1059210357, 1059243141, 1059275912, 1059308681, 1059341470, 1059374240, 1059407012, 1059439786, 1059472556, 1059505321, 1059537823, 1059570597, 1059603374, 1059636148, 1059668909, 1059701697, 1059734473, 1059767249, 1059800043, 1059832816, 1059865607, 1059898383, 1059931157, 1059963930, 1059996709, 1060029482, 1060062256, 1060095030, 1060127801, 1060160575, 1060193351, 1060226121, 1060258902, 1060291677, 1060324435, 1060357228, 1060390005, 1060422784, 1060455558, 1060487717, 1060520490, 1060553883, 1060586656, 1060619439, 1060651941, 1060684729, 1060717756, 1060750323, 1060783105, 1060815945, 1060848735, 1060881530, 1060914324, 1060949075, 1060979746, 1061012525, 1061045299, 1061078007, 1061110788, 1061143501, 1061176263, 1061209044, 1061241873, 1061274651, 1061307386, 1061340159, 1061372928, 1061405707, 1061438498, 1061471277, 1061504051, 1061536759, 1061569540, 1061602253, 1061635015, 1061667796, 1061700625, 1061733403, 1061766138, 1061798911, 1061831680, 1061864459, 1061897210, 1061929983, 1061962729, 1061995550, 1062028270, 1062061050, 1062093823, 1062126629, 1062158857, 1062191266, 1062486962, 1062519731, 1062552506, 1062585276, 1062618053, 1062650828, 1062683628, 1062716405, 1062749172, 1062781942, 1062814728, 1062847485, 1062880251, 1062913026, 1062945795, 1062978584, 1063011359, 1063044128, 1063076904, 1063109675, 1063142449, 1063175218, 1063207992, 1063240769, 1063273564, 1063306327, 1063339102, 1063371872, 1063404653, 1063437424, 1063470186, 1063502963, 1063567911, 1063601281, 1063634046, 1063666815, 1063699591, 1063732385, 1063764912, 1063797686, 1063830461, 1063863238, 1063896006, 1063928841, 1063961622, 1063994465, 1064027290, 1064060022, 1064092802, 1064125531, 1064158284, 1064191092, 1064223819, 1064256637, 1064289226, 1064322106, 1064354891, 1064387600, 1064420479, 1065108542, 1065140752, 1065174028, 1065206825, 1065239565, 1065272259, 1065764821, 1066157998, 1066223525, 1066256295, 1066289065, 1066550035, 1066582803, 1066615182, 1066647950, 1067057546, 1067467675, 1067500051, 1067533457, 1067565586, 1067599015, 1067631783, 1067663832, 1067697052, 1067729820, 1067762603, 1067795372, 1067828140, 1067861175, 1067893943, 1067926464, 1067959232, 1067992008, 1068024776, 1068057552, 1068090320, 1068123093, 1068155866, 1068188636, 1068221414, 1068254184, 1068286952, 1068319736, 1068352504, 1068385285, 1068418053, 1068450835, 1068483603, 1068516380, 1068549148, 1068581924, 1068614692, 1068647462, 1068680230, 1068713006, 1068745774, 1068778548, 1068811316, 1068844092, 1068876860, 1068909636, 1068942404, 1068975183, 1069007951, 1069040740, 1069073508, 1069106297, 1069139065, 1069171228, 1069203996, 1069237386, 1069270163, 1069302931, 1069335699, 1069368405, 1069401170, 1069433937, 1069466704, 1069563905, 1069662214, 1069973761, 1070104869, 1070154033, 1070219736, 1070252355, 1070301518, 1070334304, 1070399847, 1070481797, 1070514192, 1070547106, 1070596102, 1070645669, 1070711235, 1070744011, 1070793176, 1070858798, 1070957137, 1071006301, 1071089623, 1073267475, 1073432501, 1080262994, 1080410492, 1080443405, 1084260645, 1084326193, 1084391896, 1084490112, 1084557122, 1084588390, 1084670117, 1084768549, 1084801862, 1084932436, 1085014347, 1085047173, 1085079936, 1085113166, 1085522235, 1085867146, 1086096389, 1086341413, 1086472664, 1086620508, 1086701952, 1086832804, 1086882176, 1089784925, 1090584916, 1090863398, 1091028942, 1091093213, 1091193923, 1091240476, 1091551996, 1091617551, 1091748595, 1093143649, 1093468516, 1093697876, 1093731028, 1093894513, 1093944141, 1094058758, 1094353436, 1094550092, 1095024974, 1095106883, 1095188854, 1095336263, 1116635949, 1117113443, 1128205412, 1148306684, 1148372208, 1148652614, 1153434404, 1158040679, 1158319209, 1158401131, 1160021249, 1160086787, 1160135941, 1160201490, 1160234249, 1160267019, 1160299789, 1160381712, 1160414471, 1160463635, 1160512790, 1160660248, 1160725788, 1160758048, 1160807707, 1160840469, 1161036116, 1161069854, 1165364333, 1165397102, 1165429872, 1167101042, 1170309713, 1170440789, 1177026934, 1177076039, 1177125169, 1177174407, 1177256241, 1177321799, 1177405250, 1177469155, 1177551441, 1177583924, 1177616992, 1177682460, 1177747707, 1177895145, 1177928220, 1178010205, 1178124854, 1178157447, 1178222886, 1178321278, 1186565238, 1186598003, 1186630773, 1186679928, 1186794619, 1186827389, 1186860159, 1194101889, 1287539810, 1539441014, 1539670343, 1539771449, 1539965400, 1540178276, 1540276435, 1540342035, 1540391281];
+pub static PROTOTYPE_STARTS: [u16; 3318] = [0, 1, 2, 4, 6, 8, 9, 10, 12, 15, 19, 21, 23, 25, 27, 29, 31, 32, 34, 37, 41, 44, 47, 50, 53, 56, 59, 62, 65, 68, 71, 74, 77, 80, 83, 86, 89, 92, 95, 98, 101, 104, 107, 110, 113, 116, 119, 122, 125, 128, 131, 134, 137, 140, 143, 146, 149, 152, 155, 158, 161, 164, 167, 170, 173, 177, 181, 185, 189, 193, 197, 201, 205, 209, 213, 216, 219, 222, 225, 228, 232, 235, 238, 241, 244, 247, 250, 253, 256, 259, 263, 266, 270, 273, 277, 280, 284, 287, 291, 294, 298, 301, 305, 308, 312, 319, 325, 328, 332, 336, 339, 343, 346, 350, 353, 357, 360, 364, 367, 371, 374, 377, 380, 383, 386, 389, 392, 395, 398, 401, 404, 407, 410, 413, 416, 419, 422, 425, 428, 431, 434, 437, 440, 443, 446, 449, 452, 455, 458, 461, 464, 467, 470, 473, 476, 479, 482, 485, 488, 491, 494, 497, 500, 501, 503, 504, 505, 507, 509, 511, 513, 515, 517, 519, 520, 521, 523, 525, 527, 529, 531, 532, 534, 536, 539, 540, 542, 545, 547, 548, 550, 552, 555, 558, 561, 564, 567, 570, 573, 576, 579, 582, 585, 588, 591, 594, 597, 600, 602, 604, 606, 608, 609, 611, 613, 616, 619, 621, 623, 625, 627, 628, 630, 632, 634, 636, 638, 640, 641, 643, 645, 647, 649, 651, 652, 654, 656, 658, 660, 662, 663, 665, 667, 669, 671, 673, 674, 676, 678, 680, 682, 684, 685, 687, 689, 691, 693, 695, 696, 699, 701, 702, 703, 705, 708, 710, 711, 713, 716, 718, 720, 722, 724, 727, 729, 731, 732, 734, 736, 739, 741, 742, 744, 746, 747, 749, 751, 753, 755, 757, 759, 761, 763, 765, 766, 767, 769, 771, 773, 775, 776, 778, 781, 783, 786, 788, 789, 791, 793, 794, 797, 799, 800, 802, 804, 806, 807, 809, 811, 813, 815, 816, 818, 820, 821, 823, 825, 827, 828, 830, 832, 834, 835, 837, 839, 840, 842, 844, 846, 848, 850, 851, 853, 855, 857, 859, 861, 863, 865, 867, 869, 872, 874, 875, 877, 878, 880, 881, 883, 884, 885, 887, 890, 892, 894, 896, 898, 900, 902, 904, 905, 907, 909, 911, 913, 914, 916, 918, 921, 925, 927, 929, 931, 932, 934, 935, 937, 940, 942, 944, 945, 947, 948, 950, 952, 953, 955, 957, 958, 959, 960, 963, 966, 968, 970, 972, 974, 976, 978, 980, 982, 984, 986, 987, 989, 991, 993, 995, 997, 1000, 1002, 1004, 1005, 1008, 1011, 1013, 1015, 1016, 1018, 1020, 1023, 1025, 1027, 1029, 1031, 1033, 1035, 1037, 1040, 1041, 1043, 1045, 1047, 1048, 1050, 1053, 1056, 1058, 1060, 1062, 1064, 1066, 1067, 1069, 1071, 1073, 1075, 1076, 1078, 1080, 1081, 1083, 1086, 1088, 1090, 1092, 1094, 1096, 1098, 1099, 1101, 1102, 1104, 1105, 1107, 1109, 1111, 1114, 1117, 1120, 1123, 1126, 1129, 1132, 1135, 1138, 1141, 1144, 1147, 1150, 1153, 1156, 1159, 1162, 1165, 1168, 1171, 1174, 1177, 1180, 1183, 1186, 1188, 1190, 1193, 1196, 1199, 1202, 1204, 1206, 1208, 1210, 1213, 1216, 1219, 1222, 1225, 1227, 1229, 1231, 1233, 1235, 1237, 1239, 1241, 1243, 1247, 1253, 1255, 1259, 1265, 1267, 1269, 1271, 1273, 1275, 1276, 1278, 1280, 1282, 1284, 1285, 1287, 1289, 1291, 1293, 1295, 1297, 1299, 1301, 1303, 1305, 1308, 1311, 1313, 1315, 1318, 1320, 1322, 1323, 1325, 1327, 1329, 1330, 1332, 1333, 1335, 1337, 1340, 1343, 1346, 1348, 1350, 1352, 1354, 1355, 1358, 1360, 1362, 1364, 1365, 1367, 1369, 1371, 1373, 1375, 1377, 1379, 1381, 1382, 1384, 1386, 1388, 1390, 1391, 1393, 1396, 1400, 1401, 1403, 1405, 1408, 1409, 1411, 1414, 1416, 1417, 1419, 1421, 1422, 1424, 1426, 1428, 1430, 1431, 1432, 1433, 1435, 1437, 1439, 1440, 1441, 1442, 1443, 1445, 1447, 1449, 1451, 1452, 1453, 1455, 1457, 1459, 1461, 1463, 1465, 1467, 1469, 1471, 1474, 1476, 1479, 1481, 1483, 1485, 1487, 1489, 1491, 1493, 1495, 1497, 1499, 1501, 1503, 1505, 1507, 1509, 1511, 1513, 1515, 1517, 1519, 1521, 1523, 1525, 1527, 1529, 1531, 1533, 1535, 1537, 1539, 1541, 1543, 1545, 1547, 1549, 1551, 1553, 1555, 1557, 1559, 1561, 1563, 1565, 1567, 1569, 1571, 1573, 1575, 1577, 1579, 1581, 1583, 1585, 1587, 1589, 1591, 1593, 1595, 1597, 1599, 1601, 1603, 1605, 1607, 1609, 1611, 1613, 1615, 1617, 1619, 1621, 1623, 1625, 1627, 1629, 1631, 1633, 1635, 1637, 1638, 1641, 1645, 1650, 1651, 1652, 1653, 1654, 1655, 1657, 1659, 1660, 1662, 1663, 1664, 1665, 1666, 1667, 1668, 1669, 1670, 1671, 1673, 1676, 1679, 1681, 1682, 1683, 1684, 1685, 1686, 1688, 1690, 1692, 1693, 1694, 1696, 1698, 1699, 1701, 1702, 1703, 1704, 1705, 1706, 1707, 1709, 1710, 1711, 1712, 1714, 1717, 1721, 1723, 1724, 1725, 1727, 1728, 1729, 1730, 1731, 1733, 1735, 1736, 1737, 1738, 1739, 1740, 1742, 1745, 1746, 1747, 1748, 1749, 1750, 1751, 1752, 1753, 1754, 1755, 1757, 1758, 1759, 1760, 1761, 1763, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772, 1773, 1774, 1775, 1776, 1777, 1778, 1779, 1780, 1781, 1782, 1783, 1784, 1785, 1786, 1787, 1788, 1789, 1790, 1791, 1792, 1793, 1795, 1797, 1799, 1800, 1802, 1804, 1806, 1807, 1808, 1809, 1810, 1811, 1812, 1813, 1815, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1825, 1827, 1828, 1829, 1830, 1831, 1832, 1834, 1835, 1837, 1840, 1841, 1843, 1844, 1846, 1848, 1851, 1852, 1854, 1855, 1856, 1857, 1859, 1862, 1863, 1864, 1866, 1867, 1868, 1869, 1871, 1873, 1874, 1875, 1877, 1878, 1880, 1882, 1884, 1886, 1888, 1889, 1890, 1891, 1892, 1893, 1895, 1897, 1898, 1899, 1900, 1901, 1903, 1904, 1905, 1906, 1907, 1908, 1911, 1913, 1914, 1915, 1916, 1917, 1918, 1920, 1922, 1923, 1925, 1927, 1929, 1932, 1934, 1937, 1939, 1941, 1943, 1945, 1947, 1949, 1951, 1953, 1954, 1955, 1957, 1959, 1962, 1965, 1967, 1970, 1973, 1975, 1978, 1981, 1983, 1985, 1987, 1990, 1993, 1996, 1999, 2001, 2003, 2004, 2006, 2009, 2017, 2019, 2022, 2025, 2027, 2028, 2030, 2033, 2035, 2038, 2040, 2042, 2044, 2045, 2047, 2049, 2051, 2053, 2054, 2056, 2059, 2061, 2063, 2064, 2066, 2067, 2069, 2071, 2074, 2076, 2080, 2084, 2086, 2088, 2090, 2091, 2093, 2094, 2096, 2098, 2100, 2103, 2106, 2108, 2111, 2113, 2116, 2118, 2120, 2123, 2126, 2129, 2131, 2133, 2136, 2139, 2143, 2146, 2150, 2154, 2157, 2160, 2163, 2167, 2171, 2174, 2175, 2178, 2180, 2183, 2186, 2188, 2190, 2194, 2197, 2215, 2217, 2220, 2222, 2224, 2225, 2227, 2229, 2232, 2234, 2237, 2239, 2241, 2243, 2244, 2246, 2248, 2251, 2254, 2257, 2259, 2261, 2262, 2264, 2265, 2267, 2270, 2274, 2276, 2279, 2282, 2284, 2285, 2287, 2289, 2292, 2295, 2297, 2298, 2300, 2302, 2304, 2307, 2309, 2312, 2314, 2315, 2317, 2320, 2322, 2325, 2328, 2331, 2333, 2334, 2336, 2338, 2340, 2342, 2344, 2346, 2349, 2352, 2354, 2356, 2357, 2359, 2362, 2365, 2367, 2369, 2372, 2374, 2377, 2380, 2383, 2385, 2388, 2391, 2393, 2396, 2398, 2401, 2404, 2406, 2408, 2409, 2411, 2413, 2415, 2418, 2421, 2424, 2427, 2429, 2432, 2435, 2439, 2442, 2444, 2447, 2450, 2453, 2455, 2458, 2460, 2462, 2463, 2465, 2467, 2469, 2472, 2475, 2478, 2480, 2483, 2486, 2488, 2490, 2492, 2494, 2497, 2499, 2501, 2502, 2504, 2506, 2508, 2511, 2515, 2517, 2519, 2521, 2522, 2524, 2526, 2528, 2531, 2533, 2535, 2538, 2540, 2543, 2545, 2547, 2549, 2551, 2554, 2557, 2559, 2561, 2563, 2565, 2567, 2570, 2573, 2576, 2579, 2582, 2585, 2588, 2591, 2594, 2597, 2601, 2605, 2609, 2612, 2615, 2617, 2620, 2623, 2626, 2629, 2632, 2635, 2638, 2641, 2642, 2643, 2644, 2645, 2646, 2647, 2648, 2649, 2650, 2651, 2652, 2653, 2654, 2655, 2656, 2657, 2658, 2659, 2660, 2662, 2663, 2664, 2666, 2667, 2669, 2671, 2672, 2673, 2674, 2676, 2677, 2678, 2679, 2681, 2683, 2684, 2685, 2686, 2688, 2691, 2694, 2697, 2699, 2701, 2703, 2705, 2707, 2710, 2711, 2712, 2713, 2714, 2715, 2716, 2717, 2719, 2720, 2721, 2722, 2723, 2724, 2725, 2726, 2728, 2730, 2731, 2732, 2733, 2734, 2735, 2736, 2737, 2738, 2739, 2740, 2741, 2742, 2743, 2744, 2745, 2746, 2747, 2748, 2749, 2750, 2751, 2752, 2753, 2754, 2756, 2758, 2759, 2760, 2761, 2762, 2763, 2765, 2767, 2769, 2771, 2773, 2775, 2777, 2779, 2781, 2784, 2787, 2790, 2792, 2794, 2796, 2798, 2799, 2800, 2802, 2803, 2805, 2807, 2808, 2810, 2811, 2812, 2813, 2815, 2817, 2819, 2820, 2822, 2823, 2825, 2826, 2827, 2828, 2829, 2830, 2831, 2833, 2835, 2837, 2838, 2839, 2840, 2841, 2843, 2845, 2846, 2848, 2850, 2851, 2852, 2854, 2855, 2857, 2859, 2860, 2862, 2863, 2864, 2866, 2868, 2870, 2872, 2874, 2875, 2876, 2877, 2879, 2881, 2883, 2885, 2887, 2888, 2891, 2893, 2895, 2898, 2901, 2902, 2904, 2907, 2910, 2911, 2913, 2914, 2915, 2917, 2919, 2920, 2921, 2922, 2923, 2924, 2925, 2926, 2927, 2928, 2929, 2930, 2931, 2932, 2933, 2934, 2935, 2936, 2937, 2938, 2939, 2940, 2941, 2943, 2944, 2945, 2946, 2947, 2948, 2949, 2950, 2952, 2954, 2955, 2957, 2959, 2961, 2963, 2965, 2969, 2973, 2974, 2977, 2980, 2983, 2985, 2987, 2989, 2991, 2994, 2996, 2998, 3003, 3004, 3006, 3008, 3010, 3011, 3013, 3015, 3017, 3019, 3021, 3023, 3026, 3028, 3030, 3032, 3033, 3035, 3037, 3039, 3041, 3043, 3045, 3047, 3049, 3051, 3053, 3055, 3056, 3058, 3060, 3063, 3065, 3067, 3069, 3071, 3074, 3076, 3078, 3080, 3081, 3083, 3086, 3089, 3092, 3094, 3096, 3099, 3102, 3104, 3107, 3109, 3112, 3115, 3118, 3120, 3123, 3126, 3129, 3132, 3135, 3138, 3140, 3143, 3145, 3147, 3149, 3151, 3153, 3155, 3157, 3159, 3161, 3164, 3165, 3167, 3169, 3172, 3174, 3176, 3178, 3180, 3183, 3185, 3188, 3190, 3192, 3194, 3196, 3198, 3199, 3201, 3203, 3205, 3207, 3210, 3212, 3214, 3217, 3219, 3222, 3225, 3228, 3231, 3234, 3237, 3239, 3241, 3243, 3245, 3247, 3249, 3251, 3252, 3254, 3256, 3258, 3260, 3262, 3264, 3267, 3270, 3272, 3275, 3278, 3281, 3284, 3286, 3288, 3290, 3292, 3294, 3296, 3298, 3299, 3301, 3304, 3306, 3308, 3310, 3312, 3314, 3316, 3318, 3320, 3322, 3324, 3326, 3328, 3330, 3331, 3333, 3336, 3338, 3340, 3343, 3344, 3346, 3348, 3349, 3350, 3352, 3353, 3355, 3357, 3359, 3361, 3363, 3364, 3366, 3368, 3370, 3372, 3374, 3376, 3378, 3380, 3381, 3383, 3386, 3387, 3389, 3391, 3393, 3395, 3396, 3398, 3399, 3400, 3402, 3404, 3406, 3408, 3409, 3411, 3413, 3415, 3417, 3418, 3420, 3422, 3424, 3426, 3427, 3429, 3431, 3433, 3435, 3436, 3438, 3441, 3443, 3446, 3448, 3451, 3453, 3456, 3458, 3461, 3463, 3465, 3466, 3468, 3471, 3473, 3476, 3478, 3480, 3482, 3483, 3485, 3488, 3490, 3493, 3496, 3498, 3501, 3503, 3505, 3508, 3509, 3511, 3514, 3516, 3519, 3521, 3524, 3526, 3528, 3530, 3531, 3533, 3535, 3538, 3540, 3542, 3544, 3545, 3546, 3547, 3548, 3549, 3551, 3553, 3555, 3557, 3559, 3561, 3563, 3565, 3567, 3569, 3570, 3571, 3573, 3575, 3576, 3578, 3580, 3581, 3583, 3585, 3587, 3589, 3591, 3592, 3594, 3596, 3598, 3600, 3602, 3604, 3606, 3607, 3609, 3611, 3613, 3615, 3616, 3618, 3620, 3622, 3624, 3626, 3628, 3630, 3632, 3634, 3636, 3638, 3640, 3642, 3644, 3645, 3647, 3649, 3651, 3653, 3655, 3656, 3658, 3660, 3662, 3664, 3666, 3668, 3670, 3672, 3674, 3676, 3678, 3680, 3682, 3684, 3686, 3688, 3690, 3692, 3694, 3696, 3698, 3700, 3702, 3704, 3706, 3708, 3709, 3711, 3712, 3713, 3715, 3717, 3719, 3722, 3724, 3726, 3728, 3730, 3732, 3734, 3736, 3738, 3740, 3742, 3744, 3746, 3748, 3750, 3752, 3754, 3756, 3758, 3760, 3762, 3764, 3766, 3768, 3770, 3772, 3774, 3776, 3778, 3780, 3782, 3784, 3786, 3788, 3790, 3791, 3792, 3793, 3795, 3797, 3798, 3799, 3800, 3801, 3802, 3803, 3804, 3805, 3807, 3809, 3810, 3811, 3813, 3814, 3815, 3816, 3817, 3818, 3819, 3820, 3821, 3822, 3823, 3825, 3826, 3827, 3828, 3829, 3830, 3831, 3833, 3835, 3836, 3837, 3838, 3839, 3841, 3843, 3845, 3846, 3847, 3848, 3849, 3850, 3851, 3852, 3853, 3855, 3856, 3857, 3858, 3859, 3860, 3862, 3863, 3864, 3865, 3866, 3867, 3868, 3869, 3870, 3871, 3872, 3874, 3876, 3877, 3879, 3880, 3881, 3883, 3885, 3886, 3887, 3888, 3889, 3891, 3894, 3895, 3896, 3897, 3898, 3899, 3900, 3901, 3902, 3903, 3904, 3905, 3906, 3907, 3908, 3909, 3910, 3911, 3912, 3914, 3915, 3916, 3917, 3918, 3919, 3920, 3921, 3922, 3923, 3924, 3925, 3926, 3927, 3928, 3929, 3930, 3931, 3932, 3933, 3934, 3935, 3936, 3937, 3938, 3939, 3940, 3941, 3942, 3943, 3944, 3945, 3946, 3947, 3948, 3949, 3950, 3951, 3952, 3953, 3954, 3955, 3956, 3957, 3958, 3959, 3960, 3961, 3962, 3963, 3964, 3965, 3966, 3967, 3968, 3969, 3970, 3971, 3972, 3973, 3974, 3975, 3976, 3977, 3978, 3979, 3980, 3981, 3983, 3985, 3988, 3990, 3992, 3994, 3996, 3999, 4002, 4003, 4004, 4005, 4006, 4007, 4008, 4009, 4010, 4011, 4012, 4013, 4014, 4015, 4016, 4017, 4018, 4019, 4020, 4021, 4022, 4023, 4024, 4025, 4026, 4027, 4028, 4029, 4030, 4031, 4032, 4033, 4034, 4035, 4036, 4037, 4038, 4039, 4040, 4041, 4042, 4043, 4044, 4045, 4046, 4047, 4048, 4049, 4050, 4051, 4052, 4053, 4054, 4055, 4056, 4057, 4058, 4059, 4060, 4061, 4062, 4063, 4064, 4065, 4066, 4067, 4068, 4069, 4070, 4071, 4072, 4073, 4074, 4075, 4076, 4077, 4078, 4079, 4080, 4081, 4082, 4083, 4084, 4085, 4087, 4089, 4092, 4095, 4097, 4100, 4102, 4105, 4107, 4109, 4111, 4113, 4115, 4117, 4118, 4119, 4120, 4121, 4122, 4123, 4124, 4125, 4126, 4127, 4128, 4129, 4130, 4131, 4132, 4133, 4134, 4135, 4136, 4137, 4138, 4139, 4140, 4141, 4142, 4143, 4144, 4145, 4146, 4147, 4148, 4149, 4150, 4151, 4152, 4153, 4154, 4155, 4156, 4157, 4158, 4159, 4160, 4161, 4162, 4163, 4164, 4165, 4166, 4167, 4168, 4169, 4170, 4171, 4172, 4173, 4174, 4175, 4176, 4177, 4178, 4179, 4180, 4181, 4182, 4183, 4184, 4185, 4186, 4187, 4188, 4189, 4190, 4191, 4192, 4193, 4194, 4195, 4196, 4197, 4198, 4199, 4200, 4201, 4202, 4203, 4204, 4205, 4206, 4207, 4208, 4209, 4210, 4211, 4212, 4213, 4214, 4215, 4216, 4217, 4218, 4219, 4220, 4221, 4222, 4223, 4224, 4225, 4226, 4227, 4228, 4229, 4230, 4231, 4232, 4233, 4234, 4235, 4236, 4237, 4238, 4239, 4240, 4241, 4242, 4243, 4244, 4245, 4246, 4247, 4248, 4249, 4250, 4251, 4252, 4253, 4254, 4255, 4256, 4257, 4258, 4259, 4260, 4261, 4262, 4263, 4264, 4265, 4266, 4267, 4268, 4269, 4270, 4271, 4272, 4273, 4274, 4275, 4276, 4277, 4278, 4279, 4280, 4281, 4282, 4283, 4284, 4285, 4286, 4287, 4288, 4289, 4290, 4291, 4292, 4293, 4294, 4295, 4296, 4297, 4298, 4299, 4300, 4301, 4302, 4303, 4304, 4305, 4306, 4307, 4308, 4309, 4310, 4311, 4312, 4313, 4314, 4315, 4316, 4317, 4318, 4319, 4320, 4321, 4322, 4323, 4324, 4325, 4326, 4327, 4328, 4329, 4330, 4331, 4332, 4333, 4334, 4335, 4336, 4337, 4338, 4339, 4340, 4341, 4342, 4343, 4344, 4345, 4346, 4347, 4348, 4349, 4350, 4351, 4352, 4353, 4354, 4355, 4356, 4357, 4358, 4359, 4360, 4361, 4362, 4363, 4364, 4365, 4366, 4367, 4368, 4369, 4370, 4371, 4372, 4373, 4374, 4375, 4376, 4377, 4378, 4379, 4380, 4381, 4382, 4383, 4384, 4385, 4386, 4387, 4388, 4389, 4390, 4391, 4392, 4393, 4394, 4395, 4396, 4397, 4398, 4399, 4400, 4401, 4402, 4403, 4404, 4405, 4406, 4407, 4408, 4409, 4410, 4411, 4412, 4413, 4414, 4415, 4416, 4417, 4418, 4419, 4420, 4421, 4422, 4423, 4424, 4425, 4426, 4427, 4428, 4429, 4430, 4431, 4432, 4433, 4434, 4435, 4436, 4437, 4438, 4439, 4440, 4441, 4442, 4443, 4444, 4445, 4446, 4447, 4448, 4449, 4450, 4451, 4452, 4453, 4454, 4455, 4456, 4457, 4458, 4459, 4460, 4461, 4462, 4463, 4464, 4465, 4466, 4467, 4468, 4469, 4470, 4471, 4472, 4473, 4474, 4475, 4476, 4477, 4478, 4479, 4480, 4481, 4482, 4483, 4484, 4485, 4486, 4487, 4488, 4489, 4490, 4491, 4492, 4493, 4494, 4495, 4496, 4497, 4498, 4499, 4500, 4501, 4502, 4503, 4504, 4505, 4506, 4507, 4508, 4509, 4510, 4511, 4512, 4513, 4514, 4515, 4516, 4517, 4518, 4519, 4520, 4521, 4522, 4523, 4524, 4525, 4526, 4527, 4528, 4529, 4530, 4531, 4532, 4533, 4534, 4535, 4536, 4537, 4538, 4539, 4540, 4541, 4542, 4543, 4544, 4545, 4546, 4547, 4548, 4549, 4550, 4551, 4552, 4553, 4554, 4555, 4556, 4557, 4558, 4559, 4560, 4561, 4562, 4563, 4564, 4565, 4566, 4567, 4568, 4569, 4570, 4571, 4572, 4573, 4574, 4575, 4576, 4577, 4578, 4579, 4580, 4581, 4582, 4583, 4584, 4585, 4586, 4587, 4588, 4589, 4590, 4591, 4592, 4593, 4594, 4595, 4596, 4597, 4598, 4599, 4600, 4601, 4602, 4603, 4604, 4605, 4606, 4607, 4608, 4609, 4610, 4611, 4612, 4613, 4614, 4615, 4616, 4617, 4618, 4619, 4620, 4621, 4622, 4623, 4624, 4625, 4626, 4627, 4628, 4629, 4630, 4631, 4632, 4633, 4634, 4635, 4636, 4637, 4638, 4639, 4640, 4641, 4642, 4643, 4644, 4645, 4646, 4647, 4648, 4649, 4650, 4651, 4652, 4653, 4654, 4655, 4656, 4657, 4658, 4659, 4660, 4661, 4662, 4663, 4664, 4665, 4666, 4667, 4668, 4669, 4670, 4671, 4672, 4673, 4674, 4675, 4676, 4677, 4678, 4679, 4680, 4681, 4682, 4683, 4684, 4685, 4686, 4687, 4688, 4689, 4690, 4691, 4692, 4693, 4694, 4695, 4696, 4697, 4698, 4699, 4700, 4701, 4702, 4703, 4704, 4705, 4706, 4707, 4708, 4709, 4710, 4711, 4712, 4713, 4714, 4715, 4716, 4717, 4718, 4719, 4720, 4721, 4722, 4723, 4724, 4725, 4726, 4727, 4728, 4729, 4730, 4731, 4732, 4733, 4734, 4735, 4736, 4737, 4738, 4739, 4740, 4741, 4742, 4743, 4744, 4745, 4746, 4747, 4748, 4749, 4750, 4751, 4752, 4753, 4754, 4755, 4756, 4757, 4758, 4759, 4760, 4761, 4762, 4763, 4764, 4765, 4766, 4767, 4768, 4769, 4770, 4771, 4772, 4773, 4774, 4775, 4776, 4777, 4778, 4779, 4780, 4781, 4782, 4783, 4784, 4785, 4786, 4787, 4788, 4789, 4790, 4791, 4792, 4793, 4794, 4795, 4796, 4797, 4798, 4799, 4800, 4801, 4802, 4803, 4804, 4805, 4806, 4807, 4808, 4809, 4810, 4811, 4812, 4813, 4814, 4815, 4816, 4817, 4818, 4819, 4820, 4821, 4822, 4823, 4824, 4825, 4826, 4827, 4828, 4829, 4830, 4831, 4832, 4833, 4834, 4835, 4836, 4837, 4838, 4839, 4840, 4841, 4842, 4843, 4844, 4845, 4846, 4847, 4848, 4849, 4850, 4851, 4852, 4853, 4854, 4855, 4856, 4857, 4858, 4859, 4860, 4861, 4862, 4863, 4864, 4865, 4866, 4867, 4868, 4869, 4870, 4871, 4872, 4873, 4874, 4875, 4876, 4877, 4878, 4879, 4880, 4881, 4882, 4883, 4884, 4885, 4886, 4887, 4888, 4889, 4890, 4891, 4892, 4893, 4894, 4895, 4896, 4897, 4898, 4899, 4900, 4901, 4902, 4903, 4904, 4905, 4906, 4907, 4908, 4909, 4910, 4911, 4912, 4913, 4914, 4915, 4916, 4917, 4918, 4919, 4920, 4921, 4922, 4923, 4924, 4925, 4926, 4927, 4928, 4929, 4930, 4931, 4932, 4933, 4934, 4935, 4936, 4937, 4938, 4939, 4940, 4941, 4942, 4943, 4944, 4945, 4946, 4947, 4948, 4949, 4950, 4951, 4952, 4953, 4954, 4955, 4956, 4957, 4958, 4959, 4960, 4961, 4962, 4963, 4964, 4965, 4966, 4967, 4968, 4969, 4970, 4971, 4972, 4973, 4974, 4975, 4976, 4977, 4978, 4979, 4980, 4981, 4982, 4983, 4984, 4985, 4986, 4987, 4988, 4989, 4990, 4991, 4992, 4993, 4994, 4995, 4996, 4997, 4998, 4999, 5000, 5001, 5002, 5003, 5004, 5005, 5006, 5007, 5008, 5009, 5010, 5011, 5012, 5013, 5014, 5015, 5016, 5017, 5018, 5019, 5020, 5021, 5022, 5023, 5024, 5025, 5026, 5027, 5028, 5029, 5030, 5031, 5032, 5033, 5034, 5035, 5036, 5037, 5038, 5039, 5040, 5041, 5042, 5043, 5044, 5045, 5046, 5047, 5048, 5049, 5050, 5051, 5052, 5053, 5054, 5055, 5056, 5057, 5058, 5059, 5060, 5061, 5062, 5063, 5064, 5065, 5066, 5067, 5068, 5069, 5070, 5071, 5072, 5073, 5074, 5075, 5076, 5077, 5078, 5079, 5080, 5081, 5082, 5083, 5084, 5085, 5086, 5087, 5088, 5089, 5090, 5091, 5092, 5093, 5094, 5095, 5096, 5097, 5098, 5099, 5100, 5101, 5102, 5103, 5104, 5105, 5106, 5107, 5108, 5109, 5110, 5111, 5112, 5113, 5114, 5115, 5116, 5117, 5118, 5119, 5120, 5121, 5122, 5123, 5124, 5125, 5126, 5127, 5128, 5129, 5130, 5131, 5133, 5134, 5135, 5136, 5137, 5138, 5139, 5140, 5141, 5143, 5144, 5145, 5146, 5147, 5148, 5149, 5150, 5151, 5152, 5154, 5156, 5158, 5160, 5162, 5164, 5166, 5168, 5170, 5171, 5172, 5173, 5174, 5175, 5176, 5177, 5178, 5179, 5180, 5181, 5182, 5184, 5185, 5186, 5189, 5192, 5195, 5198, 5201, 5204, 5206, 5207, 5208, 5209, 5210, 5211, 5213, 5215, 5218, 5221, 5223, 5225, 5227, 5230, 5232, 5234, 5236, 5238, 5241, 5244, 5246, 5247, 5248, 5250, 5253, 5254, 5255, 5256, 5257, 5258, 5259, 5260, 5261, 5262, 5263, 5264, 5265, 5266, 5267, 5268, 5269, 5270, 5271, 5272, 5273, 5274, 5275, 5276, 5277, 5278, 5279, 5280, 5281, 5282, 5283, 5284, 5285, 5286, 5287, 5288, 5289, 5290, 5291, 5292, 5293, 5294, 5295, 5296, 5297, 5298, 5299, 5300, 5301, 5302, 5303, 5304, 5305, 5306, 5307, 5308, 5309, 5310, 5311, 5312, 5313, 5314, 5315, 5316, 5317, 5318, 5319, 5320, 5321, 5322, 5323, 5324, 5325, 5326, 5327, 5328, 5329, 5330, 5331, 5332, 5333, 5334, 5335, 5336, 5337, 5338, 5339, 5340, 5341, 5342, 5343, 5344, 5345, 5346, 5347, 5348, 5349, 5350, 5351, 5352, 5353, 5354, 5355, 5356, 5357, 5358, 5359, 5360, 5361, 5362, 5363, 5364, 5365];
+pub static PROTOTYPE_CHARS: [char; 5365] = [' ', '!', '!', '!', '!', '?', '$', '\u{20e0}', '&', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', 'B', '\'', 'D', '\'', 'P', '\'', 'T', '\'', 'Y', '\'', 'n', '(', '(', '(', '(', '2', ')', '(', '2', 'O', ')', '(', '3', ')', '(', '4', ')', '(', '5', ')', '(', '6', ')', '(', '7', ')', '(', '8', ')', '(', '9', ')', '(', 'A', ')', '(', 'B', ')', '(', 'C', ')', '(', 'D', ')', '(', 'E', ')', '(', 'F', ')', '(', 'G', ')', '(', 'H', ')', '(', 'J', ')', '(', 'K', ')', '(', 'L', ')', '(', 'M', ')', '(', 'N', ')', '(', 'O', ')', '(', 'P', ')', '(', 'Q', ')', '(', 'R', ')', '(', 'S', ')', '(', 'T', ')', '(', 'U', ')', '(', 'V', ')', '(', 'W', ')', '(', 'X', ')', '(', 'Y', ')', '(', 'Z', ')', '(', 'a', ')', '(', 'b', ')', '(', 'c', ')', '(', 'd', ')', '(', 'e', ')', '(', 'f', ')', '(', 'g', ')', '(', 'h', ')', '(', 'i', ')', '(', 'j', ')', '(', 'k', ')', '(', 'l', ')', '(', 'l', '2', ')', '(', 'l', '3', ')', '(', 'l', '4', ')', '(', 'l', '5', ')', '(', 'l', '6', ')', '(', 'l', '7', ')', '(', 'l', '8', ')', '(', 'l', '9', ')', '(', 'l', 'O', ')', '(', 'l', 'l', ')', '(', 'n', ')', '(', 'o', ')', '(', 'p', ')', '(', 'q', ')', '(', 'r', ')', '(', 'r', 'n', ')', '(', 's', ')', '(', 't', ')', '(', 'u', ')', '(', 'v', ')', '(', 'w', ')', '(', 'x', ')', '(', 'y', ')', '(', 'z', ')', '(', 'ᄀ', ')', '(', 'ᄀ', 'ᅡ', ')', '(', 'ᄂ', ')', '(', 'ᄂ', 'ᅡ', ')', '(', 'ᄃ', ')', '(', 'ᄃ', 'ᅡ', ')', '(', 'ᄅ', ')', '(', 'ᄅ', 'ᅡ', ')', '(', 'ᄆ', ')', '(', 'ᄆ', 'ᅡ', ')', '(', 'ᄇ', ')', '(', 'ᄇ', 'ᅡ', ')', '(', 'ᄉ', ')', '(', 'ᄉ', 'ᅡ', ')', '(', 'ᄋ', ')', '(', 'ᄋ', 'ᅡ', ')', '(', 'ᄋ', 'ᅩ', 'ᄌ', 'ᅥ', 'ᆫ', ')', '(', 'ᄋ', 'ᅩ', 'ᄒ', 'ᅮ', ')', '(', 'ᄌ', ')', '(', 'ᄌ', 'ᅡ', ')', '(', 'ᄌ', 'ᅮ', ')', '(', 'ᄎ', ')', '(', 'ᄎ', 'ᅡ', ')', '(', 'ᄏ', ')', '(', 'ᄏ', 'ᅡ', ')', '(', 'ᄐ', ')', '(', 'ᄐ', 'ᅡ', ')', '(', 'ᄑ', ')', '(', 'ᄑ', 'ᅡ', ')', '(', 'ᄒ', ')', '(', 'ᄒ', 'ᅡ', ')', '(', 'ー', ')', '(', '七', ')', '(', '三', ')', '(', '九', ')', '(', '二', ')', '(', '五', ')', '(', '代', ')', '(', '企', ')', '(', '休', ')', '(', '八', ')', '(', '六', ')', '(', '労', ')', '(', '勝', ')', '(', '十', ')', '(', '協', ')', '(', '名', ')', '(', '呼', ')', '(', '四', ')', '(', '土', ')', '(', '学', ')', '(', '安', ')', '(', '打', ')', '(', '敗', ')', '(', '日', ')', '(', '月', ')', '(', '有', ')', '(', '木', ')', '(', '本', ')', '(', '株', ')', '(', '水', ')', '(', '火', ')', '(', '点', ')', '(', '特', ')', '(', '盗', ')', '(', '監', ')', '(', '社', ')', '(', '祝', ')', '(', '祭', ')', '(', '自', ')', '(', '至', ')', '(', '財', ')', '(', '資', ')', '(', '金', ')', ')', ')', ')', '*', '+', '+', '\u{302}', '+', '\u{303}', '+', '\u{307}', '+', '\u{30a}', '+', '\u{323}', '+', '\u{330}', '+', '₂', ',', '-', '-', '.', '-', '\u{307}', '-', '\u{308}', '-', '\u{313}', '-', '\u{323}', '.', '.', ',', '.', '.', '.', '.', '.', '/', '/', '/', '/', '/', '/', '/', '\u{304}', '2', '2', ',', '2', '.', '2', '2', '日', '2', '2', '点', '2', '3', '日', '2', '3', '点', '2', '4', '日', '2', '4', '点', '2', '5', '日', '2', '6', '日', '2', '7', '日', '2', '8', '日', '2', '9', '日', '2', 'O', '.', '2', 'O', '日', '2', 'O', '点', '2', 'l', '日', '2', 'l', '点', '2', '\u{335}', '2', '日', '2', '月', '2', '点', '3', '3', ',', '3', '.', '3', 'O', '日', '3', 'l', '日', '3', '\u{326}', '3', '日', '3', '月', '3', '点', '4', '4', ',', '4', '.', '4', '·', '4', '日', '4', '月', '4', '点', '5', '5', ',', '5', '.', '5', '日', '5', '月', '5', '点', '6', '6', ',', '6', '.', '6', '日', '6', '月', '6', '点', '7', '7', ',', '7', '.', '7', '日', '7', '月', '7', '点', '8', '8', ',', '8', '.', '8', '日', '8', '月', '8', '点', '9', '9', ',', '9', '.', '9', '日', '9', '月', '9', '点', ':', ':', ':', '=', ':', '→', ';', '<', '<', '<', '<', '<', '<', '<', '·', '=', '=', '=', '=', '=', '=', '=', '\u{302}', '=', '\u{306}', '=', '\u{307}', '=', '\u{30a}', '=', '\u{323}', '\u{307}', '=', '\u{36b}', '=', '\u{20f0}', '>', '>', '<', '>', '>', '>', '>', '>', '>', '·', '?', '?', '!', '?', '?', 'A', 'A', 'A', 'A', 'E', 'A', 'O', 'A', 'R', 'A', 'U', 'A', 'V', 'A', 'Y', 'A', '\u{306}', 'A', '\u{30a}', 'B', 'C', 'C', '\'', 'C', '\u{326}', 'C', '\u{20e0}', 'C', '\u{20eb}', 'D', 'D', 'Z', 'D', 'Z', '\u{30c}', 'D', 'z', 'D', 'z', '\u{30c}', 'D', '\u{335}', 'E', 'E', '\u{306}', 'E', '\u{338}', 'F', 'F', 'A', 'X', 'F', '\u{326}', 'G', 'G', '\'', 'G', '\u{306}', 'G', '\u{335}', 'H', 'H', '\u{326}', 'H', '\u{329}', 'H', '\u{335}', 'I', '\u{306}', 'J', 'J', '·', 'J', '\u{335}', 'K', 'K', '\'', 'K', '\u{329}', 'K', '\u{335}', 'L', 'L', 'J', 'L', 'j', 'L', '\u{338}', 'M', 'M', 'B', 'M', '\u{326}', 'N', 'N', 'J', 'N', 'j', 'N', 'o', 'N', '\u{30a}', 'N', '\u{326}', 'O', 'O', '\'', 'O', ',', 'O', '.', 'O', 'E', 'O', 'O', 'O', '\u{306}', 'O', '\u{308}', 'O', '\u{335}', 'O', '\u{338}', 'O', '\u{338}', '\u{301}', 'O', '点', 'P', 'P', '\'', 'Q', 'Q', 'E', 'R', 'R', 's', 'S', 'T', 'T', '3', 'T', 'E', 'L', 'T', '\u{308}', 'T', '\u{327}', 'T', '\u{328}', 'T', '\u{329}', 'T', '\u{335}', 'T', '\u{338}', 'T', '\u{20eb}', 'U', 'U', '\'', 'U', '·', 'U', '\u{306}', 'U', '\u{335}', 'V', 'V', 'B', 'V', 'l', 'V', 'l', 'l', 'V', 'l', 'l', 'l', 'V', '·', 'V', '\u{335}', 'V', '\u{1de4}', 'W', 'W', '\u{335}', 'X', 'X', 'l', 'X', 'l', 'l', 'X', '\u{329}', 'X', '\u{335}', 'Y', 'Y', '\u{335}', 'Z', 'Z', '\u{326}', 'Z', '\u{335}', '\\', '\\', '\\', '\\', 'ᑕ', '^', '_', 'a', 'a', '/', 'c', 'a', '/', 's', 'a', 'a', 'a', 'e', 'a', 'o', 'a', 'u', 'a', 'v', 'a', 'y', 'a', '\u{306}', 'a', '\u{309}', 'a', '\u{30a}', 'a', '\u{332}', 'b', 'b', '\'', 'b', 'l', 'b', '·', 'b', '\u{304}', 'b', '\u{307}', 'b', '\u{307}', '·', 'b', '\u{314}', 'b', '\u{335}', 'c', 'c', '/', 'o', 'c', '/', 'u', 'c', '\u{326}', 'c', '\u{338}', 'd', 'd', '\'', 'd', 'z', 'd', 'z', '\u{30c}', 'd', '·', 'd', 'ȝ', 'd', 'ʑ', 'd', '\u{304}', 'd', '\u{314}', 'd', '\u{328}', 'd', '\u{335}', 'd', '\u{335}', '\u{331}', 'e', 'e', '\u{306}', 'e', '\u{328}', 'e', '\u{338}', 'f', 'f', 'f', 'f', 'f', 'i', 'f', 'f', 'l', 'f', 'i', 'f', 'l', 'f', 'ŋ', 'f', '\u{326}', 'f', '\u{334}', 'g', 'g', '\u{306}', 'g', '\u{314}', 'g', '\u{327}', 'g', '\u{335}', 'h', 'h', '\u{314}', 'h', '\u{335}', 'i', 'i', 'i', 'i', 'i', 'i', 'i', 'j', 'i', 'v', 'i', 'x', 'i', '\u{306}', 'i', '\u{332}', 'i', '\u{335}', 'j', 'j', '\u{335}', 'k', 'k', '\u{314}', 'l', 'l', '\'', 'l', ',', 'l', '.', 'l', '2', '.', 'l', '2', '日', 'l', '2', '月', 'l', '2', '点', 'l', '3', '.', 'l', '3', '日', 'l', '3', '点', 'l', '4', '.', 'l', '4', '日', 'l', '4', '点', 'l', '5', '.', 'l', '5', '日', 'l', '5', '点', 'l', '6', '.', 'l', '6', '日', 'l', '6', '点', 'l', '7', '.', 'l', '7', '日', 'l', '7', '点', 'l', '8', '.', 'l', '8', '日', 'l', '8', '点', 'l', '9', '.', 'l', '9', '日', 'l', '9', '点', 'l', 'J', 'l', 'O', 'l', 'O', '.', 'l', 'O', '日', 'l', 'O', '月', 'l', 'O', '点', 'l', 'V', 'l', 'X', 'l', 'j', 'l', 'l', 'l', 'l', '.', 'l', 'l', 'l', 'l', 'l', '日', 'l', 'l', '月', 'l', 'l', '点', 'l', 's', 'l', 't', 'l', 'z', 'l', '·', 'l', 'ȝ', 'l', '\u{30b}', 'l', '\u{328}', 'l', '\u{334}', 'l', '\u{335}', 'l', '\u{335}', 'l', '\u{335}', 'l', '\u{335}', 'l', '\u{335}', 'S', '\u{335}', 'l', '\u{338}', 'l', 'ك', 'ب', 'ر', 'l', 'ل', 'ل', '\u{651}', '\u{670}', 'o', 'l', '\u{655}', 'l', 'ٴ', 'l', '日', 'l', '月', 'l', '点', 'n', 'n', 'j', 'n', '\u{328}', 'n', '\u{329}', 'n', '\u{334}', 'o', 'o', '\'', 'o', 'e', 'o', 'o', 'o', '\u{302}', 'o', '\u{306}', 'o', '\u{31b}', 'o', '\u{335}', 'o', '\u{338}', 'o', 'ج', 'o', 'م', 'o', 'م', 'ج', 'o', 'م', 'م', 'o', 'ى', 'o', '\u{670}', 'o', 'ര', 'o', 'o', 'ာ', 'o', 'ᴇ', 'p', 'p', '·', 'p', '\u{314}', 'p', '\u{335}', 'q', 'q', '\u{314}', 'r', 'r', '\'', 'r', 'n', 'r', 'n', '\u{326}', 'r', 'n', '\u{334}', 'r', 'n', '\u{338}', 'r', '\u{328}', 'r', '\u{329}', 'r', '\u{334}', 'r', '\u{335}', 's', 's', 's', 's', 's', 't', 's', '\u{328}', 's', '\u{334}', 't', 't', 'f', 't', 's', 't', 'ȝ', 't', 'ɕ', 't', 'ʃ', 't', '\u{314}', 't', '\u{334}', 't', '\u{335}', 'u', 'u', 'e', 'u', 'o', 'u', '\u{306}', 'u', '\u{335}', 'v', 'v', 'i', 'v', 'i', 'i', 'v', 'i', 'i', 'i', 'w', 'w', '\u{307}', 'w', '\u{326}', 'w', '\u{486}', '\u{487}', 'x', 'x', 'i', 'x', 'i', 'i', 'x', '\u{307}', 'y', 'y', '\u{314}', 'y', '\u{335}', 'z', 'z', '\u{326}', 'z', '\u{328}', 'z', '\u{334}', 'z', '\u{335}', '{', '}', '~', '~', '\u{307}', '~', '\u{308}', '~', '\u{323}', '£', '©', '®', '°', '°', 'C', '°', 'F', '°', '\u{308}', '°', '\u{332}', '¶', '·', '·', '4', '·', '<', '·', '>', '·', 'J', '·', 'L', '·', 'P', '·', 'U', '·', 'V', '·', 'b', '·', 'b', '\u{307}', '·', 'd', '·', '·', '·', '·', 'Ʌ', '·', 'Γ', '·', 'Δ', '·', 'Ո', '·', 'ᐁ', '·', 'ᐄ', '·', 'ᐅ', '·', 'ᐆ', '·', 'ᐊ', '·', 'ᐋ', '·', 'ᐲ', '·', 'ᐴ', '·', 'ᐹ', '·', 'ᑏ', '·', 'ᑐ', '·', 'ᑑ', '·', 'ᑕ', '·', 'ᑖ', '·', 'ᑫ', '·', 'ᑮ', '·', 'ᑰ', '·', 'ᒉ', '·', 'ᒋ', '·', 'ᒌ', '·', 'ᒎ', '·', 'ᒐ', '·', 'ᒑ', '·', 'ᒣ', '·', 'ᒦ', '·', 'ᒧ', '·', 'ᒨ', '·', 'ᒫ', '·', 'ᓀ', '·', 'ᓂ', '·', 'ᓃ', '·', 'ᓄ', '·', 'ᓅ', '·', 'ᓇ', '·', 'ᓈ', '·', 'ᓓ', '·', 'ᓕ', '·', 'ᓖ', '·', 'ᓗ', '·', 'ᓘ', '·', 'ᓚ', '·', 'ᓛ', '·', 'ᓭ', '·', 'ᓯ', '·', 'ᓰ', '·', 'ᓱ', '·', 'ᓲ', '·', 'ᓴ', '·', 'ᓵ', '·', 'ᔐ', '·', 'ᔑ', '·', 'ᔒ', '·', 'ᔓ', '·', 'ᔔ', '·', 'ᔕ', '·', 'ᔖ', '·', 'ᔨ', '·', 'ᔩ', '·', 'ᔪ', '·', 'ᔫ', '·', 'ᔭ', '·', 'ᔮ', '·', 'ᕃ', '·', 'ᕆ', '·', 'ᕇ', '·', 'ᕈ', '·', 'ᕉ', '·', 'ᕋ', '·', 'ᕌ', '·', 'ᕚ', '·', 'ᕧ', '·', 'ᢱ', '·', 'ᢴ', '·', 'ᢸ', '·', 'ᣀ', 'º', 'º', '/', '₀', 'º', '/', '₀', '₀', 'º', '/', '₀', '₀', '₀', 'Þ', 'ß', '÷', 'þ', 'ĸ', 'ĸ', '\u{329}', 'ĸ', '\u{335}', 'ƅ', 'ƅ', 'i', 'Ɔ', 'Ǝ', 'Ə', 'Ɛ', 'ƨ', 'Ʃ', 'ƫ', 'Ʊ', 'ǝ', 'ǝ', 'o', 'ǝ', 'o', '\u{335}', 'ǝ', 'o', '\u{338}', 'ǝ', '˞', 'Ƕ', 'ȝ', 'ȷ', 'ɂ', 'Ʌ', 'Ʌ', '·', 'Ʌ', '\u{326}', 'Ʌ', '\u{338}', 'ɋ', 'ɔ', 'ɔ', 'e', 'ɔ', '\u{338}', 'ɜ', 'ɜ', '\u{326}', 'ɞ', 'ɢ', 'ɰ', 'ɲ', 'ɷ', 'ɸ', 'ɾ', '\u{334}', 'ɿ', 'ʀ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʊ', '\u{335}', 'ʌ', 'ʍ', 'ʍ', '\u{326}', 'ʘ', 'ʙ', 'ʚ', 'ʜ', 'ʜ', '\u{326}', 'ʜ', '\u{329}', 'ʟ', 'ʡ', 'ˁ', 'ˇ', 'ˉ', 'ˉ', 'b', 'ˉ', 'b', 'i', 'ˏ', '˪', '˫', '˳', '\u{300}', '\u{301}', '\u{302}', '\u{303}', '\u{304}', '\u{306}', '\u{306}', '\u{307}', '\u{307}', '\u{308}', '\u{309}', '\u{30a}', '\u{30a}', 'า', '\u{30a}', 'າ', '\u{30b}', '\u{30e}', '\u{312}', '\u{313}', '\u{314}', '\u{316}', '\u{323}', '\u{324}', '\u{325}', '\u{326}', '\u{328}', '\u{329}', '\u{32b}', '\u{32d}', '\u{32e}', '\u{331}', '\u{333}', '\u{335}', '\u{338}', '\u{350}', '\u{352}', '\u{354}', '\u{355}', '\u{363}', '\u{364}', '\u{368}', '\u{36f}', 'Γ', 'Γ', '\'', 'Γ', '·', 'Γ', '\u{335}', 'Δ', 'Δ', '·', 'Δ', '\u{332}', 'Δ', 'ᐠ', 'Ξ', 'Π', 'Φ', 'Ψ', 'Ω', 'ζ', 'λ', 'λ', '\u{338}', 'μ', 'ξ', 'π', 'ς', 'χ', 'ψ', 'ω', 'ω', '\u{301}', '\u{345}', 'ω', '\u{332}', 'ϗ', 'Ϙ', 'ϝ', 'Ϭ', 'Ћ', 'Ж', '\u{329}', 'И', 'И', '\u{300}', 'И', '\u{326}', '\u{300}', 'Ш', 'Ъ', 'l', 'Э', 'ж', '\u{329}', 'и', '\u{306}', 'и', '\u{326}', '\u{306}', 'л', 'л', '\u{326}', 'ш', 'љ', 'Ѡ', 'Ѡ', '·', 'Ѡ', '\u{486}', '\u{487}', 'Ҷ', 'ҷ', 'Ҽ', '\u{328}', 'Ӄ', 'Ӿ', 'Ո', 'Ո', '\'', 'Ո', '·', 'Ք', 'ՙ', 'ե', 'ւ', 'ձ', 'մ', 'ե', 'մ', 'ի', 'մ', 'խ', 'մ', 'ն', 'վ', 'ն', '\u{596}', '\u{598}', '\u{599}', '\u{59a}', 'א', 'א', '\u{5b7}', 'א', 'ל', 'ב', 'ג', 'ד', 'ה', 'י', '\u{5b4}', 'כ', 'ל', 'ם', 'ע', 'ר', 'ש', '\u{5bc}', '\u{5c1}', 'ש', '\u{5c1}', 'ת', '،', '؛', '؟', 'ء', 'ء', '\u{348}', 'ا', '\u{653}', 'ب', 'ب', 'o', 'ب', 'ج', 'ب', 'ح', 'ب', 'ح', 'ى', 'ب', 'خ', 'ب', 'خ', 'ى', 'ب', 'ر', 'ب', 'ز', 'ب', 'م', 'ب', 'ن', 'ب', 'ى', 'ب', '\u{654}', 'ب', '\u{6db}', 'ب', '\u{6e2}', 'ة', 'ت', 'ت', 'o', 'ت', 'ج', 'ت', 'ج', 'م', 'ت', 'ج', 'ى', 'ت', 'ح', 'ت', 'ح', 'ج', 'ت', 'ح', 'م', 'ت', 'خ', 'ت', 'خ', 'م', 'ت', 'خ', 'ى', 'ت', 'ر', 'ت', 'ز', 'ت', 'م', 'ت', 'م', 'ج', 'ت', 'م', 'ح', 'ت', 'م', 'خ', 'ت', 'م', 'ى', 'ت', 'ن', 'ت', 'ى', 'ج', 'ج', 'ح', 'ج', 'ح', 'ى', 'ج', 'ل', ' ', 'ج', 'ل', 'l', 'ل', 'o', 'ج', 'م', 'ج', 'م', 'ح', 'ج', 'م', 'ى', 'ج', 'ى', 'ح', 'ح', 'ج', 'ح', 'ج', 'ى', 'ح', 'م', 'ح', 'م', 'ى', 'ح', 'ى', 'ح', '\u{654}', 'ح', '\u{6db}', 'خ', 'خ', 'ج', 'خ', 'ح', 'خ', 'م', 'خ', 'ى', 'د', 'د', '\u{302}', 'د', '\u{324}', '\u{323}', 'د', '\u{615}', 'د', '\u{6db}', 'ذ', 'ذ', '\u{670}', 'ر', 'ر', '\u{302}', 'ر', '\u{306}', 'ر', '\u{306}', '\u{307}', 'ر', '\u{615}', 'ر', 'س', 'و', 'ل', 'ر', 'ى', 'l', 'ل', 'ر', '\u{654}', 'ر', '\u{670}', 'ر', '\u{6db}', 'ز', 'ز', '\u{302}', 'س', 'س', 'o', 'س', '\u{302}', 'س', 'ج', 'س', 'ج', 'ح', 'س', 'ج', 'ى', 'س', 'ح', 'س', 'ح', 'ج', 'س', 'خ', 'س', 'خ', 'ى', 'س', 'ر', 'س', 'م', 'س', 'م', 'ج', 'س', 'م', 'ح', 'س', 'م', 'م', 'س', 'ى', 'س', '\u{6db}', 'س', '\u{6db}', 'o', 'س', '\u{6db}', 'ج', 'س', '\u{6db}', 'ج', 'ى', 'س', '\u{6db}', 'ح', 'س', '\u{6db}', 'ح', 'م', 'س', '\u{6db}', 'ح', 'ى', 'س', '\u{6db}', 'خ', 'س', '\u{6db}', 'ر', 'س', '\u{6db}', 'م', 'س', '\u{6db}', 'م', 'خ', 'س', '\u{6db}', 'م', 'م', 'س', '\u{6db}', 'ى', 'ص', 'ص', '\u{324}', '\u{323}', 'ص', 'ح', 'ص', 'ح', 'ح', 'ص', 'ح', 'ى', 'ص', 'خ', 'ص', 'ر', 'ص', 'ل', 'ع', 'م', 'ص', 'ل', 'ى', 'ص', 'ل', 'ى', ' ', 'l', 'ل', 'ل', 'o', ' ', 'ع', 'ل', 'ى', 'o', ' ', 'و', 'س', 'ل', 'م', 'ص', 'م', 'ص', 'م', 'م', 'ص', 'ى', 'ص', '\u{6db}', 'ض', 'ض', 'ج', 'ض', 'ح', 'ض', 'ح', 'ى', 'ض', 'خ', 'ض', 'خ', 'م', 'ض', 'ر', 'ض', 'م', 'ض', 'ى', 'ط', 'ط', 'ح', 'ط', 'م', 'ط', 'م', 'ح', 'ط', 'م', 'م', 'ط', 'م', 'ى', 'ط', 'ى', 'ط', '\u{6db}', 'ظ', 'ظ', 'م', 'ع', 'ع', 'ج', 'ع', 'ج', 'م', 'ع', 'ل', 'ى', 'o', 'ع', 'م', 'ع', 'م', 'م', 'ع', 'م', 'ى', 'ع', 'ى', 'غ', 'غ', 'ج', 'غ', 'م', 'غ', 'م', 'م', 'غ', 'م', 'ى', 'غ', 'ى', 'ف', 'ف', 'ج', 'ف', 'ح', 'ف', 'خ', 'ف', 'خ', 'م', 'ف', 'م', 'ف', 'م', 'ى', 'ف', 'ى', 'ق', 'ق', 'ح', 'ق', 'ل', 'ى', 'ق', 'م', 'ق', 'م', 'ح', 'ق', 'م', 'م', 'ق', 'م', 'ى', 'ق', 'ى', 'ك', 'ك', 'l', 'ك', 'ج', 'ك', 'ح', 'ك', 'خ', 'ك', 'ل', 'ك', 'م', 'ك', 'م', 'م', 'ك', 'م', 'ى', 'ك', 'ى', 'ك', '\u{6db}', 'ل', 'ل', 'l', 'ل', 'l', '\u{655}', 'ل', 'l', 'ٴ', 'ل', 'o', 'ل', '\u{306}', 'ل', 'ا', '\u{653}', 'ل', 'ج', 'ل', 'ج', 'ج', 'ل', 'ج', 'م', 'ل', 'ج', 'ى', 'ل', 'ح', 'ل', 'ح', 'م', 'ل', 'ح', 'ى', 'ل', 'خ', 'ل', 'خ', 'م', 'ل', 'م', 'ل', 'م', 'ح', 'ل', 'م', 'ى', 'ل', 'ى', 'ل', '\u{6db}', 'م', 'م', 'l', 'م', '\u{348}', 'م', 'ج', 'م', 'ج', 'ح', 'م', 'ج', 'خ', 'م', 'ج', 'م', 'م', 'ج', 'ى', 'م', 'ح', 'م', 'ح', 'ج', 'م', 'ح', 'م', 'م', 'ح', 'م', 'د', 'م', 'ح', 'ى', 'م', 'خ', 'م', 'خ', 'ج', 'م', 'خ', 'م', 'م', 'خ', 'ى', 'م', 'م', 'م', 'م', 'ى', 'م', 'ى', 'م', '\u{6db}', 'ن', 'ن', 'o', 'ن', '\u{306}', 'ن', '\u{615}', 'ن', 'ج', 'ح', 'ن', 'ج', 'م', 'ن', 'ج', 'ى', 'ن', 'ح', 'ن', 'ح', 'م', 'ن', 'ح', 'ى', 'ن', 'خ', 'ن', 'ر', 'ن', 'ز', 'ن', 'م', 'ن', 'م', 'ى', 'ن', 'ن', 'ن', 'ى', 'و', 'و', '\u{302}', 'و', '\u{306}', 'و', '\u{313}', 'و', '\u{313}', 'ٴ', 'و', 'س', 'ل', 'م', 'و', '\u{670}', 'و', 'ٴ', 'و', '\u{6db}', 'ى', 'ى', 'o', 'ى', '\u{302}', 'ى', '\u{306}', 'ى', '\u{306}', '\u{307}', 'ى', '\u{615}', 'ى', 'ج', 'ى', 'ج', 'ى', 'ى', 'ح', 'ى', 'ح', 'ى', 'ى', 'خ', 'ى', 'ر', 'ى', 'ز', 'ى', 'م', 'ى', 'م', 'م', 'ى', 'م', 'ى', 'ى', 'ن', 'ى', 'ى', 'ى', '\u{654}', 'ى', '\u{670}', 'ى', 'ٴ', 'ى', 'ٴ', 'l', 'ى', 'ٴ', 'o', 'ى', 'ٴ', 'ج', 'ى', 'ٴ', 'ح', 'ى', 'ٴ', 'خ', 'ى', 'ٴ', 'ر', 'ى', 'ٴ', 'ز', 'ى', 'ٴ', 'م', 'ى', 'ٴ', 'ن', 'ى', 'ٴ', 'و', 'ى', 'ٴ', 'و', '\u{306}', 'ى', 'ٴ', 'و', '\u{313}', 'ى', 'ٴ', 'و', '\u{670}', 'ى', 'ٴ', 'ى', 'ى', 'ٴ', 'ٻ', 'ى', '\u{6db}', 'ى', '\u{6db}', 'o', 'ى', '\u{6db}', 'ج', 'ى', '\u{6db}', 'ر', 'ى', '\u{6db}', 'ز', 'ى', '\u{6db}', 'م', 'ى', '\u{6db}', 'ن', 'ى', '\u{6db}', 'ى', 'ى', '\u{6db}', '\u{6e2}', '\u{64c}', '\u{64d}', '\u{650}', '\u{655}', '٢', '٣', '٤', '٦', '٩', '\u{670}', 'ٱ', 'ٺ', 'ٻ', 'ٿ', 'ڀ', 'ڃ', 'ڄ', 'چ', 'ڇ', 'ڊ', '\u{615}', 'ڌ', 'ڍ', 'ڗ', '\u{615}', 'ڡ', 'ڡ', '\u{6db}', 'ڢ', '\u{6db}', 'ڦ', 'ڬ', 'گ', 'گ', '\u{6db}', 'ڱ', 'ڳ', 'ۅ', 'ے', '\u{654}', 'ە', '\u{654}', '\u{6db}', '\u{73c}', 'ݔ', 'अ', 'ा', 'अ', 'ा', '\u{946}', 'अ', 'ा', '\u{947}', 'अ', 'ा', '\u{948}', 'अ', '\u{946}', 'अ', 'ॉ', 'ए', '\u{945}', 'ए', '\u{946}', 'ए', '\u{947}', 'र', '\u{94d}', 'इ', '\u{93a}', 'ऽ', '\u{941}', '\u{942}', '\u{946}', '\u{94d}', '।', '।', '।', '२', '३', '४', '८', '॰', 'ॱ', 'ঃ', 'অ', '\u{9be}', 'ঋ', '\u{9c3}', 'ঘ', 'চ', 'জ', 'ঞ', 'ট', 'ড', 'ণ', 'ত', 'থ', 'দ', 'ধ', 'ন', 'প', 'ব', 'ম', 'য', 'র', 'ল', 'ষ', 'স', 'ঽ', '\u{9be}', 'ি', 'ে', 'ে', '\u{9be}', 'ে', '\u{9d7}', '\u{9cd}', '\u{9d7}', '১', '২', '৬', 'ਅ', 'ਾ', 'ਅ', '\u{a48}', 'ਅ', '\u{a4c}', 'ੲ', 'ਿ', 'ੲ', 'ੀ', 'ੲ', '\u{a47}', 'ੳ', '\u{a41}', 'ੳ', '\u{a42}', 'અ', 'ા', 'અ', 'ા', '\u{ac5}', 'અ', 'ા', '\u{ac7}', 'અ', 'ા', '\u{ac8}', 'અ', '\u{ac5}', 'અ', '\u{ac7}', 'અ', '\u{ac8}', 'ଅ', '\u{b3e}', 'அ', 'ஈ', 'ஈ', 'ு', 'உ', 'உ', 'ள', 'உ', '\u{d57}', 'எ', 'எ', 'வ', 'ஐ', 'க', 'ச', 'ச', 'ு', 'ச', 'ூ', 'ட', 'ி', 'ண', 'ந', '\u{bc0}', 'ன', 'ம', '\u{bc0}', 'ய', 'ள', 'ழ', 'ஶ', 'ஷ', 'ி', 'ெ', 'ஈ', 'ெ', 'ள', 'ே', 'ஈ', '௳', 'అ', 'ఆ', 'ఇ', 'ఋ', '\u{c3e}', 'ఌ', '\u{c3e}', 'ఒ', 'ఒ', '\u{c4c}', 'ఒ', '\u{c55}', 'జ', 'ఞ', 'డ', '\u{323}', 'ణ', 'ధ', '\u{5bc}', 'బ', '\u{323}', 'య', 'ర', '\u{5bc}', 'ఱ', 'ల', 'వ', '\u{323}', 'వ', '\u{c3e}', 'వ', 'ు', 'ు', '\u{c3e}', 'ృ', '\u{c3e}', '౧', '౨', '౯', 'ಌ', 'ಾ', 'ഇ', '\u{d57}', 'എ', 'െ', 'ഒ', '\u{d3e}', 'ഒ', '\u{d57}', 'ഞ', 'ദ', '\u{d4d}', 'ര', 'ന', '\u{d41}', 'ന', '\u{d4d}', 'ന', '\u{d4d}', 'ന', 'ന', '\u{d4d}', 'മ', 'ര', 'ര', '\u{d4d}', 'വ', '\u{d4d}', 'ര', 'ഹ', '\u{d4d}', 'മ', '\u{d41}', 'െ', 'െ', 'ජ', 'ද', '෨', '\u{dcf}', '෨', '\u{dd3}', 'ข', 'ค', 'ฆ', 'จ', 'ช', 'ฎ', 'ฑ', 'บ', 'ป', 'ฝ', 'พ', 'ฟ', 'ภ', 'ย', 'ฯ', 'า', '\u{e34}', '\u{e35}', '\u{e36}', '\u{e37}', '\u{e38}', '\u{e39}', 'เ', 'เ', '\u{e48}', '\u{e49}', '\u{e4a}', '\u{e4b}', '๏', '๚', '๛', 'ຫ', 'ນ', 'ຫ', 'ມ', '་', '།', '།', '༚', '༚', '༚', '༝', '༝', '༚', '༝', '༝', 'འ', '\u{f74}', '\u{f82}', '༔', 'འ', '\u{f74}', '\u{f82}', 'ཿ', 'ར', 'ཨ', '\u{f7c}', '\u{f7e}', '\u{fb2}', '\u{f71}', '\u{f80}', '\u{fb3}', '\u{f71}', '\u{f80}', 'ဂ', 'ာ', 'ဂ', '\u{103e}', 'ဃ', '\u{103e}', 'ပ', 'ာ', 'ပ', 'ာ', '\u{103e}', 'ပ', '\u{103e}', 'သ', 'ြ', 'သ', 'ြ', 'ေ', 'ာ', '\u{103a}', '၁', '၊', '၊', 'ၽ', '\u{103e}', 'ႃ', '\u{30a}', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄂ', 'ᄀ', 'ᄃ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄇ', 'ᄀ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄀ', 'ᄀ', 'ᄎ', 'ᄀ', 'ᄏ', 'ᄀ', 'ᄒ', 'ᄂ', 'ᄂ', 'ᄀ', 'ᄂ', 'ᄂ', 'ᄂ', 'ᄃ', 'ᄂ', 'ᄅ', 'ᄂ', 'ᄇ', 'ᄂ', 'ᄉ', 'ᄂ', 'ᄌ', 'ᄂ', 'ᄎ', 'ᄂ', 'ᄐ', 'ᄂ', 'ᄒ', 'ᄂ', 'ᅀ', 'ᄃ', 'ᄃ', 'ᄀ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄆ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄀ', 'ᄃ', 'ᄌ', 'ᄃ', 'ᄎ', 'ᄃ', 'ᄐ', 'ᄅ', 'ᄅ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄉ', 'ᄅ', 'ᄀ', 'ᄒ', 'ᄅ', 'ᄂ', 'ᄅ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄒ', 'ᄅ', 'ᄅ', 'ᄅ', 'ᄅ', 'ᄏ', 'ᄅ', 'ᄆ', 'ᄅ', 'ᄆ', 'ᄀ', 'ᄅ', 'ᄆ', 'ᄉ', 'ᄅ', 'ᄆ', 'ᄒ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄃ', 'ᄅ', 'ᄇ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄉ', 'ᄅ', 'ᄇ', 'ᄋ', 'ᄅ', 'ᄇ', 'ᄑ', 'ᄅ', 'ᄇ', 'ᄒ', 'ᄅ', 'ᄉ', 'ᄅ', 'ᄉ', 'ᄉ', 'ᄅ', 'ᄋ', 'ᄅ', 'ᄌ', 'ᄅ', 'ᄏ', 'ᄅ', 'ᄐ', 'ᄅ', 'ᄑ', 'ᄅ', 'ᄒ', 'ᄅ', 'ᅀ', 'ᄅ', 'ᅌ', 'ᄅ', 'ᅙ', 'ᄅ', 'ᅙ', 'ᄒ', 'ᄆ', 'ᄆ', 'ᄀ', 'ᄆ', 'ᄂ', 'ᄆ', 'ᄂ', 'ᄂ', 'ᄆ', 'ᄃ', 'ᄆ', 'ᄅ', 'ᄆ', 'ᄆ', 'ᄆ', 'ᄇ', 'ᄆ', 'ᄇ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄉ', 'ᄆ', 'ᄋ', 'ᄆ', 'ᄌ', 'ᄆ', 'ᄎ', 'ᄆ', 'ᄒ', 'ᄆ', 'ᅀ', 'ᄇ', 'ᄇ', 'ᄀ', 'ᄇ', 'ᄂ', 'ᄇ', 'ᄃ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄑ', 'ᄇ', 'ᄆ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄋ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄀ', 'ᄇ', 'ᄉ', 'ᄃ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄌ', 'ᄇ', 'ᄉ', 'ᄐ', 'ᄇ', 'ᄋ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄎ', 'ᄇ', 'ᄏ', 'ᄇ', 'ᄐ', 'ᄇ', 'ᄑ', 'ᄇ', 'ᄒ', 'ᄉ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄂ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄅ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄀ', 'ᄉ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄋ', 'ᄉ', 'ᄌ', 'ᄉ', 'ᄎ', 'ᄉ', 'ᄏ', 'ᄉ', 'ᄐ', 'ᄉ', 'ᄑ', 'ᄉ', 'ᅀ', 'ᄋ', 'ᄋ', 'ᄀ', 'ᄋ', 'ᄀ', 'ᄀ', 'ᄋ', 'ᄃ', 'ᄋ', 'ᄅ', 'ᄋ', 'ᄆ', 'ᄋ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄋ', 'ᄋ', 'ᄋ', 'ᄌ', 'ᄋ', 'ᄎ', 'ᄋ', 'ᄏ', 'ᄋ', 'ᄐ', 'ᄋ', 'ᄑ', 'ᄋ', 'ᄒ', 'ᄋ', 'ᅀ', 'ᄌ', 'ᄌ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄇ', 'ᄌ', 'ᄋ', 'ᄌ', 'ᄌ', 'ᄌ', 'ᄌ', 'ᄒ', 'ᄎ', 'ᄎ', 'ᄏ', 'ᄎ', 'ᄒ', 'ᄏ', 'ᄐ', 'ᄐ', 'ᄐ', 'ᄑ', 'ᄑ', 'ᄇ', 'ᄑ', 'ᄉ', 'ᄑ', 'ᄋ', 'ᄑ', 'ᄐ', 'ᄑ', 'ᄒ', 'ᄒ', 'ᄒ', 'ᄂ', 'ᄒ', 'ᄅ', 'ᄒ', 'ᄆ', 'ᄒ', 'ᄇ', 'ᄒ', 'ᄉ', 'ᄒ', 'ᄒ', 'ᄼ', 'ᄼ', 'ᄾ', 'ᄾ', 'ᅀ', 'ᅀ', 'ᄇ', 'ᅀ', 'ᄇ', 'ᄋ', 'ᅌ', 'ᅌ', 'ᄆ', 'ᅌ', 'ᄒ', 'ᅎ', 'ᅎ', 'ᅐ', 'ᅐ', 'ᅙ', 'ᅙ', 'ᅙ', 'ᅠ', 'ᅡ', 'ᅡ', 'ᅩ', 'ᅡ', 'ᅮ', 'ᅡ', 'ー', 'ᅡ', '丨', 'ᅣ', 'ᅣ', 'ᅩ', 'ᅣ', 'ᅭ', 'ᅣ', 'ᅮ', 'ᅣ', '丨', 'ᅥ', 'ᅥ', 'ᅩ', 'ᅥ', 'ᅮ', 'ᅥ', 'ー', 'ᅥ', '丨', 'ᅧ', 'ᅧ', 'ᅣ', 'ᅧ', 'ᅩ', 'ᅧ', 'ᅮ', 'ᅧ', '丨', 'ᅩ', 'ᅩ', 'ᅡ', 'ᅩ', 'ᅡ', '丨', 'ᅩ', 'ᅣ', 'ᅩ', 'ᅣ', '丨', 'ᅩ', 'ᅥ', 'ᅩ', 'ᅥ', '丨', 'ᅩ', 'ᅧ', 'ᅩ', 'ᅧ', '丨', 'ᅩ', 'ᅩ', 'ᅩ', 'ᅩ', '丨', 'ᅩ', 'ᅮ', 'ᅩ', '丨', 'ᅭ', 'ᅭ', 'ᅡ', 'ᅭ', 'ᅡ', '丨', 'ᅭ', 'ᅣ', 'ᅭ', 'ᅣ', '丨', 'ᅭ', 'ᅥ', 'ᅭ', 'ᅩ', 'ᅭ', '丨', 'ᅮ', 'ᅮ', 'ᅡ', 'ᅮ', 'ᅡ', '丨', 'ᅮ', 'ᅥ', 'ᅮ', 'ᅥ', 'ー', 'ᅮ', 'ᅥ', '丨', 'ᅮ', 'ᅧ', 'ᅮ', 'ᅧ', '丨', 'ᅮ', 'ᅮ', 'ᅮ', '丨', 'ᅮ', '丨', '丨', 'ᅲ', 'ᅲ', 'ᅡ', 'ᅲ', 'ᅡ', '丨', 'ᅲ', 'ᅥ', 'ᅲ', 'ᅥ', '丨', 'ᅲ', 'ᅧ', 'ᅲ', 'ᅧ', '丨', 'ᅲ', 'ᅩ', 'ᅲ', 'ᅮ', 'ᅲ', '丨', 'ᆞ', 'ᆞ', 'ᅡ', 'ᆞ', 'ᅥ', 'ᆞ', 'ᅥ', '丨', 'ᆞ', 'ᅮ', 'ᆞ', 'ᆞ', 'ᆞ', '丨', 'ሆ', 'ቡ', 'ኮ', 'ጣ', 'Ꮿ', 'ᐁ', '·', 'ᐁ', 'ᐠ', 'ᐄ', '·', 'ᐅ', '·', 'ᐅ', 'ᐠ', 'ᐆ', '·', 'ᐊ', '·', 'ᐊ', 'ᐠ', 'ᐋ', '·', 'ᐞ', 'ᣟ', 'ᐡ', 'ᐩ', 'ᐲ', '·', 'ᐴ', '·', 'ᐵ', 'ᐹ', '·', 'ᑏ', '·', 'ᑐ', 'ᑐ', '\'', 'ᑐ', '/', 'ᑐ', '·', 'ᑐ', 'ᑕ', 'ᑑ', '·', 'ᑕ', 'ᑕ', '\'', 'ᑕ', '·', 'ᑖ', '·', 'ᑫ', '\'', 'ᑫ', '·', 'ᑮ', '·', 'ᑰ', '·', 'ᒉ', 'ᒉ', '·', 'ᒋ', '·', 'ᒌ', '·', 'ᒎ', '·', 'ᒐ', 'ᒐ', '·', 'ᒑ', '·', 'ᒣ', '·', 'ᒦ', '·', 'ᒧ', '·', 'ᒨ', '·', 'ᒫ', '·', 'ᓀ', '·', 'ᓂ', '·', 'ᓃ', '·', 'ᓄ', '·', 'ᓅ', '·', 'ᓇ', '·', 'ᓈ', '·', 'ᓓ', 'ᓓ', '·', 'ᓕ', '·', 'ᓖ', '·', 'ᓗ', '·', 'ᓘ', '·', 'ᓚ', 'ᓚ', '·', 'ᓛ', '·', 'ᓭ', '·', 'ᓯ', '·', 'ᓰ', '·', 'ᓱ', '·', 'ᓲ', '·', 'ᓴ', '·', 'ᓵ', '·', 'ᔋ', '<', 'ᔋ', 'b', 'ᔋ', 'ᑕ', 'ᔋ', 'ᒐ', 'ᔐ', '·', 'ᔑ', '·', 'ᔒ', '·', 'ᔓ', '·', 'ᔔ', '·', 'ᔕ', '·', 'ᔖ', '·', 'ᔨ', '·', 'ᔩ', '·', 'ᔪ', '·', 'ᔫ', '·', 'ᔭ', '·', 'ᔮ', '·', 'ᕃ', 'ᕃ', '·', 'ᕆ', 'ᕊ', 'ᕌ', '·', 'ᕐ', 'P', 'ᕐ', 'b', 'ᕐ', 'b', '\u{307}', 'ᕐ', 'd', 'ᕐ', 'ᑫ', 'ᕐ', 'ᑬ', 'ᕐ', 'ᑮ', 'ᕐ', 'ᑰ', 'ᕐ', 'ᒃ', 'ᕚ', '·', 'ᕞ', '·', 'ᕦ', '·', 'ᕧ', '·', 'ᕫ', '·', 'ᖆ', '·', 'ᖕ', 'J', 'ᖕ', 'ᒉ', 'ᖕ', 'ᒊ', 'ᖕ', 'ᒋ', 'ᖕ', 'ᒌ', 'ᖕ', 'ᒎ', 'ᖕ', 'ᒐ', 'ᖕ', 'ᒑ', 'ᖖ', 'J', 'ᖖ', 'ᒋ', 'ᖖ', 'ᒌ', 'ᖖ', 'ᒎ', 'ᖖ', 'ᒐ', 'ᖖ', 'ᒑ', 'ᖗ', '·', 'ᖧ', '·', 'ᖨ', '·', 'ᖩ', '·', 'ᖪ', '·', 'ᖫ', '·', 'ᖬ', '·', 'ᖭ', '·', 'ᗒ', 'ᗕ', 'ᗡ', 'ᗴ', '·', 'ᘛ', '·', 'ᙆ', 'ᙠ', 'ᚹ', 'ᚼ', 'ᚽ', 'ᛋ', 'ᛏ', 'ᛐ', 'ᛐ', 'ᛚ', 'ᛐ', '⇂', 'ᛚ', 'ᛜ', 'ᛜ', '\u{332}', 'ᛞ', 'ᛦ', 'ᛨ', 'ᛯ', '\u{1715}', 'អ', 'ᠵ', 'ᡜ', 'ᣔ', 'ᣖ', 'ᣟ', 'ᐞ', 'ᣳ', 'ᣴ', 'ᣵ', 'ᦞ', 'ᦱ', 'ᩅ', '᪨', '᪨', '᪪', '᪨', 'ᬍ', 'ᬑ', 'ᬨ', '᭐', '᭞', '᭞', '᰻', '᰻', '᱾', '᱾', 'ᴀ', 'ᴅ', 'ᴇ', 'ᴊ', 'ᴎ', 'ᴘ', 'ᴙ', 'ᴛ', 'ᴛ', '\u{329}', 'ᴴ', 'ᵋ', 'ᵍ', '\u{1ddf}', 'ẟ', '₁', '₀', '₸', '\u{20e9}', '℗', 'Ⅎ', '⅄', '↞', '↟', '↠', '↡', '↲', '⇃', 'ᛚ', '⇃', '⇂', '∂', '∂', '\u{335}', '∅', '∇', '∇', '\u{308}', '∇', '\u{334}', '∎', '∐', '∠', '∧', '∮', '∮', '∮', '∮', '∮', '∴', '∵', '∷', '≈', '≏', '≡', '⊍', '⊎', '⊏', '⊐', '⊓', '⊔', '⊗', '⊛', '⊠', '⊡', '⊲', '⊳', '⋆', '\u{308}', '⌇', '⌒', '⌙', '⌤', '⌻', '⌾', '⍂', '⍉', '⍋', '⍎', '⍕', '⍭', '⎈', '⏜', '⏝', '⏞', '⏟', '⏠', '⏡', '⏥', '⏻', 'Ⓘ', '│', '┌', '├', '▌', '▖', '▘', '□', '▪', '▶', '☧', '☩', '☽', '☾', '❬', '❭', '➀', '➁', '➂', '➃', '➄', '➅', '➆', '➇', '➈', '➉', '⟦', '⟧', '⦚', '⧟', '⨟', 'ⰿ', 'Ɐ', 'Ɒ', 'Ⱶ', 'ⱶ', 'Ⲷ', '⳨', 'ⵀ', 'ⵂ', 'ⵗ', '\u{2dec}', '〜', '〼', 'へ', 'ー', 'ー', 'ᅡ', 'ー', 'ᅥ', 'ー', 'ᅥ', '丨', 'ー', 'ᅩ', 'ー', 'ᅮ', 'ー', 'ー', 'ー', '丨', 'ー', '丨', 'ᅮ', '㏄', '\t', '\u{20dd}', '㒞', '㒹', '㒻', '㓟', '㔕', '㔾', '㖈', '㘽', '㛮', '㛼', '㞁', '㠯', '㡢', '㡼', '㣇', '㣣', '㣺', '㤜', '㤺', '㨮', '㩁', '㩬', '㫚', '㫤', '㬈', '㬙', '㬵', '㬺', '㬻', '㭉', '㮝', '㮣', '㰘', '㱎', '㴳', '㶖', '㺬', '㺸', '㼛', '㿼', '䀈', '䀘', '䀹', '䀿', '䁆', '䂖', '䃣', '䄯', '䈂', '䈧', '䊠', '䌁', '䌴', '䍙', '䏕', '䏙', '䐋', '䑃', '䑫', '䔫', '䕝', '䕡', '䕫', '䗗', '䗹', '䘵', '䚶', '䚾', '䛇', '䦕', '䧦', '䩮', '䩶', '䪲', '䬳', '䯎', '䳎', '䳭', '䳸', '䵖', '不', '並', '丨', '丨', 'ᅡ', '丨', 'ᅣ', '丨', 'ᅣ', 'ᅩ', '丨', 'ᅣ', '丨', '丨', 'ᅧ', '丨', 'ᅧ', '丨', '丨', 'ᅩ', '丨', 'ᅩ', '丨', '丨', 'ᅭ', '丨', 'ᅮ', '丨', 'ᅲ', '丨', 'ᆞ', '丨', 'ー', '丨', '丨', '丬', '串', '丸', '丹', '丽', '乁', '乙', '乚', '乛', '亀', '亂', '亅', '了', '二', '亠', '亮', '人', '亻', '什', '仌', '令', '你', '併', '侀', '來', '例', '侮', '侻', '便', '値', '倫', '偺', '備', '像', '僚', '僧', '儿', '兀', '充', '免', '兔', '兤', '入', '內', '全', '兩', '八', '六', '具', '冀', '冂', '再', '冒', '冕', '冖', '冗', '冤', '冫', '冬', '况', '冷', '凉', '凌', '凜', '凞', '几', '凵', '刀', '刂', '刃', '切', '列', '利', '刺', '刻', '剆', '割', '剷', '劉', '力', '劣', '劳', '勇', '勉', '勒', '勞', '勤', '勵', '勹', '勺', '包', '匆', '匕', '北', '匚', '匸', '匿', '十', '卄', '卅', '卉', '卍', '卐', '卑', '博', '卜', '卩', '即', '卵', '卽', '卿', '厂', '厶', '參', '又', '及', '叟', '口', '句', '叫', '叱', '吆', '吏', '吝', '吸', '呂', '呈', '周', '咞', '咢', '咽', '哶', '唐', '啓', '啕', '啣', '善', '喇', '喙', '喝', '喫', '喳', '嗀', '嗂', '嗢', '嘆', '噑', '器', '噴', '囹', '圖', '圗', '土', '型', '城', '埴', '堍', '報', '堲', '塀', '塚', '塞', '塡', '墨', '墫', '墬', '墳', '壘', '壟', '壮', '売', '壷', '夂', '夆', '夊', '夕', '多', '夢', '大', '奄', '奈', '契', '奔', '奢', '女', '姘', '姬', '娛', '娧', '婢', '婦', '媯', '媵', '嬈', '嬨', '嬾', '子', '宀', '宅', '寃', '寘', '寧', '寮', '寳', '寸', '寿', '将', '小', '尢', '尣', '尸', '尿', '屠', '屢', '層', '履', '屮', '山', '岍', '峀', '崙', '嵃', '嵐', '嵫', '嵮', '嵼', '嶲', '嶺', '巛', '巡', '巢', '工', '己', '巳', '巽', '巾', '帡', '帨', '帽', '幩', '干', '年', '幺', '广', '度', '庰', '庳', '庶', '廉', '廊', '廒', '廓', '廙', '廬', '廴', '廾', '弄', '弋', '弓', '弢', '彐', '彑', '当', '彡', '形', '彩', '彫', '彳', '律', '徚', '復', '徭', '心', '忄', '忍', '志', '念', '忹', '怒', '怜', '恵', '悁', '悔', '惇', '惘', '惡', '愈', '慄', '慈', '慌', '慎', '慠', '慨', '慺', '憎', '憐', '憤', '憯', '憲', '懞', '懲', '懶', '戀', '戈', '成', '戛', '戮', '戴', '戶', '手', '扌', '扝', '抱', '拉', '拏', '拓', '拔', '拼', '拾', '挽', '捐', '捨', '捻', '掃', '掠', '掩', '揄', '揅', '揤', '搜', '搢', '摒', '摩', '摷', '摾', '撚', '撝', '擄', '支', '攴', '攵', '敏', '敖', '敬', '數', '文', '斉', '斗', '料', '斤', '方', '旅', '无', '旡', '既', '旣', '日', '易', '晉', '晚', '晴', '暈', '暑', '暜', '暴', '曆', '曰', '更', '書', '最', '月', '朌', '朏', '朐', '朓', '朗', '朘', '望', '朡', '朣', '木', '李', '杓', '杖', '杞', '杮', '杻', '枅', '林', '柳', '柺', '栗', '栟', '桒', '梁', '梅', '梎', '梨', '椔', '楂', '榝', '榣', '槪', '樂', '樓', '檨', '櫓', '櫛', '欄', '欠', '次', '歔', '止', '歯', '歲', '歷', '歹', '歺', '殟', '殮', '殳', '殺', '殻', '毋', '母', '比', '毛', '氏', '民', '气', '水', '氵', '氺', '汎', '汧', '沈', '沿', '泌', '泍', '泥', '洖', '洛', '洞', '洴', '派', '流', '浩', '浪', '海', '浸', '涅', '淋', '淚', '淪', '淹', '渚', '港', '湮', '溈', '溜', '溺', '滇', '滋', '滑', '滛', '漏', '漢', '漣', '潮', '濆', '濫', '濾', '瀛', '瀞', '瀹', '灊', '火', '灬', '灰', '灷', '災', '炙', '炭', '烈', '烙', '煅', '煉', '煮', '熜', '燎', '燐', '爐', '爛', '爨', '爪', '爫', '爵', '父', '爻', '爿', '片', '牐', '牙', '牛', '牢', '犀', '犕', '犬', '犭', '犯', '狀', '狼', '猪', '獵', '獺', '玄', '率', '玉', '王', '玥', '玲', '珞', '理', '琉', '琢', '瑇', '瑜', '瑩', '瑱', '璅', '璉', '璘', '瓊', '瓜', '瓦', '甆', '甘', '生', '甤', '用', '田', '画', '甾', '留', '略', '異', '疋', '疒', '痢', '瘐', '瘝', '瘟', '療', '癩', '癶', '白', '皮', '皿', '益', '盛', '盧', '目', '直', '省', '眞', '真', '着', '睊', '瞋', '瞧', '矛', '矢', '石', '研', '硎', '硫', '碌', '碑', '磊', '磌', '磻', '礪', '示', '礻', '礼', '社', '祈', '祉', '祐', '祖', '祝', '神', '祥', '祿', '禍', '禎', '福', '禮', '禸', '禾', '秊', '秫', '稜', '穀', '穊', '穏', '穴', '突', '窱', '立', '竜', '竮', '竹', '笠', '節', '篆', '築', '簾', '籠', '米', '类', '粒', '精', '糒', '糖', '糣', '糧', '糨', '糸', '糹', '紀', '紐', '索', '累', '絕', '絛', '絣', '綠', '綾', '緇', '練', '縂', '縉', '縷', '繁', '繅', '缶', '缾', '网', '罒', '罓', '署', '罹', '罺', '羅', '羊', '羕', '羚', '羽', '翺', '老', '耂', '者', '而', '耒', '耳', '聆', '聠', '聯', '聰', '聾', '聿', '肀', '肉', '肋', '肭', '育', '胼', '脃', '脾', '臘', '臣', '臨', '自', '臭', '至', '臼', '舁', '舄', '舌', '舘', '舛', '舟', '艮', '良', '色', '艸', '艹', '芋', '芑', '芝', '花', '芳', '芽', '若', '苦', '茝', '茣', '茶', '荒', '荓', '荣', '莭', '莽', '菉', '菊', '菌', '菜', '菧', '華', '菱', '落', '葉', '著', '蒍', '蓮', '蓱', '蓳', '蓼', '蔖', '蕤', '藍', '藺', '蘆', '蘒', '蘭', '蘷', '蘿', '虍', '虎', '虐', '虜', '虧', '虩', '虫', '蚈', '蚩', '蛢', '蜎', '蜨', '蝫', '蝹', '螆', '螺', '蟡', '蠁', '蠟', '血', '行', '衠', '衣', '衤', '裂', '裏', '裗', '裞', '裡', '裸', '裺', '褐', '襁', '襤', '襾', '西', '覀', '覆', '見', '視', '见', '角', '言', '訮', '誠', '說', '調', '請', '諒', '論', '諭', '諸', '諾', '謁', '謹', '識', '讀', '讆', '變', '讠', '谷', '豆', '豈', '豕', '豜', '豸', '貝', '貫', '賁', '賂', '賈', '賓', '贈', '贛', '贝', '赤', '走', '起', '赿', '足', '趼', '跋', '跥', '路', '跰', '躗', '身', '車', '軔', '軿', '輦', '輪', '輸', '輻', '轢', '车', '辛', '辞', '辰', '辵', '辶', '連', '逸', '遲', '遼', '邏', '邑', '邔', '郎', '郱', '都', '鄑', '鄛', '酉', '酪', '醙', '醴', '釆', '里', '量', '金', '鈴', '鈸', '鉶', '鉼', '鋗', '鋘', '錄', '鍊', '鎭', '鏹', '鐕', '钅', '長', '镸', '长', '門', '開', '閭', '閷', '门', '阜', '阝', '阮', '陋', '降', '陵', '陸', '陼', '隆', '隣', '隶', '隷', '隹', '雃', '離', '難', '雨', '零', '雷', '霣', '露', '靈', '靑', '青', '靖', '非', '面', '革', '韋', '韛', '韠', '韦', '韭', '音', '響', '頁', '頋', '領', '頩', '頻', '類', '页', '風', '风', '飛', '飞', '食', '飠', '飢', '飯', '飼', '館', '餩', '饣', '首', '香', '馧', '馬', '駂', '駱', '駾', '驪', '马', '骨', '高', '髟', '鬒', '鬥', '鬯', '鬲', '鬼', '魚', '魯', '鱀', '鱗', '鱼', '鳥', '鳽', '鵧', '鶴', '鷺', '鸞', '鹂', '鹵', '鹿', '麗', '麟', '麥', '麦', '麻', '黃', '黄', '黍', '黎', '黑', '黹', '黽', '黾', '鼅', '鼎', '鼏', '鼓', '鼖', '鼠', '鼻', '齃', '齊', '齐', '齒', '齿', '龍', '龎', '龙', '龜', '龟', '龠', 'ꁊ', 'ꁐ', 'ꃀ', 'ꄲ', 'ꉙ', 'ꊱ', 'ꋍ', 'ꎫ', 'ꎵ', 'ꎿ', 'ꏂ', 'ꑘ', 'ꓕ', 'ꓤ', 'ꓨ', 'ꓶ', 'Ꙍ', 'Ꙙ', 'ꙩ', '꛳', '꛳', 'ꜧ', 'Ꜿ', 'ꜿ', 'ꝰ', 'Ꝺ', 'Ꞇ', 'Ꞓ', 'ꞓ', 'ꞓ', '\u{332}', 'ꟻ', 'ꣻ', '꣼', 'ꦝ', '꧐', 'ꨁ', 'ꨣ', 'ꭑ', '︿', 'ﹲ', '\u{651}', 'ﹴ', '\u{651}', 'ﹶ', '\u{651}', 'ﹷ', '\u{651}', 'ﹸ', '\u{651}', 'ﹹ', '\u{651}', 'ﹺ', '\u{651}', 'ﹻ', '\u{651}', 'ﹼ', '\u{670}', '\u{ff9e}', '\u{ff9f}', '𐊨', '𐊼', '𐋀', '𐎂', '𐎓', '𐎚', '𐐒', '𐐺', '𐒆', '𐦞', '𐩖', '𐩖', '𐲂', '𐲥', '𑐴', '\u{11442}', '𑐒', '𑐴', '\u{11442}', '𑐘', '𑐴', '\u{11442}', '𑐣', '𑐴', '\u{11442}', '𑐩', '𑐴', '\u{11442}', '𑐬', '𑐴', '\u{11442}', '𑐮', '𑑋', '𑑋', '𑖂', '𑖃', '𑖄', '\u{115b2}', '\u{115b3}', '𑙁', '𑙁', '𑫥', '𑫥', '𑫥', '𑫥', '𑫯', '𑫥', '𑫥', '𑫰', '𑫥', '𑫯', '𑫥', '𑫰', '𑫫', '𑫫', '𑫫', '𑫫', '𑫯', '𑫫', '𑫯', '𑫳', '𑫯', '𑫳', '𑫰', '𑫳', '𑫳', '𑫳', '𑫳', '𑫯', '𑫳', '𑫳', '𑫰', '𑱁', '𑱁', '\u{11caa}', '𖼀', '𝅘', '\u{1d165}', '𝅘', '\u{1d165}', '\u{1d16e}', '🄍', '🄎', '𠄢', '𠔜', '𠔥', '𠕋', '𠘺', '𠠄', '𠣞', '𠨬', '𠭣', '𡓤', '𡚨', '𡛪', '𡧈', '𡬘', '𡴋', '𡷤', '𡷦', '𢆃', '𢆟', '𢌱', '𢛔', '𢡄', '𢡊', '𢬌', '𢯱', '𣀊', '𣊸', '𣍟', '𣎓', '𣎜', '𣏃', '𣏕', '𣑭', '𣚣', '𣢧', '𣪍', '𣫺', '𣲼', '𣴞', '𣻑', '𣽞', '𣾎', '𤉣', '𤋮', '𤎫', '𤘈', '𤜵', '𤠔', '𤰶', '𤲒', '𤾡', '𤾸', '𥁄', '𥃲', '𥃳', '𥄙', '𥄳', '𥉉', '𥐝', '𥘦', '𥚚', '𥛅', '𥥼', '𥪧', '𥮫', '𥲀', '𥳐', '𥾆', '𦇚', '𦈨', '𦉇', '𦋙', '𦌾', '𦓚', '𦔣', '𦖨', '𦞧', '𦞵', '𦬼', '𦰶', '𦳕', '𦵫', '𦼬', '𦾱', '𧃒', '𧏊', '𧙧', '𧢮', '𧥦', '𧲨', '𧻓', '𧼯', '𨗒', '𨗭', '𨜮', '𨯺', '𨵷', '𩅅', '𩇟', '𩈚', '𩐊', '𩒖', '𩖶', '𩬰', '𪃎', '𪄅', '𪈎', '𪊑', '𪎒', '𪘀'];
+pub static SALTS: [u16; 6355] = [0, 1, 0, 3, 2, 5, 1, 4, 0, 0, 1, 1, 0, 1, 3, 1, 3, 2, 2, 0, 6, 8, 0, 3, 1, 1, 3, 1, 5, 1, 3, 0, 0, 1, 0, 2, 1, 0, 1, 0, 1, 2, 0, 3, 0, 3, 0, 1, 3, 0, 1, 0, 3, 1, 4, 0, 1, 1, 7, 1, 3, 0, 0, 1, 0, 0, 1, 1, 0, 2, 5, 1, 3, 1, 0, 0, 1, 0, 0, 2, 1, 8, 0, 0, 0, 9, 1, 0, 1, 2, 2, 0, 0, 2, 5, 1, 0, 0, 5, 0, 0, 0, 0, 7, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 1, 3, 3, 1, 3, 0, 1, 0, 3, 10, 0, 5, 1, 5, 1, 3, 0, 0, 1, 2, 1, 5, 0, 0, 1, 0, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 1, 2, 1, 3, 2, 2, 0, 1, 1, 2, 1, 1, 0, 4, 0, 0, 0, 2, 0, 1, 1, 6, 2, 2, 1, 2, 0, 3, 2, 1, 3, 0, 0, 0, 2, 0, 1, 1, 0, 1, 2, 4, 2, 3, 1, 4, 0, 0, 4, 4, 0, 6, 1, 1, 0, 1, 2, 1, 0, 0, 1, 8, 1, 0, 0, 0, 2, 1, 4, 2, 1, 1, 0, 4, 1, 0, 0, 0, 0, 0, 4, 1, 4, 3, 0, 3, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 8, 1, 2, 1, 0, 5, 2, 1, 1, 3, 2, 1, 12, 0, 2, 6, 4, 1, 3, 3, 3, 0, 1, 6, 5, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 3, 0, 4, 1, 1, 5, 0, 0, 1, 3, 1, 1, 1, 3, 1, 1, 1, 0, 2, 0, 3, 7, 1, 1, 2, 0, 0, 1, 3, 2, 2, 4, 0, 1, 0, 1, 2, 0, 1, 2, 1, 0, 1, 1, 1, 0, 0, 3, 2, 1, 2, 0, 5, 0, 0, 2, 2, 0, 0, 1, 3, 1, 0, 2, 2, 2, 1, 1, 1, 0, 1, 2, 1, 12, 4, 3, 0, 0, 0, 1, 1, 0, 3, 8, 0, 5, 1, 0, 2, 1, 0, 3, 0, 3, 0, 3, 1, 2, 0, 0, 0, 2, 0, 0, 0, 4, 3, 3, 2, 2, 0, 2, 7, 2, 0, 0, 3, 5, 0, 7, 0, 0, 0, 4, 1, 0, 0, 0, 2, 2, 1, 0, 2, 0, 1, 0, 1, 2, 0, 0, 0, 5, 10, 2, 10, 2, 0, 7, 1, 3, 1, 0, 0, 0, 2, 1, 2, 0, 1, 0, 1, 2, 8, 1, 1, 3, 1, 5, 1, 4, 1, 0, 3, 2, 2, 4, 1, 1, 1, 4, 3, 0, 5, 2, 2, 1, 4, 0, 3, 1, 2, 8, 1, 5, 0, 0, 1, 2, 0, 1, 5, 1, 0, 1, 0, 2, 0, 9, 2, 0, 0, 2, 3, 0, 0, 1, 1, 0, 14, 1, 1, 2, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 1, 6, 4, 0, 0, 2, 0, 1, 0, 2, 3, 2, 0, 0, 0, 2, 0, 0, 2, 5, 6, 2, 3, 1, 5, 1, 5, 2, 3, 4, 0, 5, 1, 4, 0, 0, 4, 6, 1, 1, 1, 1, 3, 4, 2, 0, 2, 0, 0, 3, 0, 1, 5, 0, 0, 2, 1, 0, 1, 7, 4, 2, 1, 0, 0, 4, 1, 8, 0, 4, 0, 3, 0, 1, 0, 2, 7, 0, 1, 3, 1, 1, 1, 0, 1, 5, 1, 4, 1, 4, 0, 4, 2, 0, 7, 1, 4, 4, 7, 1, 2, 0, 1, 5, 2, 1, 1, 1, 0, 5, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 0, 4, 4, 3, 1, 1, 0, 4, 0, 1, 4, 2, 0, 0, 1, 0, 1, 3, 2, 1, 0, 3, 0, 0, 11, 0, 1, 1, 0, 5, 4, 0, 1, 5, 4, 0, 1, 3, 3, 0, 9, 1, 0, 0, 2, 4, 1, 0, 0, 1, 1, 0, 6, 1, 0, 4, 0, 2, 0, 2, 6, 0, 4, 1, 0, 1, 1, 13, 0, 0, 2, 5, 4, 2, 0, 1, 0, 2, 1, 2, 1, 0, 0, 0, 3, 3, 1, 0, 1, 2, 0, 2, 0, 2, 0, 0, 1, 0, 1, 3, 1, 1, 4, 5, 0, 3, 0, 1, 3, 1, 22, 0, 1, 2, 7, 4, 1, 2, 0, 1, 0, 1, 3, 0, 7, 1, 1, 3, 2, 0, 0, 7, 1, 3, 4, 4, 1, 4, 0, 1, 12, 1, 1, 0, 3, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 2, 3, 0, 3, 6, 0, 0, 1, 0, 2, 1, 5, 1, 0, 0, 2, 3, 1, 3, 1, 5, 1, 3, 5, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 3, 1, 8, 6, 1, 2, 4, 0, 3, 3, 7, 4, 1, 0, 1, 1, 0, 1, 1, 1, 0, 11, 0, 0, 2, 1, 1, 4, 2, 0, 1, 2, 4, 1, 9, 0, 2, 0, 0, 0, 0, 3, 3, 0, 0, 2, 2, 0, 0, 6, 0, 2, 11, 2, 1, 2, 0, 0, 2, 2, 0, 0, 1, 4, 9, 5, 4, 3, 0, 0, 0, 2, 0, 2, 0, 1, 0, 3, 0, 2, 0, 0, 6, 1, 1, 4, 0, 1, 4, 0, 3, 0, 2, 1, 1, 2, 4, 0, 1, 0, 0, 1, 0, 2, 6, 1, 0, 0, 0, 0, 3, 1, 1, 1, 0, 1, 3, 0, 0, 1, 2, 0, 1, 1, 2, 1, 0, 2, 0, 0, 0, 1, 0, 2, 1, 0, 3, 1, 1, 1, 1, 0, 0, 10, 2, 0, 0, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 4, 0, 1, 7, 2, 5, 1, 0, 0, 2, 2, 8, 2, 1, 2, 2, 6, 1, 0, 0, 1, 3, 6, 3, 0, 3, 0, 8, 0, 1, 1, 14, 2, 2, 4, 0, 3, 0, 4, 0, 1, 2, 7, 0, 11, 2, 1, 0, 1, 0, 0, 17, 3, 1, 0, 4, 0, 4, 2, 0, 0, 3, 0, 2, 0, 4, 5, 1, 1, 0, 1, 0, 0, 1, 1, 5, 0, 6, 2, 0, 2, 0, 2, 0, 4, 1, 3, 0, 2, 1, 3, 0, 1, 0, 1, 7, 0, 1, 4, 0, 0, 0, 2, 0, 0, 0, 1, 5, 0, 5, 4, 0, 0, 0, 2, 3, 0, 5, 0, 5, 4, 6, 1, 1, 0, 2, 1, 2, 3, 0, 1, 7, 1, 4, 0, 0, 2, 5, 1, 0, 1, 0, 8, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 1, 0, 0, 4, 0, 0, 0, 7, 16, 0, 7, 0, 3, 3, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 0, 0, 3, 4, 0, 21, 1, 0, 0, 0, 8, 1, 4, 2, 0, 1, 6, 0, 1, 0, 2, 2, 1, 1, 0, 1, 0, 6, 0, 2, 2, 2, 0, 1, 1, 0, 0, 1, 5, 1, 9, 0, 0, 1, 0, 1, 1, 3, 3, 0, 0, 2, 4, 0, 3, 1, 14, 0, 0, 0, 0, 3, 1, 3, 0, 1, 1, 1, 4, 2, 0, 7, 2, 6, 1, 1, 0, 2, 2, 6, 6, 0, 2, 0, 0, 2, 2, 4, 1, 1, 0, 1, 2, 2, 0, 2, 0, 4, 5, 3, 0, 3, 0, 1, 1, 0, 0, 0, 2, 0, 1, 3, 1, 3, 1, 1, 1, 3, 1, 1, 3, 0, 10, 0, 2, 4, 1, 1, 0, 0, 0, 0, 3, 0, 3, 1, 0, 0, 4, 0, 3, 2, 0, 2, 0, 0, 2, 4, 0, 0, 0, 2, 3, 2, 5, 0, 1, 0, 3, 3, 0, 0, 3, 2, 0, 0, 1, 1, 0, 0, 1, 4, 1, 0, 3, 0, 2, 1, 2, 4, 0, 1, 8, 0, 3, 0, 3, 2, 2, 1, 1, 0, 1, 0, 4, 0, 0, 0, 11, 4, 0, 0, 4, 0, 11, 3, 1, 1, 3, 0, 0, 3, 2, 4, 3, 2, 0, 0, 8, 2, 0, 2, 4, 4, 5, 1, 3, 1, 1, 6, 1, 2, 1, 0, 3, 0, 1, 4, 0, 3, 1, 2, 1, 5, 1, 0, 1, 1, 1, 0, 9, 1, 4, 1, 1, 0, 0, 2, 1, 1, 0, 0, 2, 9, 1, 1, 3, 3, 0, 0, 3, 4, 0, 0, 0, 3, 0, 1, 1, 0, 0, 1, 8, 1, 1, 0, 2, 8, 3, 1, 0, 0, 2, 2, 0, 1, 1, 0, 2, 0, 0, 0, 1, 1, 2, 4, 1, 1, 5, 0, 3, 0, 1, 2, 0, 0, 3, 0, 1, 2, 3, 2, 3, 0, 1, 1, 1, 0, 0, 2, 6, 1, 0, 0, 3, 0, 1, 9, 1, 5, 2, 0, 5, 0, 0, 0, 0, 6, 0, 2, 1, 9, 6, 0, 0, 7, 0, 3, 1, 5, 3, 2, 1, 0, 0, 0, 1, 0, 0, 4, 0, 2, 1, 0, 1, 4, 0, 1, 1, 8, 1, 0, 4, 8, 2, 0, 0, 0, 0, 1, 3, 3, 5, 2, 3, 0, 3, 2, 2, 7, 0, 0, 2, 1, 5, 2, 1, 1, 0, 0, 0, 1, 0, 2, 5, 2, 7, 1, 1, 0, 3, 2, 0, 3, 2, 3, 5, 1, 3, 5, 0, 2, 0, 15, 1, 1, 1, 2, 0, 0, 4, 1, 1, 0, 2, 1, 1, 0, 1, 2, 0, 5, 0, 0, 0, 2, 0, 1, 0, 2, 2, 2, 1, 1, 6, 1, 0, 1, 0, 0, 1, 0, 4, 0, 2, 0, 2, 11, 1, 0, 0, 1, 2, 0, 2, 0, 1, 0, 0, 0, 0, 0, 3, 3, 2, 4, 0, 0, 0, 2, 1, 0, 7, 2, 4, 0, 1, 1, 0, 0, 3, 2, 4, 5, 1, 0, 0, 0, 0, 2, 8, 8, 1, 1, 1, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 3, 2, 4, 6, 12, 0, 0, 2, 0, 0, 0, 1, 9, 2, 0, 2, 7, 0, 1, 1, 0, 3, 3, 0, 2, 2, 2, 2, 1, 6, 0, 1, 0, 0, 3, 4, 0, 0, 5, 3, 1, 1, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 7, 6, 2, 2, 0, 0, 0, 10, 0, 3, 4, 3, 1, 0, 1, 2, 8, 2, 3, 3, 0, 0, 1, 6, 3, 1, 1, 5, 3, 1, 5, 5, 17, 0, 2, 5, 0, 0, 0, 6, 2, 1, 1, 0, 0, 0, 0, 3, 8, 1, 1, 0, 1, 0, 7, 2, 0, 0, 4, 1, 2, 0, 1, 2, 0, 1, 0, 2, 0, 1, 2, 1, 1, 7, 0, 0, 1, 6, 4, 0, 1, 1, 0, 1, 7, 2, 0, 4, 2, 2, 8, 0, 0, 0, 0, 1, 1, 3, 0, 0, 10, 0, 2, 2, 0, 1, 0, 1, 5, 5, 0, 0, 9, 1, 2, 1, 2, 0, 4, 0, 2, 4, 2, 0, 3, 1, 0, 3, 1, 2, 0, 0, 0, 2, 8, 3, 1, 1, 3, 3, 5, 2, 1, 3, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 6, 0, 1, 3, 4, 0, 1, 6, 3, 1, 0, 9, 3, 2, 10, 0, 0, 0, 3, 0, 1, 0, 1, 3, 1, 3, 0, 3, 10, 0, 4, 1, 1, 17, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 11, 0, 0, 1, 1, 0, 6, 1, 3, 0, 0, 1, 0, 3, 4, 2, 1, 2, 2, 0, 4, 1, 0, 0, 3, 1, 1, 1, 9, 0, 1, 1, 1, 0, 2, 0, 0, 5, 4, 0, 0, 0, 0, 1, 0, 0, 1, 3, 1, 0, 2, 1, 9, 1, 1, 0, 1, 2, 0, 0, 0, 1, 2, 2, 4, 1, 5, 1, 2, 0, 10, 3, 2, 6, 11, 2, 1, 1, 0, 2, 0, 1, 6, 3, 0, 3, 1, 7, 0, 0, 5, 0, 0, 5, 1, 0, 2, 5, 0, 0, 3, 0, 0, 1, 0, 7, 0, 1, 1, 3, 3, 0, 1, 1, 0, 0, 2, 8, 2, 0, 6, 1, 0, 1, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 2, 2, 2, 4, 2, 2, 1, 2, 2, 1, 0, 0, 1, 9, 0, 0, 3, 4, 0, 2, 0, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 3, 6, 1, 1, 1, 2, 1, 1, 2, 0, 1, 2, 4, 0, 1, 2, 4, 3, 1, 0, 1, 0, 1, 0, 1, 7, 0, 3, 2, 3, 2, 0, 3, 1, 2, 9, 1, 1, 2, 6, 3, 0, 1, 2, 1, 0, 1, 0, 1, 4, 0, 1, 0, 0, 0, 2, 20, 0, 1, 0, 3, 3, 3, 4, 0, 0, 5, 7, 3, 2, 0, 0, 2, 3, 1, 5, 0, 0, 1, 4, 0, 2, 1, 0, 8, 0, 3, 0, 2, 1, 1, 0, 0, 1, 2, 2, 2, 0, 2, 1, 0, 5, 2, 0, 11, 2, 1, 17, 0, 1, 0, 0, 2, 1, 0, 1, 2, 1, 6, 1, 4, 1, 1, 4, 0, 3, 0, 5, 0, 2, 5, 1, 0, 0, 0, 0, 1, 0, 3, 0, 0, 3, 3, 0, 1, 2, 0, 0, 8, 0, 0, 1, 9, 2, 10, 1, 0, 2, 7, 0, 3, 3, 5, 2, 1, 3, 0, 2, 4, 1, 3, 0, 1, 0, 0, 1, 0, 0, 0, 1, 8, 2, 9, 4, 0, 0, 18, 1, 0, 3, 2, 5, 0, 1, 0, 1, 1, 9, 1, 3, 0, 1, 8, 10, 8, 0, 0, 0, 0, 5, 0, 4, 1, 2, 1, 1, 0, 3, 1, 1, 0, 2, 0, 2, 0, 1, 0, 0, 0, 4, 1, 1, 0, 1, 1, 0, 2, 6, 0, 5, 3, 0, 2, 9, 0, 0, 2, 0, 1, 4, 2, 3, 0, 1, 1, 0, 9, 0, 1, 0, 1, 0, 2, 2, 7, 1, 2, 4, 0, 0, 2, 0, 3, 2, 0, 2, 5, 2, 0, 2, 3, 0, 9, 1, 0, 0, 0, 1, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 3, 2, 1, 1, 0, 1, 0, 0, 2, 0, 1, 0, 4, 1, 5, 3, 4, 0, 5, 1, 1, 0, 1, 0, 0, 2, 4, 1, 2, 0, 8, 0, 0, 3, 0, 0, 0, 1, 1, 2, 3, 18, 2, 0, 8, 3, 1, 0, 8, 3, 1, 1, 0, 0, 1, 1, 0, 0, 3, 7, 1, 1, 0, 3, 0, 1, 2, 0, 1, 6, 1, 1, 0, 1, 2, 2, 3, 1, 1, 5, 1, 2, 3, 0, 5, 3, 7, 1, 2, 7, 0, 7, 6, 0, 0, 6, 7, 3, 4, 0, 0, 0, 1, 0, 2, 1, 0, 0, 3, 0, 1, 1, 0, 12, 2, 2, 1, 6, 6, 1, 0, 1, 1, 0, 0, 1, 1, 5, 0, 1, 3, 0, 3, 3, 11, 0, 0, 1, 0, 1, 3, 1, 4, 0, 0, 0, 0, 2, 2, 6, 0, 7, 0, 5, 0, 0, 1, 1, 0, 1, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 6, 5, 3, 2, 0, 0, 2, 4, 2, 3, 0, 5, 2, 0, 3, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 5, 3, 1, 3, 9, 3, 8, 2, 1, 0, 0, 0, 0, 12, 1, 7, 0, 2, 0, 1, 6, 0, 2, 1, 0, 1, 3, 16, 2, 0, 6, 0, 0, 1, 6, 0, 0, 5, 0, 0, 0, 5, 5, 0, 1, 10, 4, 0, 2, 1, 7, 0, 5, 0, 0, 0, 1, 3, 2, 3, 1, 3, 6, 4, 2, 1, 0, 0, 2, 0, 7, 1, 0, 0, 0, 3, 7, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 14, 0, 0, 0, 1, 2, 0, 0, 5, 1, 3, 1, 1, 2, 0, 1, 0, 3, 1, 1, 13, 5, 1, 0, 0, 2, 1, 3, 26, 0, 1, 0, 2, 0, 5, 11, 0, 0, 0, 6, 0, 2, 3, 9, 0, 0, 10, 2, 1, 0, 1, 0, 0, 0, 4, 5, 3, 0, 4, 18, 23, 1, 1, 1, 4, 3, 1, 0, 2, 5, 0, 2, 1, 11, 0, 5, 0, 0, 7, 1, 10, 0, 6, 4, 3, 0, 2, 8, 0, 7, 13, 1, 0, 6, 1, 1, 0, 0, 3, 12, 0, 3, 1, 2, 0, 1, 4, 2, 1, 3, 0, 3, 0, 0, 0, 3, 2, 1, 0, 3, 1, 3, 8, 1, 0, 5, 2, 1, 0, 6, 1, 0, 0, 6, 5, 0, 1, 0, 1, 4, 0, 13, 1, 3, 15, 5, 0, 0, 0, 5, 5, 0, 0, 0, 4, 1, 2, 0, 7, 0, 3, 1, 1, 4, 0, 0, 0, 2, 1, 0, 0, 0, 2, 1, 1, 0, 0, 1, 5, 6, 1, 5, 2, 2, 3, 0, 3, 8, 0, 3, 0, 2, 0, 13, 1, 2, 0, 1, 0, 0, 1, 14, 9, 12, 0, 1, 2, 0, 1, 7, 0, 14, 3, 0, 0, 3, 12, 0, 0, 3, 0, 2, 1, 11, 6, 0, 0, 2, 8, 0, 1, 0, 2, 3, 1, 0, 2, 0, 0, 5, 5, 2, 2, 2, 1, 5, 6, 6, 0, 5, 0, 11, 3, 0, 1, 2, 0, 1, 1, 0, 0, 5, 2, 9, 2, 14, 9, 0, 3, 6, 3, 1, 1, 2, 1, 14, 4, 10, 0, 1, 0, 1, 1, 0, 4, 2, 0, 0, 0, 0, 3, 0, 5, 18, 3, 1, 1, 3, 6, 0, 0, 3, 3, 0, 1, 1, 0, 2, 0, 3, 3, 0, 3, 6, 0, 1, 0, 7, 1, 0, 11, 3, 1, 0, 9, 0, 14, 4, 5, 0, 1, 2, 13, 1, 1, 2, 0, 4, 3, 0, 0, 10, 3, 1, 3, 0, 2, 1, 1, 5, 0, 0, 0, 1, 0, 4, 5, 6, 6, 0, 4, 12, 5, 1, 1, 0, 2, 1, 3, 1, 1, 3, 0, 0, 2, 1, 2, 10, 1, 0, 0, 3, 14, 0, 4, 3, 18, 9, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 2, 0, 5, 2, 2, 2, 1, 0, 0, 0, 27, 0, 2, 0, 0, 2, 1, 14, 5, 2, 0, 2, 2, 0, 3, 1, 2, 0, 0, 0, 1, 0, 10, 8, 8, 3, 1, 1, 0, 7, 2, 5, 7, 1, 0, 4, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 2, 1, 0, 0, 1, 2, 6, 0, 7, 2, 4, 6, 0, 11, 1, 0, 1, 2, 3, 0, 0, 2, 4, 3, 2, 1, 12, 4, 2, 7, 2, 0, 4, 0, 1, 4, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 2, 1, 0, 3, 0, 2, 0, 3, 4, 1, 1, 15, 3, 7, 6, 6, 0, 9, 2, 0, 0, 0, 3, 0, 2, 0, 27, 1, 0, 5, 2, 0, 0, 0, 5, 0, 2, 4, 0, 0, 6, 1, 12, 1, 0, 3, 1, 1, 0, 20, 0, 0, 9, 13, 2, 1, 0, 0, 0, 8, 0, 0, 1, 0, 1, 2, 0, 13, 3, 0, 11, 0, 15, 8, 0, 10, 1, 0, 1, 7, 1, 0, 3, 0, 4, 1, 1, 8, 0, 0, 0, 4, 1, 3, 0, 3, 0, 0, 0, 2, 2, 0, 0, 14, 2, 0, 5, 2, 0, 0, 0, 0, 3, 11, 9, 0, 21, 19, 1, 0, 1, 5, 3, 3, 0, 0, 6, 1, 1, 2, 2, 1, 3, 1, 2, 3, 1, 2, 2, 1, 1, 10, 7, 0, 3, 0, 1, 4, 3, 3, 3, 0, 5, 0, 8, 4, 3, 4, 10, 4, 0, 0, 0, 1, 0, 1, 1, 0, 2, 0, 4, 1, 0, 7, 0, 3, 3, 4, 0, 1, 2, 10, 0, 4, 7, 4, 2, 1, 1, 0, 0, 0, 0, 4, 4, 5, 6, 2, 2, 7, 1, 0, 0, 6, 0, 2, 2, 1, 0, 0, 9, 2, 0, 5, 6, 1, 4, 1, 0, 11, 0, 0, 0, 0, 5, 4, 3, 7, 3, 6, 0, 2, 14, 0, 15, 1, 1, 1, 5, 1, 4, 0, 0, 2, 3, 1, 7, 1, 0, 0, 0, 3, 2, 0, 1, 7, 3, 2, 3, 4, 0, 3, 2, 0, 10, 4, 0, 4, 0, 3, 3, 2, 0, 0, 6, 1, 8, 1, 1, 0, 14, 1, 3, 0, 0, 8, 0, 7, 4, 0, 3, 0, 1, 0, 0, 0, 0, 6, 2, 8, 1, 3, 3, 0, 9, 0, 0, 0, 0, 2, 0, 4, 4, 3, 0, 0, 0, 4, 0, 3, 8, 0, 22, 4, 0, 9, 0, 15, 7, 6, 0, 3, 15, 0, 1, 9, 0, 2, 3, 2, 1, 0, 3, 1, 0, 0, 0, 1, 13, 4, 0, 0, 1, 0, 6, 0, 6, 1, 2, 0, 3, 0, 5, 4, 19, 0, 0, 3, 18, 2, 5, 0, 7, 5, 6, 3, 7, 6, 0, 6, 3, 1, 0, 8, 0, 0, 1, 0, 1, 0, 5, 1, 8, 0, 2, 0, 6, 6, 2, 3, 14, 1, 0, 0, 0, 1, 1, 5, 20, 0, 1, 9, 0, 0, 0, 12, 0, 2, 2, 4, 0, 12, 0, 5, 2, 0, 1, 3, 18, 0, 3, 1, 17, 0, 0, 5, 0, 7, 1, 10, 2, 1, 5, 0, 0, 5, 3, 2, 0, 1, 3, 1, 0, 21, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0, 1, 5, 0, 0, 3, 4, 0, 0, 6, 2, 0, 12, 1, 0, 18, 0, 4, 2, 2, 1, 0, 2, 2, 1, 3, 4, 3, 0, 5, 0, 8, 1, 1, 1, 2, 0, 12, 0, 1, 1, 3, 4, 5, 0, 1, 2, 20, 0, 23, 6, 0, 1, 3, 1, 0, 3, 9, 7, 0, 11, 0, 4, 0, 3, 2, 14, 17, 3, 0, 3, 3, 3, 0, 21, 10, 0, 1, 2, 5, 0, 1, 0, 0, 0, 1, 3, 2, 2, 10, 0, 0, 2, 0, 0, 0, 1, 1, 10, 5, 0, 0, 4, 20, 12, 4, 12, 4, 6, 0, 0, 0, 2, 17, 1, 28, 6, 0, 3, 2, 1, 2, 0, 6, 1, 11, 0, 0, 0, 0, 2, 22, 17, 2, 14, 4, 6, 5, 11, 0, 4, 5, 0, 4, 0, 2, 1, 0, 0, 2, 0, 3, 0, 5, 1, 1, 5, 2, 12, 0, 1, 3, 1, 2, 1, 0, 1, 6, 3, 2, 0, 4, 17, 0, 3, 3, 0, 1, 0, 0, 0, 0, 20, 0, 0, 0, 4, 3, 1, 2, 2, 14, 9, 0, 0, 3, 3, 6, 0, 3, 1, 1, 0, 0, 4, 2, 2, 0, 0, 1, 11, 0, 0, 3, 0, 2, 0, 0, 1, 0, 0, 1, 21, 0, 5, 0, 1, 0, 2, 4, 1, 4, 0, 0, 2, 8, 1, 1, 0, 3, 4, 4, 0, 1, 1, 0, 0, 0, 0, 3, 0, 19, 1, 5, 2, 27, 0, 7, 1, 2, 1, 0, 6, 0, 0, 11, 0, 1, 2, 0, 0, 0, 1, 2, 0, 1, 0, 1, 16, 2, 0, 1, 1, 11, 1, 8, 3, 10, 13, 13, 0, 0, 0, 16, 1, 1, 3, 1, 1, 0, 2, 1, 3, 0, 3, 0, 0, 2, 2, 3, 6, 0, 1, 2, 1, 0, 1, 2, 0, 1, 0, 12, 2, 2, 0, 1, 5, 3, 0, 0, 8, 0, 0, 5, 6, 1, 27, 4, 2, 4, 2, 22, 0, 0, 0, 1, 1, 4, 9, 0, 0, 0, 9, 0, 5, 3, 3, 3, 9, 0, 0, 0, 0, 0, 0, 2, 2, 7, 10, 4, 18, 0, 0, 0, 0, 8, 0, 1, 0, 2, 0, 2, 4, 0, 10, 6, 1, 0, 1, 7, 1, 0, 2, 0, 12, 1, 5, 0, 0, 0, 0, 10, 12, 0, 0, 0, 0, 2, 3, 7, 0, 0, 2, 0, 0, 0, 7, 10, 2, 14, 7, 1, 7, 0, 11, 0, 0, 5, 3, 3, 0, 13, 0, 6, 0, 1, 8, 19, 1, 17, 1, 11, 3, 0, 2, 1, 2, 2, 12, 1, 0, 7, 8, 2, 0, 0, 1, 0, 0, 7, 0, 0, 0, 0, 0, 10, 5, 9, 39, 0, 0, 9, 0, 0, 6, 0, 2, 4, 0, 16, 1, 1, 0, 2, 10, 4, 0, 0, 16, 0, 0, 2, 1, 0, 2, 4, 1, 0, 9, 1, 3, 1, 0, 9, 3, 0, 1, 0, 0, 3, 2, 0, 1, 11, 0, 14, 1, 1, 14, 0, 4, 1, 7, 8, 1, 2, 0, 0, 1, 3, 0, 0, 0, 0, 9, 2, 3, 2, 5, 2, 5, 9, 0, 12, 0, 27, 0, 0, 0, 18, 5, 1, 6, 0, 0, 3, 5, 0, 4, 15, 0, 1, 0, 0, 3, 2, 3, 0, 6, 2, 1, 1, 23, 27, 1, 2, 5, 7, 5, 3, 15, 0, 3, 0, 5, 3, 0, 6, 0, 14, 14, 5, 0, 4, 0, 0, 1, 0, 2, 4, 0, 0, 16, 21, 0, 0, 0, 0, 5, 0, 0, 0, 8, 2, 7, 8, 0, 2, 3, 0, 1, 2, 4, 1, 10, 2, 2, 3, 6, 4, 1, 0, 27, 1, 0, 0, 1, 5, 0, 3, 0, 0, 1, 0, 21, 12, 1, 9, 0, 5, 0, 4, 10, 0, 2, 0, 0, 1, 0, 5, 5, 14, 7, 1, 0, 5, 2, 10, 15, 0, 3, 1, 0, 3, 1, 20, 5, 4, 2, 2, 16, 11, 6, 0, 11, 0, 2, 0, 2, 1, 2, 11, 0, 0, 12, 0, 3, 2, 0, 1, 11, 0, 0, 2, 0, 12, 19, 0, 2, 7, 1, 0, 2, 6, 4, 2, 12, 17, 4, 0, 6, 0, 5, 0, 9, 0, 0, 0, 0, 3, 0, 1, 0, 3, 7, 14, 18, 0, 0, 2, 1, 10, 8, 8, 1, 2, 1, 7, 2, 1, 0, 0, 0, 0, 0, 0, 0, 6, 22, 15, 4, 10, 26, 7, 0, 0, 0, 4, 9, 4, 0, 11, 11, 0, 30, 0, 0, 1, 4, 1, 0, 0, 1, 68, 0, 8, 9, 1, 0, 1, 2, 5, 3, 1, 0, 0, 0, 0, 5, 4, 4, 6, 0, 13, 6, 0, 2, 14, 26, 0, 0, 3, 4, 0, 2, 2, 2, 11, 11, 4, 3, 0, 0, 0, 7, 3, 9, 1, 9, 11, 1, 3, 2, 0, 7, 41, 2, 11, 0, 0, 2, 0, 0, 0, 3, 3, 8, 0, 7, 13, 0, 5, 12, 10, 2, 10, 2, 2, 28, 2, 0, 0, 14, 3, 13, 3, 22, 6, 15, 1, 4, 0, 3, 5, 0, 2, 0, 0, 1, 1, 0, 8, 0, 5, 1, 9, 2, 0, 1, 7, 1, 4, 0, 10, 0, 2, 5, 0, 28, 0, 13, 6, 8, 1, 0, 1, 3, 14, 6, 0, 1, 5, 0, 0, 15, 0, 17, 21, 4, 0, 1, 17, 5, 1, 2, 8, 2, 4, 3, 2, 0, 7, 0, 0, 5, 1, 1, 0, 0, 0, 0, 20, 7, 1, 4, 0, 0, 10, 0, 4, 4, 8, 2, 1, 0, 9, 18, 4, 3, 7, 1, 1, 2, 0, 8, 3, 0, 4, 21, 6, 0, 12, 20, 7, 3, 0, 10, 2, 1, 0, 1, 0, 5, 0, 0, 0, 2, 1, 0, 4, 0, 11, 0, 0, 6, 3, 3, 0, 0, 15, 15, 0, 12, 1, 6, 4, 22, 32, 1, 27, 0, 0, 0, 11, 1, 12, 0, 10, 9, 2, 3, 1, 0, 0, 0, 0, 0, 3, 9, 4, 0, 1, 13, 18, 0, 0, 0, 6, 19, 0, 0, 1, 0, 1, 0, 5, 0, 1, 0, 9, 30, 10, 0, 0, 0, 0, 0, 18, 13, 9, 0, 0, 2, 18, 0, 4, 4, 3, 3, 0, 5, 0, 0, 0, 9, 0, 8, 0, 26, 0, 0, 45, 2, 0, 12, 0, 1, 5, 1, 20, 11, 18, 2, 3, 1, 35, 22, 10, 2, 9, 0, 20, 2, 0, 3, 0, 0, 1, 6, 19, 4, 8, 20, 0, 4, 7, 73, 2, 9, 0, 0, 46, 2, 0, 3, 2, 0, 0, 22, 3, 0, 0, 1, 4, 4, 14, 7, 0, 0, 0, 5, 0, 0, 0, 0, 3, 7, 3, 1, 4, 22, 0, 14, 1, 7, 5, 0, 16, 1, 1, 0, 0, 3, 0, 0, 0, 1, 11, 7, 10, 10, 2, 1, 8, 3, 0, 1, 0, 17, 0, 11, 7, 3, 7, 0, 9, 13, 0, 7, 0, 0, 2, 2, 5, 1, 1, 0, 0, 4, 10, 0, 5, 1, 9, 8, 5, 7, 28, 4, 2, 1, 4, 1, 11, 2, 3, 2, 38, 6, 0, 0, 0, 1, 5, 4, 13, 13, 5, 8, 0, 0, 0, 0, 1, 23, 20, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 5, 9, 0, 0, 16, 3, 11, 20, 11, 3, 11, 4, 0, 4, 1, 40, 5, 1, 19, 4, 2, 1, 2, 5, 4, 0, 0, 4, 14, 0, 1, 2, 0, 0, 0, 0, 1, 0, 25, 0, 14, 14, 0, 7, 15, 13, 0, 3, 0, 12, 0, 0, 2, 12, 0, 1, 1, 16, 5, 0, 4, 3, 0, 17, 0, 3, 0, 0, 0, 0, 1, 5, 3, 0, 4, 2, 8, 1, 0, 6, 2, 2, 4, 0, 0, 12, 6, 7, 0, 0, 3, 2, 0, 1, 13, 1, 42, 15, 0, 4, 9, 0, 0, 1, 0, 14, 4, 17, 10, 0, 3, 0, 0, 0, 16, 11, 3, 1, 0, 7, 1, 0, 12, 1, 1, 0, 1, 0, 0, 18, 0, 0, 5, 43, 19, 5, 14, 15, 2, 9, 0, 1, 3, 2, 10, 6, 10, 0, 12, 0, 0, 0, 11, 0, 3, 7, 4, 9, 0, 0, 10, 10, 0, 0, 0, 8, 0, 2, 49, 2, 0, 0, 0, 20, 0, 3, 1, 0, 9, 0, 2, 1, 12, 2, 0, 79, 2, 2, 2, 26, 7, 1, 1, 0, 0, 1, 0, 0, 19, 0, 0, 0, 10, 2, 5, 39, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 11, 3, 17, 0, 5, 4, 33, 0, 9, 2, 1, 13, 0, 1, 6, 6, 4, 27, 0, 3, 3, 0, 18, 32, 19, 0, 18, 1, 0, 5, 18, 0, 2, 1, 1, 46, 0, 1, 0, 9, 0, 4, 1, 10, 11, 19, 0, 2, 37, 0, 0, 4, 34, 0, 2, 6, 2, 0, 0, 6, 25, 3, 0, 2, 1, 4, 0, 1, 4, 37, 0, 14, 0, 4, 6, 0, 0, 50, 6, 3, 14, 10, 0, 1, 2, 0, 3, 0, 11, 0, 0, 3, 3, 0, 0, 0, 2, 8, 12, 9, 11, 18, 0, 6, 0, 0, 0, 0, 3, 0, 0, 3, 13, 10, 2, 22, 0, 8, 0, 2, 3, 45, 2, 9, 21, 50, 3, 16, 0, 0, 1, 7, 0, 11, 16, 0, 0, 13, 4, 2, 0, 0, 7, 1, 12, 2, 0, 35, 0, 3, 4, 11, 0, 16, 0, 9, 3, 2, 1, 0, 2, 1, 18, 0, 3, 1, 1, 0, 1, 4, 12, 0, 6, 29, 17, 2, 2, 1, 6, 0, 18, 1, 0, 2, 0, 39, 0, 9, 8, 8, 0, 10, 2, 11, 0, 0, 0, 0, 0, 5, 8, 8, 0, 0, 0, 12, 4, 2, 0, 2, 2, 0, 0, 3, 0, 5, 1, 0, 0, 13, 3, 0, 43, 0, 13, 4, 2, 1, 0, 14, 1, 0, 0, 10, 0, 0, 13, 0, 1, 14, 19, 12, 0, 3, 0, 0, 16, 54, 9, 6, 7, 35, 15, 5, 10, 11, 0, 0, 5, 1, 0, 7, 8, 15, 13, 26, 17, 2, 23, 26, 0, 7, 0, 0, 1, 11, 0, 0, 35, 10, 0, 31, 21, 2, 3, 1, 0, 43, 0, 0, 8, 3, 12, 0, 0, 0, 21, 14, 0, 1, 1, 0, 11, 22, 34, 27, 3, 0, 0, 0, 0, 0, 13, 5, 6, 14, 1, 0, 0, 2, 0, 32, 4, 6, 1, 14, 3, 1, 8, 2, 0, 37, 13, 4, 2, 7, 3, 7, 5, 56, 9, 18, 1, 38, 1, 2, 0, 9, 0, 33, 0, 23, 12, 0, 27, 5, 3, 26, 13, 0, 33, 2, 11, 0, 8, 15, 0, 0, 31, 3, 35, 13, 24, 0, 0, 7, 22, 0, 42, 0, 11, 13, 44, 6, 10, 39, 4, 12, 0, 0, 6, 0, 1, 3, 0, 0, 10, 8, 4, 2, 0, 6, 0, 0, 1, 0, 27, 0, 0, 2, 0, 0, 1, 34, 6, 47, 0, 65, 0, 0, 18, 44, 3, 0, 26, 5, 3, 0, 82, 23, 37, 12, 0, 0, 1, 0, 3, 10, 3, 0, 0, 0, 0, 9, 5, 0, 0, 1, 0, 70, 0, 1, 7, 2, 4, 33, 0, 32, 0, 0, 0, 0, 0, 8, 0, 0, 2, 0, 1, 3, 13, 33, 14, 1, 42, 1, 5, 28, 46, 0, 2, 2, 0, 2, 20, 29, 5, 0, 0, 0, 18, 25, 0, 2, 0, 1, 7, 0, 1, 3, 48, 8, 45, 0, 1, 0, 3, 14, 1, 0, 2, 26, 17, 7, 1, 3, 22, 82, 20, 0, 0, 6, 4, 7, 0, 0, 6, 1, 13, 32, 0, 0, 0, 0, 11, 0, 0, 0, 12, 6, 28, 0, 1, 0, 0, 0, 17, 14, 2, 13, 53, 0, 22, 1, 13, 0, 10, 0, 1, 17, 0, 0, 43, 0, 3, 3, 0, 19, 59, 9, 49, 0, 4, 0, 0, 1, 0, 0, 0, 0, 15, 44, 0, 44, 0, 2, 1, 0, 16, 4, 0, 0, 39, 0, 27, 0, 49, 0, 4, 0, 2, 43, 0, 0, 0, 1, 0, 38, 21, 0, 66, 0, 0, 1, 61, 103, 5, 0, 0, 1, 17, 19, 0, 27, 26, 44, 15, 30, 16, 6, 9, 5, 0, 1, 24, 3, 5, 0, 23, 113, 90, 0, 47, 2, 0, 1, 54, 8, 3, 12, 0, 38, 3, 0, 0, 3, 40, 0, 0, 44, 22, 0, 20, 3, 0, 4, 5, 1, 0, 17, 1, 5, 93, 31, 15, 0, 21, 0, 5, 0, 0, 0, 1, 29, 0, 1, 9, 0, 6, 22, 0, 0, 6, 0, 0, 6, 0, 0, 4, 6, 0, 0, 45, 11, 61, 0, 27, 0, 0, 43, 26, 0, 0, 1, 2, 0, 42, 0, 0, 1, 61, 5, 1, 69, 31, 32, 0, 8, 5, 1, 17, 11, 53, 35, 0, 0, 1, 56, 25, 30, 1, 55, 15, 24, 0, 0, 32, 0, 42, 8, 0, 1, 0, 0, 91, 23, 82, 0, 1, 8, 55, 9, 0, 0, 6, 31, 7, 4, 15, 2, 44, 2, 10, 73, 0, 58, 6, 3, 0, 11, 0, 29, 50, 14, 6, 14, 2, 29, 26, 0, 4, 15, 9, 15, 75, 143, 6, 16, 0, 1, 0, 86, 0, 9, 0, 15, 0, 0, 6, 0, 97, 0, 0, 0, 0, 0, 30, 32, 125, 0, 0, 1, 0, 0, 92, 43, 38, 0, 0, 2, 16, 22, 1, 2, 0, 21, 0, 0, 54, 0, 0, 0, 4, 0, 12, 0, 0, 1, 71, 0, 70, 109, 0, 12, 122, 8, 0, 7, 0, 64, 1, 55, 0, 0, 0, 38, 0, 34, 0, 0, 3, 15, 0, 26, 3, 98, 0, 0, 0, 3, 1, 0, 71, 0, 1, 0, 1, 39, 2, 9, 19, 42, 41, 1, 0, 0, 199, 9, 5, 1, 13, 1, 1, 6, 21, 109, 99, 0, 6, 0, 0, 9, 28, 0, 1, 52, 35, 155, 0, 4, 14, 56, 0, 123, 20, 0, 108, 0, 10, 1, 4, 190, 13, 0, 101, 1, 0, 50, 0, 164, 139, 2, 25, 0, 130, 113, 0, 193, 0, 1, 0, 111, 0, 0, 0, 10, 79, 108, 0, 4, 8, 0, 5, 3, 45, 0, 0, 15, 2, 61, 225, 114, 4, 1, 0, 60, 27, 0, 0, 120, 17, 0, 1, 188, 103, 6, 0, 2, 31, 47, 1, 0, 16, 0, 7, 26, 20, 0, 0, 37, 6, 0, 83, 5, 0, 198, 6, 16, 0, 322, 25, 20, 9, 0, 0, 0, 0, 22, 0, 0, 4, 0, 2, 135, 0, 0, 1, 1, 1, 0, 0, 0, 49, 2, 371, 0, 4, 0, 4, 0, 0, 60, 0, 392, 0, 1, 7, 0, 7, 310, 87, 0, 31, 2, 1, 0, 86, 3, 164, 335, 2, 0, 0, 0, 1, 5, 173, 0, 0, 2, 0, 125, 259, 3, 0, 16, 0, 0, 141, 41, 1, 2, 2, 0, 13, 0, 2, 243, 313, 115, 0, 188, 208, 125, 22, 0, 1, 6, 0, 3, 698, 190, 141, 40, 32, 8, 1, 1, 114, 219, 1, 59, 70, 4, 0, 222, 3, 10, 3, 0, 6, 0, 0, 436, 3, 383, 45, 215, 0, 0, 0, 0, 25, 0, 10, 0, 172, 149, 25, 68, 222, 0, 73, 0, 8, 137, 0, 5, 1002, 3, 425, 1107, 0, 502, 282, 0, 0, 0, 6, 0, 0, 167, 0, 0, 0, 3320, 1102, 1, 9, 14, 906, 421, 0, 0, 1552, 265, 1080, 0, 0];
+pub static HASHED_ENTRIES: [u32; 6355] = [1084260645, 1970880868, 28116189, 140855110, 1059586973, 88065780, 67110311, 1970323924, 1966080470, 140018176, 1062470575, 691585380, 1051626330, 55706998, 1967686070, 703021835, 63145372, 335579227, 1048185667, 82461061, 11600269, 1978041070, 3192277540, 17236299, 3193359010, 719768643, 196496339, 1058816952, 196594659, 94177063, 1049201557, 702251259, 1972994610, 5030350, 206669352, 140362324, 73500302, 702742534, 84100788, 7651700, 177178571, 1932902693, 1050004165, 3196521500, 1054852306, 1971126723, 54789480, 1963688524, 3195259731, 1056605352, 1060324435, 4456752, 2072003484, 2084913184, 1065206825, 1177059636, 619416488, 283559914, 3191818747, 905151949, 1067484059, 1976500762, 1978532380, 104039107, 1177583924, 1068401669, 64652707, 1965261366, 50332448, 1979629795, 1046809175, 199183068, 22332236, 2074690698, 139198769, 3191048189, 86409881, 1978467152, 1046366338, 200313851, 3190212595, 88311544, 1979302115, 1068254184, 210387076, 46661972, 3189360620, 1966031304, 138821984, 1069171228, 1047579535, 1955677046, 3191425485, 146607171, 46236975, 1964818759, 24150817, 1968701980, 1061241873, 719504140, 7962937, 3193718808, 1178075476, 17842597, 88949439, 1061618637, 26673965, 138887524, 1977155924, 36471783, 111855446, 1050118910, 1968767550, 703351860, 192791359, 905266648, 1063273564, 1068909636, 36406231, 92391149, 3196079341, 9716111, 195332398, 153518578, 114804571, 13681450, 24920537, 135759828, 215515642, 1972535636, 153141316, 1968111921, 1060965461, 1050773857, 84985537, 26460970, 1063666815, 1091782498, 195184777, 3191295141, 1963278710, 3190835594, 3196439571, 1968538038, 1069270163, 2111129492, 1062667247, 1067631783, 1972502859, 210141300, 2084634836, 1117113443, 1047693826, 73615003, 8176044, 200149994, 1048939077, 1063207992, 1160480020, 1969586738, 1057162161, 3190098248, 146112776, 3189770534, 1978138976, 1977385565, 3188180967, 3192147120, 72091171, 1052035085, 1051659100, 84836988, 210665607, 1975616336, 1047710224, 55330161, 3194899249, 1160856150, 14419919, 1153434404, 176783531, 3194063109, 196807711, 73631391, 199838621, 1160168710, 1061127172, 3193212098, 1979269331, 198543923, 1050135320, 197609658, 1963557430, 3191622112, 75155039, 1080427022, 196790687, 1978892628, 1051134508, 1084572000, 82395595, 153157701, 1177469155, 1965392460, 1965490459, 18629463, 197494948, 199068336, 1968554426, 1065140752, 135743445, 1975566684, 135397657, 1046694789, 1177714899, 86296276, 2074756243, 1044515877, 3194783778, 198298025, 1070907966, 3188213904, 1965637952, 18661923, 200182765, 1049889426, 26657564, 12797129, 1973420386, 178947891, 187450200, 94324529, 1968685591, 1057112999, 1061782524, 1968587208, 158500674, 8831715, 3192933565, 1974977277, 1968619988, 1057752121, 3194080467, 60949886, 152911890, 3191409105, 217842157, 1177223419, 1051003332, 1966407985, 207357447, 1975648849, 91914539, 1963573783, 74499511, 140444120, 197675249, 62916004, 1052018690, 1973567879, 28345570, 3188459698, 173623160, 1045383874, 1963835691, 90228494, 1048054186, 161123460, 1971601728, 3191278019, 52921685, 73729703, 1059472556, 3194014443, 1979547988, 1208880222, 1054065544, 195447193, 197347451, 1974436224, 1049349055, 2085142573, 33357830, 1063683207, 55050780, 1045285754, 126929996, 3188754633, 700170428, 1977631573, 73828413, 68781484, 201375751, 114689881, 85344900, 1045302216, 207341057, 1160807707, 1069401170, 94160676, 719782480, 74810861, 3194030108, 3190278490, 1051888598, 28656156, 175523785, 1966342427, 150946264, 17777420, 2072232998, 25805272, 1046153446, 1973502326, 137052486, 1047923025, 1093731028, 1055506972, 1061717009, 67994404, 134512814, 84920000, 11174404, 1058161783, 2073773177, 195988197, 3194489611, 197478563, 1974960856, 1064158284, 153796684, 11911342, 54117731, 905709103, 1058669749, 166035630, 1967391051, 1975386496, 20316895, 87655085, 1084490112, 1963491787, 3189000430, 29344288, 75286097, 904923196, 140214571, 1051773838, 199002780, 1058096231, 39125787, 15040903, 1177944652, 710411727, 187990249, 1045923912, 1977287040, 83689765, 1978270542, 1068827700, 1976746749, 1067828140, 2085011493, 138691032, 3193063438, 1973125725, 905397742, 1962950949, 1966752135, 1058948285, 1091126113, 1054328011, 1048021183, 206997130, 3193130177, 1167101042, 3193604795, 130007645, 206456278, 1051970717, 121881116, 13746999, 196529119, 1056769204, 172671577, 1052803519, 1054868690, 204867545, 14500630, 3189164272, 3191163312, 1974846205, 1048464050, 1954924493, 3195128642, 73861187, 27133095, 140329554, 17580265, 134316032, 1964065124, 3189737765, 1966539083, 215384274, 197298287, 20333285, 37979373, 1966948824, 3193621706, 1539670343, 1977172811, 189546844, 137038914, 204701880, 49939763, 710526429, 177162138, 137068912, 1977860948, 1062011883, 1048119887, 3188526222, 88654524, 1971307060, 504613506, 1969209703, 1044859357, 1048004749, 49496865, 1972814266, 1966047691, 1051036138, 140099962, 1062077436, 1178370342, 1080443405, 139496503, 1955234119, 1968275796, 1969144160, 593595253, 1051167285, 1063863238, 1060258902, 210321537, 1057407955, 197181624, 1969406394, 153927765, 3194473240, 26919085, 86590274, 3191474644, 3190589813, 1056261198, 1061143501, 61310341, 3194637084, 2109768518, 1060701101, 200412174, 2074903612, 85262991, 11714566, 153092161, 719586041, 73074314, 14634046, 1067500051, 3194915632, 3188082776, 702939444, 152895551, 85935826, 36586463, 86278806, 176358148, 63816495, 94275374, 1058882495, 19137347, 94111517, 2074739740, 1067516435, 1084834100, 71747054, 54691125, 193939412, 1971995080, 75368026, 1056654505, 3189508368, 1053541255, 18268618, 2050180986, 72304221, 52445984, 196135755, 3190344031, 88606463, 3188852945, 1057096613, 1044728318, 196512733, 1975550490, 1068434437, 19891059, 1974337888, 1055081440, 90031979, 207701596, 73696932, 86196544, 217612505, 1060996130, 38012142, 1051609941, 218054870, 1067975616, 82805084, 1044531450, 1059603374, 1955430661, 2085060648, 1047120454, 1976041777, 1057588253, 187220526, 1050708287, 174851999, 6586817, 1968603595, 1058489510, 3188476083, 1070481797, 1045203504, 1056851125, 104023780, 1963393445, 1055048677, 139133221, 1067451289, 1058524239, 209029197, 198838895, 1093600116, 56673534, 1053508499, 22971130, 1062552506, 1158319209, 1966686582, 1061667796, 1539441014, 1084932436, 1063797686, 1971962298, 1048643851, 199723902, 1049659815, 1061585924, 1053098877, 692011265, 180273338, 71665091, 720077583, 3523161, 3196340274, 1979531515, 1979367675, 215630318, 1093928773, 1186696313, 1973076561, 1979039995, 1048988308, 3191245247, 1069368405, 1051363963, 1062568891, 6439216, 1964179845, 1973305664, 1971110330, 1064092802, 190267776, 1932886299, 1178157447, 72189486, 1967964753, 145050713, 1933328856, 3187869668, 3193834197, 207160937, 99485068, 195529190, 199756681, 200379403, 1975812950, 22446086, 199199453, 1054098634, 1068336120, 1046481090, 84199095, 1070989913, 905283034, 19087780, 1060553883, 15991509, 1045809859, 201770952, 1160135941, 86475419, 89802521, 135643145, 52479230, 176931417, 1057735735, 1975747100, 157812607, 73746399, 1978597916, 142656222, 1975502699, 198986395, 904693388, 86722267, 1976058247, 905446910, 122094156, 3190737286, 2074002478, 1160791294, 1063567911, 699156431, 143917326, 93029092, 20120434, 1973240116, 1060422784, 905004713, 16580956, 1972765093, 1066139905, 134168582, 13222704, 1976271586, 204654674, 3193244867, 49481033, 1970667825, 84214408, 3192261157, 1051495131, 3188148359, 59982628, 1065124369, 1977467228, 28443792, 62047637, 199412477, 186712898, 73270896, 3195718569, 54543136, 95437272, 2084896799, 19267800, 138592571, 704021230, 905381367, 1051085331, 139215156, 1060487717, 1070842396, 2073478190, 198642236, 3187673161, 904841181, 11501574, 1062913026, 45745400, 19431741, 1054278862, 3190983840, 1060242506, 1978696536, 46186741, 1972732303, 1054983129, 1975403341, 11567365, 146522752, 1973879358, 1059570597, 1977680460, 690916401, 1044384613, 207455769, 147408795, 74843632, 208947254, 1933263232, 1976795992, 1540309742, 75497841, 395086129, 89047746, 1068713006, 8847571, 3189655840, 32506196, 203850532, 3191080956, 1976730188, 1975304540, 1049135962, 1091617551, 1057932361, 1048381897, 1045940494, 41517396, 1070301518, 195299605, 10273305, 195397974, 1977713494, 71894548, 2074428332, 198117717, 176180313, 55100734, 142705478, 1954876506, 1979236824, 1051642710, 1047301061, 58361207, 1054459032, 3194292995, 1979203835, 1056408722, 196922406, 1061831680, 1967915582, 3192702986, 1050445937, 38061288, 87574250, 1054557400, 1967374663, 200068053, 23724932, 3192162842, 134365184, 1967817270, 90490658, 187646828, 153813069, 1969340828, 3194670300, 84313788, 2074657749, 83329351, 1955447059, 1970291144, 29311898, 786772, 1053361038, 153502191, 1047399359, 1967456610, 73647777, 1977811672, 37389089, 104384279, 1069762522, 1045465325, 217760221, 1971847557, 3188508851, 199527245, 121013032, 1974223171, 1969881560, 3190540656, 1049004693, 148883259, 74860022, 54559260, 710870632, 6783514, 56116587, 73582231, 2072855468, 193758102, 2093254585, 28967712, 429541804, 1055179996, 1977647644, 66422954, 1973354827, 1974764078, 1186565238, 20267411, 3195555044, 1060275290, 74188247, 28165219, 1963016500, 197199947, 2074231955, 1059914772, 45711900, 1046203220, 1963639348, 102057802, 27919576, 1059668909, 1965998522, 1048234149, 1969996128, 1045596396, 165445648, 1058374811, 6684996, 1061012525, 1065288674, 73139864, 1044941339, 3189443731, 1054409933, 1158368362, 1061045299, 1054950612, 14647556, 1091193923, 1060949075, 59556645, 153436643, 144737995, 138871140, 1971732836, 1055015896, 3188541987, 1049561373, 1968013917, 212893914, 202262695, 1068483603, 135200770, 1067926464, 1160021249, 1086603595, 84951319, 206833262, 1966064084, 1972912600, 122159697, 1954579317, 1978615653, 1056523468, 3193933010, 134824118, 3768723, 1062700013, 1067713436, 1061405707, 3194506000, 1054246094, 1044630227, 21168439, 199871404, 17564047, 72811973, 1975698260, 3189311745, 1977958791, 1048791251, 153223643, 1045367446, 122979024, 153714750, 187859195, 59344263, 1963082048, 720144227, 199428903, 210714750, 1059505321, 68157980, 73680547, 3188115584, 187793592, 10519105, 1979089084, 148783962, 3190688926, 1058849726, 700187420, 16187700, 905184721, 1046563466, 1050429568, 1972011467, 74712543, 1068041160, 3188246674, 1060029482, 1050593493, 1054311627, 19645021, 1068860476, 1090536160, 166855052, 139067783, 197314675, 159992930, 1050790247, 6914404, 1054196919, 1971683668, 153403868, 2085240883, 75236962, 121045810, 196840481, 16023941, 1967718851, 1060520490, 3190361243, 90261269, 1045564268, 149588022, 2084864029, 1048266944, 541706685, 178077962, 198216094, 20153096, 1057604638, 52757842, 69733441, 1045236441, 1058194337, 904677000, 1165413487, 88033011, 6603064, 1077953140, 1063060513, 1967981141, 1969832244, 177898088, 1971913125, 92325587, 1071056814, 3188000880, 1972093463, 3195669412, 710477271, 152586174, 1177256241, 1160381712, 1055409304, 1062749172, 1073432501, 86770333, 217661676, 199265006, 1068090320, 140559069, 1967080003, 187367808, 7569816, 3196406797, 1062093823, 204081452, 3193392326, 701678115, 3196357872, 24969223, 3187935342, 1054655696, 703447896, 3192392242, 1050200925, 692027567, 1067844524, 1068286952, 242010091, 2072036309, 1971863943, 1050266509, 145834612, 72140326, 1976533451, 100025750, 210452625, 158861209, 1060668317, 192660289, 29197511, 3191327179, 20070716, 1049791057, 1070104869, 210108528, 88573694, 213025282, 1177783363, 11583763, 2703750, 1975927549, 188629387, 1979384148, 3188558008, 1178124854, 27100305, 3196488728, 3194522385, 1061438498, 1973764568, 54134116, 2073805862, 1932968244, 1965195723, 74778091, 1053311878, 1058557010, 1971765607, 1969766693, 2072200313, 49906204, 905299424, 1062044656, 206652959, 23839524, 1060062256, 1060932689, 702185683, 31065212, 1051511562, 1059275912, 88639232, 1977794908, 197953845, 1964032352, 1972863435, 3195981035, 1971700064, 2073936848, 143000174, 73959085, 63423902, 701333792, 6930620, 3190310902, 74204641, 3192621650, 24183585, 1048742733, 121062189, 1967948364, 1975665491, 1068778548, 1972683136, 147490748, 1976402319, 1085047173, 29409537, 77923196, 1067811756, 189923968, 152684484, 136855850, 3192785510, 133169767, 1063552105, 1963999566, 1975534415, 703054412, 10060233, 1170211382, 3191523802, 3194833707, 66291103, 200526875, 1972257369, 28214419, 1968751156, 92833544, 85852820, 196365228, 1046497533, 87687854, 1965294108, 1046580145, 12288007, 207685203, 62948770, 1048333245, 2073363448, 1052770747, 197445789, 1062601669, 54986040, 72844750, 1966637414, 3187771488, 157419391, 207373827, 1968914725, 2093352890, 1975764837, 1049037772, 1054999512, 210698393, 148439665, 3190327285, 1973862964, 176734374, 1066289065, 72943219, 1973928529, 206423498, 85493453, 3194211072, 1963868468, 123683377, 691437862, 1058260105, 103925473, 1967833623, 1095188854, 197986616, 138560112, 1070858798, 84886255, 1972929078, 1070252355, 187072846, 206800470, 1955481486, 1057850435, 1160529175, 3408176, 85820026, 3195636631, 670091278, 2089861259, 690424874, 1058735013, 1063928841, 72468028, 1974879063, 1056425106, 1053344652, 1975255380, 1976304005, 1048431270, 200002510, 2074149404, 198560310, 190202633, 1968849497, 3187820645, 1975288160, 83821322, 1967636892, 3193572024, 710657529, 199314163, 153829454, 196611049, 19235680, 153469417, 7274723, 73418374, 1973289432, 1972847048, 718308071, 1968882272, 905807460, 1063896006, 1091093213, 541084091, 1160709401, 1068205020, 24904190, 1059243141, 1062847485, 144064780, 1062519731, 165462178, 1969815857, 186941912, 204015702, 1157942374, 1978302863, 2072921107, 1059849203, 904890426, 1049741847, 1047284640, 1970815310, 1963426230, 1954791818, 49251633, 111150932, 92127525, 1786422, 3193178760, 1084604775, 209110971, 3188410540, 1063044128, 1056736434, 210518163, 1051560775, 1060340839, 429443087, 200264696, 1055065056, 1045252898, 56656412, 50136385, 145884996, 73844800, 197658845, 2084569431, 1059931157, 1057194937, 1177600459, 1966899659, 37749563, 71648705, 1054229687, 1046071348, 1068139477, 19317465, 82592578, 1979318505, 19039075, 1046022793, 1186630773, 2073986084, 3191097339, 4833738, 44320041, 198478320, 1965212116, 1068549148, 3196472342, 209797218, 1054753999, 1976813433, 1161053469, 197134298, 198314419, 153420256, 49464650, 73762784, 1046546972, 598133628, 704054001, 1069662214, 85328243, 218005703, 82379093, 143262289, 157614867, 74057139, 1058358425, 718586710, 120996660, 2111242815, 27903028, 1051806626, 1964474930, 1057440750, 152569789, 74532287, 36553919, 195119190, 176750759, 143294833, 3189983548, 16859494, 1048611200, 3539293, 1051200069, 3191441875, 85574002, 1070566477, 1954759030, 84083333, 2074788943, 199691126, 3195653025, 1095336263, 1933410537, 74089913, 3194358533, 1972027860, 3190017267, 200297466, 1068663846, 1968226631, 1972290144, 3191310337, 666143762, 2085208113, 1051118110, 1972159028, 1053705101, 196692991, 17531382, 1049905811, 3193227922, 3192818282, 1069483088, 1047268063, 20250909, 691634550, 193692568, 1062486962, 719995473, 1052003494, 75433403, 3193818319, 210469025, 1965719892, 1177682460, 114591575, 217972933, 135168275, 21430654, 20037948, 87589547, 1160365327, 197265493, 209193035, 139412061, 43679989, 89014977, 135774208, 29033249, 139378997, 1968718382, 176799916, 1977041244, 1057834051, 1057391570, 1063027743, 1046186775, 1972077110, 87212710, 74302968, 3189328126, 29393076, 1970651435, 198675016, 700203806, 2074297465, 1933443317, 1186581623, 17285963, 1048693636, 212877521, 10142219, 3191393447, 155240366, 122225377, 1968144695, 74663387, 140149121, 1069563905, 197789969, 158515822, 1933394147, 196905038, 1964720436, 146145543, 104055492, 1060619439, 540281273, 1048594745, 71468584, 7635489, 1933050179, 142901422, 1061356545, 3194718240, 1177649391, 1977876763, 1086096389, 2072380378, 700104984, 1976926491, 22806984, 140050806, 1964917092, 82755899, 2074281060, 1060471930, 16532014, 3194603551, 1061798911, 1061159885, 85870289, 1047628726, 23216385, 2072462483, 1093894513, 1061225428, 28509331, 2074493971, 3190802831, 210272377, 1966817706, 189808989, 123650515, 148408189, 1933312340, 199658354, 1978647293, 21234400, 3189639453, 153845840, 153239742, 67371564, 1047972425, 6406439, 1933459707, 74155466, 1059193957, 1047169294, 697582357, 1973944917, 1978221388, 7291454, 1051445952, 200559646, 3188295832, 1977106763, 19693955, 1091043686, 1974453069, 68765103, 1058686133, 1974632907, 1068991567, 3191900674, 143328002, 145064284, 1044892452, 43173108, 209993823, 1977582108, 61653798, 58901887, 75269699, 1977403243, 1971438176, 10748514, 139100483, 3192883211, 1976959810, 17187682, 197363838, 2072806460, 50939224, 904874041, 1064174717, 1964294586, 1045039422, 1976697372, 905791075, 3196128232, 13108022, 71812618, 154224495, 11928338, 607619987, 3193293470, 3194948402, 153878610, 2109803610, 1968833109, 3193554966, 1964409398, 1967882802, 1063224384, 1070760404, 1971143112, 200035280, 197921069, 1095106883, 199560017, 1968963889, 710673916, 718225948, 1968488860, 2109866365, 1051462341, 3195587476, 1060717756, 89376524, 178964274, 27116686, 1047660999, 9453848, 1060783105, 72730195, 198953601, 1954840815, 2074051799, 3190704516, 148767186, 135315630, 123552330, 2086356963, 41582837, 1060373617, 1068745774, 58377592, 83804921, 71435791, 1978827060, 153059401, 3196062682, 10813720, 1051265643, 1972420923, 85770657, 1055392920, 7389185, 206947966, 89359049, 1963311488, 1067795372, 207390217, 3188197519, 8814955, 2072249396, 431851514, 1976763222, 3190066330, 207832744, 16845890, 1973813788, 5865838, 198789729, 217923775, 1048840421, 1977729879, 86853342, 3192949948, 68928939, 1057424363, 1971339843, 1065255950, 1094599511, 2084602330, 691700094, 31048933, 26738868, 1045498543, 215417058, 215532035, 1975321314, 209928309, 1965785446, 49415488, 207783587, 195430779, 142983332, 197970231, 197527726, 121078574, 9863913, 1051937782, 36242649, 137019829, 1048955507, 1046121149, 1978090314, 74876409, 207112154, 1969750299, 94371840, 1976353613, 85475985, 186780738, 140001790, 2093369273, 2075051054, 1049316355, 1045547655, 1966277205, 3190933497, 1968357735, 1976664622, 72713809, 45024045, 704497626, 1069335699, 1093697876, 3189967163, 5505371, 1068123093, 1084342580, 1954906468, 3188017272, 3193194514, 3193440276, 86000034, 905119174, 372951261, 1970520657, 2073625116, 196758543, 51905871, 3195947052, 8945909, 153763914, 1048103420, 157763507, 3189229820, 3195800498, 1061340159, 1066647950, 698893153, 29131972, 140181831, 1974552427, 1061733403, 3190409574, 1968734770, 1095074132, 3193523400, 1177092485, 3188344991, 1068893244, 1056916627, 190367559, 136970597, 2073183379, 1970716987, 1069007951, 206145641, 1044580601, 207308278, 1056556236, 17892093, 1053967252, 72418874, 3194981173, 21136106, 40829741, 3192752739, 199641961, 1976140526, 1056392334, 18694737, 96141569, 1978123083, 54855018, 123454008, 3190770061, 1067680216, 193562315, 1067762603, 56082987, 1967292727, 12665630, 701317524, 1965539627, 206538225, 199232228, 1170457173, 1057768507, 6717964, 1969504728, 3194850092, 72336990, 1054131404, 15533931, 17482142, 1090863398, 1068450835, 3188869330, 1186679928, 73467534, 29328486, 1969176932, 1045645833, 217727480, 701301022, 86114717, 3191376336, 1972338982, 52512076, 56264050, 1058507854, 148390250, 1969111374, 16646871, 88098549, 88556217, 215466228, 210534544, 26018808, 691274021, 1963180384, 1970258362, 39093046, 904660613, 1054541016, 73222198, 75204156, 1051593550, 3191786668, 1061946369, 1064043682, 1063748514, 71517768, 11125320, 2130542825, 1068172250, 1046236041, 22266214, 1048218562, 1964491316, 691732869, 3187853414, 175997115, 1057129383, 3189803307, 3194407693, 3188918486, 3934074, 1058047071, 23184251, 59262333, 1055671521, 1966113334, 1975337319, 153026586, 74515898, 3196390642, 21414450, 3192425018, 15057211, 2084667620, 199019171, 123781571, 3190442342, 1059636148, 48268068, 1045219957, 197724407, 1063994465, 1048660479, 175196062, 3195783209, 1968095531, 3195308902, 1970766147, 1056965802, 1047202140, 39404791, 1963901243, 218022088, 1066582803, 1966211646, 199707517, 215646705, 207717991, 140198182, 140575475, 1070547106, 33456526, 3189541138, 1058767783, 122176085, 74909183, 74008186, 1973223729, 1967800792, 27854040, 210616475, 3191671271, 143933712, 1972978222, 149456950, 18156611, 1964671270, 1050053328, 702792308, 3190458730, 5817059, 199674739, 198806119, 1933082955, 1977188704, 1063076904, 1970356696, 1128205412, 3187705930, 3188721862, 135102471, 9748899, 1047464207, 174475220, 148685259, 12009479, 691750712, 1049283558, 2074706918, 1063699591, 203885656, 3190901141, 1964785984, 1045629401, 690457640, 3193473708, 195611170, 73795639, 1061651399, 197413000, 1970749760, 18678134, 17613364, 1046875009, 73549460, 3192457790, 1052051470, 22218416, 41436416, 83624661, 3192359469, 74548673, 199822236, 1060291677, 186876219, 3189885236, 108283731, 1045727385, 1970405916, 1050839400, 718406196, 1966440759, 200117216, 3189016799, 139330443, 1177026934, 83083676, 73254508, 56001904, 1046055251, 72779190, 153960535, 96125556, 1044875825, 1062191266, 1069450321, 1054803153, 1070219736, 3196422195, 187236646, 1540636678, 89130687, 702693633, 7029321, 1047857251, 139166134, 3188492467, 217989318, 152977431, 1971568955, 1048086994, 153665595, 139428674, 1963229542, 73991794, 1050168142, 199379706, 176161545, 74335761, 1055278303, 89589526, 1091028942, 1061323772, 99943817, 691372343, 1069106297, 195414004, 204458228, 84625080, 1540358427, 3187755103, 1049856655, 210042981, 143394692, 3194325766, 702955962, 1161135391, 1056834742, 66406571, 3195439992, 143213054, 1045826325, 1966981655, 1048349694, 11436039, 1084391896, 1051347580, 1052118045, 1046416123, 1056457952, 3192081582, 73189338, 1974747988, 1069073508, 122291028, 1068794932, 82577359, 1046530468, 74368552, 1068696614, 1048398344, 71321012, 20022123, 199478075, 153698365, 1047447655, 83657161, 210485385, 1970995589, 87312062, 1045989990, 68945321, 1976779607, 1177076039, 209846378, 2074985424, 153321713, 1972208204, 1973436772, 72009240, 1046842053, 199494461, 148753476, 87622316, 1055212764, 1967423828, 124700471, 3194555606, 83345702, 1051790234, 1054901461, 3192654421, 76071674, 1975796477, 2110393433, 1050511511, 702414853, 153862225, 1966719360, 1972371761, 1061192647, 1060635817, 2085044263, 86704796, 1064289226, 1976715109, 19186237, 61998482, 3193637569, 75351641, 200018895, 52003356, 1067697052, 1976566608, 134987776, 67782061, 3188328606, 3195603862, 176637764, 1045776515, 104104647, 1974584143, 705430774, 2089828473, 199576419, 153453030, 73320054, 1059734473, 1963442618, 1974682450, 198068553, 719226718, 2111588243, 3195849665, 135692545, 1971831168, 1973895747, 1061307386, 1057309633, 2074461136, 153206803, 59852170, 2073281574, 196447157, 3192851642, 3193096208, 1049626993, 197626042, 72107556, 27935812, 90128985, 2130477244, 196168527, 142608333, 85983870, 3188361376, 94209833, 1160742170, 1965965738, 1975025947, 153124931, 47841531, 1068762158, 72795588, 1084326193, 217809383, 83394912, 104400664, 1050757460, 29229806, 190136674, 1933279621, 1969488342, 3193309203, 1979007215, 1964310979, 3195817191, 1967751627, 3195489421, 1086472664, 75384423, 37765920, 3188623549, 905332198, 3189573963, 1977057752, 1055163612, 75302483, 1070203195, 187596974, 1978353515, 1050315739, 217628896, 197708020, 148718424, 1966293593, 24658460, 1968439685, 3187969160, 209961051, 1061520435, 3191721130, 1054115020, 1973469543, 1063502963, 28230808, 1061372928, 90425119, 1975845720, 3192769720, 88131318, 103908002, 210813086, 207652411, 92866315, 904972353, 1963786523, 1058997307, 1057211327, 1177567806, 1057948746, 1974518485, 54019430, 103973540, 905692718, 195840719, 134283264, 1974010315, 6668812, 26969235, 1964589657, 1048561806, 90947996, 2085175343, 1051282028, 200133602, 215548383, 73123478, 26853750, 200248311, 3187804260, 1067057546, 1049544987, 1964687659, 39863072, 1970078065, 1979105491, 198707802, 27050514, 187351805, 192578340, 50152779, 75105826, 691962038, 73008765, 2072396819, 1051068949, 143180248, 2072953902, 74614219, 1048169280, 1085817813, 133152774, 2084814852, 94060928, 52462108, 3014829, 200166379, 152993816, 3188689091, 1070530954, 1974190428, 134055603, 2073920695, 198740573, 1968177624, 14025505, 87917231, 37356736, 1967178336, 3195242536, 1970979200, 73205729, 3194193950, 72156716, 1972601190, 152635329, 1933296007, 196152138, 1969603124, 3187689543, 68027646, 3189344515, 215728322, 1047677386, 153338103, 85016840, 2110980390, 3196374257, 1967030834, 104121032, 88457910, 1070334304, 198937216, 1160414471, 1186663546, 1045007085, 37815530, 201754567, 161107075, 690981933, 1061815297, 1069024335, 12419079, 8143148, 1976599121, 1964606045, 1056785588, 210239607, 18399652, 207422994, 17875549, 1058243718, 904775328, 1975206219, 193970803, 1045711799, 1967341888, 207177152, 112002275, 1964704049, 1116635949, 1046005922, 196823522, 1057555479, 23151132, 15614411, 1045662230, 13468472, 74040962, 1177272540, 2085027878, 135282872, 153174086, 1069057124, 1048316705, 62326169, 718258933, 193628038, 1059013691, 1540260198, 29425921, 199592804, 207521312, 196479947, 1057899591, 37848294, 1068942404, 1060750323, 3195899112, 213041630, 2085158958, 6652376, 26575666, 203869271, 1973617061, 24199981, 1059554209, 1062880251, 3193752781, 199986125, 1049528552, 905643561, 193364145, 3193112593, 88327930, 2109735267, 1050986942, 1170375253, 196283275, 1970192796, 197691635, 1046432599, 124241768, 9011492, 1050462300, 19170117, 28607635, 1977205084, 20169163, 153354493, 3193965730, 149981089, 1060209736, 1045121117, 89032454, 710854235, 1050069723, 1059767249, 1979662575, 1049774644, 96207692, 90112315, 1051839427, 1063879618, 143378307, 1972699525, 1970635046, 1976189770, 1068303336, 197429396, 3193326240, 1061913596, 719078144, 1963344263, 17826350, 67618218, 1091518885, 19383002, 200215540, 1965244888, 1965867392, 93127396, 147867608, 17860453, 1968062757, 140230987, 1971011975, 43614748, 1050904974, 3188771018, 87230181, 206882420, 3190787231, 701252158, 5259594, 1976861400, 1058915515, 176046474, 74925569, 1056982187, 94077529, 123929181, 905348586, 1059865607, 62079780, 1974289226, 1057326020, 1978761944, 84935287, 1070514192, 3192408629, 1979072984, 86884871, 92849930, 1059095626, 710805065, 1966228035, 72828360, 3194096852, 7979460, 1975419726, 1060848735, 1158040679, 201459643, 74286579, 1967850012, 7602641, 19874230, 1963524566, 1970618661, 36603031, 1050478699, 153747535, 3192130735, 72451651, 114607960, 1049430171, 1966866883, 200543260, 1971782001, 1047873825, 37651261, 1050413109, 134250496, 33211166, 2072708196, 83673399, 159647668, 1044695952, 1965113770, 1069319315, 75335256, 1046956181, 1539935287, 41993470, 1954971996, 1966883272, 25756817, 59786641, 1060045871, 27657189, 29180444, 702202603, 3195620581, 1058276489, 3195341674, 215351647, 1972175422, 138707271, 157073792, 27444435, 3192670806, 1966998044, 198658624, 139281286, 1965031813, 1160823597, 123503164, 1967653285, 3194653470, 1050250123, 1977598804, 1968980276, 1964212623, 2084978723, 144343302, 122340202, 1970455092, 3189278975, 699843403, 175475803, 59065724, 3191457794, 1968816721, 3194047212, 139480118, 1971405401, 217792996, 1093403374, 1045072388, 33374214, 87179937, 72074786, 1979580604, 217825770, 83165566, 50021686, 1978811220, 1968210243, 198871665, 132072281, 3192835257, 3189475597, 134563802, 3189722263, 74597831, 27395032, 140280269, 1970028900, 1977237863, 718619478, 719127074, 197642461, 563104549, 3192227847, 445237717, 1972060632, 1050495094, 1975173443, 905594394, 1048480323, 1160512790, 72533569, 3195161413, 15221088, 1966555470, 1975107975, 158189462, 1044465734, 1964573269, 2072495319, 152700869, 1059226756, 1975976219, 29213046, 202064687, 2072527772, 136987007, 3189033184, 1976387452, 1062831120, 50005307, 1085030759, 1069417554, 1060881530, 718389812, 1056507084, 1978417500, 134873088, 1963802917, 2073707667, 2074887173, 88000242, 133120006, 1054704847, 3188934876, 1058014298, 208978104, 1062585276, 1044842673, 1177370964, 1063437424, 1540178276, 2072298460, 47808852, 88588986, 905217491, 74679772, 1975042341, 207193537, 73352827, 3193850584, 1975943726, 3192491189, 209780832, 186270021, 1966195252, 1068876860, 1972797878, 2072363984, 42926893, 3189410056, 84166326, 141543230, 72975992, 1054360779, 144081165, 1048513374, 1049610603, 558877465, 1055097924, 199297778, 166936970, 1049070295, 1055588892, 1964900706, 1977024827, 1063912433, 1972109852, 1971650891, 3193277081, 103958242, 195857102, 11452422, 135234323, 1963950403, 26951844, 1063650430, 1972617575, 200231925, 73287281, 3196274736, 198248866, 196234097, 1965146554, 1086882176, 3196242941, 26985687, 1046677988, 1044663094, 2073904044, 157550513, 1069580295, 75122211, 1067599015, 148277059, 25593026, 1047907202, 1974570051, 206751309, 121963238, 139608532, 1052101857, 1095352704, 209176652, 1051183682, 1963196770, 3191867905, 1046644884, 41420032, 1058620590, 1964261802, 1060078645, 1068008392, 198281637, 6816085, 196070142, 90162957, 1974600218, 1052069056, 1051691865, 3193883856, 1066305450, 3188426925, 1177616992, 1050086129, 31130595, 29098164, 1047726672, 1057522708, 1973666234, 1973322051, 1979351285, 1160283404, 104253265, 198183319, 215581157, 22643250, 1057375185, 54100508, 1047989180, 2072413220, 197511334, 58443130, 1158401131, 59049340, 1972715911, 86803102, 200363016, 85901742, 201476028, 200576032, 1062732789, 1964343755, 3196325103, 1978483538, 1056703667, 1976516956, 1186794619, 121848583, 3190966684, 1050741070, 195348791, 37881071, 701497670, 2075018259, 1053377423, 11141703, 155764656, 103645901, 1964540492, 2073838597, 86901408, 16515800, 24526854, 31081595, 50857307, 3193375941, 1062536117, 1973158171, 15745582, 1057571866, 134627334, 86442650, 24936455, 1186614388, 1046333524, 1967702458, 905627175, 84641467, 217891019, 12093265, 206325171, 3194932020, 1979285724, 210288776, 149964706, 1058573396, 15516253, 1954892855, 207504912, 1047185704, 215679180, 1969865019, 71829003, 121815768, 1049103139, 209748060, 39437512, 3191982592, 1968996663, 136053456, 3195374448, 1061979129, 691863921, 1048366090, 72238641, 7340504, 210600095, 120897543, 5276175, 22185650, 1964982646, 1061684180, 696484020, 45122803, 196332469, 86835871, 3190655361, 1965932956, 1975452047, 1978237312, 1047955916, 145293671, 327157722, 3196177392, 1170309713, 1045842873, 58344825, 1050577103, 19448590, 62031252, 123602791, 1047152858, 187138388, 3195210975, 3194996771, 99141449, 1094107984, 1978843997, 30688169, 1969291653, 138527456, 160303012, 1970274755, 1963884855, 3194620699, 1964851534, 207537697, 710690305, 75220543, 136921657, 94226219, 3196013804, 153731135, 6291876, 196119358, 55559495, 73483919, 1050609893, 21447253, 1063306327, 1047349342, 15712796, 1963360655, 7176804, 1057915976, 1969193318, 28542102, 1084522836, 1068598308, 206866035, 1063371872, 1160054018, 1057621029, 1963966791, 710821455, 17301856, 328188088, 1933377756, 3193980954, 1049380968, 1955465101, 1050626283, 1048496678, 7144030, 87245479, 95617030, 26723523, 1056326796, 72402489, 3195030328, 1978106196, 1979498735, 1967866414, 27034131, 201441620, 395577343, 84853115, 1973830190, 195479974, 1964376534, 30770326, 3195571090, 25772562, 1965949349, 1963262321, 90474273, 72435259, 215400667, 1070793176, 1052869184, 1977763705, 691945645, 539593144, 1965359678, 11945213, 142934184, 140345939, 3192326698, 154832005, 86868601, 3194309380, 2073969683, 3191638021, 3190425959, 1063093291, 3194227455, 3195521923, 1062945795, 198396389, 1086669159, 3189132432, 155436975, 15827709, 51922256, 1965441625, 1969160546, 75023884, 72599141, 87524009, 175980938, 140427867, 1966735749, 51234142, 215597544, 1969455563, 2084651229, 30524187, 2130526435, 1964835147, 2110062940, 3785128, 1160086787, 1973649846, 3190491503, 1971061157, 1969930567, 1065272259, 200461331, 2085257260, 1970586208, 1044810735, 84281018, 198593080, 1976123715, 1969652300, 56296815, 710444497, 1972273757, 1053442224, 3196046296, 1963065816, 1976156491, 1058079842, 177194880, 55608693, 1966309981, 1967112785, 1062765558, 1085147091, 1979482345, 144754373, 3191802361, 1966965302, 1055474914, 56329587, 1069155449, 137315193, 1978630732, 153616440, 3188820173, 3193539252, 1061291035, 180306105, 199117517, 1064125531, 1177420145, 718094778, 1045531197, 134840503, 1932935467, 202279080, 1977451034, 703040565, 1967259953, 3189049569, 1060586656, 84968076, 1050020551, 1086750965, 82510129, 1067942848, 3191032235, 1059652537, 153567285, 36324581, 200330240, 2072790021, 1086701952, 1975731029, 1061635015, 49448264, 201623405, 1978581845, 197232722, 710641136, 74253809, 2073592768, 1178321278, 1976681301, 74450510, 2072626120, 3190393189, 718045622, 1977975099, 72271347, 197822741, 1050544336, 3190114632, 1062158857, 1080394116, 86066895, 175179736, 1047743076, 17089346, 90507043, 72025616, 73303669, 1974174011, 1064141868, 2621440, 3193505813, 2084684010, 3190999464, 212992255, 3190671749, 148570460, 72631910, 11993703, 53249373, 701743647, 1970504268, 97337528, 84592309, 1056621736, 7553315, 1968193856, 1971667278, 99926820, 1976893998, 1055556124, 87967473, 691798321, 3190638973, 7520592, 1045580742, 104417050, 1046252024, 1055638675, 87934704, 1976009538, 3188738248, 1970160007, 39945471, 1044924707, 1965851006, 50364956, 13140785, 207603295, 83034485, 1058063458, 74434125, 1976074555, 1966129687, 7618906, 146161942, 139512962, 1969668689, 14795380, 70894173, 86344344, 710788674, 3195096285, 206603784, 16089940, 134234112, 90441504, 1059128397, 71632320, 10125771, 71960090, 1057145774, 1971077546, 719372885, 1049021081, 2084716790, 153944150, 11076015, 697959177, 87197408, 88490679, 23921453, 1048414959, 73877570, 1055539740, 201654288, 1977123150, 1063764912, 215663092, 61031816, 2074641344, 215449838, 74352159, 1059357855, 134775412, 85131633, 1178141896, 14534498, 411961721, 2089812114, 197593271, 197249107, 10207829, 3192604681, 1058751398, 2072183908, 54723949, 1933017560, 1093959919, 75171381, 88015538, 1067565586, 1970340310, 1045908315, 1046170232, 23659397, 17990420, 139051748, 905545231, 206407112, 63358365, 104547183, 196381613, 198412777, 19628421, 3191556572, 1963475400, 158958190, 198445550, 55412037, 1057227711, 3192507574, 1050691847, 3188230289, 103122763, 71615935, 195693121, 41976609, 1974780757, 153108546, 1972896214, 197937456, 19595629, 701415830, 1053082496, 139182518, 1066615182, 1933361363, 1059701697, 3191654883, 701383062, 2074330150, 1048152785, 82690379, 42091814, 3192982719, 21185273, 1954644179, 148799887, 1973846578, 1064010908, 1975437180, 1057457136, 9372073, 1069384789, 51086688, 1068106704, 1970389527, 1056277582, 1061766138, 3193900241, 153288933, 3189557523, 16073469, 1046759828, 27083283, 140789565, 1084801862, 73238123, 142967178, 143311617, 1050823019, 1049938584, 710559205, 2074969271, 36537258, 212910305, 1974371042, 38028523, 1058899130, 2949126, 1061897210, 94308144, 2075034660, 210796701, 154255981, 1067861175, 1068221414, 139084543, 1056867509, 1965408849, 198511091, 209862765, 6341024, 45040881, 436193734, 1978254157, 1977369301, 36308133, 1062174736, 75466173, 1050937755, 691519819, 701448599, 1964802371, 90244879, 1064403953, 198019396, 1061094391, 1973912140, 3192031238, 3188574393, 1165397102, 2130510044, 702399044, 19284727, 1177321799, 31212758, 44057376, 7324375, 1968128308, 1186811004, 1045875071, 711231436, 3191966893, 9798067, 12452097, 1539425090, 44089601, 3190130674, 1977516880, 1051855820, 1964769752, 90212116, 1056818358, 1974157703, 135381274, 1977781315, 1062110238, 27755538, 1972437464, 73909930, 217645286, 52528461, 3189426440, 134496430, 1966358821, 148259656, 3195079485, 1056883893, 1972355371, 1060308063, 135413763, 209731674, 1975779916, 3193588406, 85836335, 1973731796, 138953065, 3190164683, 145753986, 209977437, 2085077033, 580864850, 1968341350, 4063780, 1965703502, 905840198, 139149606, 1160267019, 195659845, 690687017, 1968898331, 15254242, 206505445, 1972584804, 1068729390, 199625576, 3189213430, 22938135, 1051216469, 1978941651, 1067893943, 1047251670, 1063961622, 1933099342, 2089844859, 1977893157, 104088262, 1978450648, 1047497121, 1059996709, 1974075675, 209944665, 1064240216, 1977614894, 3195063100, 198855280, 206784080, 1060439171, 7537176, 1979252924, 103596748, 1977073987, 1060799481, 2085109803, 210747525, 1046727020, 1061274651, 11010611, 17121585, 719062879, 53232988, 3192113689, 17679094, 28279324, 122652493, 1063470186, 1070383462, 1058589782, 1977926470, 158205878, 3193801426, 84263561, 3189197045, 904824796, 1967243563, 9503093, 1054885074, 719291117, 207554082, 187203936, 16763965, 1044826191, 112050993, 691815134, 3191343560, 200477716, 1059112013, 1964048738, 205211834, 1084588390, 3193031290, 3189065950, 1974731292, 67798446, 1067959232, 16908608, 1966801317, 200395789, 1974649560, 1974666064, 1161036116, 3196161006, 1979465955, 149506102, 1148372208, 2072544192, 1057047454, 16662822, 2072446099, 1971454235, 1933426927, 1056720051, 1051577164, 1069089892, 30983246, 710510044, 196266886, 207668803, 3195456375, 87491240, 85443216, 123666899, 1966653799, 1060406177, 904709773, 11698182, 1932984631, 1971208664, 165479013, 42206497, 1086849496, 199740295, 18301800, 2074083800, 85967263, 3194456851, 197576885, 3191491030, 198101328, 1059210357, 85672288, 1979138275, 1969717853, 1165364333, 1057719350, 1060684729, 1965588791, 195807930, 1063814070, 45303029, 104006309, 1977500376, 100042136, 158566301, 691047472, 139264540, 1966588256, 1978335837, 198691402, 145819458, 38077673, 1068073936, 193609891, 1954726753, 720093605, 1051232860, 1068614692, 2130493651, 134660102, 1969029592, 187171659, 212959475, 87982769, 193725038, 1049676207, 195775116, 16548261, 3193916128, 176849075, 140378576, 3188951255, 75073048, 1143915772, 1066550035, 1977434959, 1044711896, 3188279447, 1063109675, 158091179, 1963163988, 1060144189, 56312059, 3190868369, 697418187, 160991042, 199215838, 3196192815, 710624751, 19841480, 87508712, 72173101, 2074870836, 1971945910, 54248805, 1964163456, 2109900728, 88508156, 17269076, 56197660, 87015612, 165495398, 1066256295, 1970913639, 14009146, 3192588297, 691552583, 45106418, 200494103, 186974531, 1963934016, 1977483723, 3189950778, 20464363, 1090584916, 1049185165, 215368214, 1094550092, 6570294, 3195685797, 1044777750, 691306922, 1970045286, 1091699262, 140116476, 691339623, 91047792, 1063584296, 3188656325, 20054797, 698319634, 196561890, 167397308, 1085064012, 74270194, 197855517, 1165429872, 215711936, 1093878623, 1974108994, 72205871, 3190720898, 1063240769, 28820260, 3189606382, 1978073422, 1971978691, 199051945, 206734922, 44303662, 698074990, 85951101, 3195226151, 1055458530, 2084880414, 690293806, 1965048199, 187302277, 1972748700, 50644307, 370854119, 1060537497, 1967407438, 701268252, 89145945, 1177125169, 71287324, 90457884, 3195472763, 190317382, 157665202, 75253307, 1968783939, 200625191, 140132736, 2073756772, 1962983723, 5538136, 1933230454, 1965130166, 702038615, 1968243019, 1964278198, 1060095030, 15139147, 1572870, 1969013051, 1068565532, 39797016, 186729012, 1050560715, 25378989, 3189819375, 148505193, 1064223819, 72058401, 1963131211, 1084557122, 1057653802, 134889472, 1971798390, 1933115732, 9847525, 1976942885, 2072101832, 9404783, 1976844636, 1044597106, 1967735240, 30425889, 198461935, 1972879828, 1066795182, 1978713977, 1061553143, 1054770385, 1963770464, 141462662, 212943085, 217743873, 158353301, 720208600, 15796069, 25739795, 1969258878, 3192687193, 42108199, 206898808, 142885602, 3193162373, 176261008, 14958875, 1967931971, 2073216079, 84296330, 1048070594, 196102952, 159531785, 1971241495, 1978371139, 3194686239, 1974403461, 1177911547, 85787283, 691568998, 1058391197, 31228878, 1978745180, 198084940, 1971323454, 2072576540, 3196110894, 146784561, 104597301, 138723800, 3190622588, 1053410194, 1059750859, 3191606441, 199920574, 3190082292, 1048774762, 1050299350, 1063257179, 2084847644, 18219476, 86737538, 905725489, 3191015861, 85312123, 1969734240, 2073330748, 1971814782, 195545581, 140247499, 1979056468, 1969799467, 1058227331, 36619479, 691470727, 217907405, 83936070, 82870728, 719325136, 1058604205, 11323216, 1975829335, 199510857, 1045416870, 83247478, 1977828093, 175964419, 197150774, 56542574, 1069302931, 1045481797, 703365440, 905496075, 3190917522, 1089784925, 1065108542, 1046349938, 1067581970, 149407798, 198724187, 187891923, 8962293, 1057670191, 11846522, 1975880771, 54838633, 15680081, 905250261, 1974420300, 196086526, 198609465, 690752562, 207128545, 1975190254, 3191195675, 90146578, 206439884, 1968030304, 207439379, 1976090972, 1049577823, 201670818, 71763455, 196873252, 2074346548, 1975632722, 207095454, 196053758, 904726160, 691783629, 206931581, 2074313704, 1050872184, 1068024776, 1967669674, 32670168, 144230282, 3193457863, 19562792, 3190884755, 905168336, 152207755, 1063126060, 1058800567, 207062680, 198429164, 1977254277, 1070727624, 3191261637, 85410447, 3195767728, 1967784406, 212975865, 1062683628, 1975863161, 94193448, 1963377052, 1932919078, 3196538100, 1968472463, 1046137014, 691290464, 719438599, 690998316, 1070088475, 14385951, 28132436, 1054213303, 3193703114, 199855007, 3190262104, 1065190369, 204130452, 1964753211, 1046825629, 1970012514, 1056294029, 1966178866, 206357945, 1062929411, 1061995550, 3188443312, 1057260732, 207734414, 1046301065, 1049332627, 1975714350, 153256149, 192774974, 1977942321, 1978385231, 111822677, 138608955, 1047431249, 134725639, 145769041, 217678066, 184239987, 19923416, 3188885716, 2072265733, 1977008519, 20447443, 207324671, 1061471277, 207242713, 1970209189, 187269479, 49022257, 3187951733, 1970307531, 187154972, 153894995, 85624524, 1055196380, 1964638491, 73598618, 15483279, 1064027290, 3189377005, 1967587717, 1967145561, 1972519246, 1047480600, 195086412, 28427405, 3194800937, 1067910327, 1194101889, 16007516, 104498986, 1962934555, 1979433171, 1051757443, 1086144903, 84525684, 207046294, 1974305108, 1068499987, 26444571, 1970422318, 2654633, 3194882862, 904758941, 1059685564, 1969979732, 1085932572, 3194342613, 1050217326, 36422662, 1967899188, 145113865, 176947804, 17498524, 1967505767, 3195292513, 144032015, 197544111, 1049053875, 85608134, 1057178548, 45778170, 145785718, 152961046, 1970094454, 2072724601, 37618490, 1062126629, 198969995, 1966162478, 206685748, 1973109337, 86918879, 3194752218, 1069040740, 1050675458, 1049462992, 719553059, 1061110788, 1080559570, 1974092069, 1979564504, 1064420479, 154585005, 1059898383, 1969127764, 1974534749, 2072167503, 2072282172, 1971503403, 1059161186, 197331063, 691765531, 1045023545, 1539736390, 148424599, 91293645, 3188983947, 7192787, 3193785550, 55575879, 7012365, 1045400335, 1974698577, 42190117, 26379411, 3194817322, 699613968, 1966473688, 2072085478, 1044957984, 74139080, 1091551996, 3189082339, 1053328267, 13124400, 1094058758, 85526222, 74483286, 74827252, 1966702974, 1063421038, 1093714697, 1045055932, 196742156, 710460883, 134529198, 140493366, 1966522695, 121766646, 3190753672, 58967424, 1091207947, 2072216552, 140460453, 1977140042, 1966670193, 1059374240, 150931372, 718832372, 3193998057, 200199155, 83460419, 3194161909, 1955579708, 82953070, 1059718084, 1933214065, 1974814565, 1061700625, 143344387, 697385697, 74761704, 1050888587, 199936960, 1047563037, 202162360, 210092142, 88934147, 9961925, 145312820, 1974912889, 1969471956, 3189295360, 38732589, 2072019904, 710608364, 1975960404, 1047366298, 88621755, 1051249250, 139346379, 135561937, 49923385, 1177895145, 17351045, 210026595, 1973535104, 74728930, 200100830, 1069187612, 419579896, 83067100, 710739486, 148734363, 1974354268, 2085126188, 3194703065, 3194259606, 3191113849, 206980736, 88916670, 703414997, 3191359950, 210305148, 701923516, 1978057035, 1962967334, 1970438706, 136905323, 714394698, 55477565, 1976238432, 1186827389, 124290921, 1057866821, 1056375952, 1091748595, 193593361, 1057801277, 1066239910, 3188639934, 2073068480, 1068844092, 1064305771, 1965818225, 138641864, 3189836079, 3194145524, 1976976198, 153649210, 82821859, 56558962, 1148652614, 1972224593, 22364500, 217694456, 1055360147, 141379446, 1148684168, 29082589, 1047022420, 1186860159, 1971421789, 1933197671, 1059390627, 134643885, 1053426581, 209895537, 1060914324, 26166421, 84787831, 1061209044, 1973518718, 16171861, 720290339, 71976476, 3196029912, 1048447658, 190038488, 1177551441, 184256372, 84689646, 73401988, 1059832816, 74384948, 94242604, 46154528, 3188312219, 1968800332, 84148870, 1978287996, 1051904984, 595495801, 1970897254, 187384662, 1968374129, 210436244, 1046924199, 73942702, 1965376067, 905414138, 17252667, 200444946, 3195144229, 15319424, 3191998989, 1165446257, 74958343, 3189180658, 94095121, 123994866, 1056441568, 2071986648, 138461807, 95764803, 698286879, 697516888, 135086086, 1047415138, 19661190, 3191540904, 1964229020, 26821148, 1954989937, 11092400, 12960962, 199789457, 1933181286, 1969062211, 1965343284, 11059628, 704037712, 7930170, 1064076354, 138543974, 710706711, 2074837992, 50660690, 1979334895, 1054573786, 1969553948, 1046891290, 1070711235, 158122822, 3193261252, 701121094, 3195964370, 905528846, 904955967, 1972142642, 134676487, 166903992, 1054491800, 1057686576, 29114840, 1972666750, 50890070, 1045793439, 1067877559, 1059062853, 3190245719, 1539771449, 3195505535, 209094736, 1065092109, 5341947, 149358646, 1967210789, 74024577, 1051872209, 1976910676, 1058178167, 1540047584, 1058456539, 207799972, 3188836559, 3190524270, 1051298419, 198494706, 197396615, 1070465408, 1970848096, 6881292, 285739008, 1045514979, 1055261919, 1968046363, 166052566, 1972945431, 1063388256, 2089926787, 1056998575, 3187738714, 3195915498, 27345871, 905233876, 10240567, 28639406, 74794473, 198166906, 691650875, 691831256, 71927318, 1060013095, 710755883, 1979171055, 17072421, 1046613001, 1973715403, 1967276340, 1967161949, 198625851, 128352665, 1054819537, 1069433937, 1061487661, 43205877, 2073150408, 62128668, 718488321, 3194374919, 1054606554, 148701742, 3188377762, 1063453800, 1045678782, 139592139, 1974486908, 1054377165, 1971470629, 121700773, 2130461144, 3192998521, 9585109, 1045153946, 1973387604, 1969094987, 1045973577, 1070957137, 22561365, 1059816430, 37782305, 17597194, 198134132, 702991456, 1048922245, 3187984357, 1964622432, 20595235, 198822504, 1969701465, 1050642680, 74696158, 904988740, 207636066, 904791717, 26903249, 1170440789, 56345966, 176228236, 93045582, 1160840469, 1062618053, 207226313, 1969963342, 2072773684, 152668099, 7487997, 75040285, 210550938, 2032088, 202246310, 75482560, 45876476, 134217728, 1976320844, 7160201, 3195423884, 1067778987, 1056474251, 202213229, 36455562, 69174705, 206587384, 7127054, 1540653062, 202048291, 68339120, 1084752155, 1062142473, 74630606, 1068532764, 23020108, 1056638121, 58770811, 198199704, 135069704, 3188099199, 1094372410, 2089894028, 27952196, 61736335, 217956547, 99468683, 76546892, 15581722, 89065223, 1963606574, 1963622962, 698696137, 1047218765, 1053557639, 1057637415, 19055465, 1975091505, 206554610, 123699792, 88540925, 1059783635, 1978172130, 28591287, 719259488, 1066599187, 2966354, 1964097895, 381683972, 74401348, 50070850, 1059439786, 45761785, 138412432, 73516689, 74466896, 3196226550, 1969685077, 3189623065, 87705327, 1161069854, 3193736396, 137807075, 184223602, 540903422, 51266911, 1970373174, 1054737615, 84231865, 2867987, 3195882439, 1287539810, 14975269, 6488497, 1066631566, 3194734625, 42042656, 1061536759, 1160725788, 18383268, 1967571328, 210190440, 123535938, 2050081240, 148359058, 1055327454, 210010209, 328040842, 212861461, 29376692, 1046989352, 75417016, 1057031349, 1050102526, 1975485021, 217710846, 1047824759, 905561619, 17039643, 6898224, 1160217864, 710723099, 710542817, 121913884, 2074166410, 1060226121, 199133908, 718848590, 9535795, 1052134435, 1057080225, 44254508, 1971519793, 1976615763, 82493760, 1974715219, 206570995, 103991011, 196397998, 1967538550, 3192442036, 1064207448, 703398181, 1966506307, 199166681, 16679243, 1047104174, 1056343180, 212926695, 17203523, 3193767961, 1978680151, 1063339102, 153190471, 3192966334, 67584540, 134610950, 2109754425, 1052836285, 187630436, 1064387600, 198052167, 1972240981, 207013515, 3196144620, 85377678, 37504192, 139395924, 1974862678, 1971290674, 3190148257, 208994698, 691914548, 1965080988, 10732125, 1051413163, 3195538659, 1186598003, 94127909, 1095024974, 1971896732, 210419861, 3192179231, 803288, 1061848050, 197773578, 1047317449, 173770560, 42075428, 1056359568, 1060897952, 1046776344, 1048873389, 148308594, 200592418, 192546666, 1069139065, 1963327877, 3191228862, 2072478935, 701432098, 197216336, 200084439, 1093468516, 3195194590, 133939206, 719095653, 1067648167, 15811148, 3190180173, 1066223525, 1090961735, 1067615399, 74073524, 1047890442, 1963754077, 1964654885, 1967768020, 84607817, 1070678454, 905872978, 199805848, 52544846, 1059407012, 205439604, 1954857268, 1969357221, 1055655137, 85754268, 27231415, 195578356, 12386310, 56509805, 1045449560, 95273344, 1049479382, 1070154033, 1933132128, 36652179, 3194113187, 54576382, 1061880857, 217874931, 165281808, 1046268291, 18825816, 6357404, 1044974408, 5243399, 14664000, 1059881994, 2074510372, 1057784891, 3194129572, 1965900167, 1955253303, 1051380360, 178061570, 197757193, 22594098, 1050348722, 71943705, 1057277118, 1978990825, 3191884293, 152618944, 2074854438, 1067467675, 2050146549, 25871543, 1977090798, 25789607, 209715288, 37487399, 1972044246, 1955007540, 31425514, 195365194, 187957575, 2074412024, 1976484687, 1128172645, 905578009, 120931123, 20087565, 210124914, 1058718628, 71861778, 210403471, 11518842, 1054835922, 121897715, 50382078, 215744708, 904742553, 1055425688, 44073761, 1978925244, 1057342409, 1047235156, 37536554, 1966260817, 1976287591, 1158876268, 12124945, 118425436, 1971634503, 1067549841, 1966096856, 3189459212, 1064256637, 1066566419, 44287277, 145801585, 2072839160, 712084553, 3189491982, 10011229, 1066157998, 6701527, 3192376499, 75056670, 336529506, 692044041, 6734673, 1963819302, 4637111, 1973633450, 1975520323, 1178010205, 1964147070, 1964507710, 2072331180, 1058472932, 207816359, 1070645669, 18073444, 74171862, 1967014446, 1058030685, 206521836, 1046661554, 358074570, 26395431, 3192343729, 148522690, 1964442140, 7504207, 72517184, 3189393670, 1055523356, 37798145, 1973977693, 139575734, 1955496120, 1979678965, 1049823886, 123568739, 1966244428, 1068188636, 83591401, 1165380717, 712230084, 199396092, 1969521206, 108267346, 1047792604, 600427392, 3191917059, 14254884, 206489057, 83181926, 48399668, 1969045824, 154010220, 1086341413, 1063011359, 1068647462, 1049299943, 3193424552, 905135564, 85395140, 145178972, 3194424072, 153010201, 1963737689, 1976452971, 37520577, 1057997910, 1970176399, 3187837032, 557063, 88901378, 15631064, 1084441326, 1971552567, 691716480, 1055605276, 718504524, 15155534, 52888916, 12927786, 1044679492, 56460660, 1049267173, 1060504102, 3193948898, 18023131, 1975140700, 92309208, 3192211997, 1056687281, 210075755, 3193866971, 7455048, 197740797, 89572042, 1977303885, 172687961, 718570060, 1058931900, 1963098435, 1051724641, 1067533457, 6504899, 1067992008, 1971093942, 1049512164, 1055572508, 15551555, 196545505, 1972961820, 199773066, 1972633969, 217776609, 711511112, 3195930667, 1061028909, 26526493, 14598899, 1964999038, 1974026988, 1054180535, 17056160, 1965162947, 134184966, 1046710582, 51447138, 1196504, 122257972, 140034555, 84869761, 1970127232, 24019745, 24461784, 1058636975, 3196259329, 73779170, 207472156, 1970569821, 119523165, 1085079936, 2073674696, 189661558, 1063175218, 73713318, 1978516307, 1062650828, 1059456171, 1977910082, 1969537559, 14582219, 134266880, 91997038, 1061962729, 190070785, 2073412560, 698745177, 1070399847, 48694584, 540182970, 1065157743, 1965523238, 86820573, 1973748182, 92341991, 2074198984, 138494374, 114755418, 21253187, 187908843, 1059800043, 27493337, 1970487875, 702975071, 73369212, 95455035, 3196505114, 1973485937, 177963282, 1963917784, 74122693, 3188133097, 215499008, 2084945954, 188285787, 1057702965, 90179347, 160663433, 1089752156, 1059046467, 100811009, 195627572, 197839127, 3189934065, 123437506, 18809712, 1063781296, 1965654339, 1046285061, 64898470, 206816874, 193644421, 54658407, 153075776, 120849180, 2074936312, 3188034698, 139247947, 11157952, 1975272267, 1978319573, 1964556881, 905823813, 24952838, 2084700400, 26870510, 1971389013, 1965179336, 3194768603, 187498760, 133136843, 2089877654, 718701149, 1950679653, 3193145987, 1060865158, 138838370, 2084618429, 1978909144, 2073543895, 1047611822, 1046104477, 85098502, 1061929983, 1055229151, 1051741039, 1933344956, 144359701, 1064109120, 11960942, 78905684, 83263846, 3193686726, 1085522235, 36291704, 153272542, 85705130, 100712705, 1051331191, 3194440462, 1971274286, 12845856, 61293956, 1047054681, 2089943181, 71910933, 1966932438, 1068516380, 73336439, 1969783078, 135004882, 3189754148, 3190229330, 153681980, 85427912, 3190573426, 1966326368, 15286661, 1973781046, 1069352083, 86377080, 3195325664, 1044548511, 1047530030, 1969439176, 86787804, 135036934, 3190475115, 1066207128, 104269646, 1061258257, 99501453, 82788741, 1966571860, 199969738, 1160758048, 691454685, 1056310413, 3190950298, 3189525652, 1068237798, 135332024, 1973371214, 3187723399, 1974796828, 1068270568, 153583670, 5882443, 19153461, 13484857, 3189590293, 42059043, 3189147887, 3190851575, 1056752818, 1054442648, 158288986, 198003011, 1063617666, 3192064530, 51070297, 1970864482, 1963213156, 1064338336, 1046449054, 1964425751, 1057244345, 1063978073, 1539965400, 719880960, 1056801974, 5390351, 1046858465, 17318182, 184272757, 29246664, 1977533266, 134807732, 75400629, 206472663, 166759476, 905479684, 1976831043, 3188672705, 18367061, 1973190950, 50201926, 691014703, 3191704043, 6324640, 3188262888, 23544603, 134547418, 1051823035, 55379268, 1049840272, 16630484, 210583703, 1058981054, 1969226097, 700777240, 176703297, 691536206, 1049807494, 1063634046, 17810260, 4817214, 207488536, 1061864459, 210059367, 1056228430, 73385603, 61326726, 202622907, 158451522, 51414369, 1057817663, 17154259, 2074919900, 1048627893, 1045433352, 84181639, 72123941, 134299648, 905201106, 1058210944, 16384188, 1068811316, 74106302, 192627520, 10437178, 1062863869, 1069466704, 195266666, 1050282947, 207849134, 1965015424, 1050921360, 1192266880, 54936941, 148456363, 206390725, 1063191608, 1975010132, 13648667, 1065174028, 1976222539, 18006748, 1965834614, 1979597011, 2050164601, 62342554, 1963032887, 6799708, 18170430, 1048300120, 1070596102, 26624798, 1046317669, 3192916599, 2072659091, 140263884, 206620175, 1964245413, 11977163, 83542364, 1051052546, 139314058, 18613069, 15499989, 1979416764, 140083577, 1047087592, 176244623, 1966457147, 199904180, 209813604, 25412646, 25330387, 1045105555, 88966916, 186237245, 720011838, 199346936, 1050724673, 2111488332, 72664652, 1059030083, 1963540952, 7258319, 103842511, 1967194395, 1970602267, 63112603, 72566343, 4620594, 1046513986, 632163263, 3189115114, 193986825, 1054688464, 1061078007, 1976550104, 3192868027, 189645550, 2074477530, 87541481, 1045760132, 1978974435, 28328476, 1060111415, 72386103, 8995425, 84132679, 905315812, 19907000, 1976992049, 9552313, 13517616, 1050659067, 1975894364, 1968652760, 1045891544, 698780761, 1047382939, 61752720, 1178222886, 14483462, 61261187, 3189918872, 1976582994, 210255995, 1932951857, 138674648, 86492890, 215564770, 195217385, 1970782535, 1969619518, 134692871, 178864404, 1055147076, 1973060172, 3195865383, 9027994, 1054082250, 9666950, 207029907, 153632825, 197806356, 3195832362, 73975405, 1973961305, 9650749, 2084831259, 13632282, 1055130692, 905758304, 1963672131, 1056670897, 3188902107, 19579304, 543803840, 699139897, 1968390518, 187335500, 1058653360, 210370682, 3193408710, 1979154665, 11485190, 1062994968, 2072429614, 36439260, 39420444, 690605107, 1178272128, 1049643418, 3196308482, 72255026, 1933164900, 1063601281, 69698994, 1970061671, 140313169, 1059947543, 1977221858, 3190557041, 152717254, 138576323, 1972322597, 1047546477, 173786999, 3195734954, 50234687, 1048136297, 1973256503, 1054524632, 3194178440, 2130591995, 1539473767, 714443851, 20136269, 215482618, 1964884320, 73451145, 905021099, 691847508, 702450752, 7946695, 56280428, 1979449564, 16728797, 1069220380, 3194964787, 77071222, 1969570350, 2073723972, 1093944141, 1068319736, 91308315, 2074805348, 1977566035, 1044761166, 138756430, 71090923, 99713828, 1060570269, 197165450, 196299659, 1968947499, 11665414, 86460121, 134348800, 188368823, 1979121884, 85000822, 210354304, 1177405250, 1085867146, 1051954213, 1965326898, 1068926020, 1971257884, 128418517, 207619644, 3189852466, 1058702243, 146802555, 1973699016, 146507733, 1062896642, 1071202932, 1059537823, 2073887736, 2111276937, 1977665381, 1178173822, 1085096781, 90195728, 28460171, 15106371, 1056933011, 198380001, 1972552032, 1972470083, 71796233, 22889196, 1177174407, 1084310342, 719635040, 3192523335, 1060734136, 1968505253, 2074952620, 3191769590, 1978433995, 905102786, 83476906, 1974469454, 149997472, 1058540624, 3191130234, 1158220904, 2074444983, 1057883206, 86361814, 187007824, 697566053, 1975222606, 187040075, 198265251, 10322477, 1966850490, 1060766697, 1057358795, 15024433, 71697883, 24117720, 1067746204, 217580052, 1969422787, 71730675, 1091240476, 215433448, 209911923, 3195751654, 198330807, 1049709061, 104466203, 1048528983, 198363614, 1969324431, 206636561, 175081373, 1058292261, 1973797399, 7471433, 26690347, 1053475732, 4031403, 25576234, 160171985, 29164742, 95814477, 49661251, 1061749787, 74974725, 50038064, 173590335, 1058325033, 1054475416, 28361643, 905430523, 1977320270, 905463296, 85885567, 3191572484, 1965064591, 1978778365, 138445094, 1051921371, 1070317908, 50103612, 1973207339, 1068352504, 1054393549, 37634876, 1057965133, 82837815, 1963295102, 1059177570, 719831771, 691978421, 3194276610, 73057920, 140411482, 1051314804, 702893116, 72369717, 1968406910, 12862236, 210763932, 152651714, 125536214, 84803845, 1048971917, 1974272334, 1971159499, 8208838, 691505008, 1068368888, 210157686, 44139816, 86098347, 6832669, 3189672223, 1046972615, 26592041, 373278953, 1970700599, 86329045, 3192048147, 3188607164, 1047644307, 197871902, 1974829644, 1966375206, 1978155356, 207209924, 20005616, 178094347, 3187918957, 1047513560, 148555646, 1068467219, 1955087072, 1963704913, 88672001, 199084729, 1964392920, 3188164746, 7668303, 1055442072, 199330550, 1058112620, 143016564, 6455307, 15073628, 542132970, 905741811, 10781419, 1978008024, 1091601144, 1050151750, 22495238, 2073445395, 1056572563, 25314002, 1964949863, 3193014906, 18186699, 1061061683, 606929, 7586111, 3191180008, 4473268, 1056900243, 1970225578, 1054917845, 44352810, 193578693, 27771939, 3196210414, 198773344, 104630070, 1084768549, 2089910424, 97322824, 2130559215, 2072069258, 1060979746, 1062962198, 153600055, 719143371, 210731168, 3260712, 10666572, 718799725, 15188308, 71779843, 904857566, 1044499493, 1972404535, 1955335237, 88048307, 905856584, 1063486578, 198920831, 3191687658, 155191258, 14517089, 3193670340, 1068680230, 3193080445, 690621483, 2072118300, 1058440356, 207291892, 199461690, 1046792747, 1177159548, 140395097, 1045859167, 64865701, 1047841722, 1055032293, 197904684, 28001358, 17696610, 20578652, 149653558, 1068581924, 701104488, 1963508180, 1057539093, 123716357, 188154718, 143278966, 187974415, 176195473, 1963655742, 140476842, 5014005, 210567302, 83886345, 1049954976, 1045335044, 1974239982, 1966391595, 195283222, 1971028367, 1046629393, 3190049946, 11468806, 137200133, 71992861, 710493656, 1071089623, 1972126254, 143589991, 1051150974, 8159534, 1053131649, 90408734, 11190790, 198232480, 176717993, 73041535, 905659946, 1057473523, 86427351, 153043016, 195561969, 1976648532, 1047006015, 74941954, 1057981517, 1062503346, 3188803789, 1048037660, 1047759492, 1966621028, 701284754, 710575591, 1059292297, 153534666, 1071006301, 1059079241, 1975353733, 207586862, 905774689, 1067663832, 1048578187, 86393153, 75138597, 1046743411, 3187902572, 2072757286, 73156252, 23003671, 91081797, 189694686, 1970537045, 1060651941, 134480046, 3194866479, 1054147788, 152928276, 11419654, 37405474, 146129527, 2072691791, 1048726456, 1064060022, 718242340, 1970471486, 19071396, 15597916, 1045612826, 95781195, 1979400664, 1046219242, 82446287, 1061389298, 1973043779, 73664162, 206718536, 2074379324, 1068975183, 1963245927, 3191737493, 467028495, 7356926, 1058145397, 1967522161, 88982208, 1973338439, 1978188135, 3192572492, 123519742, 1059259526, 1972388148, 1973682627, 1966834102, 3187887243, 1967620495, 691421504, 1540342035, 1964196231, 1975239498, 1965752674, 3192474175, 196643826, 905889364, 150603628, 1964114289, 3195702179, 1053393808, 1970831700, 1973584271, 18645340, 1964458542, 139445067, 700810007, 88113845, 196414387, 74221029, 691388739, 104071877, 1066272680, 199150295, 75089440, 1974895448, 1063158834, 2072347831, 1971175892, 104335113, 19366214, 1069122681, 1978958044, 3192293927, 84656502, 3194391308, 15123182, 85164918, 37454638, 83018464, 1969275264, 1970733528, 121324332, 1049971364, 1063355486, 719340084, 187253157, 1979646185, 3191753899, 73812024, 139542827, 1059963930, 17793564, 2074723272, 87672558, 1978663766, 3194538770, 663800838, 88278775, 3189786922, 1971716450, 186991320, 193873771, 33342242, 1051527956, 1054721231, 71714271, 1048758592, 1964524099, 2073511059, 17383808, 1978565166, 1968521642, 209829990, 143524016, 1966768527, 13665053, 88883901, 82313515, 1061569540, 122308451, 85460682, 1963852081, 1540276435, 3192310921, 19252071, 1055311070, 209764446, 1160201490, 1051429553, 195971802, 140296655, 1973272891, 1964736823, 176832690, 691487028, 206374334, 2130575605, 103695054, 10174986, 1045269290, 153911380, 526224063, 1160250634, 1974255947, 1972650358, 86754116, 3190196559, 1061454882, 175228828, 2074018963, 703955669, 1052819900, 3189247121, 1964867924, 1975583179, 1065764821, 198576695, 3192097291, 1974125382, 157040824, 9978307, 16401121, 1054344395, 710591977, 1971749222, 84902539, 71370188, 69223848, 1972306203, 1062061050, 42272034, 36193192, 2074624924, 1933148514, 24625624, 1049397356, 49988914, 1968865885, 87639788, 153305323, 1968931110, 3193653271, 1062814728, 86081889, 1049873041, 3195112257, 193430120, 176211851, 85804752, 905512461, 1061176263, 165298338, 11108933, 120881950, 1952137396, 62965153, 74892797, 1976025926, 1058407584, 197560500, 1971372625, 207259100, 6307850, 3188705476, 1063830461, 3193490093, 701727065, 1044990334, 1050954157, 1045744359, 82346343, 196217698, 111413076, 22775659, 167133543, 2072151108, 26199223, 1055294686, 1059325094, 94258989, 2085191728, 61244802, 16564692, 1048546057, 196348843, 1969308039, 12501783, 1978549076, 1045956955, 1964130678, 206702148, 1160430865, 1060357228, 199543632, 1955512714, 585354080, 1050527902, 1047939428, 72500798, 1969914179, 1064322106, 150964154, 2084585814, 71845393, 19300677, 905364981, 136203327, 1046038625, 701399329, 153387519, 206341556, 71681483, 1050364663, 3188967641, 1063716001, 904906811, 153550900, 85033613, 195742414, 905676339, 1059144799, 1976107480, 21939889, 1049758252, 210501774, 1053115262, 719243105, 3190819212, 1047775932, 2075001818, 75449788, 1085113166, 155207642, 1084965724, 199953348, 201360153, 55625077, 710837845, 699908470, 72042016, 199101131, 3189999934, 3188787404, 197888297, 3195176934, 1974321995, 1974141233, 33260316, 1051544372, 9683551, 3191949831, 86671687, 2073789416, 152944661, 95191301, 45843707, 1049987755, 210337912, 13714225, 1047333849, 196676602, 16875992, 3191212476, 2084995108, 1052787134, 176767146, 1049446572, 207275496, 1186843774, 1045351045, 2084962367, 2086371625, 17728216, 167380923, 27149464, 1955103598, 1058784167, 6275514, 904939582, 53200218, 1054672080, 19677791, 75007491, 99960215, 165396755, 1977352591, 82477339, 23134650, 1051708253, 3189098722, 1977697021, 210174060, 83132772, 1049725452, 1047136345, 7045484, 1969390006, 1160398094, 1975124283, 140165506, 1057489927, 1051987100, 1051101719, 1063732385, 200281081, 1068155866, 1970110846, 3195407586, 2073314309, 207750799, 1063142449, 74319368, 1084784934, 1068631076, 6766931, 135151877, 1976631836, 1045187035, 3244317, 207145014, 699630318, 1160463635, 1046939788, 88999685, 26936214, 1160234249, 1056588947, 59098497, 1973551493, 1048676785, 1974930499, 1177747707, 20104049, 3194588375, 186797123, 5554718, 22087343, 85508754, 27542496, 25559835, 2074395612, 8978824, 1979695355, 1967489382, 701366561, 1086357798, 2074362885, 1963147598, 72648267, 207079068, 138625339, 1977549393, 1976877821, 158238646, 138658250, 1970553433, 1965097381, 197281897, 1044744745, 1969635907, 1968570819, 3192638035, 1970143621, 204720243, 1965474400, 206915193, 24511057, 1058129009, 1963114823, 1061602253, 1975370572, 95962938, 1048709947, 1971618115, 15090136, 1054164172, 104285885, 1973600668, 144146705, 1971356236, 1054262478, 200428560, 3191507415, 2074117056, 3189868851, 1963590172, 1080410492, 1966784924, 1979023605, 37438253, 1058423972, 1977991516, 52725073, 1069203996, 88295161, 2074100636, 1972781482, 697368764, 1976435293, 28263607, 176029880, 3189263506, 1068057552, 1975157208, 17367884, 3192719967, 1978204549, 1967554942, 1972568418, 1060160575, 122192480, 28411020, 1070285131, 1966604642, 122274566, 75318871, 47121197, 698761759, 1965228502, 37733178, 10191368, 135118856, 1970962814, 49972538, 1979187445, 193446506, 2074264655, 134857332, 2050114757, 3191065007, 1046088186, 1975468757, 1056244814, 1055622291, 75187766, 3192015374, 1971192278, 1060832330, 1965736288, 1069973761, 19613542, 1974616412, 1979220308, 138854756, 1068418053, 3194243841, 1051019749, 1064191092, 2072740840, 74565060, 1050233711, 1973010996, 10109387, 218038473, 3188050044, 96157861, 1967047220, 87950000, 1976172878, 88867039, 1974206936, 15778332, 1967997529, 1045318652, 1056949395, 195070029, 140067192, 2111439207, 1977271116, 1970241974, 166872775, 99517838, 94291759, 143491329, 124684108, 1052085449, 1976205652, 1047038269, 1979515125, 3196291121, 1063404653, 1064354891, 7422255, 17646006, 1965801831, 1065239565, 1975059266, 1058833337, 1055245535, 3190507885, 1160119556, 1967358275, 199445293, 2111113026, 162202876, 85051075, 1055490588, 189677873, 3192899596, 135021267, 1977420867, 123470363, 207406603, 62014867, 74237420, 1050331485, 1933066567, 2073691164, 588827495, 217940161, 82542872, 1049364575, 1044909061, 1060603028, 1059521438, 1973027390, 1062798344, 1966916052, 1054590170, 1058341417, 74745315, 198904438, 1054295246, 1974501775, 9814450, 1976336768, 9882691, 1046464668, 195594741, 139461346, 1976369998, 138510759, 203948297, 364234938, 10420795, 2085224498, 2072134803, 1060193351, 1148306684, 1059308681, 143098399, 82329956, 26510116, 1046399671, 1059341470, 142852939, 190120276, 3188591759, 1933001019, 1963000113, 200051668, 1055376531, 2074133461, 206964351, 1073267475, 1049922199, 1054934228, 1050806627, 1054426317, 1060390005, 135495844, 72910446, 17334631, 41631995, 91717931, 1047808116, 703433784, 1540391281, 209879151, 1055343838, 134381568, 691618542, 26755544, 1964081510, 579307558, 10093011, 1063846845, 3193342625, 1044433773, 1050855793, 1048202141, 1963721301, 199281392, 1050184540, 1051478732, 179685065, 1049495768, 3192736951, 73533074, 14745606, 1973453158, 8356190, 24134433, 1051396773, 1059488943, 718291688, 1094353436, 1045695176, 197380230, 1049594217, 89785035, 213008889, 23528219, 44369195, 199363321, 1044400564, 1049413784, 63407520, 2072888272, 3196455957, 1048283695, 23085507, 1969848631, 1049692624, 120980277, 904808411, 197462177, 1054639312, 38044903, 82624894, 15270247, 1090800695, 26641183, 196856487, 1970946422, 36635863, 1046907788, 29066198, 85590903, 2072675396, 210780287, 198888050, 142950584, 206767694, 1058964670, 1056539852, 700255291, 1044646694, 1044564174, 1148636231, 1046596593, 1056490635, 3192244768, 3189704994, 1047071111, 1963459011, 1044613742, 1046383055, 193660807, 134578686, 1977746264, 1054508248, 1086832804, 50185527, 1965458013, 11796737, 1057014965, 1045088836, 17908313, 2073855036, 207570469, 1061504051, 1060176966, 43630843, 54674739, 198527521, 1963983179, 1047596066, 1160660248, 199887791, 1177485671, 198150521, 1970684212, 1068385285, 1053295505, 2084929569, 1070121254, 3195358059, 93143886, 189940450, 1967096396, 73565845, 1062716405, 2072314872, 72222256, 4997579, 905610788, 84116099, 215695566, 1059980317, 7438765, 1971585496, 1966489920, 1063945214, 87556778, 1069237386, 1148355373, 139297673, 74581449, 37962988, 199248614, 217596112, 28574924, 30409505, 2085093418, 193741428, 72746588, 3195276121, 215613931, 123388493, 1062028270, 2050099073, 1095319860, 82887047, 30967003, 1969897792, 1050970556, 2074821753, 138772817, 1085014347, 150046627, 1966424372, 3189901296, 153371127, 1045170582, 1976419029, 199609190, 1977337724, 135053319, 1973092949, 91029812, 2109849887, 141526849, 2130444628, 23806850, 1055114308, 1975075654, 210649213, 90292580, 3190376800, 165380357, 92012875, 198756958, 1045137537, 1057063839, 1975992613, 701661532, 2073248889, 200346631, 3188394153, 432637981, 1975599832, 1062634444, 152602559, 1062781942, 11633037, 55805181, 1095172390, 52741459, 30442724, 200608805, 88523448, 74647001, 1068958788, 1969078599, 103940771, 1978499665, 210632834, 208930778, 210681994, 3196095460, 1060127801, 153485804, 28492946, 1060455558, 2110687178, 1050036937, 3191851517, 1970798923, 1968423296, 1051675483, 88081076, 3195390836, 1059619761, 2074182630, 1967129173, 2074526766, 3192195614, 3192539723, 3194571992, 3195045924, 88851061, 1971044764, 12780319, 1064371240, 85361528, 1053148031, 3192802457, 3191933446, 1965425237, 87607019, 33243937, 1070235968, 691880822, 1963049275, 1059423396, 1964966257, 1969242486, 1057506319, 94144294, 1974944092, 1070612879, 1971536180, 691929268, 1070498183, 703381888, 1973174565, 1964360148, 1073349080, 20988330, 87262950, 1978401306, 85918357, 3191589343, 1970930033, 1540407685, 691323179, 88475387, 1067729820, 1044794265, 3191835127, 135578215, 198347201, 147474363, 703250643, 3187787867, 1973404000, 1160299789, 73926316, 1972453696, 2073822260, 207767186, 187401037, 701350293, 1975681564, 1965310510, 1964015956, 1974993454, 3191146934, 1973142112, 128401850, 1048250897, 2111504759, 1065223311, 1933033792, 1054966745, 139002247, 200510489, 1972191811, 74417738, 17761031, 139559338, 1070744011, 1969946955, 37307584, 3190032708, 186745670, 1975911128, 3190294878, 1058866111, 1972486471, 1062978584, 166019237, 2073265128, 3195996205, 84721939, 1084670117, 26608415, 199035558, 2074215452, 71878163, 1060815945, 193807255, 24166657, 23823235, 39109402, 1933246846, 27558878, 1177928220, 1978794542, 1967440224, 204605614, 1977844270, 134332416, 1054622938, 1063322711, 710772280, 3192555528, 218071255, 1976470595, 710428112, 139035470, 1965883781, 3193047676, 1064272834, 26329628, 139985368, 74991110, 1058308646, 17220334, 1968669238, 1964933478, 1965277719, 2281310139, 176685333, 444221443, 72484413, 1971929514, 16600131, 1061422105, 198035782, 3195013943, 99796370, 206849647, 1086620508, 1044449812, 1057293502, 12076879, 1973994080, 24003361, 1044417406, 210206825, 1974387047, 2084733180, 1963409834, 1978024259, 2072872119, 1972830659, 1080262994, 138428817, 1976254812, 7405869, 60031516, 9568578, 1968636374, 1971225142, 1979613404, 29148357, 217858544, 27280334, 1116995764, 22233457, 1978731587, 10306083, 3189689494, 1965916559, 1069286547, 1971880335, 73434759, 1054786769, 86311575, 1971487014, 153780299, 5227015, 2073576348, 1969373610, 1063289948, 53003607, 3190606198, 1967063614, 1044482235, 1093143649, 1069253770, 3188066429];
+pub static CONFUSABLE_CHAR_RANGES: [(u32, u32); 1539] = [(34, 34), (37, 37), (48, 49), (73, 73), (96, 96), (109, 109), (124, 124), (160, 160), (162, 162), (165, 165), (175, 175), (180, 181), (184, 184), (198, 199), (204, 208), (215, 216), (230, 231), (240, 240), (248, 248), (268, 273), (282, 283), (290, 291), (294, 296), (298, 298), (300, 300), (302, 302), (304, 307), (310, 311), (315, 322), (325, 329), (338, 339), (342, 345), (350, 359), (381, 388), (391, 391), (393, 394), (396, 397), (401, 403), (406, 411), (413, 415), (420, 423), (428, 430), (435, 439), (443, 445), (447, 449), (451, 468), (473, 474), (482, 489), (494, 499), (508, 511), (514, 515), (518, 520), (522, 523), (526, 527), (530, 531), (534, 535), (540, 540), (542, 543), (546, 549), (552, 553), (572, 572), (574, 574), (577, 577), (580, 580), (582, 585), (589, 591), (593, 593), (595, 595), (598, 599), (601, 603), (608, 609), (611, 611), (614, 614), (616, 619), (621, 623), (625, 625), (627, 627), (629, 630), (636, 637), (642, 642), (651, 651), (655, 656), (658, 658), (660, 660), (672, 672), (675, 683), (691, 691), (697, 703), (706, 708), (710, 710), (712, 712), (714, 715), (720, 720), (723, 723), (727, 733), (737, 738), (740, 740), (750, 750), (756, 756), (758, 758), (760, 760), (763, 763), (773, 773), (780, 781), (784, 785), (789, 789), (791, 791), (800, 802), (807, 807), (822, 823), (825, 825), (834, 834), (837, 837), (839, 839), (855, 856), (870, 870), (878, 878), (880, 880), (884, 887), (890, 891), (893, 893), (895, 895), (900, 900), (902, 902), (904, 906), (908, 908), (910, 910), (912, 914), (917, 925), (927, 927), (929, 929), (931, 933), (935, 935), (938, 949), (951, 954), (957, 957), (959, 959), (961, 961), (963, 966), (970, 973), (976, 982), (987, 988), (1000, 1001), (1008, 1013), (1015, 1018), (1021, 1021), (1023, 1025), (1027, 1032), (1036, 1036), (1038, 1038), (1040, 1043), (1045, 1045), (1047, 1047), (1050, 1061), (1067, 1068), (1070, 1070), (1072, 1075), (1077, 1077), (1079, 1082), (1084, 1093), (1098, 1100), (1103, 1105), (1107, 1112), (1115, 1118), (1121, 1123), (1136, 1143), (1148, 1149), (1162, 1165), (1168, 1171), (1174, 1179), (1182, 1183), (1186, 1187), (1194, 1202), (1211, 1211), (1213, 1216), (1221, 1243), (1246, 1249), (1251, 1251), (1253, 1259), (1262, 1267), (1272, 1273), (1281, 1281), (1290, 1290), (1292, 1293), (1296, 1297), (1307, 1309), (1339, 1339), (1348, 1348), (1354, 1354), (1356, 1357), (1359, 1359), (1363, 1363), (1365, 1365), (1370, 1370), (1373, 1373), (1377, 1377), (1379, 1379), (1382, 1382), (1390, 1390), (1392, 1392), (1397, 1397), (1400, 1400), (1402, 1402), (1404, 1405), (1409, 1409), (1412, 1413), (1415, 1415), (1417, 1417), (1436, 1437), (1444, 1444), (1448, 1448), (1453, 1455), (1460, 1460), (1465, 1466), (1472, 1477), (1493, 1493), (1496, 1497), (1503, 1503), (1505, 1505), (1520, 1524), (1545, 1546), (1549, 1549), (1551, 1551), (1560, 1562), (1570, 1571), (1573, 1575), (1579, 1579), (1588, 1588), (1597, 1597), (1599, 1599), (1607, 1607), (1610, 1611), (1614, 1615), (1618, 1619), (1622, 1629), (1631, 1633), (1637, 1637), (1639, 1640), (1642, 1647), (1650, 1651), (1653, 1657), (1662, 1662), (1665, 1665), (1669, 1669), (1672, 1672), (1675, 1675), (1678, 1678), (1681, 1682), (1688, 1688), (1694, 1695), (1700, 1700), (1703, 1706), (1709, 1709), (1716, 1717), (1719, 1719), (1722, 1723), (1725, 1726), (1728, 1731), (1734, 1737), (1739, 1740), (1742, 1742), (1744, 1749), (1759, 1759), (1768, 1768), (1772, 1772), (1774, 1785), (1789, 1791), (1793, 1796), (1856, 1858), (1863, 1863), (1873, 1873), (1878, 1878), (1890, 1891), (1895, 1897), (1900, 1900), (1905, 1906), (1918, 1918), (1984, 1984), (1994, 1994), (2027, 2027), (2029, 2030), (2035, 2037), (2042, 2042), (2209, 2209), (2212, 2212), (2215, 2217), (2222, 2226), (2230, 2231), (2233, 2237), (2277, 2277), (2280, 2280), (2282, 2283), (2285, 2286), (2288, 2291), (2296, 2298), (2303, 2308), (2310, 2310), (2312, 2312), (2317, 2318), (2320, 2324), (2345, 2345), (2353, 2353), (2356, 2356), (2364, 2364), (2386, 2388), (2392, 2399), (2405, 2407), (2429, 2429), (2433, 2433), (2438, 2438), (2492, 2492), (2524, 2525), (2527, 2529), (2534, 2534), (2538, 2538), (2541, 2541), (2562, 2563), (2566, 2570), (2575, 2576), (2580, 2580), (2611, 2611), (2614, 2614), (2620, 2620), (2635, 2635), (2637, 2637), (2649, 2651), (2654, 2654), (2662, 2663), (2666, 2666), (2689, 2691), (2694, 2694), (2701, 2701), (2703, 2705), (2707, 2708), (2748, 2749), (2753, 2754), (2765, 2765), (2790, 2790), (2792, 2794), (2798, 2798), (2800, 2800), (2817, 2817), (2819, 2819), (2822, 2822), (2848, 2848), (2876, 2876), (2908, 2909), (2918, 2918), (2920, 2920), (2946, 2946), (2954, 2954), (2964, 2964), (2972, 2972), (2992, 2992), (3006, 3006), (3016, 3016), (3018, 3021), (3031, 3031), (3046, 3048), (3050, 3054), (3056, 3056), (3058, 3058), (3060, 3061), (3063, 3064), (3066, 3066), (3072, 3072), (3074, 3075), (3091, 3092), (3104, 3104), (3106, 3106), (3109, 3109), (3117, 3118), (3127, 3127), (3129, 3129), (3138, 3138), (3140, 3140), (3168, 3169), (3174, 3174), (3201, 3203), (3205, 3207), (3218, 3220), (3228, 3228), (3230, 3230), (3235, 3235), (3247, 3247), (3249, 3250), (3297, 3297), (3302, 3304), (3311, 3311), (3329, 3331), (3336, 3338), (3340, 3340), (3344, 3344), (3347, 3348), (3353, 3353), (3356, 3356), (3360, 3360), (3363, 3363), (3377, 3377), (3380, 3380), (3382, 3382), (3386, 3386), (3391, 3392), (3394, 3395), (3400, 3400), (3406, 3406), (3418, 3418), (3423, 3423), (3425, 3425), (3430, 3430), (3434, 3439), (3446, 3446), (3449, 3449), (3451, 3452), (3458, 3459), (3561, 3563), (3567, 3567), (3587, 3587), (3595, 3595), (3599, 3599), (3604, 3605), (3607, 3607), (3617, 3617), (3622, 3622), (3635, 3635), (3649, 3649), (3653, 3653), (3661, 3661), (3664, 3664), (3720, 3720), (3725, 3725), (3738, 3739), (3741, 3743), (3763, 3763), (3768, 3769), (3784, 3787), (3789, 3789), (3792, 3792), (3804, 3805), (3840, 3840), (3842, 3843), (3852, 3852), (3854, 3854), (3867, 3867), (3870, 3871), (3895, 3895), (3946, 3946), (3959, 3959), (3961, 3961), (4046, 4046), (4053, 4054), (4096, 4096), (4112, 4112), (4125, 4125), (4127, 4127), (4137, 4138), (4150, 4150), (4152, 4152), (4160, 4160), (4171, 4171), (4197, 4198), (4207, 4208), (4222, 4222), (4225, 4225), (4254, 4254), (4256, 4256), (4327, 4327), (4339, 4339), (4351, 4351), (4353, 4353), (4356, 4356), (4360, 4360), (4362, 4362), (4365, 4365), (4371, 4411), (4413, 4413), (4415, 4415), (4417, 4427), (4429, 4429), (4431, 4431), (4433, 4435), (4438, 4440), (4442, 4446), (4450, 4450), (4452, 4452), (4454, 4454), (4456, 4456), (4458, 4460), (4463, 4465), (4467, 4509), (4511, 4608), (4643, 4643), (4672, 4672), (4704, 4704), (4756, 4756), (4816, 4816), (5024, 5026), (5028, 5029), (5032, 5036), (5038, 5038), (5040, 5041), (5043, 5043), (5047, 5047), (5051, 5051), (5053, 5056), (5058, 5059), (5063, 5063), (5067, 5068), (5070, 5071), (5074, 5074), (5076, 5077), (5081, 5082), (5086, 5087), (5090, 5090), (5094, 5095), (5099, 5099), (5102, 5102), (5104, 5104), (5106, 5108), (5115, 5116), (5120, 5120), (5123, 5123), (5132, 5141), (5143, 5146), (5159, 5159), (5163, 5167), (5169, 5169), (5171, 5171), (5175, 5176), (5178, 5191), (5194, 5194), (5196, 5196), (5198, 5198), (5204, 5204), (5207, 5220), (5223, 5226), (5229, 5229), (5231, 5231), (5234, 5249), (5253, 5256), (5261, 5261), (5266, 5279), (5285, 5285), (5290, 5290), (5292, 5305), (5311, 5311), (5321, 5326), (5329, 5329), (5340, 5353), (5366, 5379), (5388, 5391), (5399, 5412), (5423, 5436), (5440, 5441), (5454, 5455), (5467, 5468), (5480, 5481), (5495, 5495), (5500, 5509), (5511, 5511), (5518, 5524), (5551, 5551), (5556, 5557), (5559, 5559), (5572, 5573), (5598, 5598), (5610, 5610), (5615, 5616), (5623, 5623), (5634, 5636), (5639, 5639), (5666, 5668), (5678, 5679), (5684, 5685), (5741, 5757), (5760, 5760), (5810, 5810), (5815, 5815), (5825, 5826), (5836, 5836), (5845, 5846), (5848, 5848), (5857, 5857), (5867, 5869), (5872, 5872), (5940, 5941), (6051, 6051), (6071, 6074), (6086, 6086), (6091, 6091), (6099, 6101), (6105, 6106), (6147, 6147), (6153, 6153), (6229, 6229), (6294, 6294), (6323, 6323), (6326, 6326), (6329, 6329), (6338, 6338), (6342, 6355), (6363, 6365), (6368, 6368), (6371, 6373), (6376, 6376), (6378, 6378), (6381, 6381), (6384, 6384), (6386, 6386), (6608, 6609), (6784, 6784), (6800, 6800), (6825, 6825), (6827, 6827), (6836, 6836), (6839, 6839), (6994, 6995), (7000, 7000), (7004, 7004), (7007, 7007), (7228, 7228), (7295, 7295), (7376, 7376), (7378, 7379), (7381, 7381), (7384, 7386), (7388, 7390), (7405, 7405), (7428, 7428), (7432, 7432), (7435, 7435), (7437, 7437), (7439, 7441), (7444, 7444), (7452, 7452), (7456, 7458), (7460, 7460), (7462, 7465), (7467, 7467), (7486, 7486), (7506, 7506), (7531, 7531), (7534, 7536), (7538, 7542), (7544, 7544), (7547, 7551), (7555, 7555), (7564, 7564), (7568, 7568), (7583, 7583), (7586, 7586), (7610, 7611), (7662, 7662), (7688, 7689), (7696, 7697), (7708, 7709), (7720, 7721), (7724, 7724), (7726, 7726), (7743, 7743), (7745, 7745), (7747, 7747), (7782, 7783), (7834, 7835), (7837, 7838), (7880, 7880), (7882, 7882), (7935, 7957), (7960, 7965), (7968, 8005), (8008, 8013), (8016, 8023), (8025, 8025), (8027, 8027), (8029, 8029), (8031, 8031), (8038, 8039), (8046, 8059), (8064, 8116), (8118, 8132), (8134, 8147), (8150, 8155), (8157, 8172), (8175, 8175), (8178, 8180), (8182, 8185), (8188, 8190), (8192, 8202), (8208, 8214), (8216, 8221), (8223, 8223), (8226, 8226), (8228, 8233), (8239, 8247), (8249, 8250), (8252, 8252), (8254, 8254), (8257, 8257), (8259, 8260), (8263, 8265), (8270, 8270), (8274, 8275), (8279, 8279), (8282, 8282), (8285, 8287), (8304, 8304), (8313, 8313), (8353, 8353), (8356, 8357), (8360, 8361), (8363, 8366), (8374, 8374), (8381, 8381), (8411, 8411), (8448, 8451), (8453, 8467), (8469, 8470), (8473, 8477), (8481, 8481), (8484, 8484), (8487, 8489), (8492, 8497), (8499, 8505), (8507, 8515), (8517, 8521), (8544, 8575), (8579, 8580), (8593, 8593), (8597, 8597), (8629, 8629), (8634, 8634), (8638, 8639), (8704, 8704), (8707, 8708), (8710, 8710), (8719, 8719), (8721, 8722), (8724, 8729), (8734, 8734), (8739, 8742), (8744, 8749), (8751, 8752), (8758, 8758), (8760, 8760), (8764, 8764), (8769, 8769), (8784, 8785), (8791, 8791), (8793, 8794), (8798, 8798), (8803, 8803), (8810, 8811), (8834, 8837), (8853, 8854), (8857, 8857), (8861, 8861), (8868, 8869), (8896, 8901), (8904, 8904), (8918, 8921), (8942, 8943), (8948, 8948), (8959, 8960), (8997, 8997), (9001, 9002), (9025, 9025), (9049, 9050), (9052, 9052), (9055, 9055), (9057, 9061), (9064, 9065), (9067, 9068), (9075, 9082), (9087, 9087), (9116, 9116), (9119, 9119), (9122, 9122), (9125, 9125), (9130, 9130), (9134, 9134), (9153, 9155), (9158, 9158), (9192, 9192), (9212, 9214), (9290, 9290), (9312, 9321), (9332, 9397), (9400, 9400), (9413, 9413), (9415, 9415), (9435, 9435), (9450, 9450), (9472, 9473), (9475, 9475), (9487, 9487), (9507, 9507), (9585, 9585), (9587, 9587), (9608, 9608), (9616, 9616), (9620, 9620), (9623, 9623), (9629, 9629), (9632, 9632), (9649, 9649), (9651, 9651), (9655, 9656), (9658, 9658), (9661, 9661), (9665, 9665), (9671, 9671), (9674, 9675), (9678, 9678), (9696, 9696), (9702, 9702), (9737, 9737), (9744, 9744), (9765, 9765), (9776, 9776), (9784, 9784), (9806, 9806), (9826, 9826), (9833, 9834), (9900, 9900), (10088, 10089), (10094, 10095), (10098, 10101), (10133, 10135), (10178, 10178), (10184, 10185), (10187, 10187), (10189, 10189), (10201, 10201), (10216, 10217), (10539, 10540), (10595, 10595), (10597, 10597), (10606, 10607), (10649, 10649), (10672, 10672), (10686, 10686), (10692, 10693), (10695, 10695), (10710, 10710), (10713, 10713), (10740, 10742), (10744, 10745), (10752, 10758), (10764, 10764), (10781, 10781), (10784, 10791), (10793, 10794), (10799, 10800), (10813, 10815), (10858, 10858), (10862, 10862), (10868, 10870), (10917, 10917), (10922, 10923), (10967, 10967), (11003, 11003), (11005, 11005), (11244, 11247), (11367, 11367), (11369, 11369), (11396, 11398), (11400, 11401), (11406, 11406), (11410, 11410), (11412, 11414), (11416, 11416), (11418, 11418), (11422, 11424), (11426, 11430), (11432, 11432), (11434, 11438), (11441, 11441), (11444, 11444), (11450, 11450), (11452, 11453), (11462, 11462), (11466, 11466), (11468, 11469), (11472, 11474), (11484, 11484), (11492, 11492), (11497, 11497), (11513, 11513), (11569, 11569), (11575, 11578), (11585, 11585), (11592, 11593), (11599, 11599), (11601, 11601), (11604, 11605), (11609, 11609), (11613, 11613), (11616, 11616), (11619, 11619), (11752, 11752), (11754, 11754), (11757, 11757), (11759, 11759), (11766, 11767), (11802, 11802), (11806, 11807), (11814, 11820), (11822, 11822), (11824, 11826), (11829, 11829), (11833, 11833), (11837, 11837), (11839, 11840), (11906, 11907), (11909, 11909), (11913, 11913), (11915, 11915), (11918, 11920), (11922, 11924), (11926, 11929), (11931, 11931), (11934, 11940), (11942, 11942), (11944, 11944), (11947, 11947), (11949, 11949), (11951, 11951), (11953, 11954), (11961, 11962), (11966, 11973), (11976, 11977), (11979, 11981), (11983, 11988), (11990, 11990), (11992, 11997), (11999, 12000), (12002, 12002), (12004, 12005), (12008, 12009), (12011, 12016), (12018, 12019), (12032, 12245), (12290, 12291), (12295, 12297), (12306, 12306), (12308, 12309), (12314, 12315), (12332, 12333), (12339, 12339), (12342, 12342), (12344, 12346), (12367, 12368), (12401, 12401), (12404, 12404), (12407, 12407), (12410, 12410), (12413, 12413), (12442, 12444), (12448, 12448), (12452, 12452), (12456, 12456), (12459, 12460), (12479, 12480), (12488, 12489), (12491, 12491), (12494, 12497), (12500, 12500), (12503, 12506), (12509, 12509), (12525, 12525), (12539, 12539), (12582, 12582), (12593, 12686), (12752, 12753), (12755, 12756), (12758, 12758), (12762, 12763), (12767, 12768), (12800, 12830), (12832, 12867), (12992, 13003), (13144, 13168), (13280, 13310), (14771, 14771), (17307, 17307), (17440, 17440), (19968, 19968), (20022, 20022), (20031, 20031), (20482, 20482), (20540, 20540), (21855, 21855), (22231, 22231), (22635, 22635), (22763, 22763), (22783, 22783), (23296, 23296), (24114, 24114), (24144, 24144), (25144, 25144), (25609, 25609), (26211, 26211), (26217, 26217), (26358, 26358), (26406, 26406), (26623, 26623), (27113, 27113), (27175, 27175), (28505, 28505), (30799, 30799), (32118, 32118), (32934, 32934), (32970, 32970), (32976, 32976), (33014, 33014), (33025, 33025), (33063, 33063), (33089, 33089), (33191, 33191), (34111, 34111), (34369, 34369), (35358, 35358), (35453, 35453), (35727, 35727), (35939, 35939), (36230, 36230), (36346, 36346), (36507, 36507), (36647, 36647), (37086, 37086), (37806, 37806), (38584, 38584), (40515, 40515), (40658, 40658), (40899, 40899), (42132, 42132), (42140, 42140), (42142, 42142), (42151, 42152), (42156, 42156), (42160, 42160), (42170, 42170), (42174, 42176), (42178, 42178), (42192, 42196), (42198, 42199), (42201, 42211), (42213, 42215), (42218, 42229), (42231, 42235), (42237, 42239), (42510, 42510), (42564, 42565), (42567, 42567), (42573, 42573), (42576, 42577), (42600, 42600), (42607, 42607), (42620, 42620), (42622, 42622), (42645, 42645), (42648, 42650), (42657, 42657), (42672, 42673), (42701, 42702), (42715, 42715), (42719, 42719), (42731, 42731), (42735, 42737), (42740, 42740), (42772, 42772), (42774, 42774), (42792, 42793), (42801, 42813), (42816, 42816), (42826, 42827), (42830, 42831), (42842, 42842), (42849, 42849), (42858, 42859), (42862, 42862), (42871, 42872), (42874, 42874), (42889, 42889), (42892, 42892), (42895, 42895), (42901, 42901), (42904, 42907), (42909, 42911), (42923, 42923), (42929, 42935), (42966, 42966), (42970, 42972), (42999, 42999), (43056, 43056), (43360, 43388), (43410, 43410), (43427, 43427), (43462, 43462), (43471, 43471), (43603, 43603), (43606, 43606), (43826, 43826), (43829, 43829), (43837, 43839), (43841, 43842), (43847, 43848), (43853, 43854), (43858, 43859), (43861, 43861), (43866, 43866), (43872, 43872), (43874, 43875), (43888, 43890), (43892, 43893), (43898, 43900), (43902, 43902), (43904, 43905), (43907, 43907), (43911, 43911), (43915, 43915), (43918, 43918), (43920, 43920), (43923, 43923), (43931, 43932), (43935, 43935), (43938, 43938), (43945, 43946), (43950, 43951), (43954, 43954), (43958, 43958), (43963, 43963), (44033, 44087), (44089, 44143), (44145, 44199), (44201, 44255), (44257, 44367), (44369, 44395), (44397, 44507), (44509, 45207), (45209, 45263), (45265, 45319), (45321, 45375), (45377, 45431), (45433, 45543), (45545, 45571), (45573, 45683), (45685, 45795), (45797, 45851), (45853, 45907), (45909, 45963), (45965, 46019), (46021, 46131), (46133, 46159), (46161, 46271), (46273, 46971), (46973, 47027), (47029, 47083), (47085, 47139), (47141, 47195), (47197, 47307), (47309, 47335), (47337, 47447), (47449, 47559), (47561, 47615), (47617, 47671), (47673, 47727), (47729, 47783), (47785, 47895), (47897, 47923), (47925, 48035), (48037, 48147), (48149, 48203), (48205, 48259), (48261, 48315), (48317, 48371), (48373, 48483), (48485, 48511), (48513, 48623), (48625, 49323), (49325, 49379), (49381, 49435), (49437, 49491), (49493, 49547), (49549, 49659), (49661, 49687), (49689, 49799), (49801, 50499), (50501, 50555), (50557, 50611), (50613, 50667), (50669, 50723), (50725, 50835), (50837, 50863), (50865, 50975), (50977, 51087), (51089, 51143), (51145, 51199), (51201, 51255), (51257, 51311), (51313, 51423), (51425, 51451), (51453, 51563), (51565, 52263), (52265, 52319), (52321, 52375), (52377, 52431), (52433, 52487), (52489, 52599), (52601, 52627), (52629, 52739), (52741, 52851), (52853, 52907), (52909, 52963), (52965, 53019), (53021, 53075), (53077, 53187), (53189, 53215), (53217, 53327), (53329, 53439), (53441, 53495), (53497, 53551), (53553, 53607), (53609, 53663), (53665, 53775), (53777, 53803), (53805, 53915), (53917, 54027), (54029, 54083), (54085, 54139), (54141, 54195), (54197, 54251), (54253, 54363), (54365, 54391), (54393, 54503), (54505, 54615), (54617, 54671), (54673, 54727), (54729, 54783), (54785, 54839), (54841, 54951), (54953, 54979), (54981, 55091), (55093, 55203), (55216, 55238), (55243, 55291), (63928, 63928), (64046, 64046), (64256, 64260), (64262, 64262), (64275, 64279), (64285, 64285), (64287, 64301), (64309, 64309), (64312, 64313), (64321, 64321), (64331, 64331), (64335, 64433), (64467, 64831), (64848, 64911), (64914, 64967), (65008, 65020), (65049, 65049), (65072, 65073), (65076, 65082), (65097, 65103), (65112, 65112), (65128, 65128), (65152, 65276), (65281, 65282), (65287, 65287), (65293, 65293), (65306, 65306), (65313, 65315), (65317, 65317), (65320, 65323), (65325, 65328), (65331, 65332), (65336, 65342), (65344, 65345), (65347, 65347), (65349, 65349), (65351, 65354), (65356, 65356), (65359, 65360), (65363, 65363), (65366, 65366), (65368, 65369), (65372, 65372), (65374, 65374), (65381, 65381), (65507, 65507), (65512, 65512), (65517, 65517), (65793, 65793), (65934, 65934), (65942, 65945), (65952, 65952), (66178, 66178), (66181, 66183), (66186, 66186), (66189, 66189), (66192, 66192), (66194, 66194), (66196, 66199), (66203, 66203), (66208, 66211), (66213, 66213), (66219, 66219), (66221, 66221), (66224, 66230), (66232, 66232), (66255, 66255), (66273, 66273), (66276, 66276), (66280, 66280), (66290, 66290), (66293, 66293), (66305, 66306), (66313, 66313), (66321, 66322), (66325, 66325), (66327, 66327), (66330, 66330), (66335, 66336), (66338, 66338), (66513, 66513), (66515, 66515), (66561, 66561), (66564, 66564), (66577, 66577), (66581, 66581), (66587, 66587), (66591, 66592), (66595, 66595), (66597, 66597), (66601, 66602), (66604, 66604), (66621, 66621), (66623, 66623), (66626, 66627), (66632, 66632), (66635, 66635), (66637, 66637), (66720, 66720), (66736, 66736), (66740, 66740), (66748, 66748), (66754, 66756), (66765, 66766), (66768, 66770), (66776, 66776), (66779, 66779), (66794, 66795), (66806, 66806), (66809, 66809), (66835, 66835), (66838, 66838), (66840, 66840), (66844, 66845), (66853, 66855), (68154, 68154), (68176, 68176), (68183, 68183), (68858, 68858), (68860, 68860), (69819, 69819), (70087, 70087), (70090, 70091), (70107, 70108), (70110, 70110), (70400, 70400), (70675, 70675), (70681, 70681), (70692, 70692), (70698, 70698), (70701, 70701), (70703, 70703), (70732, 70732), (70802, 70802), (70804, 70804), (70806, 70806), (70808, 70809), (70811, 70811), (70813, 70819), (70823, 70827), (70829, 70830), (70832, 70833), (70841, 70841), (70843, 70847), (70849, 70853), (70864, 70866), (70870, 70870), (71128, 71133), (71234, 71234), (71424, 71424), (71430, 71430), (71434, 71434), (71438, 71439), (71840, 71840), (71842, 71844), (71846, 71846), (71848, 71849), (71852, 71852), (71854, 71855), (71858, 71858), (71861, 71861), (71863, 71864), (71867, 71868), (71872, 71876), (71878, 71878), (71880, 71880), (71882, 71882), (71884, 71884), (71886, 71886), (71893, 71896), (71900, 71900), (71904, 71904), (71907, 71910), (71913, 71913), (71916, 71916), (71919, 71919), (71922, 71922), (72422, 72426), (72428, 72430), (72436, 72440), (72770, 72770), (72882, 72882), (73784, 73784), (78585, 78585), (93959, 93960), (93962, 93962), (93974, 93974), (93978, 93978), (93980, 93980), (93990, 93990), (93992, 93992), (93997, 93997), (94005, 94005), (94010, 94011), (94013, 94013), (94015, 94016), (94018, 94019), (94033, 94034), (117974, 118009), (119060, 119060), (119149, 119149), (119298, 119298), (119302, 119302), (119307, 119307), (119309, 119309), (119311, 119311), (119314, 119319), (119322, 119324), (119329, 119330), (119338, 119339), (119344, 119344), (119350, 119355), (119359, 119359), (119365, 119365), (119808, 119892), (119894, 119964), (119966, 119967), (119970, 119970), (119973, 119974), (119977, 119980), (119982, 119993), (119995, 119995), (119997, 120003), (120005, 120069), (120071, 120074), (120077, 120084), (120086, 120092), (120094, 120121), (120123, 120126), (120128, 120132), (120134, 120134), (120138, 120144), (120146, 120485), (120488, 120779), (120782, 120831), (125127, 125129), (125131, 125133), (126464, 126467), (126469, 126495), (126497, 126498), (126500, 126500), (126503, 126503), (126505, 126514), (126516, 126519), (126521, 126521), (126523, 126523), (126530, 126530), (126535, 126535), (126537, 126537), (126539, 126539), (126541, 126543), (126545, 126546), (126548, 126548), (126551, 126551), (126553, 126553), (126555, 126555), (126557, 126557), (126559, 126559), (126561, 126562), (126564, 126564), (126567, 126570), (126572, 126578), (126580, 126583), (126585, 126588), (126590, 126590), (126592, 126601), (126603, 126619), (126625, 126627), (126629, 126633), (126635, 126651), (127232, 127242), (127247, 127274), (127341, 127342), (127552, 127560), (127762, 127762), (127768, 127769), (128768, 128770), (128772, 128772), (128775, 128776), (128778, 128778), (128788, 128788), (128808, 128808), (128826, 128826), (128844, 128844), (128852, 128853), (128860, 128860), (128862, 128862), (128872, 128872), (128875, 128876), (128881, 128881), (130032, 130041), (139240, 139240), (194567, 194567)];
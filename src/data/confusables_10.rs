@@ -0,0 +1,8 @@
+// Generated by `cargo xtask` from data/confusables-10.0.0.txt
+
+pub const VERSION: (u8, u8, u8) = (10, 0, 0);
+pub static ENTRIES: [u32; 6294] = [1067451280, 187646819, 1975337316, 74073515, 1045645826, 1966719357, 3188164739, 26903242, 124684101, 210321536, 1056277573, 1540276434, 1971110323, 1979564497, 3192359462, 15188306, 52921676, 87245472, 148408182, 199264999, 702202596, 1050036930, 1060471921, 1093959918, 1964442133, 1969013049, 1973207337, 1977434951, 2074493962, 3190262097, 3194456844, 9453847, 19595626, 37733170, 71648696, 83460417, 94324523, 139247945, 158238640, 197165447, 206505436, 540182963, 905004704, 1047743069, 1052787127, 1058374802, 1062831111, 1069762516, 1177125167, 1963377049, 1965490458, 1967850005, 1970061668, 1972159021, 1974289218, 1976387445, 1978483529, 2072921098, 2085240882, 3189213423, 3191310330, 3193408701, 3195505528, 6684994, 11796736, 17367876, 23216384, 29213043, 45761776, 59852161, 72844741, 75122202, 85475976, 89145938, 111150925, 135381273, 142885595, 153272541, 176358140, 195397967, 198216087, 200313844, 207554073, 215695565, 691831249, 718242333, 1044597099, 1046694782, 1048791244, 1051118103, 1054688455, 1057326011, 1059423387, 1061520426, 1063912424, 1068499978, 1084752154, 1160512781, 1178222885, 1955481479, 1963901241, 1964966254, 1966195245, 1967259951, 1968488857, 1969537552, 1970586201, 1971634501, 1972683133, 1973731789, 1974814558, 1975863154, 1976910667, 1977958788, 1979039994, 2072282163, 2073904035, 2084634835, 2111588236, 3188689084, 3189737758, 3190787222, 3191835120, 3192883204, 3193933001, 3194981166, 3196029905, 4833731, 7487990, 10437171, 13648659, 16532007, 18301791, 21136099, 25576226, 28165210, 33374213, 41993461, 50136376, 55706989, 64898461, 72173092, 73533065, 74597822, 82379091, 84689639, 86329039, 88295154, 91717929, 103596741, 122094149, 134563796, 138543965, 140165503, 144754364, 152586168, 153796683, 166872766, 186237243, 192774966, 196283268, 197691628, 198740566, 199789450, 202162359, 207029898, 209780831, 212877520, 218038472, 690998309, 699843395, 710510035, 719768636, 905594385, 1045121110, 1046170225, 1047218758, 1048266937, 1049512157, 1050593486, 1051642703, 1054164163, 1055212755, 1056801965, 1057850426, 1058899121, 1059947534, 1060996121, 1062044647, 1063355477, 1065092100, 1067975607, 1069024326, 1070842389, 1089752149, 1158368355, 1165364326, 1177616985, 1186860152, 1954857266, 1963114821, 1963639341, 1964163453, 1964704047, 1965228495, 1965883778, 1966457145, 1966981648, 1967571325, 1968128306, 1968751149, 1969275261, 1969799465, 1970323917, 1970848093, 1971372618, 1971896729, 1972420921, 1972945424, 1973469540, 1973994073, 1974552420, 1975075646, 1975599825, 1976123713, 1976648523, 1977172803, 1977697013, 1978221380, 1978745177, 1979302114, 2072003475, 2072576533, 2073543886, 2074215443, 2074870827, 2084978722, 2109768510, 3187902565, 3188426918, 3188951248, 3189475590, 3189999927, 3190524263, 3191048182, 3191572477, 3192097284, 3192621643, 3193145980, 3193670333, 3194193943, 3194718233, 3195242529, 3195767721, 3196291114, 3244316, 6275507, 7144023, 8143146, 9961918, 11190783, 12501775, 14634039, 15712789, 17056155, 17810251, 19137339, 20070714, 22446085, 24199973, 26510108, 27395023, 28591278, 30966994, 36619470, 39093038, 44073753, 49415479, 51266902, 54723940, 56656405, 62047628, 68928930, 71910924, 72484404, 73270887, 73795632, 74335752, 74860013, 75384414, 82837813, 84148861, 85033604, 85885558, 86754108, 87705321, 88671995, 90244878, 94060925, 99141442, 104104640, 120931115, 123503157, 134217728, 134987776, 136970594, 138854753, 139542825, 140427860, 143344379, 146145542, 149358639, 153010200, 153534665, 155207636, 161107068, 175196055, 177194873, 187171651, 189694679, 193807246, 195807923, 196611042, 197429389, 197953838, 198478313, 199002773, 199527238, 200051661, 200576025, 204701879, 206767685, 207291883, 207816350, 210042980, 210583702, 215433447, 217776602, 381683965, 600427385, 691519817, 697516879, 701333791, 703250642, 710772271, 719078136, 904742544, 905332189, 905856575, 1044859350, 1045383867, 1045908308, 1046432592, 1046956174, 1047480593, 1048004742, 1048528976, 1049201550, 1049774637, 1050299343, 1050855786, 1051380353, 1051904977, 1053393799, 1054426308, 1054950603, 1055474905, 1056539843, 1057063830, 1057588244, 1058112611, 1058636966, 1059161177, 1059685555, 1060209727, 1060734127, 1061258248, 1061782515, 1062568882, 1063093282, 1063650421, 1064174708, 1066207121, 1067713427, 1068237789, 1068762149, 1069286538, 1070465405, 1080427015, 1085817804, 1091601136, 1116995763, 1160299780, 1160807698, 1170309706, 1177405244, 1177928213, 1186630766, 1539670341, 1950679646, 1955087065, 1962983721, 1963245924, 1963508173, 1963770457, 1964032349, 1964294579, 1964573262, 1964835145, 1965097374, 1965359671, 1965736285, 1966047684, 1966326361, 1966588253, 1966850483, 1967112778, 1967423826, 1967718844, 1967981134, 1968341347, 1968619981, 1968882265, 1969144157, 1969406387, 1969668682, 1969930565, 1970192793, 1970455085, 1970716985, 1970979197, 1971241488, 1971503401, 1971765604, 1972027853, 1972290137, 1972552029, 1972814259, 1973076554, 1973338437, 1973600665, 1973862957, 1974157700, 1974420292, 1974682441, 1974944089, 1975206217, 1975468750, 1975731020, 1975992612, 1976254809, 1976516953, 1976779598, 1977041241, 1977303877, 1977566026, 1977828085, 1978090306, 1978353508, 1978615646, 1978909137, 1979171054, 1979433170, 1979695354, 2072151099, 2072413211, 2072757277, 2073281565, 2073756763, 2074083793, 2074362876, 2074723263, 2075001809, 2084847643, 2085109802, 2089910423, 2110980389, 3187771481, 3188034689, 3188295825, 3188558001, 3188820166, 3189082332, 3189344508, 3189606375, 3189868844, 3190130667, 3190393182, 3190655354, 3190917515, 3191180001, 3191441868, 3191704036, 3191966884, 3192227840, 3192491180, 3192752732, 3193014899, 3193277074, 3193539245, 3193801419, 3194063102, 3194325759, 3194588366, 3194850085, 3195112250, 3195374441, 3195636624, 3195899103, 3196160999, 3196422188, 2032081, 3934067, 5341939, 6455306, 6881291, 7340497, 7618904, 8945908, 9683544, 10174979, 11059621, 11518833, 12009478, 13108014, 14385943, 15040900, 15516246, 16023938, 16679241, 17236297, 17613357, 18006741, 18678131, 19300675, 19874223, 20267408, 21939880, 22938128, 23921445, 24936454, 26018799, 26673957, 27083276, 27854031, 28411011, 29082580, 29409536, 31425505, 36422653, 37454630, 37962979, 39945462, 42272025, 44369186, 47121189, 49939754, 50644298, 52479221, 54134107, 55330152, 56296806, 58967415, 61310332, 63112594, 67782052, 71287317, 71779834, 72042007, 72336981, 72664643, 73123469, 73401979, 73664153, 73942693, 74204632, 74466887, 74728921, 74991101, 75253298, 76071666, 82577353, 83132769, 83804913, 84296321, 84902530, 85344891, 85672285, 86066886, 86460115, 86884864, 87574244, 88033005, 88540919, 88966910, 90112313, 90490652, 92391143, 94193442, 95781193, 99943808, 103973533, 104417044, 114689874, 121700766, 122308444, 123683370, 133120005, 134348800, 134725638, 135118855, 135743439, 137807066, 138674641, 139084535, 139395915, 140034548, 140296648, 140855104, 143098392, 144032014, 145769034, 147474357, 148701735, 149997465, 152717248, 153141315, 153403861, 153665594, 153927764, 157614859, 158958183, 165462177, 173590329, 176046471, 176783530, 178964268, 186941905, 187351797, 188154709, 190202625, 193609890, 195184770, 195578349, 196102945, 196447150, 196823515, 197298280, 197560493, 197822734, 198084933, 198347194, 198609458, 198871658, 199133901, 199396085, 199658347, 199920567, 200182758, 200444939, 201476022, 203885649, 206374325, 206636552, 206898799, 207160928, 207422985, 207685194, 209094729, 209911922, 210174059, 210452624, 210714749, 213008882, 215564763, 217645285, 217907404, 328188087, 432637974, 563104542, 690424867, 691372341, 691700091, 691978420, 698745175, 700809999, 701497668, 702893109, 704497620, 710641127, 711511105, 718570053, 719325130, 720144220, 904874034, 905201097, 905463287, 905725480, 1044465727, 1044728311, 1044990327, 1045252891, 1045514972, 1045776508, 1046038618, 1046301058, 1046563459, 1046825622, 1047087585, 1047349335, 1047611815, 1047873818, 1048136290, 1048398337, 1048660472, 1049004686, 1049380961, 1049643411, 1049905804, 1050168135, 1050462293, 1050724666, 1050986935, 1051249243, 1051511555, 1051773831, 1052035078, 1053131640, 1053705092, 1054295237, 1054557391, 1054819528, 1055081431, 1055343829, 1055605269, 1056408713, 1056670888, 1056933002, 1057194928, 1057457127, 1057719341, 1057981508, 1058243709, 1058507847, 1058767774, 1059030074, 1059292288, 1059554200, 1059816421, 1060078636, 1060340830, 1060603019, 1060865149, 1061127163, 1061389289, 1061651390, 1061913587, 1062174735, 1062700004, 1062962189, 1063224375, 1063486569, 1063781287, 1064043673, 1064305762, 1065223302, 1066566411, 1067581963, 1067844515, 1068106695, 1068368879, 1068631067, 1068893235, 1069155440, 1069417545, 1070235966, 1070612876, 1071202925, 1084441319, 1085030756, 1086603593, 1091043683, 1093600107, 1095074130, 1148636224, 1160168701, 1160414462, 1160725779, 1161036114, 1165429865, 1177026931, 1177256239, 1177551434, 1177747706, 1178124847, 1186565231, 1186794612, 1287539803, 1539965393, 1540391278, 1954726744, 1954924487, 1955335230, 1955677037, 1963049273, 1963180381, 1963311485, 1963442611, 1963573776, 1963704906, 1963835689, 1963966789, 1964097892, 1964229017, 1964376527, 1964507703, 1964638490, 1964769745, 1964900703, 1965031810, 1965162940, 1965294101, 1965425230, 1965637950, 1965818222, 1965949342, 1966113327, 1966260810, 1966391593, 1966522693, 1966653796, 1966784921, 1966916045, 1967047213, 1967178329, 1967358273, 1967505764, 1967653278, 1967784399, 1967915575, 1968046362, 1968210241, 1968406907, 1968554419, 1968685584, 1968816714, 1968947497, 1969078597, 1969209700, 1969340825, 1969471949, 1969603117, 1969734233, 1969865017, 1969996125, 1970127229, 1970258355, 1970389520, 1970520650, 1970651433, 1970782533, 1970913636, 1971044761, 1971175885, 1971307053, 1971438169, 1971568953, 1971700061, 1971831165, 1971962291, 1972093456, 1972224586, 1972355369, 1972486469, 1972617572, 1972748697, 1972879821, 1973010989, 1973142105, 1973272889, 1973403997, 1973535101, 1973666227, 1973797392, 1973928522, 1974092068, 1974223169, 1974354265, 1974486901, 1974616409, 1974747979, 1974879054, 1975010123, 1975140697, 1975272259, 1975403333, 1975534407, 1975665482, 1975796469, 1975927541, 1976058244, 1976189762, 1976320836, 1976452964, 1976582985, 1976715102, 1976844633, 1976976190, 1977106761, 1977237860, 1977369294, 1977500369, 1977631564, 1977763698, 1977893156, 1978024257, 1978155353, 1978287989, 1978417497, 1978549067, 1978680142, 1978811211, 1978974434, 1979105490, 1979236817, 1979367674, 1979498734, 1979629794, 2050146548, 2072085469, 2072216543, 2072347822, 2072478926, 2072691782, 2072839151, 2073183370, 2073412551, 2073674687, 2073822251, 2073986075, 2074149397, 2074297456, 2074428323, 2074641335, 2074805339, 2074936303, 2084569429, 2084700399, 2084913183, 2085044262, 2085175342, 2089844858, 2093352884, 2109900722, 2111276930, 3187705923, 3187837025, 3187969151, 3188099192, 3188230282, 3188361369, 3188492460, 3188623542, 3188754626, 3188885709, 3189016792, 3189147880, 3189278968, 3189410049, 3189541131, 3189672216, 3189803300, 3189934058, 3190066321, 3190196552, 3190327278, 3190458723, 3190589806, 3190720891, 3190851568, 3190983831, 3191113842, 3191245240, 3191376329, 3191507408, 3191638014, 3191769583, 3191900667, 3192031231, 3192162835, 3192293920, 3192425011, 3192555521, 3192687186, 3192818275, 3192949939, 3193080438, 3193212089, 3193342618, 3193473701, 3193604788, 3193736387, 3193866964, 3193998050, 3194129563, 3194259599, 3194391301, 3194522378, 3194653463, 3194783771, 3194915625, 3195045917, 3195176927, 3195308895, 3195439985, 3195571083, 3195702172, 3195832355, 3195964363, 3196095453, 3196226543, 3196357863, 3196488721, 803281, 2867979, 3523154, 4473261, 5227008, 5554711, 6341019, 6586810, 6783507, 7012364, 7258318, 7422253, 7553314, 7930168, 8356187, 9011491, 9568576, 9814443, 10093004, 10273298, 10748507, 11125313, 11452421, 11633034, 11945204, 12288006, 12845848, 13468464, 13746991, 14517080, 14795373, 15106369, 15286658, 15597913, 15811141, 16187698, 16600124, 16859491, 17154258, 17301853, 17531375, 17728209, 17875542, 18170423, 18613061, 19039066, 19235671, 19431739, 19661187, 20005609, 20136267, 20464356, 21253180, 22233454, 22643243, 23085500, 23724923, 24134425, 24625617, 25330380, 25772555, 26379402, 26608407, 26755537, 26969226, 27149455, 27558869, 27952187, 28279317, 28492937, 28820252, 29148348, 29328477, 30442715, 31081586, 33243929, 36291695, 36537249, 37356727, 37536546, 37798144, 38044894, 39420437, 41517394, 42091805, 43614741, 44287268, 45122794, 46154520, 48399659, 49496857, 50021677, 50234678, 50939215, 51922247, 52725064, 53249364, 54576373, 54936932, 55559486, 56116578, 56460651, 58377583, 59262324, 61031807, 61752711, 62342545, 63423893, 67110302, 68157973, 69698985, 71468575, 71714262, 71845384, 71976467, 72107547, 72238632, 72418865, 72566334, 72779181, 73008756, 73205723, 73336430, 73467525, 73598609, 73729694, 73861180, 74008177, 74139071, 74270185, 74401339, 74532278, 74663378, 74794464, 74925560, 75056661, 75187757, 75318862, 75449779, 78905682, 82477338, 82755897, 83018457, 83263843, 83624654, 84083324, 84214399, 84607809, 84836981, 84968067, 85164909, 85410438, 85573999, 85820019, 85951117, 86196542, 86393151, 86671685, 86820567, 87179930, 87508706, 87639782, 87967467, 88098543, 88475381, 88606457, 88901372, 89032448, 89589520, 90179339, 90425113, 91047785, 92127524, 93029085, 94127903, 94258983, 95437265, 96141568, 99517829, 100712704, 103907995, 104039100, 104285876, 104597295, 112002266, 119523158, 121045802, 121881109, 122225370, 123388486, 123568732, 123929174, 128352662, 133939205, 134283264, 134496429, 134643884, 134840502, 135053318, 135234315, 135561930, 136203320, 137052484, 138461800, 138608953, 138756428, 139002244, 139166127, 139314049, 139461339, 139608525, 140099959, 140230985, 140362317, 140493359, 142608327, 142967175, 143278957, 143524015, 144230275, 145178969, 145834605, 146607164, 148277053, 148522681, 148767179, 149588015, 150946257, 152651708, 152944660, 153075775, 153206802, 153338102, 153469410, 153600054, 153731134, 153862224, 154224488, 157040823, 158091173, 158500668, 160171979, 165298337, 166035629, 167380917, 174475214, 175964418, 176211844, 176717992, 176931410, 178077961, 184223595, 186745662, 187040073, 187253150, 187450191, 187908836, 189546841, 190038481, 192546659, 193446499, 193692559, 193986824, 195299598, 195479967, 195659838, 195988190, 196168520, 196365221, 196529112, 196742149, 196905031, 197232715, 197363831, 197494941, 197626035, 197757186, 197888290, 198019389, 198150514, 198281630, 198412770, 198543916, 198675009, 198806112, 198937209, 199068329, 199199446, 199330543, 199461683, 199592797, 199723895, 199855000, 199986118, 200117209, 200248304, 200379396, 200510482, 201360145, 201754561, 202279073, 204130445, 205439597, 206439875, 206570986, 206702139, 206833253, 206964342, 207095445, 207226304, 207357438, 207488527, 207619635, 207750790, 208947247, 209715287, 209846377, 209977436, 210108527, 210255994, 210387075, 210518162, 210649212, 210780286, 212943084, 215368207, 215499007, 215630311, 217580045, 217710845, 217842150, 217972932, 283559907, 364234931, 419579889, 467028488, 541706678, 588827488, 663800831, 690687010, 691290461, 691454678, 691585377, 691765530, 691914542, 696484019, 698074983, 699139891, 700187412, 701268251, 701399328, 701743640, 702450745, 702991449, 703398180, 710444488, 710575582, 710706702, 710837836, 714443844, 718389805, 718799716, 719226711, 719504132, 719995466, 904676991, 904808405, 904939575, 905135555, 905266639, 905397733, 905528837, 905659937, 905791066, 1044400557, 1044531443, 1044663087, 1044794258, 1044924700, 1045055925, 1045187028, 1045318645, 1045449553, 1045580735, 1045711792, 1045842866, 1045973570, 1046104470, 1046236034, 1046366331, 1046497526, 1046629386, 1046759821, 1046891283, 1047022413, 1047152851, 1047284633, 1047415131, 1047546470, 1047677379, 1047808109, 1047939421, 1048070587, 1048202134, 1048333238, 1048464043, 1048594738, 1048726449, 1048939070, 1049070288, 1049316348, 1049446565, 1049577816, 1049709054, 1049840265, 1049971357, 1050102519, 1050233704, 1050364656, 1050527895, 1050659060, 1050790240, 1050921353, 1051052539, 1051183675, 1051314797, 1051445945, 1051577157, 1051708246, 1051839420, 1051970708, 1052101848, 1052869177, 1053328258, 1053475723, 1054098625, 1054229678, 1054360770, 1054491791, 1054622929, 1054753990, 1054885065, 1055015887, 1055147067, 1055278294, 1055409295, 1055539733, 1055671512, 1056343171, 1056474242, 1056605343, 1056736425, 1056867500, 1056998566, 1057129374, 1057260723, 1057391561, 1057522699, 1057653793, 1057784882, 1057915967, 1058047062, 1058178158, 1058308639, 1058440347, 1058573389, 1058702234, 1058833328, 1058964661, 1059095617, 1059226747, 1059357846, 1059488934, 1059619752, 1059750850, 1059881985, 1060013086, 1060144180, 1060275281, 1060406168, 1060537488, 1060668308, 1060799472, 1060932682, 1061061674, 1061192638, 1061323763, 1061454873, 1061585915, 1061717000, 1061848041, 1061979120, 1062110229, 1062503337, 1062634435, 1062765549, 1062896633, 1063027734, 1063158825, 1063289939, 1063421029, 1063584289, 1063715992, 1063846836, 1063978064, 1064109111, 1064240207, 1064371231, 1065157734, 1065288665, 1066272674, 1066631563, 1067516428, 1067648158, 1067778978, 1067910318, 1068041151, 1068172241, 1068303327, 1068434428, 1068565523, 1068696605, 1068827691, 1068958779, 1069089883, 1069220373, 1069352074, 1069483079, 1070121253, 1070317906, 1070530951, 1070727617, 1070989906, 1077953133, 1084310334, 1084571997, 1084834098, 1085096773, 1086144900, 1086750964, 1090800688, 1091207939, 1091782491, 1093878614, 1094372403, 1095319858, 1143915763, 1157942367, 1160054009, 1160250625, 1160381703, 1160463626, 1160660239, 1160758040, 1160840460, 1161069845, 1165397095, 1167101035, 1170440782, 1177076037, 1177174404, 1177321797, 1177469154, 1177583922, 1177682453, 1177895144, 1178010198, 1178157444, 1178321275, 1186597996, 1186679921, 1186827382, 1194101882, 1539441011, 1539771442, 1540178273, 1540342034, 1540636677, 1954579308, 1954791815, 1954892848, 1954989930, 1955234117, 1955447058, 1955512711, 1962950948, 1963016498, 1963082046, 1963147596, 1963213153, 1963278707, 1963344260, 1963409827, 1963475393, 1963540945, 1963606567, 1963672124, 1963737682, 1963802916, 1963868466, 1963934014, 1963999564, 1964065121, 1964130675, 1964196228, 1964261795, 1964343748, 1964409391, 1964474923, 1964540485, 1964606038, 1964671269, 1964736821, 1964802369, 1964867922, 1964933475, 1964999035, 1965064588, 1965130159, 1965195716, 1965261359, 1965326891, 1965392453, 1965458006, 1965539625, 1965703500, 1965785443, 1965851003, 1965916556, 1965998515, 1966080463, 1966162471, 1966228028, 1966293586, 1966358820, 1966424370, 1966489918, 1966555468, 1966621025, 1966686579, 1966752132, 1966817699, 1966883265, 1966948817, 1967014439, 1967079996, 1967145554, 1967210788, 1967292725, 1967391049, 1967456607, 1967538547, 1967620492, 1967686063, 1967751620, 1967817263, 1967882795, 1967948357, 1968013910, 1968095529, 1968177617, 1968243017, 1968374126, 1968439682, 1968521635, 1968587201, 1968652753, 1968718375, 1968783932, 1968849490, 1968914724, 1968980274, 1969045822, 1969111372, 1969176929, 1969242483, 1969308036, 1969373603, 1969439169, 1969504721, 1969570343, 1969635900, 1969701458, 1969766692, 1969832242, 1969897790, 1969963340, 1970028897, 1970094451, 1970160004, 1970225571, 1970291137, 1970356689, 1970422311, 1970487868, 1970553426, 1970618660, 1970684210, 1970749758, 1970815308, 1970880865, 1970946419, 1971011972, 1971077539, 1971143105, 1971208657, 1971274279, 1971339836, 1971405394, 1971470628, 1971536178, 1971601726, 1971667276, 1971732833, 1971798387, 1971863940, 1971929507, 1971995073, 1972060625, 1972126247, 1972191804, 1972257362, 1972322596, 1972388146, 1972453694, 1972519244, 1972584801, 1972650355, 1972715908, 1972781475, 1972847041, 1972912593, 1972978215, 1973043772, 1973109330, 1973174564, 1973240114, 1973305662, 1973371212, 1973436769, 1973502323, 1973567876, 1973633443, 1973699009, 1973764561, 1973830183, 1973895740, 1973961298, 1974026981, 1974125374, 1974190425, 1974255945, 1974321987, 1974387044, 1974453061, 1974518478, 1974584135, 1974649553, 1974715210, 1974780748, 1974846197, 1974912882, 1974977269, 1975042340, 1975107972, 1975173441, 1975239490, 1975304537, 1975370564, 1975437173, 1975502692, 1975566681, 1975632713, 1975698251, 1975764830, 1975829326, 1975894361, 1975960395, 1976025918, 1976090969, 1976156489, 1976222531, 1976287588, 1976353605, 1976419022, 1976484679, 1976550097, 1976615754, 1976681292, 1976746741, 1976813426, 1976877813, 1976942884, 1977008516, 1977073985, 1977140034, 1977205081, 1977271108, 1977337717, 1977403236, 1977467225, 1977533257, 1977598795, 1977665374, 1977729870, 1977794905, 1977860939, 1977926462, 1977991513, 1978057033, 1978123075, 1978188132, 1978254149, 1978319566, 1978385223, 1978450641, 1978516298, 1978581836, 1978647285, 1978713970, 1978778357, 1978843988, 1978941650, 1979007214, 1979072977, 1979138274, 1979203834, 1979269330, 1979334894, 1979400657, 1979465954, 1979531514, 1979597010, 1979662574, 2050099066, 2050180979, 2072036300, 2072118291, 2072183899, 2072249387, 2072314863, 2072380369, 2072446090, 2072527763, 2072659082, 2072724592, 2072790012, 2072872110, 2073068471, 2073248880, 2073330739, 2073478181, 2073592759, 2073707658, 2073789407, 2073855027, 2073936839, 2074018954, 2074117047, 2074182621, 2074264646, 2074330141, 2074395603, 2074461127, 2074526757, 2074690689, 2074756234, 2074837983, 2074903603, 2074969262, 2075034651, 2084602323, 2084667619, 2084733179, 2084880413, 2084945953, 2085011492, 2085077032, 2085142572, 2085208112, 2089812113, 2089877653, 2089943180, 2109735264, 2109849886, 2110393426, 2111129485, 2111488330, 3187673154, 3187738707, 3187804253, 3187869661, 3187935335, 3188000873, 3188066422, 3188133088, 3188197512, 3188262881, 3188328599, 3188394146, 3188459691, 3188526213, 3188591750, 3188656318, 3188721855, 3188787397, 3188852938, 3188918479, 3188983940, 3189049562, 3189115107, 3189180651, 3189247112, 3189311738, 3189376998, 3189443722, 3189508361, 3189573956, 3189639446, 3189704987, 3189770527, 3189836072, 3189901289, 3189967156, 3190032701, 3190098241, 3190164674, 3190229323, 3190294871, 3190361234, 3190425952, 3190491496, 3190557034, 3190622581, 3190688917, 3190753665, 3190819205, 3190884748, 3190950291, 3191015854, 3191080949, 3191146927, 3191212469, 3191278012, 3191343553, 3191409098, 3191474637, 3191540895, 3191606432, 3191671264, 3191737484, 3191802354, 3191867898, 3191933439, 3191998982, 3192064523, 3192130726, 3192195607, 3192261150, 3192326691, 3192392235, 3192457783, 3192523328, 3192588290, 3192654414, 3192719960, 3192785503, 3192851633, 3192916592, 3192982710, 3193047669, 3193112586, 3193178753, 3193244858, 3193309196, 3193375932, 3193440269, 3193505806, 3193572017, 3193637562, 3193703107, 3193767954, 3193834190, 3193900232, 3193965721, 3194030101, 3194096843, 3194161902, 3194227448, 3194292988, 3194358526, 3194424065, 3194489604, 3194555597, 3194620692, 3194686232, 3194752209, 3194817315, 3194882855, 3194948395, 3195013936, 3195079478, 3195144222, 3195210966, 3195276114, 3195341667, 3195407577, 3195472756, 3195538650, 3195603855, 3195669405, 3195734947, 3195800491, 3195865376, 3195930660, 3195996198, 3196062675, 3196128225, 3196192808, 3196259322, 3196325094, 3196390633, 3196455950, 3196521493, 606922, 1572869, 2654626, 2966345, 3408174, 3768720, 4063773, 4637104, 5013998, 5259592, 5506972, 5865835, 6307849, 6406438, 6504892, 6652369, 6717957, 6816083, 6914401, 7045481, 7176797, 7291447, 7389185, 7455046, 7520590, 7586109, 7651697, 7962935, 8176037, 8831708, 8978821, 9372066, 9535793, 9650742, 9748892, 9863906, 10011222, 10125764, 10207822, 10322470, 10666565, 10813719, 11092393, 11157945, 11419653, 11485189, 11583762, 11698181, 11911341, 11977156, 12093258, 12419078, 12780311, 12927778, 13140777, 13517608, 13681442, 14025497, 14483461, 14582212, 14663998, 14975268, 15073625, 15139145, 15254235, 15483276, 15551548, 15631057, 15778325, 15991502, 16089931, 16401114, 16564685, 16646864, 16763958, 16908606, 17089338, 17203521, 17269074, 17334628, 17482138, 17580264, 17679086, 17777412, 17842590, 17908306, 18073437, 18219469, 18383261, 18645337, 18809703, 19071389, 19170109, 19267799, 19366212, 19562791, 19628418, 19693952, 19906993, 20037946, 20104040, 20169156, 20333278, 20595228, 21185265, 21430651, 22185641, 22332228, 22561358, 22806977, 23003664, 23151125, 23544595, 23823226, 24019737, 24166656, 24511050, 24904183, 24969222, 25412637, 25739788, 25805265, 26199214, 26444563, 26575658, 26641175, 26723514, 26853747, 26936205, 27034124, 27116677, 27280325, 27493328, 27755529, 27919567, 28116180, 28230799, 28345561, 28443783, 28542093, 28639405, 29033241, 29114833, 29180437, 29246655, 29376691, 30409497, 30688160, 31048924, 31212749, 32670161, 33342234, 36193183, 36324572, 36455553, 36586454, 36652170, 37405466, 37504183, 37634868, 37765912, 37848285, 38012133, 38077664, 39125779, 39797015, 41420023, 41631994, 42059034, 42190108, 43173099, 43679988, 44139807, 44320032, 45040872, 45711893, 45843698, 46236966, 47841530, 49022248, 49464641, 49906197, 49988905, 50070841, 50185518, 50364949, 50857298, 51086679, 51447129, 52445976, 52528452, 52757833, 53200209, 54100501, 54543128, 54674730, 54838624, 55050773, 55412028, 55608684, 56001895, 56264041, 56329578, 56542565, 58344816, 58770802, 59065715, 59556637, 60031509, 61261178, 61653790, 62014858, 62128661, 62948761, 63358356, 64652698, 66406564, 67584533, 67994396, 68765094, 69174696, 70894166, 71370179, 71615926, 71681474, 71747045, 71812609, 71878154, 71943696, 72009231, 72074777, 72140317, 72205862, 72271338, 72386094, 72451642, 72517175, 72631901, 72730186, 72811964, 72943210, 73057911, 73156243, 73238114, 73303660, 73369203, 73434750, 73500293, 73565836, 73631382, 73696923, 73762778, 73828406, 73909921, 73975396, 74040953, 74106293, 74171853, 74237411, 74302959, 74368543, 74434116, 74499502, 74565051, 74630597, 74696149, 74761695, 74827243, 74892788, 74958334, 75023875, 75089431, 75155030, 75220534, 75286088, 75351632, 75417007, 75482551, 77071213, 82329953, 82446281, 82510127, 82624891, 82805081, 82887044, 83067099, 83181923, 83345701, 83542361, 83673397, 83886344, 84116090, 84181630, 84263552, 84525677, 84641458, 84787824, 84869752, 84935280, 85000815, 85098501, 85312116, 85377669, 85443207, 85508745, 85608125, 85787274, 85852811, 85918348, 85983886, 86098340, 86296270, 86361808, 86427345, 86492884, 86722261, 86787798, 86853336, 86918873, 87212703, 87312053, 87541475, 87607013, 87672552, 87934698, 88000236, 88065774, 88131312, 88327924, 88508150, 88573688, 88639226, 88867038, 88934141, 88999679, 89065217, 89376518, 89802515, 90146570, 90212108, 90292577, 90457878, 90947993, 91293639, 91997029, 92325581, 92849924, 93127389, 94095113, 94160670, 94226213, 94291753, 95191300, 95617029, 95962932, 96207684, 99485059, 99796361, 100025741, 102057795, 103695047, 103940764, 104006302, 104071870, 104253258, 104384273, 104498980, 108267339, 111822670, 114591568, 114804564, 120881942, 120996652, 121078566, 121815761, 121913877, 122176078, 122274558, 122652486, 123454001, 123535931, 123650508, 123716349, 124241761, 125536208, 130007638, 133152773, 134168581, 134250496, 134316032, 134381568, 134529197, 134610949, 134676486, 134807731, 134873088, 135021260, 135086085, 135168274, 135315629, 135413763, 135643144, 135774208, 136905316, 137019822, 137200126, 138428814, 138510752, 138576316, 138641857, 138707269, 138821981, 138887521, 139051741, 139133220, 139198767, 139281277, 139346372, 139428666, 139496496, 139575727, 140001783, 140067189, 140132733, 140198181, 140263877, 140329547, 140395090, 140460446, 140575467, 141526843, 142705470, 142934183, 143000167, 143213047, 143311609, 143394685, 143917325, 144081164, 144359700, 145064281, 145312813, 145801582, 146112775, 146507727, 146802548, 147867602, 148359051, 148439658, 148570457, 148734360, 148799884, 149456943, 149964699, 150603621, 152207752, 152618938, 152684478, 152911889, 152977430, 153043015, 153108545, 153174085, 153239741, 153305322, 153371120, 153436636, 153502184, 153567284, 153632824, 153698364, 153763913, 153829453, 153894994, 153960534, 154584999, 155436969, 157419384, 157763501, 158189455, 158353294, 158566294, 159647662, 160663426, 162202867, 165396754, 165495391, 166759469, 166936967, 172671570, 173770554, 175081366, 175475796, 175997114, 176180306, 176244616, 176685332, 176750758, 176832689, 177162131, 177963281, 178864403, 180273337, 184256365, 186712890, 186797116, 186991313, 187138386, 187220519, 187302274, 187384653, 187596973, 187859194, 187974407, 188368817, 189661555, 189923959, 190120274, 190317374, 192627512, 193364144, 193578684, 193644414, 193741421, 193939406, 195086405, 195266659, 195348784, 195430772, 195545574, 195611163, 195742407, 195857095, 196070135, 196135748, 196234090, 196332462, 196397991, 196496332, 196561883, 196676595, 196790680, 196856480, 197134292, 197199940, 197265486, 197331056, 197396608, 197462170, 197527719, 197593264, 197658838, 197724400, 197789962, 197855510, 197921062, 197986609, 198052160, 198117710, 198183312, 198248859, 198314412, 198379994, 198445543, 198511084, 198576688, 198642229, 198707795, 198773337, 198838888, 198904431, 198969988, 199035551, 199101124, 199166674, 199232221, 199297771, 199363314, 199428896, 199494454, 199560010, 199625569, 199691119, 199756674, 199822229, 199887784, 199953341, 200018888, 200084432, 200149987, 200215533, 200281074, 200346624, 200412167, 200477709, 200543253, 200608798, 201441618, 201654287, 202048283, 202246303, 203850524, 204015695, 204605607, 204867539, 206341547, 206407103, 206472654, 206538216, 206603775, 206669343, 206734913, 206800461, 206866026, 206931572, 206997121, 207062671, 207128539, 207193528, 207259091, 207324662, 207390208, 207455760, 207521303, 207586853, 207652402, 207717982, 207783578, 207849125, 208994695, 209176645, 209748059, 209813603, 209879150, 209944664, 210010208, 210075754, 210141299, 210206824, 210288775, 210354303, 210419860, 210485384, 210550937, 210616474, 210681993, 210747524, 210813085, 212910304, 212975864, 213041623, 215400666, 215466227, 215532028, 215597537, 215663085, 215728321, 217612504, 217678065, 217743866, 217809376, 217874924, 217940160, 218005702, 218071254, 327157716, 336529499, 372951254, 395577336, 429541797, 444221436, 526224056, 540903415, 543803833, 580864843, 595495794, 619416481, 670091271, 690605100, 690916394, 691047465, 691323177, 691421502, 691487026, 691552581, 691634547, 691732866, 691798319, 691863918, 691945644, 692027566, 697385690, 697582349, 698319626, 698780754, 699613960, 700104983, 700255284, 701121087, 701301021, 701366560, 701432097, 701678108, 702038608, 702399037, 702742533, 702955955, 703040558, 703365438, 703433777, 710411718, 710477262, 710542808, 710608355, 710673907, 710739477, 710805056, 710870623, 712230075, 718094771, 718291681, 718488313, 718619469, 718848583, 719127067, 719259481, 719372878, 719586033, 719831764, 720077575, 720290332, 904709764, 904775319, 904841175, 904906804, 904972346, 905102777, 905168327, 905233867, 905299415, 905364972, 905430514, 905496066, 905561610, 905627166, 905692709, 905758295, 905823804, 905889355, 1044433766, 1044499486, 1044564167, 1044630220, 1044695945, 1044761159, 1044826184, 1044892445, 1044957977, 1045023538, 1045088829, 1045153939, 1045219950, 1045285747, 1045351038, 1045416863, 1045481790, 1045547648, 1045612819, 1045678775, 1045744352, 1045809852, 1045875064, 1045940487, 1046005915, 1046071341, 1046137007, 1046203213, 1046268284, 1046333517, 1046399664, 1046464661, 1046530461, 1046596586, 1046661547, 1046727013, 1046792740, 1046858458, 1046924192, 1046989345, 1047054674, 1047120447, 1047185697, 1047251663, 1047317442, 1047382932, 1047447648, 1047513553, 1047579528, 1047644300, 1047710217, 1047775925, 1047841715, 1047907195, 1047972418, 1048037653, 1048103413, 1048169273, 1048234142, 1048300113, 1048366083, 1048431263, 1048496671, 1048561799, 1048627886, 1048693629, 1048758585, 1048873382, 1048971910, 1049037765, 1049135955, 1049283551, 1049349048, 1049413777, 1049479375, 1049544980, 1049610596, 1049676200, 1049741840, 1049807487, 1049873034, 1049938577, 1050004158, 1050069716, 1050135313, 1050200918, 1050266502, 1050331478, 1050429561, 1050495087, 1050560708, 1050626276, 1050691840, 1050757453, 1050823012, 1050888580, 1050954150, 1051019742, 1051085324, 1051150967, 1051216462, 1051282021, 1051347573, 1051413156, 1051478725, 1051544365, 1051609934, 1051675476, 1051741032, 1051806619, 1051872202, 1051937775, 1052003485, 1052069047, 1052134428, 1052819893, 1053098868, 1053295496, 1053361029, 1053426572, 1053541246, 1054065535, 1054131395, 1054196910, 1054262469, 1054328002, 1054393540, 1054459023, 1054524623, 1054590161, 1054655687, 1054721222, 1054786760, 1054852297, 1054917836, 1054983120, 1055048668, 1055114299, 1055179987, 1055245526, 1055311061, 1055376522, 1055442063, 1055506965, 1055572501, 1055638666, 1056244805, 1056310404, 1056375943, 1056441559, 1056507075, 1056572554, 1056638112, 1056703658, 1056769195, 1056834733, 1056900234, 1056965793, 1057031340, 1057096604, 1057162152, 1057227702, 1057293493, 1057358786, 1057424354, 1057489918, 1057555470, 1057621020, 1057686567, 1057752112, 1057817654, 1057883197, 1057948737, 1058014289, 1058079833, 1058145388, 1058210935, 1058276480, 1058341410, 1058407575, 1058472923, 1058540617, 1058604196, 1058669740, 1058735004, 1058800558, 1058866102, 1058931891, 1058997298, 1059062844, 1059128388, 1059193948, 1059259517, 1059325085, 1059390618, 1059456162, 1059521429, 1059586964, 1059652528, 1059718075, 1059783626, 1059849194, 1059914763, 1059980308, 1060045862, 1060111406, 1060176957, 1060242497, 1060308054, 1060373608, 1060439162, 1060504095, 1060570260, 1060635808, 1060701092, 1060766688, 1060832321, 1060897943, 1060965454, 1061028900, 1061094382, 1061159876, 1061225419, 1061291026, 1061356536, 1061422096, 1061487652, 1061553134, 1061618628, 1061684171, 1061749778, 1061815288, 1061880848, 1061946360, 1062011874, 1062077427, 1062142466, 1062470566, 1062536108, 1062601660, 1062667238, 1062732780, 1062798335, 1062863860, 1062929402, 1062994959, 1063060504, 1063126051, 1063191599, 1063257170, 1063322702, 1063388247, 1063453791, 1063552096, 1063617657, 1063683198, 1063748505, 1063814061, 1063879609, 1063945205, 1064010899, 1064076345, 1064141859, 1064207439, 1064272825, 1064338327, 1064403944, 1065124362, 1065190360, 1065255941, 1066139904, 1066239904, 1066305444, 1066599179, 1066795181, 1067484050, 1067549832, 1067615390, 1067680209, 1067746195, 1067811747, 1067877550, 1067942839, 1068008383, 1068073927, 1068139468, 1068205011, 1068270559, 1068336111, 1068401660, 1068467210, 1068532755, 1068598299, 1068663837, 1068729381, 1068794923, 1068860467, 1068926011, 1068991558, 1069057115, 1069122672, 1069187605, 1069253761, 1069319306, 1069384780, 1069450312, 1069580294, 1070088474, 1070203193, 1070285129, 1070383459, 1070498180, 1070566470, 1070678447, 1070760397, 1070907959, 1071056808, 1073349073, 1080394113, 1080559564, 1084342578, 1084522834, 1084604772, 1084784933, 1084965715, 1085064004, 1085147085, 1085932563, 1086357797, 1086669156, 1086849489, 1090536153, 1090961733, 1091126104, 1091518878, 1091699255, 1093403367, 1093714689, 1093928767, 1094107976, 1094599502, 1095172389, 1095352701, 1128172638, 1148355365, 1148684161, 1158220897, 1158876261, 1160119547, 1160217855, 1160283395, 1160365318, 1160398085, 1160430856, 1160480011, 1160529166, 1160709392, 1160742161, 1160791285, 1160823589, 1160856143, 1161053460, 1161135382, 1165380710, 1165413480, 1165446250, 1170211375, 1170375246, 1170457166, 1177059634, 1177092482, 1177159541, 1177223418, 1177272539, 1177370962, 1177420142, 1177485668, 1177567799, 1177600452, 1177649390, 1177714898, 1177783356, 1177911546, 1177944645, 1178075474, 1178141887, 1178173819, 1178272125, 1178370341, 1186581616, 1186614381, 1186663539, 1186696306, 1186810997, 1186843767, 1192266873, 1208880215, 1539425082, 1539473764, 1539736382, 1539935280, 1540047577, 1540260195, 1540309735, 1540358426, 1540407682, 1540653061, 1952137395, 1954644178, 1954759027, 1954840814, 1954876499, 1954906465, 1954971993, 1955007533, 1955103589, 1955253296, 1955430660, 1955465094, 1955496119, 1955579702, 1962934554, 1962967333, 1963000111, 1963032885, 1963065809, 1963098433, 1963131209, 1963163986, 1963196767, 1963229539, 1963262318, 1963295099, 1963327874, 1963360652, 1963393438, 1963426223, 1963459004, 1963491780, 1963524559, 1963557423, 1963590165, 1963622955, 1963655735, 1963688517, 1963721294, 1963754070, 1963786522, 1963819301, 1963852079, 1963884853, 1963917777, 1963950401, 1963983177, 1964015954, 1964048735, 1964081507, 1964114286, 1964147067, 1964179842, 1964212620, 1964245406, 1964278191, 1964310972, 1964360141, 1964392913, 1964425744, 1964458535, 1964491309, 1964524092, 1964556874, 1964589650, 1964622425, 1964654884, 1964687657, 1964720434, 1964753209, 1964785982, 1964818757, 1964851532, 1964884317, 1964917089, 1964949860, 1964982643, 1965015421, 1965048196, 1965080985, 1965113763, 1965146547, 1965179329, 1965212109, 1965244881, 1965277712, 1965310503, 1965343277, 1965376060, 1965408842, 1965441618, 1965474393, 1965523237, 1965588789, 1965654337, 1965719890, 1965752671, 1965801828, 1965834611, 1965867389, 1965900164, 1965932953, 1965965731, 1966031297, 1966064077, 1966096849, 1966129680, 1966178859, 1966211639, 1966244421, 1966277198, 1966309974, 1966342426, 1966375205, 1966407983, 1966440757, 1966473681, 1966506305, 1966539081, 1966571858, 1966604639, 1966637411, 1966670190, 1966702971, 1966735746, 1966768524, 1966801310, 1966834095, 1966866876, 1966899652, 1966932431, 1966965295, 1966998037, 1967030827, 1967063607, 1967096389, 1967129166, 1967161942, 1967194394, 1967243561, 1967276338, 1967341886, 1967374661, 1967407436, 1967440221, 1967489379, 1967522158, 1967554939, 1967587714, 1967636889, 1967669667, 1967702451, 1967735233, 1967768013, 1967800785, 1967833616, 1967866407, 1967899181, 1967931964, 1967964746, 1967997522, 1968030297, 1968062756, 1968111919, 1968144693, 1968193854, 1968226629, 1968275794, 1968357732, 1968390515, 1968423293, 1968472460, 1968505246, 1968538031, 1968570812, 1968603588, 1968636367, 1968669231, 1968701973, 1968734763, 1968767543, 1968800325, 1968833102, 1968865878, 1968898330, 1968931109, 1968963887, 1968996661, 1969029585, 1969062209, 1969094985, 1969127762, 1969160543, 1969193315, 1969226094, 1969258875, 1969291650, 1969324428, 1969357214, 1969389999, 1969422780, 1969455556, 1969488335, 1969521199, 1969553941, 1969586731, 1969619511, 1969652293, 1969685070, 1969717846, 1969750298, 1969783077, 1969815855, 1969848629, 1969881553, 1969914177, 1969946953, 1969979730, 1970012511, 1970045283, 1970078062, 1970110843, 1970143618, 1970176396, 1970209182, 1970241967, 1970274748, 1970307524, 1970340303, 1970373167, 1970405909, 1970438699, 1970471479, 1970504261, 1970537038, 1970569814, 1970602266, 1970635045, 1970667823, 1970700597, 1970733521, 1970766145, 1970798921, 1970831698, 1970864479, 1970897251, 1970930030, 1970962811, 1970995586, 1971028364, 1971061150, 1971093935, 1971126716, 1971159492, 1971192271, 1971225135, 1971257877, 1971290667, 1971323447, 1971356229, 1971389006, 1971421782, 1971454234, 1971487013, 1971519791, 1971552565, 1971585489, 1971618113, 1971650889, 1971683666, 1971716447, 1971749219, 1971781998, 1971814779, 1971847554, 1971880332, 1971913118, 1971945903, 1971978684, 1972011460, 1972044239, 1972077103, 1972109845, 1972142635, 1972175415, 1972208197, 1972240974, 1972273750, 1972306202, 1972338981, 1972371759, 1972404533, 1972437457, 1972470081, 1972502857, 1972535634, 1972568415, 1972601187, 1972633966, 1972666747, 1972699522, 1972732300, 1972765086, 1972797871, 1972830652, 1972863428, 1972896207, 1972929071, 1972961813, 1972994603, 1973027383, 1973060165, 1973092942, 1973125718, 1973158170, 1973190949, 1973223727, 1973256501, 1973289425, 1973322049, 1973354825, 1973387602, 1973420383, 1973453155, 1973485934, 1973518715, 1973551490, 1973584268, 1973617054, 1973649839, 1973682620, 1973715396, 1973748175, 1973781039, 1973813781, 1973846571, 1973879351, 1973912133, 1973944910, 1973977686, 1974010308, 1974075674, 1974108986, 1974141231, 1974174009, 1974206929, 1974239975, 1974272332, 1974305106, 1974337885, 1974371035, 1974403458, 1974436221, 1974469446, 1974501772, 1974534742, 1974570044, 1974600211, 1974632900, 1974666056, 1974698570, 1974731285, 1974764071, 1974796821, 1974829637, 1974862669, 1974895439, 1974930492, 1974960849, 1974993447, 1975025946, 1975059258, 1975091503, 1975124281, 1975157201, 1975190247, 1975222604, 1975255378, 1975288157, 1975321307, 1975353730, 1975386493, 1975419718, 1975452044, 1975485014, 1975520316, 1975550483, 1975583172, 1975616328, 1975648842, 1975681557, 1975714343, 1975747093, 1975779909, 1975812941, 1975845711, 1975880764, 1975911121, 1975943719, 1975976218, 1976009530, 1976041775, 1976074553, 1976107473, 1976140519, 1976172876, 1976205650, 1976238429, 1976271579, 1976304002, 1976336765, 1976369990, 1976402316, 1976435286, 1976470588, 1976500755, 1976533444, 1976566600, 1976599114, 1976631829, 1976664615, 1976697365, 1976730181, 1976763213, 1976795983, 1976831036, 1976861393, 1976893991, 1976926490, 1976959802, 1976992047, 1977024825, 1977057745, 1977090791, 1977123148, 1977155922, 1977188701, 1977221851, 1977254274, 1977287037, 1977320262, 1977352588, 1977385558, 1977420860, 1977451027, 1977483716, 1977516872, 1977549386, 1977582101, 1977614887, 1977647637, 1977680453, 1977713485, 1977746255, 1977781308, 1977811665, 1977844263, 1977876762, 1977910074, 1977942319, 1977975097, 1978008017, 1978041063, 1978073420, 1978106194, 1978138973, 1978172123, 1978204546, 1978237309, 1978270534, 1978302860, 1978335830, 1978371132, 1978401299, 1978433988, 1978467144, 1978499658, 1978532373, 1978565159, 1978597909, 1978630725, 1978663757, 1978696527, 1978731580, 1978761937, 1978794535, 1978827058, 1978892626, 1978925243, 1978958043, 1978990824, 1979023604, 1979056466, 1979089083, 1979121883, 1979154664, 1979187444, 1979220306, 1979252923, 1979285723, 1979318504, 1979351284, 1979384146, 1979416763, 1979449563, 1979482344, 1979515124, 1979547986, 1979580603, 1979613403, 1979646184, 1979678964, 2050081233, 2050114748, 2050164594, 2071986641, 2072019895, 2072069249, 2072101823, 2072134794, 2072167494, 2072200304, 2072232989, 2072265724, 2072298451, 2072331171, 2072363975, 2072396810, 2072429605, 2072462474, 2072495310, 2072544183, 2072626111, 2072675387, 2072708187, 2072740831, 2072773675, 2072806451, 2072855459, 2072888263, 2072953893, 2073150399, 2073216070, 2073265119, 2073314300, 2073363439, 2073445386, 2073511050, 2073576339, 2073625109, 2073691155, 2073723963, 2073773168, 2073805853, 2073838588, 2073887727, 2073920686, 2073969674, 2074002469, 2074051790, 2074100627, 2074133452, 2074166401, 2074198975, 2074231946, 2074281051, 2074313695, 2074346539, 2074379315, 2074412015, 2074444974, 2074477521, 2074510363, 2074624915, 2074657740, 2074706909, 2074739731, 2074788934, 2074821744, 2074854429, 2074887164, 2074919891, 2074952611, 2074985415, 2075018250, 2075051045, 2084585812, 2084618428, 2084651228, 2084684009, 2084716789, 2084831258, 2084864028, 2084896798, 2084929568, 2084962366, 2084995107, 2085027877, 2085060647, 2085093417, 2085126187, 2085158957, 2085191727, 2085224497, 2085257259, 2089828472, 2089861258, 2089894027, 2089926786, 2093254579, 2093369267, 2109754418, 2109803603, 2109866362, 2110062937, 2110687172, 2111113020, 2111242808, 2111439204, 2111504756, 2281310133, 3187689536, 3187723390, 3187755096, 3187787860, 3187820638, 3187853407, 3187887234, 3187918950, 3187951726, 3187984350, 3188017265, 3188050037, 3188082769, 3188115577, 3188148352, 3188180960, 3188213897, 3188246667, 3188279440, 3188312212, 3188344984, 3188377755, 3188410533, 3188443305, 3188476076, 3188508844, 3188541980, 3188574386, 3188607157, 3188639927, 3188672698, 3188705469, 3188738241, 3188771011, 3188803782, 3188836552, 3188869323, 3188902100, 3188934869, 3188967634, 3189000423, 3189033177, 3189065943, 3189098715, 3189132423, 3189164265, 3189197038, 3189229813, 3189263497, 3189295353, 3189328119, 3189360613, 3189393663, 3189426433, 3189459205, 3189491975, 3189525643, 3189557516, 3189590286, 3189623058, 3189655833, 3189689485, 3189722254, 3189754141, 3189786915, 3189819368, 3189852459, 3189885229, 3189918863, 3189950771, 3189983541, 3190017258, 3190049937, 3190082285, 3190114625, 3190148248, 3190180166, 3190212588, 3190245712, 3190278483, 3190310895, 3190344024, 3190376793, 3190409567, 3190442335, 3190475108, 3190507878, 3190540649, 3190573419, 3190606191, 3190638966, 3190671742, 3190704509, 3190737279, 3190770054, 3190802824, 3190835587, 3190868362, 3190901134, 3190933490, 3190966677, 3190999457, 3191032228, 3191065000, 3191097332, 3191130227, 3191163305, 3191195668, 3191228855, 3191261630, 3191295132, 3191327172, 3191359943, 3191393438, 3191425478, 3191457787, 3191491023, 3191523795, 3191556565, 3191589336, 3191622105, 3191654876, 3191687651, 3191721121, 3191753890, 3191786659, 3191818740, 3191851510, 3191884286, 3191917052, 3191949824, 3191982585, 3192015367, 3192048140, 3192081573, 3192113682, 3192147111, 3192179224, 3192211990, 3192244761, 3192277533, 3192310912, 3192343720, 3192376490, 3192408622, 3192442027, 3192474168, 3192507565, 3192539716, 3192572485, 3192604674, 3192638028, 3192670799, 3192702979, 3192736942, 3192769711, 3192802448, 3192835248, 3192868018, 3192899589, 3192933556, 3192966325, 3192998514, 3193031283, 3193063431, 3193096201, 3193130168, 3193162366, 3193194507, 3193227915, 3193261243, 3193293463, 3193326233, 3193359003, 3193392317, 3193424545, 3193457854, 3193490086, 3193523391, 3193554959, 3193588399, 3193621697, 3193653264, 3193686719, 3193718801, 3193752772, 3193785541, 3193818310, 3193850577, 3193883847, 3193916121, 3193948891, 3193980947, 3194014436, 3194047205, 3194080458, 3194113178, 3194145517, 3194178433, 3194211065, 3194243834, 3194276603, 3194309373, 3194342604, 3194374912, 3194407686, 3194440455, 3194473233, 3194505993, 3194538763, 3194571983, 3194603544, 3194637077, 3194670291, 3194703056, 3194734618, 3194768594, 3194800930, 3194833700, 3194866472, 3194899242, 3194932013, 3194964780, 3194996764, 3195030321, 3195063093, 3195096276, 3195128635, 3195161406, 3195194581, 3195226144, 3195259724, 3195292506, 3195325655, 3195358052, 3195390829, 3195423875, 3195456368, 3195489412, 3195521916, 3195555035, 3195587469, 3195620572, 3195653018, 3195685790, 3195718562, 3195751645, 3195783202, 3195817182, 3195849658, 3195882432, 3195915489, 3195947045, 3195981026, 3196013795, 3196046289, 3196079332, 3196110887, 3196144613, 3196177385, 3196210405, 3196242934, 3196274729, 3196308475, 3196340267, 3196374248, 3196406790, 3196439564, 3196472335, 3196505107, 3196538091, 557062, 786770, 1196497, 1786415, 2621440, 2703747, 2949125, 3014828, 3260711, 3508124, 3539290, 3785121, 4031394, 4456750, 4620592, 4817212, 4997572, 5030343, 5243392, 5276168, 5390350, 5538134, 5817052, 5882436, 6291869, 6324635, 6357401, 6439214, 6488490, 6570292, 6603062, 6668805, 6701520, 6766929, 6799705, 6832662, 6898217, 6930619, 7029314, 7127053, 7160198, 7192786, 7274722, 7324368, 7356919, 7405867, 7438758, 7471431, 7504205, 7537169, 7569813, 7602634, 7635482, 7668296, 7946688, 7979453, 8159532, 8208831, 8814952, 8847570, 8962292, 8995418, 9027991, 9404780, 9503090, 9552306, 9585102, 9666947, 9716108, 9798060, 9847518, 9882684, 9978300, 10060226, 10109380, 10142212, 10191361, 10240560, 10306076, 10420788, 10519098, 10732118, 10781412, 11010604, 11076008, 11108926, 11141696, 11174397, 11323209, 11436038, 11468805, 11501573, 11567364, 11600266, 11665413, 11714565, 11846513, 11928330, 11960935, 11993696, 12076872, 12124937, 12386309, 12452096, 12665622, 12797120, 12862228, 12960953, 13124392, 13222696, 13484849, 13632274, 13665045, 13714217, 14009138, 14254876, 14419913, 14500622, 14534491, 14598891, 14647555, 14745605, 14958874, 15024431, 15057209, 15090129, 15123175, 15155532, 15221085, 15270244, 15319421, 15499982, 15533924, 15581715, 15614404, 15680074, 15745575, 15796062, 15827701, 16007513, 16073461, 16171852, 16384187, 16515793, 16548254, 16580953, 16630477, 16662821, 16728790, 16845883, 16875985, 17039642, 17072420, 17121583, 17187673, 17220327, 17252665, 17285955, 17318181, 17351042, 17383805, 17498521, 17564044, 17597186, 17645999, 17696603, 17761023, 17793557, 17826343, 17860446, 17892085, 17990412, 18023124, 18156604, 18186692, 18268611, 18367054, 18399645, 18629454, 18661916, 18694730, 18825809, 19055456, 19087773, 19153454, 19186230, 19252062, 19284719, 19317458, 19382995, 19448582, 19579297, 19613535, 19645014, 19677784, 19841473, 19891050, 19923409, 20022114, 20054789, 20087557, 20120425, 20153088, 20250908, 20316888, 20447442, 20578649, 20988323, 21168437, 21234393, 21414443, 21447246, 22087334, 22218407, 22266211, 22364498, 22495237, 22594091, 22775652, 22889189, 22971122, 23020101, 23134643, 23184242, 23528211, 23659388, 23806841, 23839516, 24003353, 24117713, 24150809, 24183577, 24461777, 24526853, 24658453, 24920530, 24952837, 25313995, 25378988, 25559827, 25593017, 25756808, 25789598, 25871534, 26166412, 26329621, 26395423, 26460962, 26526485, 26592033, 26624790, 26657556, 26690339, 26738867, 26821141, 26870503, 26919084, 26951843, 26985678, 27050507, 27100296, 27133086, 27231406, 27345862, 27444426, 27542487, 27657180, 27771930, 27903019, 27935803, 28001349, 28132427, 28214410, 28263598, 28328469, 28361634, 28427396, 28460162, 28509322, 28574915, 28607626, 28656149, 28967704, 29066189, 29098163, 29131963, 29164733, 29197502, 29229799, 29311889, 29344281, 29393075, 29425920, 30425881, 30524179, 30770317, 30983237, 31065203, 31130586, 31228869, 32506194, 33211158, 33260308, 33357829, 33456523, 36242640, 36308124, 36406222, 36439251, 36471774, 36553910, 36603022, 36635854, 37307575, 37389081, 37438245, 37487391, 37520568, 37618482, 37651253, 37749555, 37782297, 37815521, 37881062, 37979364, 38028514, 38061279, 38732581, 39109394, 39404782, 39437503, 39863064, 40829733, 41436407, 41582836, 41976601, 42042647, 42075419, 42108190, 42206488, 42926885, 43205868, 43630842, 44057368, 44089600, 44254499, 44303653, 44352801, 45024037, 45106409, 45303020, 45745391, 45778161, 45876467, 46186740, 46661970, 47808850, 48268060, 48694575, 49251624, 49448255, 49481024, 49661242, 49923376, 49972529, 50005298, 50038055, 50103603, 50152770, 50201917, 50332440, 50382069, 50660681, 50890061, 51070288, 51234133, 51414360, 51905862, 52003349, 52462101, 52512067, 52544837, 52741450, 52888907, 53003598, 53232979, 54019421, 54117722, 54248796, 54559253, 54658398, 54691116, 54789471, 54855009, 54986031, 55100725, 55379259, 55477556, 55575870, 55625068, 55805172, 56082980, 56197653, 56280419, 56312058, 56345957, 56509796, 56558953, 56673525, 58361198, 58443121, 58901878, 59049331, 59098488, 59344254, 59786632, 59982620, 60949877, 61244793, 61293947, 61326717, 61736326, 61998473, 62031243, 62079772, 62326160, 62915995, 62965144, 63145363, 63407511, 63816487, 64865692, 66291094, 66422947, 67371557, 67618209, 67798437, 68027637, 68339111, 68781475, 68945312, 69223839, 69733434, 71090916, 71321003, 71435782, 71517759, 71632311, 71665082, 71697874, 71730666, 71763446, 71796224, 71828994, 71861769, 71894539, 71927309, 71960081, 71992852, 72025607, 72058392, 72091162, 72123932, 72156707, 72189477, 72222247, 72255017, 72304212, 72369708, 72402480, 72435250, 72468019, 72500789, 72533560, 72599132, 72648258, 72713800, 72746579, 72795579, 72828351, 72910437, 72975983, 73041526, 73074305, 73139855, 73189332, 73222191, 73254499, 73287272, 73320045, 73352818, 73385594, 73418365, 73451136, 73483910, 73516680, 73549451, 73582222, 73614994, 73647768, 73680538, 73713309, 73746393, 73779164, 73812017, 73844793, 73877563, 73926307, 73959076, 73991785, 74024568, 74057130, 74089904, 74122684, 74155457, 74188238, 74221020, 74253800, 74286570, 74319359, 74352150, 74384939, 74417729, 74450501, 74483277, 74515889, 74548664, 74581440, 74614210, 74646992, 74679763, 74712534, 74745306, 74778082, 74810852, 74843623, 74876400, 74909174, 74941945, 74974716, 75007482, 75040276, 75073039, 75105817, 75138588, 75171372, 75204147, 75236953, 75269690, 75302474, 75335247, 75368017, 75400620, 75433394, 75466164, 75497838, 76546884, 77923187, 82313513, 82346340, 82395588, 82461058, 82493758, 82542871, 82592570, 82690377, 82788738, 82821852, 82870721, 82953061, 83034482, 83083673, 83165563, 83247475, 83329349, 83394909, 83476899, 83591400, 83657154, 83689764, 83821314, 83936062, 84100779, 84132671, 84166317, 84199086, 84231856, 84281009, 84313779, 84592300, 84625071, 84656499, 84721938, 84803844, 84853112, 84886248, 84919991, 84951318, 84985528, 85016839, 85051066, 85131630, 85262982, 85328240, 85361519, 85395131, 85427903, 85460673, 85493444, 85526213, 85590894, 85624515, 85705123, 85804743, 85836328, 85870280, 85901735, 85935817, 85968587, 86001356, 86081886, 86116042, 86278799, 86311568, 86344337, 86377073, 86409874, 86442643, 86475412, 86590266, 86704789, 86737529, 86770326, 86803095, 86835864, 86868594, 86901401, 87015611, 87197402, 87230175, 87262944, 87491233, 87524002, 87556771, 87589540, 87622309, 87655078, 87687847, 87917224, 87949993, 87982762, 88015531, 88048300, 88081069, 88113838, 88278769, 88311539, 88457903, 88490672, 88523441, 88556210, 88588979, 88621748, 88654517, 88851054, 88883894, 88916663, 88949432, 88982201, 89014970, 89047739, 89130678, 89359042, 89572035, 89785028, 90031972, 90128978, 90162951, 90195720, 90228493, 90261263, 90408728, 90441498, 90474267, 90507037, 91029810, 91081790, 91308314, 91914537, 92012873, 92309202, 92341985, 92833538, 92866309, 93045574, 93143878, 94077522, 94111511, 94144288, 94177057, 94209827, 94242598, 94275368, 94308138, 94371840, 95273341, 95455029, 95764801, 95814469, 96125549, 96157860, 97337527, 99468674, 99501444, 99713820, 99926812, 99960206, 100042127, 100811008, 103122756, 103645894, 103842504, 103925467, 103958236, 103991005, 104023774, 104055485, 104088255, 104121025, 104269639, 104335107, 104400658, 104466197, 104547174, 104630064, 108283724, 111413069, 111855439, 112050985, 114607953, 114755411, 118425429, 120849172, 120897542, 120980269, 121013024, 121062181, 121324324, 121766638, 121848575, 121897707, 121963231, 122159690, 122192473, 122257965, 122291019, 122340193, 122979017, 123437499, 123470356, 123519734, 123552323, 123602784, 123666892, 123699785, 123781564, 123994858, 124290914, 124700465, 126929989, 128401843, 132072272, 133136836, 133169760, 134055594, 134184965, 134234112, 134266880, 134299648, 134332416, 134365184, 134480045, 134512813, 134547412, 134578679, 134627333, 134660101, 134692870, 134775405, 134824117, 134857325, 134889472, 135004875, 135036933, 135069703, 135102470, 135151876, 135200770, 135282871, 135332023, 135397656, 135495843, 135578208, 135692544, 135759822, 136053449, 136855848, 136921650, 136987004, 137038907, 137068909, 137315184, 138412429, 138445093, 138494367, 138527449, 138560105, 138592569, 138625337, 138658243, 138691025, 138723793, 138772815, 138838367, 138871137, 138953062, 139035462, 139067780, 139100481, 139149605, 139182511, 139215154, 139264533, 139297664, 139330434, 139378995, 139412054, 139445059, 139480111, 139512955, 139559331, 139592132, 139985361, 140018169, 140050803, 140083574, 140116469, 140149118, 140181829, 140214569, 140247492, 140280262, 140313162, 140345932, 140378569, 140411475, 140444113, 140476835, 140559062, 140789559, 141379439, 141543224, 142656215, 142852931, 142901421, 142950583, 142983331, 143016557, 143180241, 143262282, 143294830, 143327994, 143378300, 143491328, 143589984, 143933711, 144064779, 144146704, 144343301, 144737986, 145050706, 145113857, 145293668, 145753979, 145785709, 145819452, 145884990, 146129520, 146161941, 146522743, 146784559, 147408788, 147490742, 148259648, 148308587, 148390247, 148424592, 148505186, 148555639, 148685252, 148718415, 148753469, 148783953, 148883253, 149407791, 149506095, 149653551, 149981082, 150046621, 150931366, 150964148, 152569783, 152602553, 152635323, 152668093, 152700863, 152895550, 152928275, 152961045, 152993815, 153026585, 153059400, 153092160, 153124930, 153157700, 153190470, 153223636, 153256148, 153288932, 153321712, 153354492, 153387512, 153420249, 153453023, 153485797, 153518571, 153550899, 153583669, 153616439, 153649209, 153681979, 153714749, 153747534, 153780298, 153813068, 153845839, 153878609, 153911379, 153944149, 154010213, 154255974, 155191252, 155240360, 155764650, 157073789, 157550507, 157665196, 157812600, 158122814, 158205872, 158288979, 158451516, 158515815, 158861202, 159531777, 159992923, 160303006, 160991036, 161123453, 165281807, 165380356, 165445647, 165479006, 166019236, 166052559, 166855049, 166903991, 167133540, 167397302, 172687954, 173623153, 173786992, 174851992, 175179730, 175228821, 175523779, 175980935, 176029879, 176161537, 176195466, 176228229, 176261001, 176637758, 176703291, 176734373, 176767145, 176799915, 176849074, 176947797, 177178565, 177898081, 178061569, 178094346, 178947885, 179685056, 180306104, 184239980, 184272750, 186270019, 186729005, 186780731, 186876217, 186974529, 187007816, 187072844, 187154965, 187203933, 187236645, 187269476, 187335492, 187367805, 187401029, 187498759, 187630427, 187793591, 187891922, 187957573, 187990248, 188285778, 188629384, 189645543, 189677871, 189808986, 189940443, 190070785, 190136671, 190267773, 190367553, 192578332, 192660281, 192791351, 193430113, 193562306, 193593360, 193628031, 193660800, 193725031, 193758093, 193873764, 193970796, 195070022, 195119183, 195217378, 195283215, 195332391, 195365187, 195413997, 195447186, 195529183, 195561962, 195594734, 195627565, 195693114, 195775109, 195840712, 195971795, 196053751, 196086519, 196119351, 196152131, 196217691, 196266879, 196299652, 196348836, 196381606, 196414380, 196479940, 196512726, 196545498, 196594652, 196643819, 196692984, 196758536, 196807704, 196840474, 196873245, 196922399, 197150767, 197181623, 197216329, 197249100, 197281890, 197314668, 197347444, 197380223, 197412993, 197445782, 197478556, 197511327, 197544104, 197576878, 197609651, 197642454, 197675242, 197708013, 197740790, 197773571, 197806349, 197839120, 197871895, 197904677, 197937449, 197970224, 198003004, 198035775, 198068546, 198101321, 198134125, 198166899, 198199697, 198232473, 198265244, 198298018, 198330800, 198363607, 198396382, 198429157, 198461928, 198494699, 198527514, 198560303, 198593073, 198625844, 198658617, 198691395, 198724180, 198756951, 198789722, 198822497, 198855273, 198888043, 198920824, 198953594, 198986388, 199019164, 199051938, 199084722, 199117510, 199150288, 199183061, 199215831, 199248607, 199281385, 199314156, 199346929, 199379699, 199412470, 199445286, 199478068, 199510850, 199543625, 199576412, 199609183, 199641954, 199674732, 199707510, 199740288, 199773059, 199805841, 199838614, 199871397, 199904173, 199936953, 199969731, 200002503, 200035273, 200068046, 200100823, 200133595, 200166372, 200199148, 200231918, 200264689, 200297459, 200330233, 200363009, 200395782, 200428553, 200461324, 200494096, 200526868, 200559639, 200592411, 200625184, 201375750, 201459637, 201623398, 201670817, 201770946, 202064679, 202213222, 202262688, 202622901, 203869264, 203948296, 204081445, 204458221, 204654667, 204720236, 205211827, 206325162, 206357936, 206390716, 206423489, 206456269, 206489048, 206521827, 206554601, 206587375, 206620166, 206652950, 206685739, 206718527, 206751300, 206784071, 206816865, 206849638, 206882411, 206915184, 206947957, 206980727, 207013506, 207046285, 207079059, 207112148, 207145007, 207177143, 207209915, 207242704, 207275487, 207308269, 207341048, 207373818, 207406594, 207439370, 207472147, 207504903, 207537688, 207570460, 207603286, 207636057, 207668794, 207701587, 207734405, 207767177, 207799963, 207832735, 208930772, 208978103, 209029190, 209110965, 209193028, 209731673, 209764445, 209797217, 209829989, 209862764, 209895536, 209928308, 209961050, 209993822, 210026594, 210059366, 210092141, 210124913, 210157685, 210190439, 210239606, 210272376, 210305147, 210337911, 210370681, 210403470, 210436243, 210469024, 210501773, 210534543, 210567301, 210600094, 210632833, 210665606, 210698392, 210731167, 210763931, 210796700, 212861454, 212893913, 212926694, 212959474, 212992254, 213025275, 215351644, 215384273, 215417057, 215449837, 215482617, 215515635, 215548376, 215581150, 215613924, 215646698, 215679179, 215711935, 215744707, 217596111, 217628895, 217661675, 217694455, 217727473, 217760214, 217792989, 217825763, 217858537, 217891018, 217923774, 217956546, 217989317, 218022087, 218054869, 242010084, 285739001, 328040839, 335579220, 358074563, 370854112, 373278946, 395086122, 411961714, 429443080, 431851507, 436193727, 445237710, 504613499, 539593137, 540281266, 541084084, 542132963, 558877458, 579307551, 585354073, 593595246, 598133621, 607619980, 632163256, 666143755, 690293799, 690457633, 690621476, 690752555, 690981926, 691014696, 691274020, 691306915, 691339620, 691388737, 691437861, 691470724, 691505001, 691536204, 691568995, 691618535, 691650873, 691716477, 691750706, 691783623, 691815127, 691847506, 691880813, 691929267, 691962037, 692011264, 692044040, 697368763, 697418180, 697566044, 697959169, 698286871, 698696130, 698761752, 698893144, 699156425, 699630311, 699908467, 700170427, 700203798, 700777232, 701104485, 701252151, 701284751, 701317521, 701350290, 701383059, 701415827, 701448596, 701661529, 701727063, 701923515, 702185682, 702251258, 702414852, 702693632, 702792301, 702939442, 702975064, 703021827, 703054405, 703351853, 703381885, 703414990, 703447887, 705430765, 710428103, 710460874, 710493647, 710526420, 710559196, 710591968, 710624742, 710657520, 710690296, 710723090, 710755874, 710788665, 710821446, 710854226, 711231430, 712084546, 714394691, 718045615, 718225941, 718258925, 718308064, 718406189, 718504517, 718586701, 718701142, 718832364, 719062872, 719095646, 719143364, 719243098, 719291110, 719340077, 719438591, 719553052, 719635033, 719782473, 719880952, 720011831, 720093598, 720208593, 904660604, 904693379, 904726151, 904758932, 904791708, 904824790, 904857560, 904890419, 904923189, 904955960, 904988733, 905021090, 905119165, 905151940, 905184712, 905217482, 905250252, 905283025, 905315803, 905348577, 905381358, 905414129, 905446901, 905479675, 905512452, 905545222, 905578000, 905610779, 905643552, 905676330, 905709094, 905741802, 905774680, 905807451, 905840189, 905872969, 1044384606, 1044417399, 1044449805, 1044482228, 1044515870, 1044548504, 1044580594, 1044613735, 1044646687, 1044679485, 1044711889, 1044744738, 1044777743, 1044810728, 1044842666, 1044875818, 1044909054, 1044941332, 1044974401, 1045007078, 1045039415, 1045072381, 1045105548, 1045137530, 1045170575, 1045203497, 1045236434, 1045269283, 1045302209, 1045335037, 1045367439, 1045400328, 1045433345, 1045465318, 1045498536, 1045531190, 1045564261, 1045596389, 1045629394, 1045662223, 1045695169, 1045727378, 1045760125, 1045793432, 1045826318, 1045859160, 1045891537, 1045923905, 1045956948, 1045989983, 1046022786, 1046055244, 1046088179, 1046121142, 1046153439, 1046186768, 1046219235, 1046252017, 1046285054, 1046317662, 1046349931, 1046383048, 1046416116, 1046449047, 1046481083, 1046513979, 1046546965, 1046580138, 1046612994, 1046644877, 1046677981, 1046710575, 1046743404, 1046776337, 1046809168, 1046842046, 1046875002, 1046907781, 1046939781, 1046972608, 1047006008, 1047038262, 1047071104, 1047104167, 1047136338, 1047169287, 1047202133, 1047235149, 1047268056, 1047301054, 1047333842, 1047366291, 1047399352, 1047431242, 1047464200, 1047497114, 1047530023, 1047563030, 1047596059, 1047628719, 1047660992, 1047693819, 1047726665, 1047759485, 1047792597, 1047824752, 1047857244, 1047890435, 1047923018, 1047955909, 1047989173, 1048021176, 1048054179, 1048086987, 1048119880, 1048152778, 1048185660, 1048218555, 1048250890, 1048283688, 1048316698, 1048349687, 1048381890, 1048414952, 1048447651, 1048480316, 1048513367, 1048546050, 1048578180, 1048611193, 1048643844, 1048676778, 1048709940, 1048742726, 1048774755, 1048840414, 1048922238, 1048955500, 1048988301, 1049021074, 1049053868, 1049103132, 1049185158, 1049267166, 1049299936, 1049332620, 1049364568, 1049397349, 1049430164, 1049462985, 1049495761, 1049528545, 1049561366, 1049594210, 1049626986, 1049659808, 1049692617, 1049725445, 1049758245, 1049791050, 1049823879, 1049856648, 1049889419, 1049922192, 1049954969, 1049987748, 1050020544, 1050053321, 1050086122, 1050118903, 1050151743, 1050184533, 1050217319, 1050250116, 1050282940, 1050315732, 1050348713, 1050413102, 1050445930, 1050478692, 1050511504, 1050544329, 1050577096, 1050609886, 1050642673, 1050675451, 1050708280, 1050741063, 1050773850, 1050806620, 1050839393, 1050872177, 1050904967, 1050937748, 1050970549, 1051003325, 1051036131, 1051068942, 1051101712, 1051134501, 1051167278, 1051200062, 1051232853, 1051265636, 1051298412, 1051331184, 1051363956, 1051396766, 1051429546, 1051462334, 1051495124, 1051527949, 1051560768, 1051593543, 1051626323, 1051659093, 1051691858, 1051724634, 1051757436, 1051790227, 1051823028, 1051855813, 1051888591, 1051921364, 1051954206, 1051987091, 1052018683, 1052051463, 1052085440, 1052118038, 1052770740, 1052803512, 1052836278, 1053082487, 1053115253, 1053148022, 1053311869, 1053344643, 1053377414, 1053410185, 1053442223, 1053508490, 1053557630, 1053967243, 1054082241, 1054115011, 1054147779, 1054180526, 1054213294, 1054246085, 1054278853, 1054311618, 1054344386, 1054377156, 1054409924, 1054442639, 1054475407, 1054508239, 1054541007, 1054573777, 1054606545, 1054639303, 1054672071, 1054704838, 1054737606, 1054770376, 1054803144, 1054835913, 1054868681, 1054901452, 1054934219, 1054966736, 1054999503, 1055032284, 1055065047, 1055097915, 1055130683, 1055163603, 1055196371, 1055229142, 1055261910, 1055294677, 1055327445, 1055360138, 1055392911, 1055425679, 1055458521, 1055490581, 1055523349, 1055556117, 1055588885, 1055622282, 1055655128, 1056228421, 1056261189, 1056294020, 1056326787, 1056359559, 1056392325, 1056425097, 1056457943, 1056490626, 1056523459, 1056556227, 1056588938, 1056621727, 1056654496, 1056687272, 1056720042, 1056752809, 1056785579, 1056818349, 1056851116, 1056883884, 1056916618, 1056949386, 1056982178, 1057014956, 1057047445, 1057080216, 1057112990, 1057145765, 1057178539, 1057211318, 1057244336, 1057277109, 1057309624, 1057342400, 1057375176, 1057407946, 1057440741, 1057473514, 1057506310, 1057539084, 1057571857, 1057604629, 1057637406, 1057670182, 1057702956, 1057735726, 1057768498, 1057801268, 1057834042, 1057866812, 1057899582, 1057932352, 1057965124, 1057997901, 1058030676, 1058063449, 1058096222, 1058129000, 1058161774, 1058194328, 1058227322, 1058260096, 1058292254, 1058325026, 1058358416, 1058391188, 1058423963, 1058456530, 1058489501, 1058524232, 1058557003, 1058589775, 1058620581, 1058653351, 1058686124, 1058718619, 1058751389, 1058784158, 1058816943, 1058849717, 1058882486, 1058915506, 1058948276, 1058981045, 1059013682, 1059046458, 1059079232, 1059112004, 1059144790, 1059177561, 1059210348, 1059243132, 1059275903, 1059308672, 1059341461, 1059374231, 1059407003, 1059439777, 1059472547, 1059505312, 1059537814, 1059570588, 1059603365, 1059636139, 1059668900, 1059701688, 1059734464, 1059767240, 1059800034, 1059832807, 1059865598, 1059898374, 1059931148, 1059963921, 1059996700, 1060029473, 1060062247, 1060095021, 1060127792, 1060160566, 1060193342, 1060226112, 1060258893, 1060291668, 1060324426, 1060357219, 1060389996, 1060422775, 1060455549, 1060487710, 1060520483, 1060553874, 1060586647, 1060619430, 1060651932, 1060684720, 1060717747, 1060750314, 1060783096, 1060815936, 1060848726, 1060881521, 1060914315, 1060949068, 1060979737, 1061012516, 1061045290, 1061077998, 1061110779, 1061143492, 1061176254, 1061209035, 1061241864, 1061274642, 1061307377, 1061340150, 1061372919, 1061405698, 1061438489, 1061471268, 1061504042, 1061536750, 1061569531, 1061602244, 1061635006, 1061667787, 1061700616, 1061733394, 1061766129, 1061798902, 1061831671, 1061864450, 1061897201, 1061929974, 1061962720, 1061995541, 1062028261, 1062061041, 1062093814, 1062126620, 1062158850, 1062191265, 1062486953, 1062519722, 1062552497, 1062585267, 1062618044, 1062650819, 1062683619, 1062716396, 1062749163, 1062781933, 1062814719, 1062847476, 1062880242, 1062913017, 1062945786, 1062978575, 1063011350, 1063044119, 1063076895, 1063109666, 1063142440, 1063175209, 1063207983, 1063240760, 1063273555, 1063306318, 1063339093, 1063371863, 1063404644, 1063437415, 1063470177, 1063502954, 1063567904, 1063601272, 1063634037, 1063666806, 1063699582, 1063732376, 1063764903, 1063797677, 1063830452, 1063863229, 1063895997, 1063928832, 1063961613, 1063994456, 1064027281, 1064060013, 1064092793, 1064125522, 1064158275, 1064191083, 1064223810, 1064256628, 1064289217, 1064322097, 1064354882, 1064387591, 1064420470, 1065108533, 1065140745, 1065174019, 1065206816, 1065239556, 1065272250, 1065764815, 1066157992, 1066223519, 1066256289, 1066289059, 1066550027, 1066582795, 1066615179, 1066647947, 1067057543, 1067467666, 1067500044, 1067533448, 1067565579, 1067599006, 1067631774, 1067663825, 1067697043, 1067729811, 1067762594, 1067795363, 1067828131, 1067861166, 1067893934, 1067926455, 1067959223, 1067991999, 1068024767, 1068057543, 1068090311, 1068123084, 1068155857, 1068188627, 1068221405, 1068254175, 1068286943, 1068319727, 1068352495, 1068385276, 1068418044, 1068450826, 1068483594, 1068516371, 1068549139, 1068581915, 1068614683, 1068647453, 1068680221, 1068712997, 1068745765, 1068778539, 1068811307, 1068844083, 1068876851, 1068909627, 1068942395, 1068975174, 1069007942, 1069040731, 1069073499, 1069106288, 1069139056, 1069171221, 1069203989, 1069237377, 1069270154, 1069302922, 1069335690, 1069368396, 1069401161, 1069433928, 1069466695, 1069563905, 1069662213, 1069973760, 1070104868, 1070154031, 1070219729, 1070252353, 1070301516, 1070334301, 1070399844, 1070481794, 1070514191, 1070547105, 1070596101, 1070645662, 1070711228, 1070744004, 1070793169, 1070858791, 1070957130, 1071006294, 1071089617, 1073267467, 1073432495, 1080262992, 1080410489, 1080443398, 1084260644, 1084326191, 1084391889, 1084490109, 1084557116, 1084588387, 1084670116, 1084768548, 1084801854, 1084932434, 1085014345, 1085047170, 1085079933, 1085113158, 1085522233, 1085867137, 1086096380, 1086341412, 1086472657, 1086620499, 1086701949, 1086832803, 1086882173, 1089784918, 1090584914, 1090863397, 1091028936, 1091093206, 1091193916, 1091240469, 1091551988, 1091617543, 1091748587, 1093143642, 1093468513, 1093697874, 1093731021, 1093894510, 1093944133, 1094058750, 1094353429, 1094550085, 1095024972, 1095106881, 1095188851, 1095336261, 1116635941, 1117113436, 1128205405, 1148306675, 1148372199, 1148652607, 1153434396, 1158040672, 1158319202, 1158401124, 1160021240, 1160086778, 1160135932, 1160201481, 1160234240, 1160267010];
+pub static PROTOTYPE_STARTS: [u16; 3309] = [0, 1, 2, 4, 6, 7, 8, 10, 13, 17, 19, 21, 23, 25, 27, 29, 30, 32, 35, 39, 42, 45, 48, 51, 54, 57, 60, 63, 66, 69, 72, 75, 78, 81, 84, 87, 90, 93, 96, 99, 102, 105, 108, 111, 114, 117, 120, 123, 126, 129, 132, 135, 138, 141, 144, 147, 150, 153, 156, 159, 162, 165, 168, 171, 175, 179, 183, 187, 191, 195, 199, 203, 207, 211, 214, 217, 220, 223, 226, 230, 233, 236, 239, 242, 245, 248, 251, 254, 257, 261, 264, 268, 271, 275, 278, 282, 285, 289, 292, 296, 299, 303, 306, 310, 317, 323, 326, 330, 334, 337, 341, 344, 348, 351, 355, 358, 362, 365, 369, 372, 375, 378, 381, 384, 387, 390, 393, 396, 399, 402, 405, 408, 411, 414, 417, 420, 423, 426, 429, 432, 435, 438, 441, 444, 447, 450, 453, 456, 459, 462, 465, 468, 471, 474, 477, 480, 483, 486, 489, 492, 495, 498, 499, 501, 502, 503, 505, 507, 509, 511, 513, 515, 517, 518, 519, 521, 523, 525, 527, 529, 530, 532, 534, 537, 538, 540, 543, 545, 546, 548, 550, 553, 556, 559, 562, 565, 568, 571, 574, 577, 580, 583, 586, 589, 592, 595, 598, 600, 602, 604, 606, 607, 609, 611, 614, 617, 619, 621, 623, 625, 626, 628, 630, 632, 634, 636, 638, 639, 641, 643, 645, 647, 649, 650, 652, 654, 656, 658, 660, 661, 663, 665, 667, 669, 671, 672, 674, 676, 678, 680, 682, 683, 685, 687, 689, 691, 693, 694, 697, 699, 700, 701, 703, 706, 708, 709, 711, 714, 716, 718, 720, 722, 725, 727, 729, 730, 732, 734, 737, 739, 740, 742, 744, 745, 747, 749, 751, 753, 755, 757, 759, 761, 763, 764, 765, 767, 769, 771, 772, 774, 777, 779, 782, 784, 785, 787, 789, 790, 793, 795, 796, 798, 800, 802, 803, 805, 807, 809, 811, 812, 814, 816, 817, 819, 821, 823, 824, 826, 828, 830, 831, 833, 835, 836, 838, 840, 842, 844, 846, 847, 849, 851, 853, 855, 857, 859, 861, 863, 866, 868, 869, 871, 872, 874, 875, 877, 878, 879, 881, 884, 886, 888, 890, 892, 894, 896, 898, 899, 901, 903, 905, 907, 908, 910, 912, 915, 919, 921, 923, 925, 926, 928, 929, 931, 934, 936, 938, 939, 941, 942, 944, 946, 947, 949, 951, 952, 953, 954, 957, 960, 962, 964, 966, 968, 970, 972, 974, 976, 978, 980, 981, 983, 985, 987, 989, 990, 993, 996, 998, 1000, 1001, 1003, 1005, 1008, 1010, 1012, 1014, 1016, 1018, 1020, 1022, 1025, 1026, 1028, 1030, 1032, 1033, 1035, 1038, 1041, 1043, 1045, 1047, 1049, 1051, 1052, 1054, 1056, 1058, 1060, 1061, 1063, 1065, 1066, 1068, 1071, 1073, 1075, 1077, 1079, 1081, 1083, 1084, 1086, 1087, 1089, 1090, 1092, 1094, 1096, 1099, 1102, 1105, 1108, 1111, 1114, 1117, 1120, 1123, 1126, 1129, 1132, 1135, 1138, 1141, 1144, 1147, 1150, 1153, 1156, 1159, 1162, 1165, 1168, 1171, 1173, 1175, 1178, 1181, 1184, 1187, 1189, 1191, 1193, 1195, 1198, 1201, 1204, 1207, 1210, 1212, 1214, 1216, 1218, 1220, 1222, 1224, 1226, 1228, 1232, 1238, 1240, 1244, 1250, 1252, 1254, 1256, 1258, 1260, 1261, 1263, 1265, 1267, 1269, 1270, 1272, 1274, 1276, 1278, 1280, 1282, 1284, 1286, 1288, 1290, 1293, 1296, 1298, 1300, 1303, 1305, 1307, 1308, 1310, 1312, 1314, 1315, 1317, 1318, 1320, 1322, 1325, 1328, 1331, 1333, 1335, 1337, 1339, 1340, 1343, 1345, 1347, 1349, 1350, 1352, 1354, 1356, 1358, 1360, 1362, 1364, 1366, 1367, 1369, 1371, 1373, 1375, 1376, 1378, 1381, 1385, 1386, 1388, 1390, 1393, 1394, 1396, 1399, 1401, 1402, 1404, 1406, 1407, 1409, 1411, 1413, 1415, 1416, 1417, 1418, 1420, 1422, 1424, 1425, 1426, 1427, 1428, 1430, 1432, 1434, 1436, 1437, 1438, 1440, 1442, 1444, 1446, 1448, 1450, 1452, 1454, 1456, 1459, 1461, 1463, 1465, 1467, 1469, 1471, 1473, 1475, 1477, 1479, 1481, 1483, 1485, 1487, 1489, 1491, 1493, 1495, 1497, 1499, 1501, 1503, 1505, 1507, 1509, 1511, 1513, 1515, 1517, 1519, 1521, 1523, 1525, 1527, 1529, 1531, 1533, 1535, 1537, 1539, 1541, 1543, 1545, 1547, 1549, 1551, 1553, 1555, 1557, 1559, 1561, 1563, 1565, 1567, 1569, 1571, 1573, 1575, 1577, 1579, 1581, 1583, 1585, 1587, 1589, 1591, 1593, 1595, 1597, 1599, 1601, 1603, 1605, 1607, 1609, 1611, 1613, 1615, 1617, 1619, 1621, 1622, 1625, 1629, 1634, 1635, 1636, 1637, 1638, 1639, 1641, 1643, 1644, 1646, 1647, 1648, 1649, 1650, 1651, 1652, 1653, 1654, 1655, 1657, 1660, 1663, 1665, 1666, 1667, 1668, 1669, 1670, 1672, 1674, 1675, 1676, 1678, 1680, 1681, 1683, 1684, 1685, 1686, 1687, 1688, 1689, 1691, 1692, 1693, 1694, 1696, 1699, 1703, 1705, 1706, 1707, 1709, 1710, 1711, 1712, 1713, 1715, 1717, 1718, 1719, 1720, 1721, 1722, 1724, 1727, 1728, 1729, 1730, 1731, 1732, 1733, 1734, 1735, 1736, 1737, 1739, 1740, 1741, 1742, 1743, 1745, 1747, 1748, 1749, 1750, 1751, 1752, 1753, 1754, 1755, 1756, 1757, 1758, 1759, 1760, 1761, 1762, 1763, 1764, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772, 1773, 1774, 1775, 1777, 1779, 1781, 1782, 1784, 1786, 1788, 1789, 1790, 1791, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1800, 1801, 1802, 1805, 1807, 1808, 1809, 1810, 1811, 1812, 1814, 1815, 1817, 1820, 1821, 1823, 1824, 1826, 1828, 1831, 1832, 1834, 1835, 1836, 1837, 1839, 1842, 1843, 1844, 1846, 1847, 1848, 1849, 1851, 1853, 1854, 1855, 1857, 1858, 1860, 1862, 1864, 1866, 1868, 1869, 1870, 1871, 1872, 1873, 1875, 1877, 1878, 1879, 1880, 1881, 1883, 1884, 1885, 1886, 1887, 1888, 1891, 1893, 1894, 1895, 1896, 1897, 1898, 1900, 1902, 1903, 1905, 1907, 1909, 1912, 1914, 1917, 1919, 1921, 1923, 1925, 1927, 1929, 1931, 1933, 1934, 1935, 1937, 1939, 1942, 1945, 1947, 1950, 1953, 1955, 1958, 1961, 1963, 1965, 1967, 1970, 1973, 1976, 1979, 1981, 1983, 1984, 1986, 1989, 1997, 1999, 2002, 2005, 2007, 2008, 2010, 2013, 2015, 2018, 2020, 2022, 2024, 2025, 2027, 2029, 2031, 2033, 2034, 2036, 2039, 2041, 2043, 2044, 2046, 2047, 2049, 2051, 2054, 2056, 2060, 2064, 2066, 2068, 2070, 2071, 2073, 2074, 2076, 2078, 2080, 2083, 2086, 2088, 2091, 2093, 2096, 2098, 2100, 2103, 2106, 2109, 2111, 2113, 2116, 2119, 2123, 2126, 2130, 2134, 2137, 2140, 2143, 2147, 2151, 2154, 2155, 2158, 2160, 2163, 2166, 2168, 2170, 2174, 2177, 2195, 2197, 2200, 2202, 2204, 2205, 2207, 2209, 2212, 2214, 2217, 2219, 2221, 2223, 2224, 2226, 2228, 2231, 2234, 2237, 2239, 2241, 2242, 2244, 2245, 2247, 2250, 2254, 2256, 2259, 2262, 2264, 2265, 2267, 2269, 2272, 2275, 2277, 2278, 2280, 2282, 2284, 2287, 2289, 2292, 2294, 2295, 2297, 2300, 2302, 2305, 2308, 2311, 2313, 2314, 2316, 2318, 2320, 2322, 2324, 2326, 2329, 2332, 2334, 2336, 2337, 2339, 2342, 2345, 2347, 2349, 2352, 2354, 2357, 2360, 2363, 2365, 2368, 2371, 2373, 2376, 2378, 2381, 2384, 2386, 2388, 2389, 2391, 2393, 2395, 2398, 2401, 2404, 2407, 2409, 2412, 2415, 2419, 2422, 2424, 2427, 2430, 2433, 2435, 2438, 2440, 2442, 2443, 2445, 2447, 2449, 2452, 2455, 2458, 2460, 2463, 2466, 2468, 2470, 2472, 2474, 2477, 2479, 2481, 2482, 2484, 2486, 2488, 2491, 2495, 2497, 2499, 2501, 2502, 2504, 2506, 2508, 2511, 2513, 2515, 2518, 2520, 2523, 2525, 2527, 2529, 2531, 2534, 2537, 2539, 2541, 2543, 2545, 2547, 2550, 2553, 2556, 2559, 2562, 2565, 2568, 2571, 2574, 2577, 2581, 2585, 2589, 2592, 2595, 2597, 2600, 2603, 2606, 2609, 2612, 2615, 2618, 2621, 2622, 2623, 2624, 2625, 2626, 2627, 2628, 2629, 2630, 2631, 2632, 2633, 2634, 2635, 2636, 2637, 2638, 2639, 2640, 2642, 2643, 2644, 2646, 2647, 2649, 2651, 2652, 2653, 2654, 2656, 2657, 2658, 2659, 2661, 2663, 2664, 2665, 2666, 2668, 2671, 2674, 2677, 2679, 2681, 2683, 2685, 2687, 2690, 2691, 2692, 2693, 2694, 2695, 2696, 2697, 2699, 2700, 2701, 2702, 2703, 2704, 2705, 2706, 2708, 2710, 2711, 2712, 2713, 2714, 2715, 2716, 2717, 2718, 2719, 2720, 2721, 2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2733, 2734, 2736, 2738, 2739, 2740, 2741, 2742, 2743, 2745, 2747, 2749, 2751, 2753, 2755, 2757, 2759, 2761, 2764, 2767, 2770, 2772, 2774, 2776, 2778, 2779, 2780, 2782, 2783, 2785, 2787, 2788, 2790, 2791, 2792, 2793, 2795, 2797, 2799, 2800, 2802, 2803, 2805, 2806, 2807, 2808, 2809, 2810, 2811, 2813, 2815, 2817, 2818, 2819, 2820, 2821, 2823, 2825, 2826, 2828, 2830, 2831, 2832, 2834, 2835, 2837, 2839, 2840, 2842, 2843, 2844, 2846, 2848, 2850, 2852, 2854, 2855, 2856, 2857, 2859, 2861, 2863, 2865, 2867, 2868, 2871, 2873, 2875, 2878, 2881, 2882, 2884, 2887, 2890, 2891, 2893, 2894, 2895, 2897, 2899, 2900, 2901, 2902, 2903, 2904, 2905, 2906, 2907, 2908, 2909, 2910, 2911, 2912, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2923, 2924, 2925, 2926, 2927, 2928, 2929, 2930, 2932, 2934, 2935, 2937, 2939, 2941, 2943, 2945, 2949, 2953, 2954, 2957, 2960, 2963, 2965, 2967, 2969, 2971, 2974, 2976, 2978, 2983, 2984, 2986, 2988, 2990, 2991, 2993, 2995, 2997, 2999, 3001, 3003, 3006, 3008, 3010, 3012, 3013, 3015, 3017, 3019, 3021, 3023, 3025, 3027, 3029, 3031, 3033, 3035, 3036, 3038, 3040, 3043, 3045, 3047, 3049, 3051, 3054, 3056, 3058, 3060, 3061, 3063, 3066, 3069, 3072, 3074, 3076, 3079, 3082, 3084, 3087, 3089, 3092, 3095, 3098, 3100, 3103, 3106, 3109, 3112, 3115, 3118, 3120, 3123, 3125, 3127, 3129, 3131, 3133, 3135, 3137, 3139, 3141, 3144, 3145, 3147, 3149, 3152, 3154, 3156, 3158, 3160, 3163, 3165, 3168, 3170, 3172, 3174, 3176, 3178, 3179, 3181, 3183, 3185, 3187, 3190, 3192, 3194, 3197, 3199, 3202, 3205, 3208, 3211, 3214, 3217, 3219, 3221, 3223, 3225, 3227, 3229, 3231, 3232, 3234, 3236, 3238, 3240, 3242, 3244, 3247, 3250, 3252, 3255, 3258, 3261, 3264, 3266, 3268, 3270, 3272, 3274, 3276, 3278, 3279, 3281, 3284, 3286, 3288, 3290, 3292, 3294, 3296, 3298, 3300, 3302, 3304, 3306, 3308, 3310, 3311, 3313, 3316, 3318, 3320, 3323, 3324, 3326, 3328, 3329, 3330, 3332, 3333, 3335, 3337, 3339, 3341, 3343, 3344, 3346, 3348, 3350, 3352, 3354, 3356, 3358, 3360, 3361, 3363, 3366, 3367, 3369, 3371, 3373, 3375, 3376, 3378, 3379, 3380, 3382, 3384, 3386, 3388, 3389, 3391, 3393, 3395, 3397, 3398, 3400, 3402, 3404, 3406, 3407, 3409, 3411, 3413, 3415, 3416, 3418, 3421, 3423, 3426, 3428, 3431, 3433, 3436, 3438, 3441, 3443, 3445, 3446, 3448, 3451, 3453, 3456, 3458, 3460, 3462, 3463, 3465, 3468, 3470, 3473, 3476, 3478, 3481, 3483, 3485, 3488, 3489, 3491, 3494, 3496, 3499, 3501, 3504, 3506, 3508, 3510, 3511, 3513, 3515, 3518, 3520, 3522, 3524, 3525, 3526, 3527, 3528, 3529, 3531, 3533, 3535, 3537, 3539, 3541, 3543, 3545, 3547, 3549, 3550, 3551, 3553, 3555, 3556, 3558, 3560, 3561, 3563, 3565, 3567, 3569, 3571, 3572, 3574, 3576, 3578, 3580, 3582, 3584, 3586, 3588, 3590, 3592, 3593, 3595, 3597, 3599, 3601, 3602, 3604, 3606, 3608, 3610, 3612, 3614, 3616, 3618, 3620, 3622, 3624, 3626, 3628, 3630, 3631, 3633, 3635, 3637, 3639, 3641, 3642, 3644, 3646, 3648, 3650, 3652, 3654, 3656, 3658, 3660, 3662, 3664, 3666, 3668, 3670, 3672, 3674, 3676, 3678, 3680, 3682, 3684, 3686, 3688, 3690, 3692, 3694, 3695, 3697, 3698, 3699, 3701, 3703, 3705, 3707, 3709, 3711, 3713, 3715, 3717, 3719, 3721, 3723, 3725, 3727, 3729, 3731, 3733, 3735, 3737, 3739, 3741, 3743, 3745, 3747, 3749, 3751, 3753, 3755, 3757, 3759, 3761, 3763, 3765, 3767, 3769, 3771, 3773, 3775, 3776, 3777, 3778, 3780, 3782, 3783, 3784, 3785, 3786, 3787, 3788, 3789, 3790, 3792, 3794, 3795, 3796, 3798, 3799, 3800, 3801, 3802, 3803, 3804, 3805, 3806, 3807, 3809, 3810, 3811, 3812, 3813, 3814, 3815, 3817, 3819, 3820, 3821, 3822, 3823, 3825, 3827, 3829, 3830, 3831, 3832, 3833, 3834, 3835, 3836, 3837, 3839, 3840, 3841, 3842, 3843, 3844, 3846, 3847, 3848, 3849, 3850, 3851, 3852, 3853, 3854, 3855, 3856, 3858, 3860, 3861, 3863, 3864, 3865, 3867, 3869, 3870, 3871, 3872, 3873, 3875, 3878, 3879, 3880, 3881, 3882, 3883, 3884, 3885, 3886, 3887, 3888, 3889, 3890, 3891, 3892, 3893, 3894, 3895, 3896, 3898, 3899, 3900, 3901, 3902, 3903, 3904, 3905, 3906, 3907, 3908, 3909, 3910, 3911, 3912, 3913, 3914, 3915, 3916, 3917, 3918, 3919, 3920, 3921, 3922, 3923, 3924, 3925, 3926, 3927, 3928, 3929, 3930, 3931, 3932, 3933, 3934, 3935, 3936, 3937, 3938, 3939, 3940, 3941, 3942, 3943, 3944, 3945, 3946, 3947, 3948, 3949, 3950, 3951, 3952, 3953, 3954, 3955, 3956, 3957, 3958, 3959, 3960, 3961, 3962, 3963, 3964, 3965, 3966, 3968, 3970, 3973, 3975, 3977, 3979, 3981, 3984, 3985, 3986, 3987, 3988, 3989, 3990, 3991, 3992, 3993, 3994, 3995, 3996, 3997, 3998, 3999, 4000, 4001, 4002, 4003, 4004, 4005, 4006, 4007, 4008, 4009, 4010, 4011, 4012, 4013, 4014, 4015, 4016, 4017, 4018, 4019, 4020, 4021, 4022, 4023, 4024, 4025, 4026, 4027, 4028, 4029, 4030, 4031, 4032, 4033, 4034, 4035, 4036, 4037, 4038, 4039, 4040, 4041, 4042, 4043, 4044, 4045, 4046, 4047, 4048, 4049, 4050, 4051, 4052, 4053, 4054, 4055, 4056, 4057, 4058, 4059, 4060, 4061, 4062, 4063, 4064, 4065, 4066, 4067, 4069, 4071, 4074, 4077, 4079, 4082, 4084, 4087, 4089, 4091, 4093, 4095, 4097, 4099, 4100, 4101, 4102, 4103, 4104, 4105, 4106, 4107, 4108, 4109, 4110, 4111, 4112, 4113, 4114, 4115, 4116, 4117, 4118, 4119, 4120, 4121, 4122, 4123, 4124, 4125, 4126, 4127, 4128, 4129, 4130, 4131, 4132, 4133, 4134, 4135, 4136, 4137, 4138, 4139, 4140, 4141, 4142, 4143, 4144, 4145, 4146, 4147, 4148, 4149, 4150, 4151, 4152, 4153, 4154, 4155, 4156, 4157, 4158, 4159, 4160, 4161, 4162, 4163, 4164, 4165, 4166, 4167, 4168, 4169, 4170, 4171, 4172, 4173, 4174, 4175, 4176, 4177, 4178, 4179, 4180, 4181, 4182, 4183, 4184, 4185, 4186, 4187, 4188, 4189, 4190, 4191, 4192, 4193, 4194, 4195, 4196, 4197, 4198, 4199, 4200, 4201, 4202, 4203, 4204, 4205, 4206, 4207, 4208, 4209, 4210, 4211, 4212, 4213, 4214, 4215, 4216, 4217, 4218, 4219, 4220, 4221, 4222, 4223, 4224, 4225, 4226, 4227, 4228, 4229, 4230, 4231, 4232, 4233, 4234, 4235, 4236, 4237, 4238, 4239, 4240, 4241, 4242, 4243, 4244, 4245, 4246, 4247, 4248, 4249, 4250, 4251, 4252, 4253, 4254, 4255, 4256, 4257, 4258, 4259, 4260, 4261, 4262, 4263, 4264, 4265, 4266, 4267, 4268, 4269, 4270, 4271, 4272, 4273, 4274, 4275, 4276, 4277, 4278, 4279, 4280, 4281, 4282, 4283, 4284, 4285, 4286, 4287, 4288, 4289, 4290, 4291, 4292, 4293, 4294, 4295, 4296, 4297, 4298, 4299, 4300, 4301, 4302, 4303, 4304, 4305, 4306, 4307, 4308, 4309, 4310, 4311, 4312, 4313, 4314, 4315, 4316, 4317, 4318, 4319, 4320, 4321, 4322, 4323, 4324, 4325, 4326, 4327, 4328, 4329, 4330, 4331, 4332, 4333, 4334, 4335, 4336, 4337, 4338, 4339, 4340, 4341, 4342, 4343, 4344, 4345, 4346, 4347, 4348, 4349, 4350, 4351, 4352, 4353, 4354, 4355, 4356, 4357, 4358, 4359, 4360, 4361, 4362, 4363, 4364, 4365, 4366, 4367, 4368, 4369, 4370, 4371, 4372, 4373, 4374, 4375, 4376, 4377, 4378, 4379, 4380, 4381, 4382, 4383, 4384, 4385, 4386, 4387, 4388, 4389, 4390, 4391, 4392, 4393, 4394, 4395, 4396, 4397, 4398, 4399, 4400, 4401, 4402, 4403, 4404, 4405, 4406, 4407, 4408, 4409, 4410, 4411, 4412, 4413, 4414, 4415, 4416, 4417, 4418, 4419, 4420, 4421, 4422, 4423, 4424, 4425, 4426, 4427, 4428, 4429, 4430, 4431, 4432, 4433, 4434, 4435, 4436, 4437, 4438, 4439, 4440, 4441, 4442, 4443, 4444, 4445, 4446, 4447, 4448, 4449, 4450, 4451, 4452, 4453, 4454, 4455, 4456, 4457, 4458, 4459, 4460, 4461, 4462, 4463, 4464, 4465, 4466, 4467, 4468, 4469, 4470, 4471, 4472, 4473, 4474, 4475, 4476, 4477, 4478, 4479, 4480, 4481, 4482, 4483, 4484, 4485, 4486, 4487, 4488, 4489, 4490, 4491, 4492, 4493, 4494, 4495, 4496, 4497, 4498, 4499, 4500, 4501, 4502, 4503, 4504, 4505, 4506, 4507, 4508, 4509, 4510, 4511, 4512, 4513, 4514, 4515, 4516, 4517, 4518, 4519, 4520, 4521, 4522, 4523, 4524, 4525, 4526, 4527, 4528, 4529, 4530, 4531, 4532, 4533, 4534, 4535, 4536, 4537, 4538, 4539, 4540, 4541, 4542, 4543, 4544, 4545, 4546, 4547, 4548, 4549, 4550, 4551, 4552, 4553, 4554, 4555, 4556, 4557, 4558, 4559, 4560, 4561, 4562, 4563, 4564, 4565, 4566, 4567, 4568, 4569, 4570, 4571, 4572, 4573, 4574, 4575, 4576, 4577, 4578, 4579, 4580, 4581, 4582, 4583, 4584, 4585, 4586, 4587, 4588, 4589, 4590, 4591, 4592, 4593, 4594, 4595, 4596, 4597, 4598, 4599, 4600, 4601, 4602, 4603, 4604, 4605, 4606, 4607, 4608, 4609, 4610, 4611, 4612, 4613, 4614, 4615, 4616, 4617, 4618, 4619, 4620, 4621, 4622, 4623, 4624, 4625, 4626, 4627, 4628, 4629, 4630, 4631, 4632, 4633, 4634, 4635, 4636, 4637, 4638, 4639, 4640, 4641, 4642, 4643, 4644, 4645, 4646, 4647, 4648, 4649, 4650, 4651, 4652, 4653, 4654, 4655, 4656, 4657, 4658, 4659, 4660, 4661, 4662, 4663, 4664, 4665, 4666, 4667, 4668, 4669, 4670, 4671, 4672, 4673, 4674, 4675, 4676, 4677, 4678, 4679, 4680, 4681, 4682, 4683, 4684, 4685, 4686, 4687, 4688, 4689, 4690, 4691, 4692, 4693, 4694, 4695, 4696, 4697, 4698, 4699, 4700, 4701, 4702, 4703, 4704, 4705, 4706, 4707, 4708, 4709, 4710, 4711, 4712, 4713, 4714, 4715, 4716, 4717, 4718, 4719, 4720, 4721, 4722, 4723, 4724, 4725, 4726, 4727, 4728, 4729, 4730, 4731, 4732, 4733, 4734, 4735, 4736, 4737, 4738, 4739, 4740, 4741, 4742, 4743, 4744, 4745, 4746, 4747, 4748, 4749, 4750, 4751, 4752, 4753, 4754, 4755, 4756, 4757, 4758, 4759, 4760, 4761, 4762, 4763, 4764, 4765, 4766, 4767, 4768, 4769, 4770, 4771, 4772, 4773, 4774, 4775, 4776, 4777, 4778, 4779, 4780, 4781, 4782, 4783, 4784, 4785, 4786, 4787, 4788, 4789, 4790, 4791, 4792, 4793, 4794, 4795, 4796, 4797, 4798, 4799, 4800, 4801, 4802, 4803, 4804, 4805, 4806, 4807, 4808, 4809, 4810, 4811, 4812, 4813, 4814, 4815, 4816, 4817, 4818, 4819, 4820, 4821, 4822, 4823, 4824, 4825, 4826, 4827, 4828, 4829, 4830, 4831, 4832, 4833, 4834, 4835, 4836, 4837, 4838, 4839, 4840, 4841, 4842, 4843, 4844, 4845, 4846, 4847, 4848, 4849, 4850, 4851, 4852, 4853, 4854, 4855, 4856, 4857, 4858, 4859, 4860, 4861, 4862, 4863, 4864, 4865, 4866, 4867, 4868, 4869, 4870, 4871, 4872, 4873, 4874, 4875, 4876, 4877, 4878, 4879, 4880, 4881, 4882, 4883, 4884, 4885, 4886, 4887, 4888, 4889, 4890, 4891, 4892, 4893, 4894, 4895, 4896, 4897, 4898, 4899, 4900, 4901, 4902, 4903, 4904, 4905, 4906, 4907, 4908, 4909, 4910, 4911, 4912, 4913, 4914, 4915, 4916, 4917, 4918, 4919, 4920, 4921, 4922, 4923, 4924, 4925, 4926, 4927, 4928, 4929, 4930, 4931, 4932, 4933, 4934, 4935, 4936, 4937, 4938, 4939, 4940, 4941, 4942, 4943, 4944, 4945, 4946, 4947, 4948, 4949, 4950, 4951, 4952, 4953, 4954, 4955, 4956, 4957, 4958, 4959, 4960, 4961, 4962, 4963, 4964, 4965, 4966, 4967, 4968, 4969, 4970, 4971, 4972, 4973, 4974, 4975, 4976, 4977, 4978, 4979, 4980, 4981, 4982, 4983, 4984, 4985, 4986, 4987, 4988, 4989, 4990, 4991, 4992, 4993, 4994, 4995, 4996, 4997, 4998, 4999, 5000, 5001, 5002, 5003, 5004, 5005, 5006, 5007, 5008, 5009, 5010, 5011, 5012, 5013, 5014, 5015, 5016, 5017, 5018, 5019, 5020, 5021, 5022, 5023, 5024, 5025, 5026, 5027, 5028, 5029, 5030, 5031, 5032, 5033, 5034, 5035, 5036, 5037, 5038, 5039, 5040, 5041, 5042, 5043, 5044, 5045, 5046, 5047, 5048, 5049, 5050, 5051, 5052, 5053, 5054, 5055, 5056, 5057, 5058, 5059, 5060, 5061, 5062, 5063, 5064, 5065, 5066, 5067, 5068, 5069, 5070, 5071, 5072, 5073, 5074, 5075, 5076, 5077, 5078, 5079, 5080, 5081, 5082, 5083, 5084, 5085, 5086, 5087, 5088, 5089, 5090, 5091, 5092, 5093, 5094, 5095, 5096, 5097, 5098, 5099, 5100, 5101, 5102, 5103, 5104, 5105, 5106, 5107, 5108, 5109, 5110, 5111, 5112, 5113, 5115, 5116, 5117, 5118, 5119, 5120, 5121, 5122, 5123, 5125, 5126, 5127, 5128, 5129, 5130, 5131, 5132, 5133, 5134, 5136, 5138, 5140, 5142, 5144, 5146, 5148, 5150, 5152, 5153, 5154, 5155, 5156, 5157, 5158, 5159, 5160, 5161, 5162, 5163, 5164, 5166, 5167, 5168, 5171, 5174, 5177, 5180, 5183, 5186, 5188, 5189, 5190, 5191, 5192, 5193, 5195, 5197, 5200, 5203, 5205, 5207, 5209, 5212, 5214, 5216, 5218, 5220, 5223, 5226, 5228, 5229, 5230, 5232, 5235, 5236, 5237, 5238, 5239, 5240, 5241, 5242, 5243, 5244, 5245, 5246, 5247, 5248, 5249, 5250, 5251, 5252, 5253, 5254, 5255, 5256, 5257, 5258, 5259, 5260, 5261, 5262, 5263, 5264, 5265, 5266, 5267, 5268, 5269, 5270, 5271, 5272, 5273, 5274, 5275, 5276, 5277, 5278, 5279, 5280, 5281, 5282, 5283, 5284, 5285, 5286, 5287, 5288, 5289, 5290, 5291, 5292, 5293, 5294, 5295, 5296, 5297, 5298, 5299, 5300, 5301, 5302, 5303, 5304, 5305, 5306, 5307, 5308, 5309, 5310, 5311, 5312, 5313, 5314, 5315, 5316, 5317, 5318, 5319, 5320, 5321, 5322, 5323, 5324, 5325, 5326, 5327, 5328, 5329, 5330, 5331, 5332, 5333, 5334, 5335, 5336, 5337, 5338, 5339, 5340, 5341, 5342, 5343, 5344, 5345];
+pub static PROTOTYPE_CHARS: [char; 5345] = [' ', '!', '!', '!', '!', '?', '&', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', '\'', 'B', '\'', 'D', '\'', 'P', '\'', 'T', '\'', 'Y', '\'', 'n', '(', '(', '(', '(', '2', ')', '(', '2', 'O', ')', '(', '3', ')', '(', '4', ')', '(', '5', ')', '(', '6', ')', '(', '7', ')', '(', '8', ')', '(', '9', ')', '(', 'A', ')', '(', 'B', ')', '(', 'C', ')', '(', 'D', ')', '(', 'E', ')', '(', 'F', ')', '(', 'G', ')', '(', 'H', ')', '(', 'J', ')', '(', 'K', ')', '(', 'L', ')', '(', 'M', ')', '(', 'N', ')', '(', 'O', ')', '(', 'P', ')', '(', 'Q', ')', '(', 'R', ')', '(', 'S', ')', '(', 'T', ')', '(', 'U', ')', '(', 'V', ')', '(', 'W', ')', '(', 'X', ')', '(', 'Y', ')', '(', 'Z', ')', '(', 'a', ')', '(', 'b', ')', '(', 'c', ')', '(', 'd', ')', '(', 'e', ')', '(', 'f', ')', '(', 'g', ')', '(', 'h', ')', '(', 'i', ')', '(', 'j', ')', '(', 'k', ')', '(', 'l', ')', '(', 'l', '2', ')', '(', 'l', '3', ')', '(', 'l', '4', ')', '(', 'l', '5', ')', '(', 'l', '6', ')', '(', 'l', '7', ')', '(', 'l', '8', ')', '(', 'l', '9', ')', '(', 'l', 'O', ')', '(', 'l', 'l', ')', '(', 'n', ')', '(', 'o', ')', '(', 'p', ')', '(', 'q', ')', '(', 'r', ')', '(', 'r', 'n', ')', '(', 's', ')', '(', 't', ')', '(', 'u', ')', '(', 'v', ')', '(', 'w', ')', '(', 'x', ')', '(', 'y', ')', '(', 'z', ')', '(', 'ᄀ', ')', '(', 'ᄀ', 'ᅡ', ')', '(', 'ᄂ', ')', '(', 'ᄂ', 'ᅡ', ')', '(', 'ᄃ', ')', '(', 'ᄃ', 'ᅡ', ')', '(', 'ᄅ', ')', '(', 'ᄅ', 'ᅡ', ')', '(', 'ᄆ', ')', '(', 'ᄆ', 'ᅡ', ')', '(', 'ᄇ', ')', '(', 'ᄇ', 'ᅡ', ')', '(', 'ᄉ', ')', '(', 'ᄉ', 'ᅡ', ')', '(', 'ᄋ', ')', '(', 'ᄋ', 'ᅡ', ')', '(', 'ᄋ', 'ᅩ', 'ᄌ', 'ᅥ', 'ᆫ', ')', '(', 'ᄋ', 'ᅩ', 'ᄒ', 'ᅮ', ')', '(', 'ᄌ', ')', '(', 'ᄌ', 'ᅡ', ')', '(', 'ᄌ', 'ᅮ', ')', '(', 'ᄎ', ')', '(', 'ᄎ', 'ᅡ', ')', '(', 'ᄏ', ')', '(', 'ᄏ', 'ᅡ', ')', '(', 'ᄐ', ')', '(', 'ᄐ', 'ᅡ', ')', '(', 'ᄑ', ')', '(', 'ᄑ', 'ᅡ', ')', '(', 'ᄒ', ')', '(', 'ᄒ', 'ᅡ', ')', '(', 'ー', ')', '(', '七', ')', '(', '三', ')', '(', '九', ')', '(', '二', ')', '(', '五', ')', '(', '代', ')', '(', '企', ')', '(', '休', ')', '(', '八', ')', '(', '六', ')', '(', '労', ')', '(', '勝', ')', '(', '十', ')', '(', '協', ')', '(', '名', ')', '(', '呼', ')', '(', '四', ')', '(', '土', ')', '(', '学', ')', '(', '安', ')', '(', '打', ')', '(', '敗', ')', '(', '日', ')', '(', '月', ')', '(', '有', ')', '(', '木', ')', '(', '本', ')', '(', '株', ')', '(', '水', ')', '(', '火', ')', '(', '点', ')', '(', '特', ')', '(', '盗', ')', '(', '監', ')', '(', '社', ')', '(', '祝', ')', '(', '祭', ')', '(', '自', ')', '(', '至', ')', '(', '財', ')', '(', '資', ')', '(', '金', ')', ')', ')', ')', '*', '+', '+', '\u{302}', '+', '\u{303}', '+', '\u{307}', '+', '\u{30a}', '+', '\u{323}', '+', '\u{330}', '+', '₂', ',', '-', '-', '.', '-', '\u{307}', '-', '\u{308}', '-', '\u{313}', '-', '\u{323}', '.', '.', ',', '.', '.', '.', '.', '.', '/', '/', '/', '/', '/', '/', '/', '\u{304}', '2', '2', ',', '2', '.', '2', '2', '日', '2', '2', '点', '2', '3', '日', '2', '3', '点', '2', '4', '日', '2', '4', '点', '2', '5', '日', '2', '6', '日', '2', '7', '日', '2', '8', '日', '2', '9', '日', '2', 'O', '.', '2', 'O', '日', '2', 'O', '点', '2', 'l', '日', '2', 'l', '点', '2', '\u{335}', '2', '日', '2', '月', '2', '点', '3', '3', ',', '3', '.', '3', 'O', '日', '3', 'l', '日', '3', '\u{326}', '3', '日', '3', '月', '3', '点', '4', '4', ',', '4', '.', '4', '·', '4', '日', '4', '月', '4', '点', '5', '5', ',', '5', '.', '5', '日', '5', '月', '5', '点', '6', '6', ',', '6', '.', '6', '日', '6', '月', '6', '点', '7', '7', ',', '7', '.', '7', '日', '7', '月', '7', '点', '8', '8', ',', '8', '.', '8', '日', '8', '月', '8', '点', '9', '9', ',', '9', '.', '9', '日', '9', '月', '9', '点', ':', ':', ':', '=', ':', '→', ';', '<', '<', '<', '<', '<', '<', '<', '·', '=', '=', '=', '=', '=', '=', '=', '\u{302}', '=', '\u{306}', '=', '\u{307}', '=', '\u{30a}', '=', '\u{323}', '\u{307}', '=', '\u{36b}', '=', '\u{20f0}', '>', '>', '<', '>', '>', '>', '>', '>', '>', '·', '?', '?', '!', '?', '?', 'A', 'A', 'A', 'A', 'E', 'A', 'O', 'A', 'R', 'A', 'U', 'A', 'V', 'A', 'Y', 'A', '\u{306}', 'A', '\u{30a}', 'B', 'C', 'C', '\'', 'C', '\u{326}', 'C', '\u{20eb}', 'D', 'D', 'Z', 'D', 'Z', '\u{30c}', 'D', 'z', 'D', 'z', '\u{30c}', 'D', '\u{335}', 'E', 'E', '\u{306}', 'E', '\u{338}', 'F', 'F', 'A', 'X', 'F', '\u{326}', 'G', 'G', '\'', 'G', '\u{306}', 'G', '\u{335}', 'H', 'H', '\u{326}', 'H', '\u{329}', 'H', '\u{335}', 'I', '\u{306}', 'J', 'J', '·', 'J', '\u{335}', 'K', 'K', '\'', 'K', '\u{329}', 'K', '\u{335}', 'L', 'L', 'J', 'L', 'j', 'L', '\u{338}', 'M', 'M', 'B', 'M', '\u{326}', 'N', 'N', 'J', 'N', 'j', 'N', 'o', 'N', '\u{30a}', 'N', '\u{326}', 'O', 'O', '\'', 'O', ',', 'O', '.', 'O', 'E', 'O', 'O', 'O', '\u{306}', 'O', '\u{335}', 'O', '\u{338}', 'O', '\u{338}', '\u{301}', 'O', '点', 'P', 'P', '\'', 'Q', 'Q', 'E', 'R', 'R', 's', 'S', 'T', 'T', '3', 'T', 'E', 'L', 'T', '\u{308}', 'T', '\u{327}', 'T', '\u{328}', 'T', '\u{329}', 'T', '\u{335}', 'T', '\u{338}', 'T', '\u{20eb}', 'U', 'U', '\'', 'U', '·', 'U', '\u{306}', 'U', '\u{335}', 'V', 'V', 'B', 'V', 'l', 'V', 'l', 'l', 'V', 'l', 'l', 'l', 'V', '·', 'V', '\u{335}', 'V', '\u{1de4}', 'W', 'W', '\u{335}', 'X', 'X', 'l', 'X', 'l', 'l', 'X', '\u{329}', 'X', '\u{335}', 'Y', 'Y', '\u{335}', 'Z', 'Z', '\u{326}', 'Z', '\u{335}', '\\', '\\', '\\', '\\', 'ᑕ', '^', '_', 'a', 'a', '/', 'c', 'a', '/', 's', 'a', 'a', 'a', 'e', 'a', 'o', 'a', 'u', 'a', 'v', 'a', 'y', 'a', '\u{306}', 'a', '\u{309}', 'a', '\u{30a}', 'a', '\u{332}', 'b', 'b', 'l', 'b', '\u{304}', 'b', '\u{314}', 'b', '\u{335}', 'c', 'c', '/', 'o', 'c', '/', 'u', 'c', '\u{326}', 'c', '\u{338}', 'd', 'd', '\'', 'd', 'z', 'd', 'z', '\u{30c}', 'd', '·', 'd', 'ȝ', 'd', 'ʑ', 'd', '\u{304}', 'd', '\u{314}', 'd', '\u{328}', 'd', '\u{335}', 'd', '\u{335}', '\u{331}', 'e', 'e', '\u{306}', 'e', '\u{328}', 'e', '\u{338}', 'f', 'f', 'f', 'f', 'f', 'i', 'f', 'f', 'l', 'f', 'i', 'f', 'l', 'f', 'ŋ', 'f', '\u{326}', 'f', '\u{334}', 'g', 'g', '\u{306}', 'g', '\u{314}', 'g', '\u{327}', 'g', '\u{335}', 'h', 'h', '\u{314}', 'h', '\u{335}', 'i', 'i', 'i', 'i', 'i', 'i', 'i', 'j', 'i', 'v', 'i', 'x', 'i', '\u{306}', 'i', '\u{332}', 'i', '\u{335}', 'j', 'j', '\u{335}', 'k', 'k', '\u{314}', 'l', 'l', '\'', 'l', ',', 'l', '.', 'l', '2', '.', 'l', '2', '日', 'l', '2', '月', 'l', '2', '点', 'l', '3', '.', 'l', '3', '日', 'l', '3', '点', 'l', '4', '.', 'l', '4', '日', 'l', '4', '点', 'l', '5', '.', 'l', '5', '日', 'l', '5', '点', 'l', '6', '.', 'l', '6', '日', 'l', '6', '点', 'l', '7', '.', 'l', '7', '日', 'l', '7', '点', 'l', '8', '.', 'l', '8', '日', 'l', '8', '点', 'l', '9', '.', 'l', '9', '日', 'l', '9', '点', 'l', 'J', 'l', 'O', 'l', 'O', '.', 'l', 'O', '日', 'l', 'O', '月', 'l', 'O', '点', 'l', 'V', 'l', 'X', 'l', 'j', 'l', 'l', 'l', 'l', '.', 'l', 'l', 'l', 'l', 'l', '日', 'l', 'l', '月', 'l', 'l', '点', 'l', 's', 'l', 't', 'l', 'z', 'l', '·', 'l', 'ȝ', 'l', '\u{30b}', 'l', '\u{328}', 'l', '\u{334}', 'l', '\u{335}', 'l', '\u{335}', 'l', '\u{335}', 'l', '\u{335}', 'l', '\u{335}', 'S', '\u{335}', 'l', '\u{338}', 'l', 'ك', 'ب', 'ر', 'l', 'ل', 'ل', '\u{651}', '\u{670}', 'o', 'l', '\u{655}', 'l', 'ٴ', 'l', '日', 'l', '月', 'l', '点', 'n', 'n', 'j', 'n', '\u{328}', 'n', '\u{329}', 'n', '\u{334}', 'o', 'o', '\'', 'o', 'e', 'o', 'o', 'o', '\u{302}', 'o', '\u{306}', 'o', '\u{31b}', 'o', '\u{335}', 'o', '\u{338}', 'o', 'ج', 'o', 'م', 'o', 'م', 'ج', 'o', 'م', 'م', 'o', 'ى', 'o', '\u{670}', 'o', 'ര', 'o', 'o', 'ာ', 'o', 'ᴇ', 'p', 'p', '·', 'p', '\u{314}', 'p', '\u{335}', 'q', 'q', '\u{314}', 'r', 'r', '\'', 'r', 'n', 'r', 'n', '\u{326}', 'r', 'n', '\u{334}', 'r', 'n', '\u{338}', 'r', '\u{328}', 'r', '\u{329}', 'r', '\u{334}', 'r', '\u{335}', 's', 's', 's', 's', 's', 't', 's', '\u{328}', 's', '\u{334}', 't', 't', 'f', 't', 's', 't', 'ȝ', 't', 'ɕ', 't', 'ʃ', 't', '\u{314}', 't', '\u{334}', 't', '\u{335}', 'u', 'u', 'e', 'u', 'o', 'u', '\u{306}', 'u', '\u{335}', 'v', 'v', 'i', 'v', 'i', 'i', 'v', 'i', 'i', 'i', 'w', 'w', '\u{307}', 'w', '\u{326}', 'w', '\u{486}', '\u{487}', 'x', 'x', 'i', 'x', 'i', 'i', 'x', '\u{307}', 'y', 'y', '\u{314}', 'y', '\u{335}', 'z', 'z', '\u{326}', 'z', '\u{328}', 'z', '\u{334}', 'z', '\u{335}', '{', '}', '~', '~', '\u{307}', '~', '\u{308}', '~', '\u{323}', '£', '©', '®', '°', '°', 'C', '°', 'F', '°', '\u{308}', '°', '\u{332}', '¶', '·', '·', '4', '·', '<', '·', '>', '·', 'J', '·', 'L', '·', 'P', '·', 'U', '·', 'V', '·', 'd', '·', '·', '·', '·', 'Ʌ', '·', 'Γ', '·', 'Δ', '·', 'Ո', '·', 'ᐁ', '·', 'ᐄ', '·', 'ᐅ', '·', 'ᐆ', '·', 'ᐊ', '·', 'ᐋ', '·', 'ᐲ', '·', 'ᐴ', '·', 'ᐹ', '·', 'ᑏ', '·', 'ᑐ', '·', 'ᑑ', '·', 'ᑕ', '·', 'ᑖ', '·', 'ᑫ', '·', 'ᑮ', '·', 'ᑰ', '·', 'ᑲ', '·', 'ᑳ', '·', 'ᒉ', '·', 'ᒋ', '·', 'ᒌ', '·', 'ᒎ', '·', 'ᒐ', '·', 'ᒑ', '·', 'ᒣ', '·', 'ᒦ', '·', 'ᒧ', '·', 'ᒨ', '·', 'ᒫ', '·', 'ᓀ', '·', 'ᓂ', '·', 'ᓃ', '·', 'ᓄ', '·', 'ᓅ', '·', 'ᓇ', '·', 'ᓈ', '·', 'ᓓ', '·', 'ᓕ', '·', 'ᓖ', '·', 'ᓗ', '·', 'ᓘ', '·', 'ᓚ', '·', 'ᓛ', '·', 'ᓭ', '·', 'ᓯ', '·', 'ᓰ', '·', 'ᓱ', '·', 'ᓲ', '·', 'ᓴ', '·', 'ᓵ', '·', 'ᔐ', '·', 'ᔑ', '·', 'ᔒ', '·', 'ᔓ', '·', 'ᔔ', '·', 'ᔕ', '·', 'ᔖ', '·', 'ᔨ', '·', 'ᔩ', '·', 'ᔪ', '·', 'ᔫ', '·', 'ᔭ', '·', 'ᔮ', '·', 'ᕃ', '·', 'ᕆ', '·', 'ᕇ', '·', 'ᕈ', '·', 'ᕉ', '·', 'ᕋ', '·', 'ᕌ', '·', 'ᕚ', '·', 'ᕧ', '·', 'ᢱ', '·', 'ᢴ', '·', 'ᢸ', '·', 'ᣀ', 'º', 'º', '/', '₀', 'º', '/', '₀', '₀', 'º', '/', '₀', '₀', '₀', 'Þ', 'ß', '÷', 'þ', 'ĸ', 'ĸ', '\u{329}', 'ĸ', '\u{335}', 'ƅ', 'ƅ', 'i', 'Ɔ', 'Ǝ', 'Ə', 'Ɛ', 'ƨ', 'Ʃ', 'ƫ', 'Ʊ', 'ǝ', 'ǝ', 'o', 'ǝ', 'o', '\u{335}', 'ǝ', 'o', '\u{338}', 'ǝ', '˞', 'Ƕ', 'ȝ', 'ȷ', 'ɂ', 'Ʌ', 'Ʌ', '·', 'Ʌ', '\u{326}', 'ɋ', 'ɔ', 'ɔ', 'e', 'ɔ', '\u{338}', 'ɜ', 'ɜ', '\u{326}', 'ɞ', 'ɢ', 'ɰ', 'ɲ', 'ɷ', 'ɸ', 'ɾ', '\u{334}', 'ɿ', 'ʀ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʃ', 'ʊ', '\u{335}', 'ʌ', 'ʍ', 'ʍ', '\u{326}', 'ʘ', 'ʙ', 'ʚ', 'ʜ', 'ʜ', '\u{326}', 'ʜ', '\u{329}', 'ʟ', 'ʡ', 'ˁ', 'ˇ', 'ˉ', 'ˉ', 'b', 'ˉ', 'b', 'i', 'ˏ', '˪', '˫', '˳', '\u{300}', '\u{301}', '\u{302}', '\u{303}', '\u{304}', '\u{306}', '\u{306}', '\u{307}', '\u{307}', '\u{308}', '\u{309}', '\u{30a}', '\u{30a}', 'า', '\u{30a}', 'າ', '\u{30b}', '\u{30e}', '\u{312}', '\u{313}', '\u{314}', '\u{316}', '\u{323}', '\u{324}', '\u{325}', '\u{326}', '\u{328}', '\u{329}', '\u{32b}', '\u{32d}', '\u{32e}', '\u{331}', '\u{333}', '\u{335}', '\u{338}', '\u{350}', '\u{352}', '\u{354}', '\u{355}', '\u{363}', '\u{364}', '\u{368}', '\u{36f}', 'Γ', 'Γ', '\'', 'Γ', '·', 'Γ', '\u{335}', 'Δ', 'Δ', '·', 'Δ', '\u{332}', 'Δ', 'ᐠ', 'Ξ', 'Π', 'Φ', 'Ψ', 'Ω', 'ζ', 'λ', 'μ', 'ξ', 'π', 'ς', 'χ', 'ψ', 'ω', 'ω', '\u{301}', '\u{345}', 'ω', '\u{332}', 'ϗ', 'Ϙ', 'ϝ', 'Ϭ', 'Ћ', 'Ж', '\u{329}', 'И', 'И', '\u{300}', 'И', '\u{326}', '\u{300}', 'Ш', 'Ъ', 'l', 'Э', 'ж', '\u{329}', 'и', '\u{306}', 'и', '\u{326}', '\u{306}', 'л', 'л', '\u{326}', 'ш', 'љ', 'Ѡ', 'Ѡ', '·', 'Ѡ', '\u{486}', '\u{487}', 'Ҷ', 'ҷ', 'Ҽ', '\u{328}', 'Ӄ', 'Ӿ', 'Ո', 'Ո', '\'', 'Ո', '·', 'Ք', 'ՙ', 'ե', 'ւ', 'ձ', 'մ', 'ե', 'մ', 'ի', 'մ', 'խ', 'մ', 'ն', 'վ', 'ն', '\u{596}', '\u{598}', '\u{599}', '\u{59a}', 'א', 'א', '\u{5b7}', 'א', 'ל', 'ב', 'ג', 'ד', 'ה', 'י', '\u{5b4}', 'כ', 'ל', 'ם', 'ע', 'ר', 'ש', '\u{5bc}', '\u{5c1}', 'ש', '\u{5c1}', 'ת', '،', '؛', '؟', 'ء', 'ء', '\u{348}', 'ا', '\u{653}', 'ب', 'ب', 'o', 'ب', 'ج', 'ب', 'ح', 'ب', 'ح', 'ى', 'ب', 'خ', 'ب', 'خ', 'ى', 'ب', 'ر', 'ب', 'ز', 'ب', 'م', 'ب', 'ن', 'ب', 'ى', 'ب', '\u{654}', 'ب', '\u{6db}', 'ب', '\u{6e2}', 'ة', 'ت', 'ت', 'o', 'ت', 'ج', 'ت', 'ج', 'م', 'ت', 'ج', 'ى', 'ت', 'ح', 'ت', 'ح', 'ج', 'ت', 'ح', 'م', 'ت', 'خ', 'ت', 'خ', 'م', 'ت', 'خ', 'ى', 'ت', 'ر', 'ت', 'ز', 'ت', 'م', 'ت', 'م', 'ج', 'ت', 'م', 'ح', 'ت', 'م', 'خ', 'ت', 'م', 'ى', 'ت', 'ن', 'ت', 'ى', 'ج', 'ج', 'ح', 'ج', 'ح', 'ى', 'ج', 'ل', ' ', 'ج', 'ل', 'l', 'ل', 'o', 'ج', 'م', 'ج', 'م', 'ح', 'ج', 'م', 'ى', 'ج', 'ى', 'ح', 'ح', 'ج', 'ح', 'ج', 'ى', 'ح', 'م', 'ح', 'م', 'ى', 'ح', 'ى', 'ح', '\u{654}', 'ح', '\u{6db}', 'خ', 'خ', 'ج', 'خ', 'ح', 'خ', 'م', 'خ', 'ى', 'د', 'د', '\u{302}', 'د', '\u{324}', '\u{323}', 'د', '\u{615}', 'د', '\u{6db}', 'ذ', 'ذ', '\u{670}', 'ر', 'ر', '\u{302}', 'ر', '\u{306}', 'ر', '\u{306}', '\u{307}', 'ر', '\u{615}', 'ر', 'س', 'و', 'ل', 'ر', 'ى', 'l', 'ل', 'ر', '\u{654}', 'ر', '\u{670}', 'ر', '\u{6db}', 'ز', 'ز', '\u{302}', 'س', 'س', 'o', 'س', '\u{302}', 'س', 'ج', 'س', 'ج', 'ح', 'س', 'ج', 'ى', 'س', 'ح', 'س', 'ح', 'ج', 'س', 'خ', 'س', 'خ', 'ى', 'س', 'ر', 'س', 'م', 'س', 'م', 'ج', 'س', 'م', 'ح', 'س', 'م', 'م', 'س', 'ى', 'س', '\u{6db}', 'س', '\u{6db}', 'o', 'س', '\u{6db}', 'ج', 'س', '\u{6db}', 'ج', 'ى', 'س', '\u{6db}', 'ح', 'س', '\u{6db}', 'ح', 'م', 'س', '\u{6db}', 'ح', 'ى', 'س', '\u{6db}', 'خ', 'س', '\u{6db}', 'ر', 'س', '\u{6db}', 'م', 'س', '\u{6db}', 'م', 'خ', 'س', '\u{6db}', 'م', 'م', 'س', '\u{6db}', 'ى', 'ص', 'ص', '\u{324}', '\u{323}', 'ص', 'ح', 'ص', 'ح', 'ح', 'ص', 'ح', 'ى', 'ص', 'خ', 'ص', 'ر', 'ص', 'ل', 'ع', 'م', 'ص', 'ل', 'ى', 'ص', 'ل', 'ى', ' ', 'l', 'ل', 'ل', 'o', ' ', 'ع', 'ل', 'ى', 'o', ' ', 'و', 'س', 'ل', 'م', 'ص', 'م', 'ص', 'م', 'م', 'ص', 'ى', 'ص', '\u{6db}', 'ض', 'ض', 'ج', 'ض', 'ح', 'ض', 'ح', 'ى', 'ض', 'خ', 'ض', 'خ', 'م', 'ض', 'ر', 'ض', 'م', 'ض', 'ى', 'ط', 'ط', 'ح', 'ط', 'م', 'ط', 'م', 'ح', 'ط', 'م', 'م', 'ط', 'م', 'ى', 'ط', 'ى', 'ط', '\u{6db}', 'ظ', 'ظ', 'م', 'ع', 'ع', 'ج', 'ع', 'ج', 'م', 'ع', 'ل', 'ى', 'o', 'ع', 'م', 'ع', 'م', 'م', 'ع', 'م', 'ى', 'ع', 'ى', 'غ', 'غ', 'ج', 'غ', 'م', 'غ', 'م', 'م', 'غ', 'م', 'ى', 'غ', 'ى', 'ف', 'ف', 'ج', 'ف', 'ح', 'ف', 'خ', 'ف', 'خ', 'م', 'ف', 'م', 'ف', 'م', 'ى', 'ف', 'ى', 'ق', 'ق', 'ح', 'ق', 'ل', 'ى', 'ق', 'م', 'ق', 'م', 'ح', 'ق', 'م', 'م', 'ق', 'م', 'ى', 'ق', 'ى', 'ك', 'ك', 'l', 'ك', 'ج', 'ك', 'ح', 'ك', 'خ', 'ك', 'ل', 'ك', 'م', 'ك', 'م', 'م', 'ك', 'م', 'ى', 'ك', 'ى', 'ك', '\u{6db}', 'ل', 'ل', 'l', 'ل', 'l', '\u{655}', 'ل', 'l', 'ٴ', 'ل', 'o', 'ل', '\u{306}', 'ل', 'ا', '\u{653}', 'ل', 'ج', 'ل', 'ج', 'ج', 'ل', 'ج', 'م', 'ل', 'ج', 'ى', 'ل', 'ح', 'ل', 'ح', 'م', 'ل', 'ح', 'ى', 'ل', 'خ', 'ل', 'خ', 'م', 'ل', 'م', 'ل', 'م', 'ح', 'ل', 'م', 'ى', 'ل', 'ى', 'ل', '\u{6db}', 'م', 'م', 'l', 'م', '\u{348}', 'م', 'ج', 'م', 'ج', 'ح', 'م', 'ج', 'خ', 'م', 'ج', 'م', 'م', 'ج', 'ى', 'م', 'ح', 'م', 'ح', 'ج', 'م', 'ح', 'م', 'م', 'ح', 'م', 'د', 'م', 'ح', 'ى', 'م', 'خ', 'م', 'خ', 'ج', 'م', 'خ', 'م', 'م', 'خ', 'ى', 'م', 'م', 'م', 'م', 'ى', 'م', 'ى', 'م', '\u{6db}', 'ن', 'ن', 'o', 'ن', '\u{306}', 'ن', '\u{615}', 'ن', 'ج', 'ح', 'ن', 'ج', 'م', 'ن', 'ج', 'ى', 'ن', 'ح', 'ن', 'ح', 'م', 'ن', 'ح', 'ى', 'ن', 'خ', 'ن', 'ر', 'ن', 'ز', 'ن', 'م', 'ن', 'م', 'ى', 'ن', 'ن', 'ن', 'ى', 'و', 'و', '\u{302}', 'و', '\u{306}', 'و', '\u{313}', 'و', '\u{313}', 'ٴ', 'و', 'س', 'ل', 'م', 'و', '\u{670}', 'و', 'ٴ', 'و', '\u{6db}', 'ى', 'ى', 'o', 'ى', '\u{302}', 'ى', '\u{306}', 'ى', '\u{306}', '\u{307}', 'ى', '\u{615}', 'ى', 'ج', 'ى', 'ج', 'ى', 'ى', 'ح', 'ى', 'ح', 'ى', 'ى', 'خ', 'ى', 'ر', 'ى', 'ز', 'ى', 'م', 'ى', 'م', 'م', 'ى', 'م', 'ى', 'ى', 'ن', 'ى', 'ى', 'ى', '\u{654}', 'ى', '\u{670}', 'ى', 'ٴ', 'ى', 'ٴ', 'l', 'ى', 'ٴ', 'o', 'ى', 'ٴ', 'ج', 'ى', 'ٴ', 'ح', 'ى', 'ٴ', 'خ', 'ى', 'ٴ', 'ر', 'ى', 'ٴ', 'ز', 'ى', 'ٴ', 'م', 'ى', 'ٴ', 'ن', 'ى', 'ٴ', 'و', 'ى', 'ٴ', 'و', '\u{306}', 'ى', 'ٴ', 'و', '\u{313}', 'ى', 'ٴ', 'و', '\u{670}', 'ى', 'ٴ', 'ى', 'ى', 'ٴ', 'ٻ', 'ى', '\u{6db}', 'ى', '\u{6db}', 'o', 'ى', '\u{6db}', 'ج', 'ى', '\u{6db}', 'ر', 'ى', '\u{6db}', 'ز', 'ى', '\u{6db}', 'م', 'ى', '\u{6db}', 'ن', 'ى', '\u{6db}', 'ى', 'ى', '\u{6db}', '\u{6e2}', '\u{64c}', '\u{64d}', '\u{650}', '\u{655}', '٢', '٣', '٤', '٦', '٩', '\u{670}', 'ٱ', 'ٺ', 'ٻ', 'ٿ', 'ڀ', 'ڃ', 'ڄ', 'چ', 'ڇ', 'ڊ', '\u{615}', 'ڌ', 'ڍ', 'ڗ', '\u{615}', 'ڡ', 'ڡ', '\u{6db}', 'ڢ', '\u{6db}', 'ڦ', 'ڬ', 'گ', 'گ', '\u{6db}', 'ڱ', 'ڳ', 'ۅ', 'ے', '\u{654}', 'ە', '\u{654}', '\u{6db}', '\u{73c}', 'ݔ', 'अ', 'ा', 'अ', 'ा', '\u{946}', 'अ', 'ा', '\u{947}', 'अ', 'ा', '\u{948}', 'अ', '\u{946}', 'अ', 'ॉ', 'ए', '\u{945}', 'ए', '\u{946}', 'ए', '\u{947}', 'र', '\u{94d}', 'इ', '\u{93a}', 'ऽ', '\u{941}', '\u{942}', '\u{946}', '\u{94d}', '।', '।', '।', '२', '३', '४', '८', '॰', 'ॱ', 'ঃ', 'অ', '\u{9be}', 'ঋ', '\u{9c3}', 'ঘ', 'চ', 'জ', 'ঞ', 'ট', 'ড', 'ণ', 'ত', 'থ', 'দ', 'ধ', 'ন', 'প', 'ব', 'ম', 'য', 'র', 'ল', 'ষ', 'স', 'ঽ', '\u{9be}', 'ি', 'ে', 'ে', '\u{9be}', 'ে', '\u{9d7}', '\u{9cd}', '\u{9d7}', '১', '২', '৬', 'ਅ', 'ਾ', 'ਅ', '\u{a48}', 'ਅ', '\u{a4c}', 'ੲ', 'ਿ', 'ੲ', 'ੀ', 'ੲ', '\u{a47}', 'ੳ', '\u{a41}', 'ੳ', '\u{a42}', 'અ', 'ા', 'અ', 'ા', '\u{ac5}', 'અ', 'ા', '\u{ac7}', 'અ', 'ા', '\u{ac8}', 'અ', '\u{ac5}', 'અ', '\u{ac7}', 'અ', '\u{ac8}', 'ଅ', '\u{b3e}', 'அ', 'ஈ', 'ஈ', 'ு', 'உ', 'உ', 'ள', 'உ', '\u{d57}', 'எ', 'எ', 'வ', 'ஐ', 'க', 'ச', 'ச', 'ு', 'ச', 'ூ', 'ட', 'ி', 'ண', 'ந', '\u{bc0}', 'ன', 'ம', '\u{bc0}', 'ய', 'ள', 'ழ', 'ஶ', 'ஷ', 'ி', 'ெ', 'ஈ', 'ெ', 'ள', 'ே', 'ஈ', '௳', 'అ', 'ఆ', 'ఇ', 'ఋ', '\u{c3e}', 'ఌ', '\u{c3e}', 'ఒ', 'ఒ', '\u{c4c}', 'ఒ', '\u{c55}', 'జ', 'ఞ', 'డ', '\u{323}', 'ణ', 'ధ', '\u{5bc}', 'బ', '\u{323}', 'య', 'ర', '\u{5bc}', 'ఱ', 'ల', 'వ', '\u{323}', 'వ', '\u{c3e}', 'వ', 'ు', 'ు', '\u{c3e}', 'ృ', '\u{c3e}', '౧', '౨', '౯', 'ಌ', 'ಾ', 'ഇ', '\u{d57}', 'എ', 'െ', 'ഒ', '\u{d3e}', 'ഒ', '\u{d57}', 'ഞ', 'ദ', '\u{d4d}', 'ര', 'ന', '\u{d41}', 'ന', '\u{d4d}', 'ന', '\u{d4d}', 'ന', 'ന', '\u{d4d}', 'മ', 'ര', 'ര', '\u{d4d}', 'വ', '\u{d4d}', 'ര', 'ഹ', '\u{d4d}', 'മ', '\u{d41}', 'െ', 'െ', 'ජ', 'ද', '෨', '\u{dcf}', '෨', '\u{dd3}', 'ข', 'ค', 'ฆ', 'จ', 'ช', 'ฎ', 'ฑ', 'บ', 'ป', 'ฝ', 'พ', 'ฟ', 'ภ', 'ย', 'ฯ', 'า', '\u{e34}', '\u{e35}', '\u{e36}', '\u{e37}', '\u{e38}', '\u{e39}', 'เ', 'เ', '\u{e48}', '\u{e49}', '\u{e4a}', '\u{e4b}', '๏', '๚', '๛', 'ຫ', 'ນ', 'ຫ', 'ມ', '་', '།', '།', '༚', '༚', '༚', '༝', '༝', '༚', '༝', '༝', 'འ', '\u{f74}', '\u{f82}', '༔', 'འ', '\u{f74}', '\u{f82}', 'ཿ', 'ར', 'ཨ', '\u{f7c}', '\u{f7e}', '\u{fb2}', '\u{f71}', '\u{f80}', '\u{fb3}', '\u{f71}', '\u{f80}', 'ဂ', 'ာ', 'ဂ', '\u{103e}', 'ဃ', '\u{103e}', 'ပ', 'ာ', 'ပ', 'ာ', '\u{103e}', 'ပ', '\u{103e}', 'သ', 'ြ', 'သ', 'ြ', 'ေ', 'ာ', '\u{103a}', '၁', '၊', '၊', 'ၽ', '\u{103e}', 'ႃ', '\u{30a}', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄀ', 'ᄂ', 'ᄀ', 'ᄃ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄇ', 'ᄀ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄀ', 'ᄀ', 'ᄎ', 'ᄀ', 'ᄏ', 'ᄀ', 'ᄒ', 'ᄂ', 'ᄂ', 'ᄀ', 'ᄂ', 'ᄂ', 'ᄂ', 'ᄃ', 'ᄂ', 'ᄅ', 'ᄂ', 'ᄇ', 'ᄂ', 'ᄉ', 'ᄂ', 'ᄌ', 'ᄂ', 'ᄎ', 'ᄂ', 'ᄐ', 'ᄂ', 'ᄒ', 'ᄂ', 'ᅀ', 'ᄃ', 'ᄃ', 'ᄀ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄆ', 'ᄃ', 'ᄇ', 'ᄃ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄀ', 'ᄃ', 'ᄌ', 'ᄃ', 'ᄎ', 'ᄃ', 'ᄐ', 'ᄅ', 'ᄅ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄀ', 'ᄅ', 'ᄀ', 'ᄉ', 'ᄅ', 'ᄀ', 'ᄒ', 'ᄅ', 'ᄂ', 'ᄅ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄃ', 'ᄅ', 'ᄃ', 'ᄒ', 'ᄅ', 'ᄅ', 'ᄅ', 'ᄅ', 'ᄏ', 'ᄅ', 'ᄆ', 'ᄅ', 'ᄆ', 'ᄀ', 'ᄅ', 'ᄆ', 'ᄉ', 'ᄅ', 'ᄆ', 'ᄒ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄃ', 'ᄅ', 'ᄇ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄉ', 'ᄅ', 'ᄇ', 'ᄋ', 'ᄅ', 'ᄇ', 'ᄑ', 'ᄅ', 'ᄇ', 'ᄒ', 'ᄅ', 'ᄉ', 'ᄅ', 'ᄉ', 'ᄉ', 'ᄅ', 'ᄋ', 'ᄅ', 'ᄌ', 'ᄅ', 'ᄏ', 'ᄅ', 'ᄐ', 'ᄅ', 'ᄑ', 'ᄅ', 'ᄒ', 'ᄅ', 'ᅀ', 'ᄅ', 'ᅌ', 'ᄅ', 'ᅙ', 'ᄅ', 'ᅙ', 'ᄒ', 'ᄆ', 'ᄆ', 'ᄀ', 'ᄆ', 'ᄂ', 'ᄆ', 'ᄂ', 'ᄂ', 'ᄆ', 'ᄃ', 'ᄆ', 'ᄅ', 'ᄆ', 'ᄆ', 'ᄆ', 'ᄇ', 'ᄆ', 'ᄇ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄉ', 'ᄆ', 'ᄋ', 'ᄆ', 'ᄌ', 'ᄆ', 'ᄎ', 'ᄆ', 'ᄒ', 'ᄆ', 'ᅀ', 'ᄇ', 'ᄇ', 'ᄀ', 'ᄇ', 'ᄂ', 'ᄇ', 'ᄃ', 'ᄇ', 'ᄅ', 'ᄇ', 'ᄅ', 'ᄑ', 'ᄇ', 'ᄆ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄇ', 'ᄋ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄀ', 'ᄇ', 'ᄉ', 'ᄃ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄌ', 'ᄇ', 'ᄉ', 'ᄐ', 'ᄇ', 'ᄋ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄎ', 'ᄇ', 'ᄏ', 'ᄇ', 'ᄐ', 'ᄇ', 'ᄑ', 'ᄇ', 'ᄒ', 'ᄉ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄂ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄅ', 'ᄉ', 'ᄆ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄇ', 'ᄀ', 'ᄉ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄀ', 'ᄉ', 'ᄉ', 'ᄃ', 'ᄉ', 'ᄉ', 'ᄇ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄉ', 'ᄋ', 'ᄉ', 'ᄌ', 'ᄉ', 'ᄎ', 'ᄉ', 'ᄏ', 'ᄉ', 'ᄐ', 'ᄉ', 'ᄑ', 'ᄉ', 'ᅀ', 'ᄋ', 'ᄋ', 'ᄀ', 'ᄋ', 'ᄀ', 'ᄀ', 'ᄋ', 'ᄃ', 'ᄋ', 'ᄅ', 'ᄋ', 'ᄆ', 'ᄋ', 'ᄇ', 'ᄋ', 'ᄉ', 'ᄋ', 'ᄋ', 'ᄋ', 'ᄌ', 'ᄋ', 'ᄎ', 'ᄋ', 'ᄏ', 'ᄋ', 'ᄐ', 'ᄋ', 'ᄑ', 'ᄋ', 'ᄒ', 'ᄋ', 'ᅀ', 'ᄌ', 'ᄌ', 'ᄇ', 'ᄌ', 'ᄇ', 'ᄇ', 'ᄌ', 'ᄋ', 'ᄌ', 'ᄌ', 'ᄌ', 'ᄌ', 'ᄒ', 'ᄎ', 'ᄎ', 'ᄏ', 'ᄎ', 'ᄒ', 'ᄏ', 'ᄐ', 'ᄐ', 'ᄐ', 'ᄑ', 'ᄑ', 'ᄇ', 'ᄑ', 'ᄉ', 'ᄑ', 'ᄋ', 'ᄑ', 'ᄐ', 'ᄑ', 'ᄒ', 'ᄒ', 'ᄒ', 'ᄂ', 'ᄒ', 'ᄅ', 'ᄒ', 'ᄆ', 'ᄒ', 'ᄇ', 'ᄒ', 'ᄉ', 'ᄒ', 'ᄒ', 'ᄼ', 'ᄼ', 'ᄾ', 'ᄾ', 'ᅀ', 'ᅀ', 'ᄇ', 'ᅀ', 'ᄇ', 'ᄋ', 'ᅌ', 'ᅌ', 'ᄆ', 'ᅌ', 'ᄒ', 'ᅎ', 'ᅎ', 'ᅐ', 'ᅐ', 'ᅙ', 'ᅙ', 'ᅙ', 'ᅠ', 'ᅡ', 'ᅡ', 'ᅩ', 'ᅡ', 'ᅮ', 'ᅡ', 'ー', 'ᅡ', '丨', 'ᅣ', 'ᅣ', 'ᅩ', 'ᅣ', 'ᅭ', 'ᅣ', 'ᅮ', 'ᅣ', '丨', 'ᅥ', 'ᅥ', 'ᅩ', 'ᅥ', 'ᅮ', 'ᅥ', 'ー', 'ᅥ', '丨', 'ᅧ', 'ᅧ', 'ᅣ', 'ᅧ', 'ᅩ', 'ᅧ', 'ᅮ', 'ᅧ', '丨', 'ᅩ', 'ᅩ', 'ᅡ', 'ᅩ', 'ᅡ', '丨', 'ᅩ', 'ᅣ', 'ᅩ', 'ᅣ', '丨', 'ᅩ', 'ᅥ', 'ᅩ', 'ᅥ', '丨', 'ᅩ', 'ᅧ', 'ᅩ', 'ᅧ', '丨', 'ᅩ', 'ᅩ', 'ᅩ', 'ᅩ', '丨', 'ᅩ', 'ᅮ', 'ᅩ', '丨', 'ᅭ', 'ᅭ', 'ᅡ', 'ᅭ', 'ᅡ', '丨', 'ᅭ', 'ᅣ', 'ᅭ', 'ᅣ', '丨', 'ᅭ', 'ᅥ', 'ᅭ', 'ᅩ', 'ᅭ', '丨', 'ᅮ', 'ᅮ', 'ᅡ', 'ᅮ', 'ᅡ', '丨', 'ᅮ', 'ᅥ', 'ᅮ', 'ᅥ', 'ー', 'ᅮ', 'ᅥ', '丨', 'ᅮ', 'ᅧ', 'ᅮ', 'ᅧ', '丨', 'ᅮ', 'ᅮ', 'ᅮ', '丨', 'ᅮ', '丨', '丨', 'ᅲ', 'ᅲ', 'ᅡ', 'ᅲ', 'ᅡ', '丨', 'ᅲ', 'ᅥ', 'ᅲ', 'ᅥ', '丨', 'ᅲ', 'ᅧ', 'ᅲ', 'ᅧ', '丨', 'ᅲ', 'ᅩ', 'ᅲ', 'ᅮ', 'ᅲ', '丨', 'ᆞ', 'ᆞ', 'ᅡ', 'ᆞ', 'ᅥ', 'ᆞ', 'ᅥ', '丨', 'ᆞ', 'ᅮ', 'ᆞ', 'ᆞ', 'ᆞ', '丨', 'ሆ', 'ቡ', 'ኮ', 'ጣ', 'Ꮿ', 'ᐁ', '·', 'ᐁ', 'ᐠ', 'ᐄ', '·', 'ᐅ', '·', 'ᐅ', 'ᐠ', 'ᐆ', '·', 'ᐊ', '·', 'ᐊ', 'ᐠ', 'ᐋ', '·', 'ᐞ', 'ᣟ', 'ᐡ', 'ᐩ', 'ᐲ', '·', 'ᐴ', '·', 'ᐵ', 'ᐹ', '·', 'ᑏ', '·', 'ᑐ', 'ᑐ', '\'', 'ᑐ', '/', 'ᑐ', '·', 'ᑐ', 'ᑕ', 'ᑑ', '·', 'ᑕ', 'ᑕ', '\'', 'ᑕ', '·', 'ᑖ', '·', 'ᑫ', '\'', 'ᑫ', '·', 'ᑮ', '·', 'ᑰ', '·', 'ᑲ', '\'', 'ᑲ', '·', 'ᑳ', '·', 'ᒉ', 'ᒉ', '·', 'ᒋ', '·', 'ᒌ', '·', 'ᒎ', '·', 'ᒐ', 'ᒐ', '·', 'ᒑ', '·', 'ᒣ', '·', 'ᒦ', '·', 'ᒧ', '·', 'ᒨ', '·', 'ᒫ', '·', 'ᓀ', '·', 'ᓂ', '·', 'ᓃ', '·', 'ᓄ', '·', 'ᓅ', '·', 'ᓇ', '·', 'ᓈ', '·', 'ᓓ', 'ᓓ', '·', 'ᓕ', '·', 'ᓖ', '·', 'ᓗ', '·', 'ᓘ', '·', 'ᓚ', 'ᓚ', '·', 'ᓛ', '·', 'ᓭ', '·', 'ᓯ', '·', 'ᓰ', '·', 'ᓱ', '·', 'ᓲ', '·', 'ᓴ', '·', 'ᓵ', '·', 'ᔋ', '<', 'ᔋ', 'ᑕ', 'ᔋ', 'ᑲ', 'ᔋ', 'ᒐ', 'ᔐ', '·', 'ᔑ', '·', 'ᔒ', '·', 'ᔓ', '·', 'ᔔ', '·', 'ᔕ', '·', 'ᔖ', '·', 'ᔨ', '·', 'ᔩ', '·', 'ᔪ', '·', 'ᔫ', '·', 'ᔭ', '·', 'ᔮ', '·', 'ᕃ', 'ᕃ', '·', 'ᕆ', 'ᕊ', 'ᕌ', '·', 'ᕐ', 'P', 'ᕐ', 'd', 'ᕐ', 'ᑫ', 'ᕐ', 'ᑬ', 'ᕐ', 'ᑮ', 'ᕐ', 'ᑰ', 'ᕐ', 'ᑲ', 'ᕐ', 'ᑳ', 'ᕐ', 'ᒃ', 'ᕚ', '·', 'ᕞ', '·', 'ᕦ', '·', 'ᕧ', '·', 'ᕫ', '·', 'ᖆ', '·', 'ᖕ', 'J', 'ᖕ', 'ᒉ', 'ᖕ', 'ᒊ', 'ᖕ', 'ᒋ', 'ᖕ', 'ᒌ', 'ᖕ', 'ᒎ', 'ᖕ', 'ᒐ', 'ᖕ', 'ᒑ', 'ᖖ', 'J', 'ᖖ', 'ᒋ', 'ᖖ', 'ᒌ', 'ᖖ', 'ᒎ', 'ᖖ', 'ᒐ', 'ᖖ', 'ᒑ', 'ᖗ', '·', 'ᖧ', '·', 'ᖨ', '·', 'ᖩ', '·', 'ᖪ', '·', 'ᖫ', '·', 'ᖬ', '·', 'ᖭ', '·', 'ᗒ', 'ᗕ', 'ᗡ', 'ᗴ', '·', 'ᘛ', '·', 'ᙆ', 'ᙠ', 'ᚹ', 'ᚼ', 'ᚽ', 'ᛋ', 'ᛏ', 'ᛐ', 'ᛐ', 'ᛚ', 'ᛐ', '⇂', 'ᛚ', 'ᛜ', 'ᛜ', '\u{332}', 'ᛞ', 'ᛦ', 'ᛨ', 'ᛯ', 'អ', 'ᠵ', 'ᡜ', 'ᣔ', 'ᣖ', 'ᣟ', 'ᐞ', 'ᣳ', 'ᣴ', 'ᣵ', 'ᦞ', 'ᦱ', 'ᩅ', '᪨', '᪨', '᪪', '᪨', 'ᬍ', 'ᬑ', 'ᬨ', '᭐', '᭞', '᭞', '᰻', '᰻', '᱾', '᱾', 'ᴀ', 'ᴅ', 'ᴇ', 'ᴊ', 'ᴎ', 'ᴘ', 'ᴙ', 'ᴛ', 'ᴛ', '\u{329}', 'ᴴ', 'ᵋ', 'ᵍ', '\u{1ddf}', 'ẟ', '₁', '₀', '₸', '\u{20e9}', '℗', 'Ⅎ', '⅄', '↞', '↟', '↠', '↡', '↲', '⇃', 'ᛚ', '⇃', '⇂', '∂', '∂', '\u{335}', '∅', '∇', '∇', '\u{308}', '∇', '\u{334}', '∎', '∐', '∠', '∧', '∮', '∮', '∮', '∮', '∮', '∴', '∵', '∷', '≈', '≏', '≡', '⊍', '⊎', '⊏', '⊐', '⊓', '⊔', '⊗', '⊛', '⊠', '⊡', '⊲', '⊳', '⋆', '\u{308}', '⌇', '⌒', '⌙', '⌤', '⌻', '⌾', '⍂', '⍉', '⍋', '⍎', '⍕', '⍥', '⍭', '⎈', '⏜', '⏝', '⏞', '⏟', '⏠', '⏡', '⏥', '⏻', 'Ⓘ', '│', '┌', '├', '▌', '▖', '▘', '□', '▪', '▶', '☧', '☩', '☽', '☾', '❬', '❭', '➀', '➁', '➂', '➃', '➄', '➅', '➆', '➇', '➈', '➉', '⟦', '⟧', '⦚', '⧟', '⨟', 'ⰿ', 'Ɐ', 'Ɒ', 'Ⱶ', 'ⱶ', 'Ⲷ', '⳨', 'ⵀ', 'ⵂ', 'ⵗ', '\u{2dec}', '〜', '〼', 'へ', 'ー', 'ー', 'ᅡ', 'ー', 'ᅥ', 'ー', 'ᅥ', '丨', 'ー', 'ᅩ', 'ー', 'ᅮ', 'ー', 'ー', 'ー', '丨', 'ー', '丨', 'ᅮ', '㒞', '㒹', '㒻', '㓟', '㔕', '㔾', '㖈', '㘽', '㛮', '㛼', '㞁', '㠯', '㡢', '㡼', '㣇', '㣣', '㣺', '㤜', '㤺', '㨮', '㩁', '㩬', '㫚', '㫤', '㬈', '㬙', '㬵', '㬺', '㬻', '㭉', '㮝', '㮣', '㰘', '㱎', '㴳', '㶖', '㺬', '㺸', '㼛', '㿼', '䀈', '䀘', '䀹', '䀿', '䁆', '䂖', '䃣', '䄯', '䈂', '䈧', '䊠', '䌁', '䌴', '䍙', '䏕', '䏙', '䐋', '䑃', '䑫', '䔫', '䕝', '䕡', '䕫', '䗗', '䗹', '䘵', '䚶', '䚾', '䛇', '䦕', '䧦', '䩮', '䩶', '䪲', '䬳', '䯎', '䳎', '䳭', '䳸', '䵖', '不', '並', '丨', '丨', 'ᅡ', '丨', 'ᅣ', '丨', 'ᅣ', 'ᅩ', '丨', 'ᅣ', '丨', '丨', 'ᅧ', '丨', 'ᅧ', '丨', '丨', 'ᅩ', '丨', 'ᅩ', '丨', '丨', 'ᅭ', '丨', 'ᅮ', '丨', 'ᅲ', '丨', 'ᆞ', '丨', 'ー', '丨', '丨', '丬', '串', '丸', '丹', '丽', '乁', '乙', '乚', '乛', '亀', '亂', '亅', '了', '二', '亠', '亮', '人', '亻', '什', '仌', '令', '你', '併', '侀', '來', '例', '侮', '侻', '便', '値', '倫', '偺', '備', '像', '僚', '僧', '儿', '兀', '充', '免', '兔', '兤', '入', '內', '全', '兩', '八', '六', '具', '冀', '冂', '再', '冒', '冕', '冖', '冗', '冤', '冫', '冬', '况', '冷', '凉', '凌', '凜', '凞', '几', '凵', '刀', '刂', '刃', '切', '列', '利', '刺', '刻', '剆', '割', '剷', '劉', '力', '劣', '劳', '勇', '勉', '勒', '勞', '勤', '勵', '勹', '勺', '包', '匆', '匕', '北', '匚', '匸', '匿', '十', '卄', '卅', '卉', '卍', '卐', '卑', '博', '卜', '卩', '即', '卵', '卽', '卿', '厂', '厶', '參', '又', '及', '叟', '口', '句', '叫', '叱', '吆', '吏', '吝', '吸', '呂', '呈', '周', '咞', '咢', '咽', '哶', '唐', '啓', '啕', '啣', '善', '喇', '喙', '喝', '喫', '喳', '嗀', '嗂', '嗢', '嘆', '噑', '器', '噴', '囹', '圖', '圗', '土', '型', '城', '埴', '堍', '報', '堲', '塀', '塚', '塞', '塡', '墨', '墫', '墬', '墳', '壘', '壟', '壮', '売', '壷', '夂', '夆', '夊', '夕', '多', '夢', '大', '奄', '奈', '契', '奔', '奢', '女', '姘', '姬', '娛', '娧', '婢', '婦', '媯', '媵', '嬈', '嬨', '嬾', '子', '宀', '宅', '寃', '寘', '寧', '寮', '寳', '寸', '寿', '将', '小', '尢', '尣', '尸', '尿', '屠', '屢', '層', '履', '屮', '山', '岍', '峀', '崙', '嵃', '嵐', '嵫', '嵮', '嵼', '嶲', '嶺', '巛', '巡', '巢', '工', '己', '巳', '巽', '巾', '帡', '帨', '帽', '幩', '干', '年', '幺', '广', '度', '庰', '庳', '庶', '廉', '廊', '廒', '廓', '廙', '廬', '廴', '廾', '弄', '弋', '弓', '弢', '彐', '彑', '当', '彡', '形', '彩', '彫', '彳', '律', '徚', '復', '徭', '心', '忄', '忍', '志', '念', '忹', '怒', '怜', '恵', '悁', '悔', '惇', '惘', '惡', '愈', '慄', '慈', '慌', '慎', '慠', '慨', '慺', '憎', '憐', '憤', '憯', '憲', '懞', '懲', '懶', '戀', '戈', '成', '戛', '戮', '戴', '戶', '手', '扌', '扝', '抱', '拉', '拏', '拓', '拔', '拼', '拾', '挽', '捐', '捨', '捻', '掃', '掠', '掩', '揄', '揅', '揤', '搜', '搢', '摒', '摩', '摷', '摾', '撚', '撝', '擄', '支', '攴', '攵', '敏', '敖', '敬', '數', '文', '斉', '斗', '料', '斤', '方', '旅', '无', '旡', '既', '旣', '日', '易', '晉', '晚', '晴', '暈', '暑', '暜', '暴', '曆', '曰', '更', '書', '最', '月', '朌', '朏', '朐', '朓', '朗', '朘', '望', '朡', '朣', '木', '李', '杓', '杖', '杞', '杮', '杻', '枅', '林', '柳', '柺', '栗', '栟', '桒', '梁', '梅', '梎', '梨', '椔', '楂', '榝', '榣', '槪', '樂', '樓', '檨', '櫓', '櫛', '欄', '欠', '次', '歔', '止', '歯', '歲', '歷', '歹', '歺', '殟', '殮', '殳', '殺', '殻', '毋', '母', '比', '毛', '氏', '民', '气', '水', '氵', '氺', '汎', '汧', '沈', '沿', '泌', '泍', '泥', '洖', '洛', '洞', '洴', '派', '流', '浩', '浪', '海', '浸', '涅', '淋', '淚', '淪', '淹', '渚', '港', '湮', '溈', '溜', '溺', '滇', '滋', '滑', '滛', '漏', '漢', '漣', '潮', '濆', '濫', '濾', '瀛', '瀞', '瀹', '灊', '火', '灬', '灰', '灷', '災', '炙', '炭', '烈', '烙', '煅', '煉', '煮', '熜', '燎', '燐', '爐', '爛', '爨', '爪', '爫', '爵', '父', '爻', '爿', '片', '牐', '牙', '牛', '牢', '犀', '犕', '犬', '犭', '犯', '狀', '狼', '猪', '獵', '獺', '玄', '率', '玉', '王', '玥', '玲', '珞', '理', '琉', '琢', '瑇', '瑜', '瑩', '瑱', '璅', '璉', '璘', '瓊', '瓜', '瓦', '甆', '甘', '生', '甤', '用', '田', '画', '甾', '留', '略', '異', '疋', '疒', '痢', '瘐', '瘝', '瘟', '療', '癩', '癶', '白', '皮', '皿', '益', '盛', '盧', '目', '直', '省', '眞', '真', '着', '睊', '瞋', '瞧', '矛', '矢', '石', '研', '硎', '硫', '碌', '碑', '磊', '磌', '磻', '礪', '示', '礻', '礼', '社', '祈', '祉', '祐', '祖', '祝', '神', '祥', '祿', '禍', '禎', '福', '禮', '禸', '禾', '秊', '秫', '稜', '穀', '穊', '穏', '穴', '突', '窱', '立', '竜', '竮', '竹', '笠', '節', '篆', '築', '簾', '籠', '米', '类', '粒', '精', '糒', '糖', '糣', '糧', '糨', '糸', '糹', '紀', '紐', '索', '累', '絕', '絛', '絣', '綠', '綾', '緇', '練', '縂', '縉', '縷', '繁', '繅', '缶', '缾', '网', '罒', '罓', '署', '罹', '罺', '羅', '羊', '羕', '羚', '羽', '翺', '老', '耂', '者', '而', '耒', '耳', '聆', '聠', '聯', '聰', '聾', '聿', '肀', '肉', '肋', '肭', '育', '胼', '脃', '脾', '臘', '臣', '臨', '自', '臭', '至', '臼', '舁', '舄', '舌', '舘', '舛', '舟', '艮', '良', '色', '艸', '艹', '芋', '芑', '芝', '花', '芳', '芽', '若', '苦', '茝', '茣', '茶', '荒', '荓', '荣', '莭', '莽', '菉', '菊', '菌', '菜', '菧', '華', '菱', '落', '葉', '著', '蒍', '蓮', '蓱', '蓳', '蓼', '蔖', '蕤', '藍', '藺', '蘆', '蘒', '蘭', '蘷', '蘿', '虍', '虎', '虐', '虜', '虧', '虩', '虫', '蚈', '蚩', '蛢', '蜎', '蜨', '蝫', '蝹', '螆', '螺', '蟡', '蠁', '蠟', '血', '行', '衠', '衣', '衤', '裂', '裏', '裗', '裞', '裡', '裸', '裺', '褐', '襁', '襤', '襾', '西', '覀', '覆', '見', '視', '见', '角', '言', '訮', '誠', '說', '調', '請', '諒', '論', '諭', '諸', '諾', '謁', '謹', '識', '讀', '讆', '變', '讠', '谷', '豆', '豈', '豕', '豜', '豸', '貝', '貫', '賁', '賂', '賈', '賓', '贈', '贛', '贝', '赤', '走', '起', '赿', '足', '趼', '跋', '跥', '路', '跰', '躗', '身', '車', '軔', '軿', '輦', '輪', '輸', '輻', '轢', '车', '辛', '辞', '辰', '辵', '辶', '連', '逸', '遲', '遼', '邏', '邑', '邔', '郎', '郱', '都', '鄑', '鄛', '酉', '酪', '醙', '醴', '釆', '里', '量', '金', '鈴', '鈸', '鉶', '鉼', '鋗', '鋘', '錄', '鍊', '鎭', '鏹', '鐕', '钅', '長', '镸', '长', '門', '開', '閭', '閷', '门', '阜', '阝', '阮', '陋', '降', '陵', '陸', '陼', '隆', '隣', '隶', '隷', '隹', '雃', '離', '難', '雨', '零', '雷', '霣', '露', '靈', '靑', '青', '靖', '非', '面', '革', '韋', '韛', '韠', '韦', '韭', '音', '響', '頁', '頋', '領', '頩', '頻', '類', '页', '風', '风', '飛', '飞', '食', '飠', '飢', '飯', '飼', '館', '餩', '饣', '首', '香', '馧', '馬', '駂', '駱', '駾', '驪', '马', '骨', '高', '髟', '鬒', '鬥', '鬯', '鬲', '鬼', '魚', '魯', '鱀', '鱗', '鱼', '鳥', '鳽', '鵧', '鶴', '鷺', '鸞', '鹂', '鹵', '鹿', '麗', '麟', '麥', '麦', '麻', '黃', '黄', '黍', '黎', '黑', '黹', '黽', '黾', '鼅', '鼎', '鼏', '鼓', '鼖', '鼠', '鼻', '齃', '齊', '齐', '齒', '齿', '龍', '龎', '龙', '龜', '龟', '龠', 'ꁊ', 'ꁐ', 'ꃀ', 'ꄲ', 'ꉙ', 'ꊱ', 'ꋍ', 'ꎫ', 'ꎵ', 'ꎿ', 'ꏂ', 'ꑘ', 'ꓕ', 'ꓤ', 'ꓨ', 'ꓶ', 'Ꙍ', 'Ꙙ', 'ꙩ', '꛳', '꛳', 'ꜧ', 'Ꜿ', 'ꜿ', 'ꝰ', 'Ꝺ', 'Ꞇ', 'Ꞓ', 'ꞓ', 'ꞓ', '\u{332}', 'ꟻ', 'ꣻ', '꣼', 'ꦝ', '꧐', 'ꨁ', 'ꨣ', 'ꭑ', '︿', 'ﹲ', '\u{651}', 'ﹴ', '\u{651}', 'ﹶ', '\u{651}', 'ﹷ', '\u{651}', 'ﹸ', '\u{651}', 'ﹹ', '\u{651}', 'ﹺ', '\u{651}', 'ﹻ', '\u{651}', 'ﹼ', '\u{670}', '\u{ff9e}', '\u{ff9f}', '𐊨', '𐊼', '𐋀', '𐎂', '𐎓', '𐎚', '𐐒', '𐐺', '𐒆', '𐦞', '𐩖', '𐩖', '𐲂', '𐲥', '𑐴', '\u{11442}', '𑐒', '𑐴', '\u{11442}', '𑐘', '𑐴', '\u{11442}', '𑐣', '𑐴', '\u{11442}', '𑐩', '𑐴', '\u{11442}', '𑐬', '𑐴', '\u{11442}', '𑐮', '𑑋', '𑑋', '𑖂', '𑖃', '𑖄', '\u{115b2}', '\u{115b3}', '𑙁', '𑙁', '𑫥', '𑫥', '𑫥', '𑫥', '𑫯', '𑫥', '𑫥', '𑫰', '𑫥', '𑫯', '𑫥', '𑫰', '𑫫', '𑫫', '𑫫', '𑫫', '𑫯', '𑫫', '𑫯', '𑫳', '𑫯', '𑫳', '𑫰', '𑫳', '𑫳', '𑫳', '𑫳', '𑫯', '𑫳', '𑫳', '𑫰', '𑱁', '𑱁', '\u{11caa}', '𖼀', '𝅘', '\u{1d165}', '𝅘', '\u{1d165}', '\u{1d16e}', '𠄢', '𠔜', '𠔥', '𠕋', '𠘺', '𠠄', '𠣞', '𠨬', '𠭣', '𡓤', '𡚨', '𡛪', '𡧈', '𡬘', '𡴋', '𡷤', '𡷦', '𢆃', '𢆟', '𢌱', '𢛔', '𢡄', '𢡊', '𢬌', '𢯱', '𣀊', '𣊸', '𣍟', '𣎓', '𣎜', '𣏃', '𣏕', '𣑭', '𣚣', '𣢧', '𣪍', '𣫺', '𣲼', '𣴞', '𣻑', '𣽞', '𣾎', '𤉣', '𤋮', '𤎫', '𤘈', '𤜵', '𤠔', '𤰶', '𤲒', '𤾡', '𤾸', '𥁄', '𥃲', '𥃳', '𥄙', '𥄳', '𥉉', '𥐝', '𥘦', '𥚚', '𥛅', '𥥼', '𥪧', '𥮫', '𥲀', '𥳐', '𥾆', '𦇚', '𦈨', '𦉇', '𦋙', '𦌾', '𦓚', '𦔣', '𦖨', '𦞧', '𦞵', '𦬼', '𦰶', '𦳕', '𦵫', '𦼬', '𦾱', '𧃒', '𧏊', '𧙧', '𧢮', '𧥦', '𧲨', '𧻓', '𧼯', '𨗒', '𨗭', '𨜮', '𨯺', '𨵷', '𩅅', '𩇟', '𩈚', '𩐊', '𩒖', '𩖶', '𩬰', '𪃎', '𪄅', '𪈎', '𪊑', '𪎒', '𪘀'];
+pub static SALTS: [u16; 6294] = [0, 2, 0, 1, 1, 3, 2, 3, 0, 2, 1, 2, 0, 1, 2, 1, 4, 1, 1, 3, 2, 0, 0, 1, 1, 3, 3, 1, 0, 1, 5, 0, 1, 0, 5, 1, 2, 0, 2, 1, 3, 2, 0, 4, 2, 4, 0, 2, 0, 0, 1, 5, 5, 1, 0, 1, 2, 9, 1, 0, 2, 0, 2, 1, 0, 9, 6, 4, 2, 3, 1, 1, 1, 0, 0, 3, 3, 0, 3, 3, 2, 0, 0, 0, 0, 1, 0, 1, 1, 4, 0, 0, 1, 1, 3, 0, 0, 3, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 3, 4, 0, 0, 1, 7, 3, 5, 3, 1, 0, 0, 4, 7, 0, 13, 1, 0, 1, 2, 0, 3, 1, 4, 2, 5, 0, 1, 0, 6, 1, 1, 1, 1, 6, 2, 0, 0, 0, 1, 1, 1, 1, 2, 0, 4, 1, 1, 1, 1, 1, 0, 0, 2, 0, 0, 0, 2, 3, 1, 2, 1, 1, 1, 3, 0, 3, 2, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 3, 1, 2, 1, 3, 2, 1, 0, 0, 1, 9, 0, 3, 1, 2, 0, 2, 14, 4, 0, 5, 0, 4, 3, 0, 0, 0, 1, 7, 1, 5, 2, 3, 0, 2, 1, 0, 0, 0, 0, 0, 3, 3, 7, 8, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 4, 1, 1, 12, 1, 0, 1, 1, 1, 0, 4, 1, 1, 2, 0, 1, 0, 1, 2, 2, 4, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1, 3, 3, 0, 0, 1, 1, 3, 3, 1, 1, 4, 3, 0, 1, 3, 1, 6, 1, 1, 2, 1, 1, 0, 3, 0, 1, 11, 1, 1, 2, 0, 0, 1, 0, 1, 1, 2, 0, 1, 0, 1, 2, 0, 1, 2, 3, 2, 5, 2, 0, 0, 2, 0, 3, 1, 2, 0, 2, 0, 1, 3, 3, 0, 0, 1, 1, 1, 2, 2, 2, 2, 2, 0, 1, 0, 2, 4, 2, 4, 0, 2, 0, 0, 0, 3, 3, 1, 0, 2, 2, 1, 0, 2, 1, 3, 0, 1, 5, 4, 3, 1, 1, 0, 0, 0, 1, 0, 0, 0, 2, 1, 1, 5, 3, 0, 2, 7, 4, 0, 0, 3, 1, 0, 1, 0, 0, 0, 4, 2, 0, 0, 0, 2, 2, 2, 0, 2, 0, 1, 0, 1, 0, 0, 0, 0, 2, 2, 2, 1, 5, 0, 4, 4, 3, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 2, 5, 1, 1, 4, 4, 0, 8, 8, 2, 0, 3, 4, 1, 1, 1, 2, 2, 7, 4, 1, 0, 2, 1, 2, 2, 3, 0, 1, 3, 0, 1, 7, 0, 0, 1, 0, 1, 2, 0, 3, 2, 1, 2, 0, 0, 1, 0, 0, 2, 1, 0, 0, 1, 1, 0, 1, 2, 0, 1, 0, 0, 1, 6, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 1, 2, 2, 0, 0, 1, 0, 3, 0, 1, 1, 8, 0, 0, 0, 1, 0, 8, 2, 0, 2, 4, 2, 1, 6, 1, 1, 3, 0, 6, 0, 2, 1, 0, 0, 5, 1, 4, 3, 1, 4, 7, 1, 1, 2, 8, 0, 0, 0, 2, 1, 1, 7, 0, 0, 3, 1, 2, 1, 1, 1, 0, 1, 0, 2, 3, 2, 0, 0, 1, 4, 0, 6, 0, 1, 2, 0, 1, 3, 1, 2, 1, 1, 1, 4, 2, 4, 0, 3, 0, 2, 5, 0, 3, 1, 4, 4, 2, 6, 1, 0, 1, 0, 1, 1, 3, 1, 0, 3, 1, 1, 1, 8, 1, 0, 0, 0, 0, 2, 2, 1, 2, 1, 5, 1, 0, 3, 2, 0, 2, 0, 0, 0, 1, 0, 2, 6, 2, 2, 0, 4, 0, 0, 3, 0, 3, 1, 0, 2, 0, 0, 1, 1, 0, 5, 1, 3, 0, 4, 9, 4, 0, 1, 2, 5, 0, 0, 0, 1, 0, 1, 0, 5, 2, 0, 2, 1, 3, 7, 0, 1, 0, 1, 1, 1, 2, 0, 0, 1, 1, 1, 1, 0, 1, 0, 2, 3, 5, 1, 0, 0, 0, 3, 2, 1, 0, 2, 2, 0, 1, 16, 2, 0, 0, 2, 1, 4, 0, 5, 0, 2, 4, 0, 4, 2, 0, 4, 4, 0, 4, 2, 1, 0, 1, 1, 1, 3, 9, 1, 2, 0, 0, 6, 1, 3, 0, 7, 0, 1, 2, 0, 1, 1, 8, 3, 4, 0, 1, 4, 1, 0, 7, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 4, 3, 0, 2, 4, 0, 0, 1, 0, 3, 5, 0, 1, 0, 0, 3, 2, 1, 7, 4, 1, 1, 1, 4, 0, 0, 0, 0, 0, 4, 0, 4, 2, 1, 1, 2, 0, 5, 1, 3, 2, 3, 0, 5, 1, 1, 1, 0, 1, 2, 0, 1, 2, 1, 8, 0, 0, 0, 3, 1, 2, 3, 1, 2, 2, 1, 3, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2, 0, 4, 2, 0, 0, 0, 5, 1, 1, 2, 2, 2, 0, 0, 2, 2, 3, 0, 1, 0, 2, 2, 1, 0, 2, 0, 0, 4, 0, 3, 0, 2, 0, 2, 0, 4, 0, 0, 3, 1, 1, 4, 0, 1, 1, 0, 3, 0, 1, 2, 5, 1, 15, 0, 1, 8, 0, 8, 0, 4, 0, 5, 0, 0, 0, 0, 1, 1, 1, 2, 0, 1, 0, 0, 0, 3, 3, 0, 2, 4, 2, 1, 2, 1, 0, 0, 6, 1, 0, 1, 0, 3, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 3, 0, 3, 1, 0, 3, 3, 6, 1, 1, 4, 2, 7, 2, 1, 1, 1, 0, 1, 2, 3, 6, 2, 1, 1, 0, 1, 5, 0, 1, 2, 2, 5, 0, 3, 0, 1, 0, 1, 1, 4, 2, 2, 4, 0, 3, 0, 6, 0, 2, 1, 2, 0, 7, 2, 1, 0, 4, 0, 0, 1, 5, 1, 0, 2, 1, 0, 2, 0, 0, 1, 0, 2, 0, 2, 4, 1, 2, 0, 1, 0, 1, 2, 1, 0, 0, 4, 16, 4, 0, 0, 4, 4, 3, 4, 2, 2, 0, 5, 1, 0, 3, 0, 3, 5, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 5, 5, 1, 0, 1, 1, 6, 0, 1, 0, 2, 5, 4, 3, 3, 1, 1, 0, 1, 2, 0, 1, 2, 8, 0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 3, 4, 0, 2, 1, 3, 3, 0, 0, 0, 4, 1, 0, 5, 0, 0, 0, 0, 2, 0, 1, 0, 6, 1, 0, 0, 0, 1, 4, 0, 2, 1, 4, 1, 1, 1, 0, 2, 0, 1, 2, 0, 5, 5, 0, 0, 3, 2, 1, 1, 1, 2, 0, 0, 3, 5, 10, 2, 0, 3, 0, 6, 0, 1, 1, 0, 0, 15, 1, 3, 2, 1, 0, 2, 0, 0, 0, 5, 11, 1, 0, 2, 2, 1, 0, 3, 0, 0, 3, 14, 4, 3, 0, 3, 1, 1, 5, 0, 4, 0, 0, 3, 5, 3, 1, 1, 7, 0, 2, 1, 0, 1, 0, 1, 1, 3, 0, 1, 1, 1, 5, 0, 0, 0, 1, 0, 1, 2, 1, 3, 2, 0, 2, 2, 2, 4, 1, 0, 0, 1, 2, 4, 7, 2, 0, 0, 0, 7, 0, 2, 0, 1, 0, 0, 4, 2, 2, 2, 0, 2, 0, 3, 0, 5, 0, 0, 2, 3, 0, 2, 0, 1, 2, 2, 2, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 1, 4, 2, 0, 3, 0, 6, 1, 8, 1, 0, 1, 2, 0, 2, 0, 1, 1, 2, 1, 1, 0, 2, 0, 2, 0, 0, 0, 11, 1, 0, 0, 4, 0, 1, 1, 2, 0, 4, 0, 0, 2, 9, 0, 6, 1, 0, 1, 2, 3, 0, 1, 1, 1, 1, 1, 1, 2, 2, 1, 0, 3, 1, 0, 3, 3, 2, 5, 0, 3, 2, 16, 2, 3, 3, 5, 1, 1, 4, 1, 4, 1, 7, 1, 0, 0, 0, 1, 1, 8, 0, 3, 11, 0, 1, 1, 1, 0, 0, 5, 11, 0, 0, 0, 0, 4, 1, 1, 0, 0, 1, 1, 3, 1, 0, 2, 2, 4, 4, 0, 0, 3, 3, 0, 4, 1, 0, 2, 0, 0, 0, 2, 0, 4, 4, 1, 2, 0, 0, 4, 0, 3, 2, 0, 0, 3, 0, 2, 8, 3, 2, 3, 3, 0, 1, 1, 0, 0, 3, 2, 1, 0, 2, 0, 0, 1, 1, 3, 5, 3, 0, 1, 0, 0, 0, 0, 6, 3, 3, 9, 2, 0, 0, 7, 0, 14, 1, 9, 3, 2, 0, 1, 0, 0, 3, 0, 0, 4, 0, 2, 3, 0, 5, 0, 1, 1, 2, 1, 1, 0, 6, 1, 1, 0, 0, 0, 0, 1, 2, 2, 1, 1, 2, 0, 3, 2, 3, 1, 0, 0, 2, 2, 5, 3, 0, 1, 0, 0, 0, 1, 0, 2, 1, 5, 1, 2, 3, 1, 5, 1, 4, 13, 2, 1, 13, 2, 2, 3, 1, 0, 0, 11, 1, 2, 0, 12, 0, 0, 5, 1, 1, 0, 5, 1, 0, 5, 3, 0, 2, 2, 0, 0, 10, 0, 2, 0, 5, 0, 1, 2, 2, 2, 1, 0, 3, 1, 0, 2, 0, 2, 9, 0, 2, 1, 3, 1, 0, 0, 5, 2, 0, 2, 0, 3, 1, 0, 0, 0, 0, 5, 2, 2, 4, 0, 0, 0, 4, 2, 0, 2, 2, 27, 0, 5, 1, 0, 0, 2, 2, 0, 5, 1, 0, 0, 0, 0, 2, 0, 2, 4, 1, 3, 0, 0, 8, 3, 0, 0, 0, 0, 0, 11, 0, 3, 1, 4, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 1, 0, 1, 1, 2, 5, 2, 1, 0, 1, 5, 3, 4, 2, 3, 0, 0, 2, 0, 3, 1, 8, 0, 2, 1, 4, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 7, 2, 2, 3, 0, 0, 0, 4, 0, 5, 1, 2, 5, 0, 1, 3, 2, 1, 2, 4, 0, 4, 1, 2, 1, 1, 3, 3, 2, 0, 1, 9, 1, 0, 3, 5, 0, 0, 0, 2, 4, 0, 2, 0, 0, 0, 1, 8, 4, 3, 1, 0, 1, 2, 5, 0, 0, 5, 0, 3, 1, 0, 1, 0, 1, 0, 0, 6, 1, 1, 2, 3, 1, 0, 0, 0, 3, 1, 0, 2, 1, 0, 1, 6, 1, 0, 12, 3, 9, 8, 0, 0, 0, 0, 2, 4, 1, 1, 0, 3, 0, 6, 1, 0, 4, 0, 2, 7, 3, 0, 0, 2, 1, 4, 1, 1, 0, 6, 0, 1, 3, 1, 0, 4, 1, 0, 3, 14, 5, 0, 0, 0, 5, 2, 1, 1, 1, 6, 0, 2, 0, 1, 6, 0, 0, 1, 0, 0, 0, 0, 11, 1, 0, 2, 2, 3, 6, 0, 1, 1, 12, 9, 1, 3, 0, 2, 2, 4, 0, 0, 0, 0, 3, 0, 0, 2, 1, 1, 0, 2, 1, 0, 2, 9, 3, 3, 0, 0, 0, 1, 3, 4, 1, 2, 0, 2, 7, 0, 0, 3, 6, 0, 12, 1, 3, 0, 0, 1, 0, 1, 1, 0, 2, 10, 9, 0, 2, 1, 0, 0, 2, 3, 2, 1, 12, 0, 1, 1, 9, 0, 1, 0, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 0, 1, 6, 1, 5, 1, 1, 1, 3, 2, 1, 2, 0, 0, 0, 2, 5, 6, 8, 1, 0, 1, 13, 0, 4, 3, 2, 0, 1, 2, 1, 2, 7, 0, 1, 4, 1, 0, 1, 5, 2, 2, 0, 3, 0, 0, 0, 1, 0, 7, 5, 0, 0, 4, 0, 0, 0, 8, 4, 0, 1, 1, 3, 8, 0, 1, 2, 0, 0, 3, 2, 4, 0, 3, 1, 0, 1, 1, 3, 0, 0, 0, 0, 0, 3, 17, 1, 1, 2, 3, 2, 3, 9, 1, 0, 1, 0, 0, 3, 0, 2, 0, 0, 2, 7, 0, 5, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 10, 6, 1, 2, 5, 7, 6, 1, 2, 0, 1, 2, 0, 1, 3, 0, 9, 8, 1, 1, 0, 13, 1, 5, 2, 8, 2, 0, 1, 0, 1, 0, 1, 6, 2, 1, 3, 5, 0, 6, 0, 1, 3, 10, 6, 2, 0, 1, 1, 0, 2, 0, 0, 0, 4, 7, 0, 2, 0, 2, 6, 3, 4, 0, 0, 1, 1, 4, 3, 0, 0, 1, 13, 3, 1, 0, 0, 2, 5, 0, 3, 0, 0, 7, 0, 6, 0, 3, 2, 5, 0, 5, 5, 2, 0, 1, 0, 5, 0, 0, 3, 2, 12, 0, 15, 1, 1, 0, 3, 0, 1, 1, 0, 3, 1, 1, 3, 18, 1, 2, 5, 6, 13, 9, 11, 2, 5, 0, 2, 4, 0, 0, 0, 0, 5, 2, 1, 13, 0, 3, 1, 0, 3, 1, 0, 0, 3, 0, 0, 0, 2, 7, 12, 1, 0, 6, 12, 0, 1, 2, 4, 1, 0, 1, 0, 2, 1, 2, 2, 0, 0, 0, 0, 2, 0, 0, 0, 1, 3, 2, 9, 8, 0, 0, 1, 2, 3, 0, 1, 1, 0, 2, 0, 3, 1, 2, 12, 0, 0, 1, 0, 5, 13, 0, 0, 0, 1, 0, 0, 5, 2, 1, 2, 0, 6, 1, 1, 3, 7, 0, 0, 1, 0, 9, 0, 0, 5, 5, 1, 1, 2, 1, 28, 2, 0, 5, 2, 3, 0, 2, 2, 0, 0, 1, 0, 1, 2, 0, 2, 1, 9, 1, 0, 1, 0, 3, 1, 1, 0, 2, 1, 4, 1, 3, 4, 0, 0, 7, 0, 3, 2, 0, 1, 1, 0, 1, 0, 1, 7, 3, 1, 0, 0, 0, 1, 0, 5, 17, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 8, 10, 1, 1, 15, 0, 1, 0, 0, 2, 1, 1, 0, 6, 7, 4, 1, 1, 1, 13, 6, 0, 19, 0, 0, 0, 5, 1, 3, 4, 0, 1, 0, 0, 1, 0, 0, 2, 2, 2, 1, 7, 0, 6, 0, 1, 4, 5, 8, 5, 5, 4, 10, 0, 1, 1, 0, 0, 1, 3, 8, 2, 0, 3, 0, 1, 1, 0, 1, 7, 2, 1, 0, 2, 1, 2, 3, 1, 1, 1, 1, 15, 3, 2, 0, 5, 4, 3, 2, 5, 0, 5, 2, 0, 3, 0, 3, 0, 3, 0, 0, 0, 7, 0, 1, 0, 0, 0, 3, 6, 1, 0, 2, 9, 0, 8, 8, 5, 3, 2, 0, 1, 2, 0, 0, 1, 4, 0, 1, 3, 10, 3, 1, 0, 4, 0, 1, 3, 1, 2, 0, 1, 0, 0, 0, 0, 2, 6, 3, 0, 2, 0, 10, 0, 0, 8, 1, 0, 2, 0, 0, 0, 3, 0, 8, 2, 0, 0, 0, 0, 0, 2, 2, 6, 23, 6, 3, 0, 0, 1, 2, 1, 14, 0, 2, 0, 0, 1, 3, 0, 1, 0, 0, 0, 1, 2, 2, 1, 0, 0, 4, 10, 5, 1, 1, 4, 4, 1, 2, 6, 1, 0, 0, 0, 0, 1, 3, 7, 1, 0, 7, 0, 4, 2, 1, 0, 4, 14, 1, 1, 2, 3, 0, 0, 1, 0, 5, 0, 0, 1, 0, 0, 1, 13, 0, 1, 9, 2, 0, 2, 1, 3, 0, 1, 0, 0, 0, 1, 3, 5, 0, 1, 0, 3, 4, 5, 1, 0, 0, 2, 0, 3, 1, 0, 0, 0, 4, 1, 0, 0, 2, 1, 24, 0, 5, 0, 0, 8, 1, 0, 0, 0, 1, 4, 0, 1, 3, 2, 1, 1, 2, 6, 5, 0, 0, 5, 1, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 6, 1, 7, 0, 6, 5, 1, 2, 2, 0, 0, 7, 10, 6, 3, 6, 1, 0, 0, 4, 0, 1, 0, 6, 7, 2, 6, 4, 3, 0, 4, 1, 6, 11, 3, 0, 2, 2, 1, 0, 1, 0, 0, 2, 3, 0, 3, 1, 1, 1, 0, 1, 4, 0, 3, 3, 5, 4, 2, 2, 1, 0, 0, 7, 1, 0, 8, 1, 3, 0, 1, 14, 3, 1, 1, 1, 2, 0, 0, 0, 10, 1, 1, 0, 3, 2, 5, 1, 0, 5, 0, 1, 0, 0, 1, 2, 0, 0, 2, 1, 7, 2, 0, 2, 0, 0, 1, 1, 1, 2, 13, 0, 0, 0, 4, 2, 0, 0, 6, 4, 1, 0, 1, 0, 2, 10, 1, 1, 0, 0, 0, 1, 1, 3, 0, 0, 2, 1, 0, 4, 0, 1, 1, 0, 1, 1, 0, 3, 1, 0, 9, 8, 0, 4, 0, 0, 4, 5, 1, 1, 0, 1, 0, 0, 3, 7, 13, 3, 0, 1, 2, 0, 1, 3, 10, 0, 1, 0, 0, 4, 18, 0, 0, 1, 0, 2, 0, 11, 3, 0, 0, 1, 1, 1, 5, 0, 1, 3, 2, 3, 0, 0, 2, 6, 3, 7, 3, 3, 1, 3, 0, 3, 0, 3, 5, 0, 18, 16, 3, 0, 1, 0, 1, 0, 0, 2, 7, 10, 14, 5, 0, 0, 1, 7, 0, 2, 2, 2, 0, 5, 1, 0, 1, 1, 1, 1, 0, 8, 0, 0, 0, 0, 0, 1, 0, 3, 4, 3, 1, 3, 9, 5, 0, 0, 4, 3, 0, 2, 3, 0, 2, 0, 11, 3, 0, 6, 4, 0, 4, 0, 1, 1, 0, 1, 6, 2, 0, 2, 13, 0, 7, 4, 0, 1, 1, 3, 4, 3, 3, 6, 2, 2, 0, 0, 6, 9, 1, 0, 5, 8, 1, 4, 11, 0, 0, 3, 6, 6, 11, 1, 2, 0, 1, 5, 1, 0, 1, 0, 2, 2, 3, 4, 2, 0, 2, 0, 1, 3, 1, 1, 1, 0, 0, 13, 1, 0, 3, 1, 16, 4, 0, 0, 1, 6, 2, 1, 0, 0, 0, 13, 2, 0, 2, 0, 0, 0, 2, 0, 8, 1, 8, 4, 1, 0, 0, 0, 5, 0, 2, 0, 0, 6, 4, 9, 2, 0, 0, 3, 0, 0, 7, 3, 2, 0, 0, 0, 1, 10, 0, 2, 0, 12, 5, 0, 1, 3, 0, 1, 3, 1, 1, 0, 1, 1, 3, 1, 0, 6, 1, 0, 1, 1, 0, 1, 0, 0, 0, 4, 2, 1, 5, 2, 0, 0, 7, 9, 20, 5, 4, 7, 2, 1, 4, 1, 0, 2, 2, 2, 0, 0, 4, 5, 10, 3, 14, 2, 2, 0, 1, 4, 0, 3, 0, 1, 8, 0, 1, 0, 0, 1, 4, 0, 0, 0, 0, 2, 0, 0, 1, 2, 3, 4, 1, 8, 0, 1, 0, 0, 7, 6, 4, 3, 6, 3, 2, 1, 9, 6, 3, 1, 0, 0, 2, 0, 4, 9, 0, 3, 17, 0, 2, 1, 0, 0, 1, 0, 2, 3, 0, 0, 1, 4, 4, 1, 0, 0, 7, 2, 1, 0, 2, 0, 4, 0, 3, 4, 0, 0, 0, 0, 4, 0, 0, 2, 2, 1, 0, 13, 2, 0, 0, 11, 11, 2, 0, 2, 1, 0, 10, 2, 1, 0, 9, 0, 4, 2, 1, 13, 0, 0, 0, 9, 5, 3, 0, 3, 0, 0, 0, 6, 2, 0, 0, 1, 6, 0, 8, 2, 0, 0, 0, 5, 0, 5, 7, 0, 4, 0, 3, 0, 7, 2, 2, 0, 0, 0, 1, 5, 3, 4, 1, 0, 2, 22, 3, 2, 1, 7, 5, 1, 1, 5, 5, 0, 1, 1, 5, 1, 1, 0, 3, 6, 0, 0, 6, 2, 3, 1, 0, 1, 0, 0, 1, 0, 0, 1, 5, 6, 0, 3, 3, 0, 2, 14, 0, 1, 0, 9, 1, 1, 16, 0, 0, 1, 20, 1, 1, 11, 0, 0, 0, 0, 4, 4, 35, 1, 1, 5, 3, 2, 0, 0, 17, 0, 7, 6, 2, 0, 0, 13, 9, 0, 17, 13, 1, 1, 11, 0, 10, 0, 0, 0, 0, 7, 1, 7, 2, 2, 0, 4, 1, 4, 0, 15, 27, 1, 1, 8, 2, 4, 0, 0, 3, 9, 5, 9, 2, 0, 0, 5, 2, 2, 11, 1, 0, 3, 7, 1, 0, 0, 2, 0, 7, 1, 0, 7, 0, 1, 1, 2, 10, 0, 5, 14, 1, 0, 1, 7, 1, 0, 1, 9, 0, 9, 0, 4, 1, 0, 4, 0, 1, 0, 0, 0, 0, 5, 4, 3, 1, 2, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1, 10, 12, 0, 0, 8, 8, 0, 9, 3, 0, 20, 12, 0, 1, 0, 4, 3, 13, 1, 0, 8, 0, 1, 0, 0, 1, 3, 10, 5, 0, 1, 0, 0, 0, 0, 1, 1, 4, 0, 3, 0, 3, 0, 12, 1, 0, 2, 0, 3, 0, 8, 18, 0, 0, 2, 3, 2, 4, 4, 4, 1, 1, 30, 6, 1, 0, 9, 4, 1, 4, 11, 0, 0, 1, 0, 8, 0, 1, 1, 17, 0, 1, 0, 1, 16, 7, 12, 14, 3, 0, 0, 0, 4, 2, 1, 20, 0, 7, 5, 0, 0, 0, 12, 0, 27, 6, 1, 0, 3, 0, 5, 0, 0, 1, 2, 18, 14, 0, 1, 16, 0, 1, 1, 0, 5, 3, 7, 2, 2, 0, 0, 0, 3, 3, 3, 0, 4, 2, 2, 0, 7, 0, 0, 1, 2, 2, 1, 4, 12, 0, 1, 5, 0, 0, 0, 2, 0, 0, 7, 1, 10, 3, 1, 0, 2, 0, 4, 2, 4, 1, 0, 1, 1, 2, 1, 1, 4, 0, 1, 0, 24, 4, 1, 0, 0, 2, 0, 6, 1, 2, 1, 1, 0, 4, 6, 4, 9, 0, 17, 4, 0, 4, 4, 1, 0, 9, 1, 6, 0, 3, 0, 5, 0, 3, 1, 7, 6, 2, 0, 2, 14, 2, 0, 7, 17, 6, 2, 2, 1, 0, 10, 0, 0, 1, 1, 3, 2, 1, 2, 0, 0, 9, 0, 0, 4, 1, 0, 6, 2, 0, 1, 0, 1, 7, 1, 0, 1, 0, 0, 0, 7, 5, 24, 0, 3, 0, 17, 19, 1, 16, 0, 9, 2, 15, 0, 0, 0, 0, 4, 9, 0, 9, 1, 6, 26, 8, 1, 0, 3, 1, 0, 4, 0, 2, 1, 0, 0, 9, 0, 1, 0, 4, 2, 1, 3, 2, 2, 0, 19, 3, 14, 2, 2, 0, 24, 2, 3, 0, 0, 6, 19, 0, 5, 13, 6, 5, 0, 0, 0, 0, 2, 0, 0, 1, 5, 0, 4, 1, 17, 6, 0, 0, 21, 7, 1, 0, 0, 2, 3, 24, 0, 0, 2, 5, 1, 0, 0, 6, 0, 0, 0, 8, 8, 4, 0, 0, 5, 0, 2, 5, 0, 0, 5, 19, 1, 1, 2, 0, 1, 0, 0, 5, 4, 1, 1, 13, 3, 14, 4, 0, 13, 1, 0, 0, 0, 0, 1, 0, 3, 1, 5, 2, 4, 0, 6, 1, 1, 1, 0, 1, 0, 0, 12, 0, 1, 1, 0, 0, 0, 1, 1, 8, 7, 0, 1, 0, 2, 7, 1, 0, 1, 2, 5, 3, 4, 3, 2, 0, 0, 0, 5, 4, 4, 1, 0, 2, 2, 0, 1, 0, 2, 4, 0, 1, 3, 13, 4, 17, 0, 1, 0, 1, 1, 3, 0, 9, 0, 0, 2, 31, 6, 14, 26, 0, 9, 0, 1, 28, 0, 1, 0, 1, 18, 2, 0, 1, 0, 2, 0, 0, 0, 3, 1, 4, 7, 0, 0, 0, 7, 0, 8, 28, 4, 2, 6, 0, 0, 0, 0, 0, 0, 1, 2, 1, 6, 23, 0, 0, 0, 0, 7, 0, 0, 1, 0, 1, 8, 2, 1, 0, 4, 5, 10, 1, 0, 1, 0, 13, 0, 0, 6, 2, 0, 0, 0, 0, 6, 7, 0, 0, 0, 0, 3, 2, 3, 0, 0, 1, 0, 0, 0, 0, 6, 5, 5, 2, 0, 2, 0, 0, 5, 0, 0, 2, 0, 7, 4, 13, 0, 5, 11, 7, 3, 3, 1, 5, 24, 2, 0, 7, 3, 0, 1, 5, 1, 0, 5, 1, 1, 4, 0, 1, 0, 0, 9, 0, 0, 0, 0, 0, 11, 2, 7, 25, 0, 1, 0, 0, 2, 0, 5, 4, 5, 0, 40, 1, 4, 0, 2, 2, 12, 0, 4, 0, 0, 0, 2, 0, 2, 0, 1, 12, 1, 0, 2, 1, 1, 0, 9, 2, 2, 6, 0, 40, 2, 4, 6, 1, 0, 14, 1, 29, 8, 12, 0, 1, 18, 3, 13, 1, 2, 0, 1, 5, 0, 0, 0, 0, 0, 7, 0, 4, 4, 1, 1, 1, 0, 2, 5, 2, 0, 0, 0, 2, 4, 1, 10, 0, 0, 3, 11, 0, 2, 2, 0, 2, 0, 0, 2, 16, 1, 0, 6, 2, 16, 1, 9, 2, 1, 1, 2, 9, 31, 1, 7, 0, 3, 0, 19, 3, 0, 6, 0, 12, 9, 8, 0, 2, 0, 0, 8, 0, 2, 4, 0, 0, 8, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 7, 1, 1, 2, 3, 0, 1, 2, 2, 8, 5, 19, 1, 2, 0, 2, 1, 4, 0, 13, 1, 0, 16, 6, 18, 5, 0, 0, 1, 0, 9, 9, 1, 7, 0, 2, 0, 0, 4, 16, 1, 14, 0, 2, 0, 6, 9, 10, 7, 12, 0, 1, 5, 2, 3, 0, 1, 1, 0, 1, 1, 20, 5, 42, 4, 2, 15, 16, 1, 0, 22, 0, 5, 0, 4, 8, 4, 2, 0, 0, 13, 0, 1, 2, 6, 6, 25, 0, 0, 1, 1, 0, 5, 2, 0, 3, 1, 0, 2, 1, 11, 0, 1, 4, 4, 0, 3, 5, 0, 19, 0, 0, 0, 0, 0, 12, 1, 2, 5, 0, 6, 15, 0, 0, 15, 5, 1, 0, 5, 1, 1, 2, 1, 2, 18, 0, 0, 0, 0, 0, 0, 0, 6, 1, 15, 14, 6, 5, 6, 0, 0, 0, 4, 2, 17, 0, 3, 12, 0, 16, 0, 0, 5, 2, 1, 0, 0, 3, 7, 0, 2, 8, 1, 1, 0, 4, 0, 1, 6, 0, 0, 0, 0, 2, 2, 6, 6, 0, 7, 3, 0, 1, 0, 7, 0, 0, 9, 6, 3, 4, 2, 2, 2, 0, 1, 3, 0, 0, 0, 4, 5, 8, 1, 5, 9, 1, 1, 0, 17, 4, 2, 2, 0, 0, 3, 0, 0, 0, 1, 4, 0, 2, 7, 0, 15, 0, 1, 0, 1, 12, 3, 2, 9, 1, 0, 0, 1, 4, 3, 6, 0, 1, 17, 3, 2, 0, 17, 7, 0, 12, 0, 0, 1, 3, 0, 8, 0, 5, 2, 5, 6, 5, 1, 0, 1, 8, 0, 28, 0, 3, 5, 0, 3, 0, 4, 12, 2, 1, 0, 5, 5, 5, 11, 0, 1, 24, 0, 0, 20, 0, 33, 10, 3, 0, 4, 1, 1, 2, 1, 1, 0, 1, 28, 9, 4, 0, 0, 0, 6, 14, 0, 0, 0, 0, 14, 19, 17, 0, 13, 0, 0, 11, 3, 1, 12, 1, 1, 0, 0, 1, 2, 5, 0, 2, 1, 2, 0, 8, 3, 3, 13, 0, 9, 0, 36, 7, 0, 1, 0, 12, 3, 1, 0, 7, 0, 7, 0, 0, 0, 8, 8, 0, 4, 0, 1, 0, 0, 10, 3, 14, 0, 0, 1, 22, 0, 36, 5, 8, 4, 45, 5, 1, 48, 0, 0, 0, 3, 3, 0, 0, 1, 2, 3, 3, 0, 0, 0, 0, 0, 3, 13, 0, 21, 12, 0, 13, 5, 0, 0, 2, 7, 0, 0, 0, 9, 5, 12, 5, 8, 0, 3, 1, 6, 10, 0, 0, 0, 0, 0, 16, 22, 0, 11, 0, 3, 5, 0, 17, 2, 21, 2, 0, 1, 0, 0, 0, 1, 0, 24, 0, 5, 0, 0, 0, 2, 0, 7, 0, 2, 2, 16, 11, 8, 23, 2, 3, 1, 2, 25, 2, 3, 3, 0, 1, 7, 0, 3, 0, 0, 1, 15, 6, 10, 8, 1, 0, 4, 0, 1, 2, 2, 0, 0, 15, 0, 0, 3, 1, 0, 0, 3, 0, 0, 0, 12, 1, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 1, 0, 3, 1, 0, 4, 3, 2, 1, 0, 1, 8, 11, 1, 0, 10, 0, 0, 0, 1, 1, 47, 5, 25, 2, 23, 1, 1, 0, 3, 0, 35, 0, 29, 5, 7, 14, 0, 16, 0, 0, 2, 0, 0, 2, 2, 1, 1, 1, 0, 0, 14, 5, 0, 31, 12, 4, 1, 4, 4, 2, 2, 41, 28, 1, 2, 12, 0, 4, 1, 5, 0, 0, 0, 3, 3, 4, 30, 0, 6, 0, 11, 0, 0, 0, 0, 3, 0, 8, 5, 34, 0, 0, 0, 9, 0, 0, 0, 13, 2, 0, 0, 8, 1, 14, 6, 1, 6, 1, 0, 3, 1, 12, 1, 16, 6, 16, 0, 4, 1, 2, 14, 1, 0, 0, 18, 0, 14, 1, 7, 7, 0, 0, 0, 20, 0, 6, 0, 4, 13, 0, 1, 3, 2, 0, 12, 0, 18, 0, 0, 1, 8, 0, 4, 21, 1, 30, 0, 2, 4, 0, 15, 0, 11, 0, 0, 0, 0, 1, 1, 0, 0, 1, 43, 9, 1, 21, 2, 0, 2, 3, 0, 0, 29, 4, 0, 0, 6, 15, 0, 0, 21, 1, 5, 4, 0, 0, 12, 0, 0, 2, 0, 5, 0, 17, 2, 0, 4, 0, 0, 0, 4, 1, 3, 10, 3, 2, 1, 12, 3, 10, 2, 0, 0, 2, 0, 11, 0, 0, 8, 10, 9, 29, 14, 12, 2, 0, 11, 3, 2, 1, 24, 5, 13, 0, 1, 0, 0, 0, 7, 0, 29, 13, 6, 10, 0, 0, 6, 9, 0, 0, 0, 14, 0, 20, 16, 38, 0, 0, 0, 5, 0, 3, 0, 0, 11, 0, 1, 8, 10, 2, 16, 0, 9, 1, 3, 0, 9, 1, 4, 0, 2, 3, 0, 0, 10, 0, 0, 0, 8, 4, 2, 0, 9, 8, 5, 5, 8, 0, 0, 0, 0, 8, 6, 21, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 6, 2, 0, 14, 6, 12, 0, 1, 9, 0, 4, 0, 26, 15, 11, 1, 0, 35, 13, 6, 0, 18, 6, 4, 0, 2, 1, 0, 5, 0, 6, 2, 4, 6, 26, 0, 1, 0, 5, 0, 1, 11, 18, 1, 16, 0, 2, 8, 0, 18, 16, 21, 8, 2, 15, 2, 0, 0, 1, 0, 3, 14, 0, 9, 63, 45, 1, 7, 0, 0, 40, 0, 14, 35, 0, 0, 16, 1, 3, 8, 21, 9, 0, 3, 10, 0, 1, 0, 0, 9, 7, 20, 0, 0, 2, 5, 1, 9, 4, 4, 0, 7, 0, 0, 0, 0, 37, 0, 0, 5, 8, 0, 2, 6, 0, 1, 0, 2, 2, 11, 2, 19, 3, 30, 28, 14, 0, 0, 1, 3, 0, 30, 1, 0, 0, 6, 18, 37, 0, 0, 13, 3, 17, 2, 0, 12, 0, 29, 4, 17, 0, 15, 0, 5, 0, 1, 0, 5, 3, 1, 0, 0, 6, 1, 13, 18, 51, 0, 33, 0, 1, 0, 2, 0, 3, 1, 2, 9, 0, 14, 0, 2, 0, 31, 4, 0, 2, 15, 4, 45, 1, 2, 0, 0, 0, 0, 4, 8, 17, 0, 0, 0, 0, 12, 1, 2, 4, 2, 0, 0, 6, 0, 5, 3, 6, 0, 7, 3, 0, 0, 11, 9, 15, 2, 1, 0, 27, 24, 25, 0, 1, 0, 0, 4, 0, 4, 11, 7, 6, 0, 12, 0, 0, 2, 13, 20, 5, 11, 16, 4, 9, 3, 6, 0, 0, 11, 3, 3, 0, 2, 1, 4, 0, 18, 2, 26, 0, 0, 3, 0, 3, 5, 31, 0, 0, 2, 12, 0, 5, 1, 4, 39, 1, 0, 14, 0, 1, 0, 4, 0, 0, 0, 14, 0, 2, 2, 0, 11, 26, 19, 40, 12, 27, 15, 0, 0, 0, 0, 92, 11, 2, 13, 12, 0, 0, 9, 68, 95, 1, 0, 3, 1, 0, 2, 0, 8, 0, 9, 12, 2, 1, 1, 14, 4, 9, 17, 5, 3, 2, 16, 4, 5, 0, 3, 0, 11, 0, 3, 3, 8, 0, 23, 2, 1, 13, 0, 7, 1, 9, 0, 8, 2, 0, 0, 1, 0, 8, 0, 5, 0, 0, 22, 11, 0, 28, 52, 19, 3, 0, 1, 13, 30, 2, 43, 0, 0, 28, 0, 1, 7, 0, 6, 0, 1, 1, 2, 0, 11, 0, 10, 0, 0, 4, 0, 0, 7, 0, 10, 20, 3, 10, 0, 7, 0, 0, 44, 0, 4, 0, 19, 38, 5, 0, 41, 14, 12, 18, 0, 0, 0, 10, 3, 7, 9, 0, 0, 0, 0, 22, 1, 0, 0, 9, 0, 9, 0, 1, 27, 1, 15, 8, 0, 2, 0, 0, 0, 0, 16, 6, 0, 0, 2, 0, 2, 12, 1, 0, 42, 1, 2, 0, 9, 8, 20, 35, 1, 0, 0, 2, 6, 29, 29, 0, 0, 0, 1, 0, 2, 9, 1, 28, 7, 0, 1, 43, 6, 0, 26, 0, 9, 68, 21, 6, 2, 28, 1, 0, 17, 1, 8, 12, 26, 4, 0, 0, 6, 8, 0, 7, 0, 41, 1, 3, 54, 0, 0, 0, 0, 25, 0, 0, 0, 10, 3, 12, 0, 1, 0, 0, 0, 0, 5, 5, 8, 49, 0, 2, 1, 49, 0, 19, 0, 1, 19, 0, 0, 0, 0, 1, 20, 0, 35, 1, 1, 15, 0, 59, 0, 20, 24, 0, 0, 0, 0, 62, 54, 0, 42, 32, 32, 2, 0, 8, 80, 0, 0, 21, 0, 17, 0, 47, 0, 3, 92, 43, 32, 0, 0, 4, 0, 0, 82, 65, 33, 0, 0, 14, 48, 28, 31, 0, 0, 1, 1, 20, 10, 38, 8, 44, 17, 9, 0, 1, 64, 1, 0, 1, 7, 14, 12, 0, 20, 0, 11, 0, 41, 18, 0, 1, 76, 19, 2, 13, 0, 8, 27, 0, 0, 2, 6, 0, 0, 24, 6, 0, 33, 3, 0, 4, 8, 2, 0, 4, 0, 47, 1, 3, 43, 1, 0, 30, 5, 0, 0, 46, 6, 3, 28, 11, 11, 0, 13, 9, 0, 0, 51, 0, 15, 1, 0, 4, 9, 1, 0, 0, 3, 65, 1, 0, 61, 0, 0, 7, 0, 0, 8, 2, 9, 20, 0, 0, 70, 1, 8, 2, 18, 89, 7, 0, 12, 2, 2, 1, 23, 0, 76, 101, 0, 53, 15, 96, 53, 1, 71, 18, 154, 0, 0, 10, 0, 14, 40, 0, 5, 0, 0, 9, 35, 14, 0, 4, 12, 31, 4, 0, 41, 28, 31, 3, 10, 4, 1, 45, 2, 11, 94, 0, 6, 0, 2, 23, 0, 0, 2, 0, 1, 3, 1, 3, 14, 19, 0, 9, 14, 10, 3, 25, 0, 5, 0, 0, 10, 0, 48, 200, 0, 49, 102, 0, 0, 4, 0, 44, 0, 0, 0, 0, 12, 112, 0, 1, 0, 11, 0, 0, 3, 108, 97, 0, 0, 49, 27, 8, 2, 6, 0, 13, 0, 0, 8, 0, 0, 0, 32, 78, 108, 0, 0, 8, 8, 0, 35, 59, 0, 35, 8, 8, 0, 8, 0, 46, 1, 1, 0, 0, 0, 1, 0, 119, 0, 0, 1, 2, 0, 11, 5, 26, 0, 0, 6, 3, 4, 19, 0, 12, 5, 0, 4, 44, 1, 199, 19, 74, 8, 5, 0, 0, 3, 6, 0, 1, 0, 2, 21, 108, 0, 31, 18, 0, 9, 0, 0, 28, 18, 0, 1, 26, 82, 0, 5, 14, 1, 0, 10, 27, 0, 0, 180, 29, 57, 1, 13, 3, 0, 0, 9, 8, 80, 0, 104, 23, 15, 1, 0, 106, 38, 0, 136, 0, 29, 100, 5, 0, 0, 0, 10, 56, 44, 0, 312, 183, 0, 73, 26, 261, 0, 0, 374, 3, 0, 2, 106, 4, 1, 0, 23, 78, 0, 0, 16, 4, 0, 7, 45, 252, 6, 4, 0, 4, 9, 2, 0, 37, 428, 5, 70, 108, 0, 0, 68, 11, 0, 9, 2, 0, 198, 5, 19, 40, 0, 20, 6, 207, 0, 0, 0, 22, 0, 0, 12, 0, 4, 2, 0, 0, 0, 1, 1, 24, 0, 0, 0, 3, 230, 0, 234, 41, 43, 149, 0, 146, 0, 22, 0, 1, 10, 0, 8, 65, 349, 0, 0, 1, 1, 0, 133, 9, 153, 145, 1, 0, 0, 0, 1, 9, 55, 0, 0, 2, 0, 81, 92, 3, 0, 61, 0, 56, 0, 149, 1, 1, 1, 0, 2, 109, 5, 260, 141, 6, 0, 543, 208, 322, 4, 0, 1, 3, 323, 3, 398, 0, 94, 119, 195, 2, 2, 681, 362, 196, 1, 328, 18, 564, 509, 0, 2, 3, 0, 140, 920, 0, 508, 655, 162, 4, 1260, 0, 0, 0, 0, 0, 314, 216, 0, 0, 33, 0, 14, 1791, 0, 544, 0, 22, 113, 0, 2, 0, 1, 0, 5, 0, 9, 289, 0, 0, 0, 5, 0, 0, 0, 967, 0, 0, 540, 0, 5, 15, 6, 1518, 1472, 0, 0, 13500, 0, 12, 0, 0];
+pub static HASHED_ENTRIES: [u32; 6294] = [1084260644, 1970880865, 28116180, 3189393663, 1059586964, 88065774, 67110302, 1054786760, 217760214, 85395131, 36553910, 1973518715, 3188312212, 3189639446, 1967686063, 703021827, 63145363, 2072708187, 45106409, 11600266, 87508706, 73222191, 17236297, 13517608, 3193359003, 1062503337, 1977271108, 20087557, 2111242808, 94177057, 563104542, 702251258, 1056638112, 69223839, 1964491309, 73500293, 1966391593, 84100779, 7651697, 1062781933, 19595626, 1050004158, 3190409567, 1054852297, 1044744738, 1971126716, 77923187, 210206824, 198658617, 1975337316, 153911379, 2072003475, 1061487652, 71845384, 710673907, 199543625, 619416481, 3191818740, 139592132, 1064371231, 13484849, 139281277, 104039100, 1978549067, 72205862, 1068401660, 207422985, 1965261359, 1979629794, 1046809168, 199183061, 1177911546, 27345862, 139198767, 3191048182, 1177076037, 1062994959, 1046366331, 198412770, 1972666747, 3190212588, 1979302114, 1065140745, 210387075, 72975983, 1971831165, 1077953133, 138821981, 1069171221, 1046285054, 3195013936, 135234315, 1964736821, 719127067, 2084602323, 24150809, 3934067, 1061241864, 198248859, 1968046362, 85508745, 20070714, 210550937, 88949432, 187957573, 2073904035, 3195112250, 1049594210, 111855439, 1978401299, 3194588366, 1056883884, 1047022413, 2072380369, 905266639, 1049070288, 159531777, 36406222, 198199697, 3192359462, 9716108, 195332391, 1964081507, 114804564, 1978155353, 1062863860, 1061831671, 206718527, 1972535634, 153141315, 1060965454, 1050773850, 187401029, 26460962, 1068368879, 24511050, 1057866812, 1054737606, 197249100, 176767145, 135102470, 3196439564, 16845883, 1964606038, 54691116, 1057522699, 1067631774, 702792301, 210075754, 1117113436, 1047693819, 206702139, 73614994, 217907404, 128401843, 215532028, 140149118, 1969586731, 1057162152, 3194374912, 38012133, 3189770527, 145113857, 1044810728, 82805081, 13140777, 7340497, 103925467, 712084546, 190367553, 1969652293, 1975616328, 1973403997, 55330152, 701383059, 202262688, 1055097915, 94308138, 1177026931, 1972338981, 196807704, 1970110843, 1055474905, 85705123, 1061127163, 114607953, 24199973, 148570457, 1974960849, 1066566411, 83936062, 111150925, 75155030, 1065092100, 153206802, 3195817182, 1051134501, 718586701, 1968652753, 718308064, 74122684, 1053361029, 1965490458, 138560105, 1972306202, 1969111372, 905807451, 1969455556, 135743439, 1975566681, 135397656, 1046694782, 1973469540, 1973649839, 2074756234, 165380356, 3194783771, 15811141, 3188213897, 89589520, 29376691, 18661916, 3195374441, 1049889419, 145293668, 1050233704, 3191376329, 1975321307, 3188115577, 94324523, 3195030321, 710559196, 1061782515, 1976582985, 158500668, 8831708, 86361808, 1047038262, 1963704906, 1061749778, 138658243, 152911889, 1067713427, 3191409098, 217842150, 135332023, 153338102, 1047955909, 3192966325, 83591400, 160663426, 84199086, 1968767543, 37438245, 1161036114, 175997114, 104335107, 3196144613, 28345561, 24625617, 173623153, 1955677037, 1963835689, 1068876851, 1970471479, 1094107976, 3191359943, 121815761, 1048202134, 710657520, 3194014436, 139512955, 1208880215, 3187918950, 1963606567, 1045153939, 1044777743, 1050118903, 1967259951, 30425881, 1964442133, 1063683198, 1045285747, 51234133, 6898217, 3188754626, 1965736285, 2072855459, 16600124, 1973666227, 196119351, 1969996125, 3189132423, 37520568, 134857325, 3193440269, 1974584135, 1971765604, 1966277198, 1976074553, 3190278483, 1051888591, 28656149, 175523779, 1070727617, 150946257, 3196013795, 2072232989, 25805265, 3190524263, 137052484, 1975731020, 1047923018, 1093731021, 691047465, 1967800785, 67994396, 134512813, 905119165, 1974305106, 1058423963, 395577336, 195988190, 195578349, 197478556, 207046285, 719226711, 11911341, 213008882, 691847506, 2084880413, 1063814061, 6766929, 1050266502, 9503090, 73696923, 1060537488, 1963491780, 90408728, 174475214, 206357936, 187171651, 904923189, 1046301058, 84787824, 1064322097, 29393075, 5341939, 1058833328, 75384414, 1976402316, 1045923905, 1062896633, 1977287037, 29131963, 1971356229, 1068827691, 1976746741, 2085011492, 1058292254, 2073887727, 50070841, 2074887164, 13648659, 1962950948, 3189852459, 1977680453, 2109849886, 1093468513, 197232715, 1060897943, 1167101035, 85836328, 3193604788, 130007638, 206456269, 200494096, 176703291, 196529112, 1161135382, 90261263, 1967669667, 134824117, 12124937, 1056392325, 3189164265, 15712789, 1062798335, 1068762149, 3194358526, 3189557516, 160303006, 207685194, 197314668, 19382995, 10420788, 6701520, 1964065121, 3189737758, 215384273, 1062126620, 140395090, 20333278, 1055523349, 1186581616, 11665413, 1977172803, 176195466, 197265486, 56329578, 49939754, 710526420, 198101321, 2072675387, 3189459205, 1539425082, 9978300, 3188526213, 1974912882, 1971307053, 28542093, 1065239556, 31130586, 1045023538, 9650742, 10174979, 1966047684, 1051036131, 1969619511, 4031394, 2072839151, 207652402, 1048528976, 3193178753, 1968275794, 46661970, 2111488330, 1059472547, 158958183, 1060258893, 200215533, 1047596059, 197181623, 1969406387, 719062872, 2084700399, 26919084, 88113838, 3191474637, 37356727, 1048037653, 2074379315, 1061553134, 152684478, 39797015, 1060701092, 1051478725, 1963524559, 1050544329, 11714565, 199248607, 1058472923, 50185518, 1050348713, 3194915625, 160171979, 3188082769, 153453023, 1048283688, 1963295099, 1060471921, 199084722, 3192130726, 94275368, 1058882486, 3195161406, 1965408842, 2074739731, 1058276480, 1069253761, 1968996661, 1970258355, 121897707, 72336981, 1971995073, 1055278294, 1063978064, 142934183, 710575582, 89014970, 5554711, 15155532, 73205723, 1976976190, 88606457, 71960081, 1967423826, 1044728311, 710723090, 16532007, 44287268, 19891050, 21185265, 1055081431, 1046005915, 1977697013, 3189934058, 210649212, 1966899652, 1973387602, 176637758, 1045973570, 3194899242, 1067975607, 1964261795, 1044531443, 1057850426, 17564044, 29425920, 84166317, 1976041775, 1057588244, 187220519, 1050708280, 153616439, 73926307, 95437265, 1060045862, 1050577096, 206767685, 1968734763, 104023774, 72402480, 1048774755, 905741802, 139133220, 1973535101, 1978499658, 1047152851, 198838888, 1967096389, 139496496, 3195800491, 3195685790, 1062552497, 1966686579, 1974157700, 1051167278, 1057981508, 48399659, 1050511504, 165495391, 1976615754, 199723895, 1049659808, 1061585915, 83624654, 200117209, 2074854429, 71665082, 720077575, 197921062, 3196340267, 1062962189, 1061028900, 215630311, 88048300, 1186696306, 1973076554, 1979039994, 3191245240, 1066223519, 1069368396, 1062568882, 15106369, 1964179842, 905102777, 697418180, 1064092793, 193578684, 87967467, 89047739, 72189477, 1967964746, 95455029, 1068844083, 2093352884, 198609458, 207160928, 1972552029, 1057358786, 195529183, 139247945, 1978565159, 152602553, 1964769745, 1965212109, 1068336111, 198691395, 198363607, 1070989906, 905283025, 1968374126, 1975992612, 1972896207, 43679988, 1968128306, 1160135932, 1068303327, 1046792740, 2084929568, 135643144, 153075775, 2085109802, 1060078636, 1060144180, 133136836, 1978597909, 1063126051, 1068041151, 198986388, 1090961733, 1970979197, 1046071341, 1060389996, 122094149, 86884864, 209764445, 1057457127, 2074018954, 2085208112, 73434750, 93029085, 3189065943, 1046858458, 1969946953, 16580953, 1967718844, 3190294871, 209846377, 1062716396, 215351644, 73385594, 3193244858, 1061929974, 73877563, 1970667823, 3192261150, 1975863154, 134266880, 59982620, 1051806619, 718848583, 1966932431, 3196160999, 23823226, 1970766145, 186712890, 3193047669, 149964699, 82395588, 88327924, 1063011350, 3193490086, 138592569, 215499007, 905381358, 17121583, 1973142105, 1070842389, 3189754141, 1049430164, 1967178329, 1056507075, 904841175, 37815521, 1963147596, 45745391, 1054278853, 19874223, 1060242497, 17203521, 188285778, 1972732300, 1049021074, 1975403333, 1062618044, 197134292, 1056752809, 153485797, 210272376, 690916394, 1044384606, 207013506, 147408788, 3194211065, 1044449805, 3188574386, 1069122672, 1048693629, 703381885, 395086122, 1045727378, 1068712997, 1054885065, 1055327445, 1979613403, 905823804, 1070645662, 1540407682, 2074870827, 207259091, 1091617543, 1060750314, 15796062, 1051970708, 41517394, 1540260195, 195299598, 1067599006, 195397967, 206816865, 3193850577, 3189590286, 3187902565, 176180306, 55100725, 29246655, 1970831698, 1979236817, 1170309706, 1047301054, 58361198, 27657180, 9372066, 3195521916, 1056408713, 719340077, 1967915575, 26641175, 3192702979, 1050445930, 38061279, 190070785, 2085093417, 177162131, 718799716, 1049905804, 3192162835, 74319359, 193873764, 2110980389, 1070612876, 710772271, 1969340825, 49448255, 84313779, 1972159021, 134627333, 1955447058, 1970733521, 1086603593, 1047497114, 1050217319, 196790680, 58901878, 86820567, 29148348, 28165210, 2074969262, 1047841715, 206374325, 1045465318, 1971847554, 18383261, 1070514191, 1070203193, 1067697043, 1969881553, 3188771011, 123388486, 905528837, 1060209727, 74860013, 17039642, 710870623, 56116578, 1063470177, 73582222, 82542871, 193758093, 5538134, 1044826184, 1966195245, 1055179987, 1977647637, 66422947, 1971044761, 26592033, 37765912, 1540178273, 1060275281, 74188238, 1084965715, 112050985, 51086679, 2074231946, 87982762, 45711893, 1977352588, 1963639341, 1045187028, 3191622105, 27919567, 1975534407, 1971650889, 3192752732, 719325130, 200477709, 1058374802, 1977434951, 9882684, 138625337, 73139855, 905561610, 1050872177, 62079772, 3192147111, 17318181, 198265244, 14647555, 3196046289, 1071056808, 1964048735, 5882436, 144737986, 3192899589, 1971732833, 3190573419, 3188541980, 1049561366, 207783578, 212893913, 1084557116, 1068483594, 1974649553, 1064207439, 1160021240, 701284751, 84951318, 1968538031, 1972912593, 1069483079, 3191457787, 1064174708, 1965146547, 61261178, 1091748587, 1160840460, 3768720, 1062700004, 206751300, 17154258, 3194505993, 905348577, 3194653463, 199871397, 1972502857, 6668805, 1975698251, 1084342578, 3189311738, 1977958788, 1177616985, 153223636, 691880813, 1955496119, 86196542, 1968226629, 187974407, 1968341347, 199428896, 1057293493, 3195832355, 1971487013, 68157973, 153059400, 1046842046, 1058589775, 1047660992, 1979089083, 3191786659, 199330543, 197412993, 1047104167, 1047989173, 905725480, 90457878, 206685739, 1965359671, 1978483529, 122291019, 166035629, 1060029473, 1971405394, 58377583, 364234931, 1051429546, 193725031, 83542361, 146522743, 86296270, 1050527895, 3194932013, 103973533, 1971683666, 140280262, 1177059634, 133169760, 17793557, 196840474, 2084716789, 16023938, 1970684210, 153813068, 87015611, 136053449, 1055671512, 1976025918, 73549451, 199215831, 88621748, 198216087, 165298337, 1963688517, 99517829, 69733434, 1045236434, 20988323, 904676991, 1165413480, 1963540945, 6603062, 202064679, 1969144157, 199707510, 1050168135, 1046088179, 372951254, 136203320, 1062536108, 74040953, 1069089883, 28411011, 140329547, 152586168, 1177256239, 1160381703, 45843698, 208994695, 1055539733, 217661675, 1048627886, 3190180166, 1068090311, 137200126, 187367805, 1056605343, 1969717846, 1055506965, 88851054, 74171853, 3193392317, 1974108986, 3196357863, 24969222, 3188967634, 85590894, 56673525, 3193277074, 1055655128, 91047785, 1979334894, 1975583172, 2072036300, 1186827382, 702939442, 75351632, 145834605, 8995418, 143589984, 1971913118, 7586109, 1053410185, 1060668308, 1057129374, 3191327172, 1975059258, 1972470081, 92866309, 1054622929, 83083673, 905233867, 2111276930, 72255017, 2703747, 1975927541, 3195325655, 139215154, 1979384146, 1965130159, 44352801, 3196488721, 714443844, 1061438489, 1973764561, 1051314797, 1060324426, 1963901241, 1050560708, 703447887, 74237411, 210616474, 29114833, 1969766692, 2072200304, 3190933490, 3192523328, 1062044647, 1045449553, 142983331, 1965801828, 1979121883, 84985528, 74843623, 197609651, 1059275903, 1045580735, 1955512711, 197953838, 1964032349, 1051265636, 3195981026, 1051855813, 74909174, 3187951726, 1972420921, 193644414, 905021090, 141526843, 3190310895, 74204632, 1969963340, 24183577, 1055311061, 138887521, 17220327, 217678065, 37979364, 1057211318, 1967161942, 3193505806, 557062, 1091699255, 718045615, 1970782533, 1970815308, 136855848, 138756428, 3192785503, 1047726665, 1976844633, 28820252, 703054405, 135053318, 1093944133, 1170211375, 1064354882, 719768636, 140789559, 1046956174, 104071870, 28214410, 2073969674, 3188394146, 85852811, 140411475, 1061176254, 50644298, 14419913, 12288006, 1060439162, 62948761, 1969701458, 1070744004, 94258983, 197445782, 199609183, 1965834611, 1051003325, 1045891537, 3187771481, 1045072381, 157419384, 1971225135, 1968914724, 56460651, 22971122, 1054999503, 3193752772, 36242640, 148439658, 3188591750, 176734373, 1066289059, 195545574, 1973436769, 206423489, 21136099, 1045416863, 1963868466, 1970307524, 2072544183, 691437861, 137019822, 1967833616, 1095188851, 719635033, 3196455950, 3189672216, 84886248, 206325162, 1070252353, 904955960, 1979220306, 1062061041, 3189704987, 118425429, 710854226, 85820019, 1954892848, 670091271, 1971798387, 690424867, 27149455, 3195603855, 72468019, 1974879054, 3194047205, 2089828472, 17842590, 1976304002, 1054770376, 1061618628, 1969127762, 3193162366, 90228493, 1968849490, 698319626, 6652369, 147867602, 3193572017, 1044465727, 138854753, 153829453, 76546884, 1050429561, 143213047, 1059554200, 176947797, 1165446250, 1539441011, 1972847041, 71828994, 1968882265, 148718415, 1091093206, 541084084, 1979252923, 196873245, 24904183, 27493328, 1059243132, 134480045, 1966817699, 1062519722, 3196095453, 186941905, 190136671, 1157942367, 1978302860, 2073805853, 3188344984, 3191130227, 27083276, 1049741840, 187630427, 2072167494, 691323177, 49251624, 45024037, 1973420383, 92127524, 3014828, 1084604772, 96207684, 209110965, 1063044119, 212992254, 1056736425, 1051560768, 1964310972, 1975880764, 1061405698, 1970504261, 1055163603, 188368817, 74417729, 145884990, 30688160, 73844793, 1970012511, 1059931148, 1973617054, 1047087585, 199855000, 1965244881, 1054557391, 1054229678, 217694455, 1068139468, 19317458, 153256148, 2084733179, 92012873, 1046022786, 1058669740, 1086849489, 719782473, 4833731, 1963884853, 3188279440, 1066239904, 143378300, 209797217, 140214569, 210239606, 1976813426, 1161053460, 138691025, 95781193, 49464641, 2084978722, 73762778, 3194325759, 90292577, 1068958779, 1971880332, 198183312, 218005702, 1979531514, 1046580138, 542132963, 1060013086, 1058358416, 120996652, 1063699582, 1093143642, 1045219950, 54789471, 1964474923, 1051773831, 74434116, 103907995, 195119183, 3190425952, 3190787222, 143294830, 1047317442, 11141696, 1967538547, 153796683, 1051200062, 3191441868, 85573999, 3193900232, 1049774637, 3190491496, 1965637950, 3195653018, 144032014, 3193261243, 204654667, 85493444, 3196406790, 6783507, 3190017258, 24936454, 1975157201, 199396085, 82577353, 1955579702, 1047185697, 1963262318, 1051118103, 1053705092, 196692984, 3193621697, 17531375, 196365221, 157073789, 1068237789, 1047268056, 152928275, 1070104868, 691634547, 1972568415, 719995466, 1974534742, 1052003485, 3193818310, 71697874, 1965719890, 1177682453, 1063486569, 198330800, 124700465, 21430651, 3187787860, 87589540, 1160365318, 1046629386, 209193028, 1963213153, 195840712, 204081445, 135774208, 29033241, 1974993447, 1054065535, 1963573776, 1977041241, 11796736, 1054704838, 1061471268, 1046186768, 1058557003, 1051823028, 1968439682, 1069417545, 699613960, 1972519244, 124241761, 700203798, 3188934869, 1047890435, 199117510, 1966489918, 17498521, 212877520, 10142212, 3189376998, 28509322, 1067057543, 1968144693, 74663378, 1972044239, 20267408, 3191835120, 1049692617, 1068499978, 7635482, 56296806, 905004704, 1177895144, 1060619430, 1967587714, 210026594, 71468575, 83018457, 1064010899, 1063666806, 96141568, 198019389, 7258318, 1086096380, 25313995, 700104983, 75138588, 17761023, 22806977, 140050803, 82755897, 207799963, 2074281051, 2074428323, 20136267, 149358639, 94242598, 1979465954, 3191753890, 710837836, 2072462474, 1093894510, 1084326191, 1975386493, 121700766, 1976730181, 154255974, 198494699, 197150767, 1973207337, 1047366291, 3191146927, 50332440, 1970274748, 1160250625, 1045121110, 1063928832, 28574915, 1061192638, 31425505, 3194407686, 195365187, 1070596101, 1059193948, 1047169287, 579307551, 1964704047, 1053475723, 7291447, 3188983940, 87934698, 1055048668, 3188295825, 148522681, 1048561799, 1974453061, 68765094, 146784559, 112002266, 1068991558, 7553314, 1063437415, 145064281, 1044892445, 1069286538, 1964343748, 42190108, 197331056, 593595246, 12780311, 1977403236, 1971438169, 87672552, 139100481, 19366212, 1976959802, 3189836072, 1058243709, 1048021176, 1048349687, 3189278968, 123454001, 1964294579, 158566294, 1976697365, 186729005, 206652950, 2072216543, 1970995586, 23151125, 207226304, 607619980, 3194948395, 201459637, 2109803603, 1969373603, 3193212089, 1975960395, 176783530, 153403861, 1967407436, 148505186, 139051741, 200035273, 83394909, 1095106881, 2089812113, 82313513, 718225941, 63407511, 1968488857, 1969914177, 1051462334, 1060717747, 3194161902, 85427903, 46154520, 193364144, 9453847, 9027991, 1967931964, 146802548, 198953594, 2074051790, 3190704509, 3188852938, 1054377156, 193430113, 9798060, 1046924192, 1972093456, 19628418, 3193965721, 196102945, 50364949, 29164733, 43630842, 197576878, 3196062675, 145178969, 55608684, 1148306675, 3190475108, 7389185, 139166127, 89359042, 1160709392, 1067795363, 1966965295, 207390208, 3188197512, 1048218555, 431851507, 1070678447, 1976763213, 1973158170, 1964392913, 526224056, 1063273555, 1068172241, 217923774, 94144288, 445237710, 1058751389, 209862764, 153649209, 1965818222, 18613061, 1065255941, 1977303877, 17875542, 1976353605, 1063355477, 26738867, 1970389520, 207439370, 74925560, 83165563, 1063863229, 1059701688, 691519817, 7127053, 1058931891, 209895536, 210780286, 197527719, 121078566, 3192097284, 1050282940, 1978090306, 1049397349, 1970537038, 1046121142, 699908467, 196512726, 192791351, 1047579528, 904709764, 3193408701, 1054803144, 1177174404, 1954726744, 197970224, 1055360138, 3188230282, 1057932352, 2072429605, 691945644, 1976664615, 37307575, 1085113158, 1975550483, 1069335690, 206587375, 36652170, 5506972, 1177420142, 1068123084, 85443207, 3190835587, 2084995107, 16875985, 3195176927, 86001356, 198740566, 84525677, 122979017, 2073625109, 25739788, 51905862, 3195947045, 31081586, 1048103413, 1969062209, 3189229813, 207357438, 1056720042, 2084962366, 71894539, 698893144, 1064158275, 1973027383, 1046563459, 42042647, 104630064, 88278769, 3193523391, 36324572, 172687954, 904874034, 1056916618, 196070135, 136970594, 2073183370, 196922399, 2085240882, 19906993, 77071213, 207308269, 1056556227, 1062945786, 7930168, 3190393182, 3194981166, 2093254579, 1062667238, 210354303, 90507037, 1047775925, 1963426223, 206570986, 83247475, 1954791815, 1974239975, 159992923, 1973043772, 1067680209, 37536546, 24117713, 73631382, 1071202925, 1050986935, 1975452044, 1965539625, 71648696, 1160086778, 1170457166, 1057768498, 1969504721, 1965310503, 3194850085, 200559639, 3190327278, 1158876261, 17482138, 61653790, 1068450826, 1052803512, 1057604629, 36455553, 17285955, 1969176929, 2110687172, 217727473, 86116042, 1974321987, 1046759821, 1051380353, 209731673, 56264041, 1054098625, 1056277573, 3194292988, 1160267010, 1965031810, 198789722, 1975124281, 1061569531, 720144220, 111413069, 1968833102, 1046383048, 2073723963, 904660604, 1063879609, 75204147, 1047415131, 30966994, 1050200918, 186797116, 1046055244, 7160198, 3193736387, 1062601660, 207275487, 53200209, 7537169, 200149987, 13108014, 11108926, 691732866, 15090129, 1973600665, 1966473681, 2085191727, 138428814, 3188918479, 1964130675, 1058047062, 200084432, 7455046, 1966113327, 7602634, 1059112004, 19645014, 3196390633, 21414443, 3192425011, 15057209, 1975107972, 62326160, 1977106761, 3190442335, 1059636139, 1068565523, 48268060, 197724400, 1063994456, 3193326233, 1066631563, 3195783202, 1062011874, 1050790240, 1968619981, 1056965793, 1047202133, 39404782, 1978778357, 1057817654, 1056588938, 1977188701, 215646698, 207717982, 1973813781, 3195930660, 1045612819, 1070547105, 33456523, 710510035, 1058767774, 198461928, 14958874, 1046432592, 2621440, 24658453, 153731134, 1974616409, 1051609934, 143933711, 1972978215, 217972932, 18156604, 195266659, 1050053321, 74106293, 2072446090, 3194734618, 199674732, 134055594, 1068598299, 1063076895, 3190164674, 703040558, 213025275, 3187705923, 1060996121, 1062634435, 697368763, 1047644300, 1073432495, 3188050037, 691750706, 1068221405, 153518571, 1976172876, 21168437, 1051577157, 1050495087, 1964785982, 10093004, 1063846836, 9404780, 73795632, 217776602, 1059881985, 1786415, 8208831, 23134643, 17613357, 198904431, 1976435286, 1052051463, 152207752, 905201097, 1967358273, 166855049, 1160463626, 199822229, 19693952, 3193916121, 1085030756, 108283724, 2111113020, 1970405909, 1050839393, 718406189, 1044663087, 1050299343, 7618904, 75007482, 27100296, 88966910, 2085027877, 32670161, 104400658, 72779181, 19841473, 96125549, 1044875818, 12960953, 199920567, 19613535, 1978204546, 3189115107, 187236645, 1055556117, 26624790, 206833253, 7029314, 2074264646, 692011264, 3188492460, 1051511555, 5030343, 3191917052, 153665594, 204867539, 6324635, 70894166, 73991785, 139461339, 905872969, 28001349, 74335752, 210567301, 1967079996, 52479221, 2050081233, 17089338, 61752711, 195413997, 28427396, 204458221, 1540358426, 56509796, 718242333, 1049856648, 210042980, 1178222885, 1976369990, 1056982178, 698286871, 3193670333, 3195439985, 1058079833, 690752555, 93143878, 189645543, 187596973, 11436038, 27034124, 73287272, 14254876, 94291753, 143491328, 1979695354, 1974747979, 189923959, 1069073499, 2109754418, 9863906, 1046530461, 703433777, 74368543, 1048398337, 3193588399, 691454678, 16728790, 153698364, 1047447648, 200395782, 1971389006, 210485384, 87312053, 92833538, 134381568, 1160119547, 29197502, 1969832242, 197789962, 153321712, 1972208197, 135021260, 703398180, 187908836, 123470356, 142950583, 52757833, 1045007078, 135004875, 10732118, 3189213423, 283559907, 1060865149, 1054901452, 3192654414, 3187673154, 1975796469, 2073150399, 10191361, 1080410489, 3188689084, 1966719357, 1972371759, 1056900234, 1050937748, 1053098868, 86704789, 17810251, 1976715102, 193446499, 61998473, 3193637562, 74745306, 200018888, 2073248880, 3189623058, 1044482228, 198314412, 700255284, 1056375943, 71714262, 2074706909, 196414380, 31048924, 29066189, 705430765, 1963459004, 199576412, 3189722254, 73320045, 207455760, 196332462, 1059013682, 198068546, 148799884, 1967391049, 18678131, 114689874, 207701587, 1973502323, 1061307377, 139575727, 89785028, 905578000, 2073281565, 84607809, 1975485014, 3192851633, 3193096201, 72435250, 691783623, 1177485668, 3195079478, 15483276, 86590266, 196168520, 3194637077, 28263598, 3188361369, 1977008516, 199281385, 1067910318, 1975025946, 153124930, 207832735, 1084490109, 3189967156, 176750758, 217809376, 1062929402, 27903019, 29229799, 29311889, 134643884, 1963721294, 1969488335, 1970700597, 87212703, 1970291137, 108267339, 3189426433, 1086472657, 1978106194, 3191556565, 73401979, 3193980947, 17301853, 712230075, 82788738, 1973485934, 75302474, 3192293920, 1974223169, 3195276114, 197494941, 206734913, 153894994, 1966293586, 1044597099, 197839120, 1160529166, 3192211990, 1057277109, 200068046, 1160856143, 1045252891, 904939575, 1051445945, 3194752209, 3190229323, 1975845711, 1085014345, 197740790, 1063093282, 1539670341, 10011222, 3190196552, 1068106695, 1963786522, 1973944910, 3191195668, 200182758, 598133621, 704497620, 189546841, 1050659060, 905692709, 1056490626, 134283264, 1974010308, 26969226, 50038055, 196217691, 1057670182, 90947993, 63423893, 1051282021, 1963229539, 215548376, 73123469, 200248304, 1974469446, 904693379, 14634039, 1060176957, 1964556874, 39863064, 1963098433, 1061356536, 3189983541, 27050507, 74057130, 1977582101, 1058194328, 1048152778, 8176037, 72074777, 1093714689, 1051068942, 1069563905, 2072953893, 1048169273, 3191884286, 1972437457, 203850524, 153092160, 16007513, 3193342618, 200166372, 134610949, 1969537552, 143098392, 1050151743, 153944149, 2072314863, 2084569429, 1055130683, 14025497, 1967489379, 1050102519, 1062077427, 3196177385, 196053751, 190202625, 1070793169, 1978925243, 83657154, 1972601187, 3187837025, 134563796, 1969603117, 1059783626, 1969013049, 1059718075, 140559062, 1047677379, 1970913636, 18268611, 210796700, 1160283395, 3196374248, 1952137395, 1066139904, 1070334301, 135168274, 1178272125, 1977729870, 1061995541, 1970618660, 201754561, 2074821744, 148767179, 1044794258, 1069024326, 1059505312, 8143146, 195184770, 20037946, 1056785579, 10781412, 207767177, 1067451280, 1068680221, 176358140, 904775319, 1975206217, 193970796, 1045711792, 207177143, 49923376, 1148652607, 1976254809, 166936967, 1968636367, 196823515, 1057555470, 1058341410, 1045662223, 1965867389, 710608355, 1070399844, 1972355369, 3192539716, 153174085, 193939406, 1069057115, 75497838, 1045940487, 718258925, 75236953, 135086085, 1044761159, 1062978575, 199592797, 196479940, 217628895, 1051937775, 1048480316, 71812609, 3195899103, 74958334, 699156425, 698745175, 3194670291, 73713309, 1084522834, 199658347, 1969685070, 3194440455, 718832364, 1047382932, 1049528545, 2074788934, 905643552, 1059619752, 3192555521, 1061667787, 72730186, 1047628719, 1050724666, 210714749, 1052134428, 1970798921, 51070288, 11518833, 5865835, 14975268, 28607626, 1051691858, 540182963, 1048840414, 595495794, 149981082, 1049446565, 1048316698, 1068893235, 1964933475, 1050069716, 1093928767, 2072282163, 206849638, 27558869, 1059456162, 1976238429, 10519098, 1177551434, 1045269283, 1069155440, 1976189762, 3189016792, 6914401, 3187723390, 153878609, 1048185660, 1963344260, 17826343, 1965277712, 3190884748, 175475796, 3192442027, 1059161177, 2072773675, 93127389, 55575870, 17860446, 1046317662, 1094058750, 1971011972, 905446901, 97337527, 87230175, 42059034, 1054819528, 177194873, 15533924, 710460874, 22233454, 184272750, 198445543, 199133901, 217792989, 94077522, 2084896798, 1049364568, 1964900703, 1975911121, 197363831, 1972797871, 1978761937, 190317374, 3192408622, 1979072977, 1054131395, 37618482, 92849924, 710805056, 1054475407, 2084618428, 3194096843, 123437499, 7979453, 1061389289, 1048513367, 86442643, 1178141887, 1967850005, 177898081, 1051396766, 2072691782, 73303660, 36603022, 3191507408, 1050478692, 153747534, 1978794535, 72451642, 143344379, 14745605, 138543965, 198117710, 1963000111, 1046497526, 82821852, 210370681, 33211158, 2089943180, 122176078, 1068581915, 1973748175, 3189655833, 1965883778, 148734360, 75335247, 1539935280, 86344337, 1954971993, 104253258, 25756808, 85328240, 15286658, 1045433345, 2074624915, 444221436, 3195620572, 1973731789, 103645894, 153845839, 1972175415, 138707269, 83329349, 88508150, 3192670799, 143278957, 3190082285, 1976222531, 1048266937, 2074330141, 123503157, 1080427015, 3188099192, 1971568953, 1977598795, 1968980274, 203885649, 85312116, 1964999035, 1048873382, 1091028936, 1047464200, 1972879821, 195217378, 1979482344, 1059832807, 2074117047, 904791708, 1978008017, 3188869323, 1057244336, 1093403367, 1069237377, 33374213, 87179930, 1966457145, 1955234117, 217825763, 1970045283, 1972781475, 176244616, 3190851568, 1970192793, 158091173, 184256365, 3189475590, 36635854, 1966834095, 699139891, 74352150, 187040073, 42206488, 195659838, 3190245712, 197642454, 1539771442, 3192227840, 153469410, 1973190949, 1050413102, 140247492, 3195702172, 1046449047, 2074477521, 1160512781, 94127903, 3195669405, 3188262881, 1966555468, 1954857266, 1977205081, 1964573262, 1070235966, 2072495310, 1059226747, 123699785, 1975976218, 139067780, 691372341, 74515889, 1084801854, 3189033177, 3193080438, 1063895997, 5013998, 1084834098, 1977155922, 198052160, 165281807, 56558953, 53249364, 1963802916, 1967620492, 12419078, 1068057543, 133120005, 1058899121, 1976287588, 1058014289, 1970160004, 1062585267, 1080559564, 1084391889, 209961050, 3192687186, 1965588789, 74155457, 1060062247, 188629384, 74679763, 140116469, 2074346539, 242010084, 18170423, 25378988, 139559331, 15614404, 1054082241, 215564763, 1060111406, 3195226144, 2072363975, 1963032885, 1067877550, 184223595, 3194063102, 29344281, 1084670116, 144081164, 143524015, 1049610596, 13468464, 1965392453, 2074952611, 3191425478, 1054213294, 1054491791, 1059914763, 1977024825, 121881109, 7487990, 26821141, 103958236, 165445647, 195857095, 3196308475, 1977631564, 26951843, 23184242, 3192802448, 200231918, 1063175209, 1044695945, 3196274729, 196234090, 691585377, 166052559, 1086882173, 26985678, 1061077998, 1046677981, 147490742, 1059095617, 200199148, 92391143, 1058178158, 20464356, 148277053, 134316032, 1974570044, 1049004686, 121963231, 75023875, 1051659093, 1052101848, 87574244, 209176645, 1057112990, 1971536178, 94371840, 41420023, 198969988, 1058620581, 75089431, 1068008383, 198281630, 1049201550, 6816083, 3195865376, 1974600211, 2072331171, 2084585812, 3193883847, 196299652, 3188426918, 158451516, 1050086122, 1060570260, 905184712, 197165447, 86460115, 2073216070, 73664153, 1973322049, 197773571, 88081069, 905479675, 215581150, 905463287, 1051347573, 1964949860, 690293799, 39093038, 197511327, 1053508490, 1158401124, 59049331, 22561358, 1052819893, 12452096, 26853747, 201476022, 200576025, 1056769195, 203948296, 152569783, 1963327874, 1056703658, 74646992, 1186794612, 146607164, 3190966677, 1050741063, 195348784, 37881062, 701497668, 153600054, 1053377414, 155764650, 15827701, 17777412, 1969160543, 145801582, 16515793, 123568732, 24526853, 1069762516, 199805841, 1058489501, 72304212, 88475381, 50234678, 1057571857, 1976107473, 23003664, 710477262, 1055409295, 1057637406, 207145007, 1057309624, 1051790227, 74089904, 85131630, 1060766688, 1965458006, 210288775, 123666892, 1977123148, 1056933002, 1063945205, 1963770457, 1068024767, 207062671, 2074657740, 1061717000, 148753469, 1049103132, 1057719341, 196905031, 44073753, 1979449563, 178947885, 3194964780, 1061979120, 904972346, 1058524232, 12501775, 2074002469, 63112594, 73041526, 5276168, 72500789, 22185641, 1048333238, 904857560, 75286088, 1054360770, 86835864, 3190655354, 175179730, 85935817, 1049185158, 701268251, 1054409924, 161107068, 3194243834, 39125779, 71861769, 58344816, 1063584289, 122652486, 1965326891, 1084932434, 123602784, 187138386, 3190589806, 196397991, 1047006008, 1974436221, 1965343277, 1969734233, 17908306, 138527449, 19677784, 720208593, 210190439, 1044957977, 1968783932, 207537688, 1046416116, 75220534, 1969897790, 197347444, 1048464043, 25330380, 6291869, 55559486, 1975502692, 73483910, 39945462, 1969389999, 1047349335, 6832662, 140378569, 1963360652, 1070760397, 1095172389, 71730666, 201375750, 1046907781, 1053311869, 206866026, 1063371863, 25576226, 1963966789, 710821446, 215368207, 1969422780, 1045302209, 702185682, 1066795181, 1048709940, 186974529, 1048496671, 1964212620, 7144023, 1091782491, 140855104, 691536204, 72844741, 2072888263, 71927309, 64652698, 1061520426, 1967866407, 201441618, 3195063093, 206489048, 1973830183, 1067746195, 1964376527, 7520590, 1954989930, 21234393, 26690339, 22495237, 135315629, 1974682441, 1965179329, 1973567876, 1965294101, 710755874, 1977763698, 539593137, 1070907959, 144230275, 75105817, 140345932, 30983237, 3192326691, 1977254274, 3194309373, 718619469, 3191638014, 2111504756, 29082580, 1964753209, 11960935, 26444563, 1049267166, 71910924, 3192933556, 155436969, 1979154664, 83067099, 1965441618, 3189819368, 2073363439, 19071389, 1970455085, 558877458, 140427860, 1977337717, 1067778978, 15680074, 1045744352, 3191113842, 30524179, 66406564, 1964835145, 2110062937, 3785121, 1979318504, 1060504095, 19170109, 1971061150, 2074133452, 3193703107, 200461324, 3188607157, 1061880848, 63816487, 84281009, 198593073, 88573688, 88000236, 3188000873, 699843395, 1972273750, 1089784918, 1061274642, 1540276434, 20153088, 8847570, 199297771, 1974944089, 143394685, 86671685, 1062765549, 7668296, 144754364, 143311609, 3191802354, 1068467210, 3194555597, 196086519, 1975648842, 66291094, 1978630725, 73565836, 3193539245, 1955007533, 180306104, 6455306, 1178370341, 1064125522, 1058636966, 540281266, 710591968, 134840502, 2073822251, 199773059, 1977451027, 3194571983, 19562791, 1054917836, 84968067, 17580264, 3193014899, 49415479, 1067942839, 3191032228, 2089910423, 1059652528, 172671570, 135200770, 2072790012, 206931572, 1048643844, 210731167, 1194101882, 1964720434, 1161069845, 46236966, 710641127, 2073478181, 3191540895, 3188951248, 1178321275, 1955335230, 1066256289, 1973010989, 1045383867, 786770, 72271338, 189808986, 2050146548, 1046137007, 1062158850, 1080394113, 192774966, 3191278012, 193660800, 3188133088, 92341985, 199986118, 72025607, 1974174009, 1064141859, 1066647947, 1975894361, 2084684009, 3190999457, 174851992, 2073330739, 217858537, 1062749163, 11993696, 1979400657, 165462177, 51266902, 3189868844, 176228229, 15499982, 196676595, 217645285, 138608953, 99926812, 1976893991, 111822670, 1963622955, 1975520316, 1978909137, 1057997901, 138576316, 104417044, 1046252017, 205211827, 1976009530, 123683370, 3195128635, 1048119880, 701104485, 1044924700, 1059816421, 1964409391, 215433447, 1160823589, 83034482, 176717992, 1160054009, 1966129680, 56280419, 146161941, 1050593486, 3193293463, 122308444, 1056851116, 74499502, 1967981134, 52528452, 187793591, 16089931, 18073437, 1047431242, 1057473514, 1059128388, 10125764, 1966981648, 1057145765, 140099959, 1971077539, 1063158825, 121324324, 1972765086, 1060291668, 209944664, 697959169, 134168581, 200100823, 1048414952, 84214399, 1048070587, 3190819205, 1059963921, 72664643, 710542808, 1044433766, 153681979, 215449837, 74827243, 158861202, 199494454, 104055485, 1963114821, 14534491, 3192949939, 11157945, 197593264, 199838614, 10207822, 10813719, 1972290137, 1978811211, 54723940, 1061602244, 1093959918, 7962935, 1054721222, 1067565579, 1048758585, 1045908308, 153927764, 710411718, 697582349, 1085867137, 905545222, 1962983721, 167133540, 88098543, 1054983120, 1965113763, 1057194928, 3192474168, 44369186, 1056244805, 3191851510, 55412028, 1963508173, 1540636677, 1978123075, 1045547648, 103122756, 159647662, 17728209, 196643819, 3191393438, 1975140697, 663800831, 197937449, 142608327, 1976320836, 3187969151, 1069352074, 1058784158, 1973928522, 1057489918, 76071666, 197199940, 1979547986, 28328469, 82690377, 75400620, 90490652, 198888043, 18645337, 1085147085, 1973125718, 1062831111, 1051495124, 15221085, 7012364, 1069384780, 63358356, 195430772, 1976090969, 215417057, 1063421029, 74483277, 3194538763, 1047743069, 155207636, 135282871, 74728921, 86081886, 73238114, 142967175, 1045645826, 2074083793, 1964917089, 71321003, 201360145, 36537249, 140460446, 1068286943, 1979269330, 197675242, 50939215, 2949125, 1061897201, 187891922, 2075034651, 1060340830, 145819452, 50660681, 1964638490, 87705321, 1058440347, 1063060504, 701727063, 6341019, 1063797677, 436193727, 1978254149, 17269074, 23806841, 3523154, 75466164, 121013024, 197904677, 701448596, 1058718619, 90244878, 1064403944, 2109768510, 89802515, 1973912133, 3190983831, 2073314300, 1068745765, 7356919, 702399037, 19284719, 31212749, 1962934554, 44057368, 7324368, 703351853, 1186810997, 1045875064, 158353294, 3191966884, 1044859350, 1165429865, 1974862669, 1068778539, 1973272889, 1066305444, 1966326361, 701743640, 62031243, 1095319858, 135381273, 1977781308, 1968193854, 27755529, 1979203834, 192578332, 73909921, 1048381890, 2074166401, 1964818757, 134496429, 144064779, 1054344386, 86868594, 71370179, 86098340, 1060308054, 135413763, 2085060647, 691388737, 1153434396, 1969815855, 72091162, 1973240114, 19252062, 1979498734, 209977436, 580864843, 1976058244, 15778325, 4063773, 1965703500, 3191097332, 1067648158, 104121025, 153190470, 690687010, 3194259599, 15254235, 206505436, 1968587201, 1068729381, 1976779598, 198576688, 22938128, 72533560, 82887044, 1047251663, 1954579308, 86853336, 2089844858, 1057424354, 71517759, 1969668682, 104597295, 3194227448, 36422653, 1975288157, 1064240207, 1977614887, 153714749, 198855273, 206784071, 75482551, 2073707658, 26379402, 905315803, 1050691840, 1060799472, 7045481, 210747524, 72009231, 1967948357, 1056834733, 73812017, 1068942395, 53232979, 1963278707, 2074985415, 28279317, 2072298451, 1186843767, 1070383459, 176046471, 140493359, 158205872, 3193801419, 1048791244, 904824790, 41993461, 1967243561, 1060422775, 1970356689, 1055147067, 207554073, 187203933, 1971929507, 19431739, 691815127, 83132769, 1977713485, 10273298, 207029898, 26903242, 1976877813, 1084588387, 102057795, 85033604, 1049037765, 67798437, 1067959223, 139445059, 3188148352, 1063224375, 215728321, 59098488, 905594385, 134547412, 201770946, 149506095, 1148372199, 1968013910, 3196079332, 16662821, 218022087, 1971454234, 201654287, 11174397, 1975173441, 62014858, 1970028897, 1059734464, 72943210, 207668794, 87491233, 1047611815, 1954906465, 3189541131, 1977811665, 157550507, 11698181, 3193933001, 1065124362, 1971208657, 187335492, 1966064077, 199740288, 336529499, 18301791, 85968587, 3194456844, 1055392911, 3191491023, 1061684171, 157763501, 198429157, 11419653, 1979138274, 1165364326, 1086357797, 1974518478, 167380917, 1966637411, 195807923, 1067828131, 1060881521, 215597537, 100042127, 61031807, 74597822, 139264533, 1966588253, 1968800325, 2085257259, 3192392235, 38077664, 1068073927, 27395023, 3194145517, 720093598, 11076008, 74712534, 1974930492, 134660101, 1969029585, 82477338, 212959474, 1977975097, 15123175, 1049676200, 195775109, 16548254, 141543224, 89032448, 210108527, 3189885229, 200510482, 99501444, 1066550027, 1973846571, 1044711889, 691274020, 1053967243, 1160234240, 200281074, 1966604639, 56312058, 702693632, 59065715, 160991036, 1064109111, 1053115253, 710624742, 74941945, 72173092, 24952837, 1051904977, 121766638, 1964163453, 206997121, 78905682, 103842504, 3196242934, 56197653, 187072844, 100712704, 1052118038, 14009138, 1070154031, 195693114, 1063339093, 210632833, 3188623542, 1963934014, 1045826318, 1977483716, 3189950771, 84132671, 1090584914, 1974420292, 1094550085, 1976648523, 26166412, 1069106288, 2072806451, 1069139056, 1969865017, 1054508239, 691339620, 1972683133, 73189332, 3188656318, 91717929, 1056425097, 1974780748, 196561883, 210600094, 1063207983, 197855510, 74302959, 200526868, 215711935, 153567284, 3195571083, 3190720891, 1063240760, 3192277533, 3189606375, 1978073420, 1055622282, 199051938, 84083324, 44303653, 698074983, 85951117, 1051101712, 1972814259, 29098163, 27231406, 1965048196, 75056661, 1972748697, 3192507565, 1068860467, 1978843988, 1177272539, 3187853407, 89145938, 1177125167, 71287317, 198625844, 198707795, 157665196, 1049791050, 75253298, 200625184, 54134107, 2073756763, 153026585, 2281310133, 7438758, 71992852, 1062470566, 702038608, 1968243017, 1964278191, 15139145, 905299415, 1572869, 136921650, 37733170, 1059800034, 140575467, 1056670888, 690605100, 23839516, 1064223810, 1068188627, 72058392, 36586454, 1049938577, 1963737682, 197658838, 90128978, 198560303, 95617029, 1976942884, 3187984350, 1094353429, 1054590161, 50857298, 84641458, 199150288, 72369708, 1051675476, 1967702451, 1978713970, 1069270154, 148685252, 51447129, 1963393438, 193692559, 1978680142, 711511105, 1058735004, 210469024, 206554601, 690457633, 38044894, 1061012516, 142885595, 90162951, 176261001, 207586853, 13714217, 3191949824, 84296321, 2089926786, 3188721855, 1971700061, 73418365, 1978371132, 9748892, 1974403458, 209715287, 1967505764, 691568995, 1058391188, 68339111, 2084945953, 198084933, 1971323447, 13681442, 3196110887, 217891018, 200363009, 1061291026, 1057375176, 1047480593, 1059750850, 3191606432, 6799705, 114591568, 178094346, 44089600, 1178010198, 190267773, 18219469, 1056326787, 1965932953, 3191015854, 123535931, 58443121, 1069007942, 99960206, 1061340150, 1046333517, 1045859160, 1090800688, 1967899181, 1975468750, 2074641335, 905332189, 82870721, 1977746255, 1973862957, 1058604196, 84836981, 1975829326, 1059374231, 198298018, 1977828085, 153354492, 207079059, 27854031, 56542565, 1045481790, 187990248, 703365438, 905496066, 1057948737, 84625071, 1046349931, 1067581963, 149407791, 3187755096, 1177159541, 8962292, 5243392, 19186230, 1054753990, 198642229, 1177405244, 905250252, 49906197, 1049709054, 54559253, 120897542, 207128539, 2050114748, 1975190247, 1057391561, 206439875, 1968030297, 1060455549, 153043015, 187351797, 72713800, 84935280, 1054311618, 2074297456, 199199446, 1975632713, 17892085, 904726151, 3195242529, 600427385, 1063257170, 1050020544, 18629454, 188154709, 1046546965, 1969324428, 3193457854, 206603775, 1160168701, 905168327, 74532278, 215663085, 1972224586, 1062847476, 6307849, 2072921098, 84721938, 3192736942, 5817052, 905676330, 3195767721, 1068647453, 1062683619, 1966621025, 94193442, 139149605, 692044040, 59852161, 1968472460, 87917224, 158189455, 1044941332, 71090916, 1070088474, 1048366083, 28132427, 199035551, 123519734, 56656405, 3190262097, 20250908, 204130445, 11468805, 1046825622, 200543253, 1056294020, 1966178859, 198035775, 18809703, 19267799, 3188443305, 1057260723, 3194996764, 1049332620, 38732581, 1048136290, 74286570, 1974075674, 1977942319, 83804913, 59344254, 17990412, 1977991513, 134725638, 145769034, 1047399352, 184239980, 3193785541, 2072265724, 1055638666, 124290914, 207324662, 3195489412, 1972011460, 207242704, 1975075646, 187269476, 75187757, 196545498, 1056621727, 187154965, 1160807698, 1063453791, 1054459023, 1977876762, 73598609, 1064027281, 153960534, 1059570588, 1967145554, 1978024257, 1966883265, 1058063449, 16679241, 1050626276, 150046621, 209748059, 71943696, 19448582, 27444426, 7405867, 1051757436, 67782052, 1049135955, 1976419022, 1069401161, 186745662, 1976516953, 1971716447, 1970422311, 11583762, 3194882855, 1059685555, 1969979730, 1085932563, 701252151, 3194342604, 1051642703, 1979433170, 16859491, 153502184, 1059210348, 1977057745, 3195292506, 1972027853, 1973781039, 1049053868, 697516879, 1968669231, 187384653, 1967063607, 73647768, 1970094451, 1063567904, 3192818275, 153371120, 2084651228, 1966162471, 1063961613, 20316888, 86918873, 72418865, 1069040731, 1050675451, 1058096222, 719553052, 3193375932, 189661555, 1979564497, 3195882432, 1064420470, 154584999, 10060226, 1048546050, 1055490581, 1969570343, 1095074130, 1968357732, 88033005, 1966571858, 691765530, 10109380, 1539736382, 1974289218, 91293639, 1974632900, 7192786, 134184965, 6504892, 86329039, 1055458521, 3189525643, 1977369294, 123650508, 1976861393, 1971093935, 3189049562, 692027566, 1977549386, 74139071, 24003353, 1053328258, 1058816943, 176849074, 1971781998, 85526213, 140230985, 1048250890, 1966702971, 1058686124, 2072183899, 1058507847, 42272025, 134529197, 1974255945, 85262982, 1044613735, 122225370, 1047907195, 72828351, 137038907, 153780298, 1977385558, 1977140034, 1050135313, 691831249, 150931366, 1969209700, 3193998050, 1186614381, 83460417, 11928330, 82953061, 3195390829, 121045802, 1050036930, 143000167, 166759469, 697385690, 74761695, 189694679, 3190802824, 1047563030, 202162359, 210092141, 85870280, 1064272825, 145312813, 1051593543, 1969471949, 1048578180, 199560010, 2072019895, 1966424370, 202213222, 1057965124, 2050099066, 123552323, 139346372, 1116635941, 23216384, 718291681, 17351042, 1972388146, 153239741, 700170427, 1069187605, 3188738241, 419579889, 3192031231, 710739477, 187302274, 1974354265, 719372878, 1058866102, 411961714, 206980727, 196447150, 88916663, 703414990, 703250642, 1054950603, 701923515, 1975239490, 1962967333, 1974829637, 714394691, 3190130667, 1056343171, 104006302, 150603621, 1050462293, 1963950401, 1067663825, 1954840814, 193593360, 3190066321, 1972322596, 3188639927, 198347194, 94226213, 1064305762, 1979351284, 197626035, 71681474, 75368017, 29213043, 1051019742, 89130678, 71779834, 86409874, 2074936303, 22364498, 1045498536, 141379439, 8814952, 1148684161, 1048955500, 166903991, 1045809852, 1165397095, 55379259, 1965474393, 1053426572, 22218407, 1063732376, 1060914315, 1979367674, 212943084, 74253800, 16171852, 2109900722, 720290332, 71976467, 1048447651, 1978827058, 190038481, 1967440221, 1160480011, 134332416, 3191687651, 1068794923, 1062880242, 86311568, 3188820166, 1971339836, 1094599502, 1978287989, 3193112586, 18006741, 1970897251, 198511084, 84592300, 103695047, 1968816714, 73942693, 1965376060, 1050904967, 1966768524, 1057539084, 3195144222, 2073511050, 719078136, 186991313, 1060815936, 3189180651, 94095113, 41582836, 31228869, 165479006, 176685332, 1059603365, 22889189, 88588979, 16763958, 29409536, 3194129563, 3191261630, 429541797, 1047136338, 1979056466, 11092393, 3196505107, 86492884, 199232221, 1964458535, 2050164594, 3194833700, 210583702, 710428103, 1064076345, 1160398085, 210665606, 2074837983, 206947957, 153632824, 1054573777, 1969553941, 1070711228, 1061700616, 158122814, 1064289217, 209780831, 3408174, 3189360613, 1972142635, 146112775, 72140317, 26723514, 1057686567, 1964245406, 1972257362, 3539290, 1045793432, 140018169, 1059062844, 1977516872, 1048431263, 3195505528, 1966031297, 1976140519, 1965425230, 1974846197, 1044990327, 1086620499, 1063634037, 1063912424, 1976910667, 1046743404, 1058456530, 1055343829, 3193718801, 3188836552, 1051298412, 3191704036, 3188508844, 186780731, 87262944, 691618535, 285739001, 1045514972, 1055261910, 3196029905, 1084784933, 1972945424, 1063388247, 31065203, 1056998566, 3187738707, 1045629394, 200444939, 22332228, 10240560, 28639405, 1970520650, 198166899, 691650873, 1054541007, 215400666, 1084752154, 2072118291, 1979171054, 17072420, 1046612994, 1973715396, 1967276338, 206669343, 1969750298, 128352662, 1068516371, 2111129485, 1090863397, 43205868, 43173099, 62128661, 3189803300, 1050250116, 1054606545, 1976500755, 3188377755, 72517175, 1045678775, 25412637, 1974486901, 2084913183, 1045137530, 2072626111, 1968718375, 3192998514, 9585102, 1977926462, 1978745177, 1969094985, 197298280, 1045039415, 1095352701, 74974716, 2085126187, 17597186, 198134125, 1045760125, 1046170225, 5227008, 3192343720, 198822497, 30770317, 1057047445, 1968095529, 193609890, 1978057033, 207636057, 1049643411, 86901401, 1055212755, 56345957, 93045574, 1051249243, 1968701973, 56082980, 1054115011, 3190622581, 152668093, 152618938, 1057899582, 1186679921, 1047546470, 202246303, 16630477, 1068909627, 134217728, 3192835248, 1967522158, 139182511, 3195423875, 1056474242, 215679179, 1063027734, 69174696, 1061864450, 4620592, 1540653061, 202048283, 1955481479, 55706989, 1062142466, 3193194507, 74630597, 23020101, 199625569, 193807246, 58770802, 135069703, 199887784, 1968947497, 2089894027, 3196291114, 1972126247, 217956546, 99468674, 3195259724, 15581715, 89065217, 3188705469, 210518162, 698696130, 1047218758, 37487391, 1064043673, 19055456, 1975091503, 3191295132, 1968963887, 1977893156, 1978172123, 1967636889, 197216329, 3193031283, 1066599179, 2966345, 1964097892, 381683965, 74401339, 99141442, 905364972, 15991502, 138412429, 3192572485, 52921676, 3196226543, 217940160, 69698985, 3192081573, 702893109, 41436407, 137807066, 1978237309, 1966653796, 1084441319, 13746991, 1976926490, 1970651433, 2867979, 1978385223, 199379699, 3193130168, 6488490, 1058161774, 1049349048, 1061536750, 74614210, 12862228, 1967571325, 588827488, 3189098715, 126929989, 2074444974, 1044580594, 1177469154, 217989317, 328040839, 1973633443, 2075018250, 3191671264, 1057031340, 3195734947, 1972650355, 47121189, 217710845, 1047824752, 104088255, 153157700, 68781475, 72599132, 1057407946, 1061045290, 139480111, 1177567799, 195594734, 3189443722, 1969258875, 1963049273, 22087334, 1057080216, 1971519791, 1963082046, 1068663837, 1974715210, 12009478, 103991005, 1978941650, 1055015887, 7946688, 1054966736, 1057178539, 1966506305, 199166674, 1052869177, 1056261189, 1971503401, 212926694, 1974977269, 1967456607, 3193767954, 17187673, 202622901, 1062486953, 67584533, 91914537, 1056523459, 1052836278, 95764801, 1064387591, 905397733, 1972240974, 1978270534, 26018799, 85377669, 3191572477, 14598891, 56001895, 1971290667, 3190148248, 1055588885, 691914542, 200002503, 1073267467, 1051413156, 200428553, 1048054179, 1095024972, 2073773168, 1048939070, 3194489604, 3192179224, 803281, 1061848041, 16187698, 173770554, 691978420, 42075419, 1056359559, 606922, 1054868681, 90195720, 148308587, 200592411, 192546659, 1969193315, 3191228855, 1060373608, 1046513979, 1965195716, 199756674, 1044417399, 1979580603, 3195194581, 1160430856, 691552581, 204015695, 155240360, 3192491180, 1977467225, 198527514, 33260308, 1178124847, 199953341, 1973305662, 1964654884, 14385943, 1063306318, 3192048140, 2085044262, 52544837, 1059407003, 205439597, 23921445, 1969357214, 2085077032, 1059849194, 1060832321, 1966375205, 197888290, 12386309, 1970241967, 1056949386, 1049479375, 138641857, 1091126104, 1093878614, 207472147, 54576373, 94060925, 217874924, 1970716985, 100025741, 18825809, 1964687657, 1044974401, 3195407577, 14663998, 139330434, 134987776, 39437503, 3192868018, 1965900164, 200297459, 12797120, 178061569, 197757186, 212910304, 3189508361, 3194473233, 1978041063, 1978990824, 72631901, 1053393799, 2074493962, 1068549139, 134889472, 25871534, 1061225419, 25789598, 1061143492, 1053344643, 1055229142, 1979597010, 39109394, 3194800930, 15319421, 2074412015, 1976484679, 1128172638, 120931115, 74450501, 1177649390, 2071986641, 1050888580, 140263877, 88934141, 142901421, 1054835913, 72156707, 50382069, 88982201, 701301021, 1055425679, 1053541246, 1069319306, 1061913587, 152895550, 1057834042, 1091193916, 710690296, 153862224, 1060949068, 1971634501, 197871895, 1973289425, 1966096849, 1091518878, 1064256628, 61293947, 702955955, 196742149, 10748507, 1974666056, 1978138973, 1964229017, 200346624, 3194686232, 3192376490, 3191080949, 2073936839, 1058407575, 1963819301, 200379396, 4637104, 215744707, 153305322, 52462101, 1964507703, 691798319, 1068631067, 1972699522, 207816350, 73729694, 1058260096, 1967014439, 1058030676, 1971470628, 1046661547, 217612504, 26395423, 1963999564, 3191900667, 1186860152, 1045367439, 7504205, 1540309735, 700187412, 37798144, 3188803782, 197544104, 1085817804, 1979678964, 3187887234, 1049823879, 1966244421, 719504132, 202279073, 1165380710, 1971962291, 71747045, 1969521199, 71632311, 2073543886, 10666565, 1967751620, 20578649, 201670817, 85016839, 719095646, 1969045822, 154010213, 13124392, 3191998982, 1049299936, 52741450, 3193424545, 905135555, 17367876, 3194424065, 153010200, 1967882795, 3192588290, 1976452964, 3193473701, 1045695169, 1970176396, 16908606, 1178173819, 88901372, 15631057, 153420249, 2085142572, 691716477, 1055605269, 718504517, 52888907, 89376518, 1044679485, 1050921353, 3193834190, 1070498180, 210419860, 3193948891, 1976533444, 905627166, 3191769583, 104498980, 1056687272, 23659388, 3193866964, 1976566600, 89572035, 9011491, 3188410533, 1044646687, 718570053, 196283268, 195283215, 1067533448, 1058112611, 540903415, 3195849658, 84902530, 1160758040, 15551548, 1967341886, 1972961813, 1060406168, 1972633966, 1068385276, 2073855027, 1056457943, 3189410049, 1971241488, 26526485, 1973256501, 1974026981, 1047120447, 17056155, 1044499486, 207209915, 1046710575, 1059079232, 1196497, 2072347822, 140034548, 84869752, 1970127229, 24019737, 24461777, 1978892626, 1067844515, 73779164, 1044630220, 1970569814, 119523158, 1085079933, 2073674687, 1063322702, 13222696, 95191300, 1978516298, 1062650819, 3195555035, 1977910074, 1967374661, 1085064004, 1058948276, 91997029, 1061962720, 200608798, 2073412551, 1177783356, 48694575, 1052035078, 1970225571, 1065157734, 90425113, 1051987091, 59556637, 152635323, 138494367, 3508124, 1060553874, 21253180, 328188087, 1051085324, 710444488, 1970487868, 702975064, 73369203, 1046481083, 1979007214, 1065764815, 1963917777, 3191737484, 1970635045, 1062913017, 1967030827, 82461058, 3188885709, 1057702956, 90179339, 218054869, 1089752149, 100811008, 18399645, 195627565, 54117722, 1965949342, 3190606191, 1056441559, 1965654337, 1067926455, 64898461, 1059325085, 1975747093, 54658398, 1971945903, 16564685, 82592570, 3188034689, 3188328599, 3191721121, 1975272259, 75433394, 3195718562, 1059030074, 99713820, 3191523795, 26870503, 1971110323, 701661529, 1977533257, 3194768594, 1057915967, 3195210966, 2089877653, 718701142, 84919991, 2074461127, 138838367, 2073986075, 1060160566, 1177321797, 37848285, 1046104470, 26575658, 198543916, 1978467144, 1051741032, 207095445, 72386094, 144359700, 1063764903, 49481024, 83263843, 3193686719, 1973109330, 1050315732, 153272541, 134775405, 1060684720, 1051331184, 1054655687, 2654626, 12845848, 87607013, 1047054674, 175228821, 1047873818, 1060848726, 206341547, 73336430, 1969783077, 1979285723, 3196538091, 198478313, 3196192808, 22643243, 1966735746, 1066615179, 54100501, 2075001809, 86377073, 62047628, 1059898374, 1044548504, 1047530023, 1969439169, 86787798, 135036933, 1091551988, 1066207121, 104269639, 1061258248, 1978696527, 197429389, 1976156489, 1060487710, 1045989983, 148390247, 1056310404, 73008756, 1061946360, 28443783, 1054196910, 1973371212, 701415827, 1974796821, 208947247, 153583669, 1977237860, 176931410, 206915184, 104104640, 2073445386, 217743866, 7422253, 208978103, 1054442639, 198003004, 2084634835, 1052018683, 3192064523, 2072085469, 1970864479, 75040276, 1064338327, 140362317, 1964425744, 199641954, 6684994, 1539965393, 210813085, 5390350, 175081366, 3191654876, 23724923, 1964867922, 1059259517, 134807731, 157812600, 206472654, 157040823, 1070301516, 1976831036, 3188672698, 18367054, 904988733, 50201917, 691014696, 1051921364, 905610779, 3196325094, 23544595, 1954876499, 1047857244, 710788665, 1049840265, 1057653793, 1978615646, 1058981045, 1976387445, 700777232, 54019421, 28967704, 196266879, 358074563, 158288979, 4817212, 1963016498, 1061651390, 210059366, 1056228421, 1052069047, 61326717, 1977500369, 3195915489, 1050609886, 11059621, 2074919891, 3188476076, 1054246085, 1046644877, 199264999, 72123932, 134299648, 1967768013, 1058210935, 1060193342, 1967047213, 1965785443, 192627512, 10437171, 691700091, 153108545, 711231430, 3190688917, 207849125, 1965015421, 1977073985, 1192266873, 54936932, 718389805, 85918348, 1063191599, 1975010123, 1186630766, 1065174019, 37782297, 1177944645, 1966309974, 1050823012, 62342545, 2072576533, 1061209035, 1068614683, 1975370564, 1048300113, 3189573956, 1966998037, 187450191, 20595228, 2072659082, 206620166, 1970962811, 11977156, 74548664, 7176797, 1051052539, 139314049, 122159690, 134234112, 1979416763, 140083574, 1968210241, 21447246, 199904173, 179685056, 209813603, 1966228028, 1045105548, 541706678, 203869264, 132072272, 148701735, 199346929, 702742533, 114755411, 1073349073, 1972584801, 74024568, 153436636, 1967194394, 1970602266, 504613499, 54248796, 90146570, 207504903, 3194391301, 2074690689, 193986824, 22446085, 1963163986, 121913877, 1070465405, 1965523237, 1540047577, 87541475, 1170375246, 1978974434, 3191867898, 3188459691, 1160791285, 1950679646, 42926885, 186270019, 1068975174, 1976992047, 9552306, 1051183675, 1966670190, 1059980308, 11010604, 1046038618, 698780754, 1059996700, 199314156, 7569813, 1972994603, 1972617572, 3189918863, 45761776, 210255994, 3187935335, 200412167, 199789450, 47808850, 143327994, 88540919, 134692870, 210698392, 178864403, 1973060165, 1971601726, 1186597996, 1060635808, 59786632, 9666947, 27952187, 1046203213, 197806349, 1062093814, 1968177617, 207193528, 2084831258, 13632274, 20022114, 1978532373, 905758295, 1963672124, 1051626323, 1970373167, 19579297, 543803833, 1968390515, 3190507878, 15073625, 1058653351, 75122202, 74810852, 11485189, 1975042340, 122274558, 36439251, 200330233, 1963442611, 178964268, 88015531, 3190361234, 175196055, 1974814558, 1158368355, 1975419718, 1964540485, 140313162, 87245472, 1977221851, 3190557034, 200264689, 1978335830, 85787274, 701678108, 173786992, 88639226, 701333791, 74073515, 3191212469, 1054524623, 3194178433, 206898799, 1539473764, 1966211639, 1955465094, 133152773, 1964884317, 73451136, 1186663539, 1967735233, 702450745, 1974371035, 135561930, 140181829, 1047710217, 1069220373, 88457903, 1975665482, 2073592759, 42091805, 3189000423, 1968751149, 91308314, 2074805339, 1977566026, 3195636624, 1978647285, 3191982585, 82493758, 1047792597, 95273341, 72238632, 7274722, 3188017265, 134348800, 1972715908, 1063502954, 85000815, 85901735, 192660281, 1055572501, 1954924487, 1051954206, 1054328002, 1068926011, 1971257877, 1967292725, 1057735726, 1067467666, 1971667276, 327157716, 1973699009, 146507727, 1971814779, 1969291650, 1059537814, 1976681292, 99796361, 1977665374, 1049544980, 1085096773, 84231856, 28460162, 121848575, 3188558001, 1966539081, 1054639303, 691306915, 71796224, 1964851532, 3194522378, 1084310334, 1046776337, 1060734127, 94160670, 1968505246, 148408182, 691470724, 1062732780, 1978353508, 3187804253, 149997465, 1058540617, 1977794905, 1158220897, 71763446, 1057883197, 1066157992, 187007816, 697566044, 75417007, 52445976, 710493647, 10322470, 1966850483, 1970946419, 196152131, 1061110779, 1046236034, 6570292, 1968685584, 3195096276, 1063715992, 9683544, 1091240469, 15745575, 209911922, 905840189, 696484019, 139412054, 72811964, 1056801965, 1067861166, 3194080458, 6357401, 1070530951, 719259481, 1973797392, 7471431, 196496332, 212861454, 702414852, 196594652, 52512067, 186237243, 95814469, 49661242, 123781564, 1086750964, 1069203989, 2074510363, 1058325026, 1975714343, 28361634, 905430514, 1977320262, 1045842866, 85885558, 3188246667, 1965064588, 161123453, 138445093, 1070317906, 3190114625, 50103603, 1046399664, 1068352495, 139378995, 37634868, 82837813, 719291110, 1067516428, 691962037, 719831764, 86803095, 62915995, 73057911, 3195538650, 49022248, 2075051045, 1968406907, 698761752, 15024431, 210763931, 125536208, 84803844, 1052770740, 1048971910, 1974272332, 1048742726, 691505001, 3194620692, 1057342400, 85344891, 44139807, 1955430660, 1973354825, 1046972608, 1968554419, 701432097, 373278946, 1964966254, 20169156, 1056654496, 1068205011, 3189901289, 1065272250, 1056818349, 207734405, 209029190, 18023124, 20005609, 9535793, 1047513553, 1068696605, 148555639, 1955087065, 87639782, 88671995, 3190032701, 67618209, 1049380961, 3188164739, 134578679, 67371557, 1051724634, 143016557, 187646819, 206521827, 1964147067, 1067500044, 19235671, 1085522233, 1091601136, 1045531190, 149456943, 152993815, 1056572554, 82379091, 8945908, 196611042, 18186692, 1061061674, 44320032, 1048611193, 3191180001, 4473261, 9568576, 153288932, 1177928213, 158238640, 1968111919, 27771930, 3196210405, 198773337, 200133595, 1084768548, 22594091, 207373818, 1057784882, 2072069249, 1060979737, 198396382, 152961045, 719143364, 3260711, 148883253, 1048004742, 1979662574, 904890419, 1975764830, 1086669156, 1972486469, 1051839420, 1049987748, 44254499, 2085175342, 86066886, 198920824, 155191252, 82624891, 14517080, 1063650421, 145050706, 27935803, 690621476, 1059767240, 143917325, 207291883, 86770326, 99943808, 1963180381, 1059292288, 64865692, 135495843, 1970438699, 1055032284, 19923409, 17696603, 84853112, 3189328119, 149653551, 1080443398, 1049512157, 206636552, 123716349, 2074100627, 26657556, 1976123713, 1047808109, 197708013, 1963655735, 140476835, 1069302922, 3187820638, 1084571997, 1045335037, 1287539803, 1049462985, 207619635, 2072872110, 1971028364, 1054262469, 88654517, 143262282, 1062191265, 14500622, 1071089617, 20120425, 1170440782, 1051150967, 8159532, 1053131640, 1049577816, 11190783, 198232473, 1060586647, 1061635006, 158515815, 905659937, 1966080463, 1048086987, 1063748505, 1070566470, 198675009, 207603286, 1086701949, 122257965, 39420437, 1954759027, 1047759485, 201623398, 1975437173, 1973895740, 153534665, 1071006294, 1049626986, 73680538, 1975353730, 3190458723, 905774680, 55050773, 86393151, 86278799, 210157685, 199691119, 193628031, 2072757277, 73156243, 12076872, 91081790, 88867038, 1968603588, 1060651932, 122192473, 3194866472, 1054147779, 199412470, 140198181, 37405466, 146129520, 1048726449, 1064060013, 666143755, 1047972418, 1086341412, 1963131209, 15597913, 176832689, 2085224497, 1046219235, 1054672071, 82446281, 3196472335, 26510108, 11945204, 1966866876, 1069662213, 1160742161, 1963245924, 467028488, 1047333842, 1955253296, 1058145388, 3190098241, 1057801268, 196381606, 1972863428, 153550899, 177963281, 1965965731, 1973682620, 1974552420, 1069433928, 905709094, 1061372919, 691421502, 195086405, 1964196228, 136905316, 1965752671, 1046939781, 905889355, 1976795983, 1964114286, 187498759, 1059357846, 146145542, 1973584268, 16384187, 178077961, 1971896729, 700809999, 1070858791, 1178075474, 74221020, 90441498, 905791066, 1066272674, 1979515124, 1148355365, 1974895439, 3191343553, 1048660472, 1971175885, 6406438, 3191589336, 85672285, 1978958043, 134676486, 84656499, 137068909, 16646864, 85164909, 37454630, 1963196767, 1969275261, 204701879, 75073039, 199002773, 1061454873, 187253150, 1979646184, 1048988301, 14582212, 1966948817, 139542825, 1051232853, 3192621643, 2074723263, 145753979, 1978663757, 1061733394, 3192604674, 84689639, 3189786915, 180273337, 1065206816, 33342234, 167397302, 210436243, 139084535, 1069450312, 1045564261, 1964524092, 17383805, 2072724592, 92309202, 1968521635, 209829989, 138461800, 1068434428, 13665045, 88883894, 1978221380, 75171372, 85460673, 148359051, 1963852079, 90212108, 3189491975, 1969930565, 1070957130, 1972929071, 1091043683, 195971795, 1963311485, 1057227702, 11567364, 72566334, 691487026, 1965080985, 45040872, 1966260810, 3190671742, 198724180, 1186565231, 2074198975, 904906804, 3189147880, 83476899, 86754108, 2072527763, 3194817315, 1966358820, 1977860939, 904758932, 138674641, 3189247112, 152700863, 138871137, 210010208, 1974698570, 73746393, 1974125374, 3194193943, 1067893934, 16401114, 148259648, 1975779909, 1971749219, 370854112, 88523441, 74794464, 154224488, 86722261, 1049283551, 36193183, 1054180526, 120980269, 73270887, 3192638028, 49988905, 1968865878, 1068319727, 2109866362, 1968931109, 3193653264, 1062814719, 51922247, 1049873034, 3194603544, 1085047170, 176211844, 1063601272, 905512452, 1969848629, 3193554959, 1050855786, 61310332, 62965144, 1057752112, 74892788, 1160217855, 691290461, 197560493, 1971372618, 1046596586, 198871658, 1054393540, 1063830452, 210403470, 2073068471, 1051872202, 1050954150, 37504183, 82346340, 195447186, 22775652, 3195456368, 2072151099, 26199214, 88311539, 1055294677, 1964802369, 71435782, 61244793, 198937209, 144343301, 149588015, 196348836, 1969308036, 59262324, 3195587469, 1045956948, 17679086, 99485059, 1060357219, 27116677, 1973879351, 585354073, 23085500, 1047939421, 87622309, 1973961298, 36291695, 73828406, 207521303, 84263552, 19300675, 1059046458, 3188180960, 54855009, 3190770054, 1973453155, 2073838588, 193562306, 24920530, 1050364656, 1060520483, 632163256, 200313844, 1090536153, 195742407, 1540342034, 1059144790, 148783953, 21939880, 1049758245, 210501773, 3190049937, 719243098, 12665622, 18694730, 1063109666, 75449779, 206538216, 140001783, 1177223418, 1968898330, 2093369267, 55625068, 85804743, 1053557630, 72042007, 199101124, 3189999927, 3188787397, 1068532755, 210305147, 74270185, 1974141231, 83821314, 1051544365, 45122794, 11452421, 3195996198, 2073789407, 152944660, 1964982643, 1976271579, 3192916592, 210337911, 145785709, 215482617, 196758536, 3189197038, 1049316348, 103596741, 1052787127, 215515635, 2072413211, 1045203497, 175964418, 1045351038, 68928930, 1974337885, 1062174735, 36308124, 45876467, 1955103589, 1058702234, 6275507, 1177714898, 1177092482, 1067762594, 1063617657, 22266211, 1067484050, 165396754, 1978450641, 123929174, 1051708246, 1971863940, 1976470588, 1059177561, 210174059, 1049725445, 1061159876, 1053082487, 1067549832, 37749555, 905151940, 2085158957, 140165503, 2084667619, 135759822, 40829733, 1061094382, 1068155857, 206390716, 2072249387, 197691628, 207750790, 1063142440, 3189295353, 1972060625, 73254499, 701121087, 135151876, 1058573389, 1976631829, 3244316, 1963557423, 699630311, 134250496, 204605607, 88999679, 26936205, 1143915763, 3190737279, 1066582795, 1973551490, 187859194, 1048676778, 1177747706, 1058849717, 20104040, 104466197, 6930619, 209094729, 73467525, 27542487, 25559827, 2074395603, 8978821, 50136376, 210321536, 701366560, 720011831, 2074362876, 1068811307, 72648258, 1045596389, 1048922238, 177178565, 173590329, 1063552096, 11125313, 1970553426, 1965097374, 197281890, 157614859, 1969635900, 1968570812, 45303020, 1970143618, 204720236, 1059390618, 25772555, 1058129000, 85475976, 1049971357, 140132733, 1046727013, 95962932, 88556210, 1971618113, 210141299, 1054164163, 104285876, 1044515870, 144146704, 86737529, 2050180979, 199478068, 87687847, 37389081, 1963590165, 1966801310, 1966784921, 1979023604, 20054789, 68027637, 1966440757, 3195472756, 3192113682, 88295154, 1048594738, 2074313695, 718488313, 1057440741, 152717248, 176029879, 1069466695, 3189263497, 3191310330, 36619470, 1975222604, 3192719960, 1967554939, 3190753665, 3193227915, 3193063431, 83886344, 1070285129, 1969799465, 2032081, 75318862, 1093600107, 702991449, 3196128225, 1965228495, 3196422188, 135118855, 1966342426, 49972529, 1979187444, 3192457783, 1050642673, 1068254175, 1971421782, 3191065000, 3196521493, 199068329, 1978581836, 2072478926, 152651708, 3192015367, 1971192271, 3194276603, 1048234142, 1069973760, 198806112, 1964622425, 41976601, 1971159492, 1068418044, 3188902100, 1070219729, 1064191083, 2072740831, 74565051, 92325581, 1965998515, 195611163, 218038472, 197822734, 96157860, 198379994, 87949993, 176799915, 1061798902, 1974206929, 217580045, 1967997522, 1045318645, 1970209182, 195070022, 140067189, 2111439204, 1970749758, 166872766, 1046891283, 1046268284, 210452624, 124684101, 1128205405, 1052085440, 1976205650, 1966752132, 16073461, 140444113, 1063404644, 74466887, 68945312, 17645999, 1046875002, 199510850, 2111588236, 87655078, 1055245526, 2074149397, 50005298, 199445286, 3195341667, 162202867, 85051066, 28591278, 1044842666, 189677871, 690998309, 73516680, 1977420860, 15040900, 207406594, 74778082, 2110393426, 1050331478, 2073691155, 1160725779, 83345701, 1059947534, 1975255378, 50890061, 1044909054, 1972404533, 1051216462, 1059439777, 1966916045, 73861180, 84181630, 73352818, 1177370962, 1054295237, 61736326, 9814443, 1976336765, 1966407983, 1046464661, 2072101823, 1060226112, 73959076, 1970848093, 138510752, 121062181, 6439214, 74876400, 2072134794, 3187689536, 133939205, 1059308672, 1086144900, 82329953, 1975812941, 150964148, 1059341461, 142852931, 176161537, 190120274, 139428666, 1050970549, 200051661, 1055376522, 54986031, 206964342, 60949877, 1049922192, 1054934219, 1050806620, 1054426308, 42108190, 186876217, 72910437, 17334628, 41631994, 60031509, 1069580294, 1540391278, 14795373, 209879150, 138723793, 1067811747, 26755537, 27133086, 19137339, 138953062, 83181923, 1976550097, 120849172, 1971552565, 72795579, 1060095021, 142656215, 1050184533, 85983886, 72107547, 2084847643, 1049495761, 197986609, 73533065, 1060783096, 8356187, 74696149, 24134425, 1059488934, 88490672, 47841530, 206407103, 1058800558, 197380223, 1065190360, 719586033, 905414129, 23528211, 199363314, 1044400557, 1049413777, 195479967, 1970340303, 54838624, 12927778, 3194718233, 210124913, 1158319202, 1972077103, 199969731, 904808405, 197462170, 17252665, 36471774, 1974190425, 15270244, 1965851003, 9847518, 196856480, 1051527949, 2089861258, 94209827, 33357829, 1068270559, 14483461, 52003349, 20447442, 1177600452, 1049954969, 1056539843, 54543128, 1065108533, 1044564167, 1148636224, 206800461, 3190950291, 3192244761, 1067615390, 1047071104, 206882411, 1060603019, 3194113178, 19153454, 1973338437, 49496857, 46186740, 1086832803, 1965162940, 1160414462, 104547174, 1057014956, 1045088829, 74008177, 1963475393, 207570460, 1061504042, 1095336261, 1058997298, 54674730, 1973223727, 1963983177, 85410438, 1160660239, 3192982710, 12093258, 198150514, 3190917515, 1977090791, 1053295496, 1070121253, 1065288665, 3195358052, 1050757453, 189940443, 143180241, 3192769711, 1059521429, 120881942, 72222247, 4997572, 1093697874, 84116090, 215695565, 73975396, 45778161, 1971585489, 15516246, 207112148, 3193309196, 1058227322, 2074903603, 139297664, 74581440, 37962979, 1055065047, 217596111, 3195751645, 30409497, 90474267, 193741421, 72746579, 1974092068, 215613924, 1062028261, 1971978684, 210534543, 199527238, 83689764, 1055442063, 3187869661, 1972109845, 71615926, 138772815, 11501573, 88131312, 86475412, 1964671269, 1974764071, 1045170575, 701399328, 1057096604, 1978433988, 1973092942, 91029810, 83673397, 1973977686, 11846513, 3190344024, 1055114299, 1045776508, 87524002, 1954644178, 1055196371, 3190376793, 19039066, 198756951, 85624515, 1978319566, 1057063830, 3191163305, 1970586201, 1063781287, 199461683, 432637974, 1975599825, 6586810, 1964589650, 1970078062, 11633034, 55805172, 1057621020, 90031972, 30442715, 3190868362, 207341048, 1963377049, 1969226094, 1969078597, 103940764, 3192883204, 175980935, 3190901134, 210681993, 38028514, 1060127792, 28492937, 2072396810, 1062110229, 82510127, 1047284633, 85098501, 3193145980, 1968423293, 719438591, 84148861, 2109735264, 1967817263, 2074182621, 1967129166, 2074526757, 3192195607, 6717957, 1978417497, 3195045917, 37651253, 50021677, 2073920686, 85361519, 139608525, 1053148022, 3191933439, 1971274279, 1158040672, 33243929, 3195308895, 3189344508, 73074305, 1160201481, 1059423387, 1969242483, 1057506310, 52725064, 1970323917, 1067991999, 2084864028, 691929267, 3192310912, 215466227, 3194703056, 1973174564, 1964360141, 153387512, 208930772, 1967210788, 90112313, 122340193, 1970930030, 1974731285, 904742544, 905856575, 1067729811, 1070481794, 1963754070, 135578208, 196135748, 147474357, 718094771, 1091207939, 1976599114, 1160299780, 209928308, 1972453694, 11323209, 3189082332, 1094372403, 701350290, 1975681557, 140296648, 1964015954, 1968062756, 148424592, 87556771, 9961918, 134365184, 137315184, 212975864, 1065223302, 1979105490, 139002244, 3196259322, 1972191804, 1053442223, 1975943719, 19087773, 1963065809, 15188306, 1045055925, 50152770, 1973994073, 1967112778, 1970061668, 1059668900, 1966522693, 1978188132, 166019236, 2073265119, 1061323763, 1061766129, 1974501772, 26608407, 153763913, 2074215443, 71878154, 710706702, 1051363956, 24166656, 1177583922, 135692544, 1178157444, 1058964661, 26673957, 75269690, 3195964363, 1977844263, 1049807487, 5259592, 199936953, 136987004, 74384939, 1058915506, 218071254, 213041623, 702202596, 139035462, 94111511, 25593017, 905217482, 26329621, 139985361, 74991101, 1058308639, 209993822, 1061815288, 29328477, 29180437, 1967784399, 134873088, 72484404, 51414360, 1054688455, 1061422096, 1047235149, 429443080, 85608125, 123994858, 701317521, 197396608, 6881291, 195561962, 1975304537, 55477556, 207488527, 1967653278, 1974387044, 1057326011, 1963409827, 3190540649, 1060932682, 1972830652, 1080262992, 43614741, 1046153439, 104384273, 19661187, 690981926, 1045400328, 719880952, 691863918, 335579220, 1056867500, 27280325, 1116995763, 3190638966, 1978731580, 10306076, 3189689485, 1965916556, 28230799, 86427345, 1046989345, 87197402, 32506194, 152977430, 4456750, 1059865598, 2073576339, 3194030101, 1063289939, 53003598, 199019164, 142705470, 139395915, 58967415, 1971143105, 3188066422];
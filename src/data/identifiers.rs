@@ -0,0 +1,4 @@
+// Generated by `cargo xtask` from Unicode 14.0.0 data
+
+pub static ALLOWED_RANGES: [(u32, u32); 388] = [(39, 39), (45, 46), (48, 58), (65, 90), (95, 95), (97, 122), (183, 183), (192, 214), (216, 246), (248, 305), (308, 318), (321, 328), (330, 382), (399, 399), (416, 417), (431, 432), (461, 476), (478, 483), (486, 496), (500, 501), (504, 539), (542, 543), (550, 563), (601, 601), (699, 700), (748, 748), (768, 772), (774, 780), (783, 785), (787, 788), (795, 795), (803, 808), (813, 814), (816, 817), (821, 821), (824, 825), (834, 834), (837, 837), (885, 885), (891, 893), (902, 902), (904, 906), (908, 908), (910, 929), (931, 974), (1020, 1119), (1162, 1279), (1296, 1321), (1326, 1327), (1329, 1366), (1369, 1369), (1377, 1414), (1418, 1418), (1460, 1460), (1488, 1514), (1519, 1524), (1568, 1599), (1601, 1621), (1632, 1641), (1648, 1650), (1652, 1652), (1657, 1677), (1679, 1696), (1698, 1747), (1749, 1749), (1765, 1766), (1774, 1791), (1872, 1969), (2160, 2183), (2185, 2190), (2208, 2220), (2226, 2226), (2229, 2249), (2305, 2381), (2383, 2384), (2390, 2391), (2400, 2403), (2406, 2415), (2417, 2423), (2425, 2431), (2433, 2435), (2437, 2444), (2447, 2448), (2451, 2472), (2474, 2480), (2482, 2482), (2486, 2489), (2492, 2500), (2503, 2504), (2507, 2510), (2519, 2519), (2528, 2531), (2534, 2545), (2558, 2558), (2561, 2563), (2565, 2570), (2575, 2576), (2579, 2600), (2602, 2608), (2610, 2610), (2613, 2613), (2616, 2617), (2620, 2620), (2622, 2626), (2631, 2632), (2635, 2637), (2652, 2652), (2662, 2676), (2689, 2691), (2693, 2701), (2703, 2705), (2707, 2728), (2730, 2736), (2738, 2739), (2741, 2745), (2748, 2757), (2759, 2761), (2763, 2765), (2768, 2768), (2784, 2787), (2790, 2799), (2810, 2815), (2817, 2819), (2821, 2828), (2831, 2832), (2835, 2856), (2858, 2864), (2866, 2867), (2869, 2873), (2876, 2883), (2887, 2888), (2891, 2893), (2901, 2903), (2911, 2913), (2918, 2927), (2929, 2929), (2946, 2947), (2949, 2954), (2958, 2960), (2962, 2965), (2969, 2970), (2972, 2972), (2974, 2975), (2979, 2980), (2984, 2986), (2990, 3001), (3006, 3010), (3014, 3016), (3018, 3021), (3024, 3024), (3031, 3031), (3046, 3055), (3073, 3084), (3086, 3088), (3090, 3112), (3114, 3123), (3125, 3129), (3132, 3140), (3142, 3144), (3146, 3149), (3157, 3158), (3165, 3165), (3168, 3169), (3174, 3183), (3200, 3200), (3202, 3203), (3205, 3212), (3214, 3216), (3218, 3240), (3242, 3251), (3253, 3257), (3260, 3268), (3270, 3272), (3274, 3277), (3285, 3286), (3293, 3293), (3296, 3299), (3302, 3311), (3313, 3314), (3328, 3328), (3330, 3331), (3333, 3340), (3342, 3344), (3346, 3386), (3389, 3395), (3398, 3400), (3402, 3406), (3412, 3415), (3424, 3425), (3430, 3439), (3450, 3455), (3458, 3459), (3461, 3470), (3473, 3478), (3482, 3493), (3495, 3505), (3507, 3515), (3517, 3517), (3520, 3526), (3530, 3530), (3535, 3540), (3542, 3542), (3544, 3550), (3570, 3570), (3585, 3634), (3636, 3642), (3648, 3662), (3664, 3673), (3713, 3714), (3716, 3716), (3718, 3722), (3724, 3747), (3749, 3749), (3751, 3762), (3764, 3773), (3776, 3780), (3782, 3782), (3784, 3789), (3792, 3801), (3806, 3807), (3840, 3840), (3851, 3851), (3872, 3881), (3893, 3893), (3895, 3895), (3902, 3906), (3908, 3911), (3913, 3916), (3918, 3921), (3923, 3926), (3928, 3931), (3933, 3944), (3946, 3948), (3953, 3954), (3956, 3956), (3962, 3968), (3970, 3972), (3974, 3986), (3988, 3991), (3993, 3996), (3998, 4001), (4003, 4006), (4008, 4011), (4013, 4024), (4026, 4028), (4038, 4038), (4096, 4169), (4176, 4253), (4295, 4295), (4301, 4301), (4304, 4336), (4343, 4346), (4349, 4351), (4608, 4680), (4682, 4685), (4688, 4694), (4696, 4696), (4698, 4701), (4704, 4744), (4746, 4749), (4752, 4784), (4786, 4789), (4792, 4798), (4800, 4800), (4802, 4805), (4808, 4822), (4824, 4880), (4882, 4885), (4888, 4954), (4957, 4959), (4992, 5007), (6016, 6050), (6053, 6055), (6057, 6067), (6070, 6093), (6096, 6096), (6098, 6098), (6103, 6103), (6108, 6108), (6112, 6121), (7312, 7354), (7357, 7359), (7680, 7833), (7838, 7838), (7840, 7929), (7936, 7957), (7960, 7965), (7968, 8005), (8008, 8013), (8016, 8023), (8025, 8025), (8027, 8027), (8029, 8029), (8031, 8048), (8050, 8050), (8052, 8052), (8054, 8054), (8056, 8056), (8058, 8058), (8060, 8060), (8064, 8116), (8118, 8122), (8124, 8124), (8130, 8132), (8134, 8136), (8138, 8138), (8140, 8140), (8144, 8146), (8150, 8154), (8160, 8162), (8164, 8170), (8172, 8172), (8178, 8180), (8182, 8184), (8186, 8186), (8188, 8188), (8204, 8205), (8208, 8208), (8217, 8217), (8231, 8231), (11559, 11559), (11565, 11565), (11648, 11670), (11680, 11686), (11688, 11694), (11696, 11702), (11704, 11710), (11712, 11718), (11720, 11726), (11728, 11734), (11736, 11742), (12293, 12295), (12353, 12438), (12441, 12442), (12445, 12446), (12448, 12542), (12549, 12589), (12591, 12591), (12704, 12735), (13312, 19903), (19968, 40959), (42623, 42623), (42775, 42783), (42888, 42888), (42893, 42893), (42898, 42899), (42922, 42922), (42926, 42926), (42936, 42937), (42944, 42954), (42960, 42961), (42963, 42963), (42965, 42969), (43495, 43518), (43616, 43638), (43642, 43647), (43777, 43782), (43785, 43790), (43793, 43798), (43808, 43814), (43816, 43822), (43878, 43879), (44032, 55203), (64014, 64015), (64017, 64017), (64019, 64020), (64031, 64031), (64033, 64033), (64035, 64036), (64039, 64041), (70401, 70401), (70403, 70403), (70459, 70460), (94192, 94193), (110879, 110882), (110928, 110930), (110948, 110951), (122624, 122654), (124896, 124902), (124904, 124907), (124909, 124910), (124912, 124926), (131072, 173791), (173824, 177976), (177984, 178205), (178208, 183969), (183984, 191456), (196608, 201546)];
+pub static IDENTIFIER_TYPE_RANGES: [(u32, u32, u16); 1629] = [(9, 13, 16), (32, 38, 16), (39, 39, 1024), (40, 44, 16), (45, 46, 1024), (47, 47, 16), (48, 57, 2048), (58, 58, 1024), (59, 64, 16), (65, 90, 2048), (91, 94, 16), (95, 95, 2048), (96, 96, 16), (97, 122, 2048), (123, 126, 16), (133, 133, 16), (160, 160, 8), (161, 167, 16), (168, 168, 8), (169, 169, 16), (170, 170, 8), (171, 172, 16), (173, 173, 4), (174, 174, 16), (175, 175, 8), (176, 177, 16), (178, 181, 8), (182, 182, 16), (183, 183, 1024), (184, 186, 8), (187, 187, 16), (188, 190, 8), (191, 191, 16), (192, 214, 2048), (215, 215, 16), (216, 246, 2048), (247, 247, 16), (248, 305, 2048), (306, 307, 8), (308, 318, 2048), (319, 320, 8), (321, 328, 2048), (329, 329, 2), (330, 382, 2048), (383, 383, 8), (384, 384, 128), (385, 396, 256), (397, 397, 192), (398, 398, 256), (399, 399, 2048), (400, 415, 256), (416, 417, 2048), (418, 425, 256), (426, 427, 192), (428, 430, 256), (431, 432, 2048), (433, 440, 256), (441, 441, 64), (442, 443, 192), (444, 445, 256), (446, 446, 192), (447, 447, 64), (448, 451, 128), (452, 460, 8), (461, 476, 2048), (477, 477, 256), (478, 483, 2048), (484, 485, 256), (486, 496, 2048), (497, 499, 8), (500, 501, 2048), (502, 503, 64), (504, 539, 2048), (540, 541, 64), (542, 543, 2048), (544, 549, 256), (550, 563, 2048), (564, 566, 128), (567, 591, 256), (592, 594, 128), (595, 596, 384), (597, 597, 128), (598, 599, 384), (600, 600, 128), (601, 601, 2048), (602, 602, 128), (603, 603, 384), (604, 610, 128), (611, 611, 384), (612, 615, 128), (616, 617, 384), (618, 625, 128), (626, 626, 384), (627, 630, 128), (631, 631, 192), (632, 635, 128), (636, 636, 192), (637, 648, 128), (649, 649, 384), (650, 657, 128), (658, 658, 384), (659, 669, 128), (670, 670, 192), (671, 687, 128), (688, 696, 8), (697, 698, 128), (699, 700, 2048), (701, 705, 128), (706, 709, 16), (710, 721, 128), (722, 727, 16), (728, 733, 8), (734, 735, 16), (736, 740, 8), (741, 747, 16), (748, 748, 2048), (749, 749, 16), (750, 750, 128), (751, 767, 16), (768, 772, 2048), (773, 773, 256), (774, 780, 2048), (781, 781, 256), (782, 782, 128), (783, 785, 2048), (786, 786, 128), (787, 788, 2048), (789, 789, 128), (790, 790, 256), (791, 794, 128), (795, 795, 2048), (796, 800, 128), (801, 802, 256), (803, 808, 2048), (809, 812, 128), (813, 814, 2048), (815, 815, 128), (816, 817, 2048), (818, 818, 256), (819, 819, 128), (820, 820, 256), (821, 821, 2048), (822, 822, 256), (823, 823, 128), (824, 825, 2048), (826, 831, 128), (832, 833, 8), (834, 834, 2048), (835, 836, 8), (837, 837, 2048), (838, 846, 128), (847, 847, 4), (848, 855, 128), (856, 856, 256), (857, 866, 128), (867, 883, 64), (884, 884, 8), (885, 885, 1024), (886, 887, 64), (890, 890, 8), (891, 893, 2048), (894, 894, 8), (895, 895, 64), (900, 901, 8), (902, 902, 2048), (903, 903, 8), (904, 906, 2048), (908, 908, 2048), (910, 929, 2048), (931, 974, 2048), (975, 975, 128), (976, 982, 8), (983, 983, 128), (984, 993, 64), (994, 1007, 32), (1008, 1010, 8), (1011, 1011, 192), (1012, 1013, 8), (1014, 1014, 16), (1015, 1016, 64), (1017, 1017, 8), (1018, 1019, 64), (1020, 1119, 2048), (1120, 1153, 64), (1154, 1154, 80), (1155, 1155, 64), (1156, 1159, 192), (1160, 1161, 80), (1162, 1279, 2048), (1280, 1295, 64), (1296, 1321, 2048), (1322, 1325, 64), (1326, 1327, 2048), (1329, 1366, 2048), (1369, 1369, 2048), (1370, 1375, 16), (1376, 1376, 128), (1377, 1414, 2048), (1415, 1415, 8), (1416, 1416, 128), (1417, 1417, 16), (1418, 1418, 1024), (1421, 1423, 16), (1425, 1441, 256), (1442, 1442, 320), (1443, 1459, 256), (1460, 1460, 2048), (1461, 1469, 256), (1470, 1470, 16), (1471, 1471, 256), (1472, 1472, 16), (1473, 1474, 256), (1475, 1475, 16), (1476, 1476, 256), (1477, 1477, 320), (1478, 1478, 80), (1479, 1479, 384), (1488, 1514, 2048), (1519, 1522, 2048), (1523, 1524, 1024), (1536, 1551, 16), (1552, 1562, 256), (1563, 1563, 16), (1564, 1564, 4), (1565, 1567, 16), (1568, 1599, 2048), (1600, 1600, 64), (1601, 1621, 2048), (1622, 1631, 256), (1632, 1641, 2048), (1642, 1645, 16), (1646, 1647, 64), (1648, 1650, 2048), (1651, 1651, 2), (1652, 1652, 2048), (1653, 1656, 8), (1657, 1677, 2048), (1678, 1678, 64), (1679, 1696, 2048), (1697, 1697, 64), (1698, 1747, 2048), (1748, 1748, 16), (1749, 1749, 2048), (1750, 1756, 256), (1757, 1758, 16), (1759, 1764, 256), (1765, 1766, 2048), (1767, 1768, 256), (1769, 1769, 16), (1770, 1773, 256), (1774, 1788, 2048), (1789, 1790, 1024), (1791, 1791, 2048), (1792, 1805, 528), (1807, 1807, 528), (1808, 1855, 512), (1856, 1866, 640), (1869, 1871, 512), (1872, 1969, 2048), (1984, 2023, 512), (2024, 2026, 576), (2027, 2037, 512), (2038, 2041, 528), (2042, 2042, 576), (2045, 2045, 512), (2046, 2047, 528), (2048, 2093, 32), (2096, 2110, 48), (2112, 2139, 512), (2142, 2142, 528), (2144, 2154, 512), (2160, 2183, 2048), (2184, 2184, 16), (2185, 2190, 2048), (2192, 2193, 16), (2200, 2207, 256), (2208, 2220, 2048), (2221, 2225, 64), (2226, 2226, 2048), (2227, 2228, 256), (2229, 2249, 2048), (2250, 2273, 256), (2274, 2274, 16), (2275, 2304, 256), (2305, 2381, 2048), (2382, 2382, 64), (2383, 2384, 2048), (2385, 2386, 64), (2387, 2388, 128), (2389, 2389, 256), (2390, 2391, 2048), (2392, 2399, 8), (2400, 2403, 2048), (2404, 2405, 16), (2406, 2415, 2048), (2416, 2416, 16), (2417, 2423, 2048), (2424, 2424, 64), (2425, 2431, 2048), (2432, 2432, 64), (2433, 2435, 2048), (2437, 2444, 2048), (2447, 2448, 2048), (2451, 2472, 2048), (2474, 2480, 2048), (2482, 2482, 2048), (2486, 2489, 2048), (2492, 2500, 2048), (2503, 2504, 2048), (2507, 2510, 2048), (2519, 2519, 2048), (2524, 2525, 8), (2527, 2527, 8), (2528, 2531, 2048), (2534, 2545, 2048), (2546, 2555, 16), (2556, 2556, 64), (2557, 2557, 16), (2558, 2558, 2048), (2561, 2563, 2048), (2565, 2570, 2048), (2575, 2576, 2048), (2579, 2600, 2048), (2602, 2608, 2048), (2610, 2610, 2048), (2611, 2611, 8), (2613, 2613, 2048), (2614, 2614, 8), (2616, 2617, 2048), (2620, 2620, 2048), (2622, 2626, 2048), (2631, 2632, 2048), (2635, 2637, 2048), (2641, 2641, 256), (2649, 2651, 8), (2652, 2652, 2048), (2654, 2654, 8), (2662, 2676, 2048), (2677, 2677, 256), (2678, 2678, 16), (2689, 2691, 2048), (2693, 2701, 2048), (2703, 2705, 2048), (2707, 2728, 2048), (2730, 2736, 2048), (2738, 2739, 2048), (2741, 2745, 2048), (2748, 2757, 2048), (2759, 2761, 2048), (2763, 2765, 2048), (2768, 2768, 2048), (2784, 2787, 2048), (2790, 2799, 2048), (2800, 2801, 16), (2809, 2809, 256), (2810, 2815, 2048), (2817, 2819, 2048), (2821, 2828, 2048), (2831, 2832, 2048), (2835, 2856, 2048), (2858, 2864, 2048), (2866, 2867, 2048), (2869, 2873, 2048), (2876, 2883, 2048), (2884, 2884, 256), (2887, 2888, 2048), (2891, 2893, 2048), (2901, 2903, 2048), (2908, 2909, 8), (2911, 2913, 2048), (2914, 2915, 256), (2918, 2927, 2048), (2928, 2928, 16), (2929, 2929, 2048), (2930, 2935, 16), (2946, 2947, 2048), (2949, 2954, 2048), (2958, 2960, 2048), (2962, 2965, 2048), (2969, 2970, 2048), (2972, 2972, 2048), (2974, 2975, 2048), (2979, 2980, 2048), (2984, 2986, 2048), (2990, 3001, 2048), (3006, 3010, 2048), (3014, 3016, 2048), (3018, 3021, 2048), (3024, 3024, 2048), (3031, 3031, 2048), (3046, 3055, 2048), (3056, 3066, 16), (3072, 3072, 64), (3073, 3084, 2048), (3086, 3088, 2048), (3090, 3112, 2048), (3114, 3123, 2048), (3124, 3124, 64), (3125, 3129, 2048), (3132, 3140, 2048), (3142, 3144, 2048), (3146, 3149, 2048), (3157, 3158, 2048), (3160, 3161, 64), (3162, 3162, 256), (3165, 3165, 2048), (3168, 3169, 2048), (3170, 3171, 256), (3174, 3183, 2048), (3191, 3199, 16), (3200, 3200, 2048), (3201, 3201, 64), (3202, 3203, 2048), (3204, 3204, 16), (3205, 3212, 2048), (3214, 3216, 2048), (3218, 3240, 2048), (3242, 3251, 2048), (3253, 3257, 2048), (3260, 3268, 2048), (3270, 3272, 2048), (3274, 3277, 2048), (3285, 3286, 2048), (3293, 3293, 2048), (3294, 3294, 64), (3296, 3299, 2048), (3302, 3311, 2048), (3313, 3314, 2048), (3328, 3328, 2048), (3329, 3329, 64), (3330, 3331, 2048), (3332, 3332, 192), (3333, 3340, 2048), (3342, 3344, 2048), (3346, 3386, 2048), (3387, 3388, 64), (3389, 3395, 2048), (3396, 3396, 256), (3398, 3400, 2048), (3402, 3406, 2048), (3407, 3407, 16), (3412, 3415, 2048), (3416, 3422, 16), (3423, 3423, 64), (3424, 3425, 2048), (3426, 3427, 256), (3430, 3439, 2048), (3440, 3449, 16), (3450, 3455, 2048), (3457, 3457, 128), (3458, 3459, 2048), (3461, 3470, 2048), (3471, 3472, 384), (3473, 3478, 2048), (3482, 3493, 2048), (3494, 3494, 384), (3495, 3505, 2048), (3507, 3515, 2048), (3517, 3517, 2048), (3520, 3526, 2048), (3530, 3530, 2048), (3535, 3540, 2048), (3542, 3542, 2048), (3544, 3550, 2048), (3551, 3551, 384), (3558, 3567, 64), (3570, 3570, 2048), (3571, 3571, 384), (3572, 3572, 16), (3585, 3634, 2048), (3635, 3635, 8), (3636, 3642, 2048), (3647, 3647, 16), (3648, 3662, 2048), (3663, 3663, 16), (3664, 3673, 2048), (3674, 3675, 16), (3713, 3714, 2048), (3716, 3716, 2048), (3718, 3722, 2048), (3724, 3747, 2048), (3749, 3749, 2048), (3751, 3762, 2048), (3763, 3763, 8), (3764, 3773, 2048), (3776, 3780, 2048), (3782, 3782, 2048), (3784, 3789, 2048), (3792, 3801, 2048), (3804, 3805, 8), (3806, 3807, 2048), (3840, 3840, 2048), (3841, 3850, 16), (3851, 3851, 1024), (3852, 3852, 8), (3853, 3863, 16), (3864, 3865, 128), (3866, 3871, 16), (3872, 3881, 2048), (3882, 3892, 16), (3893, 3893, 2048), (3894, 3894, 16), (3895, 3895, 2048), (3896, 3896, 16), (3897, 3897, 256), (3898, 3901, 16), (3902, 3906, 2048), (3907, 3907, 8), (3908, 3911, 2048), (3913, 3916, 2048), (3917, 3917, 8), (3918, 3921, 2048), (3922, 3922, 8), (3923, 3926, 2048), (3927, 3927, 8), (3928, 3931, 2048), (3932, 3932, 8), (3933, 3944, 2048), (3945, 3945, 8), (3946, 3948, 2048), (3953, 3954, 2048), (3955, 3955, 8), (3956, 3956, 2048), (3957, 3958, 8), (3959, 3959, 2), (3960, 3960, 8), (3961, 3961, 2), (3962, 3968, 2048), (3969, 3969, 8), (3970, 3972, 2048), (3973, 3973, 16), (3974, 3986, 2048), (3987, 3987, 8), (3988, 3991, 2048), (3993, 3996, 2048), (3997, 3997, 8), (3998, 4001, 2048), (4002, 4002, 8), (4003, 4006, 2048), (4007, 4007, 8), (4008, 4011, 2048), (4012, 4012, 8), (4013, 4024, 2048), (4025, 4025, 8), (4026, 4028, 2048), (4030, 4037, 16), (4038, 4038, 2048), (4039, 4044, 16), (4046, 4058, 16), (4096, 4169, 2048), (4170, 4175, 16), (4176, 4253, 2048), (4254, 4255, 16), (4256, 4293, 64), (4295, 4295, 2048), (4301, 4301, 2048), (4304, 4336, 2048), (4337, 4342, 64), (4343, 4346, 2048), (4347, 4347, 16), (4348, 4348, 8), (4349, 4351, 2048), (4352, 4446, 64), (4447, 4448, 4), (4449, 4607, 64), (4608, 4680, 2048), (4682, 4685, 2048), (4688, 4694, 2048), (4696, 4696, 2048), (4698, 4701, 2048), (4704, 4744, 2048), (4746, 4749, 2048), (4752, 4784, 2048), (4786, 4789, 2048), (4792, 4798, 2048), (4800, 4800, 2048), (4802, 4805, 2048), (4808, 4822, 2048), (4824, 4880, 2048), (4882, 4885, 2048), (4888, 4954, 2048), (4957, 4959, 2048), (4960, 4968, 16), (4969, 4977, 64), (4978, 4988, 16), (4992, 5007, 2048), (5008, 5017, 16), (5024, 5109, 512), (5112, 5117, 512), (5120, 5120, 528), (5121, 5740, 512), (5741, 5742, 528), (5743, 5759, 512), (5760, 5760, 48), (5761, 5786, 32), (5787, 5788, 48), (5792, 5866, 32), (5867, 5869, 16), (5870, 5880, 32), (5888, 5909, 32), (5919, 5940, 32), (5941, 5942, 48), (5952, 5971, 32), (5984, 5996, 32), (5998, 6000, 32), (6002, 6003, 32), (6016, 6050, 2048), (6051, 6052, 2), (6053, 6055, 2048), (6056, 6056, 64), (6057, 6067, 2048), (6068, 6069, 4), (6070, 6093, 2048), (6094, 6095, 128), (6096, 6096, 2048), (6097, 6097, 192), (6098, 6098, 2048), (6099, 6099, 64), (6100, 6102, 16), (6103, 6103, 2048), (6104, 6104, 80), (6105, 6107, 16), (6108, 6108, 2048), (6109, 6109, 192), (6112, 6121, 2048), (6128, 6137, 16), (6144, 6154, 48), (6155, 6159, 4), (6160, 6169, 32), (6176, 6264, 32), (6272, 6312, 32), (6313, 6313, 288), (6314, 6314, 32), (6320, 6389, 512), (6400, 6430, 512), (6432, 6443, 512), (6448, 6459, 512), (6464, 6464, 528), (6468, 6469, 528), (6470, 6509, 512), (6512, 6516, 512), (6528, 6571, 512), (6576, 6601, 512), (6608, 6618, 512), (6622, 6623, 528), (6624, 6655, 16), (6656, 6683, 32), (6686, 6687, 48), (6688, 6750, 512), (6752, 6780, 512), (6783, 6793, 512), (6800, 6809, 512), (6816, 6822, 528), (6823, 6823, 512), (6824, 6829, 528), (6832, 6845, 64), (6846, 6846, 16), (6847, 6848, 128), (6849, 6862, 256), (6912, 6988, 512), (6992, 7001, 512), (7002, 7018, 528), (7019, 7027, 640), (7028, 7038, 528), (7040, 7155, 512), (7164, 7167, 528), (7168, 7223, 512), (7227, 7231, 528), (7232, 7241, 512), (7245, 7293, 512), (7294, 7295, 528), (7296, 7304, 64), (7312, 7354, 2048), (7357, 7359, 2048), (7360, 7367, 528), (7376, 7378, 64), (7379, 7379, 80), (7380, 7417, 64), (7418, 7418, 32), (7424, 7467, 128), (7468, 7470, 8), (7471, 7471, 128), (7472, 7482, 8), (7483, 7483, 128), (7484, 7501, 8), (7502, 7502, 128), (7503, 7530, 8), (7531, 7543, 128), (7544, 7544, 8), (7545, 7578, 128), (7579, 7615, 8), (7616, 7619, 192), (7620, 7629, 128), (7630, 7630, 192), (7631, 7632, 128), (7633, 7654, 192), (7655, 7673, 128), (7674, 7674, 640), (7675, 7679, 128), (7680, 7833, 2048), (7834, 7835, 8), (7836, 7837, 128), (7838, 7838, 2048), (7839, 7839, 128), (7840, 7929, 2048), (7930, 7935, 128), (7936, 7957, 2048), (7960, 7965, 2048), (7968, 8005, 2048), (8008, 8013, 2048), (8016, 8023, 2048), (8025, 8025, 2048), (8027, 8027, 2048), (8029, 8029, 2048), (8031, 8048, 2048), (8049, 8049, 8), (8050, 8050, 2048), (8051, 8051, 8), (8052, 8052, 2048), (8053, 8053, 8), (8054, 8054, 2048), (8055, 8055, 8), (8056, 8056, 2048), (8057, 8057, 8), (8058, 8058, 2048), (8059, 8059, 8), (8060, 8060, 2048), (8061, 8061, 8), (8064, 8116, 2048), (8118, 8122, 2048), (8123, 8123, 8), (8124, 8124, 2048), (8125, 8129, 8), (8130, 8132, 2048), (8134, 8136, 2048), (8137, 8137, 8), (8138, 8138, 2048), (8139, 8139, 8), (8140, 8140, 2048), (8141, 8143, 8), (8144, 8146, 2048), (8147, 8147, 8), (8150, 8154, 2048), (8155, 8155, 8), (8157, 8159, 8), (8160, 8162, 2048), (8163, 8163, 8), (8164, 8170, 2048), (8171, 8171, 8), (8172, 8172, 2048), (8173, 8175, 8), (8178, 8180, 2048), (8182, 8184, 2048), (8185, 8185, 8), (8186, 8186, 2048), (8187, 8187, 8), (8188, 8188, 2048), (8189, 8190, 8), (8192, 8202, 8), (8203, 8203, 4), (8204, 8205, 1024), (8206, 8207, 4), (8208, 8208, 1024), (8209, 8209, 8), (8210, 8214, 16), (8215, 8215, 8), (8216, 8216, 16), (8217, 8217, 1024), (8218, 8227, 16), (8228, 8230, 8), (8231, 8231, 1024), (8232, 8233, 16), (8234, 8238, 4), (8239, 8239, 8), (8240, 8242, 16), (8243, 8244, 8), (8245, 8245, 16), (8246, 8247, 8), (8248, 8251, 16), (8252, 8252, 8), (8253, 8253, 16), (8254, 8254, 8), (8255, 8256, 128), (8257, 8262, 16), (8263, 8265, 8), (8266, 8275, 16), (8276, 8276, 256), (8277, 8277, 16), (8278, 8278, 80), (8279, 8279, 8), (8280, 8286, 80), (8287, 8287, 8), (8288, 8292, 4), (8294, 8297, 4), (8298, 8303, 2), (8304, 8305, 8), (8308, 8334, 8), (8336, 8348, 8), (8352, 8359, 16), (8360, 8360, 8), (8361, 8384, 16), (8400, 8412, 128), (8413, 8416, 144), (8417, 8417, 128), (8418, 8420, 144), (8421, 8432, 128), (8448, 8451, 8), (8452, 8452, 16), (8453, 8455, 8), (8456, 8456, 16), (8457, 8467, 8), (8468, 8468, 16), (8469, 8470, 8), (8471, 8471, 16), (8472, 8472, 128), (8473, 8477, 8), (8478, 8479, 16), (8480, 8482, 8), (8483, 8483, 16), (8484, 8484, 8), (8485, 8485, 16), (8486, 8486, 8), (8487, 8487, 80), (8488, 8488, 8), (8489, 8489, 16), (8490, 8493, 8), (8494, 8494, 128), (8495, 8497, 8), (8498, 8498, 64), (8499, 8505, 8), (8506, 8506, 16), (8507, 8512, 8), (8513, 8516, 16), (8517, 8521, 8), (8522, 8525, 16), (8526, 8526, 64), (8527, 8527, 80), (8528, 8575, 8), (8576, 8579, 192), (8580, 8584, 64), (8585, 8585, 8), (8586, 8587, 272), (8592, 8747, 16), (8748, 8749, 8), (8750, 8750, 16), (8751, 8752, 8), (8753, 9000, 16), (9001, 9002, 2), (9003, 9254, 16), (9280, 9290, 16), (9312, 9450, 8), (9451, 9471, 144), (9472, 10239, 16), (10240, 10495, 144), (10496, 10763, 16), (10764, 10764, 8), (10765, 10867, 16), (10868, 10870, 8), (10871, 10971, 16), (10972, 10972, 8), (10973, 11123, 16), (11126, 11157, 16), (11159, 11243, 16), (11244, 11247, 272), (11248, 11263, 16), (11264, 11359, 32), (11360, 11367, 128), (11368, 11372, 256), (11373, 11382, 64), (11383, 11387, 128), (11388, 11389, 8), (11390, 11391, 64), (11392, 11492, 32), (11493, 11498, 48), (11499, 11503, 32), (11504, 11505, 160), (11506, 11507, 32), (11513, 11519, 48), (11520, 11557, 64), (11559, 11559, 2048), (11565, 11565, 2048), (11568, 11623, 512), (11631, 11631, 8), (11632, 11632, 528), (11647, 11647, 512), (11648, 11670, 2048), (11680, 11686, 2048), (11688, 11694, 2048), (11696, 11702, 2048), (11704, 11710, 2048), (11712, 11718, 2048), (11720, 11726, 2048), (11728, 11734, 2048), (11736, 11742, 2048), (11744, 11775, 64), (11776, 11789, 208), (11790, 11798, 80), (11799, 11817, 16), (11818, 11826, 80), (11827, 11828, 16), (11829, 11829, 80), (11830, 11832, 16), (11833, 11833, 80), (11834, 11869, 16), (11904, 11929, 16), (11931, 11934, 16), (11935, 11935, 8), (11936, 12018, 16), (12019, 12019, 8), (12032, 12245, 8), (12272, 12283, 16), (12288, 12288, 8), (12289, 12292, 16), (12293, 12295, 2048), (12296, 12317, 16), (12318, 12318, 80), (12319, 12320, 16), (12321, 12333, 128), (12334, 12335, 192), (12336, 12336, 16), (12337, 12341, 128), (12342, 12342, 8), (12343, 12343, 16), (12344, 12346, 8), (12347, 12348, 128), (12349, 12351, 16), (12353, 12438, 2048), (12441, 12442, 2048), (12443, 12444, 8), (12445, 12446, 2048), (12447, 12447, 8), (12448, 12448, 1024), (12449, 12538, 2048), (12539, 12539, 1024), (12540, 12542, 2048), (12543, 12543, 8), (12549, 12589, 2048), (12590, 12590, 64), (12591, 12591, 2048), (12593, 12643, 8), (12644, 12644, 4), (12645, 12686, 8), (12688, 12689, 16), (12690, 12703, 8), (12704, 12735, 2048), (12736, 12771, 16), (12784, 12799, 64), (12800, 12830, 8), (12832, 12871, 8), (12872, 12879, 16), (12880, 12926, 8), (12927, 12927, 144), (12928, 13311, 8), (13312, 19903, 2048), (19904, 19967, 144), (19968, 40959, 2048), (40960, 42124, 512), (42128, 42182, 528), (42192, 42237, 512), (42238, 42239, 528), (42240, 42508, 512), (42509, 42511, 528), (42512, 42514, 576), (42515, 42537, 512), (42538, 42539, 576), (42560, 42606, 64), (42607, 42607, 256), (42608, 42611, 80), (42612, 42619, 64), (42620, 42621, 256), (42622, 42622, 16), (42623, 42623, 2048), (42624, 42651, 64), (42652, 42653, 8), (42654, 42654, 320), (42655, 42655, 64), (42656, 42737, 512), (42738, 42743, 528), (42752, 42759, 80), (42760, 42774, 144), (42775, 42783, 2048), (42784, 42785, 16), (42786, 42799, 192), (42800, 42863, 64), (42864, 42864, 8), (42865, 42887, 64), (42888, 42888, 2048), (42889, 42890, 16), (42891, 42892, 256), (42893, 42893, 2048), (42894, 42894, 128), (42895, 42895, 256), (42896, 42897, 64), (42898, 42899, 2048), (42900, 42921, 64), (42922, 42922, 2048), (42923, 42925, 64), (42926, 42926, 2048), (42927, 42927, 128), (42928, 42929, 64), (42930, 42935, 256), (42936, 42937, 2048), (42938, 42943, 128), (42944, 42954, 2048), (42960, 42961, 2048), (42963, 42963, 2048), (42965, 42969, 2048), (42994, 42996, 8), (42997, 42999, 64), (43000, 43001, 8), (43002, 43002, 128), (43003, 43007, 64), (43008, 43047, 512), (43048, 43051, 528), (43052, 43052, 512), (43056, 43065, 16), (43072, 43123, 32), (43124, 43127, 48), (43136, 43205, 512), (43214, 43215, 528), (43216, 43225, 512), (43232, 43255, 64), (43256, 43258, 80), (43259, 43259, 64), (43260, 43260, 336), (43261, 43261, 320), (43262, 43263, 64), (43264, 43309, 512), (43310, 43310, 16), (43311, 43311, 528), (43312, 43347, 32), (43359, 43359, 48), (43360, 43388, 64), (43392, 43456, 512), (43457, 43469, 528), (43471, 43471, 544), (43472, 43481, 512), (43486, 43487, 528), (43488, 43494, 64), (43495, 43518, 2048), (43520, 43574, 512), (43584, 43597, 512), (43600, 43609, 512), (43612, 43615, 528), (43616, 43638, 2048), (43639, 43641, 16), (43642, 43647, 2048), (43648, 43714, 512), (43739, 43741, 512), (43742, 43743, 528), (43744, 43759, 512), (43760, 43761, 528), (43762, 43766, 512), (43777, 43782, 2048), (43785, 43790, 2048), (43793, 43798, 2048), (43808, 43814, 2048), (43816, 43822, 2048), (43824, 43866, 64), (43867, 43867, 16), (43868, 43871, 8), (43872, 43875, 256), (43876, 43877, 64), (43878, 43879, 2048), (43880, 43880, 128), (43881, 43881, 8), (43882, 43883, 16), (43888, 44010, 512), (44011, 44011, 528), (44012, 44013, 512), (44016, 44025, 512), (44032, 55203, 2048), (55216, 55238, 64), (55243, 55291, 64), (63744, 64013, 8), (64014, 64015, 2048), (64016, 64016, 8), (64017, 64017, 2048), (64018, 64018, 8), (64019, 64020, 2048), (64021, 64030, 8), (64031, 64031, 2048), (64032, 64032, 8), (64033, 64033, 2048), (64034, 64034, 8), (64035, 64036, 2048), (64037, 64038, 8), (64039, 64041, 2048), (64042, 64109, 8), (64112, 64217, 8), (64256, 64262, 8), (64275, 64279, 8), (64285, 64285, 8), (64286, 64286, 384), (64287, 64310, 8), (64312, 64316, 8), (64318, 64318, 8), (64320, 64321, 8), (64323, 64324, 8), (64326, 64433, 8), (64434, 64450, 144), (64467, 64829, 8), (64830, 64847, 144), (64848, 64911, 8), (64914, 64967, 8), (64975, 64975, 144), (65008, 65020, 8), (65021, 65023, 144), (65024, 65039, 4), (65040, 65049, 8), (65056, 65069, 128), (65070, 65071, 384), (65072, 65092, 8), (65093, 65094, 144), (65095, 65106, 8), (65108, 65126, 8), (65128, 65131, 8), (65136, 65138, 8), (65139, 65139, 128), (65140, 65140, 8), (65142, 65276, 8), (65279, 65279, 4), (65281, 65439, 8), (65440, 65440, 4), (65441, 65470, 8), (65474, 65479, 8), (65482, 65487, 8), (65490, 65495, 8), (65498, 65500, 8), (65504, 65510, 8), (65512, 65518, 8), (65529, 65533, 16), (65536, 65547, 32), (65549, 65574, 32), (65576, 65594, 32), (65596, 65597, 32), (65599, 65613, 32), (65616, 65629, 32), (65664, 65786, 32), (65792, 65794, 48), (65799, 65843, 48), (65847, 65855, 48), (65856, 65908, 64), (65909, 65934, 16), (65936, 65948, 16), (65952, 65952, 16), (66000, 66044, 80), (66045, 66045, 64), (66176, 66204, 32), (66208, 66256, 32), (66272, 66272, 64), (66273, 66299, 80), (66304, 66335, 32), (66336, 66339, 48), (66349, 66378, 32), (66384, 66426, 32), (66432, 66461, 32), (66463, 66463, 48), (66464, 66499, 32), (66504, 66511, 32), (66512, 66512, 48), (66513, 66517, 32), (66560, 66717, 32), (66720, 66729, 32), (66736, 66771, 512), (66776, 66811, 512), (66816, 66855, 32), (66864, 66915, 32), (66927, 66927, 48), (66928, 66938, 32), (66940, 66954, 32), (66956, 66962, 32), (66964, 66965, 32), (66967, 66977, 32), (66979, 66993, 32), (66995, 67001, 32), (67003, 67004, 32), (67072, 67382, 32), (67392, 67413, 32), (67424, 67431, 32), (67456, 67456, 256), (67457, 67461, 8), (67463, 67504, 8), (67506, 67514, 8), (67584, 67589, 32), (67592, 67592, 32), (67594, 67637, 32), (67639, 67640, 32), (67644, 67644, 32), (67647, 67669, 32), (67671, 67679, 48), (67680, 67702, 32), (67703, 67711, 48), (67712, 67742, 32), (67751, 67759, 48), (67808, 67826, 32), (67828, 67829, 32), (67835, 67839, 48), (67840, 67861, 32), (67862, 67867, 48), (67871, 67871, 48), (67872, 67897, 32), (67903, 67903, 48), (67968, 68023, 32), (68028, 68029, 48), (68030, 68031, 32), (68032, 68047, 48), (68050, 68095, 48), (68096, 68099, 32), (68101, 68102, 32), (68108, 68115, 32), (68117, 68119, 32), (68121, 68149, 32), (68152, 68154, 32), (68159, 68159, 32), (68160, 68168, 48), (68176, 68184, 48), (68192, 68220, 32), (68221, 68223, 48), (68224, 68252, 32), (68253, 68255, 48), (68288, 68295, 32), (68296, 68296, 48), (68297, 68326, 32), (68331, 68342, 48), (68352, 68405, 32), (68409, 68415, 48), (68416, 68437, 32), (68440, 68447, 48), (68448, 68466, 32), (68472, 68479, 48), (68480, 68497, 32), (68505, 68508, 48), (68521, 68527, 48), (68608, 68680, 32), (68736, 68786, 32), (68800, 68850, 32), (68858, 68863, 48), (68864, 68903, 512), (68912, 68921, 512), (69216, 69246, 16), (69248, 69289, 32), (69291, 69292, 32), (69293, 69293, 48), (69296, 69297, 32), (69376, 69404, 32), (69405, 69414, 48), (69415, 69415, 32), (69424, 69456, 32), (69457, 69465, 48), (69488, 69509, 32), (69510, 69513, 48), (69552, 69572, 32), (69573, 69579, 48), (69600, 69622, 32), (69632, 69702, 32), (69703, 69709, 48), (69714, 69733, 48), (69734, 69749, 32), (69759, 69818, 32), (69819, 69825, 48), (69826, 69826, 32), (69837, 69837, 48), (69840, 69864, 32), (69872, 69881, 32), (69888, 69940, 512), (69942, 69951, 512), (69952, 69955, 528), (69956, 69959, 512), (69968, 70003, 32), (70004, 70005, 48), (70006, 70006, 32), (70016, 70084, 32), (70085, 70088, 48), (70089, 70092, 32), (70093, 70093, 48), (70094, 70106, 32), (70107, 70107, 48), (70108, 70108, 32), (70109, 70111, 48), (70113, 70132, 16), (70144, 70161, 32), (70163, 70199, 32), (70200, 70205, 48), (70206, 70206, 32), (70272, 70278, 32), (70280, 70280, 32), (70282, 70285, 32), (70287, 70301, 32), (70303, 70312, 32), (70313, 70313, 48), (70320, 70378, 32), (70384, 70393, 32), (70400, 70400, 32), (70401, 70401, 2048), (70402, 70402, 32), (70403, 70403, 2048), (70405, 70412, 32), (70415, 70416, 32), (70419, 70440, 32), (70442, 70448, 32), (70450, 70451, 32), (70453, 70457, 32), (70459, 70460, 2048), (70461, 70468, 32), (70471, 70472, 32), (70475, 70477, 32), (70480, 70480, 32), (70487, 70487, 32), (70493, 70499, 32), (70502, 70508, 32), (70512, 70516, 32), (70656, 70730, 512), (70731, 70735, 528), (70736, 70745, 512), (70746, 70747, 528), (70749, 70749, 528), (70750, 70753, 512), (70784, 70853, 32), (70854, 70854, 48), (70855, 70855, 32), (70864, 70873, 32), (71040, 71093, 32), (71096, 71104, 32), (71105, 71127, 48), (71128, 71133, 32), (71168, 71232, 32), (71233, 71235, 48), (71236, 71236, 32), (71248, 71257, 32), (71264, 71276, 48), (71296, 71352, 32), (71353, 71353, 48), (71360, 71369, 32), (71424, 71450, 32), (71453, 71467, 32), (71472, 71481, 32), (71482, 71487, 48), (71488, 71494, 32), (71680, 71738, 32), (71739, 71739, 48), (71840, 71913, 32), (71914, 71922, 48), (71935, 71942, 32), (71945, 71945, 32), (71948, 71955, 32), (71957, 71958, 32), (71960, 71989, 32), (71991, 71992, 32), (71995, 72003, 32), (72004, 72006, 48), (72016, 72025, 32), (72096, 72103, 32), (72106, 72151, 32), (72154, 72161, 32), (72162, 72162, 48), (72163, 72164, 32), (72192, 72254, 32), (72255, 72262, 48), (72263, 72263, 32), (72272, 72345, 32), (72346, 72348, 48), (72349, 72349, 32), (72350, 72354, 48), (72368, 72383, 512), (72384, 72440, 32), (72704, 72712, 32), (72714, 72758, 32), (72760, 72768, 32), (72769, 72773, 48), (72784, 72793, 32), (72794, 72812, 48), (72816, 72817, 48), (72818, 72847, 32), (72850, 72871, 32), (72873, 72886, 32), (72960, 72966, 32), (72968, 72969, 32), (72971, 73014, 32), (73018, 73018, 32), (73020, 73021, 32), (73023, 73031, 32), (73040, 73049, 32), (73056, 73061, 512), (73063, 73064, 512), (73066, 73102, 512), (73104, 73105, 512), (73107, 73112, 512), (73120, 73129, 512), (73440, 73462, 32), (73463, 73464, 48), (73648, 73648, 512), (73664, 73713, 16), (73727, 73727, 16), (73728, 74649, 32), (74752, 74862, 32), (74864, 74868, 48), (74880, 75075, 32), (77712, 77808, 32), (77809, 77810, 48), (77824, 78894, 32), (78896, 78904, 48), (82944, 83526, 32), (92160, 92728, 512), (92736, 92766, 288), (92768, 92777, 288), (92782, 92783, 48), (92784, 92862, 32), (92864, 92873, 32), (92880, 92909, 32), (92912, 92916, 32), (92917, 92917, 48), (92928, 92982, 32), (92983, 92991, 48), (92992, 92995, 32), (92996, 92997, 48), (93008, 93017, 32), (93019, 93025, 48), (93027, 93047, 32), (93053, 93071, 32), (93760, 93823, 32), (93824, 93850, 48), (93952, 94026, 512), (94031, 94087, 512), (94095, 94111, 512), (94176, 94177, 32), (94178, 94178, 16), (94179, 94179, 64), (94180, 94180, 32), (94192, 94193, 2048), (94208, 100343, 32), (100352, 101589, 32), (101632, 101640, 32), (110576, 110579, 256), (110581, 110587, 256), (110589, 110590, 256), (110592, 110878, 64), (110879, 110882, 2048), (110928, 110930, 2048), (110948, 110951, 2048), (110960, 111355, 32), (113664, 113770, 32), (113776, 113788, 32), (113792, 113800, 32), (113808, 113817, 32), (113820, 113820, 48), (113821, 113822, 32), (113823, 113823, 48), (113824, 113827, 4), (118528, 118573, 128), (118576, 118598, 128), (118608, 118723, 144), (118784, 119029, 144), (119040, 119078, 144), (119081, 119133, 144), (119134, 119140, 8), (119141, 119145, 128), (119146, 119148, 144), (119149, 119154, 128), (119155, 119162, 4), (119163, 119170, 128), (119171, 119172, 144), (119173, 119179, 128), (119180, 119209, 144), (119210, 119213, 128), (119214, 119226, 144), (119227, 119232, 8), (119233, 119261, 144), (119262, 119272, 400), (119273, 119274, 144), (119296, 119361, 80), (119362, 119364, 192), (119365, 119365, 80), (119520, 119539, 16), (119552, 119638, 144), (119648, 119672, 16), (119808, 119892, 8), (119894, 119964, 8), (119966, 119967, 8), (119970, 119970, 8), (119973, 119974, 8), (119977, 119980, 8), (119982, 119993, 8), (119995, 119995, 8), (119997, 120003, 8), (120005, 120069, 8), (120071, 120074, 8), (120077, 120084, 8), (120086, 120092, 8), (120094, 120121, 8), (120123, 120126, 8), (120128, 120132, 8), (120134, 120134, 8), (120138, 120144, 8), (120146, 120485, 8), (120488, 120779, 8), (120782, 120831, 8), (120832, 121343, 48), (121344, 121398, 32), (121399, 121402, 48), (121403, 121452, 32), (121453, 121460, 48), (121461, 121461, 32), (121462, 121475, 48), (121476, 121476, 32), (121477, 121483, 48), (121499, 121503, 32), (121505, 121519, 32), (122624, 122654, 2048), (122880, 122886, 32), (122888, 122904, 32), (122907, 122913, 32), (122915, 122916, 32), (122918, 122922, 32), (123136, 123180, 512), (123184, 123197, 512), (123200, 123209, 512), (123214, 123214, 512), (123215, 123215, 528), (123536, 123566, 32), (123584, 123641, 512), (123647, 123647, 528), (124896, 124902, 2048), (124904, 124907, 2048), (124909, 124910, 2048), (124912, 124926, 2048), (124928, 125124, 32), (125127, 125135, 48), (125136, 125142, 32), (125184, 125259, 512), (125264, 125273, 512), (125278, 125279, 528), (126065, 126132, 16), (126209, 126269, 16), (126464, 126467, 8), (126469, 126495, 8), (126497, 126498, 8), (126500, 126500, 8), (126503, 126503, 8), (126505, 126514, 8), (126516, 126519, 8), (126521, 126521, 8), (126523, 126523, 8), (126530, 126530, 8), (126535, 126535, 8), (126537, 126537, 8), (126539, 126539, 8), (126541, 126543, 8), (126545, 126546, 8), (126548, 126548, 8), (126551, 126551, 8), (126553, 126553, 8), (126555, 126555, 8), (126557, 126557, 8), (126559, 126559, 8), (126561, 126562, 8), (126564, 126564, 8), (126567, 126570, 8), (126572, 126578, 8), (126580, 126583, 8), (126585, 126588, 8), (126590, 126590, 8), (126592, 126601, 8), (126603, 126619, 8), (126625, 126627, 8), (126629, 126633, 8), (126635, 126651, 8), (126704, 126705, 16), (126976, 127019, 16), (127024, 127123, 16), (127136, 127150, 16), (127153, 127167, 16), (127169, 127183, 16), (127185, 127221, 16), (127232, 127242, 8), (127243, 127247, 16), (127248, 127278, 8), (127279, 127279, 16), (127280, 127311, 8), (127312, 127337, 16), (127338, 127340, 8), (127341, 127375, 16), (127376, 127376, 8), (127377, 127405, 16), (127462, 127487, 16), (127488, 127490, 8), (127504, 127547, 8), (127552, 127560, 8), (127568, 127569, 8), (127584, 127589, 16), (127744, 128334, 16), (128335, 128335, 272), (128336, 128727, 16), (128733, 128748, 16), (128752, 128764, 16), (128768, 128883, 16), (128896, 128984, 16), (128992, 129003, 16), (129008, 129008, 16), (129024, 129035, 16), (129040, 129095, 16), (129104, 129113, 16), (129120, 129159, 16), (129168, 129197, 16), (129200, 129201, 16), (129280, 129619, 16), (129632, 129645, 16), (129648, 129652, 16), (129656, 129660, 16), (129664, 129670, 16), (129680, 129708, 16), (129712, 129722, 16), (129728, 129733, 16), (129744, 129753, 16), (129760, 129767, 16), (129776, 129782, 16), (129792, 129938, 16), (129940, 129994, 16), (130032, 130041, 8), (131072, 173791, 2048), (173824, 177976, 2048), (177984, 178205, 2048), (178208, 183969, 2048), (183984, 191456, 2048), (194560, 195101, 8), (196608, 201546, 2048), (917505, 917505, 2), (917536, 917631, 4), (917760, 917999, 4)];
@@ -0,0 +1,3 @@
+// Generated by `cargo xtask` from data/intentional-derived.txt
+
+pub static INTENTIONAL_PAIRS: [(u32, u32); 147] = [(65, 913), (65, 1040), (66, 914), (66, 1042), (67, 1057), (69, 917), (69, 1045), (70, 988), (71, 1292), (72, 919), (72, 1053), (73, 921), (73, 1030), (73, 1216), (74, 895), (74, 1032), (75, 922), (75, 1050), (77, 924), (77, 1018), (77, 1052), (78, 925), (79, 927), (79, 1054), (80, 929), (80, 1056), (83, 1029), (84, 932), (84, 1058), (86, 1140), (87, 1308), (88, 935), (88, 1061), (89, 933), (89, 1059), (89, 1198), (90, 918), (97, 945), (97, 1072), (99, 1089), (101, 1077), (105, 953), (105, 1110), (105, 1231), (106, 1011), (106, 1112), (111, 959), (111, 1086), (112, 961), (112, 1088), (115, 1109), (118, 1141), (119, 1309), (120, 1093), (121, 1091), (121, 1199), (222, 1015), (254, 1016), (305, 953), (305, 1110), (305, 1231), (388, 1068), (389, 1100), (390, 1021), (399, 1240), (400, 1296), (406, 921), (406, 1030), (406, 1216), (423, 42564), (424, 42565), (425, 931), (439, 1047), (439, 1248), (502, 1290), (540, 1047), (540, 1248), (541, 1249), (581, 923), (581, 1051), (596, 891), (601, 1241), (603, 1297), (604, 1079), (617, 953), (617, 1110), (617, 1231), (658, 1249), (880, 11381), (886, 1048), (887, 1080), (893, 42815), (895, 1032), (895, 42930), (913, 1040), (914, 1042), (914, 42932), (915, 1043), (917, 1045), (919, 1053), (921, 1030), (921, 1216), (922, 1050), (923, 1051), (924, 1052), (927, 1054), (928, 1055), (929, 1056), (932, 1058), (933, 1059), (933, 1198), (934, 1060), (935, 1061), (935, 42931), (936, 1136), (945, 1072), (946, 42933), (953, 1110), (953, 1231), (954, 1082), (959, 1086), (960, 1087), (961, 1088), (964, 1090), (966, 1092), (967, 43859), (968, 1137), (988, 42904), (1011, 1112), (1018, 1052), (1023, 42814), (1028, 42898), (1032, 42930), (1042, 42932), (1047, 42858), (1047, 42923), (1061, 42931), (1108, 42899), (1248, 42858), (1248, 42923), (1249, 42859), (7448, 7465), (7462, 43847), (7462, 43848), (42564, 42842), (42572, 42934), (42573, 42935)];
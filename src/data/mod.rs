@@ -0,0 +1,342 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The tables in the submodules are generated by `cargo xtask` from the files in
+// the `data` directory at the root of the repository; do not edit them by hand.
+
+// The perfect hash tables are only read with the `phf` feature, and the
+// character ranges only when there is no custom table.
+#[cfg_attr(any(not(feature = "phf"), unicode_skeleton_custom_confusables), allow(dead_code))]
+mod confusables;
+// Generated by build.rs from the file named by UNICODE_SKELETON_CONFUSABLES.
+#[cfg(unicode_skeleton_custom_confusables)]
+#[cfg_attr(not(feature = "phf"), allow(dead_code))]
+mod custom_confusables {
+    include!(concat!(env!("OUT_DIR"), "/confusables.rs"));
+}
+#[cfg(feature = "data-10")]
+#[cfg_attr(not(feature = "phf"), allow(dead_code))]
+mod confusables_10;
+mod identifiers;
+mod intentional;
+mod properties;
+mod script_ext;
+mod scripts;
+
+pub use self::identifiers::{ALLOWED_RANGES, IDENTIFIER_TYPE_RANGES};
+pub use self::intentional::INTENTIONAL_PAIRS;
+pub use self::properties::{DECIMAL_DIGIT_RANGES, DEFAULT_IGNORABLE_RANGES};
+pub use self::script_ext::SCRIPT_EXTENSIONS;
+pub use self::scripts::{Script, SCRIPTS, SCRIPT_RANGES};
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::char;
+
+use scripts::ScriptSet;
+
+/// The number of low bits of each entry of a `PrototypeTable` that hold the
+/// index of its prototype. The code point is stored above them, so sources
+/// must be below U+40000.
+pub const PROTOTYPE_INDEX_BITS: u32 = 14;
+
+/// A confusables table, as generated by `cargo xtask`.
+///
+/// `entries` holds each source character packed with the index of its
+/// prototype, in Eytzinger order: the sorted entries laid out as a complete
+/// binary search tree in breadth-first order, so the first steps of every
+/// search share a few cache lines. Each distinct prototype is stored once: prototype `i`
+/// is `prototype_chars[prototype_starts[i]..prototype_starts[i+1]]`. This is
+/// around 40% smaller than storing each entry's prototype separately.
+///
+/// `salts` and `hashed_entries` are a minimal perfect hash over the sources,
+/// which `prototype_of` probes instead of binary searching `entries` when the
+/// `phf` feature is enabled and they are not empty.
+///
+/// If `single_script` is set, only the entries whose source and prototype
+/// share a script are used, as in the legacy SA table.
+#[derive(Clone, Copy)]
+pub struct PrototypeTable<'a> {
+    pub entries: &'a [u32],
+    pub prototype_starts: &'a [u16],
+    pub prototype_chars: &'a [char],
+    #[cfg_attr(not(feature = "phf"), allow(dead_code))]
+    pub salts: &'a [u16],
+    #[cfg_attr(not(feature = "phf"), allow(dead_code))]
+    pub hashed_entries: &'a [u32],
+    pub single_script: bool,
+}
+
+// The `PrototypeTable` of a generated confusables module.
+macro_rules! prototype_table {
+    ($module:ident) => {
+        PrototypeTable {
+            entries: &$module::ENTRIES,
+            prototype_starts: &$module::PROTOTYPE_STARTS,
+            prototype_chars: &$module::PROTOTYPE_CHARS,
+            salts: &$module::SALTS,
+            hashed_entries: &$module::HASHED_ENTRIES,
+            single_script: false,
+        }
+    }
+}
+
+/// The characters that `CONFUSABLES` replaces, directly or through a
+/// character of their canonical decomposition, as sorted, disjoint, inclusive
+/// ranges. This lets `is_confusable_char` run in a const context, where
+/// decomposition cannot.
+#[cfg(not(unicode_skeleton_custom_confusables))]
+pub static CONFUSABLE_CHAR_RANGES: &[(u32, u32)] = &confusables::CONFUSABLE_CHAR_RANGES;
+#[cfg(unicode_skeleton_custom_confusables)]
+pub static CONFUSABLE_CHAR_RANGES: &[(u32, u32)] = &custom_confusables::CONFUSABLE_CHAR_RANGES;
+
+/// The version of `CONFUSABLES`, as (major, minor, update).
+#[cfg(not(unicode_skeleton_custom_confusables))]
+pub const CONFUSABLES_VERSION: (u8, u8, u8) = CONFUSABLES_16_VERSION;
+#[cfg(unicode_skeleton_custom_confusables)]
+pub const CONFUSABLES_VERSION: (u8, u8, u8) = CUSTOM_CONFUSABLES_VERSION;
+
+/// The table that skeletons are computed with by default: `CONFUSABLES_16`,
+/// or `CUSTOM_CONFUSABLES` when UNICODE_SKELETON_CONFUSABLES is set.
+#[cfg(not(unicode_skeleton_custom_confusables))]
+pub static CONFUSABLES: PrototypeTable<'static> = prototype_table!(confusables);
+#[cfg(unicode_skeleton_custom_confusables)]
+pub static CONFUSABLES: PrototypeTable<'static> = prototype_table!(custom_confusables);
+
+/// The version of `CONFUSABLES_16`, which is always 16.0.0.
+pub const CONFUSABLES_16_VERSION: (u8, u8, u8) = confusables::VERSION;
+
+/// The bundled confusables table of Unicode 16.0.0, which is always compiled
+/// in.
+pub static CONFUSABLES_16: PrototypeTable<'static> = prototype_table!(confusables);
+
+/// The version given in the file named by UNICODE_SKELETON_CONFUSABLES.
+#[cfg(unicode_skeleton_custom_confusables)]
+pub const CUSTOM_CONFUSABLES_VERSION: (u8, u8, u8) = custom_confusables::VERSION;
+
+/// The table generated by build.rs from the file named by
+/// UNICODE_SKELETON_CONFUSABLES.
+#[cfg(unicode_skeleton_custom_confusables)]
+pub static CUSTOM_CONFUSABLES: PrototypeTable<'static> = prototype_table!(custom_confusables);
+
+/// The version of `CONFUSABLES_10`, which is always 10.0.0.
+#[cfg(feature = "data-10")]
+pub const CONFUSABLES_10_VERSION: (u8, u8, u8) = confusables_10::VERSION;
+
+/// The confusables table of Unicode 10.0.0.
+#[cfg(feature = "data-10")]
+pub static CONFUSABLES_10: PrototypeTable<'static> = prototype_table!(confusables_10);
+
+impl<'a> PrototypeTable<'a> {
+    /// Look up the prototype of `c`, if it is a source of the table.
+    pub fn prototype_of(self, c: char) -> Option<&'a [char]> {
+        self.lookup(c).filter(|prototype| !self.single_script || is_single_script_mapping(c, prototype))
+    }
+
+    /// Look up the prototype of `c` as `prototype_of` does, but ignoring
+    /// `single_script`, in a const context.
+    pub const fn lookup(self, c: char) -> Option<&'a [char]> {
+        match self.find_entry(c) {
+            Some(entry) => Some(self.prototype(entry)),
+            None => None,
+        }
+    }
+
+    #[cfg(feature = "phf")]
+    const fn find_entry(self, c: char) -> Option<u32> {
+        if self.salts.is_empty() {
+            return self.search_entry(c);
+        }
+        let key = c as u32;
+        let n = self.salts.len();
+        let salt = self.salts[phf_hash(key, 0, n)] as u32;
+        let entry = self.hashed_entries[phf_hash(key, salt, n)];
+        if entry >> PROTOTYPE_INDEX_BITS == key { Some(entry) } else { None }
+    }
+
+    #[cfg(not(feature = "phf"))]
+    const fn find_entry(self, c: char) -> Option<u32> {
+        self.search_entry(c)
+    }
+
+    // Search the tree without branching on comparisons: descend to a leaf,
+    // going right past every key less than `c`, then back up to the last
+    // node where the search went left, which holds the first key not less
+    // than `c`.
+    const fn search_entry(self, c: char) -> Option<u32> {
+        let key = c as u32;
+        let mut node = 1;
+        while node <= self.entries.len() {
+            node = 2 * node + (self.entries[node - 1] >> PROTOTYPE_INDEX_BITS < key) as usize;
+        }
+        node >>= node.trailing_ones() + 1;
+        if node == 0 {
+            return None;
+        }
+        let entry = self.entries[node - 1];
+        if entry >> PROTOTYPE_INDEX_BITS == key { Some(entry) } else { None }
+    }
+
+    /// Iterate over the entries of the table in order of code point, as
+    /// pairs of a source and its prototype.
+    pub fn entries(self) -> Entries<'a> {
+        let mut node = 1;
+        while 2 * node <= self.entries.len() {
+            node *= 2;
+        }
+        Entries { table: self, node, remaining: self.entries.len() }
+    }
+
+    const fn prototype(self, entry: u32) -> &'a [char] {
+        let prototype = (entry & ((1 << PROTOTYPE_INDEX_BITS) - 1)) as usize;
+        let start = self.prototype_starts[prototype] as usize;
+        let end = self.prototype_starts[prototype + 1] as usize;
+        // Range indexing is not const.
+        self.prototype_chars.split_at(end).0.split_at(start).1
+    }
+}
+
+/// An iterator over the entries of a `PrototypeTable` in order of code
+/// point, walking the tree in order.
+#[derive(Clone, Copy)]
+pub struct Entries<'a> {
+    table: PrototypeTable<'a>,
+    // The 1-based tree node of the next entry.
+    node: usize,
+    remaining: usize,
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = (char, &'a [char]);
+
+    fn next(&mut self) -> Option<(char, &'a [char])> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let entry = self.table.entries[self.node - 1];
+        let n = self.table.entries.len();
+        // The successor is the leftmost node of the right subtree, if there
+        // is one, or else the nearest ancestor of which this node is in the
+        // left subtree.
+        if 2 * self.node < n {
+            self.node = 2 * self.node + 1;
+            while 2 * self.node <= n {
+                self.node *= 2;
+            }
+        } else {
+            self.node >>= self.node.trailing_ones() + 1;
+        }
+        let source = char::from_u32(entry >> PROTOTYPE_INDEX_BITS).expect("Invalid codepoint in table");
+        Some((source, self.table.prototype(entry)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Entries<'a> {}
+
+/// Lay out `sorted` in Eytzinger order, as `PrototypeTable` expects. The
+/// generator in build/confusables.rs does the same.
+pub fn eytzinger(sorted: &[u32]) -> Vec<u32> {
+    fn fill(sorted: &[u32], out: &mut [u32], next: &mut usize, node: usize) {
+        if node <= out.len() {
+            fill(sorted, out, next, 2 * node);
+            out[node - 1] = sorted[*next];
+            *next += 1;
+            fill(sorted, out, next, 2 * node + 1);
+        }
+    }
+    let mut out = vec![0; sorted.len()];
+    fill(sorted, &mut out, &mut 0, 1);
+    out
+}
+
+// The hash of the perfect hash tables: a multiplicative hash of the key and
+// salt, scaled to `n` slots. The generator in build/confusables.rs must use
+// the same function.
+#[cfg(feature = "phf")]
+const fn phf_hash(key: u32, salt: u32, n: usize) -> usize {
+    let y = key.wrapping_add(salt).wrapping_mul(0x9e37_79b9) ^ key.wrapping_mul(0x3141_5926);
+    ((y as u64 * n as u64) >> 32) as usize
+}
+
+fn is_single_script_mapping(c: char, prototype: &[char]) -> bool {
+    let mut prototype_scripts = ScriptSet::all();
+    for &p in prototype {
+        prototype_scripts.intersect_with(ScriptSet::of_char(p));
+    }
+    ScriptSet::of_char(c).intersects(prototype_scripts)
+}
+
+/// Find the range containing a character in a sorted table of disjoint,
+/// inclusive ranges.
+pub const fn range_containing(ranges: &[(u32, u32)], c: char) -> Option<(u32, u32)> {
+    let c = c as u32;
+    // The number of ranges starting at or before `c`, by binary search.
+    let (mut low, mut high) = (0, ranges.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if ranges[mid].0 <= c {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low > 0 && c <= ranges[low - 1].1 { Some(ranges[low - 1]) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{eytzinger, PrototypeTable, CONFUSABLES, PROTOTYPE_INDEX_BITS};
+    use std::char;
+
+    #[test]
+    fn lookup_agrees_with_search() {
+        let mut previous = None;
+        for (c, prototype) in CONFUSABLES.entries() {
+            assert!(previous < Some(c));
+            assert_eq!(CONFUSABLES.prototype_of(c), Some(prototype));
+            previous = Some(c);
+        }
+        assert_eq!(CONFUSABLES.entries().count(), CONFUSABLES.entries.len());
+        let mut entries = CONFUSABLES.entries();
+        for c in (0..0x40000).filter_map(char::from_u32) {
+            let expected = if entries.clone().next().map(|(source, _)| source) == Some(c) {
+                entries.next().map(|(_, prototype)| prototype)
+            } else {
+                None
+            };
+            assert_eq!(CONFUSABLES.search_entry(c).map(|entry| CONFUSABLES.prototype(entry)), expected);
+            assert_eq!(CONFUSABLES.find_entry(c), CONFUSABLES.search_entry(c));
+        }
+    }
+
+    #[test]
+    fn eytzinger_layouts() {
+        for n in 0..40u32 {
+            let sorted: Vec<u32> = (0..n).map(|i| (10 + 3 * i) << PROTOTYPE_INDEX_BITS).collect();
+            let layout = eytzinger(&sorted);
+            let table = PrototypeTable { entries: &layout, prototype_starts: &[0, 0], ..CONFUSABLES };
+            let sources: Vec<u32> = table.entries().map(|(c, _)| c as u32).collect();
+            let expected: Vec<u32> = (0..n).map(|i| 10 + 3 * i).collect();
+            assert_eq!(sources, expected);
+            for key in 0..(20 + 3 * n) {
+                let found = table.search_entry(char::from_u32(key).unwrap()).is_some();
+                assert_eq!(found, key >= 10 && (key - 10) % 3 == 0 && key < 10 + 3 * n, "{} of {}", key, n);
+            }
+        }
+    }
+}
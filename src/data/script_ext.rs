@@ -0,0 +1,6 @@
+// Generated by `cargo xtask` from Unicode 16.0.0 data
+
+use super::Script;
+use super::Script::*;
+
+pub static SCRIPT_EXTENSIONS: [(u32, u32, &[Script]); 174] = [(183, 183, &[Avestan, Carian, Coptic, Duployan, Elbasan, Georgian, Glagolitic, Gothic, Greek, GunjalaGondi, Han, Latin, Lydian, Mahajani, OldPermic, Shavian]), (700, 700, &[Bengali, Cyrillic, Devanagari, Latin, Lisu, Thai, Toto]), (711, 711, &[Bopomofo, Latin]), (713, 715, &[Bopomofo, Latin]), (717, 717, &[Latin, Lisu]), (727, 727, &[Latin, Thai]), (729, 729, &[Bopomofo, Latin]), (768, 768, &[Cherokee, Coptic, Cyrillic, Greek, Latin, OldPermic, Sunuwar, TaiLe]), (769, 769, &[Cherokee, Cyrillic, Greek, Latin, Osage, Sunuwar, TaiLe, Todhri]), (770, 770, &[Cherokee, Cyrillic, Latin, Tifinagh]), (771, 771, &[Glagolitic, Latin, Sunuwar, Syriac, Thai]), (772, 772, &[CaucasianAlbanian, Cherokee, Coptic, Cyrillic, Gothic, Greek, Latin, Osage, Syriac, Tifinagh, Todhri]), (773, 773, &[Coptic, Elbasan, Glagolitic, Gothic, Katakana, Latin]), (774, 774, &[Cyrillic, Greek, Latin, OldPermic]), (775, 775, &[Coptic, Duployan, Hebrew, Latin, OldPermic, Syriac, TaiLe, Tifinagh, Todhri]), (776, 776, &[Armenian, Cyrillic, Duployan, Gothic, Greek, Hebrew, Latin, OldPermic, Syriac, TaiLe]), (777, 777, &[Latin, Tifinagh]), (778, 778, &[Duployan, Latin, Syriac]), (779, 779, &[Cherokee, Cyrillic, Latin, Osage]), (780, 780, &[Cherokee, Latin, TaiLe]), (781, 781, &[Latin, Sunuwar]), (782, 782, &[Ethiopic, Latin]), (784, 784, &[Latin, Sunuwar]), (785, 785, &[Cyrillic, Latin, Todhri]), (787, 787, &[Greek, Latin, OldPermic, Todhri]), (800, 800, &[Latin, Syriac]), (803, 803, &[Cherokee, Duployan, Katakana, Latin, Syriac]), (804, 804, &[Cherokee, Duployan, Latin, Syriac]), (805, 805, &[Latin, Syriac]), (813, 813, &[Latin, Sunuwar, Syriac]), (814, 814, &[Latin, Syriac]), (816, 816, &[Cherokee, Latin, Syriac]), (817, 817, &[CaucasianAlbanian, Cherokee, Gothic, Latin, Sunuwar, Thai]), (834, 834, &[Greek]), (837, 837, &[Greek]), (856, 856, &[Latin, Osage]), (862, 862, &[CaucasianAlbanian, Latin, Todhri]), (867, 879, &[Latin]), (884, 885, &[Coptic, Greek]), (1155, 1155, &[Cyrillic, OldPermic]), (1156, 1156, &[Cyrillic, Glagolitic]), (1157, 1158, &[Cyrillic, Latin]), (1159, 1159, &[Cyrillic, Glagolitic]), (1417, 1417, &[Armenian, Georgian, Glagolitic]), (1548, 1548, &[Arabic, Garay, HanifiRohingya, Nko, Syriac, Thaana, Yezidi]), (1563, 1563, &[Arabic, Garay, HanifiRohingya, Nko, Syriac, Thaana, Yezidi]), (1564, 1564, &[Arabic, Syriac, Thaana]), (1567, 1567, &[Adlam, Arabic, Garay, HanifiRohingya, Nko, Syriac, Thaana, Yezidi]), (1600, 1600, &[Adlam, Arabic, HanifiRohingya, Mandaic, Manichaean, OldUyghur, PsalterPahlavi, Sogdian, Syriac]), (1611, 1621, &[Arabic, Syriac]), (1632, 1641, &[Arabic, Thaana, Yezidi]), (1648, 1648, &[Arabic, Syriac]), (1748, 1748, &[Arabic, HanifiRohingya]), (2385, 2385, &[Bengali, Devanagari, Grantha, Gujarati, Gurmukhi, Kannada, Latin, Malayalam, Oriya, Sharada, Tamil, Telugu, Tirhuta]), (2386, 2386, &[Bengali, Devanagari, Grantha, Gujarati, Gurmukhi, Kannada, Latin, Malayalam, Oriya, Tamil, Telugu, Tirhuta]), (2404, 2404, &[Bengali, Devanagari, Dogra, Grantha, Gujarati, GunjalaGondi, Gurmukhi, Kannada, Khudawadi, Mahajani, Malayalam, MasaramGondi, Nandinagari, OlOnal, Oriya, Sinhala, SylotiNagri, Takri, Tamil, Telugu, Tirhuta]), (2405, 2405, &[Bengali, Devanagari, Dogra, Grantha, Gujarati, GunjalaGondi, Gurmukhi, GurungKhema, Kannada, Khudawadi, Limbu, Mahajani, Malayalam, MasaramGondi, Nandinagari, OlOnal, Oriya, Sinhala, SylotiNagri, Takri, Tamil, Telugu, Tirhuta]), (2406, 2415, &[Devanagari, Dogra, Kaithi, Mahajani]), (2534, 2543, &[Bengali, Chakma, SylotiNagri]), (2662, 2671, &[Gurmukhi, Multani]), (2790, 2799, &[Gujarati, Khojki]), (3046, 3059, &[Grantha, Tamil]), (3302, 3311, &[Kannada, Nandinagari, TuluTigalari]), (4160, 4169, &[Chakma, Myanmar, TaiLe]), (4347, 4347, &[Georgian, Glagolitic, Latin]), (5867, 5869, &[Runic]), (5941, 5942, &[Buhid, Hanunoo, Tagalog, Tagbanwa]), (6146, 6147, &[Mongolian, PhagsPa]), (6149, 6149, &[Mongolian, PhagsPa]), (7376, 7376, &[Bengali, Devanagari, Grantha, Kannada]), (7377, 7377, &[Devanagari]), (7378, 7378, &[Bengali, Devanagari, Grantha, Kannada]), (7379, 7379, &[Devanagari, Grantha, Kannada]), (7380, 7380, &[Devanagari]), (7381, 7382, &[Bengali, Devanagari]), (7383, 7383, &[Devanagari, Sharada]), (7384, 7384, &[Bengali, Devanagari]), (7385, 7385, &[Devanagari, Sharada]), (7386, 7386, &[Devanagari, Kannada, Malayalam, Oriya, Tamil, Telugu]), (7387, 7387, &[Devanagari]), (7388, 7389, &[Devanagari, Sharada]), (7390, 7391, &[Devanagari]), (7392, 7392, &[Devanagari, Sharada]), (7393, 7393, &[Bengali, Devanagari]), (7394, 7400, &[Devanagari]), (7401, 7401, &[Devanagari, Nandinagari]), (7402, 7402, &[Bengali, Devanagari]), (7403, 7404, &[Devanagari]), (7405, 7405, &[Bengali, Devanagari]), (7406, 7409, &[Devanagari]), (7410, 7410, &[Bengali, Devanagari, Grantha, Kannada, Malayalam, Nandinagari, Oriya, Sinhala, Telugu, Tirhuta, TuluTigalari]), (7411, 7411, &[Devanagari, Grantha]), (7412, 7412, &[Devanagari, Grantha, Kannada, TuluTigalari]), (7413, 7414, &[Bengali, Devanagari]), (7415, 7415, &[Bengali]), (7416, 7417, &[Devanagari, Grantha]), (7418, 7418, &[Nandinagari]), (7616, 7617, &[Greek]), (7672, 7672, &[Cyrillic, Latin, Syriac]), (7674, 7674, &[Syriac]), (8239, 8239, &[Latin, Mongolian, PhagsPa]), (8271, 8271, &[Adlam, Arabic]), (8282, 8282, &[Carian, Georgian, Glagolitic, Lycian, OldHungarian, OldTurkic]), (8285, 8285, &[Carian, Greek, MeroiticHieroglyphs, OldHungarian]), (8432, 8432, &[Devanagari, Grantha, Latin]), (11799, 11799, &[Coptic, Latin]), (11824, 11824, &[Avestan, OldTurkic]), (11825, 11825, &[Avestan, Carian, Georgian, Kaithi, Lydian, OldHungarian, Samaritan]), (11836, 11836, &[Duployan]), (11841, 11841, &[Adlam, Arabic, OldHungarian]), (11843, 11843, &[Cyrillic, Glagolitic]), (12272, 12287, &[Han, Tangut]), (12289, 12289, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Mongolian, Yi]), (12290, 12290, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Mongolian, PhagsPa, Yi]), (12291, 12291, &[Bopomofo, Han, Hangul, Hiragana, Katakana]), (12294, 12294, &[Han]), (12296, 12297, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Mongolian, Tibetan, Yi]), (12298, 12299, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Lisu, Mongolian, Tibetan, Yi]), (12300, 12305, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Yi]), (12307, 12307, &[Bopomofo, Han, Hangul, Hiragana, Katakana]), (12308, 12315, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Yi]), (12316, 12319, &[Bopomofo, Han, Hangul, Hiragana, Katakana]), (12330, 12333, &[Bopomofo, Han]), (12336, 12336, &[Bopomofo, Han, Hangul, Hiragana, Katakana]), (12337, 12341, &[Hiragana, Katakana]), (12343, 12343, &[Bopomofo, Han, Hangul, Hiragana, Katakana]), (12348, 12349, &[Han, Hiragana, Katakana]), (12350, 12351, &[Han]), (12441, 12444, &[Hiragana, Katakana]), (12448, 12448, &[Hiragana, Katakana]), (12539, 12539, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Yi]), (12540, 12540, &[Hiragana, Katakana]), (12688, 12703, &[Han]), (12736, 12773, &[Han]), (12783, 12783, &[Han, Tangut]), (12832, 12871, &[Han]), (12928, 12976, &[Han]), (12992, 13003, &[Han]), (13055, 13055, &[Han]), (13144, 13168, &[Han]), (13179, 13183, &[Han]), (13280, 13310, &[Han]), (42607, 42607, &[Cyrillic, Glagolitic]), (42752, 42759, &[Han, Latin]), (43056, 43058, &[Devanagari, Dogra, Gujarati, Gurmukhi, Kaithi, Kannada, Khojki, Khudawadi, Mahajani, Malayalam, Modi, Nandinagari, Sharada, Takri, Tirhuta, TuluTigalari]), (43059, 43061, &[Devanagari, Dogra, Gujarati, Gurmukhi, Kaithi, Kannada, Khojki, Khudawadi, Mahajani, Modi, Nandinagari, Sharada, Takri, Tirhuta, TuluTigalari]), (43062, 43063, &[Devanagari, Dogra, Gujarati, Gurmukhi, Kaithi, Khojki, Khudawadi, Mahajani, Modi, Takri, Tirhuta]), (43064, 43064, &[Devanagari, Dogra, Gujarati, Gurmukhi, Kaithi, Khojki, Khudawadi, Mahajani, Modi, Sharada, Takri, Tirhuta]), (43065, 43065, &[Devanagari, Dogra, Gujarati, Gurmukhi, Kaithi, Khojki, Khudawadi, Mahajani, Modi, Takri, Tirhuta]), (43249, 43249, &[Bengali, Devanagari, TuluTigalari]), (43251, 43251, &[Devanagari, Tamil]), (43310, 43310, &[KayahLi, Latin, Myanmar]), (43471, 43471, &[Buginese, Javanese]), (64830, 64831, &[Arabic, Nko]), (65010, 65010, &[Arabic, Thaana]), (65021, 65021, &[Arabic, Thaana]), (65093, 65094, &[Bopomofo, Han, Hangul, Hiragana, Katakana]), (65377, 65381, &[Bopomofo, Han, Hangul, Hiragana, Katakana, Yi]), (65392, 65392, &[Hiragana, Katakana]), (65438, 65439, &[Hiragana, Katakana]), (65792, 65793, &[Cypriot, CyproMinoan, LinearB]), (65794, 65794, &[Cypriot, LinearB]), (65799, 65843, &[Cypriot, LinearA, LinearB]), (65847, 65855, &[Cypriot, LinearB]), (66272, 66299, &[Arabic, Coptic]), (68338, 68338, &[Manichaean, OldUyghur]), (70401, 70401, &[Grantha, Tamil]), (70403, 70403, &[Grantha, Tamil]), (70459, 70460, &[Grantha, Tamil]), (73680, 73681, &[Grantha, Tamil]), (73683, 73683, &[Grantha, Tamil]), (113824, 113827, &[Duployan]), (119648, 119665, &[Han]), (127568, 127569, &[Han])];
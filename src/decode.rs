@@ -0,0 +1,162 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Skeletons of text that is not already a `str`.
+
+use std::char::{decode_utf16, DecodeUtf16, DecodeUtf16Error, REPLACEMENT_CHARACTER};
+use std::iter::Cloned;
+use std::slice;
+use std::str::{Chars, Utf8Chunks};
+
+use {skeleton_chars_of, SkeletonChars};
+
+/// Retrieve the characters of the skeleton of UTF-8 encoded bytes, decoding
+/// them as they are read.
+///
+/// Invalid sequences are replaced with U+FFFD REPLACEMENT CHARACTER, in the
+/// same way as `String::from_utf8_lossy`.
+///
+/// # Examples
+/// ```Rust
+/// skeleton_chars_lossy(b"\xe2\x84\x9dust\xff").collect::<String>() // "Rust\u{fffd}"
+/// ```
+pub fn skeleton_chars_lossy<'a>(bytes: &'a [u8]) -> SkeletonChars<'static, Utf8LossyChars<'a>> {
+    skeleton_chars_of(Utf8LossyChars::new(bytes))
+}
+
+/// An iterator over the `char`s of UTF-8 encoded bytes, with invalid sequences
+/// replaced by U+FFFD REPLACEMENT CHARACTER.
+#[derive(Clone, Debug)]
+pub struct Utf8LossyChars<'a> {
+    chunks: Utf8Chunks<'a>,
+    valid: Chars<'a>,
+    invalid: bool,
+}
+
+impl<'a> Utf8LossyChars<'a> {
+    /// Decode `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Utf8LossyChars<'a> {
+        Utf8LossyChars {
+            chunks: bytes.utf8_chunks(),
+            valid: "".chars(),
+            invalid: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Utf8LossyChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.valid.next() {
+                return Some(c);
+            }
+            if self.invalid {
+                self.invalid = false;
+                return Some(REPLACEMENT_CHARACTER);
+            }
+            let chunk = self.chunks.next()?;
+            self.valid = chunk.valid().chars();
+            self.invalid = !chunk.invalid().is_empty();
+        }
+    }
+}
+
+/// How to handle unpaired surrogates when decoding UTF-16.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnpairedSurrogates {
+    /// Replace each unpaired surrogate with U+FFFD REPLACEMENT CHARACTER.
+    Replace,
+    /// Leave unpaired surrogates out of the decoded text.
+    Skip,
+}
+
+/// Retrieve the characters of the skeleton of UTF-16 encoded text, decoding
+/// it as it is read.
+///
+/// # Examples
+/// ```Rust
+/// let units: Vec<u16> = "ℝ𝓊𝓈𝓉".encode_utf16().collect();
+/// skeleton_chars_utf16(&units, UnpairedSurrogates::Replace).collect::<String>() // "Rust"
+/// ```
+pub fn skeleton_chars_utf16<'a>(units: &'a [u16], unpaired: UnpairedSurrogates) -> SkeletonChars<'static, Utf16Chars<'a>> {
+    skeleton_chars_of(Utf16Chars::new(units, unpaired))
+}
+
+/// Retrieve the characters of the skeleton of UTF-16 encoded text, or the
+/// first decoding error if it contains an unpaired surrogate.
+pub fn skeleton_chars_utf16_strict<'a>(units: &'a [u16]) -> Result<SkeletonChars<'static, Utf16Chars<'a>>, DecodeUtf16Error> {
+    if let Some(Err(e)) = decode_utf16(units.iter().cloned()).find(Result::is_err) {
+        return Err(e);
+    }
+    Ok(skeleton_chars_utf16(units, UnpairedSurrogates::Replace))
+}
+
+/// An iterator over the `char`s of UTF-16 encoded text.
+#[derive(Clone, Debug)]
+pub struct Utf16Chars<'a> {
+    units: DecodeUtf16<Cloned<slice::Iter<'a, u16>>>,
+    unpaired: UnpairedSurrogates,
+}
+
+impl<'a> Utf16Chars<'a> {
+    /// Decode `units`, handling unpaired surrogates as specified.
+    pub fn new(units: &'a [u16], unpaired: UnpairedSurrogates) -> Utf16Chars<'a> {
+        Utf16Chars {
+            units: decode_utf16(units.iter().cloned()),
+            unpaired,
+        }
+    }
+}
+
+impl<'a> Iterator for Utf16Chars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            match self.units.next()? {
+                Ok(c) => return Some(c),
+                Err(_) => match self.unpaired {
+                    UnpairedSurrogates::Replace => return Some(REPLACEMENT_CHARACTER),
+                    UnpairedSurrogates::Skip => {}
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{skeleton_chars_lossy, Utf8LossyChars};
+    use super::{skeleton_chars_utf16, skeleton_chars_utf16_strict, UnpairedSurrogates};
+
+    #[test]
+    fn lossy_utf8() {
+        let bytes = b"\xe2\x84\x9d\xf0\x9d\x93\x8as\xff\xf0\x9d\x93t\xe2";
+        assert_eq!(Utf8LossyChars::new(bytes).collect::<String>(), String::from_utf8_lossy(bytes));
+        assert_eq!(skeleton_chars_lossy(bytes).collect::<String>(), "Rus\u{fffd}\u{fffd}t\u{fffd}");
+        assert_eq!(skeleton_chars_lossy(b"").count(), 0);
+    }
+
+    #[test]
+    fn utf16() {
+        let mut units: Vec<u16> = "ℝ𝓊𝓈𝓉".encode_utf16().collect();
+        assert_eq!(skeleton_chars_utf16(&units, UnpairedSurrogates::Skip).collect::<String>(), "Rust");
+        assert!(skeleton_chars_utf16_strict(&units).is_ok());
+
+        units.insert(1, 0xd800);
+        assert_eq!(skeleton_chars_utf16(&units, UnpairedSurrogates::Replace).collect::<String>(), "R\u{fffd}ust");
+        assert_eq!(skeleton_chars_utf16(&units, UnpairedSurrogates::Skip).collect::<String>(), "Rust");
+        assert_eq!(skeleton_chars_utf16_strict(&units).err().map(|e| e.unpaired_surrogate()), Some(0xd800));
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The normalization stage of the skeleton pipeline. This produces the same
+//! characters as `unicode_normalization`'s `nfd()`, but keeps track of enough
+//! state to give useful bounds in `size_hint`.
+//!
+//! Prototypes are stored in NFD when the tables are built, so the characters
+//! that the mapping stage emits need no normalization of their own.
+
+use std::iter::Fuse;
+use std::ops::Range;
+
+use tinyvec::TinyVec;
+use unicode_normalization::char::{canonical_combining_class, decompose_canonical};
+
+/// The most characters that the canonical decomposition of one character has.
+pub const MAX_DECOMPOSITION_LEN: usize = 4;
+
+/// The most characters that the skeleton of one fully decomposed character
+/// has: the length of the longest prototype.
+pub const MAX_PROTOTYPE_SKELETON_LEN: usize = 18;
+
+/// Test whether the canonical decomposition of `c` begins with a starter.
+/// Canonical reordering never moves characters across such a character, so
+/// text can be split before it and each part normalized independently.
+pub fn starts_with_starter(c: char) -> bool {
+    let mut first = None;
+    decompose_canonical(c, |d| if first.is_none() { first = Some(d) });
+    first.map(canonical_combining_class) == Some(0)
+}
+
+/// The canonical decomposition (NFD) of a source sequence of characters.
+#[derive(Clone)]
+pub struct SourceDecompositions<I> {
+    source: Fuse<I>,
+    // Pairs of (canonical combining class, character), in text order.
+    // Those in `ready` are in canonical order and can be emitted. Those after
+    // it may still need to be reordered with characters not yet read.
+    buffer: TinyVec<[(u8, char); 4]>,
+    ready: Range<usize>,
+}
+
+impl<I: Iterator<Item=char>> SourceDecompositions<I> {
+    pub fn new(source: I) -> SourceDecompositions<I> {
+        SourceDecompositions {
+            source: source.fuse(),
+            buffer: TinyVec::new(),
+            ready: 0..0,
+        }
+    }
+
+    fn push_back(&mut self, c: char) {
+        let class = canonical_combining_class(c);
+        if class == 0 {
+            self.sort_pending();
+            self.buffer.push((class, c));
+            self.ready.end = self.buffer.len();
+        } else {
+            self.buffer.push((class, c));
+        }
+    }
+
+    fn sort_pending(&mut self) {
+        // `sort_by_key` is stable, so this preserves the text order within a combining class.
+        self.buffer[self.ready.end..].sort_by_key(|entry| entry.0);
+    }
+}
+
+impl<I: Iterator<Item=char>> Iterator for SourceDecompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.ready.end == 0 {
+            match self.source.next() {
+                Some(c) => decompose_canonical(c, |d| self.push_back(d)),
+                None => {
+                    if self.buffer.is_empty() {
+                        return None;
+                    }
+                    self.sort_pending();
+                    self.ready.end = self.buffer.len();
+                }
+            }
+        }
+
+        let (_, c) = self.buffer[self.ready.start];
+        self.ready.start += 1;
+        if self.ready.start == self.ready.end {
+            self.buffer.drain(..self.ready.end);
+            self.ready = 0..0;
+        }
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every source character decomposes to at least one character.
+        let buffered = self.buffer.len() - self.ready.start;
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_mul(MAX_DECOMPOSITION_LEN)).and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use std::char;
+
+    use unicode_normalization::UnicodeNormalization;
+    use unicode_normalization::char::decompose_canonical;
+
+    use super::{SourceDecompositions, MAX_DECOMPOSITION_LEN, MAX_PROTOTYPE_SKELETON_LEN};
+    use data_entries;
+
+    #[test]
+    fn bounds_are_tight() {
+        let mut max_decomposition_len = 0;
+        for c in (0..0x110000).filter_map(char::from_u32) {
+            let mut len = 0;
+            decompose_canonical(c, |_| len += 1);
+            max_decomposition_len = max_decomposition_len.max(len);
+        }
+        assert_eq!(max_decomposition_len, MAX_DECOMPOSITION_LEN);
+
+        let max_prototype_skeleton_len = data_entries().map(|(_, prototype)| prototype.len()).max();
+        assert_eq!(max_prototype_skeleton_len, Some(MAX_PROTOTYPE_SKELETON_LEN));
+    }
+
+    #[test]
+    fn prototypes_are_decomposed() {
+        for (c, prototype) in data_entries() {
+            assert!(prototype.iter().cloned().eq(prototype.iter().cloned().nfd()), "prototype of U+{:04X}", c as u32);
+        }
+    }
+
+    #[test]
+    fn source_decompositions_match_nfd() {
+        for s in &["a\u{301}\u{316}b", "\u{e9}\u{316}\u{1f82}", "\u{1e3f}\u{301}\u{301}\u{316}", ""] {
+            assert_eq!(SourceDecompositions::new(s.chars()).collect::<String>(), s.nfd().collect::<String>());
+        }
+    }
+}
@@ -0,0 +1,395 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Checking domain names label by label.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+#[cfg(feature = "idna")]
+use idna::domain_to_unicode;
+
+use checker::{SpoofChecker, SpoofReport};
+use {case_folded, caseless_skeleton_chars};
+#[cfg(feature = "idna")]
+use UnicodeSkeleton;
+
+/// The results of checking each label of a domain name, from
+/// `check_domain` or `DomainChecker::check`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DomainReport<'a> {
+    /// The labels of the domain, in order.
+    pub labels: Vec<LabelReport<'a>>,
+    /// The places where the domain is confusable with a protected name
+    /// without being that name.
+    pub impersonations: Vec<Impersonation>,
+}
+
+impl<'a> DomainReport<'a> {
+    /// Test whether every label passed its checks and no protected name is
+    /// impersonated.
+    pub fn is_clean(&self) -> bool {
+        self.impersonations.is_empty() && self.labels.iter().all(|label| label.report.is_clean())
+    }
+
+    // Move every byte range later by `by`, for a domain that is part of a
+    // longer string.
+    pub(crate) fn offset(mut self, by: usize) -> DomainReport<'a> {
+        for label in &mut self.labels {
+            label.byte_range = label.byte_range.start + by..label.byte_range.end + by;
+            for finding in &mut label.report.findings {
+                finding.byte_range = finding.byte_range.start + by..finding.byte_range.end + by;
+            }
+        }
+        for impersonation in &mut self.impersonations {
+            impersonation.byte_range = impersonation.byte_range.start + by..impersonation.byte_range.end + by;
+        }
+        self
+    }
+}
+
+/// The results of checking one label of a domain name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelReport<'a> {
+    /// The label.
+    pub label: &'a str,
+    /// The location of `label` in the domain.
+    pub byte_range: Range<usize>,
+    /// The skeleton of the label.
+    pub skeleton: String,
+    /// The checks that the label failed. The byte ranges of its findings are
+    /// in the domain, not the label.
+    pub report: SpoofReport<'a>,
+}
+
+/// A run of labels of a domain that is confusable with a protected name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Impersonation {
+    /// The protected name, as given to `DomainChecker::protect`.
+    pub protected: String,
+    /// The indices of the labels that make up the confusable part.
+    pub labels: Range<usize>,
+    /// The location of the confusable part in the domain.
+    pub byte_range: Range<usize>,
+}
+
+/// Checks for domain names, as `check_domain` applies, with names such as
+/// brands to protect from impersonation.
+///
+/// # Examples
+/// ```Rust
+/// let checker = DomainChecker::new().protect(&["paypal", "apple.com"]);
+/// let report = checker.check("login.pаypal.com");
+/// report.labels[1].report.failed // Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL
+/// report.impersonations[0].protected // "paypal"
+/// checker.check("paypal.com").is_clean() // true
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DomainChecker {
+    checker: SpoofChecker,
+    // Each protected name, with the caseless skeletons of its labels.
+    protected: Vec<(String, Vec<String>)>,
+    #[cfg(feature = "psl")]
+    registrable_only: bool,
+}
+
+impl DomainChecker {
+    /// Create a checker that applies every check of `SpoofChecker::new` to
+    /// each label, and protects no names.
+    pub fn new() -> DomainChecker {
+        DomainChecker::default()
+    }
+
+    /// Check each label with `checker` rather than `SpoofChecker::new()`.
+    pub fn spoof_checker(mut self, checker: SpoofChecker) -> DomainChecker {
+        self.checker = checker;
+        self
+    }
+
+    /// Report the places where a domain is confusable with any of `names`,
+    /// but different from it. A name can be a single label, such as
+    /// "paypal", or several, such as "paypal.com", and differences in case
+    /// are disregarded, as they are in DNS.
+    pub fn protect<I>(mut self, names: I) -> DomainChecker
+        where I: IntoIterator, I::Item: AsRef<str>
+    {
+        for name in names {
+            let name = name.as_ref();
+            let labels = name.split(LABEL_SEPARATORS).map(|label| caseless_skeleton_chars(label).collect()).collect();
+            self.protected.push((name.to_string(), labels));
+        }
+        self
+    }
+
+    /// Only report the protected names impersonated by the registrable
+    /// domain, the public suffix and the label before it, such as
+    /// "paypal.co.uk" in "login.paypal.co.uk", or by that label alone.
+    /// Subdomains are chosen freely by the owner of the registrable domain,
+    /// so with this on, "pаypal.com.evil.example" is not reported for
+    /// "paypal.com", but "pаypal.co.uk" is reported for both "paypal" and
+    /// "paypal.co.uk". Off by default.
+    #[cfg(feature = "psl")]
+    pub fn registrable_only(mut self, registrable_only: bool) -> DomainChecker {
+        self.registrable_only = registrable_only;
+        self
+    }
+
+    /// Check each label of `domain`, and find the protected names it
+    /// impersonates. `domain` should be in the Unicode form that users see,
+    /// with any A-labels ("xn--…") decoded first. Labels are separated by
+    /// any of the dots that `normalize_separators` replaces, as well as ".".
+    pub fn check<'a>(&self, domain: &'a str) -> DomainReport<'a> {
+        let mut labels = Vec::new();
+        let mut start = 0;
+        for label in domain.split(LABEL_SEPARATORS) {
+            let byte_range = start..start + label.len();
+            start = byte_range.end + domain[byte_range.end..].chars().next().map_or(0, char::len_utf8);
+            let mut report = self.checker.check(label);
+            for finding in &mut report.findings {
+                finding.byte_range = finding.byte_range.start + byte_range.start..finding.byte_range.end + byte_range.start;
+            }
+            labels.push(LabelReport { label, byte_range, skeleton: ::skeleton(label), report });
+        }
+
+        let skeletons: Vec<String> = labels.iter().map(|label| caseless_skeleton_chars(label.label).collect()).collect();
+        #[cfg(feature = "psl")]
+        let registrable = if self.registrable_only { Some(registrable_runs(domain)) } else { None };
+        let mut impersonations = Vec::new();
+        for (name, protected) in &self.protected {
+            for first in 0..(labels.len() + 1).saturating_sub(protected.len()) {
+                let run = first..first + protected.len();
+                if skeletons[run.clone()] != protected[..] {
+                    continue;
+                }
+                #[cfg(feature = "psl")]
+                {
+                    if registrable.as_ref().is_some_and(|runs| !runs.contains(&run)) {
+                        continue;
+                    }
+                }
+                let byte_range = labels[run.start].byte_range.start..labels[run.end - 1].byte_range.end;
+                let found = case_folded(&normalize_separators(&domain[byte_range.clone()]));
+                if found != case_folded(&normalize_separators(name)) {
+                    impersonations.push(Impersonation { protected: name.clone(), labels: run, byte_range });
+                }
+            }
+        }
+        DomainReport { labels, impersonations }
+    }
+}
+
+// The runs of labels of `domain` that make up its registrable domain and the
+// label before its public suffix, or none if it has no registrable domain.
+#[cfg(feature = "psl")]
+fn registrable_runs(domain: &str) -> Vec<Range<usize>> {
+    let domain = normalize_separators(domain).to_lowercase();
+    let domain = domain.strip_suffix('.').unwrap_or(&domain);
+    let (registrable, suffix) = match (psl::domain_str(domain), psl::suffix_str(domain)) {
+        (Some(registrable), Some(suffix)) => (registrable.split('.').count(), suffix.split('.').count()),
+        _ => return Vec::new(),
+    };
+    let count = domain.split('.').count();
+    vec![count - registrable..count, count - registrable..count - suffix]
+}
+
+/// Check each label of a domain name with the checks of `SpoofChecker::new`,
+/// reporting which labels failed which checks, and the characters
+/// responsible. Use `DomainChecker` to also protect names from
+/// impersonation.
+///
+/// # Examples
+/// ```Rust
+/// let report = check_domain("pаypal.com"); // with a Cyrillic "а"
+/// report.labels[0].report.failed // Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL
+/// report.labels[0].report.findings[0].text // "а"
+/// check_domain("paypal.com").is_clean() // true
+/// ```
+pub fn check_domain<'a>(domain: &'a str) -> DomainReport<'a> {
+    DomainChecker::new().check(domain)
+}
+
+// The characters that IDNA maps to ".", separating labels.
+pub(crate) const LABEL_SEPARATORS: &[char] = &['.', '\u{3002}', '\u{FF0E}', '\u{FF61}'];
+
+/// Replace the characters that IDNA treats as label separators, the
+/// ideographic full stop "。" (U+3002), the fullwidth full stop "．" (U+FF0E)
+/// and the halfwidth ideographic full stop "｡" (U+FF61), with ".".
+///
+/// Browsers accept these in host names, so they can be used to slip a
+/// separator past a filter that only looks for ".". The domain functions of
+/// this crate already treat them as ".".
+///
+/// # Examples
+/// ```Rust
+/// normalize_separators("paypal。com") // "paypal.com"
+/// normalize_separators("paypal.com") // "paypal.com", borrowed
+/// ```
+pub fn normalize_separators(domain: &str) -> Cow<'_, str> {
+    if domain.contains(&LABEL_SEPARATORS[1..]) {
+        Cow::Owned(domain.replace(LABEL_SEPARATORS, "."))
+    } else {
+        Cow::Borrowed(domain)
+    }
+}
+
+/// The labels of `domain` as Unicode, as a browser would display them: with
+/// A-labels ("xn--…") decoded and the IDNA mapping applied, which folds case
+/// and width and treats "。" and the like as dots. A trailing dot, for the
+/// root, is dropped. Labels that are not valid IDNA are kept as they are.
+#[cfg(feature = "idna")]
+pub(crate) fn unicode_labels(domain: &str) -> Vec<String> {
+    // Errors are reported for the whole domain, but the labels that could
+    // not be processed are still returned.
+    let (unicode, _errors) = domain_to_unicode(domain);
+    let unicode = unicode.strip_suffix('.').unwrap_or(&unicode);
+    unicode.split('.').map(str::to_string).collect()
+}
+
+/// Test if two domain names could be visually confused for each other once
+/// they are displayed, comparing the skeletons of their labels.
+///
+/// Punycode A-labels are decoded first, so an "xn--" domain is compared as
+/// the Unicode name it stands for, and case is disregarded, as it is in DNS.
+/// Each label is compared separately, so a dot lookalike within a label does
+/// not make it confusable with two labels.
+///
+/// # Examples
+/// ```Rust
+/// confusable_domains("xn--pypal-4ve.com", "paypal.com") // true, as "xn--pypal-4ve" is "pаypal"
+/// confusable_domains("PAYPAL.com.", "𝔭𝒶ỿ𝕡𝕒ℓ.com") // true
+/// confusable_domains("paypal.com", "paypal.co") // false
+/// ```
+#[cfg(feature = "idna")]
+pub fn confusable_domains(a: &str, b: &str) -> bool {
+    let a = unicode_labels(a);
+    let b = unicode_labels(b);
+    a.len() == b.len() && a.iter().zip(&b).all(|(a, b)| a.skeleton_chars().eq(b.skeleton_chars()))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use checker::{Check, Checks, SpoofChecker};
+    use std::borrow::Cow;
+
+    use super::{check_domain, normalize_separators, DomainChecker, Impersonation};
+    #[cfg(feature = "idna")]
+    use super::{confusable_domains, unicode_labels};
+
+    #[test]
+    fn checks_labels() {
+        let report = check_domain("login.pаypal.com");
+        assert_eq!(report.labels.iter().map(|label| label.label).collect::<Vec<_>>(), vec!["login", "pаypal", "com"]);
+        assert!(report.labels[0].report.is_clean());
+        let label = &report.labels[1];
+        assert_eq!(label.byte_range, 6..13);
+        assert_eq!(label.skeleton, "paypal");
+        assert!(label.report.failed.contains(Checks::MIXED_SCRIPT));
+        let finding = &label.report.findings_for(Check::MixedScript)[0];
+        assert_eq!((&*finding.text, finding.byte_range.clone()), ("а", 7..9));
+        assert!(!report.is_clean());
+        assert!(check_domain("example.com").is_clean());
+        // Whole-script confusables pass the per-label checks.
+        assert!(check_domain("аре.com").labels[0].report.is_clean());
+        assert_eq!(check_domain("").labels.len(), 1);
+    }
+
+    #[test]
+    fn finds_impersonations() {
+        let checker = DomainChecker::new().protect(&["paypal", "Apple.com"]);
+        let report = checker.check("login.pаypal.com");
+        assert_eq!(report.impersonations, vec![
+            Impersonation { protected: "paypal".to_string(), labels: 1..2, byte_range: 6..13 },
+        ]);
+        assert!(checker.check("paypal.com").is_clean());
+        assert!(checker.check("PayPal.com").is_clean());
+        assert!(checker.check("apple.com").is_clean());
+        let report = checker.check("аррlе.com.evil.example");
+        assert_eq!(report.impersonations, vec![
+            Impersonation { protected: "Apple.com".to_string(), labels: 0..2, byte_range: 0..13 },
+        ]);
+        assert!(checker.check("apple.co").impersonations.is_empty());
+        assert!(checker.check("").impersonations.is_empty());
+
+        let lenient = checker.spoof_checker(SpoofChecker::new().disable(Check::MixedScript).disable(Check::RestrictionLevel));
+        let report = lenient.check("pаypal.com");
+        assert!(report.labels.iter().all(|label| label.report.is_clean()));
+        assert_eq!(report.impersonations.len(), 1);
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!(normalize_separators("a。b．c｡d.e"), "a.b.c.d.e");
+        assert!(matches!(normalize_separators("paypal.com"), Cow::Borrowed("paypal.com")));
+        let report = check_domain("login。pаypal．com");
+        assert_eq!(report.labels.iter().map(|label| label.label).collect::<Vec<_>>(), vec!["login", "pаypal", "com"]);
+        assert_eq!(report.labels[1].byte_range, 8..15);
+        assert_eq!(report.labels[2].byte_range, 18..21);
+        let checker = DomainChecker::new().protect(&["paypal．com"]);
+        let report = checker.check("pаypal｡com");
+        assert_eq!(report.impersonations, vec![
+            Impersonation { protected: "paypal．com".to_string(), labels: 0..2, byte_range: 0..13 },
+        ]);
+        // A different separator alone is not an impersonation.
+        assert!(checker.check("paypal.com").impersonations.is_empty());
+    }
+
+    #[cfg(feature = "psl")]
+    #[test]
+    fn registrable_domains() {
+        let checker = DomainChecker::new().protect(&["paypal", "paypal.com"]).registrable_only(true);
+        assert!(checker.check("pаypal.com.evil.example").impersonations.is_empty());
+        assert!(checker.check("login.paypal.com").impersonations.is_empty());
+        let report = checker.check("login.pаypal.com");
+        assert_eq!(report.impersonations, vec![
+            Impersonation { protected: "paypal".to_string(), labels: 1..2, byte_range: 6..13 },
+            Impersonation { protected: "paypal.com".to_string(), labels: 1..3, byte_range: 6..17 },
+        ]);
+        // Suffixes of several labels are recognized.
+        let report = checker.check("www.pаypal.co.uk.");
+        assert_eq!(report.impersonations.iter().map(|found| found.labels.clone()).collect::<Vec<_>>(), vec![1..2]);
+        assert_eq!(checker.check("pаypal｡com").impersonations.len(), 2);
+        // A public suffix alone has no registrable domain.
+        let suffixes = DomainChecker::new().protect(&["com"]);
+        assert_eq!(suffixes.check("cоm").impersonations.len(), 1);
+        assert!(suffixes.registrable_only(true).check("cоm").impersonations.is_empty());
+
+        let everywhere = DomainChecker::new().protect(&["paypal.com"]);
+        assert_eq!(everywhere.check("pаypal.com.evil.example").impersonations.len(), 1);
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn decodes_labels() {
+        assert_eq!(unicode_labels("xn--pypal-4ve.com"), vec!["pаypal", "com"]);
+        assert_eq!(unicode_labels("WWW.Example.COM."), vec!["www", "example", "com"]);
+        assert_eq!(unicode_labels("ｐａｙｐａｌ。com"), vec!["paypal", "com"]);
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn compares_labels() {
+        assert!(confusable_domains("xn--pypal-4ve.com", "paypal.com"));
+        assert!(confusable_domains("pаypal.com", "xn--pypal-4ve.com"));
+        assert!(confusable_domains("PAYPAL.com.", "𝔭𝒶ỿ𝕡𝕒ℓ.com"));
+        assert!(confusable_domains("rnicrosoft.com", "microsoft.com"));
+        assert!(!confusable_domains("paypal.com", "paypal.co"));
+        assert!(!confusable_domains("paypal.com", "paypal.com.evil"));
+        // A dot lookalike does not split a label.
+        assert!(!confusable_domains("paypal\u{2024}com", "paypal.com"));
+        // Undecodable A-labels are compared as they are.
+        assert!(confusable_domains("xn--zz.com", "xn--zz.com"));
+        assert!(!confusable_domains("xn--zz.com", "zz.com"));
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Comparing and checking email addresses.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use checker::{SpoofChecker, SpoofReport};
+use domain::{DomainChecker, DomainReport};
+#[cfg(feature = "idna")]
+use domain::unicode_labels;
+#[cfg(not(feature = "idna"))]
+use domain::LABEL_SEPARATORS;
+use {caseless_skeleton_chars, UnicodeSkeleton};
+
+/// How `confusable_emails` compares the local parts of addresses, the part
+/// before the "@".
+///
+/// Domains are always compared label by label and without regard to case,
+/// as by `confusable_domains`, decoding A-labels with the `idna` feature.
+///
+/// # Examples
+/// ```Rust
+/// let options = EmailOptions::new().ignore_dots(true).ignore_tags(true);
+/// options.confusable("j.smith+billing@gmail.com", "jsmith@grnail.com") // true
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmailOptions {
+    ignore_case: bool,
+    ignore_dots: bool,
+    ignore_tags: bool,
+}
+
+impl Default for EmailOptions {
+    fn default() -> EmailOptions {
+        EmailOptions { ignore_case: true, ignore_dots: false, ignore_tags: false }
+    }
+}
+
+impl EmailOptions {
+    /// Create options that disregard case in local parts, but not dots or tags.
+    pub fn new() -> EmailOptions {
+        EmailOptions::default()
+    }
+
+    /// Set whether differences in case are disregarded in local parts. Most
+    /// mail servers disregard them, though the standard leaves it to them.
+    pub fn ignore_case(mut self, ignore_case: bool) -> EmailOptions {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Set whether dots are disregarded in local parts, as Gmail does.
+    pub fn ignore_dots(mut self, ignore_dots: bool) -> EmailOptions {
+        self.ignore_dots = ignore_dots;
+        self
+    }
+
+    /// Set whether a "+" and what follows it in a local part, a subaddress
+    /// tag, are disregarded.
+    pub fn ignore_tags(mut self, ignore_tags: bool) -> EmailOptions {
+        self.ignore_tags = ignore_tags;
+        self
+    }
+
+    // The skeleton of a local part, as it is compared. Dots and tags are
+    // found in the skeleton, so their lookalikes are handled too.
+    fn local_skeleton(&self, local: &str) -> String {
+        let mut skeleton: String = if self.ignore_case {
+            caseless_skeleton_chars(local).collect()
+        } else {
+            local.skeleton_chars().collect()
+        };
+        if self.ignore_tags {
+            if let Some(plus) = skeleton.find('+') {
+                skeleton.truncate(plus);
+            }
+        }
+        if self.ignore_dots {
+            skeleton.retain(|c| c != '.');
+        }
+        skeleton
+    }
+
+    /// Test if two email addresses could be visually confused for each
+    /// other, or would reach the same mailbox, under these options. Strings
+    /// that are not addresses, without an "@", are compared as by
+    /// `confusable`.
+    pub fn confusable(&self, a: &str, b: &str) -> bool {
+        match (a.rfind('@'), b.rfind('@')) {
+            (Some(at_a), Some(at_b)) => {
+                self.local_skeleton(&a[..at_a]) == self.local_skeleton(&b[..at_b]) &&
+                    domain_skeletons(&a[at_a + 1..]) == domain_skeletons(&b[at_b + 1..])
+            }
+            (None, None) => ::confusable(a, b),
+            _ => false,
+        }
+    }
+}
+
+// The caseless skeletons of the labels of an email domain.
+#[cfg(feature = "idna")]
+fn domain_skeletons(domain: &str) -> Vec<String> {
+    unicode_labels(domain).iter().map(|label| caseless_skeleton_chars(label).collect()).collect()
+}
+
+#[cfg(not(feature = "idna"))]
+fn domain_skeletons(domain: &str) -> Vec<String> {
+    let domain = domain.strip_suffix(LABEL_SEPARATORS).unwrap_or(domain);
+    domain.split(LABEL_SEPARATORS).map(|label| caseless_skeleton_chars(label).collect()).collect()
+}
+
+/// Test if two email addresses could be visually confused for each other,
+/// comparing them as `EmailOptions::new()` does: local parts by skeleton
+/// without regard to case, and domains label by label.
+///
+/// # Examples
+/// ```Rust
+/// confusable_emails("support@pаypal.com", "Support@PayPal.com") // true
+/// confusable_emails("support@paypal.com", "support@paypal.co") // false
+/// ```
+pub fn confusable_emails(a: &str, b: &str) -> bool {
+    EmailOptions::new().confusable(a, b)
+}
+
+/// The results of checking an email address, from `check_email`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmailReport<'a> {
+    /// The part of the address before the "@".
+    pub local_part: &'a str,
+    /// The checks that the local part failed.
+    pub local_report: SpoofReport<'a>,
+    /// The results of checking the domain, with byte ranges in the address.
+    pub domain: DomainReport<'a>,
+}
+
+impl<'a> EmailReport<'a> {
+    /// Test whether the local part and every label of the domain passed their
+    /// checks.
+    pub fn is_clean(&self) -> bool {
+        self.local_report.is_clean() && self.domain.is_clean()
+    }
+}
+
+/// Check the local part of an email address with the checks of
+/// `SpoofChecker::new`, and its domain as `check_domain` does, reporting the
+/// characters responsible. Returns `None` if `address` has no "@".
+///
+/// The address should be in the Unicode form that users see, with any
+/// A-labels ("xn--…") of the domain decoded first.
+///
+/// # Examples
+/// ```Rust
+/// let report = check_email("suppоrt@paypal.com").unwrap(); // with a Cyrillic "о"
+/// report.local_report.findings[0].text // "о"
+/// report.domain.is_clean() // true
+/// ```
+pub fn check_email<'a>(address: &'a str) -> Option<EmailReport<'a>> {
+    let at = address.rfind('@')?;
+    let local_part = &address[..at];
+    let domain = DomainChecker::new().check(&address[at + 1..]).offset(at + 1);
+    Some(EmailReport {
+        local_part,
+        local_report: SpoofChecker::new().check(local_part),
+        domain,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use checker::{Check, Checks};
+    use super::{check_email, confusable_emails, EmailOptions};
+
+    #[test]
+    fn compares_addresses() {
+        assert!(confusable_emails("support@pаypal.com", "Support@PayPal.com"));
+        assert!(confusable_emails("ѕupport@paypal.com", "support@paypal.com."));
+        assert!(confusable_emails("support@paypal。com", "support@paypal.com"));
+        assert!(confusable_emails("admin@rnicrosoft.com", "admin@microsoft.com"));
+        assert!(!confusable_emails("support@paypal.com", "support@paypal.co"));
+        assert!(!confusable_emails("j.smith@gmail.com", "jsmith@gmail.com"));
+        assert!(!confusable_emails("a@b.com", "a.b.com"));
+        assert!(confusable_emails("not an address", "nοt an address"));
+        // Only the last "@" separates the domain.
+        assert!(confusable_emails("\"a@b\"@example.com", "\"a@b\"@exаmple.com"));
+        assert!(!EmailOptions::new().ignore_case(false).confusable("Support@paypal.com", "support@paypal.com"));
+    }
+
+    #[test]
+    fn dots_and_tags() {
+        let options = EmailOptions::new().ignore_dots(true).ignore_tags(true);
+        assert!(options.confusable("j.smith+billing@gmail.com", "jsmith@grnail.com"));
+        assert!(options.confusable("j\u{2024}smith+x@gmail.com", "jsmith@gmail.com"));
+        assert!(!options.confusable("jsmith@gmail.com", "jsmith@g.mail.com"));
+        assert!(!EmailOptions::new().ignore_dots(true).confusable("jsmith+a@gmail.com", "jsmith@gmail.com"));
+        assert!(!EmailOptions::new().ignore_tags(true).confusable("j.smith+a@gmail.com", "jsmith@gmail.com"));
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn decodes_domains() {
+        assert!(confusable_emails("support@xn--pypal-4ve.com", "support@paypal.com"));
+    }
+
+    #[test]
+    fn checks_addresses() {
+        let report = check_email("suppоrt@paypal.com").unwrap();
+        assert_eq!(report.local_part, "suppоrt");
+        assert!(report.local_report.failed.contains(Checks::MIXED_SCRIPT));
+        assert_eq!(report.local_report.findings_for(Check::MixedScript)[0].byte_range, 4..6);
+        assert!(report.domain.is_clean());
+        assert!(!report.is_clean());
+
+        let report = check_email("support@pаypal.com").unwrap();
+        assert!(report.local_report.is_clean());
+        assert_eq!(report.domain.labels[0].byte_range, 8..15);
+        assert_eq!(report.domain.labels[0].report.findings_for(Check::MixedScript)[0].byte_range, 9..11);
+        assert!(check_email("support@paypal.com").unwrap().is_clean());
+        assert_eq!(check_email("support"), None);
+    }
+}
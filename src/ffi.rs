@@ -0,0 +1,288 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A C interface to skeletons, confusability and `SpoofChecker`, declared
+//! in `include/unicode_skeleton.h`.
+//!
+//! Strings are passed as a pointer to UTF-8 and a length in bytes, and need
+//! not be NUL-terminated. Functions return a `USK_*` status code, and write
+//! their results through out-parameters. A pointer may only be null if its
+//! length is zero.
+//!
+//! Requires the `ffi` feature. To build a shared library, run
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+
+use std::mem;
+use std::os::raw::c_int;
+use std::ptr;
+use std::slice;
+use std::str;
+
+use checker::{Check, RestrictionLevel, SpoofChecker};
+use {confusable, skeleton};
+
+/// The call succeeded.
+pub const USK_OK: c_int = 0;
+/// A required pointer was null.
+pub const USK_NULL_POINTER: c_int = 1;
+/// An input string was not valid UTF-8.
+pub const USK_INVALID_UTF8: c_int = 2;
+/// The output buffer was too small. The length it needs has been written.
+pub const USK_BUFFER_TOO_SMALL: c_int = 3;
+/// An argument was out of range, such as an unknown restriction level.
+pub const USK_INVALID_ARGUMENT: c_int = 4;
+
+/// A `SpoofChecker`, owned by C code through a pointer from
+/// `usk_checker_new` until it is passed to `usk_checker_free`.
+pub struct UskChecker(SpoofChecker);
+
+// Borrow a string passed from C, as a pointer and a length in bytes.
+unsafe fn input<'a>(s: *const u8, len: usize) -> Result<&'a str, c_int> {
+    if s.is_null() {
+        return if len == 0 { Ok("") } else { Err(USK_NULL_POINTER) };
+    }
+    str::from_utf8(slice::from_raw_parts(s, len)).map_err(|_| USK_INVALID_UTF8)
+}
+
+macro_rules! try_status {
+    ($e:expr) => {
+        match $e {
+            Ok(value) => value,
+            Err(status) => return status,
+        }
+    }
+}
+
+/// Compute the skeleton of the UTF-8 string `s`, of `len` bytes, into the
+/// buffer `out` of `out_cap` bytes.
+///
+/// The length of the skeleton in bytes is written to `out_len`. If it is
+/// more than `out_cap`, nothing is written to `out` and
+/// `USK_BUFFER_TOO_SMALL` is returned, so a caller can pass a null `out` and
+/// an `out_cap` of zero to find the length needed. The skeleton is not
+/// NUL-terminated.
+///
+/// # Safety
+/// `s` must point to `len` readable bytes, `out` to `out_cap` writable
+/// bytes, and `out_len` to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_skeleton_utf8(s: *const u8, len: usize, out: *mut u8, out_cap: usize, out_len: *mut usize) -> c_int {
+    let s = try_status!(input(s, len));
+    if out_len.is_null() || (out.is_null() && out_cap != 0) {
+        return USK_NULL_POINTER;
+    }
+    let skeleton = skeleton(s);
+    *out_len = skeleton.len();
+    if skeleton.len() > out_cap {
+        return USK_BUFFER_TOO_SMALL;
+    }
+    if !skeleton.is_empty() {
+        ptr::copy_nonoverlapping(skeleton.as_ptr(), out, skeleton.len());
+    }
+    USK_OK
+}
+
+/// Test whether the UTF-8 strings `a` and `b` are confusable, writing the
+/// answer to `result`.
+///
+/// # Safety
+/// `a` and `b` must point to `a_len` and `b_len` readable bytes, and
+/// `result` to a writable `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_confusable(a: *const u8, a_len: usize, b: *const u8, b_len: usize, result: *mut bool) -> c_int {
+    let a = try_status!(input(a, a_len));
+    let b = try_status!(input(b, b_len));
+    if result.is_null() {
+        return USK_NULL_POINTER;
+    }
+    *result = confusable(a, b);
+    USK_OK
+}
+
+/// Create a checker with every check enabled, as `SpoofChecker::new` does.
+/// It must be freed with `usk_checker_free`.
+#[no_mangle]
+pub extern "C" fn usk_checker_new() -> *mut UskChecker {
+    Box::into_raw(Box::new(UskChecker(SpoofChecker::new())))
+}
+
+/// Free a checker created by `usk_checker_new`. Null is ignored.
+///
+/// # Safety
+/// `checker` must be null or a checker from `usk_checker_new` that has not
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn usk_checker_free(checker: *mut UskChecker) {
+    if !checker.is_null() {
+        drop(Box::from_raw(checker));
+    }
+}
+
+// Apply a builder method of `SpoofChecker` to a checker passed from C.
+unsafe fn update<F: FnOnce(SpoofChecker) -> SpoofChecker>(checker: *mut UskChecker, f: F) -> c_int {
+    match checker.as_mut() {
+        Some(checker) => {
+            checker.0 = f(mem::take(&mut checker.0));
+            USK_OK
+        }
+        None => USK_NULL_POINTER,
+    }
+}
+
+/// Turn on the checks whose `USK_CHECK_*` bits are set in `checks`.
+///
+/// # Safety
+/// `checker` must be a live checker from `usk_checker_new`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_checker_enable(checker: *mut UskChecker, checks: u32) -> c_int {
+    update(checker, |checker| Check::all_in(checks).fold(checker, SpoofChecker::enable))
+}
+
+/// Turn off the checks whose `USK_CHECK_*` bits are set in `checks`.
+///
+/// # Safety
+/// `checker` must be a live checker from `usk_checker_new`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_checker_disable(checker: *mut UskChecker, checks: u32) -> c_int {
+    update(checker, |checker| Check::all_in(checks).fold(checker, SpoofChecker::disable))
+}
+
+/// Set the most permissive restriction level that passes, as one of the
+/// `USK_RESTRICTION_*` constants.
+///
+/// # Safety
+/// `checker` must be a live checker from `usk_checker_new`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_checker_set_restriction_level(checker: *mut UskChecker, level: c_int) -> c_int {
+    let level = match RestrictionLevel::from_number(level.into()) {
+        Some(level) => level,
+        None => return USK_INVALID_ARGUMENT,
+    };
+    update(checker, |checker| checker.restriction_level(level))
+}
+
+/// Run the checker's enabled checks against the UTF-8 string `s`, writing
+/// the `USK_CHECK_*` bits of those that failed to `failed`.
+///
+/// # Safety
+/// `checker` must be a live checker from `usk_checker_new`, `s` must point
+/// to `len` readable bytes, and `failed` to a writable `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_checker_check(checker: *const UskChecker, s: *const u8, len: usize, failed: *mut u32) -> c_int {
+    let s = try_status!(input(s, len));
+    match (checker.as_ref(), failed.is_null()) {
+        (Some(checker), false) => {
+            *failed = checker.0.quick_check(s).bits();
+            USK_OK
+        }
+        _ => USK_NULL_POINTER,
+    }
+}
+
+/// Test whether the UTF-8 strings `a` and `b` are confusable under the
+/// checker's configuration, writing the answer to `result`.
+///
+/// # Safety
+/// `checker` must be a live checker from `usk_checker_new`, `a` and `b`
+/// must point to `a_len` and `b_len` readable bytes, and `result` to a
+/// writable `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn usk_checker_are_confusable(checker: *const UskChecker, a: *const u8, a_len: usize, b: *const u8, b_len: usize, result: *mut bool) -> c_int {
+    let a = try_status!(input(a, a_len));
+    let b = try_status!(input(b, b_len));
+    match (checker.as_ref(), result.is_null()) {
+        (Some(checker), false) => {
+            *result = checker.0.are_confusable(a, b);
+            USK_OK
+        }
+        _ => USK_NULL_POINTER,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+    use checker::Checks;
+
+    #[test]
+    fn header_matches() {
+        let header = include_str!("../include/unicode_skeleton.h");
+        let define = |name: &str, value: &str| assert!(header.contains(&format!("#define {} {}\n", name, value)), "{}", name);
+        define("USK_OK", &USK_OK.to_string());
+        define("USK_NULL_POINTER", &USK_NULL_POINTER.to_string());
+        define("USK_INVALID_UTF8", &USK_INVALID_UTF8.to_string());
+        define("USK_BUFFER_TOO_SMALL", &USK_BUFFER_TOO_SMALL.to_string());
+        define("USK_INVALID_ARGUMENT", &USK_INVALID_ARGUMENT.to_string());
+        for check in Checks::all().iter() {
+            define(&format!("USK_CHECK_{}", check.name().to_uppercase()), &format!("(1u << {})", Checks::from(check).bits().trailing_zeros()));
+        }
+    }
+
+    #[test]
+    fn skeletons() {
+        let s = "ℝ𝓊𝓈𝓉";
+        let mut len = 0;
+        unsafe {
+            assert_eq!(usk_skeleton_utf8(s.as_ptr(), s.len(), ptr::null_mut(), 0, &mut len), USK_BUFFER_TOO_SMALL);
+            assert_eq!(len, 4);
+            let mut out = [0u8; 4];
+            assert_eq!(usk_skeleton_utf8(s.as_ptr(), s.len(), out.as_mut_ptr(), out.len(), &mut len), USK_OK);
+            assert_eq!(&out, b"Rust");
+            assert_eq!(usk_skeleton_utf8(ptr::null(), 0, ptr::null_mut(), 0, &mut len), USK_OK);
+            assert_eq!(len, 0);
+            assert_eq!(usk_skeleton_utf8(b"\xff".as_ptr(), 1, out.as_mut_ptr(), out.len(), &mut len), USK_INVALID_UTF8);
+            assert_eq!(usk_skeleton_utf8(ptr::null(), 1, out.as_mut_ptr(), out.len(), &mut len), USK_NULL_POINTER);
+        }
+    }
+
+    #[test]
+    fn confusables() {
+        let mut result = false;
+        unsafe {
+            assert_eq!(usk_confusable("ℝ𝓊𝓈𝓉".as_ptr(), "ℝ𝓊𝓈𝓉".len(), b"Rust".as_ptr(), 4, &mut result), USK_OK);
+            assert!(result);
+            assert_eq!(usk_confusable(b"Rest".as_ptr(), 4, b"Rust".as_ptr(), 4, &mut result), USK_OK);
+            assert!(!result);
+            assert_eq!(usk_confusable(b"Rest".as_ptr(), 4, b"Rust".as_ptr(), 4, ptr::null_mut()), USK_NULL_POINTER);
+        }
+    }
+
+    #[test]
+    fn checkers() {
+        let s = "pаypаl";
+        let mut failed = 0;
+        let mut result = false;
+        unsafe {
+            let checker = usk_checker_new();
+            assert_eq!(usk_checker_check(checker, s.as_ptr(), s.len(), &mut failed), USK_OK);
+            assert_eq!(failed, (Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL).bits());
+
+            assert_eq!(usk_checker_disable(checker, Checks::MIXED_SCRIPT.bits()), USK_OK);
+            assert_eq!(usk_checker_set_restriction_level(checker, 4), USK_OK);
+            assert_eq!(usk_checker_check(checker, s.as_ptr(), s.len(), &mut failed), USK_OK);
+            assert_eq!(failed, 0);
+            assert_eq!(usk_checker_set_restriction_level(checker, 6), USK_INVALID_ARGUMENT);
+
+            assert_eq!(usk_checker_are_confusable(checker, s.as_ptr(), s.len(), b"paypal".as_ptr(), 6, &mut result), USK_OK);
+            assert!(result);
+            assert_eq!(usk_checker_disable(checker, Checks::CONFUSABLE.bits()), USK_OK);
+            assert_eq!(usk_checker_are_confusable(checker, s.as_ptr(), s.len(), b"paypal".as_ptr(), 6, &mut result), USK_OK);
+            assert!(!result);
+            assert_eq!(usk_checker_enable(checker, Checks::CONFUSABLE.bits()), USK_OK);
+            assert_eq!(usk_checker_are_confusable(checker, s.as_ptr(), s.len(), b"paypal".as_ptr(), 6, &mut result), USK_OK);
+            assert!(result);
+
+            usk_checker_free(checker);
+            usk_checker_free(ptr::null_mut());
+            assert_eq!(usk_checker_enable(ptr::null_mut(), 0), USK_NULL_POINTER);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Skeletons that disregard spacing, punctuation between words and case.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use {caseless_skeleton_chars, UnicodeSkeleton};
+
+/// How `FuzzyOptions` treats one class of separator characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeparatorFold {
+    /// Compare the separators by skeleton, as other characters are.
+    Keep,
+    /// Treat every run of separators of the class as one representative
+    /// character, so "pay - pal" and "pay-pal" are the same, but still
+    /// differ from "paypal".
+    Unify,
+    /// Drop the separators of the class.
+    Ignore,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Whitespace,
+    Hyphen,
+    Underscore,
+    Apostrophe,
+}
+
+impl Class {
+    fn of(c: char) -> Option<Class> {
+        match c {
+            c if c.is_whitespace() => Some(Class::Whitespace),
+            '-' | '\u{AD}' | '\u{2010}'..='\u{2015}' | '\u{2043}' | '\u{2212}' | '\u{2E3A}' | '\u{2E3B}' |
+            '\u{FE58}' | '\u{FE63}' | '\u{FF0D}' => Some(Class::Hyphen),
+            '_' | '\u{2017}' | '\u{FE4D}'..='\u{FE4F}' | '\u{FF3F}' => Some(Class::Underscore),
+            '\'' | '`' | '\u{B4}' | '\u{2B9}' | '\u{2BB}' | '\u{2BC}' | '\u{2BD}' | '\u{2018}' | '\u{2019}' |
+            '\u{201B}' | '\u{2032}' | '\u{FF07}' => Some(Class::Apostrophe),
+            _ => None,
+        }
+    }
+
+    fn representative(self) -> char {
+        match self {
+            Class::Whitespace => ' ',
+            Class::Hyphen => '-',
+            Class::Underscore => '_',
+            Class::Apostrophe => '\'',
+        }
+    }
+}
+
+/// A looser comparison than `confusable`, for names that people write with
+/// varying spacing and punctuation, such as "Pay Pal", "pay-pal" and
+/// "𝔭𝒶𝓎𝓅𝒶𝓁".
+///
+/// Whitespace, hyphens, underscores and apostrophes are each a class of
+/// separators, which can be kept, unified or ignored, including their
+/// lookalikes such as "‐" and "’". The rest of the string is compared by
+/// skeleton, and by default without regard to case, as with
+/// `confusable_ignore_case`.
+///
+/// # Examples
+/// ```Rust
+/// let options = FuzzyOptions::new();
+/// options.confusable("Pay Pal", "𝔭𝒶𝓎𝓅𝒶𝓁") // true
+/// options.skeleton("pay-pal") // "paypal"
+/// let options = FuzzyOptions::new().hyphens(SeparatorFold::Unify);
+/// options.confusable("pay‐pal", "pay - pal") // true
+/// options.confusable("pay-pal", "paypal") // false
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FuzzyOptions {
+    whitespace: SeparatorFold,
+    hyphens: SeparatorFold,
+    underscores: SeparatorFold,
+    apostrophes: SeparatorFold,
+    ignore_case: bool,
+}
+
+impl Default for FuzzyOptions {
+    fn default() -> FuzzyOptions {
+        FuzzyOptions {
+            whitespace: SeparatorFold::Ignore,
+            hyphens: SeparatorFold::Ignore,
+            underscores: SeparatorFold::Ignore,
+            apostrophes: SeparatorFold::Ignore,
+            ignore_case: true,
+        }
+    }
+}
+
+impl FuzzyOptions {
+    /// Create options that ignore all separators and case.
+    pub fn new() -> FuzzyOptions {
+        FuzzyOptions::default()
+    }
+
+    /// Set how whitespace is treated.
+    pub fn whitespace(mut self, fold: SeparatorFold) -> FuzzyOptions {
+        self.whitespace = fold;
+        self
+    }
+
+    /// Set how hyphens and dashes are treated.
+    pub fn hyphens(mut self, fold: SeparatorFold) -> FuzzyOptions {
+        self.hyphens = fold;
+        self
+    }
+
+    /// Set how underscores are treated.
+    pub fn underscores(mut self, fold: SeparatorFold) -> FuzzyOptions {
+        self.underscores = fold;
+        self
+    }
+
+    /// Set how apostrophes and single quotes are treated.
+    pub fn apostrophes(mut self, fold: SeparatorFold) -> FuzzyOptions {
+        self.apostrophes = fold;
+        self
+    }
+
+    /// Set whether differences in case are disregarded.
+    pub fn ignore_case(mut self, ignore_case: bool) -> FuzzyOptions {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    fn fold(&self, class: Class) -> SeparatorFold {
+        match class {
+            Class::Whitespace => self.whitespace,
+            Class::Hyphen => self.hyphens,
+            Class::Underscore => self.underscores,
+            Class::Apostrophe => self.apostrophes,
+        }
+    }
+
+    fn push_skeleton(&self, s: &str, out: &mut String) {
+        if self.ignore_case {
+            out.extend(caseless_skeleton_chars(s));
+        } else {
+            out.extend(s.skeleton_chars());
+        }
+    }
+
+    /// Compute the string that `confusable` compares: the skeleton of `s`,
+    /// with separators folded. With `ignore_case`, it is case folded.
+    pub fn skeleton(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        // The start of the text not yet skeletonized, and the class of the
+        // last unified separator if nothing else has been pushed since it.
+        let mut pending = 0;
+        let mut last_class = None;
+        for (i, c) in s.char_indices() {
+            let class = match Class::of(c) {
+                Some(class) if self.fold(class) != SeparatorFold::Keep => class,
+                _ => continue,
+            };
+            if pending < i {
+                self.push_skeleton(&s[pending..i], &mut out);
+                last_class = None;
+            }
+            pending = i + c.len_utf8();
+            if self.fold(class) == SeparatorFold::Unify {
+                if last_class != Some(class) {
+                    out.push(class.representative());
+                }
+                last_class = Some(class);
+            }
+        }
+        self.push_skeleton(&s[pending..], &mut out);
+        out
+    }
+
+    /// Test if two strings are the same under these options.
+    pub fn confusable(&self, a: &str, b: &str) -> bool {
+        self.skeleton(a) == self.skeleton(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FuzzyOptions, SeparatorFold};
+
+    #[test]
+    fn ignores_separators() {
+        let options = FuzzyOptions::new();
+        for name in &["Pay Pal", "pay-pal", "𝔭𝒶𝓎𝓅𝒶𝓁", "pay_pal", "pay‐pal", "PAY—PAL", "pay' pal", "pay’pal"] {
+            assert!(options.confusable(name, "paypal"), "{}", name);
+        }
+        assert_eq!(options.skeleton("Pay - Pal"), "paypal");
+        assert!(!options.confusable("paypa1 x", "paypal"));
+        assert!(!options.ignore_case(false).confusable("Pay Pal", "paypal"));
+        assert!(options.ignore_case(false).confusable("pay pal", "paypal"));
+    }
+
+    #[test]
+    fn folds_separators() {
+        let options = FuzzyOptions::new().hyphens(SeparatorFold::Unify);
+        assert_eq!(options.skeleton("pay‐pal"), "pay-pal");
+        assert!(options.confusable("pay‐pal", "pay-pal"));
+        assert!(options.confusable("pay - pal", "pay—pal"));
+        assert!(options.confusable("pay--pal", "pay-pal"));
+        assert!(!options.confusable("pay-pal", "paypal"));
+        assert!(!options.confusable("pay-pal", "pay_pal"));
+
+        let options = FuzzyOptions::new().whitespace(SeparatorFold::Unify).underscores(SeparatorFold::Keep);
+        assert_eq!(options.skeleton("Pay  \u{3000}Pal"), "pay pal");
+        assert_eq!(options.skeleton("pay_pal"), "pay_pal");
+        assert_eq!(options.skeleton(" - "), " ");
+
+        let options = FuzzyOptions::new().hyphens(SeparatorFold::Keep);
+        // Kept separators are compared by skeleton.
+        assert!(options.confusable("pay‐pal", "pay-pal"));
+        assert!(!options.confusable("pay-pal", "paypal"));
+    }
+}
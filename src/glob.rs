@@ -0,0 +1,125 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matching strings against glob patterns by skeleton.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use search::SkeletonSpanTable;
+use UnicodeSkeleton;
+
+enum Token {
+    // The skeleton of a run of characters other than wildcards.
+    Literal(Vec<char>),
+    // `?`
+    One,
+    // `*`
+    Any,
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        let literal_len = rest.find(['*', '?']).unwrap_or(rest.len());
+        if literal_len > 0 {
+            tokens.push(Token::Literal(rest[..literal_len].skeleton_chars().collect()));
+            rest = &rest[literal_len..];
+        } else {
+            tokens.push(if rest.starts_with('*') { Token::Any } else { Token::One });
+            rest = &rest[1..];
+        }
+    }
+    tokens
+}
+
+/// Test if `s` matches the glob `pattern`, in which `*` matches any run of
+/// characters and `?` matches any one character, and the text between them
+/// is compared by skeleton. There is no way to match `*` or `?` literally.
+///
+/// The whole of `s` must match. Wildcards match whole characters of `s`,
+/// so `?` matches "m", though its skeleton is "rn", and "r*" does not.
+///
+/// # Examples
+/// ```Rust
+/// skeleton_glob_match("admin*", "аdmin-team") // true, with a Cyrillic "а"
+/// skeleton_glob_match("*pay?al*", "my-𝔭𝒶ỿ𝕡𝕒ℓ") // true
+/// skeleton_glob_match("admin*", "my-admin") // false
+/// ```
+pub fn skeleton_glob_match(pattern: &str, s: &str) -> bool {
+    let text = SkeletonSpanTable::new(s);
+    let len = text.chars.len();
+    // The positions in the skeleton of `s` that the tokens so far can end at.
+    let mut reachable = vec![false; len + 1];
+    reachable[0] = true;
+    for token in tokenize(pattern) {
+        let mut next = vec![false; len + 1];
+        match token {
+            Token::Literal(literal) => {
+                for start in (0..=len).filter(|&start| reachable[start]) {
+                    let end = start + literal.len();
+                    if end <= len && text.chars[start..end] == literal[..] && text.starts_char(end) {
+                        next[end] = true;
+                    }
+                }
+            }
+            Token::One => {
+                for start in (0..len).filter(|&start| reachable[start]) {
+                    let end = (start + 1..=len).find(|&end| text.starts_char(end)).unwrap();
+                    next[end] = true;
+                }
+            }
+            Token::Any => {
+                if let Some(first) = reachable.iter().position(|&r| r) {
+                    for (end, next) in next.iter_mut().enumerate().skip(first) {
+                        *next = text.starts_char(end);
+                    }
+                }
+            }
+        }
+        reachable = next;
+    }
+    reachable[len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::skeleton_glob_match;
+
+    #[test]
+    fn wildcards() {
+        assert!(skeleton_glob_match("admin*", "аdmin-team"));
+        assert!(skeleton_glob_match("admin*", "admin"));
+        assert!(!skeleton_glob_match("admin*", "my-admin"));
+        assert!(skeleton_glob_match("*pay?al*", "my-𝔭𝒶ỿ𝕡𝕒ℓ"));
+        assert!(skeleton_glob_match("*pay?al*", "paycal"));
+        assert!(!skeleton_glob_match("*pay?al*", "payal"));
+        assert!(skeleton_glob_match("a*b*c", "abbbc"));
+        assert!(!skeleton_glob_match("a*b*c", "acb"));
+        assert!(skeleton_glob_match("", ""));
+        assert!(!skeleton_glob_match("", "a"));
+        assert!(skeleton_glob_match("*", ""));
+        assert!(skeleton_glob_match("**", "ℝ𝓊𝓈𝓉"));
+        assert!(!skeleton_glob_match("?", ""));
+    }
+
+    #[test]
+    fn wildcards_match_whole_characters() {
+        assert!(skeleton_glob_match("?", "m"));
+        assert!(skeleton_glob_match("?", "é"));
+        assert!(skeleton_glob_match("rn", "m"));
+        assert!(skeleton_glob_match("m", "rn"));
+        assert!(!skeleton_glob_match("r*", "m"));
+        assert!(!skeleton_glob_match("r?", "m"));
+        assert!(skeleton_glob_match("caf?", "café"));
+        assert!(skeleton_glob_match("cafe*", "cafe\u{301}"));
+        assert!(!skeleton_glob_match("cafe*", "café"));
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Identifier status and type of characters, from UTS #39 section 3.1.
+//!
+//! ```Rust
+//! identifier_status('a') // IdentifierStatus::Allowed
+//! identifier_types('ƍ').collect::<Vec<_>>() // [IdentifierType::Obsolete, IdentifierType::Technical]
+//! is_general_security_profile("paypal") // true
+//! check_identifier("pаypаl").problems // [ConfusableWithAscii { ascii: "paypal" }]
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_ident::{is_xid_continue, is_xid_start};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+use data;
+use skeleton;
+
+/// Whether a character may appear in identifiers under the General Security
+/// Profile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IdentifierStatus {
+    /// The character is recommended or specifically included for identifiers.
+    Allowed,
+    /// The character is excluded from identifiers by the profile.
+    Restricted,
+}
+
+/// Why a character is, or is not, allowed in identifiers. A character can have
+/// several types; see `identifier_types`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IdentifierType {
+    /// Unassigned, a surrogate, a private use character or a noncharacter.
+    NotCharacter,
+    /// Deprecated in the Unicode Standard.
+    Deprecated,
+    /// Default ignorable, such as a zero width space.
+    DefaultIgnorable,
+    /// Changed by NFKC normalization.
+    NotNfkc,
+    /// Not allowed by the XID_Continue property.
+    NotXid,
+    /// In a script excluded from identifiers by UAX #31.
+    Exclusion,
+    /// No longer in modern customary use.
+    Obsolete,
+    /// Used in specialized technical contexts.
+    Technical,
+    /// Not in common modern use.
+    UncommonUse,
+    /// In a script in limited use by its communities.
+    LimitedUse,
+    /// Specifically included for identifiers, such as the hyphen.
+    Inclusion,
+    /// In a script recommended for identifiers by UAX #31.
+    Recommended,
+}
+
+// In order of bit position in the generated masks.
+const IDENTIFIER_TYPES: [IdentifierType; 12] = [
+    IdentifierType::NotCharacter, IdentifierType::Deprecated, IdentifierType::DefaultIgnorable,
+    IdentifierType::NotNfkc, IdentifierType::NotXid, IdentifierType::Exclusion,
+    IdentifierType::Obsolete, IdentifierType::Technical, IdentifierType::UncommonUse,
+    IdentifierType::LimitedUse, IdentifierType::Inclusion, IdentifierType::Recommended,
+];
+
+/// Look up the identifier status of a character.
+pub fn identifier_status(c: char) -> IdentifierStatus {
+    match data::range_containing(&data::ALLOWED_RANGES, c) {
+        Some(_) => IdentifierStatus::Allowed,
+        None => IdentifierStatus::Restricted,
+    }
+}
+
+/// Look up the identifier types of a character. Unassigned code points,
+/// surrogates and noncharacters have the single type `NotCharacter`.
+///
+/// The identifier data is still that of Unicode Security Mechanisms 14.0.0,
+/// so characters assigned since, such as those of Kawi, are `NotCharacter`
+/// here and `Restricted` to `identifier_status`.
+pub fn identifier_types(c: char) -> IdentifierTypes {
+    let c = c as u32;
+    let not_character = IdentifierTypes(1 << IdentifierType::NotCharacter as u16);
+    let index = match data::IDENTIFIER_TYPE_RANGES.binary_search_by_key(&c, |range| range.0) {
+        Ok(index) => index,
+        Err(0) => return not_character,
+        Err(index) => index - 1,
+    };
+    let (_, end, mask) = data::IDENTIFIER_TYPE_RANGES[index];
+    if c <= end { IdentifierTypes(mask) } else { not_character }
+}
+
+/// Test whether every character of a string is `Allowed`, which is the
+/// General Security Profile for identifiers.
+pub fn is_general_security_profile(s: &str) -> bool {
+    s.chars().all(|c| identifier_status(c) == IdentifierStatus::Allowed)
+}
+
+/// The identifier types of a character, iterated in the order they are
+/// declared in `IdentifierType`. This is retrieved via `identifier_types`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IdentifierTypes(u16);
+
+impl IdentifierTypes {
+    /// Test whether a type is among these.
+    pub fn contains(&self, identifier_type: IdentifierType) -> bool {
+        self.0 & (1 << identifier_type as u16) != 0
+    }
+}
+
+impl Iterator for IdentifierTypes {
+    type Item = IdentifierType;
+
+    fn next(&mut self) -> Option<IdentifierType> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros();
+        self.0 &= !(1 << bit);
+        Some(IDENTIFIER_TYPES[bit as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for IdentifierTypes {}
+
+/// A reason that `check_identifier` found an identifier unsuitable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdentifierProblem {
+    /// The identifier is empty.
+    Empty,
+    /// The first character is neither XID_Start nor "_".
+    InvalidStart {
+        /// The character.
+        c: char,
+    },
+    /// A character after the first is not XID_Continue.
+    InvalidContinue {
+        /// The byte offset of the character in the identifier.
+        byte_offset: usize,
+        /// The character.
+        c: char,
+    },
+    /// The identifier is not in NFC, so the same-looking identifier could be
+    /// spelled with different characters.
+    NotNfc {
+        /// The identifier in NFC.
+        nfc: String,
+    },
+    /// A character is `Restricted` under the General Security Profile.
+    Restricted {
+        /// The byte offset of the character in the identifier.
+        byte_offset: usize,
+        /// The character.
+        c: char,
+        /// Why it is restricted.
+        types: IdentifierTypes,
+    },
+    /// The identifier is not ASCII, but its skeleton is, so it can be
+    /// mistaken for an ASCII identifier.
+    ConfusableWithAscii {
+        /// The ASCII identifier it can be mistaken for.
+        ascii: String,
+    },
+}
+
+/// The results of `check_identifier`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifierReport {
+    /// The problems found, in the order of the variants of
+    /// `IdentifierProblem`, and of the characters responsible.
+    pub problems: Vec<IdentifierProblem>,
+}
+
+impl IdentifierReport {
+    /// Test whether no problems were found.
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Check an identifier of a programming language in one call, for the
+/// problems that UAX #31 and UTS #39 describe: characters that are not
+/// XID_Start at the start or XID_Continue after it, a form other than NFC,
+/// characters outside the General Security Profile, and a skeleton that is
+/// ASCII when the identifier is not.
+///
+/// "_" is accepted at the start, as it is in most languages. Characters that
+/// are not XID are only reported as such, not also as `Restricted`.
+///
+/// # Examples
+/// ```Rust
+/// check_identifier("größe").is_clean() // true
+/// check_identifier("pаypаl").problems // [ConfusableWithAscii { ascii: "paypal" }]
+/// check_identifier("1x").problems // [InvalidStart { c: '1' }]
+/// ```
+pub fn check_identifier(s: &str) -> IdentifierReport {
+    let mut problems = Vec::new();
+    let mut chars = s.char_indices();
+    let mut invalid = Vec::new();
+    match chars.next() {
+        None => problems.push(IdentifierProblem::Empty),
+        Some((_, c)) if !is_xid_start(c) && c != '_' => {
+            problems.push(IdentifierProblem::InvalidStart { c });
+            invalid.push(0);
+        }
+        Some(_) => {}
+    }
+    for (byte_offset, c) in chars.filter(|&(_, c)| !is_xid_continue(c)) {
+        problems.push(IdentifierProblem::InvalidContinue { byte_offset, c });
+        invalid.push(byte_offset);
+    }
+    if !is_nfc(s) {
+        problems.push(IdentifierProblem::NotNfc { nfc: s.nfc().collect() });
+    }
+    for (byte_offset, c) in s.char_indices() {
+        if identifier_status(c) == IdentifierStatus::Restricted && !invalid.contains(&byte_offset) {
+            problems.push(IdentifierProblem::Restricted { byte_offset, c, types: identifier_types(c) });
+        }
+    }
+    if !s.is_ascii() {
+        let ascii = skeleton(s);
+        if ascii.is_ascii() {
+            problems.push(IdentifierProblem::ConfusableWithAscii { ascii });
+        }
+    }
+    IdentifierReport { problems }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{check_identifier, identifier_status, identifier_types, is_general_security_profile, IdentifierProblem, IdentifierStatus, IdentifierType};
+
+    #[test]
+    fn status() {
+        assert_eq!(identifier_status('a'), IdentifierStatus::Allowed);
+        assert_eq!(identifier_status('-'), IdentifierStatus::Allowed);
+        assert_eq!(identifier_status(' '), IdentifierStatus::Restricted);
+        assert_eq!(identifier_status('ſ'), IdentifierStatus::Restricted);
+        assert_eq!(identifier_status('\u{10FFFF}'), IdentifierStatus::Restricted);
+    }
+
+    #[test]
+    fn types() {
+        assert_eq!(identifier_types('a').collect::<Vec<_>>(), vec![IdentifierType::Recommended]);
+        assert_eq!(identifier_types('ſ').collect::<Vec<_>>(), vec![IdentifierType::NotNfkc]);
+        assert_eq!(identifier_types('ƍ').collect::<Vec<_>>(), vec![IdentifierType::Obsolete, IdentifierType::Technical]);
+        assert_eq!(identifier_types('\u{378}').collect::<Vec<_>>(), vec![IdentifierType::NotCharacter]);
+        assert!(identifier_types('\u{200B}').contains(IdentifierType::DefaultIgnorable));
+        assert_eq!(identifier_types('ƍ').len(), 2);
+    }
+
+    #[test]
+    fn general_security_profile() {
+        assert!(is_general_security_profile("paypal"));
+        assert!(is_general_security_profile("παράδειγμα"));
+        assert!(!is_general_security_profile("pay pal"));
+        assert!(!is_general_security_profile("ℝust"));
+    }
+
+    #[test]
+    fn identifiers() {
+        assert!(check_identifier("größe").is_clean());
+        assert!(check_identifier("_private").is_clean());
+        assert!(check_identifier("παράδειγμα").is_clean());
+        assert_eq!(check_identifier("pаypаl").problems, vec![
+            IdentifierProblem::ConfusableWithAscii { ascii: "paypal".to_string() },
+        ]);
+        assert_eq!(check_identifier("").problems, vec![IdentifierProblem::Empty]);
+        assert_eq!(check_identifier("1x-y").problems, vec![
+            IdentifierProblem::InvalidStart { c: '1' },
+            IdentifierProblem::InvalidContinue { byte_offset: 2, c: '-' },
+        ]);
+        assert_eq!(check_identifier("e\u{301}").problems, vec![
+            IdentifierProblem::NotNfc { nfc: "\u{e9}".to_string() },
+        ]);
+        let problems = check_identifier("ſtop").problems;
+        assert_eq!(problems.len(), 2);
+        match problems[0] {
+            IdentifierProblem::Restricted { byte_offset: 0, c: 'ſ', types } => assert!(types.contains(IdentifierType::NotNfkc)),
+            ref problem => panic!("{:?}", problem),
+        }
+        assert_eq!(problems[1], IdentifierProblem::ConfusableWithAscii { ascii: "ftop".to_string() });
+    }
+}
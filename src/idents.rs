@@ -0,0 +1,220 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Linting the identifiers of a program for ones that are confusable with
+//! each other, as rustc's `confusable_idents` lint does.
+//!
+//! ```Rust
+//! let idents = vec![("x", 1), ("y", 2), ("х", 3), ("x", 4)]; // the third is Cyrillic
+//! let groups = confusable_groups(idents);
+//! groups[0].members // [("x", 1), ("х", 3), ("x", 4)]
+//! groups[0].identifiers() // ["x", "х"]
+//! ```
+//!
+//! `confusable_diagnostics` turns the groups into one report per confusable
+//! name, with the spans of the name and the one it imitates.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use Skeleton;
+
+/// Identifiers that share a skeleton, along with the data they were given
+/// with. This is retrieved via `confusable_groups`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfusableGroup<S, T> {
+    /// The skeleton the identifiers share.
+    pub skeleton: Skeleton,
+    /// Every identifier with the skeleton, including repeats of the same
+    /// identifier, with its data, in input order.
+    pub members: Vec<(S, T)>,
+}
+
+impl<S: AsRef<str>, T> ConfusableGroup<S, T> {
+    /// The distinct identifiers of the group, in order of first appearance.
+    /// There are always at least two.
+    pub fn identifiers(&self) -> Vec<&str> {
+        let mut identifiers: Vec<&str> = Vec::new();
+        for (identifier, _) in &self.members {
+            let identifier = identifier.as_ref();
+            if !identifiers.contains(&identifier) {
+                identifiers.push(identifier);
+            }
+        }
+        identifiers
+    }
+
+    /// Whether every identifier of the group is ASCII. Like rustc, a
+    /// language may choose not to report these, as the only ASCII
+    /// confusables are ones such as "rn" and "m" that readers know to
+    /// look out for.
+    pub fn is_ascii(&self) -> bool {
+        self.members.iter().all(|(identifier, _)| identifier.as_ref().is_ascii())
+    }
+}
+
+/// Group identifiers that are confusable with each other, in one pass over
+/// `idents`. Each identifier comes with data of the caller's choosing, such
+/// as where it appears.
+///
+/// Only groups with at least two different identifiers are returned, so an
+/// identifier that is used many times is not confusable with itself. Groups
+/// are ordered by the first appearance of their skeleton, and members keep
+/// their input order, so results are the same from run to run.
+///
+/// # Examples
+/// ```Rust
+/// let groups = confusable_groups(vec![("scope", 0), ("ѕсоре", 1), ("x", 2)]);
+/// groups.len() // 1
+/// groups[0].identifiers() // ["scope", "ѕсоре"]
+/// ```
+pub fn confusable_groups<I, S, T>(idents: I) -> Vec<ConfusableGroup<S, T>>
+    where I: IntoIterator<Item = (S, T)>, S: AsRef<str>
+{
+    let mut group_indices = HashMap::new();
+    // Each group, and whether it has an identifier different from its first.
+    let mut groups: Vec<(ConfusableGroup<S, T>, bool)> = Vec::new();
+    for (identifier, data) in idents {
+        let skeleton = Skeleton::of(identifier.as_ref());
+        let next_index = groups.len();
+        let index = *group_indices.entry(skeleton.clone()).or_insert(next_index);
+        if index == next_index {
+            groups.push((ConfusableGroup { skeleton, members: Vec::new() }, false));
+        }
+        let (group, confusable) = &mut groups[index];
+        if let Some((first, _)) = group.members.first() {
+            *confusable |= first.as_ref() != identifier.as_ref();
+        }
+        group.members.push((identifier, data));
+    }
+    groups.into_iter().filter(|&(_, confusable)| confusable).map(|(group, _)| group).collect()
+}
+
+/// A report that a name is confusable with an earlier one, for a compiler
+/// to show at `span`. This is retrieved via `confusable_diagnostics`.
+///
+/// Displaying it gives a message naming the characters responsible, such as
+/// "identifier `х` (U+0445) is confusable with `x`", to which the spans can
+/// be added in whatever form the compiler uses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfusableDiagnostic<S, Span> {
+    /// The name, where it first appears.
+    pub name: S,
+    /// Where it first appears.
+    pub span: Span,
+    /// The earlier name it is confusable with, the first of its group.
+    pub original: S,
+    /// Where `original` first appears.
+    pub original_span: Span,
+    /// The characters of `name` that do not appear in `original`, in order.
+    pub chars: Vec<char>,
+}
+
+impl<S: AsRef<str>, Span> fmt::Display for ConfusableDiagnostic<S, Span> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "identifier `{}` (", self.name.as_ref())?;
+        for (i, c) in self.chars.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "U+{:04X}", *c as u32)?;
+        }
+        write!(f, ") is confusable with `{}`", self.original.as_ref())
+    }
+}
+
+/// Pair each name that is confusable with an earlier, different name with
+/// that name, along with the spans of both, so a compiler can report them
+/// without looking its results up again. `Span` can be any type, such as a
+/// byte range or a line and column.
+///
+/// Names are grouped as by `confusable_groups`. Each different name of a
+/// group after the first is reported once, where it first appears, against
+/// the first appearance of the first name. Diagnostics are in the order of
+/// the groups, then of the names.
+///
+/// # Examples
+/// ```Rust
+/// let diagnostics = confusable_diagnostics(vec![("x", 10), ("y", 11), ("х", 12), ("х", 13)]);
+/// diagnostics[0].span // 12
+/// diagnostics[0].original_span // 10
+/// diagnostics[0].to_string() // "identifier `х` (U+0445) is confusable with `x`"
+/// ```
+pub fn confusable_diagnostics<I, S, Span>(items: I) -> Vec<ConfusableDiagnostic<S, Span>>
+    where I: IntoIterator<Item = (S, Span)>, S: AsRef<str> + Clone, Span: Clone
+{
+    let mut diagnostics = Vec::new();
+    for group in confusable_groups(items) {
+        let mut members = group.members.into_iter();
+        let (original, original_span) = match members.next() {
+            Some(first) => first,
+            None => continue,
+        };
+        let mut reported: Vec<S> = Vec::new();
+        for (name, span) in members {
+            let (text, original_text) = (name.as_ref(), original.as_ref());
+            if text == original_text || reported.iter().any(|earlier| earlier.as_ref() == text) {
+                continue;
+            }
+            let mut chars: Vec<char> = Vec::new();
+            for c in text.chars().filter(|&c| !original_text.contains(c)) {
+                if !chars.contains(&c) {
+                    chars.push(c);
+                }
+            }
+            reported.push(name.clone());
+            diagnostics.push(ConfusableDiagnostic {
+                name,
+                span,
+                original: original.clone(),
+                original_span: original_span.clone(),
+                chars,
+            });
+        }
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{confusable_diagnostics, confusable_groups, ConfusableDiagnostic};
+
+    #[test]
+    fn groups_confusables() {
+        let idents = vec![("x", 1), ("y", 2), ("х", 3), ("x", 4), ("y", 5), ("scope", 6), ("ѕсоре", 7)];
+        let groups = confusable_groups(idents);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].members, vec![("x", 1), ("х", 3), ("x", 4)]);
+        assert_eq!(groups[0].identifiers(), vec!["x", "х"]);
+        assert_eq!(groups[0].skeleton.as_str(), "x");
+        assert!(!groups[0].is_ascii());
+        assert_eq!(groups[1].identifiers(), vec!["scope", "ѕсоре"]);
+
+        let groups = confusable_groups(vec![("rn".to_string(), ()), ("m".to_string(), ())]);
+        assert!(groups[0].is_ascii());
+        assert!(confusable_groups(vec![("a", 0), ("a", 1)]).is_empty());
+        assert!(confusable_groups(Vec::<(&str, ())>::new()).is_empty());
+    }
+
+    #[test]
+    fn diagnostics() {
+        let items = vec![("x", 10), ("y", 11), ("х", 12), ("х", 13), ("scope", 14), ("ѕсоре", 15), ("ѕcope", 16)];
+        let diagnostics = confusable_diagnostics(items);
+        assert_eq!(diagnostics[0], ConfusableDiagnostic { name: "х", span: 12, original: "x", original_span: 10, chars: vec!['\u{445}'] });
+        assert_eq!(diagnostics[0].to_string(), "identifier `х` (U+0445) is confusable with `x`");
+        assert_eq!(diagnostics.iter().map(|diagnostic| (diagnostic.name, diagnostic.span)).collect::<Vec<_>>(),
+                   vec![("х", 12), ("ѕсоре", 15), ("ѕcope", 16)]);
+        assert_eq!(diagnostics[1].to_string(), "identifier `ѕсоре` (U+0455, U+0441, U+043E, U+0440, U+0435) is confusable with `scope`");
+
+        // Spans can be of any type.
+        let spans = confusable_diagnostics(vec![("rn".to_string(), (1, 5)..(1, 7)), ("m".to_string(), (3, 1)..(3, 2))]);
+        assert_eq!(spans[0].original_span, (1, 5)..(1, 7));
+        assert!(confusable_diagnostics(vec![("a", 0), ("a", 1)]).is_empty());
+    }
+}
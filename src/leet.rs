@@ -0,0 +1,133 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An opt-in table of ASCII lookalikes, layered over the confusables table.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use caseless_skeleton_chars;
+
+/// Sequences that confusables.txt deliberately leaves distinct, each with the
+/// sequence it is mistaken for. Both sides are written as they appear in
+/// case folded skeletons: for example, 'm' has the skeleton "rn", so "nn" is
+/// mapped to "rn" rather than "m". Longer sequences come first, so that they
+/// take precedence.
+static LEET_SEQUENCES: [(&str, &str); 22] = [
+    ("()", "o"),
+    ("><", "x"),
+    ("c\u{338}", "c"),
+    ("cl", "d"),
+    ("l<", "k"),
+    ("nn", "rn"),
+    ("vv", "w"),
+    ("!", "i"),
+    ("$", "s"),
+    ("(", "c"),
+    ("+", "t"),
+    ("2", "z"),
+    ("3", "e"),
+    ("4", "a"),
+    ("5", "s"),
+    ("6", "b"),
+    ("7", "t"),
+    ("8", "b"),
+    ("9", "g"),
+    ("<", "c"),
+    ("@", "a"),
+    ("ꞓ", "e"),
+];
+
+/// Compute an aggressive skeleton of `s`, for vetting usernames and domain
+/// names: the skeleton with case disregarded, as in `confusable_ignore_case`,
+/// with common ASCII lookalikes such as "4" for "a", "vv" for "w", and "cl"
+/// for "d" then replaced as well. The result is case folded.
+///
+/// These lookalikes are excluded from confusables.txt because they would
+/// make ordinary text confusable with too much else, so this is only
+/// suitable where false positives are acceptable.
+///
+/// # Examples
+/// ```Rust
+/// leet_skeleton("P4YP4L") // "paypal"
+/// leet_skeleton("vvikipedia") // "wikipedia"
+/// ```
+pub fn leet_skeleton(s: &str) -> String {
+    let skeleton: String = caseless_skeleton_chars(s).collect();
+    let mut out = String::with_capacity(skeleton.len());
+    let mut rest = &skeleton[..];
+    while let Some(c) = rest.chars().next() {
+        match LEET_SEQUENCES.iter().find(|&&(from, _)| rest.starts_with(from)) {
+            Some(&(from, to)) => {
+                out.push_str(to);
+                rest = &rest[from.len()..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+/// Test if two strings have the same `leet_skeleton`.
+///
+/// # Examples
+/// ```Rust
+/// confusable_leet("g00gle", "Google") // true
+/// confusable_leet("pay", "play") // false
+/// ```
+pub fn confusable_leet(a: &str, b: &str) -> bool {
+    leet_skeleton(a) == leet_skeleton(b)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{confusable_leet, leet_skeleton, LEET_SEQUENCES};
+    use {caseless_skeleton_chars, confusable};
+
+    #[test]
+    fn table_is_well_formed() {
+        for window in LEET_SEQUENCES.windows(2) {
+            assert!(window[0].0.chars().count() >= window[1].0.chars().count());
+        }
+        // Both sides are in case folded skeleton form, and replacements are
+        // left alone by another pass through the table.
+        for &(from, to) in LEET_SEQUENCES.iter() {
+            assert_eq!(caseless_skeleton_chars(from).collect::<String>(), from);
+            assert_eq!(caseless_skeleton_chars(to).collect::<String>(), to);
+            assert_eq!(leet_skeleton(to), to);
+        }
+    }
+
+    #[test]
+    fn lookalikes() {
+        assert_eq!(leet_skeleton("P4YP4L"), "paypal");
+        assert_eq!(leet_skeleton("vvikipedia"), "wikipedia");
+        assert_eq!(leet_skeleton("c1ient"), "dient");
+        assert_eq!(leet_skeleton("€"), "e");
+        assert_eq!(leet_skeleton("¢"), "c");
+        assert!(!confusable("g00gle", "Google"));
+        assert!(confusable_leet("g00gle", "Google"));
+        assert!(confusable_leet("arnazon", "amazon"));
+        assert!(confusable_leet("$73AM", "steam"));
+        assert!(!confusable_leet("pay", "play"));
+    }
+
+    #[test]
+    fn includes_standard_confusables() {
+        assert!(confusable_leet("ℝ𝓊𝓈𝓉", "rust"));
+        assert!(confusable_leet("pаypаl", "PAYPAL"));
+        assert_eq!(leet_skeleton(""), "");
+    }
+}
@@ -14,6 +14,15 @@
 //! This simplified string is called the "skeleton".
 //!
 //! ```Rust
+//! use unicode_skeleton::skeleton;
+//!
+//! skeleton("ℝ𝓊𝓈𝓉") // "Rust"
+//! ```
+//!
+//! The characters of the skeleton can also be retrieved one at a time,
+//! without allocating, via the `UnicodeSkeleton` trait.
+//!
+//! ```Rust
 //! use unicode_skeleton::UnicodeSkeleton;
 //!
 //! "ℝ𝓊𝓈𝓉".skeleton_chars().collect::<String>() // "Rust"
@@ -29,56 +38,464 @@
 //! ```
 //!
 //! The translation to skeletons is based on
-//! Unicode Security Mechanisms for UTR #39 version 10.0.0.
+//! Unicode Security Mechanisms for UTR #39 version 16.0.0; see `UNICODE_VERSION`.
+//!
+//! The crate can be built without the standard library, needing only `core`
+//! and `alloc`, by disabling the default `std` feature. The skeleton and
+//! confusable functions are all available then; what needs the standard
+//! library is listed with the `std` feature in Cargo.toml.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
+extern crate tinyvec;
 extern crate unicode_normalization;
+extern crate unicode_ident;
+#[cfg(feature = "aho-corasick")]
+extern crate aho_corasick;
+#[cfg(feature = "idna")]
+extern crate idna;
+#[cfg(feature = "url")]
+extern crate url;
+#[cfg(feature = "psl")]
+extern crate psl;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
+#[cfg(feature = "futures")]
+extern crate futures;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(test)]
+extern crate regex;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(feature = "macros")]
+extern crate unicode_skeleton_macros;
+#[cfg(feature = "sqlx")]
+extern crate sqlx;
+#[cfg(feature = "diesel")]
+extern crate diesel;
+#[cfg(feature = "python")]
+extern crate pyo3;
+#[cfg(feature = "node")]
+extern crate napi;
+#[cfg(feature = "node")]
+extern crate napi_derive;
+// PyO3's and napi-rs's macros refer to `::core`, which in this edition means
+// the crate root.
+#[cfg(any(feature = "python", feature = "node"))]
+extern crate core;
 
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::borrow::Cow;
 use std::char;
-use std::iter::FlatMap;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hasher;
+#[cfg(feature = "std")]
+use std::io;
+use std::rc::Rc;
+use std::iter::FusedIterator;
 use std::slice;
 use std::str::Chars;
-use std::option;
+use std::sync::Arc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+use tinyvec::TinyVec;
 
-use unicode_normalization::Decompositions;
-use unicode_normalization::UnicodeNormalization;
+use ascii::push_skeleton_str;
+use decompose::{SourceDecompositions, MAX_PROTOTYPE_SKELETON_LEN};
 
+// Stands in for the standard library without the `std` feature, so that
+// `std::` paths throughout the crate resolve to `core` and `alloc`.
+#[cfg(not(feature = "std"))]
+mod std {
+    pub use core::*;
+    pub use alloc::{borrow, collections, fmt, rc, slice, str, string, sync, vec};
+}
+
+// The parts of the standard prelude that come from `alloc`.
+#[cfg(not(feature = "std"))]
+mod alloc_prelude {
+    pub use alloc::boxed::Box;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+}
+
+mod ascii;
+mod bidi;
+#[cfg(feature = "std")]
+mod cached;
 mod data;
+mod decode;
+mod decompose;
+mod domain;
+mod email;
+mod fuzzy;
+mod glob;
+mod leet;
+#[cfg(feature = "aho-corasick")]
+mod matcher;
+mod mode;
+mod options;
+mod regex_class;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod paths;
+mod protect;
+mod search;
+mod severity;
+mod spans;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+mod sql;
+mod summary;
+mod table;
+mod typosquat;
+#[cfg(feature = "url")]
+mod url_check;
+mod variants;
+mod version;
+pub mod checker;
+#[cfg(feature = "std")]
+pub mod collections;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod identifier;
+#[cfg(feature = "std")]
+pub mod idents;
+#[cfg(feature = "node")]
+pub mod node;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod scripts;
+pub mod source_scan;
+pub mod stream;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use scripts::Script;
+#[cfg(feature = "std")]
+pub use cached::CachedSkeleton;
+pub use bidi::{bidi_controls, has_unbalanced_bidi, is_bidi_control, BidiControl};
+pub use decode::{skeleton_chars_lossy, Utf8LossyChars};
+pub use decode::{skeleton_chars_utf16, skeleton_chars_utf16_strict, Utf16Chars, UnpairedSurrogates};
+#[cfg(feature = "idna")]
+pub use domain::confusable_domains;
+pub use domain::{check_domain, normalize_separators, DomainChecker, DomainReport, Impersonation, LabelReport};
+pub use email::{check_email, confusable_emails, EmailOptions, EmailReport};
+pub use fuzzy::{FuzzyOptions, SeparatorFold};
+pub use glob::skeleton_glob_match;
+pub use leet::{confusable_leet, leet_skeleton};
+#[cfg(feature = "aho-corasick")]
+pub use matcher::{ConfusableMatcher, ConfusableMatcherMatches};
+pub use mode::ConfusableMode;
+pub use regex_class::confusable_regex;
+pub use options::{confusable_with, SkeletonCharsWith, SkeletonOptions};
+#[cfg(feature = "rayon")]
+pub use parallel::{par_find_confusable_pairs, par_skeletons};
+#[cfg(feature = "std")]
+pub use paths::{confusable_paths, skeleton_chars_strict};
+pub use protect::{ProtectedTermHit, ProtectedTerms};
+pub use search::{find_confusable, find_confusables, ConfusableMatches};
+pub use severity::{confusability, is_intentionally_confusable, whole_script_confusables, Confusability, ConfusableKind};
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+pub use sql::SkeletonNormalized;
+pub use spans::{skeleton_spans, skeleton_diff, SkeletonSpans, Substitution};
+pub use summary::SkeletonSummary;
+pub use table::{ConfusableTable, ParseError, ParseErrorKind};
+pub use typosquat::{typosquat_score, Typosquat, TyposquatKind};
+#[cfg(feature = "url")]
+pub use url_check::{check_url, UrlIssue, UrlReport};
+pub use variants::{homoglyph_variants, MAX_VARIANT_SKELETON_LEN};
+pub use version::DataVersion;
+#[cfg(feature = "macros")]
+pub use unicode_skeleton_macros::skeleton;
 
-enum PrototypeCharsIterator {
+#[derive(Clone)]
+enum PrototypeCharsIterator<'a> {
     One(Option<char>),
-    Slice(slice::Iter<'static, char>),
-}
-
-impl PrototypeCharsIterator {
-    pub fn new(c: char) -> PrototypeCharsIterator {
-        if let Ok(input_index) = data::INPUT_AND_OUTPUT_INDICES.binary_search_by_key(&(c as u32), |entry| entry.0) {
-            let output_index_start = data::INPUT_AND_OUTPUT_INDICES[input_index].1 as usize;
-            let output_index_end = data::INPUT_AND_OUTPUT_INDICES.get(input_index+1).map(|x| x.1 as usize).unwrap_or(data::OUTPUTS.len());
-            let prototype_chars = &data::OUTPUTS[output_index_start..output_index_end];
-            PrototypeCharsIterator::Slice(prototype_chars.iter())
-        } else {
-            PrototypeCharsIterator::One(Some(c))
+    Slice(slice::Iter<'a, char>),
+}
+
+/// Look up the prototype that a single character maps to in the confusables table.
+///
+/// Returns `None` for characters that are their own prototype. The prototype is
+/// in NFD, as `skeleton_chars` emits it; `skeleton_chars` additionally applies
+/// NFD to its input before this mapping.
+///
+/// # Examples
+/// ```Rust
+/// prototype_of('ℝ') // Some(['R'])
+/// prototype_of('m') // Some(['r', 'n'])
+/// prototype_of('a') // None
+/// ```
+pub const fn prototype_of(c: char) -> Option<&'static [char]> {
+    data::CONFUSABLES.lookup(c)
+}
+
+/// The version of Unicode Security Mechanisms for UTR #39 whose confusables
+/// table is compiled into the crate, as (major, minor, update).
+///
+/// Skeletons can change between versions, so applications that store them
+/// should record this alongside, and recompute them when it changes, or pin
+/// the version they were computed with via `DataVersion`.
+///
+/// This is 16.0.0, unless the crate was built with the
+/// `UNICODE_SKELETON_CONFUSABLES` environment variable naming a different
+/// confusables.txt, in which case it is the version given in that file.
+pub const UNICODE_VERSION: (u8, u8, u8) = data::CONFUSABLES_VERSION;
+
+/// The version of the confusables table that skeletons are computed with, as
+/// (major, minor, update).
+///
+/// ```Rust
+/// data_version() // (16, 0, 0)
+/// ```
+pub fn data_version() -> (u8, u8, u8) {
+    UNICODE_VERSION
+}
+
+/// Iterate over every entry of the confusables table, as pairs of a character
+/// and the prototype it maps to, in order of code point.
+pub fn data_entries() -> DataEntries {
+    DataEntries(data::CONFUSABLES.entries())
+}
+
+/// An iterator over the entries of the confusables table. This is retrieved
+/// via `data_entries`.
+#[derive(Clone)]
+pub struct DataEntries(data::Entries<'static>);
+
+impl Iterator for DataEntries {
+    type Item = (char, &'static [char]);
+
+    fn next(&mut self) -> Option<(char, &'static [char])> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for DataEntries {}
+
+impl fmt::Debug for DataEntries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DataEntries")
+            .field("remaining", &self.0.len())
+            .finish()
+    }
+}
+
+/// Test whether a character is replaced by a different prototype when
+/// computing skeletons, either directly or through one of the characters of
+/// its canonical decomposition.
+///
+/// This is a cheap check that avoids running the full skeleton pipeline. Like
+/// `prototype_of`, it can be called in a const context.
+///
+/// # Examples
+/// ```Rust
+/// is_confusable_char('\u{430}') // true, CYRILLIC SMALL LETTER A
+/// is_confusable_char('a') // false
+/// ```
+pub const fn is_confusable_char(c: char) -> bool {
+    data::range_containing(data::CONFUSABLE_CHAR_RANGES, c).is_some()
+}
+
+impl<'a> PrototypeCharsIterator<'a> {
+    /// The prototype of `c`, which is `c` itself if `prototype` is `None`.
+    /// Prototypes are stored in NFD, so if `c` is decomposed, so is this.
+    fn new(c: char, prototype: Option<&'a [char]>) -> PrototypeCharsIterator<'a> {
+        match prototype {
+            Some(prototype_chars) => PrototypeCharsIterator::Slice(prototype_chars.iter()),
+            None => PrototypeCharsIterator::One(Some(c)),
         }
     }
+
+    fn empty() -> PrototypeCharsIterator<'a> {
+        PrototypeCharsIterator::One(None)
+    }
 }
 
-impl Iterator for PrototypeCharsIterator {
+impl<'a> Iterator for PrototypeCharsIterator<'a> {
     type Item = char;
 
     fn next(&mut self) -> Option<char> {
-        match self {
-            &mut PrototypeCharsIterator::One(ref mut x) => x.take(),
-            &mut PrototypeCharsIterator::Slice(ref mut xs) => xs.next().map(|c| *c),
+        match *self {
+            PrototypeCharsIterator::One(ref mut x) => x.take(),
+            PrototypeCharsIterator::Slice(ref mut xs) => xs.next().cloned(),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            PrototypeCharsIterator::One(ref x) => (x.is_some() as usize, Some(x.is_some() as usize)),
+            PrototypeCharsIterator::Slice(ref xs) => xs.size_hint(),
+        }
+    }
+}
+
+
+/// Compute the skeleton of a string or other `char` sequence as a `String`.
+///
+/// This is shorthand for collecting `skeleton_chars()`.
+///
+/// # Examples
+/// ```Rust
+/// skeleton("𝔭𝒶ỿ𝕡𝕒ℓ") // "paypal"
+/// ```
+pub fn skeleton<S>(s: S) -> String
+    where S: UnicodeSkeleton
+{
+    let mut out = String::new();
+    s.push_skeleton(&mut out);
+    out
+}
+
+/// Count the characters of the skeleton of a string or other `char` sequence,
+/// without allocating it.
+pub fn skeleton_len<S>(s: S) -> usize
+    where S: UnicodeSkeleton
+{
+    s.skeleton_chars().count()
+}
+
+/// Count the bytes of the UTF-8 encoded skeleton of a string or other `char`
+/// sequence, without allocating it. This is the length `skeleton` would return.
+pub fn skeleton_len_utf8<S>(s: S) -> usize
+    where S: UnicodeSkeleton
+{
+    s.skeleton_chars().map(char::len_utf8).sum()
+}
+
+/// Replace the contents of `buf` with the skeleton of a string or other `char`
+/// sequence, reusing `buf`'s allocation.
+///
+/// # Examples
+/// ```Rust
+/// let mut buf = String::new();
+/// for name in names {
+///     skeleton_into(name, &mut buf);
+///     // ...
+/// }
+/// ```
+pub fn skeleton_into<S>(s: S, buf: &mut String)
+    where S: UnicodeSkeleton
+{
+    buf.clear();
+    s.push_skeleton(buf);
+}
+
+/// Compute the UTF-8 encoded skeleton of `s` into a `SmallVec`, so that
+/// skeletons of up to 32 bytes do not allocate.
+///
+/// Requires the `smallvec` feature.
+#[cfg(feature = "smallvec")]
+pub fn skeleton_small(s: &str) -> SmallVec<[u8; 32]> {
+    let mut out = SmallVec::new();
+    let mut buf = [0u8; 4];
+    for c in s.skeleton_chars() {
+        out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+    }
+    out
+}
+
+/// Write the skeleton of a string or other `char` sequence into a `fmt::Write`
+/// sink, without allocating.
+pub fn write_skeleton<S, W>(s: S, out: &mut W) -> fmt::Result
+    where S: UnicodeSkeleton, W: fmt::Write + ?Sized
+{
+    for c in s.skeleton_chars() {
+        out.write_char(c)?;
+    }
+    Ok(())
+}
+
+/// Write the skeleton of a string or other `char` sequence, encoded as UTF-8,
+/// into an `io::Write` sink, without allocating.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn write_skeleton_io<S, W>(s: S, out: &mut W) -> io::Result<()>
+    where S: UnicodeSkeleton, W: io::Write + ?Sized
+{
+    let mut buf = [0u8; 4];
+    for c in s.skeleton_chars() {
+        out.write_all(c.encode_utf8(&mut buf).as_bytes())?;
+    }
+    Ok(())
 }
 
-type DecompositionsToPrototypeChars<I> = FlatMap<Decompositions<I>, PrototypeCharsIterator, fn(char) -> PrototypeCharsIterator>;
-type DecomposeSingleChar = Decompositions<option::IntoIter<char>>;
+/// A precomputed skeleton.
+///
+/// Two strings are confusable exactly when their `Skeleton`s are equal, so
+/// these can be stored in sets and maps in place of repeated `confusable` calls.
+///
+/// With the `serde` feature, a `Skeleton` serializes as the string of its
+/// characters. Deserializing does not check that the string is a skeleton,
+/// so skeletons should only be read back by the same version of the table
+/// they were computed with; see `UNICODE_VERSION`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Skeleton(String);
+
+impl Skeleton {
+    /// Compute the skeleton of `s`.
+    pub fn of(s: &str) -> Skeleton {
+        Skeleton(skeleton(s))
+    }
+
+    /// Compute the skeleton of `s` with the confusables table of `version`.
+    pub fn of_version(s: &str, version: DataVersion) -> Skeleton {
+        Skeleton(version.skeleton(s))
+    }
+
+    /// The characters of the skeleton.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Test if a string or other `char` sequence has this skeleton, and so is
+    /// confusable with the string this was computed from.
+    ///
+    /// Only the skeleton of `s` is computed, and only as far as its first
+    /// difference from this one, so comparing one string against many
+    /// candidates does not recompute its skeleton each time as `confusable`
+    /// would. The skeleton of `s` is computed with the latest table, as by
+    /// `Skeleton::of`.
+    ///
+    /// # Examples
+    /// ```Rust
+    /// let rust = Skeleton::of("Rust");
+    /// rust.matches("ℝ𝓊𝓈𝓉") // true
+    /// rust.matches("Rest") // false
+    /// ```
+    pub fn matches<S: UnicodeSkeleton>(&self, s: S) -> bool {
+        s.skeleton_chars().eq(self.0.chars())
+    }
+
+    /// Summarize the skeleton, for rejecting non-matches cheaply.
+    pub fn summary(&self) -> SkeletonSummary {
+        SkeletonSummary::of_chars(self.0.chars())
+    }
+}
 
 /// Test if two strings have the same "skeleton", and thus could be visually
 /// confused for each another.
-pub fn confusable<A, B, AI, BI>(a: A, b: B) -> bool
-    where A: UnicodeSkeleton<AI>, B: UnicodeSkeleton<BI>, AI: Iterator<Item=char>, BI: Iterator<Item=char>
+pub fn confusable<A, B>(a: A, b: B) -> bool
+    where A: UnicodeSkeleton, B: UnicodeSkeleton
 {
     let mut skeleton_a = a.skeleton_chars();
     let mut skeleton_b = b.skeleton_chars();
@@ -97,76 +514,659 @@ pub fn confusable<A, B, AI, BI>(a: A, b: B) -> bool
     }
 }
 
+/// Find the first of `candidates` that is confusable with `needle`, returning its index.
+///
+/// The skeleton of `needle` is computed once, rather than once per candidate
+/// as with repeated calls to `confusable`, and candidates whose length or
+/// first skeleton character rule them out are rejected without streaming
+/// the rest of their skeletons.
+///
+/// # Examples
+/// ```Rust
+/// confusable_with_any("ℝ𝓊𝓈𝓉", &["Go", "Rust"]) // Some(1)
+/// ```
+pub fn confusable_with_any<I>(needle: &str, candidates: I) -> Option<usize>
+    where I: IntoIterator, I::Item: AsRef<str>
+{
+    let needle = Skeleton::of(needle);
+    let summary = needle.summary();
+    candidates.into_iter()
+        .position(|candidate| summary.may_match_str(candidate.as_ref()) && needle.matches(candidate.as_ref()))
+}
+
+/// Compare the skeletons of two strings lexicographically by character,
+/// without allocating either skeleton.
+///
+/// This orders strings consistently with `confusable`: the result is
+/// `Ordering::Equal` exactly when the strings are confusable.
+pub fn skeleton_cmp<A, B>(a: A, b: B) -> Ordering
+    where A: UnicodeSkeleton, B: UnicodeSkeleton
+{
+    a.skeleton_chars().cmp(b.skeleton_chars())
+}
+
+/// Feed the skeleton of `s` into a `Hasher` without allocating it.
+///
+/// Confusable strings feed identical data to the hasher, so this can be used
+/// for hash-based deduplication by skeleton. The data fed is terminated so
+/// that a skeleton and its prefixes hash differently, but it is not
+/// guaranteed to match the hash of the corresponding `Skeleton`.
+pub fn skeleton_hash<H: Hasher>(s: &str, h: &mut H) {
+    let mut buf = [0u8; 4];
+    for c in s.skeleton_chars() {
+        h.write(c.encode_utf8(&mut buf).as_bytes());
+    }
+    h.write_u8(0xff);
+}
+
+/// Test if two strings could be visually confused for each other when
+/// differences in case are also disregarded.
+///
+/// Both strings are case folded, with the full case folding of Unicode,
+/// before their skeletons are computed, and the skeletons are case folded
+/// again, since a prototype character may itself be uppercase (for example,
+/// "ℝ" has no lowercase form but maps to "R"). Full case folding also
+/// equates "ß" with "ss" and final "ς" with "σ".
+///
+/// # Examples
+/// ```Rust
+/// confusable_ignore_case("PayPal", "𝔭𝒶ỿ𝕡𝕒ℓ") // true
+/// confusable_ignore_case("STRASSE", "straße") // true
+/// ```
+pub fn confusable_ignore_case(a: &str, b: &str) -> bool {
+    caseless_skeleton_chars(a).eq(caseless_skeleton_chars(b))
+}
+
+fn caseless_skeleton_chars<'a>(s: &'a str) -> impl Iterator<Item=char> + 'a {
+    s.chars()
+        .flat_map(case_fold)
+        .skeleton_chars()
+        .flat_map(case_fold)
+}
+
+/// The full case folding of `c`, as given by the C and F mappings of
+/// CaseFolding.txt.
+///
+/// Those mappings are what lowercasing the uppercase of the lowercase of a
+/// character gives, apart from two exceptions: dotless "ı" folds to itself,
+/// and Cherokee folds to its uppercase letters.
+pub(crate) fn case_fold(c: char) -> tinyvec::TinyVecIterator<[char; 3]> {
+    let mut folded: TinyVec<[char; 3]> = TinyVec::new();
+    match c {
+        '\u{131}' => folded.push(c),
+        '\u{13A0}'..='\u{13FF}' | '\u{AB70}'..='\u{ABBF}' => folded.extend(c.to_uppercase()),
+        _ => folded.extend(c.to_lowercase().flat_map(char::to_uppercase).flat_map(char::to_lowercase)),
+    }
+    folded.into_iter()
+}
+
+/// `s` with full case folding applied, as by `case_fold`.
+pub(crate) fn case_folded(s: &str) -> String {
+    s.chars().flat_map(case_fold).collect()
+}
+
+/// Test if the skeleton of `a` begins with the skeleton of `b`.
+pub fn skeleton_starts_with<A, B>(a: A, b: B) -> bool
+    where A: UnicodeSkeleton, B: UnicodeSkeleton
+{
+    is_prefix(b.skeleton_chars(), a.skeleton_chars())
+}
+
+/// Test if the skeleton of `a` ends with the skeleton of `b`.
+///
+/// This streams each skeleton twice, once to find its length.
+pub fn skeleton_ends_with<A, B>(a: A, b: B) -> bool
+    where A: UnicodeSkeleton, B: UnicodeSkeleton, A::Iter: Clone, B::Iter: Clone
+{
+    let skeleton_a = a.skeleton_chars();
+    let skeleton_b = b.skeleton_chars();
+    let len_a = skeleton_a.clone().count();
+    let len_b = skeleton_b.clone().count();
+    len_b <= len_a && skeleton_a.skip(len_a - len_b).eq(skeleton_b)
+}
+
+/// Test if the skeleton of `needle` occurs anywhere within the skeleton of `haystack`.
+///
+/// # Examples
+/// ```Rust
+/// skeleton_contains("my-𝒶𝒹𝓂𝒾𝓃-account", "admin") // true
+/// ```
+pub fn skeleton_contains<A, B>(haystack: A, needle: B) -> bool
+    where A: UnicodeSkeleton, B: UnicodeSkeleton, A::Iter: Clone, B::Iter: Clone
+{
+    let mut haystack = haystack.skeleton_chars();
+    let needle = needle.skeleton_chars();
+    loop {
+        if is_prefix(needle.clone(), haystack.clone()) {
+            return true;
+        }
+        if haystack.next().is_none() {
+            return false;
+        }
+    }
+}
+
+/// The Levenshtein distance between the skeletons of two strings: the
+/// number of characters that must be inserted, deleted or substituted to
+/// turn one skeleton into the other.
+///
+/// Confusable strings are at distance 0, and near-misses that are not
+/// confusable, such as a doubled or dropped letter, can be ranked by it.
+/// Distances are counted in skeleton characters, so a character whose
+/// prototype is several characters long, such as "m", can count for more
+/// than one.
+///
+/// # Examples
+/// ```Rust
+/// skeleton_distance("ℝ𝓊𝓈𝓉", "Rust") // 0
+/// skeleton_distance("rustlamg", "rustlaxg") // 2, as "m" is "rn"
+/// skeleton_distance("ℝ𝓊𝓈𝓉𝓉", "Rust") // 1
+/// ```
+pub fn skeleton_distance<A, B>(a: A, b: B) -> usize
+    where A: UnicodeSkeleton, B: UnicodeSkeleton
+{
+    let a: Vec<char> = a.skeleton_chars().collect();
+    let b: Vec<char> = b.skeleton_chars().collect();
+    levenshtein(&a, &b)
+}
+
+// The Levenshtein distance between `a` and `b`, keeping one row of the
+// table at a time.
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + if ca == cb { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(diagonal + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn is_prefix<P, I>(mut prefix: P, mut of: I) -> bool
+    where P: Iterator<Item=char>, I: Iterator<Item=char>
+{
+    prefix.all(|c| of.next() == Some(c))
+}
+
 /// An iterator over the characters of the skeleton of a unicode string.
-/// This is retrieved via the `UnicodeSkeleton` trait.
-pub struct SkeletonChars<I: Iterator<Item=char>>(
-    FlatMap<DecompositionsToPrototypeChars<I>, DecomposeSingleChar, fn(char) -> Decompositions<option::IntoIter<char>>>
-);
+/// This is retrieved via the `UnicodeSkeleton` trait or
+/// `ConfusableTable::skeleton_chars`, and borrows the table of prototypes
+/// for `'t`.
+#[derive(Clone)]
+pub struct SkeletonChars<'t, I: Iterator<Item=char>> {
+    source: SourceDecompositions<I>,
+    prototype: PrototypeCharsIterator<'t>,
+    table: data::PrototypeTable<'t>,
+}
 
-impl<I: Iterator<Item=char>> Iterator for SkeletonChars<I> {
+impl<'t, I: Iterator<Item=char>> Iterator for SkeletonChars<'t, I> {
     type Item = char;
 
     fn next(&mut self) -> Option<char> {
-        self.0.next()
+        loop {
+            if let Some(c) = self.prototype.next() {
+                return Some(c);
+            }
+            let c = self.source.next()?;
+            self.prototype = PrototypeCharsIterator::new(c, self.table.prototype_of(c));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every decomposed source character has a skeleton of at least one character.
+        let (prototype_lower, prototype_upper) = self.prototype.size_hint();
+        let (source_lower, source_upper) = self.source.size_hint();
+        (
+            prototype_lower.saturating_add(source_lower),
+            source_upper
+                .and_then(|upper| upper.checked_mul(MAX_PROTOTYPE_SKELETON_LEN))
+                .and_then(|upper| prototype_upper.and_then(|prototype_upper| upper.checked_add(prototype_upper))),
+        )
     }
 }
 
-impl<I: Iterator<Item=char>> SkeletonChars<I> {
-    fn new(source: I) -> SkeletonChars<I> {
-        SkeletonChars(
-            source
-                .nfd()
-                .flat_map(PrototypeCharsIterator::new as fn(char) -> PrototypeCharsIterator)
-                .flat_map(|x| Some(x).into_iter().nfd()) )
+impl<'t, I: Iterator<Item=char>> FusedIterator for SkeletonChars<'t, I> {}
+
+/// Shows the remaining characters of the skeleton.
+impl<'t, I: Iterator<Item=char> + Clone> fmt::Debug for SkeletonChars<'t, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SkeletonChars")
+            .field(&self.clone().collect::<String>())
+            .finish()
+    }
+}
+
+impl<I: Iterator<Item=char>> SkeletonChars<'static, I> {
+    fn new(source: I) -> SkeletonChars<'static, I> {
+        SkeletonChars {
+            source: SourceDecompositions::new(source),
+            prototype: PrototypeCharsIterator::empty(),
+            table: data::CONFUSABLES,
+        }
     }
 }
 
 /// Method for retrieving a `SkeletonChars` from a `str` or other `char` iterator.
-pub trait UnicodeSkeleton<I: Iterator<Item=char>> {
+///
+/// This is implemented for string slices and for the standard library's
+/// iterators and iterator adapters over `char`s. Other `char` iterators can be
+/// used via `skeleton_chars_of`.
+///
+/// Generic code can accept anything with a skeleton with a plain
+/// `T: UnicodeSkeleton` bound:
+///
+/// ```Rust
+/// fn is_reserved<T: UnicodeSkeleton>(name: T) -> bool {
+///     confusable(name, "admin")
+/// }
+/// ```
+pub trait UnicodeSkeleton: Sized {
+    /// The `char` iterator that the skeleton is computed from.
+    type Iter: Iterator<Item=char>;
+
     /// Retrieve an iterater of the characters of the provided char sequence's skeleton
     ///
     /// # Examples
     /// ```Rust
     /// "𝔭𝒶ỿ𝕡𝕒ℓ".skeleton_chars().collect::<String>(); // "paypal"
-    /// ['𝒶', '𝒷', '𝒸'].iter().map(|c| *c).collect::<String>();  "abc"
-    fn skeleton_chars(self) -> SkeletonChars<I>;
+    /// ['𝒶', '𝒷', '𝒸'].iter().cloned().skeleton_chars().collect::<String>(); // "abc"
+    /// ```
+    fn skeleton_chars(self) -> SkeletonChars<'static, Self::Iter>;
+
+    /// Retrieve an iterator of the characters of the skeleton, computed with
+    /// the mappings of `options` rather than the bundled table alone.
+    ///
+    /// # Examples
+    /// ```Rust
+    /// let options = SkeletonOptions::new().exempt('ℓ');
+    /// "𝔭𝒶ỿ𝕡𝕒ℓ".skeleton_chars_with(&options).collect::<String>(); // "paypaℓ"
+    /// ```
+    fn skeleton_chars_with<'o>(self, options: &'o SkeletonOptions) -> SkeletonCharsWith<'o, Self::Iter> {
+        SkeletonCharsWith::new(self.skeleton_chars().source, options)
+    }
+
+    /// Append the characters of the skeleton to `out`.
+    ///
+    /// For strings, runs of ASCII characters that the confusables table does
+    /// not map are copied straight through, and only the rest of the string
+    /// goes through normalization and lookup.
+    fn push_skeleton(self, out: &mut String) {
+        out.extend(self.skeleton_chars());
+    }
+}
+
+/// Retrieve the characters of the skeleton of any `char` sequence, including
+/// iterators that do not implement `UnicodeSkeleton`.
+pub fn skeleton_chars_of<I: IntoIterator<Item=char>>(chars: I) -> SkeletonChars<'static, I::IntoIter> {
+    SkeletonChars::new(chars.into_iter())
 }
 
-impl<I: Iterator<Item=char>> UnicodeSkeleton<I> for I {
-    fn skeleton_chars(self) -> SkeletonChars<I> {
+impl<'a> UnicodeSkeleton for &'a str {
+    type Iter = Chars<'a>;
+
+    fn skeleton_chars(self) -> SkeletonChars<'static, Chars<'a>> {
+        SkeletonChars::new(self.chars())
+    }
+
+    fn push_skeleton(self, out: &mut String) {
+        push_skeleton_str(self, out);
+    }
+}
+
+// A blanket implementation over all `char` iterators would conflict with the
+// one for `&str`, so the standard iterators are listed individually.
+macro_rules! iterator_skeleton_impls {
+    ($([$($param:tt)*] $ty:ty;)*) => {
+        $(
+            impl<$($param)*> UnicodeSkeleton for $ty where $ty: Iterator<Item=char> {
+                type Iter = $ty;
+
+                fn skeleton_chars(self) -> SkeletonChars<'static, $ty> {
+                    SkeletonChars::new(self)
+                }
+            }
+        )*
+    }
+}
+
+iterator_skeleton_impls! {
+    ['a] Chars<'a>;
+    ['a] ::std::string::Drain<'a>;
+    [] ::std::char::ToLowercase;
+    [] ::std::char::ToUppercase;
+    [] ::std::option::IntoIter<char>;
+    [] ::std::vec::IntoIter<char>;
+    [] ::std::collections::vec_deque::IntoIter<char>;
+    [] ::std::iter::Empty<char>;
+    [] ::std::iter::Once<char>;
+    [] ::std::iter::Repeat<char>;
+    [F] ::std::iter::FromFn<F>;
+    [A, B] ::std::iter::Chain<A, B>;
+    [I] ::std::iter::Cloned<I>;
+    [I] ::std::iter::Copied<I>;
+    [I] ::std::iter::Cycle<I>;
+    [I, P] ::std::iter::Filter<I, P>;
+    [I, F] ::std::iter::FilterMap<I, F>;
+    [I: Iterator, U: IntoIterator, F] ::std::iter::FlatMap<I, U, F>;
+    [I: Iterator<Item=U>, U: IntoIterator] ::std::iter::Flatten<I>;
+    [I] ::std::iter::Fuse<I>;
+    [I, F] ::std::iter::Inspect<I, F>;
+    [I, F] ::std::iter::Map<I, F>;
+    [I: Iterator] ::std::iter::Peekable<I>;
+    [I] ::std::iter::Rev<I>;
+    [I, St, F] ::std::iter::Scan<I, St, F>;
+    [I] ::std::iter::Skip<I>;
+    [I, P] ::std::iter::SkipWhile<I, P>;
+    [I] ::std::iter::StepBy<I>;
+    [I] ::std::iter::Take<I>;
+    [I, P] ::std::iter::TakeWhile<I, P>;
+    ['t, I: Iterator<Item=char>] SkeletonChars<'t, I>;
+    [S: AsRef<str>] OwnedChars<S>;
+}
+
+impl<'a, I: Iterator<Item=char> + ?Sized> UnicodeSkeleton for &'a mut I {
+    type Iter = &'a mut I;
+
+    fn skeleton_chars(self) -> SkeletonChars<'static, &'a mut I> {
         SkeletonChars::new(self)
     }
 }
 
-impl<'a> UnicodeSkeleton<Chars<'a>> for &'a str {
-    fn skeleton_chars(self) -> SkeletonChars<Chars<'a>> {
-        SkeletonChars::new(self.chars())
+impl<'a> UnicodeSkeleton for Box<dyn Iterator<Item=char> + 'a> {
+    type Iter = Box<dyn Iterator<Item=char> + 'a>;
+
+    fn skeleton_chars(self) -> SkeletonChars<'static, Self::Iter> {
+        SkeletonChars::new(self)
+    }
+}
+
+impl<'a> UnicodeSkeleton for Box<dyn Iterator<Item=char> + Send + 'a> {
+    type Iter = Box<dyn Iterator<Item=char> + Send + 'a>;
+
+    fn skeleton_chars(self) -> SkeletonChars<'static, Self::Iter> {
+        SkeletonChars::new(self)
+    }
+}
+
+macro_rules! borrowed_string_skeleton_impls {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> UnicodeSkeleton for &'a $ty {
+                type Iter = Chars<'a>;
+
+                fn skeleton_chars(self) -> SkeletonChars<'static, Chars<'a>> {
+                    SkeletonChars::new(self.chars())
+                }
+
+                fn push_skeleton(self, out: &mut String) {
+                    push_skeleton_str(self, out);
+                }
+            }
+        )*
+    }
+}
+
+borrowed_string_skeleton_impls!(String, Box<str>, Rc<str>, Arc<str>, Cow<'a, str>);
+
+macro_rules! owned_string_skeleton_impls {
+    ($([$($param:tt)*] $ty:ty;)*) => {
+        $(
+            impl<$($param)*> UnicodeSkeleton for $ty {
+                type Iter = OwnedChars<$ty>;
+
+                fn skeleton_chars(self) -> SkeletonChars<'static, OwnedChars<$ty>> {
+                    SkeletonChars::new(OwnedChars::new(self))
+                }
+
+                fn push_skeleton(self, out: &mut String) {
+                    push_skeleton_str(self.as_ref(), out);
+                }
+            }
+        )*
+    }
+}
+
+owned_string_skeleton_impls! {
+    [] String;
+    [] Box<str>;
+    [] Rc<str>;
+    [] Arc<str>;
+    ['a] Cow<'a, str>;
+}
+
+/// An iterator over the `char`s of an owned string, used to compute the
+/// skeletons of owned strings such as `String` and `Rc<str>`.
+#[derive(Clone, Debug)]
+pub struct OwnedChars<S> {
+    s: S,
+    position: usize,
+}
+
+impl<S: AsRef<str>> OwnedChars<S> {
+    /// Iterate over the `char`s of `s`.
+    pub fn new(s: S) -> OwnedChars<S> {
+        OwnedChars { s, position: 0 }
+    }
+}
+
+impl<S: AsRef<str>> Iterator for OwnedChars<S> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.s.as_ref()[self.position..].chars().next()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.s.as_ref()[self.position..].chars().size_hint()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{UnicodeSkeleton, confusable};
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{UnicodeSkeleton, Skeleton, skeleton, confusable, confusable_ignore_case, skeleton_cmp, confusable_with_any};
+    use super::{skeleton_starts_with, skeleton_ends_with, skeleton_contains, skeleton_distance};
+    use super::{skeleton_len, skeleton_len_utf8};
+    use super::{write_skeleton, skeleton_into, prototype_of, is_confusable_char, data_entries};
+    #[cfg(feature = "std")]
+    use super::{skeleton_hash, write_skeleton_io};
+    use super::{data_version, UNICODE_VERSION};
+    use std::cmp::Ordering;
+    #[cfg(feature = "std")]
+    use std::collections::hash_map::DefaultHasher;
+    #[cfg(feature = "std")]
+    use std::hash::Hasher;
 
     #[test]
     fn skeleton_char_cases() {
-        assert_eq!("\u{0441}".skeleton_chars().collect::<String>(), "\u{0063}");
-        assert_eq!("𝔭𝒶ỿ𝕡𝕒ℓ".skeleton_chars().collect::<String>(), "paypal");
+        assert_eq!(skeleton("\u{0441}"), "\u{0063}");
+        assert_eq!(skeleton("𝔭𝒶ỿ𝕡𝕒ℓ"), "paypal");
+        assert_eq!(skeleton("ℝ𝓊𝓈𝓉"), "Rust");
+
+        assert_eq!(skeleton(['𝒶', '𝒷', '𝒸'].iter().cloned()), "abc");
         assert_eq!("ℝ𝓊𝓈𝓉".skeleton_chars().collect::<String>(), "Rust");
+    }
+
+    #[test]
+    fn skeleton_lengths() {
+        assert_eq!(skeleton_len("ℝ𝓊𝓈𝓉"), 4);
+        assert_eq!(skeleton_len("m"), 2);
+        assert_eq!(skeleton_len_utf8("ℝ𝓊𝓈𝓉"), 4);
+        assert_eq!(skeleton_len_utf8("\u{2fa1d}"), skeleton("\u{2fa1d}").len());
+    }
+
+    #[test]
+    fn reuse_buffer() {
+        let mut buf = String::new();
+        skeleton_into("ℝ𝓊𝓈𝓉", &mut buf);
+        assert_eq!(buf, "Rust");
+        skeleton_into("𝔭𝒶ỿ𝕡𝕒ℓ", &mut buf);
+        assert_eq!(buf, "paypal");
+    }
+
+    #[test]
+    fn ascii_fast_path() {
+        let cases = [
+            "", "hello world", "mississippi", "100% I|`\"", "ℝ𝓊𝓈𝓉 and rust", "cafe\u{301}",
+            "m\u{301}", "a\u{323}\u{301}b", "\u{301}abc", "xx\u{0430}yy\u{2fa1d}", "ǆm",
+            "lorem ipsum dolor sit amet, 100%\u{301} of 1\u{301}mm%%\u{0430}%m",
+        ];
+        for &case in cases.iter() {
+            let slow: String = case.chars().skeleton_chars().collect();
+            assert_eq!(skeleton(case), slow, "{:?}", case);
+            assert_eq!(skeleton(case.to_string()), slow, "{:?}", case);
+        }
+    }
 
-        assert_eq!(['𝒶', '𝒷', '𝒸'].iter().map(|c| *c).skeleton_chars().collect::<String>(), "abc");
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn small_skeleton() {
+        let small = super::skeleton_small("ℝ𝓊𝓈𝓉");
+        assert_eq!(&small[..], b"Rust");
+        assert!(!small.spilled());
+    }
+
+    #[test]
+    fn write_to_sinks() {
+        let mut s = String::from("name: ");
+        write_skeleton("ℝ𝓊𝓈𝓉", &mut s).unwrap();
+        assert_eq!(s, "name: Rust");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_to_io() {
+        let mut bytes = Vec::new();
+        write_skeleton_io("𝔭𝒶ỿ𝕡𝕒ℓ", &mut bytes).unwrap();
+        assert_eq!(bytes, b"paypal");
+    }
+
+    #[test]
+    fn skeleton_chars_traits() {
+        fn assert_fused<I: ::std::iter::FusedIterator>(_: &I) {}
+
+        let mut chars = "ℝ𝓊".skeleton_chars();
+        assert_fused(&chars);
+        assert_eq!(format!("{:?}", chars), "SkeletonChars(\"Ru\")");
+        chars.next();
+        assert_eq!(format!("{:?}", chars), "SkeletonChars(\"u\")");
+        chars.next();
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn skeleton_chars_size_hint() {
+        let mut chars = ['ℝ', '𝓊', 'm'].iter().cloned().skeleton_chars();
+        assert_eq!(chars.size_hint(), (3, Some(3 * 4 * 18)));
+        assert_eq!(chars.next(), Some('R'));
+        let (lower, upper) = chars.size_hint();
+        assert!(lower <= 3 && upper.unwrap() >= 3);
+        assert_eq!(chars.count(), 3);
+
+        assert_eq!("".skeleton_chars().size_hint(), (0, Some(0)));
+        assert_eq!(::std::iter::repeat('a').skeleton_chars().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn generic_bounds() {
+        fn is_reserved<T: UnicodeSkeleton>(name: T) -> bool {
+            confusable(name, "admin")
+        }
+        assert!(is_reserved("𝒶𝒹𝓂𝒾𝓃"));
+        assert!(is_reserved("𝒶𝒹𝓂𝒾𝓃".chars()));
+        assert!(is_reserved(Box::new("𝒶𝒹𝓂𝒾𝓃".chars()) as Box<dyn Iterator<Item=char>>));
+        assert!(!is_reserved("root-𝒶𝒹𝓂𝒾𝓃".chars().take(4)));
+        assert!(is_reserved("root-𝒶𝒹𝓂𝒾𝓃".chars().skip(5)));
+        assert!(is_reserved(&mut "𝒶𝒹𝓂𝒾𝓃".chars() as &mut dyn Iterator<Item=char>));
+
+        let digits = (0..3).filter_map(|d| ::std::char::from_digit(d, 10));
+        assert_eq!(super::skeleton_chars_of(digits).collect::<String>(), skeleton("012"));
+    }
+
+    #[test]
+    fn string_like_inputs() {
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let owned = String::from("ℝ𝓊𝓈𝓉");
+        assert!(confusable(&owned, Cow::Borrowed("Rust")));
+        assert!(confusable(owned.clone(), Cow::Owned::<str>("Rust".to_string())));
+        assert!(confusable(Box::<str>::from("ℝ𝓊𝓈𝓉"), Rc::<str>::from("Rust")));
+        let shared = Arc::<str>::from("ℝ𝓊𝓈𝓉");
+        assert!(confusable(&shared, "Rust"));
+        assert!(confusable(shared, "Rust"));
+        assert_eq!(skeleton(owned), "Rust");
     }
 
     #[test]
     fn final_table_entry() {
-        // The last character is a special case, since we don't have a next index to check.
-        // (Handled by the `unwrap_or` in `PrototypeCharsIterator::new`.)
-        assert_eq!("\u{2fa1d}".skeleton_chars().collect::<String>(), "𪘀");
+        // The last character of the table: each prototype ends where the next
+        // begins in `PROTOTYPE_STARTS`, whose sentinel final entry ends the
+        // last prototype.
+        assert_eq!(skeleton("\u{2fa1d}"), "𪘀");
+    }
+
+    #[test]
+    fn single_char_prototypes() {
+        assert_eq!(prototype_of('ℝ'), Some(&['R'][..]));
+        assert_eq!(prototype_of('m'), Some(&['r', 'n'][..]));
+        assert_eq!(prototype_of('\u{2fa1d}'), Some(&['𪘀'][..]));
+        assert_eq!(prototype_of('a'), None);
+    }
+
+    #[test]
+    fn confusable_char_predicate() {
+        assert!(is_confusable_char('\u{430}'));
+        assert!(is_confusable_char('m'));
+        // Decomposes to "m" followed by a combining acute accent.
+        assert!(is_confusable_char('\u{1e3f}'));
+        assert!(!is_confusable_char('a'));
+        assert!(!is_confusable_char('\u{e9}'));
+    }
+
+    #[test]
+    fn confusable_char_predicate_agrees_with_decomposition() {
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let mut confusable = false;
+            unicode_normalization::char::decompose_canonical(c, |d| confusable = confusable || prototype_of(d).is_some());
+            assert_eq!(is_confusable_char(c), confusable, "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn const_lookups() {
+        const R: Option<&[char]> = prototype_of('ℝ');
+        const _: () = assert!(is_confusable_char('\u{430}'));
+        const _: () = assert!(!is_confusable_char('a'));
+        assert_eq!(R, Some(&['R'][..]));
+    }
+
+    #[test]
+    fn table_entries() {
+        assert_eq!(data_entries().len(), 6355);
+        assert!(data_entries().all(|(c, prototype)| prototype_of(c) == Some(prototype)));
+        assert_eq!(data_entries().last(), Some(('\u{2fa1d}', &['𪘀'][..])));
+    }
+
+    #[test]
+    fn version() {
+        assert_eq!(data_version(), UNICODE_VERSION);
+        assert_eq!(UNICODE_VERSION.0, 16);
     }
 
     #[test]
     fn leave_intact() {
-        assert_eq!("Plain letters".skeleton_chars().collect::<String>(), "Plain letters");
+        assert_eq!(skeleton("Plain letters"), "Plain letters");
     }
 
     #[test]
@@ -176,4 +1176,108 @@ mod tests {
         assert!(!confusable("ℝ𝓊𝓈𝓉", "Rus"));
         assert!(!confusable("Rast", "Rust"));
     }
+
+    #[test]
+    fn confusable_with_any_candidate() {
+        assert_eq!(confusable_with_any("ℝ𝓊𝓈𝓉", &["Go", "Rust", "rust"]), Some(1));
+        assert_eq!(confusable_with_any("ℝ𝓊𝓈𝓉", vec!["Go".to_string()]), None);
+        assert_eq!(confusable_with_any("ℝ𝓊𝓈𝓉", Vec::<&str>::new()), None);
+    }
+
+    #[test]
+    fn confusables_ignoring_case() {
+        assert!(confusable_ignore_case("PayPal", "𝔭𝒶ỿ𝕡𝕒ℓ"));
+        assert!(confusable_ignore_case("ℝ𝓊𝓈𝓉", "rust"));
+        assert!(!confusable("PayPal", "𝔭𝒶ỿ𝕡𝕒ℓ"));
+        assert!(!confusable_ignore_case("PayPal", "PayPals"));
+        // Full case folding, beyond lowercasing.
+        assert!(confusable_ignore_case("STRASSE", "straße"));
+        assert!(confusable_ignore_case("ΣΟΦΟΣ", "σοφος"));
+        assert!(confusable_ignore_case("ﬁle", "FILE"));
+    }
+
+    #[test]
+    fn skeleton_ordering() {
+        assert_eq!(skeleton_cmp("ℝ𝓊𝓈𝓉", "Rust"), Ordering::Equal);
+        assert_eq!(skeleton_cmp("ℝ𝓊𝓈", "Rust"), Ordering::Less);
+        assert_eq!(skeleton_cmp("ℝ𝓊𝓈𝓉", "Rast"), Ordering::Greater);
+        // "m" has the skeleton "rn", which sorts after "p".
+        assert_eq!(skeleton_cmp("𝔭𝒶ỿ𝕡𝕒ℓ", "payment"), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn skeleton_hashing() {
+        fn hash(s: &str) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            skeleton_hash(s, &mut hasher);
+            hasher.finish()
+        }
+        assert_eq!(hash("ℝ𝓊𝓈𝓉"), hash("Rust"));
+        assert_ne!(hash("ℝ𝓊𝓈"), hash("Rust"));
+    }
+
+    #[test]
+    fn skeleton_affixes() {
+        assert!(skeleton_starts_with("𝒶𝒹𝓂𝒾𝓃istrator", "admin"));
+        assert!(skeleton_starts_with("admin", ""));
+        assert!(!skeleton_starts_with("adm", "admin"));
+
+        assert!(skeleton_ends_with("site-𝒶𝒹𝓂𝒾𝓃", "admin"));
+        assert!(!skeleton_ends_with("𝒶𝒹𝓂𝒾𝓃-site", "admin"));
+        assert!(!skeleton_ends_with("min", "admin"));
+
+        assert!(skeleton_contains("my-𝒶𝒹𝓂𝒾𝓃-account", "admin"));
+        assert!(skeleton_contains("admin", "𝒶𝒹𝓂𝒾𝓃"));
+        assert!(skeleton_contains("anything", ""));
+        assert!(!skeleton_contains("my-account", "admin"));
+    }
+
+    #[test]
+    fn skeleton_distances() {
+        assert_eq!(skeleton_distance("ℝ𝓊𝓈𝓉", "Rust"), 0);
+        assert_eq!(skeleton_distance("rust1ang", "rustlang"), 0);
+        assert_eq!(skeleton_distance("ℝ𝓊𝓈𝓉𝓉", "Rust"), 1);
+        assert_eq!(skeleton_distance("rustlamg", "rustlaxg"), 2);
+        assert_eq!(skeleton_distance("rustlamg", "rustlang"), 1);
+        assert_eq!(skeleton_distance("", "Rust"), 4);
+        assert_eq!(skeleton_distance("Rust", ""), 4);
+        assert_eq!(skeleton_distance("kitten", "sitting"), 3);
+        assert_eq!(skeleton_distance("sitting", "kitten"), 3);
+    }
+
+    #[test]
+    fn skeleton_equality() {
+        assert_eq!(Skeleton::of("ℝ𝓊𝓈𝓉"), Skeleton::of("Rust"));
+        assert_ne!(Skeleton::of("Rast"), Skeleton::of("Rust"));
+        assert_eq!(Skeleton::of("𝔭𝒶ỿ𝕡𝕒ℓ").as_str(), "paypal");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn skeleton_serde() {
+        let rust = Skeleton::of("ℝ𝓊𝓈𝓉");
+        assert_eq!(::serde_json::to_string(&rust).unwrap(), r#""Rust""#);
+        assert_eq!(::serde_json::from_str::<Skeleton>(r#""Rust""#).unwrap(), rust);
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn skeleton_macro() {
+        const RUST: &str = skeleton!("ℝ𝓊𝓈𝓉");
+        assert_eq!(RUST, "Rust");
+        assert_eq!(skeleton!("pаypаl"), skeleton("pаypаl"));
+    }
+
+    #[test]
+    fn skeleton_matches() {
+        let rust = Skeleton::of("Rust");
+        assert!(rust.matches("ℝ𝓊𝓈𝓉"));
+        assert!(rust.matches("Rust".chars()));
+        assert!(!rust.matches("Rus"));
+        assert!(!rust.matches("Rusty"));
+        assert!(!rust.matches("Rest"));
+        assert!(Skeleton::of("").matches(""));
+        assert!(Skeleton::of("rn").matches("m"));
+    }
 }
@@ -0,0 +1,158 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Finding confusables of many patterns at once, with Aho-Corasick.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::ops::Range;
+
+use aho_corasick::automaton::OverlappingState;
+use aho_corasick::AhoCorasick;
+
+use search::SkeletonSpanTable;
+use UnicodeSkeleton;
+
+/// A set of patterns to find confusables of in texts, such as brand names to
+/// look for in phishing messages.
+///
+/// The skeletons of the patterns are compiled into one automaton, so a text
+/// is scanned once for all of them rather than once per pattern, as with
+/// `find_confusables`. Matches are found as by `find_confusable`.
+///
+/// # Examples
+/// ```Rust
+/// let matcher = ConfusableMatcher::new(&["paypal", "apple"]);
+/// matcher.find_iter("Your 𝔭𝒶ỿ𝕡𝕒ℓ and аррlе accounts").collect::<Vec<_>>()
+/// // [(0, 5..27), (1, 32..41)]
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfusableMatcher {
+    automaton: AhoCorasick,
+}
+
+impl ConfusableMatcher {
+    /// Compile a matcher for `patterns`, which are numbered in order from 0.
+    ///
+    /// # Panics
+    /// If the automaton would exceed the limits of the `aho-corasick` crate,
+    /// which takes far more patterns than are realistic to check for.
+    pub fn new<I, P>(patterns: I) -> ConfusableMatcher
+        where I: IntoIterator<Item = P>, P: AsRef<str>
+    {
+        let skeletons = patterns.into_iter()
+            .map(|pattern| pattern.as_ref().skeleton_chars().collect::<String>());
+        let automaton = AhoCorasick::new(skeletons).expect("too many confusable patterns");
+        ConfusableMatcher { automaton }
+    }
+
+    /// The number of patterns.
+    pub fn patterns_len(&self) -> usize {
+        self.automaton.patterns_len()
+    }
+
+    /// Iterate over the places in `text` where a confusable of any of the
+    /// patterns appears, as the index of the pattern and the byte range in
+    /// `text`. Matches are ordered by where they end, and may overlap, even
+    /// when they are of the same pattern.
+    pub fn find_iter<'m>(&'m self, text: &str) -> ConfusableMatcherMatches<'m> {
+        let spans = SkeletonSpanTable::new(text);
+        let mut skeleton = String::new();
+        let mut char_starts = Vec::with_capacity(spans.chars.len());
+        for &c in &spans.chars {
+            char_starts.push(skeleton.len());
+            skeleton.push(c);
+        }
+        ConfusableMatcherMatches {
+            automaton: &self.automaton,
+            state: OverlappingState::start(),
+            spans,
+            skeleton,
+            char_starts,
+        }
+    }
+
+    /// Whether a confusable of any of the patterns appears in `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.find_iter(text).next().is_some()
+    }
+}
+
+/// An iterator over the places in a text where confusables of the patterns
+/// of a `ConfusableMatcher` appear. This is retrieved via
+/// `ConfusableMatcher::find_iter`.
+#[derive(Debug, Clone)]
+pub struct ConfusableMatcherMatches<'m> {
+    automaton: &'m AhoCorasick,
+    state: OverlappingState,
+    spans: SkeletonSpanTable,
+    // The skeleton of the text, and the byte offset in it of each character.
+    skeleton: String,
+    char_starts: Vec<usize>,
+}
+
+impl<'m> Iterator for ConfusableMatcherMatches<'m> {
+    type Item = (usize, Range<usize>);
+
+    fn next(&mut self) -> Option<(usize, Range<usize>)> {
+        loop {
+            self.automaton.find_overlapping(&self.skeleton[..], &mut self.state);
+            let found = self.state.get_match()?;
+            if found.is_empty() {
+                continue;
+            }
+            // Patterns are UTF-8, so matches start and end on characters.
+            let start = self.char_starts.binary_search(&found.start()).unwrap();
+            let end = self.char_starts.binary_search(&found.end()).unwrap_or(self.char_starts.len());
+            if let Some(range) = self.spans.source_range(start, end) {
+                return Some((found.pattern().as_usize(), range));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfusableMatcher;
+
+    #[test]
+    fn finds_every_pattern() {
+        let matcher = ConfusableMatcher::new(["paypal", "apple", ""]);
+        assert_eq!(matcher.patterns_len(), 3);
+        let text = "Your 𝔭𝒶ỿ𝕡𝕒ℓ and аррlе accounts";
+        let found: Vec<_> = matcher.find_iter(text).collect();
+        assert_eq!(found.iter().map(|&(i, ref range)| (i, &text[range.clone()])).collect::<Vec<_>>(),
+                   vec![(0, "𝔭𝒶ỿ𝕡𝕒ℓ"), (1, "аррlе")]);
+        assert!(matcher.is_match("PayPal or paypal"));
+        assert!(!matcher.is_match("PayPal or Apple"));
+        assert!(!matcher.is_match(""));
+    }
+
+    #[test]
+    fn agrees_with_find_confusables() {
+        let patterns = ["rn", "m", "paypal", "café"];
+        let matcher = ConfusableMatcher::new(patterns);
+        for text in &["m rn rnm", "paypaĺ paypalé", "a café cafe\u{301}"] {
+            for (i, pattern) in patterns.iter().enumerate() {
+                let mut expected: Vec<_> = ::find_confusables(text, pattern).collect();
+                let mut found: Vec<_> = matcher.find_iter(text)
+                    .filter(|&(j, _)| j == i)
+                    .map(|(_, range)| range)
+                    .collect();
+                expected.sort_by_key(|range| range.start);
+                found.sort_by_key(|range| range.start);
+                assert_eq!(found, expected, "{:?} in {:?}", pattern, text);
+            }
+        }
+        // Unlike `find_confusables`, overlapping matches are all found.
+        let matcher = ConfusableMatcher::new(["aa"]);
+        assert_eq!(matcher.find_iter("aaa").collect::<Vec<_>>(), vec![(0, 0..2), (0, 1..3)]);
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The confusable table variants of older revisions of UTS #39.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use data::{self, PrototypeTable};
+use {skeleton_chars_of, SkeletonChars};
+
+/// Which of the four confusable tables of older revisions of UTS #39 to
+/// compute skeletons with. Those revisions tagged each mapping of
+/// confusables.txt as SL, SA, ML or MA, and ICU's deprecated spoof checker
+/// options still refer to them.
+///
+/// Current revisions only publish the MA table, so the others are derived
+/// from it: the single-script tables keep only the mappings whose character
+/// and prototype share a script, and the lowercase tables lowercase the
+/// string before and after mapping it.
+///
+/// # Examples
+/// ```Rust
+/// ConfusableMode::MixedAny.confusable("pаypаl", "paypal") // true, with Cyrillic 'а's
+/// ConfusableMode::SingleAny.confusable("pаypаl", "paypal") // false
+/// ConfusableMode::SingleLowercase.confusable("RN", "m") // true
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ConfusableMode {
+    /// The MA table: mixed-script, any case. This is what `skeleton` uses.
+    #[default]
+    MixedAny,
+    /// The ML table: mixed-script, lowercase.
+    MixedLowercase,
+    /// The SA table: single-script, any case.
+    SingleAny,
+    /// The SL table: single-script, lowercase.
+    SingleLowercase,
+}
+
+impl ConfusableMode {
+    /// The two-letter code of the table in confusables.txt, such as `"MA"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            ConfusableMode::MixedAny => "MA",
+            ConfusableMode::MixedLowercase => "ML",
+            ConfusableMode::SingleAny => "SA",
+            ConfusableMode::SingleLowercase => "SL",
+        }
+    }
+
+    /// Find the mode for a two-letter table code, as returned by `code`.
+    pub fn from_code(code: &str) -> Option<ConfusableMode> {
+        match code {
+            "MA" => Some(ConfusableMode::MixedAny),
+            "ML" => Some(ConfusableMode::MixedLowercase),
+            "SA" => Some(ConfusableMode::SingleAny),
+            "SL" => Some(ConfusableMode::SingleLowercase),
+            _ => None,
+        }
+    }
+
+    /// Whether the mode only maps characters to prototypes of the same script.
+    pub fn is_single_script(self) -> bool {
+        matches!(self, ConfusableMode::SingleAny | ConfusableMode::SingleLowercase)
+    }
+
+    /// Whether the mode disregards differences in case.
+    pub fn is_lowercase(self) -> bool {
+        matches!(self, ConfusableMode::MixedLowercase | ConfusableMode::SingleLowercase)
+    }
+
+    fn skeleton_chars_in<I: Iterator<Item=char>>(self, chars: I) -> SkeletonChars<'static, I> {
+        let mut skeleton = skeleton_chars_of(chars);
+        skeleton.table = PrototypeTable { single_script: self.is_single_script(), ..data::CONFUSABLES };
+        skeleton
+    }
+
+    /// Compute the skeleton of `s` under this mode. The skeletons of the
+    /// lowercase modes are entirely lowercase.
+    pub fn skeleton(self, s: &str) -> String {
+        if self.is_lowercase() {
+            self.skeleton_chars_in(s.chars().flat_map(char::to_lowercase)).flat_map(char::to_lowercase).collect()
+        } else {
+            self.skeleton_chars_in(s.chars()).collect()
+        }
+    }
+
+    /// Test if two strings have the same skeleton under this mode.
+    pub fn confusable(self, a: &str, b: &str) -> bool {
+        if self.is_lowercase() {
+            self.skeleton_chars_in(a.chars().flat_map(char::to_lowercase)).flat_map(char::to_lowercase)
+                .eq(self.skeleton_chars_in(b.chars().flat_map(char::to_lowercase)).flat_map(char::to_lowercase))
+        } else {
+            self.skeleton_chars_in(a.chars()).eq(self.skeleton_chars_in(b.chars()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfusableMode;
+    use {confusable, confusable_ignore_case, skeleton};
+
+    const MODES: [ConfusableMode; 4] = [
+        ConfusableMode::MixedAny, ConfusableMode::MixedLowercase,
+        ConfusableMode::SingleAny, ConfusableMode::SingleLowercase,
+    ];
+
+    #[test]
+    fn codes_round_trip() {
+        for &mode in MODES.iter() {
+            assert_eq!(ConfusableMode::from_code(mode.code()), Some(mode));
+        }
+        assert_eq!(ConfusableMode::from_code("XX"), None);
+        assert_eq!(ConfusableMode::default(), ConfusableMode::MixedAny);
+    }
+
+    #[test]
+    fn mixed_any_is_the_default_skeleton() {
+        for s in &["ℝ𝓊𝓈𝓉", "pаypаl", "rn", "Ꭺ"] {
+            assert_eq!(ConfusableMode::MixedAny.skeleton(s), skeleton(*s));
+        }
+    }
+
+    #[test]
+    fn single_script_keeps_same_script_mappings() {
+        let single = ConfusableMode::SingleAny;
+        assert!(confusable("pаypаl", "paypal"));
+        assert!(!single.confusable("pаypаl", "paypal"));
+        assert_eq!(single.skeleton("pаypаl"), "pаypаl");
+        assert!(single.confusable("rn", "m"));
+        // Common characters, such as mathematical letters, are compatible with every script.
+        assert!(single.confusable("ℝ𝓊𝓈𝓉", "Rust"));
+    }
+
+    #[test]
+    fn lowercase_modes() {
+        assert!(!ConfusableMode::MixedAny.confusable("PAYPAL", "paypal"));
+        assert!(ConfusableMode::MixedLowercase.confusable("PAYPAL", "pаypаl"));
+        assert_eq!(ConfusableMode::MixedLowercase.confusable("PayPal", "𝔭𝒶ỿ𝕡𝕒ℓ"), confusable_ignore_case("PayPal", "𝔭𝒶ỿ𝕡𝕒ℓ"));
+        assert!(ConfusableMode::SingleLowercase.confusable("RN", "m"));
+        assert!(!ConfusableMode::SingleLowercase.confusable("PAYPAL", "pаypаl"));
+        let lower = ConfusableMode::SingleLowercase.skeleton("ℝUST");
+        assert_eq!(lower, lower.to_lowercase());
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A Node.js addon, through napi-rs.
+//!
+//! Requires the `node` feature. The addon exports three functions:
+//!
+//! ```js
+//! const usk = require('./unicode_skeleton.node');
+//! usk.skeleton('ℝ𝓊𝓈𝓉');             // 'Rust'
+//! usk.confusable('ℝ𝓊𝓈𝓉', 'Rust');   // true
+//! usk.check('pаypаl');              // ['mixed_script', 'restriction_level']
+//! ```
+//!
+//! As in the Python module, `check` runs the checks of `SpoofChecker::new`,
+//! and returns the names of those that failed, as `Check::name` gives them.
+
+use napi_derive::napi;
+
+use checker::{Check, SpoofChecker};
+
+/// The skeleton of `s`.
+#[napi]
+pub fn skeleton(s: String) -> String {
+    ::skeleton(&s)
+}
+
+/// Test whether `a` and `b` are confusable.
+#[napi]
+pub fn confusable(a: String, b: String) -> bool {
+    ::confusable(&a, &b)
+}
+
+/// The names of the checks of `SpoofChecker::new` that `s` fails.
+#[napi]
+pub fn check(s: String) -> Vec<&'static str> {
+    SpoofChecker::new().quick_check(&s).iter().map(Check::name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn functions() {
+        assert_eq!(skeleton("ℝ𝓊𝓈𝓉".to_string()), "Rust");
+        assert!(confusable("ℝ𝓊𝓈𝓉".to_string(), "Rust".to_string()));
+        assert!(!confusable("Rest".to_string(), "Rust".to_string()));
+        assert_eq!(check("pаypаl".to_string()), ["mixed_script", "restriction_level"]);
+        assert!(check("paypal".to_string()).is_empty());
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Skeletons computed with application-specific changes to the confusables table.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use unicode_normalization::UnicodeNormalization;
+
+use decompose::SourceDecompositions;
+use {DataVersion, PrototypeCharsIterator, UnicodeSkeleton};
+
+/// A customized skeletonizer: a version of the bundled confusables table,
+/// with some mappings added, replaced or removed.
+///
+/// Skeletons are computed with it via `UnicodeSkeleton::skeleton_chars_with`
+/// and `confusable_with`.
+///
+/// Prototypes given to `map` are not themselves mapped, so a mapping to a
+/// character that has a prototype of its own makes skeletons that are no
+/// longer stable under being recomputed.
+///
+/// # Examples
+/// ```Rust
+/// let options = SkeletonOptions::new()
+///     .map('5', "S")
+///     .exempt('ℓ');
+/// confusable_with(&options, "5tar", "Star") // true
+/// "ℓ".skeleton_chars_with(&options).collect::<String>() // "ℓ"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SkeletonOptions {
+    version: DataVersion,
+    // A prototype of `None` exempts the character from being mapped at all.
+    overrides: BTreeMap<char, Option<Vec<char>>>,
+}
+
+impl SkeletonOptions {
+    /// Create options that use the latest bundled table unchanged.
+    pub fn new() -> SkeletonOptions {
+        SkeletonOptions::default()
+    }
+
+    /// Use the bundled table of `version` as the base table.
+    pub fn version(mut self, version: DataVersion) -> SkeletonOptions {
+        self.version = version;
+        self
+    }
+
+    /// Map `c` to `prototype`, replacing any mapping of `c` in the base table.
+    ///
+    /// Strings are decomposed before their characters are mapped, so
+    /// mappings of characters with a canonical decomposition, such as 'é',
+    /// have no effect. `prototype` is stored in NFD, as the prototypes of
+    /// the bundled tables are.
+    pub fn map(mut self, c: char, prototype: &str) -> SkeletonOptions {
+        self.overrides.insert(c, Some(prototype.nfd().collect()));
+        self
+    }
+
+    /// Leave `c` as it is in skeletons, even if the base table maps it, for
+    /// example to exempt a character used in a brand name.
+    pub fn exempt(mut self, c: char) -> SkeletonOptions {
+        self.overrides.insert(c, None);
+        self
+    }
+
+    /// Look up the prototype that a single character maps to with these
+    /// options, as with `prototype_of`.
+    pub fn prototype_of(&self, c: char) -> Option<&[char]> {
+        match self.overrides.get(&c) {
+            Some(prototype) => prototype.as_deref(),
+            None => self.version.prototype_of(c),
+        }
+    }
+
+    /// Compute the skeleton of a string or other `char` sequence with these options.
+    pub fn skeleton<S: UnicodeSkeleton>(&self, s: S) -> String {
+        s.skeleton_chars_with(self).collect()
+    }
+}
+
+/// Test if two strings have the same skeleton under `options`.
+pub fn confusable_with<A, B>(options: &SkeletonOptions, a: A, b: B) -> bool
+    where A: UnicodeSkeleton, B: UnicodeSkeleton
+{
+    a.skeleton_chars_with(options).eq(b.skeleton_chars_with(options))
+}
+
+/// An iterator over the characters of the skeleton of a unicode string,
+/// computed with `SkeletonOptions`. This is retrieved via
+/// `UnicodeSkeleton::skeleton_chars_with`.
+#[derive(Clone)]
+pub struct SkeletonCharsWith<'o, I: Iterator<Item=char>> {
+    source: SourceDecompositions<I>,
+    prototype: PrototypeCharsIterator<'o>,
+    options: &'o SkeletonOptions,
+}
+
+impl<'o, I: Iterator<Item=char>> SkeletonCharsWith<'o, I> {
+    pub(crate) fn new(source: SourceDecompositions<I>, options: &'o SkeletonOptions) -> SkeletonCharsWith<'o, I> {
+        SkeletonCharsWith {
+            source,
+            prototype: PrototypeCharsIterator::empty(),
+            options,
+        }
+    }
+}
+
+impl<'o, I: Iterator<Item=char>> Iterator for SkeletonCharsWith<'o, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.prototype.next() {
+                return Some(c);
+            }
+            let c = self.source.next()?;
+            self.prototype = PrototypeCharsIterator::new(c, self.options.prototype_of(c));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Overridden prototypes can be any length, including empty.
+        (self.prototype.size_hint().0, None)
+    }
+}
+
+impl<'o, I: Iterator<Item=char>> FusedIterator for SkeletonCharsWith<'o, I> {}
+
+/// Shows the remaining characters of the skeleton.
+impl<'o, I: Iterator<Item=char> + Clone> fmt::Debug for SkeletonCharsWith<'o, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SkeletonCharsWith")
+            .field(&self.clone().collect::<String>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{confusable_with, SkeletonOptions};
+    use {confusable, skeleton, DataVersion, UnicodeSkeleton};
+
+    #[test]
+    fn unchanged_options_match_skeleton() {
+        let options = SkeletonOptions::new();
+        for s in &["ℝ𝓊𝓈𝓉", "𝔭𝒶ỿ𝕡𝕒ℓ", "caf\u{e9}", ""] {
+            assert_eq!(options.skeleton(*s), skeleton(*s));
+        }
+        assert_eq!(SkeletonOptions::new().version(DataVersion::LATEST), options);
+    }
+
+    #[test]
+    fn added_mappings() {
+        let options = SkeletonOptions::new().map('5', "S").map('$', "S");
+        assert!(!confusable("5tar", "Star"));
+        assert!(confusable_with(&options, "5tar", "Star"));
+        assert!(confusable_with(&options, "$tar", "5tar"));
+        assert_eq!(options.prototype_of('5'), Some(&['S'][..]));
+        // Prototypes are decomposed, like those of the bundled table.
+        assert_eq!(SkeletonOptions::new().map('x', "\u{e9}").skeleton("x"), "e\u{301}");
+    }
+
+    #[test]
+    fn exempted_and_replaced_mappings() {
+        let options = SkeletonOptions::new().exempt('ℓ').map('ℝ', "P");
+        assert_eq!(options.prototype_of('ℓ'), None);
+        assert_eq!("𝔭𝒶ỿ𝕡𝕒ℓ".skeleton_chars_with(&options).collect::<String>(), "paypaℓ");
+        assert_eq!(options.skeleton("ℝ𝓊𝓈𝓉"), "Pust");
+        assert!(!confusable_with(&options, "ℓ", "l"));
+        // A later call replaces an earlier one for the same character.
+        assert_eq!(options.map('ℓ', "L").skeleton("ℓ"), "L");
+    }
+
+    #[test]
+    fn size_hint_is_a_lower_bound() {
+        let options = SkeletonOptions::new().map('a', "");
+        let chars = "abc".skeleton_chars_with(&options);
+        assert!(chars.size_hint().0 <= chars.clone().count());
+        assert_eq!(chars.collect::<String>(), "bc");
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Skeletons of large corpora, computed across threads with rayon.
+
+use std::collections::HashMap;
+use std::mem;
+
+use rayon::prelude::*;
+
+use Skeleton;
+
+/// Compute the skeleton of each of `items` across rayon's thread pool,
+/// returning them in the same order.
+///
+/// Requires the `rayon` feature.
+pub fn par_skeletons<T: AsRef<str> + Sync>(items: &[T]) -> Vec<Skeleton> {
+    items.par_iter().map(|item| Skeleton::of(item.as_ref())).collect()
+}
+
+/// Find every pair of confusable strings among `items`, as pairs of indices
+/// `(i, j)` with `i < j`, in order.
+///
+/// Each thread groups the indices of its share of `items` by skeleton, and
+/// the groups are then merged, so no skeleton is computed twice and no two
+/// strings are compared directly. A group of `k` confusable strings yields
+/// `k * (k - 1) / 2` pairs.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+/// ```Rust
+/// par_find_confusable_pairs(&["Rust", "Go", "ℝ𝓊𝓈𝓉"]) // [(0, 2)]
+/// ```
+pub fn par_find_confusable_pairs<T: AsRef<str> + Sync>(items: &[T]) -> Vec<(usize, usize)> {
+    let groups = items.par_iter().enumerate()
+        .fold(HashMap::new, |mut groups: HashMap<Skeleton, Vec<usize>>, (index, item)| {
+            groups.entry(Skeleton::of(item.as_ref())).or_default().push(index);
+            groups
+        })
+        .reduce(HashMap::new, |mut a, mut b| {
+            if a.len() < b.len() {
+                mem::swap(&mut a, &mut b);
+            }
+            for (skeleton, indices) in b {
+                a.entry(skeleton).or_default().extend(indices);
+            }
+            a
+        });
+
+    let mut pairs: Vec<(usize, usize)> = groups.into_par_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .flat_map_iter(|(_, mut indices)| {
+            indices.sort_unstable();
+            let mut group_pairs = Vec::new();
+            for (position, &i) in indices.iter().enumerate() {
+                for &j in indices[position + 1..].iter() {
+                    group_pairs.push((i, j));
+                }
+            }
+            group_pairs
+        })
+        .collect();
+    pairs.par_sort_unstable();
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{par_find_confusable_pairs, par_skeletons};
+    use Skeleton;
+
+    #[test]
+    fn skeletons_in_order() {
+        let names = ["ℝ𝓊𝓈𝓉", "Go", "𝔭𝒶ỿ𝕡𝕒ℓ"];
+        assert_eq!(par_skeletons(&names), vec![Skeleton::of("Rust"), Skeleton::of("Go"), Skeleton::of("paypal")]);
+    }
+
+    #[test]
+    fn pairs() {
+        let names = vec!["Rust", "Go", "ℝ𝓊𝓈𝓉", "paypal", "rust", "pаypаl", "𝔭𝒶ỿ𝕡𝕒ℓ", "Rust"];
+        assert_eq!(par_find_confusable_pairs(&names), vec![(0, 2), (0, 7), (2, 7), (3, 5), (3, 6), (5, 6)]);
+
+        let many: Vec<String> = (0..10000).map(|i| if i % 1000 == 0 { "admin".to_string() } else { format!("user{}", i) }).collect();
+        let pairs = par_find_confusable_pairs(&many);
+        assert_eq!(pairs.len(), 45);
+        assert_eq!(pairs[0], (0, 1000));
+        assert!(par_find_confusable_pairs::<&str>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::{Component, Path, PathBuf};
+use std::str::Chars;
+
+use {confusable, OwnedChars, SkeletonChars, UnicodeSkeleton};
+
+// OS strings that are not valid Unicode are converted lossily, with any
+// invalid sequences replaced by U+FFFD REPLACEMENT CHARACTER.
+macro_rules! os_string_skeleton_impls {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> UnicodeSkeleton for &'a $ty {
+                type Iter = OwnedChars<Cow<'a, str>>;
+
+                fn skeleton_chars(self) -> SkeletonChars<'static, Self::Iter> {
+                    OwnedChars::new(self.to_string_lossy()).skeleton_chars()
+                }
+            }
+        )*
+    }
+}
+
+os_string_skeleton_impls!(OsStr, OsString, Path, PathBuf);
+
+/// Retrieve the characters of the skeleton of an OS string, or `None` if it
+/// is not valid Unicode.
+///
+/// Borrowed `OsStr`s and `Path`s also implement `UnicodeSkeleton`, which
+/// instead replaces invalid sequences with U+FFFD REPLACEMENT CHARACTER.
+pub fn skeleton_chars_strict<'a>(s: &'a OsStr) -> Option<SkeletonChars<'static, Chars<'a>>> {
+    s.to_str().map(UnicodeSkeleton::skeleton_chars)
+}
+
+/// Test whether two paths could be visually confused for each other.
+///
+/// The paths are compared component by component, so a separator in one
+/// path is never matched against a lookalike character within a file name
+/// in the other. Root, prefix, `.` and `..` components must match exactly,
+/// and file names must be confusable.
+///
+/// # Examples
+/// ```Rust
+/// confusable_paths(Path::new("src/lib.rs"), Path::new("src/l\u{131}b.rs")) // true
+/// ```
+pub fn confusable_paths(a: &Path, b: &Path) -> bool {
+    let mut components_a = a.components();
+    let mut components_b = b.components();
+    loop {
+        match (components_a.next(), components_b.next()) {
+            (None, None) => return true,
+            (Some(Component::Normal(a)), Some(Component::Normal(b))) => {
+                if !confusable(a, b) {
+                    return false;
+                }
+            }
+            (a, b) => {
+                if a != b {
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    use super::{confusable_paths, skeleton_chars_strict};
+    use skeleton;
+
+    #[test]
+    fn os_string_skeletons() {
+        assert_eq!(skeleton(OsStr::new("ℝ𝓊𝓈𝓉")), "Rust");
+        assert_eq!(skeleton(Path::new("𝒶/𝒷")), "a/b");
+        assert_eq!(skeleton_chars_strict(OsStr::new("ℝ𝓊𝓈𝓉")).map(|chars| chars.collect::<String>()), Some("Rust".to_string()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn invalid_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let invalid = OsStr::from_bytes(b"ab\xff");
+        assert!(skeleton_chars_strict(invalid).is_none());
+        assert_eq!(skeleton(invalid), "ab\u{fffd}");
+    }
+
+    #[test]
+    fn paths() {
+        assert!(confusable_paths(Path::new("src/lib.rs"), Path::new("src/l\u{131}b.rs")));
+        assert!(confusable_paths(Path::new("/home/ℝ𝓊𝓈𝓉/"), Path::new("/home/Rust")));
+        assert!(!confusable_paths(Path::new("/home/Rust"), Path::new("home/Rust")));
+        assert!(!confusable_paths(Path::new("a/b"), Path::new("a/b/c")));
+        // U+2215 DIVISION SLASH looks like a separator, but is part of a file name.
+        assert!(!confusable_paths(Path::new("a/b"), Path::new("a\u{2215}b")));
+    }
+}
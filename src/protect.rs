@@ -0,0 +1,209 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Scanning texts for confusables of protected terms, such as brand names.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use search::SkeletonSpanTable;
+use {case_folded, caseless_skeleton_chars, UnicodeSkeleton};
+
+/// A list of terms, such as brand names, to find confusables of in texts.
+///
+/// The terms are compiled once, and each scan reports every place a text
+/// spells one of them, whether with homoglyphs or plainly. By default, only
+/// whole words are matched and case is disregarded, so "PayPal" is found in
+/// "Pay with 𝐏𝐚𝐲𝐏𝐚𝐥 today" but not in "paypals".
+///
+/// # Examples
+/// ```Rust
+/// let terms = ProtectedTerms::new(&["PayPal", "Apple"]);
+/// let hits = terms.scan("Your 𝔭𝒶ỿ𝕡𝕒ℓ and аррlе accounts");
+/// (hits[0].term, hits[0].text, hits[0].exact) // (0, "𝔭𝒶ỿ𝕡𝕒ℓ", false)
+/// (hits[1].term, hits[1].text) // (1, "аррlе")
+/// ProtectedTerms::new(&["apple"]).whole_words(false).scan("pineapples")[0].byte_range // 4..9
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProtectedTerms {
+    terms: Vec<String>,
+    // The skeletons of the terms, with and without regard to case, and the
+    // indices of the terms whose skeleton starts with each character.
+    skeletons: Vec<Vec<char>>,
+    caseless_skeletons: Vec<Vec<char>>,
+    by_first: BTreeMap<char, Vec<usize>>,
+    caseless_by_first: BTreeMap<char, Vec<usize>>,
+    whole_words: bool,
+    ignore_case: bool,
+}
+
+/// A place where a text spells a protected term. This is retrieved via
+/// `ProtectedTerms::scan`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProtectedTermHit<'t> {
+    /// The index of the term, in the order given to `ProtectedTerms::new`.
+    pub term: usize,
+    /// The text that spells the term.
+    pub text: &'t str,
+    /// The location of `text` in the scanned text.
+    pub byte_range: Range<usize>,
+    /// Whether `text` is the term itself, rather than a confusable of it,
+    /// disregarding case if `ProtectedTerms::ignore_case` is on.
+    pub exact: bool,
+}
+
+impl ProtectedTerms {
+    /// Compile a list of terms, which are numbered in order from 0. Empty
+    /// terms are never found.
+    pub fn new<I>(terms: I) -> ProtectedTerms
+        where I: IntoIterator, I::Item: AsRef<str>
+    {
+        let terms: Vec<String> = terms.into_iter().map(|term| term.as_ref().to_string()).collect();
+        let skeletons: Vec<Vec<char>> = terms.iter().map(|term| term.skeleton_chars().collect()).collect();
+        let caseless_skeletons: Vec<Vec<char>> = terms.iter().map(|term| caseless_skeleton_chars(term).collect()).collect();
+        ProtectedTerms {
+            by_first: index_by_first(&skeletons),
+            caseless_by_first: index_by_first(&caseless_skeletons),
+            terms,
+            skeletons,
+            caseless_skeletons,
+            whole_words: true,
+            ignore_case: true,
+        }
+    }
+
+    /// Set whether terms are only found as whole words, not preceded or
+    /// followed by letters or digits. On by default.
+    pub fn whole_words(mut self, whole_words: bool) -> ProtectedTerms {
+        self.whole_words = whole_words;
+        self
+    }
+
+    /// Set whether differences in case are disregarded. On by default.
+    pub fn ignore_case(mut self, ignore_case: bool) -> ProtectedTerms {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// The terms, in order.
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    /// Find every place in `text` that spells one of the terms, ordered by
+    /// where they start, then by term. Hits of different terms may overlap.
+    /// Hits are found as by `find_confusable`, so they cover whole
+    /// characters and are not followed by a combining mark.
+    pub fn scan<'t>(&self, text: &'t str) -> Vec<ProtectedTermHit<'t>> {
+        let (spans, skeletons, by_first) = if self.ignore_case {
+            (SkeletonSpanTable::caseless(text), &self.caseless_skeletons, &self.caseless_by_first)
+        } else {
+            (SkeletonSpanTable::new(text), &self.skeletons, &self.by_first)
+        };
+        let mut hits = Vec::new();
+        // The end of the last hit of each term, so hits of a term do not
+        // overlap each other.
+        let mut ends = vec![0; self.terms.len()];
+        for (start, c) in spans.chars.iter().enumerate() {
+            for &term in by_first.get(c).into_iter().flatten() {
+                let skeleton = &skeletons[term];
+                let end = start + skeleton.len();
+                if start < ends[term] || spans.chars.get(start..end) != Some(&skeleton[..]) {
+                    continue;
+                }
+                let byte_range = match spans.source_range(start, end) {
+                    Some(byte_range) => byte_range,
+                    None => continue,
+                };
+                if self.whole_words && !is_whole_word(text, &byte_range) {
+                    continue;
+                }
+                ends[term] = end;
+                let found = &text[byte_range.clone()];
+                let exact = if self.ignore_case {
+                    case_folded(found) == case_folded(&self.terms[term])
+                } else {
+                    found == self.terms[term]
+                };
+                hits.push(ProtectedTermHit { term, text: found, byte_range, exact });
+            }
+        }
+        hits.sort_by_key(|hit| (hit.byte_range.start, hit.term));
+        hits
+    }
+
+    /// Whether `text` spells any of the terms.
+    pub fn is_match(&self, text: &str) -> bool {
+        !self.scan(text).is_empty()
+    }
+}
+
+fn index_by_first(skeletons: &[Vec<char>]) -> BTreeMap<char, Vec<usize>> {
+    let mut by_first: BTreeMap<char, Vec<usize>> = BTreeMap::new();
+    for (i, skeleton) in skeletons.iter().enumerate() {
+        if let Some(&first) = skeleton.first() {
+            by_first.entry(first).or_default().push(i);
+        }
+    }
+    by_first
+}
+
+// Whether the part of `text` at `range` is not preceded or followed by a
+// letter or digit.
+fn is_whole_word(text: &str, range: &Range<usize>) -> bool {
+    let before = text[..range.start].chars().next_back();
+    let after = text[range.end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{ProtectedTermHit, ProtectedTerms};
+
+    #[test]
+    fn scans_for_terms() {
+        let terms = ProtectedTerms::new(["PayPal", "Apple", ""]);
+        let text = "Your 𝔭𝒶ỿ𝕡𝕒ℓ and аррlе accounts, and paypal";
+        let hits = terms.scan(text);
+        assert_eq!(hits, vec![
+            ProtectedTermHit { term: 0, text: "𝔭𝒶ỿ𝕡𝕒ℓ", byte_range: 5..27, exact: false },
+            ProtectedTermHit { term: 1, text: "аррlе", byte_range: 32..41, exact: false },
+            ProtectedTermHit { term: 0, text: "paypal", byte_range: 56..62, exact: true },
+        ]);
+        assert_eq!(terms.terms()[1], "Apple");
+        assert!(terms.is_match("PAYPAL"));
+        assert!(!terms.is_match(""));
+        // Case is disregarded before and after the skeleton is taken.
+        assert!(ProtectedTerms::new(["microsoft"]).is_match("MICROSOFT"));
+        assert!(ProtectedTerms::new(["MICROSOFT"]).is_match("rnicrosoft"));
+        assert!(!terms.clone().ignore_case(false).is_match("PAYPAL"));
+        assert!(!terms.ignore_case(false).scan("PаyPal")[0].exact);
+    }
+
+    #[test]
+    fn words_and_substrings() {
+        let terms = ProtectedTerms::new(["apple", "app"]);
+        assert!(terms.scan("pineapples").is_empty());
+        assert_eq!(terms.scan("an аpp, or an apple.").iter().map(|hit| hit.text).collect::<Vec<_>>(), vec!["аpp", "apple"]);
+        let terms = terms.whole_words(false);
+        let hits = terms.scan("pineapples");
+        assert_eq!(hits.iter().map(|hit| (hit.term, hit.byte_range.clone())).collect::<Vec<_>>(), vec![(0, 4..9), (1, 4..7)]);
+        // Hits of one term do not overlap.
+        assert_eq!(ProtectedTerms::new(["aa"]).whole_words(false).scan("aaaa").len(), 2);
+        // Hits cover whole characters.
+        assert!(ProtectedTerms::new(["n"]).whole_words(false).scan("m").is_empty());
+        assert!(ProtectedTerms::new(["paypal"]).scan("paypal\u{301}").is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A Python extension module, `unicode_skeleton`, through PyO3.
+//!
+//! Requires the `python` feature. The module has three functions:
+//!
+//! ```python
+//! import unicode_skeleton
+//! unicode_skeleton.skeleton("ℝ𝓊𝓈𝓉")            # 'Rust'
+//! unicode_skeleton.confusable("ℝ𝓊𝓈𝓉", "Rust")  # True
+//! unicode_skeleton.check("pаypаl")             # ['mixed_script', 'restriction_level']
+//! ```
+//!
+//! `check` runs the checks of `SpoofChecker::new`, and returns the names of
+//! those that failed in snake case, so that an empty list means the string
+//! passed. Each function can be passed to `pandas.Series.map`.
+
+use pyo3::prelude::*;
+
+use checker::{Check, SpoofChecker};
+
+/// The skeleton of `s`.
+#[pyfunction]
+pub fn skeleton(s: &str) -> String {
+    ::skeleton(s)
+}
+
+/// Test whether `a` and `b` are confusable.
+#[pyfunction]
+pub fn confusable(a: &str, b: &str) -> bool {
+    ::confusable(a, b)
+}
+
+/// The names of the checks of `SpoofChecker::new` that `s` fails.
+#[pyfunction]
+pub fn check(s: &str) -> Vec<&'static str> {
+    SpoofChecker::new().quick_check(s).iter().map(Check::name).collect()
+}
+
+#[pymodule]
+fn unicode_skeleton(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(self::skeleton, m)?)?;
+    m.add_function(wrap_pyfunction!(self::confusable, m)?)?;
+    m.add_function(wrap_pyfunction!(self::check, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn functions() {
+        assert_eq!(skeleton("ℝ𝓊𝓈𝓉"), "Rust");
+        assert!(confusable("ℝ𝓊𝓈𝓉", "Rust"));
+        assert!(!confusable("Rest", "Rust"));
+        assert_eq!(check("pаypаl"), ["mixed_script", "restriction_level"]);
+        assert!(check("paypal").is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Regular expressions that match the confusables of a literal.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::char;
+use std::collections::BTreeMap;
+
+use unicode_normalization::char::decompose_canonical;
+
+use {is_confusable_char, UnicodeSkeleton};
+
+// The characters escaped in patterns: those with a meaning of their own in
+// the `regex` crate, outside of classes or in them.
+const META_CHARACTERS: &str = "\\.+*?()|[]{}^$#&-~";
+
+/// A regular expression pattern matching `literal` and the strings made by
+/// replacing any of its characters with a character of the same skeleton,
+/// for making existing regex-based filters homoglyph-aware.
+///
+/// Each character of `literal` becomes a character class of every character
+/// whose skeleton is the same as its own, such as `[pρр𝐩𝑝…]` for "p". The
+/// characters that are special in patterns or classes, such as `.` and `-`,
+/// are escaped with a backslash, and other characters appear as themselves,
+/// which the `regex` crate, PCRE and most other engines accept. Other ASCII
+/// punctuation is left unescaped, since escapes such as `\<` are assertions
+/// to some engines.
+///
+/// Characters are replaced one for one, so this does not match confusables
+/// that replace a character with several, such as "rn" for "m", which
+/// `find_confusable` finds. Finding the classes means examining every code
+/// point, so build the pattern once and reuse it.
+///
+/// # Examples
+/// ```Rust
+/// let pattern = confusable_regex("paypal");
+/// Regex::new(&pattern).unwrap().is_match("Log in to 𝔭𝒶ỿ𝕡𝕒ℓ") // true
+/// ```
+pub fn confusable_regex(literal: &str) -> String {
+    // The characters with each skeleton of a character of `literal`.
+    let mut classes: BTreeMap<Vec<char>, Vec<char>> = literal.chars()
+        .map(|c| (c.to_string().skeleton_chars().collect(), Vec::new()))
+        .collect();
+    for_each_char_skeleton(|c, skeleton| {
+        if let Some(class) = classes.get_mut(skeleton) {
+            class.push(c);
+        }
+    });
+
+    let mut pattern = String::new();
+    for c in literal.chars() {
+        let skeleton: Vec<char> = c.to_string().skeleton_chars().collect();
+        let class = &classes[&skeleton];
+        if class.len() > 1 {
+            pattern.push('[');
+        }
+        for &member in class {
+            if META_CHARACTERS.contains(member) {
+                pattern.push('\\');
+            }
+            pattern.push(member);
+        }
+        if class.len() > 1 {
+            pattern.push(']');
+        }
+    }
+    pattern
+}
+
+// Call `f` with every character, in order of code point, and its skeleton.
+pub(crate) fn for_each_char_skeleton<F: FnMut(char, &[char])>(mut f: F) {
+    for code_point in 0..=char::MAX as u32 {
+        let c = match char::from_u32(code_point) {
+            Some(c) => c,
+            None => continue,
+        };
+        // Characters unchanged by decomposition and the table are their
+        // own skeleton, so only those need their skeletons computed.
+        let mut decomposed = false;
+        decompose_canonical(c, |d| decomposed |= d != c);
+        if decomposed || is_confusable_char(c) {
+            let skeleton: Vec<char> = c.to_string().skeleton_chars().collect();
+            f(c, &skeleton);
+        } else {
+            f(c, &[c]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use regex::Regex;
+
+    use super::confusable_regex;
+
+    #[test]
+    fn matches_confusables() {
+        let regex = Regex::new(&confusable_regex("paypal")).unwrap();
+        assert!(regex.is_match("Log in to 𝔭𝒶ỿ𝕡𝕒ℓ"));
+        assert!(regex.is_match("paypal"));
+        assert!(regex.is_match("pаypаl"));
+        assert!(!regex.is_match("paypa"));
+        assert!(!regex.is_match("paypol"));
+
+        let regex = Regex::new(&format!("^{}$", confusable_regex("ℝ𝓊𝓈𝓉"))).unwrap();
+        assert!(regex.is_match("Rust"));
+        assert!(!regex.is_match("Rusty"));
+    }
+
+    #[test]
+    fn classes() {
+        let pattern = confusable_regex("p");
+        assert!(pattern.starts_with('[') && pattern.ends_with(']'));
+        assert!(pattern.contains('p') && pattern.contains('\u{440}') && pattern.contains('𝐩'));
+        assert!(!pattern.contains('q'));
+        // "m" is confused with "rn", and these are its other confusables.
+        let regex = Regex::new(&confusable_regex("m")).unwrap();
+        assert!(regex.is_match("𝐦"));
+        assert!(!regex.is_match("rn"));
+        // Meta characters are escaped.
+        let regex = Regex::new(&format!("^{}$", confusable_regex("a.b[c]-d"))).unwrap();
+        assert!(regex.is_match("a.b[c]-d"));
+        assert!(!regex.is_match("axb[c]-d"));
+        assert_eq!(confusable_regex(""), "");
+    }
+
+    #[test]
+    fn every_ascii_punctuation_character_compiles() {
+        // Built as one pattern, since each pattern examines every code point.
+        let punctuation: String = (0u8..128).map(char::from).filter(char::is_ascii_punctuation).collect();
+        let regex = Regex::new(&format!("^{}$", confusable_regex(&punctuation))).unwrap();
+        assert!(regex.is_match(&punctuation));
+        assert!(!regex.is_match(&punctuation[1..]));
+        // `\<` and `\>` would be word boundaries, not the characters.
+        assert!(Regex::new(&confusable_regex("a<b")).unwrap().is_match("a<b"));
+        assert!(Regex::new(&confusable_regex("a>b")).unwrap().is_match("a>b"));
+    }
+}
@@ -0,0 +1,456 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Script properties of characters and strings.
+//!
+//! ```Rust
+//! script_of('a') // Script::Latin
+//! is_single_script("pаypаl") // false, the 'а's are Cyrillic
+//! dominant_script("pаypаl") // Some(Script::Latin)
+//! resolved_script_set("東京カタカナ").contains(Script::Japanese) // true
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::slice;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use data;
+
+pub use data::Script;
+
+/// Look up the Script property of a character.
+pub fn script_of(c: char) -> Script {
+    let c = c as u32;
+    match data::SCRIPT_RANGES.binary_search_by_key(&c, |range| range.0) {
+        Ok(index) => data::SCRIPT_RANGES[index].2,
+        Err(0) => Script::Unknown,
+        Err(index) => {
+            let (_, end, script) = data::SCRIPT_RANGES[index - 1];
+            if c <= end { script } else { Script::Unknown }
+        }
+    }
+}
+
+/// Look up the Script_Extensions property of a character: the scripts it is
+/// used with. For most characters this is just its script, but some, such as
+/// the prolonged sound mark 'ー', are shared by several scripts.
+///
+/// ```Rust
+/// script_extensions('ー') // [Script::Hiragana, Script::Katakana]
+/// script_extensions('a') // [Script::Latin]
+/// ```
+pub fn script_extensions(c: char) -> &'static [Script] {
+    let code = c as u32;
+    let index = match data::SCRIPT_EXTENSIONS.binary_search_by_key(&code, |range| range.0) {
+        Ok(index) => Some(index),
+        Err(0) => None,
+        Err(index) => Some(index - 1),
+    };
+    match index.map(|index| data::SCRIPT_EXTENSIONS[index]) {
+        Some((_, end, scripts)) if code <= end => scripts,
+        // `SCRIPTS` is in discriminant order
+        _ => slice::from_ref(&data::SCRIPTS[script_of(c) as usize]),
+    }
+}
+
+/// Test whether a string is written in a single script. Characters of the
+/// Common and Inherited scripts, such as digits, punctuation and combining
+/// accents, are compatible with any script, so a string made only of them is
+/// single-script.
+pub fn is_single_script(s: &str) -> bool {
+    !resolved_script_set(s).is_empty()
+}
+
+/// The script with the most characters in a string, not counting Common and
+/// Inherited characters. Ties go to the script that appears first. Returns
+/// `None` if every character is Common or Inherited.
+pub fn dominant_script(s: &str) -> Option<Script> {
+    let mut counts: Vec<(Script, usize)> = Vec::new();
+    for c in s.chars() {
+        let script = script_of(c);
+        if script == Script::Common || script == Script::Inherited {
+            continue;
+        }
+        match counts.iter_mut().find(|entry| entry.0 == script) {
+            Some(entry) => entry.1 += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    let mut dominant: Option<(Script, usize)> = None;
+    for (script, count) in counts {
+        if dominant.is_none_or(|(_, best)| count > best) {
+            dominant = Some((script, count));
+        }
+    }
+    dominant.map(|(script, _)| script)
+}
+
+/// A set of scripts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScriptSet {
+    // Indexed by `Script` discriminant
+    bits: [u64; 3],
+}
+
+impl ScriptSet {
+    /// The set containing no scripts.
+    pub fn empty() -> ScriptSet {
+        ScriptSet { bits: [0; 3] }
+    }
+
+    /// The set containing every script, which is the script set of Common
+    /// and Inherited characters.
+    pub fn all() -> ScriptSet {
+        ScriptSet::of_scripts(&data::SCRIPTS)
+    }
+
+    pub(crate) fn of_char(c: char) -> ScriptSet {
+        let mut set = ScriptSet::empty();
+        for &script in script_extensions(c) {
+            let augmented: &[Script] = match script {
+                Script::Common | Script::Inherited => return ScriptSet::all(),
+                Script::Han => &[Script::Han, Script::HanWithBopomofo, Script::Japanese, Script::Korean],
+                Script::Hiragana => &[Script::Hiragana, Script::Japanese],
+                Script::Katakana => &[Script::Katakana, Script::Japanese],
+                Script::Hangul => &[Script::Hangul, Script::Korean],
+                Script::Bopomofo => &[Script::Bopomofo, Script::HanWithBopomofo],
+                _ => &[script],
+            };
+            set.union_with(ScriptSet::of_scripts(augmented));
+        }
+        set
+    }
+
+    pub(crate) fn of_scripts(scripts: &[Script]) -> ScriptSet {
+        let mut set = ScriptSet::empty();
+        for &script in scripts {
+            set.insert(script);
+        }
+        set
+    }
+
+    /// Add a script to the set.
+    pub fn insert(&mut self, script: Script) {
+        let index = script as usize;
+        self.bits[index / 64] |= 1 << (index % 64);
+    }
+
+    /// Remove a script from the set.
+    pub fn remove(&mut self, script: Script) {
+        let index = script as usize;
+        self.bits[index / 64] &= !(1 << (index % 64));
+    }
+
+    /// Test whether a script is in the set.
+    pub fn contains(&self, script: Script) -> bool {
+        let index = script as usize;
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// The number of scripts in the set.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|bits| bits.count_ones() as usize).sum()
+    }
+
+    /// The scripts in either set.
+    pub fn union(&self, other: ScriptSet) -> ScriptSet {
+        let mut set = *self;
+        set.union_with(other);
+        set
+    }
+
+    /// The scripts in both sets.
+    pub fn intersection(&self, other: ScriptSet) -> ScriptSet {
+        let mut set = *self;
+        set.intersect_with(other);
+        set
+    }
+
+    /// The scripts in this set but not the other.
+    pub fn difference(&self, other: ScriptSet) -> ScriptSet {
+        let mut set = *self;
+        for (bits, other_bits) in set.bits.iter_mut().zip(other.bits.iter()) {
+            *bits &= !*other_bits;
+        }
+        set
+    }
+
+    /// Test whether every script in this set is in the other.
+    pub fn is_subset(&self, other: ScriptSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Iterate over the scripts in the set, in the order they are declared
+    /// in `Script`.
+    pub fn iter(&self) -> ScriptSetIter {
+        ScriptSetIter { set: *self, next: 0 }
+    }
+
+    /// Keep only the scripts that are also in the other set.
+    pub fn intersect_with(&mut self, other: ScriptSet) {
+        for (bits, other_bits) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *bits &= *other_bits;
+        }
+    }
+
+    /// Add every script of the other set.
+    pub fn union_with(&mut self, other: ScriptSet) {
+        for (bits, other_bits) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *bits |= *other_bits;
+        }
+    }
+
+    /// Test whether the sets have any script in common.
+    pub fn intersects(&self, other: ScriptSet) -> bool {
+        self.bits.iter().zip(other.bits.iter()).any(|(bits, other_bits)| bits & other_bits != 0)
+    }
+
+    /// Test whether the set contains no scripts.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&bits| bits == 0)
+    }
+}
+
+impl Default for ScriptSet {
+    fn default() -> ScriptSet {
+        ScriptSet::empty()
+    }
+}
+
+/// With the `serde` feature, a script set serializes as a sequence of
+/// `Script`s.
+#[cfg(feature = "serde")]
+impl Serialize for ScriptSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ScriptSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ScriptSet, D::Error> {
+        Ok(Vec::<Script>::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
+impl FromIterator<Script> for ScriptSet {
+    fn from_iter<I: IntoIterator<Item=Script>>(iter: I) -> ScriptSet {
+        let mut set = ScriptSet::empty();
+        for script in iter {
+            set.insert(script);
+        }
+        set
+    }
+}
+
+impl Extend<Script> for ScriptSet {
+    fn extend<I: IntoIterator<Item=Script>>(&mut self, iter: I) {
+        for script in iter {
+            self.insert(script);
+        }
+    }
+}
+
+impl IntoIterator for ScriptSet {
+    type Item = Script;
+    type IntoIter = ScriptSetIter;
+
+    fn into_iter(self) -> ScriptSetIter {
+        self.iter()
+    }
+}
+
+/// Formats as `ALL` for the set of every script, which UTS #39 uses for
+/// Common and Inherited characters, and otherwise as a braced list such as
+/// `{Cyrillic, Latin}`.
+impl fmt::Display for ScriptSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == ScriptSet::all() {
+            return f.write_str("ALL");
+        }
+        f.write_str("{")?;
+        for (i, script) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", script)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// An iterator over the scripts of a `ScriptSet`. This is retrieved via
+/// `ScriptSet::iter`.
+#[derive(Clone, Debug)]
+pub struct ScriptSetIter {
+    set: ScriptSet,
+    next: usize,
+}
+
+impl Iterator for ScriptSetIter {
+    type Item = Script;
+
+    fn next(&mut self) -> Option<Script> {
+        while self.next < data::SCRIPTS.len() {
+            let script = data::SCRIPTS[self.next];
+            self.next += 1;
+            if self.set.contains(script) {
+                return Some(script);
+            }
+        }
+        None
+    }
+}
+
+/// Test whether every character of a string can be written in at least one
+/// of a set of scripts.
+pub(crate) fn is_covered_by(s: &str, scripts: ScriptSet) -> bool {
+    s.chars().all(|c| ScriptSet::of_char(c).intersects(scripts))
+}
+
+/// The augmented script set of a character, as defined in UTS #39 section
+/// 5.1. This is the character's script extensions, plus the writing systems
+/// that use them:
+/// Han is augmented with `HanWithBopomofo`, `Japanese` and `Korean`,
+/// Hiragana and Katakana with `Japanese`, Hangul with `Korean`, and Bopomofo
+/// with `HanWithBopomofo`. Common and Inherited characters have every script.
+pub fn augmented_script_set(c: char) -> ScriptSet {
+    ScriptSet::of_char(c)
+}
+
+/// The resolved script set of a string, as defined in UTS #39 section 5.1:
+/// the intersection of the augmented script sets of its characters. An empty
+/// result means the string is mixed-script.
+pub fn resolved_script_set(s: &str) -> ScriptSet {
+    let mut set = ScriptSet::all();
+    for c in s.chars() {
+        set.intersect_with(ScriptSet::of_char(c));
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{script_of, script_extensions, is_single_script, dominant_script, augmented_script_set, resolved_script_set, is_covered_by, Script, ScriptSet};
+
+    #[test]
+    fn script_lookup() {
+        assert_eq!(script_of('a'), Script::Latin);
+        assert_eq!(script_of('а'), Script::Cyrillic);
+        assert_eq!(script_of('α'), Script::Greek);
+        assert_eq!(script_of('1'), Script::Common);
+        assert_eq!(script_of('\u{301}'), Script::Inherited);
+        assert_eq!(script_of('\u{378}'), Script::Unknown);
+        assert_eq!(script_of('\0'), Script::Common);
+        // Added in Unicode 15.0 and 16.0.
+        assert_eq!(script_of('\u{11F04}'), Script::Kawi);
+        assert_eq!(script_of('\u{1CCD6}'), Script::Common);
+    }
+
+    #[test]
+    fn extensions() {
+        assert_eq!(script_extensions('ー'), &[Script::Hiragana, Script::Katakana]);
+        assert_eq!(script_extensions('a'), &[Script::Latin]);
+        assert_eq!(script_extensions('1'), &[Script::Common]);
+        assert_eq!(script_extensions('\u{378}'), &[Script::Unknown]);
+        assert!(script_extensions('।').contains(&Script::Devanagari));
+    }
+
+    #[test]
+    fn single_script() {
+        assert!(is_single_script("paypal"));
+        assert!(is_single_script("παράδειγμα-1"));
+        assert!(is_single_script("123"));
+        assert!(is_single_script(""));
+        assert!(!is_single_script("pаypаl"));
+        assert!(is_single_script("東京カタカナ"));
+        assert!(is_single_script("서울시청"));
+        assert!(!is_single_script("東京abc"));
+        assert!(!is_single_script("カタカナ한글"));
+        // The prolonged sound mark is Common by Script, but only used with kana.
+        assert!(is_single_script("ひらがなー"));
+        assert!(!is_single_script("abcー"));
+        // The danda is shared by many Indic scripts.
+        assert!(is_single_script("नमस्ते।"));
+    }
+
+    #[test]
+    fn augmented() {
+        let han = augmented_script_set('東');
+        assert_eq!(han.len(), 4);
+        assert!(han.contains(Script::Han));
+        assert!(han.contains(Script::Japanese));
+        assert!(!han.contains(Script::Hiragana));
+        assert_eq!(augmented_script_set('a').len(), 1);
+        assert_eq!(augmented_script_set('1'), ScriptSet::all());
+    }
+
+    #[test]
+    fn dominant() {
+        assert_eq!(dominant_script("pаypаl"), Some(Script::Latin));
+        assert_eq!(dominant_script("раурal"), Some(Script::Cyrillic));
+        assert_eq!(dominant_script("aа"), Some(Script::Latin));
+        assert_eq!(dominant_script("аa"), Some(Script::Cyrillic));
+        assert_eq!(dominant_script("1 + 1\u{301}"), None);
+    }
+
+    #[test]
+    fn resolution() {
+        assert_eq!(resolved_script_set("paypal"), ScriptSet::of_char('p'));
+        assert!(resolved_script_set("pаypаl").is_empty());
+        assert_eq!(resolved_script_set("1.5"), ScriptSet::all());
+        let japanese = resolved_script_set("東京カタカナ");
+        assert_eq!(japanese.len(), 1);
+        assert!(japanese.contains(Script::Japanese));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let latin_cyrillic: ScriptSet = vec![Script::Latin, Script::Cyrillic].into_iter().collect();
+        let latin_greek: ScriptSet = vec![Script::Latin, Script::Greek].into_iter().collect();
+        assert_eq!(latin_cyrillic.union(latin_greek).len(), 3);
+        assert_eq!(latin_cyrillic.intersection(latin_greek).iter().collect::<Vec<_>>(), vec![Script::Latin]);
+        assert_eq!(latin_cyrillic.difference(latin_greek).iter().collect::<Vec<_>>(), vec![Script::Cyrillic]);
+        assert!(latin_cyrillic.intersection(latin_greek).is_subset(latin_cyrillic));
+        assert!(!latin_cyrillic.is_subset(latin_greek));
+
+        let mut set = latin_cyrillic;
+        set.remove(Script::Latin);
+        assert!(!set.contains(Script::Latin));
+        assert!(set.contains(Script::Cyrillic));
+        set.extend(vec![Script::Han]);
+        assert_eq!(set.len(), 2);
+        assert_eq!(ScriptSet::all().iter().count(), ScriptSet::all().len());
+    }
+
+    #[test]
+    fn display() {
+        let set: ScriptSet = vec![Script::Latin, Script::Cyrillic].into_iter().collect();
+        assert_eq!(set.to_string(), "{Cyrillic, Latin}");
+        assert_eq!(ScriptSet::empty().to_string(), "{}");
+        assert_eq!(ScriptSet::all().to_string(), "ALL");
+        assert_eq!(augmented_script_set('ー').to_string(), "{Hiragana, Katakana, Japanese}");
+    }
+
+    #[test]
+    fn coverage() {
+        let latin_cyrillic = ScriptSet::of_scripts(&[Script::Latin, Script::Cyrillic]);
+        assert!(is_covered_by("pаypаl1", latin_cyrillic));
+        assert!(!is_covered_by("pαypαl", latin_cyrillic));
+    }
+}
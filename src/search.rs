@@ -0,0 +1,192 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Finding confusables of a string inside a longer text.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::ops::Range;
+
+use unicode_normalization::char::is_combining_mark;
+
+use spans::skeleton_spans;
+use {case_fold, UnicodeSkeleton};
+
+/// Find the first place where a string confusable with `needle` appears in
+/// `haystack`, returning its byte range in `haystack`.
+///
+/// The skeleton of `needle` is searched for in the skeleton of `haystack`.
+/// A match must cover whole characters of `haystack`, and must not be
+/// followed by a combining mark, so "paypal" is not found in "paypaĺ", nor
+/// "n" in "m", whose skeleton is "rn". An empty `needle` is never found.
+///
+/// # Examples
+/// ```Rust
+/// find_confusable("Log in to your 𝔭𝒶ỿ𝕡𝕒ℓ account", "paypal") // Some(15..37)
+/// find_confusable("Log in to your account", "paypal") // None
+/// ```
+pub fn find_confusable(haystack: &str, needle: &str) -> Option<Range<usize>> {
+    find_confusables(haystack, needle).next()
+}
+
+/// Iterate over the byte ranges of the places where strings confusable with
+/// `needle` appear in `haystack`, from left to right and without overlaps.
+/// Matches are found as by `find_confusable`.
+///
+/// # Examples
+/// ```Rust
+/// find_confusables("rust ℝ𝓊𝓈𝓉 Rust", "Rust").collect::<Vec<_>>() // [5..20, 21..25]
+/// ```
+pub fn find_confusables(haystack: &str, needle: &str) -> ConfusableMatches {
+    ConfusableMatches {
+        spans: SkeletonSpanTable::new(haystack),
+        needle: needle.skeleton_chars().collect(),
+        position: 0,
+    }
+}
+
+/// An iterator over the places in a text where a string confusable with a
+/// needle appears. This is retrieved via `find_confusables`.
+#[derive(Debug, Clone)]
+pub struct ConfusableMatches {
+    spans: SkeletonSpanTable,
+    needle: Vec<char>,
+    position: usize,
+}
+
+impl Iterator for ConfusableMatches {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        if self.needle.is_empty() {
+            return None;
+        }
+        let chars = &self.spans.chars;
+        while self.position + self.needle.len() <= chars.len() {
+            let start = self.position;
+            let end = start + self.needle.len();
+            if chars[start..end] == self.needle[..] {
+                if let Some(range) = self.spans.source_range(start, end) {
+                    self.position = end;
+                    return Some(range);
+                }
+            }
+            self.position += 1;
+        }
+        None
+    }
+}
+
+/// The skeleton of a text, with the byte range in the text of the character
+/// each skeleton character came from.
+#[derive(Debug, Clone)]
+pub(crate) struct SkeletonSpanTable {
+    pub(crate) chars: Vec<char>,
+    ranges: Vec<Range<usize>>,
+}
+
+impl SkeletonSpanTable {
+    pub(crate) fn new(text: &str) -> SkeletonSpanTable {
+        let (ranges, chars) = skeleton_spans(text).unzip();
+        SkeletonSpanTable { chars, ranges }
+    }
+
+    /// The same for a text case folded, as by `caseless_skeleton_chars`: the
+    /// text is case folded before its skeleton is computed, and the skeleton
+    /// afterwards. Ranges are still in the original text.
+    pub(crate) fn caseless(text: &str) -> SkeletonSpanTable {
+        // The case folded text, and for each of its characters, its offset
+        // and the range in `text` of the character it came from.
+        let mut lower = String::with_capacity(text.len());
+        let mut origins = Vec::with_capacity(text.len());
+        for (i, c) in text.char_indices() {
+            for lower_c in case_fold(c) {
+                origins.push((lower.len(), i..i + c.len_utf8()));
+                lower.push(lower_c);
+            }
+        }
+        let mut chars = Vec::with_capacity(lower.len());
+        let mut ranges = Vec::with_capacity(lower.len());
+        for (range, c) in skeleton_spans(&lower) {
+            // Spans are of whole characters of `lower`.
+            let index = origins.binary_search_by_key(&range.start, |origin| origin.0).unwrap();
+            let origin = origins[index].1.clone();
+            for lower_c in case_fold(c) {
+                chars.push(lower_c);
+                ranges.push(origin.clone());
+            }
+        }
+        SkeletonSpanTable { chars, ranges }
+    }
+
+    /// Whether skeleton character `i` is the first that came from its
+    /// character of the text, or `i` is the end of the skeleton.
+    pub(crate) fn starts_char(&self, i: usize) -> bool {
+        i == 0 || i == self.ranges.len() || self.ranges[i] != self.ranges[i - 1]
+    }
+
+    /// The byte range of the text that the skeleton characters `start..end`
+    /// came from, if they cover whole characters of the text and are not
+    /// followed by a combining mark.
+    pub(crate) fn source_range(&self, start: usize, end: usize) -> Option<Range<usize>> {
+        if !self.starts_char(start) || !self.starts_char(end) {
+            return None;
+        }
+        if self.chars.get(end).is_some_and(|&c| is_combining_mark(c)) {
+            return None;
+        }
+        let ranges = &self.ranges;
+        // Canonical reordering can move a mark's range before its base's.
+        let ranges = &ranges[start..end];
+        let source_start = ranges.iter().map(|range| range.start).min()?;
+        let source_end = ranges.iter().map(|range| range.end).max()?;
+        Some(source_start..source_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{find_confusable, find_confusables};
+
+    #[test]
+    fn finds_confusables() {
+        let text = "Log in to your 𝔭𝒶ỿ𝕡𝕒ℓ account";
+        let range = find_confusable(text, "paypal").unwrap();
+        assert_eq!(&text[range], "𝔭𝒶ỿ𝕡𝕒ℓ");
+        assert_eq!(find_confusable("Log in to your account", "paypal"), None);
+        assert_eq!(find_confusable("paypal", ""), None);
+        assert_eq!(find_confusable("", "paypal"), None);
+        // The needle is compared by skeleton too.
+        assert_eq!(find_confusable("paypal", "pаypаl"), Some(0..6));
+    }
+
+    #[test]
+    fn matches_cover_whole_characters() {
+        assert_eq!(find_confusable("m", "n"), None);
+        assert_eq!(find_confusable("m", "rn"), Some(0..1));
+        assert_eq!(find_confusable("rn", "m"), Some(0..2));
+        assert_eq!(find_confusable("paypaĺ", "paypal"), None);
+        assert_eq!(find_confusable("paypalé", "paypal"), Some(0..6));
+        assert_eq!(find_confusable("paypal\u{301}", "paypal"), None);
+        assert_eq!(find_confusable("café", "cafe\u{301}"), Some(0..5));
+        assert_eq!(find_confusable("a café", "café"), Some(2..7));
+    }
+
+    #[test]
+    fn finds_every_match() {
+        let text = "rust ℝ𝓊𝓈𝓉 Rust";
+        let ranges: Vec<_> = find_confusables(text, "Rust").collect();
+        assert_eq!(ranges.iter().map(|range| &text[range.clone()]).collect::<Vec<_>>(), vec!["ℝ𝓊𝓈𝓉", "Rust"]);
+        assert_eq!(find_confusables("aaaa", "aa").count(), 2);
+    }
+}
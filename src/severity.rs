@@ -0,0 +1,247 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+use {confusable, prototype_of};
+use data;
+use scripts::{resolved_script_set, ScriptSet};
+use spans::skeleton_diff;
+
+/// How two confusable strings relate in terms of scripts, following the
+/// classes of UTS #39 section 4. Variants are ordered from least to most
+/// suspicious.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfusableKind {
+    /// The strings are canonically equivalent, so they are the same text.
+    Identical,
+    /// The strings share a script, such as "rn" and "m".
+    SingleScript,
+    /// The strings share no script and at least one of them mixes scripts,
+    /// such as "paypal" and "pаypаl" with Cyrillic 'а's.
+    MixedScript,
+    /// Each string is written in a single script, but not the same one, such
+    /// as Latin "scope" and Cyrillic "ѕсоре".
+    WholeScript,
+}
+
+/// A graded measure of how two confusable strings differ. This is retrieved
+/// via `confusability`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Confusability {
+    /// How the scripts of the strings relate.
+    pub kind: ConfusableKind,
+    /// The number of characters, in both strings together, that were replaced
+    /// by a different prototype to reach the shared skeleton.
+    pub substitutions: usize,
+    /// The number of characters in both strings together.
+    pub chars: usize,
+    /// Whether the strings differ only by characters that are intentionally
+    /// confusable with each other, as with `is_intentionally_confusable`.
+    pub intentional: bool,
+}
+
+impl Confusability {
+    /// A score between 0.0 and 1.0 for ranking review queues, higher being
+    /// more suspicious. Each kind has its own quarter of the range, from 0.0
+    /// for `Identical` to 0.75 and above for `WholeScript`; within it, the
+    /// score grows with the fraction of characters that were substituted.
+    pub fn score(&self) -> f64 {
+        let base = match self.kind {
+            ConfusableKind::Identical => return 0.0,
+            ConfusableKind::SingleScript => 0.25,
+            ConfusableKind::MixedScript => 0.5,
+            ConfusableKind::WholeScript => 0.75,
+        };
+        let fraction = if self.chars == 0 { 0.0 } else { self.substitutions as f64 / self.chars as f64 };
+        base + 0.25 * fraction
+    }
+}
+
+/// Grade how confusable two strings are. Returns `None` if they are not
+/// confusable at all.
+///
+/// ```Rust
+/// confusability("paypal", "pаypаl").unwrap().kind // ConfusableKind::MixedScript
+/// confusability("paypal", "paypai") // None
+/// ```
+pub fn confusability(a: &str, b: &str) -> Option<Confusability> {
+    if !confusable(a, b) {
+        return None;
+    }
+    let chars = a.chars().count() + b.chars().count();
+    if a.nfd().eq(b.nfd()) {
+        return Some(Confusability { kind: ConfusableKind::Identical, substitutions: 0, chars, intentional: false });
+    }
+
+    let a_scripts = resolved_script_set(a);
+    let b_scripts = resolved_script_set(b);
+    let kind = if a_scripts.intersects(b_scripts) {
+        ConfusableKind::SingleScript
+    } else if !a_scripts.is_empty() && !b_scripts.is_empty() {
+        ConfusableKind::WholeScript
+    } else {
+        ConfusableKind::MixedScript
+    };
+    let substitutions = skeleton_diff(a).len() + skeleton_diff(b).len();
+    let intentional = differs_intentionally(a, b);
+    Some(Confusability { kind, substitutions, chars, intentional })
+}
+
+/// The scripts, other than its own, in which a string has a whole-script
+/// confusable, following UTS #39 section 4: the scripts in which every
+/// character of its NFD that is not Common or Inherited has a character of
+/// the same prototype. A string that mixes scripts, or has no characters but
+/// Common and Inherited ones, has none.
+///
+/// ```Rust
+/// whole_script_confusables("ѕсоре").contains(Script::Latin) // true
+/// whole_script_confusables("scope").contains(Script::Cyrillic) // true
+/// whole_script_confusables("жук").is_empty() // true
+/// ```
+pub fn whole_script_confusables(s: &str) -> ScriptSet {
+    let own = resolved_script_set(s);
+    if own.is_empty() || own == ScriptSet::all() {
+        return ScriptSet::empty();
+    }
+    let mut scripts = ScriptSet::all();
+    for c in s.nfd().filter(|&c| ScriptSet::of_char(c) != ScriptSet::all()) {
+        scripts.intersect_with(lookalike_scripts(c));
+    }
+    scripts.difference(own)
+}
+
+// The scripts of `c` and of the characters with the same prototype as it,
+// other than Common and Inherited characters, which suit every script.
+fn lookalike_scripts(c: char) -> ScriptSet {
+    let itself = [c];
+    let prototype = prototype_of(c).unwrap_or(&itself);
+    let mut scripts = ScriptSet::empty();
+    let mut add = |d: char| {
+        let set = ScriptSet::of_char(d);
+        if set != ScriptSet::all() {
+            scripts.union_with(set);
+        }
+    };
+    if let [p] = *prototype {
+        add(p);
+    }
+    for (source, source_prototype) in data::CONFUSABLES.entries() {
+        if source_prototype == prototype {
+            add(source);
+        }
+    }
+    scripts
+}
+
+/// Test whether two characters are intentionally confusable: distinct
+/// characters, usually of different scripts, that are designed to look the
+/// same, such as Latin 'a' and Cyrillic 'а'. The test is symmetric.
+///
+/// Many registrar policies treat such pairs more strictly than other
+/// confusables. Unicode lists them in intentional.txt, which is not yet
+/// bundled with the crate; until it is, the pairs are derived from the
+/// confusables table instead: letters of the same case from two of Latin,
+/// Greek and Cyrillic that share a single-character prototype, such as 'a'
+/// and 'а', but not 'r' and 'г', whose uppercase forms differ. Policies that
+/// must follow Unicode's list exactly should not rely on this yet.
+///
+/// ```Rust
+/// is_intentionally_confusable('a', '\u{430}') // true
+/// is_intentionally_confusable('r', '\u{433}') // false, CYRILLIC SMALL LETTER GHE
+/// ```
+pub fn is_intentionally_confusable(a: char, b: char) -> bool {
+    let pair = if a < b { (a as u32, b as u32) } else { (b as u32, a as u32) };
+    data::INTENTIONAL_PAIRS.binary_search(&pair).is_ok()
+}
+
+// Whether the decompositions of `a` and `b` have the same length and differ
+// only, and at least once, by intentionally confusable characters.
+fn differs_intentionally(a: &str, b: &str) -> bool {
+    let mut a = a.nfd();
+    let mut b = b.nfd();
+    let mut differs = false;
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return differs,
+            (Some(x), Some(y)) if x == y => {}
+            (Some(x), Some(y)) if is_intentionally_confusable(x, y) => differs = true,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{confusability, is_intentionally_confusable, whole_script_confusables, ConfusableKind};
+    use data::Script;
+
+    #[test]
+    fn kinds() {
+        assert_eq!(confusability("paypal", "paypai"), None);
+        assert_eq!(confusability("caf\u{e9}", "cafe\u{301}").unwrap().kind, ConfusableKind::Identical);
+        assert_eq!(confusability("rn", "m").unwrap().kind, ConfusableKind::SingleScript);
+        assert_eq!(confusability("paypal", "pаypаl").unwrap().kind, ConfusableKind::MixedScript);
+        assert_eq!(confusability("scope", "ѕсоре").unwrap().kind, ConfusableKind::WholeScript);
+    }
+
+    #[test]
+    fn whole_script() {
+        let scripts = whole_script_confusables("ѕсоре");
+        assert!(scripts.contains(Script::Latin));
+        assert!(!scripts.contains(Script::Cyrillic));
+        assert!(whole_script_confusables("scope").contains(Script::Cyrillic));
+        // Common characters, and marks after NFD, suit every script.
+        assert!(whole_script_confusables("ѕсоре-1").contains(Script::Latin));
+        assert!(whole_script_confusables("ѐ").contains(Script::Latin));
+        assert!(whole_script_confusables("жук").is_empty());
+        assert!(whole_script_confusables("pаypаl").is_empty());
+        assert!(whole_script_confusables("123").is_empty());
+        assert!(whole_script_confusables("").is_empty());
+    }
+
+    #[test]
+    fn substitutions() {
+        let result = confusability("paypal", "pаypаl").unwrap();
+        assert_eq!(result.substitutions, 2);
+        assert_eq!(result.chars, 12);
+        assert_eq!(confusability("scope", "ѕсоре").unwrap().substitutions, 5);
+    }
+
+    #[test]
+    fn scores_rank_by_kind_then_substitutions() {
+        let identical = confusability("abc", "abc").unwrap().score();
+        let single = confusability("rn", "m").unwrap().score();
+        let one_letter = confusability("paypal", "pаypal").unwrap().score();
+        let two_letters = confusability("paypal", "pаypаl").unwrap().score();
+        let whole = confusability("scope", "ѕсоре").unwrap().score();
+        assert_eq!(identical, 0.0);
+        assert!(identical < single && single < one_letter && one_letter < two_letters && two_letters < whole);
+        assert!(whole <= 1.0);
+    }
+
+    #[test]
+    fn intentional() {
+        assert!(is_intentionally_confusable('a', '\u{430}'));
+        assert!(is_intentionally_confusable('\u{430}', 'a'));
+        assert!(is_intentionally_confusable('A', '\u{391}'));
+        assert!(!is_intentionally_confusable('a', 'a'));
+        assert!(!is_intentionally_confusable('r', '\u{433}'));
+        assert!(!is_intentionally_confusable('m', 'n'));
+
+        assert!(confusability("paypal", "pаypаl").unwrap().intentional);
+        assert!(confusability("scope", "ѕсоре").unwrap().intentional);
+        assert!(!confusability("rn", "m").unwrap().intentional);
+        assert!(!confusability("abc", "abc").unwrap().intentional);
+        assert!(!confusability("ℝust", "Rust").unwrap().intentional);
+    }
+}
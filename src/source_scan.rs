@@ -0,0 +1,249 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Scanning of source code for homoglyph and bidi attacks.
+//!
+//! The scanner understands C-family syntax: `//` and `/* */` comments, and
+//! double-quoted strings with backslash escapes. Everything else is code.
+//!
+//! ```Rust
+//! for finding in scan("let pаypаl = 1; // \u{202E}") {
+//!     println!("{}:{}: {:?}", finding.line, finding.column, finding.kind);
+//! }
+//! // 1:5: ConfusableIdentifier { ascii: "paypal" }
+//! // 1:20: BidiControl { context: Comment, unbalanced: true }
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::ops::Range;
+
+use unicode_normalization::char::is_combining_mark;
+
+use bidi::{bidi_controls, is_bidi_control};
+use checker::is_default_ignorable;
+use skeleton;
+
+/// The lexical context of a character in source code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Context {
+    /// Outside comments and strings.
+    Code,
+    /// Inside a line or block comment.
+    Comment,
+    /// Inside a string literal.
+    String,
+}
+
+/// What a `SourceFinding` is about.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+    /// An identifier with non-ASCII characters that is confusable with the
+    /// ASCII identifier `ascii`.
+    ConfusableIdentifier {
+        /// The ASCII identifier it can be mistaken for.
+        ascii: String,
+    },
+    /// A bidirectional formatting character.
+    BidiControl {
+        /// Where the character appears.
+        context: Context,
+        /// Whether the character is unbalanced on its line; see
+        /// `bidi_controls`.
+        unbalanced: bool,
+    },
+    /// A default ignorable character other than a bidi control, which is
+    /// normally rendered invisibly.
+    Invisible {
+        /// Where the character appears.
+        context: Context,
+    },
+}
+
+/// A suspicious part of source text. This is retrieved via `scan`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceFinding<'a> {
+    /// What was found.
+    pub kind: FindingKind,
+    /// The offending text.
+    pub text: &'a str,
+    /// The location of `text` in the source.
+    pub byte_range: Range<usize>,
+    /// The line `text` starts on, counting from 1.
+    pub line: usize,
+    /// The column `text` starts at, in characters, counting from 1.
+    pub column: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Code,
+    LineComment,
+    BlockComment,
+    String,
+    StringEscape,
+}
+
+impl State {
+    fn context(self) -> Context {
+        match self {
+            State::Code => Context::Code,
+            State::LineComment | State::BlockComment => Context::Comment,
+            State::String | State::StringEscape => Context::String,
+        }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || is_combining_mark(c)
+}
+
+/// Scan source text for identifiers confusable with ASCII identifiers, bidi
+/// controls and invisible characters. Findings are in order of position.
+pub fn scan<'a>(source: &'a str) -> Vec<SourceFinding<'a>> {
+    let unbalanced: Vec<usize> = bidi_controls(source).into_iter()
+        .filter(|control| control.unbalanced)
+        .map(|control| control.byte_offset)
+        .collect();
+
+    let mut findings = Vec::new();
+    let mut state = State::Code;
+    let mut identifier_start = None;
+    let mut chars = source.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let context = state.context();
+        let range = i..i + c.len_utf8();
+        if is_bidi_control(c) {
+            let unbalanced = unbalanced.binary_search(&i).is_ok();
+            findings.push((range.clone(), FindingKind::BidiControl { context, unbalanced }));
+        } else if is_default_ignorable(c) {
+            findings.push((range.clone(), FindingKind::Invisible { context }));
+        }
+
+        if state == State::Code && is_identifier_char(c) {
+            identifier_start.get_or_insert(i);
+        } else if let Some(start) = identifier_start.take() {
+            findings.extend(confusable_identifier(source, start..i));
+        }
+
+        let next = chars.peek().map(|&(_, next)| next);
+        state = match (state, c) {
+            (State::Code, '/') if next == Some('/') => { chars.next(); State::LineComment }
+            (State::Code, '/') if next == Some('*') => { chars.next(); State::BlockComment }
+            (State::Code, '"') => State::String,
+            (State::LineComment, '\n') => State::Code,
+            (State::BlockComment, '*') if next == Some('/') => { chars.next(); State::Code }
+            (State::String, '\\') => State::StringEscape,
+            (State::String, '"') => State::Code,
+            (State::StringEscape, _) => State::String,
+            (state, _) => state,
+        };
+    }
+    if let Some(start) = identifier_start {
+        findings.extend(confusable_identifier(source, start..source.len()));
+    }
+
+    findings.sort_by_key(|finding| finding.0.start);
+    let mut lines = LineCounter { source, line: 1, line_start: 0, offset: 0 };
+    findings.into_iter().map(|(byte_range, kind)| {
+        let (line, column) = lines.position(byte_range.start);
+        SourceFinding { kind, text: &source[byte_range.clone()], byte_range, line, column }
+    }).collect()
+}
+
+fn confusable_identifier(source: &str, range: Range<usize>) -> Option<(Range<usize>, FindingKind)> {
+    let identifier = &source[range.clone()];
+    if identifier.is_ascii() {
+        return None;
+    }
+    let ascii = skeleton(identifier);
+    if ascii.is_ascii() {
+        Some((range, FindingKind::ConfusableIdentifier { ascii }))
+    } else {
+        None
+    }
+}
+
+// Converts increasing byte offsets to line and column numbers.
+struct LineCounter<'a> {
+    source: &'a str,
+    line: usize,
+    line_start: usize,
+    offset: usize,
+}
+
+impl<'a> LineCounter<'a> {
+    fn position(&mut self, offset: usize) -> (usize, usize) {
+        for (i, c) in self.source[self.offset..offset].char_indices() {
+            if c == '\n' {
+                self.line += 1;
+                self.line_start = self.offset + i + 1;
+            }
+        }
+        self.offset = offset;
+        (self.line, self.source[self.line_start..offset].chars().count() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{scan, Context, FindingKind, SourceFinding};
+
+    #[test]
+    fn confusable_identifiers() {
+        let findings = scan("let pаypаl = café;\nlet x = pаypаl;");
+        assert_eq!(findings, vec![
+            SourceFinding {
+                kind: FindingKind::ConfusableIdentifier { ascii: "paypal".to_string() },
+                text: "pаypаl",
+                byte_range: 4..12,
+                line: 1,
+                column: 5,
+            },
+            SourceFinding {
+                kind: FindingKind::ConfusableIdentifier { ascii: "paypal".to_string() },
+                text: "pаypаl",
+                byte_range: 30..38,
+                line: 2,
+                column: 9,
+            },
+        ]);
+    }
+
+    #[test]
+    fn identifiers_in_comments_and_strings_are_ignored() {
+        assert_eq!(scan("// pаypаl\n/* pаypаl */ \"pаypаl\""), vec![]);
+    }
+
+    #[test]
+    fn bidi_contexts() {
+        let source = "if x { /* \u{202E} } \u{2066}*/ s = \"\u{2067}\u{2069}\"; }";
+        let kinds: Vec<FindingKind> = scan(source).into_iter().map(|finding| finding.kind).collect();
+        assert_eq!(kinds, vec![
+            FindingKind::BidiControl { context: Context::Comment, unbalanced: true },
+            FindingKind::BidiControl { context: Context::Comment, unbalanced: true },
+            FindingKind::BidiControl { context: Context::String, unbalanced: false },
+            FindingKind::BidiControl { context: Context::String, unbalanced: false },
+        ]);
+    }
+
+    #[test]
+    fn invisible_chars() {
+        let findings = scan("let a\u{200B}b = \"\\\"\u{FEFF}\";");
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].kind, FindingKind::Invisible { context: Context::Code });
+        assert_eq!((findings[0].line, findings[0].column), (1, 6));
+        assert_eq!(findings[1].kind, FindingKind::Invisible { context: Context::String });
+        assert_eq!(findings[1].column, 14);
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::collections::{BTreeMap, VecDeque};
+use std::iter::Peekable;
+use std::ops::Range;
+use std::str::CharIndices;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::{canonical_combining_class, decompose_canonical};
+
+use data;
+use decompose::starts_with_starter;
+use PrototypeCharsIterator;
+
+/// Iterate over the characters of the skeleton of `s`, each paired with the
+/// byte range of the character in `s` it was derived from.
+///
+/// The characters yielded are exactly those of `skeleton_chars()`.
+///
+/// # Examples
+/// ```Rust
+/// skeleton_spans("a𝒷").collect::<Vec<_>>() // [(0..1, 'a'), (1..5, 'b')]
+/// ```
+pub fn skeleton_spans<'a>(s: &'a str) -> SkeletonSpans<'a> {
+    SkeletonSpans {
+        source: s.char_indices().peekable(),
+        source_len: s.len(),
+        decomposed: Vec::new(),
+        pending: VecDeque::new(),
+    }
+}
+
+/// An iterator over the characters of a skeleton along with the byte ranges
+/// of the source characters they came from. This is retrieved via `skeleton_spans`.
+pub struct SkeletonSpans<'a> {
+    source: Peekable<CharIndices<'a>>,
+    source_len: usize,
+    decomposed: Vec<(Range<usize>, char)>,
+    pending: VecDeque<(Range<usize>, char)>,
+}
+
+impl<'a> SkeletonSpans<'a> {
+    fn next_source_char(&mut self) -> Option<(Range<usize>, char)> {
+        let (start, c) = self.source.next()?;
+        let end = self.source.peek().map(|&(i, _)| i).unwrap_or(self.source_len);
+        Some((start..end, c))
+    }
+
+    /// Decompose the source up to the next character whose decomposition
+    /// begins with a starter. Canonical reordering never crosses such a
+    /// character, so each of these segments can be normalized independently.
+    fn fill_pending(&mut self) {
+        self.decomposed.clear();
+        while let Some((range, c)) = self.next_source_char() {
+            {
+                let decomposed = &mut self.decomposed;
+                decompose_canonical(c, |d| decomposed.push((range.clone(), d)));
+            }
+            match self.source.peek() {
+                Some(&(_, next)) if !starts_with_starter(next) => {}
+                _ => break,
+            }
+        }
+
+        // Canonical ordering: stably sort each run of non-starters by combining class.
+        let mut run_start = 0;
+        for i in 0..self.decomposed.len() + 1 {
+            let is_starter = self.decomposed.get(i).map(|&(_, d)| canonical_combining_class(d) == 0).unwrap_or(true);
+            if is_starter {
+                self.decomposed[run_start..i].sort_by_key(|&(_, d)| canonical_combining_class(d));
+                run_start = i + 1;
+            }
+        }
+
+        for &(ref range, d) in self.decomposed.iter() {
+            for output in PrototypeCharsIterator::new(d, data::CONFUSABLES.prototype_of(d)) {
+                self.pending.push_back((range.clone(), output));
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for SkeletonSpans<'a> {
+    type Item = (Range<usize>, char);
+
+    fn next(&mut self) -> Option<(Range<usize>, char)> {
+        while self.pending.is_empty() {
+            self.source.peek()?;
+            self.fill_pending();
+        }
+        self.pending.pop_front()
+    }
+}
+
+/// A character of a string that was replaced when computing its skeleton.
+/// These are retrieved via `skeleton_diff`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution<'a> {
+    /// The original character.
+    pub original: &'a str,
+    /// The skeleton characters it was replaced with, in NFD.
+    pub replacement: String,
+    /// The byte range of `original` in the source string.
+    pub byte_range: Range<usize>,
+}
+
+/// List the characters of `s` that were replaced by different characters in
+/// its skeleton, with what they were replaced by.
+///
+/// Characters whose only change is canonical decomposition (such as "é"
+/// becoming "e" followed by a combining acute accent) are not listed.
+///
+/// # Examples
+/// ```Rust
+/// skeleton_diff("p\u{430}y")
+/// // [Substitution { original: "\u{430}", replacement: "a", byte_range: 1..3 }]
+/// ```
+pub fn skeleton_diff<'a>(s: &'a str) -> Vec<Substitution<'a>> {
+    let mut replacements: BTreeMap<usize, (Range<usize>, String)> = BTreeMap::new();
+    for (range, c) in skeleton_spans(s) {
+        replacements.entry(range.start).or_insert_with(|| (range, String::new())).1.push(c);
+    }
+
+    let mut substitutions = Vec::new();
+    for (start, c) in s.char_indices() {
+        let end = start + c.len_utf8();
+        let replacement = replacements.remove(&start).map(|(_, replacement)| replacement).unwrap_or_default();
+        if !Some(c).into_iter().nfd().eq(replacement.chars()) {
+            substitutions.push(Substitution {
+                original: &s[start..end],
+                replacement,
+                byte_range: start..end,
+            });
+        }
+    }
+    substitutions
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{skeleton_spans, skeleton_diff, Substitution};
+    use skeleton;
+
+    #[test]
+    fn spans_match_skeleton() {
+        for s in &["ℝ𝓊𝓈𝓉", "𝔭𝒶ỿ𝕡𝕒ℓ", "Plain letters", "a\u{301}\u{316}b", "\u{e9}\u{316}", "\u{2fa1d}", ""] {
+            assert_eq!(skeleton_spans(s).map(|(_, c)| c).collect::<String>(), skeleton(*s));
+        }
+    }
+
+    #[test]
+    fn spans_point_at_source() {
+        assert_eq!(skeleton_spans("a𝒷m").collect::<Vec<_>>(), vec![(0..1, 'a'), (1..5, 'b'), (5..6, 'r'), (5..6, 'n')]);
+        // The acute accent from "é" is reordered after the grave accent below.
+        assert_eq!(skeleton_spans("\u{e9}\u{316}").collect::<Vec<_>>(), vec![(0..2, 'e'), (2..4, '\u{316}'), (0..2, '\u{301}')]);
+    }
+
+    #[test]
+    fn diff_lists_substitutions() {
+        assert_eq!(skeleton_diff("p\u{430}y\u{e9}"), vec![
+            Substitution { original: "\u{430}", replacement: "a".to_string(), byte_range: 1..3 },
+        ]);
+        assert_eq!(skeleton_diff("ℝust"), vec![
+            Substitution { original: "ℝ", replacement: "R".to_string(), byte_range: 0..3 },
+        ]);
+        assert_eq!(skeleton_diff("Plain letters"), vec![]);
+    }
+}
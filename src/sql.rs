@@ -0,0 +1,131 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Binding skeletons to database queries, with sqlx or diesel.
+
+#[cfg(feature = "diesel")]
+use diesel::backend::Backend;
+#[cfg(feature = "diesel")]
+use diesel::serialize::{self, Output, ToSql};
+#[cfg(feature = "diesel")]
+use diesel::sql_types::Text;
+#[cfg(feature = "diesel")]
+use diesel::expression::AsExpression;
+#[cfg(feature = "sqlx")]
+use sqlx::encode::IsNull;
+#[cfg(feature = "sqlx")]
+use sqlx::error::BoxDynError;
+#[cfg(feature = "sqlx")]
+use sqlx::{Database, Encode, Type};
+
+use {DataVersion, Skeleton};
+
+/// A string to be stored by its skeleton, such as a username in a column
+/// with a unique index, so that no two stored names are confusable.
+///
+/// When bound to a query, with the `sqlx` or `diesel` feature, the value
+/// sent to the database is the skeleton, as text. The original string is
+/// kept alongside, to be stored in a column of its own. Reading a skeleton
+/// column back gives a `String`, since the original cannot be recovered
+/// from it.
+///
+/// # Stability
+/// Skeletons computed with the same `DataVersion` by the same version of
+/// this crate are the same on every platform and build. They can change
+/// when `UNICODE_VERSION` changes, or when a new version of the crate fixes
+/// how they are computed, which its changelog will say. A table of
+/// skeletons should therefore record the version it was computed with, and
+/// be recomputed from the originals when that changes. `with_version` pins
+/// the confusables data to an older version while that happens.
+///
+/// # Examples
+/// ```Rust
+/// let name = SkeletonNormalized::new("ℝ𝓊𝓈𝓉".to_string());
+/// sqlx::query("INSERT INTO users (name, name_skeleton) VALUES (?, ?)")
+///     .bind(name.original())
+///     .bind(&name) // "Rust"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "diesel", derive(AsExpression))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = Text))]
+pub struct SkeletonNormalized<T = String> {
+    original: T,
+    skeleton: Skeleton,
+}
+
+impl<T: AsRef<str>> SkeletonNormalized<T> {
+    /// Wrap `original`, computing its skeleton with the latest table.
+    pub fn new(original: T) -> SkeletonNormalized<T> {
+        let skeleton = Skeleton::of(original.as_ref());
+        SkeletonNormalized { original, skeleton }
+    }
+
+    /// Wrap `original`, computing its skeleton with the table of `version`.
+    pub fn with_version(original: T, version: DataVersion) -> SkeletonNormalized<T> {
+        let skeleton = Skeleton::of_version(original.as_ref(), version);
+        SkeletonNormalized { original, skeleton }
+    }
+}
+
+impl<T> SkeletonNormalized<T> {
+    /// The original string.
+    pub fn original(&self) -> &T {
+        &self.original
+    }
+
+    /// The skeleton of the original string, which is what is stored.
+    pub fn skeleton(&self) -> &Skeleton {
+        &self.skeleton
+    }
+
+    /// Unwrap the original string.
+    pub fn into_original(self) -> T {
+        self.original
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<T, DB: Database> Type<DB> for SkeletonNormalized<T> where String: Type<DB> {
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, T, DB: Database> Encode<'q, DB> for SkeletonNormalized<T> where String: Encode<'q, DB> {
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.skeleton.0.encode_by_ref(buf)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl<T: ::std::fmt::Debug, DB: Backend> ToSql<Text, DB> for SkeletonNormalized<T> where str: ToSql<Text, DB> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.skeleton.as_str().to_sql(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkeletonNormalized;
+    use DataVersion;
+
+    #[test]
+    fn wraps_originals() {
+        let name = SkeletonNormalized::new("ℝ𝓊𝓈𝓉");
+        assert_eq!(*name.original(), "ℝ𝓊𝓈𝓉");
+        assert_eq!(name.skeleton().as_str(), "Rust");
+        assert_eq!(SkeletonNormalized::with_version("ℝ𝓊𝓈𝓉", DataVersion::LATEST).skeleton(), name.skeleton());
+        assert_eq!(name.into_original(), "ℝ𝓊𝓈𝓉");
+    }
+}
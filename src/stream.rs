@@ -0,0 +1,521 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Skeletons of text that arrives in pieces.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+#[cfg(feature = "std")]
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+#[cfg(feature = "std")]
+use std::cmp;
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+#[cfg(feature = "std")]
+use std::mem;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
+#[cfg(feature = "futures")]
+use std::task::{Context, Poll};
+
+#[cfg(feature = "futures")]
+use futures::stream::{FusedStream, Stream};
+
+use decompose::starts_with_starter;
+use write_skeleton;
+
+/// The longest run of non-starters that a `SkeletonBuilder` holds back for
+/// canonical reordering, as in the Stream-Safe Text Format of UAX #15.
+const MAX_NON_STARTERS: usize = 30;
+
+/// Computes the skeleton of text pushed to it in chunks, writing the skeleton
+/// to a `fmt::Write` sink as it goes.
+///
+/// The output is the same as the skeleton of all of the chunks concatenated.
+/// Only the end of the input that may still be affected by canonical
+/// reordering with later chunks is buffered.
+///
+/// That end is bounded as in the Stream-Safe Text Format of UAX #15: after
+/// 30 characters in a row that do not begin with a starter, such as the
+/// combining marks of Zalgo text, the input is split as though a starter
+/// came next, and marks are not reordered across the split. Only text with
+/// such runs, which no real language uses, gets a different skeleton than
+/// it would as a whole.
+///
+/// ```Rust
+/// let mut builder = SkeletonBuilder::new(String::new());
+/// builder.push_str("ℝ𝓊")?;
+/// builder.push_str("𝓈𝓉")?;
+/// builder.finish()? // "Rust"
+/// ```
+#[derive(Clone, Debug)]
+pub struct SkeletonBuilder<W: fmt::Write> {
+    pending: String,
+    // The offset in `pending` of its last character that begins with a
+    // starter, and the number of characters after it.
+    last_starter: usize,
+    non_starters: usize,
+    sink: W,
+}
+
+impl<W: fmt::Write> SkeletonBuilder<W> {
+    /// Create a builder that writes the skeleton to `sink`.
+    pub fn new(sink: W) -> SkeletonBuilder<W> {
+        SkeletonBuilder {
+            pending: String::new(),
+            last_starter: 0,
+            non_starters: 0,
+            sink,
+        }
+    }
+
+    /// Add the next chunk of input, writing as much of the skeleton as can be
+    /// determined so far.
+    pub fn push_str(&mut self, chunk: &str) -> fmt::Result {
+        for c in chunk.chars() {
+            if starts_with_starter(c) {
+                self.last_starter = self.pending.len();
+                self.non_starters = 0;
+            } else if self.non_starters == MAX_NON_STARTERS {
+                write_skeleton(&self.pending[..], &mut self.sink)?;
+                self.pending.clear();
+                self.last_starter = 0;
+                self.non_starters = 1;
+            } else {
+                self.non_starters += 1;
+            }
+            self.pending.push(c);
+        }
+        write_skeleton(&self.pending[..self.last_starter], &mut self.sink)?;
+        self.pending.drain(..self.last_starter);
+        self.last_starter = 0;
+        Ok(())
+    }
+
+    /// The sink that the skeleton is being written to.
+    pub fn get_ref(&self) -> &W {
+        &self.sink
+    }
+
+    /// A mutable reference to the sink, for example to drain output written so far.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.sink
+    }
+
+    /// Write the skeleton of any buffered input and return the sink.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        write_skeleton(&self.pending[..], &mut self.sink)?;
+        Ok(self.sink)
+    }
+}
+
+/// The encoding of text read by a `SkeletonReader`.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextEncoding {
+    /// UTF-8, with or without a byte order mark.
+    Utf8,
+    /// UTF-16, little endian, with a byte order mark.
+    Utf16Le,
+    /// UTF-16, big endian, with a byte order mark.
+    Utf16Be,
+}
+
+#[cfg(feature = "std")]
+impl TextEncoding {
+    /// Detect the encoding from the byte order mark at the start of `bytes`,
+    /// returning it and the length of the mark. Text without a byte order
+    /// mark is taken to be UTF-8.
+    fn detect(bytes: &[u8]) -> (TextEncoding, usize) {
+        if bytes.starts_with(b"\xef\xbb\xbf") {
+            (TextEncoding::Utf8, 3)
+        } else if bytes.starts_with(b"\xff\xfe") {
+            (TextEncoding::Utf16Le, 2)
+        } else if bytes.starts_with(b"\xfe\xff") {
+            (TextEncoding::Utf16Be, 2)
+        } else {
+            (TextEncoding::Utf8, 0)
+        }
+    }
+
+    /// Decode as much of `bytes` as possible onto `out`, replacing invalid
+    /// sequences with U+FFFD REPLACEMENT CHARACTER, and return the number of
+    /// bytes consumed. Unless `eof` is set, a sequence at the end that may be
+    /// completed by more input is left unconsumed.
+    fn decode(self, bytes: &[u8], eof: bool, out: &mut String) -> usize {
+        match self {
+            TextEncoding::Utf8 => {
+                let mut position = 0;
+                loop {
+                    match ::std::str::from_utf8(&bytes[position..]) {
+                        Ok(valid) => {
+                            out.push_str(valid);
+                            return bytes.len();
+                        }
+                        Err(e) => {
+                            let valid_end = position + e.valid_up_to();
+                            out.push_str(::std::str::from_utf8(&bytes[position..valid_end]).expect("checked valid"));
+                            match e.error_len() {
+                                Some(len) => {
+                                    out.push(REPLACEMENT_CHARACTER);
+                                    position = valid_end + len;
+                                }
+                                None if eof => {
+                                    out.push(REPLACEMENT_CHARACTER);
+                                    return bytes.len();
+                                }
+                                None => return valid_end,
+                            }
+                        }
+                    }
+                }
+            }
+            TextEncoding::Utf16Le | TextEncoding::Utf16Be => {
+                let mut units: Vec<u16> = bytes.chunks(2)
+                    .filter(|pair| pair.len() == 2)
+                    .map(|pair| if self == TextEncoding::Utf16Le {
+                        u16::from_le_bytes([pair[0], pair[1]])
+                    } else {
+                        u16::from_be_bytes([pair[0], pair[1]])
+                    })
+                    .collect();
+                let mut consumed = units.len() * 2;
+                if !eof && units.last().map(|&unit| (0xd800..0xdc00).contains(&unit)) == Some(true) {
+                    // A high surrogate whose pair has not been read yet.
+                    units.pop();
+                    consumed -= 2;
+                }
+                out.extend(decode_utf16(units).map(|c| c.unwrap_or(REPLACEMENT_CHARACTER)));
+                if eof && consumed < bytes.len() {
+                    out.push(REPLACEMENT_CHARACTER);
+                    consumed = bytes.len();
+                }
+                consumed
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Read the skeleton of the text in `reader`, as UTF-8.
+///
+/// See `SkeletonReader` for details.
+///
+/// # Examples
+/// ```Rust
+/// let mut skeleton = String::new();
+/// skeleton_from_reader(File::open("names.txt")?).read_to_string(&mut skeleton)?;
+/// ```
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn skeleton_from_reader<R: Read>(reader: R) -> SkeletonReader<R> {
+    SkeletonReader {
+        inner: reader,
+        encoding: None,
+        input: Vec::new(),
+        decoded: String::new(),
+        builder: Some(SkeletonBuilder::new(String::new())),
+        output: String::new(),
+        output_position: 0,
+    }
+}
+
+/// Reads text from an inner reader and produces its skeleton, encoded as UTF-8.
+///
+/// The encoding of the input is detected from its byte order mark: UTF-8,
+/// UTF-16LE and UTF-16BE are supported, and input without a byte order mark
+/// is read as UTF-8. Invalid sequences are replaced with U+FFFD REPLACEMENT
+/// CHARACTER. The input is read in fixed-size pieces, and runs of combining
+/// marks are bounded as described for `SkeletonBuilder`, so memory use does
+/// not grow with the length of the input.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SkeletonReader<R> {
+    inner: R,
+    encoding: Option<TextEncoding>,
+    input: Vec<u8>,
+    decoded: String,
+    builder: Option<SkeletonBuilder<String>>,
+    output: String,
+    output_position: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> SkeletonReader<R> {
+    /// The detected encoding of the input, once enough of it has been read.
+    pub fn encoding(&self) -> Option<TextEncoding> {
+        self.encoding
+    }
+
+    /// Read and skeletonize more input. Returns `false` once all output has
+    /// been produced.
+    fn fill(&mut self) -> io::Result<bool> {
+        if self.builder.is_none() {
+            return Ok(false);
+        }
+
+        let input_len = self.input.len();
+        self.input.resize(input_len + READ_BUFFER_SIZE, 0);
+        let read = match self.inner.read(&mut self.input[input_len..]) {
+            Ok(read) => read,
+            Err(e) => {
+                self.input.truncate(input_len);
+                return Err(e);
+            }
+        };
+        self.input.truncate(input_len + read);
+        let eof = read == 0;
+
+        let mut bom_len = 0;
+        if self.encoding.is_none() {
+            if self.input.len() < 3 && !eof {
+                return Ok(true);
+            }
+            let (encoding, len) = TextEncoding::detect(&self.input);
+            self.encoding = Some(encoding);
+            bom_len = len;
+        }
+
+        self.decoded.clear();
+        let consumed = self.encoding.expect("detected above").decode(&self.input[bom_len..], eof, &mut self.decoded);
+        self.input.drain(..bom_len + consumed);
+
+        self.output.clear();
+        self.output_position = 0;
+        if eof {
+            let mut builder = self.builder.take().expect("checked above");
+            builder.push_str(&self.decoded).expect("writing to a String cannot fail");
+            self.output = builder.finish().expect("writing to a String cannot fail");
+        } else {
+            let builder = self.builder.as_mut().expect("checked above");
+            builder.push_str(&self.decoded).expect("writing to a String cannot fail");
+            mem::swap(&mut self.output, builder.get_mut());
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Read for SkeletonReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.output_position == self.output.len() {
+            if !self.fill()? {
+                return Ok(0);
+            }
+        }
+        let available = &self.output.as_bytes()[self.output_position..];
+        let len = cmp::min(available.len(), buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.output_position += len;
+        Ok(len)
+    }
+}
+
+/// Adapts a `Stream` of text chunks into a `Stream` of the skeletons of those
+/// chunks, carrying normalization state across chunk boundaries.
+///
+/// Concatenating the output chunks gives the skeleton of the concatenated
+/// input. Because the end of a chunk may be buffered until the next one
+/// arrives, an output chunk may be empty, and one extra chunk may be produced
+/// after the input ends.
+///
+/// The wrapped stream must be `Unpin`; other streams can be wrapped with `Box::pin`.
+///
+/// Requires the `futures` feature.
+#[cfg(feature = "futures")]
+#[derive(Debug)]
+pub struct SkeletonStream<S> {
+    inner: S,
+    builder: Option<SkeletonBuilder<String>>,
+}
+
+#[cfg(feature = "futures")]
+impl<S> SkeletonStream<S> {
+    /// Wrap a stream of text chunks.
+    pub fn new(inner: S) -> SkeletonStream<S> {
+        SkeletonStream {
+            inner,
+            builder: Some(SkeletonBuilder::new(String::new())),
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<S, T> Stream for SkeletonStream<S>
+    where S: Stream<Item=T> + Unpin, T: AsRef<str>
+{
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<String>> {
+        let this = &mut *self;
+        if this.builder.is_none() {
+            return Poll::Ready(None);
+        }
+        match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Some(chunk)) => {
+                let builder = this.builder.as_mut().expect("checked above");
+                builder.push_str(chunk.as_ref()).expect("writing to a String cannot fail");
+                Poll::Ready(Some(mem::take(builder.get_mut())))
+            }
+            Poll::Ready(None) => {
+                let rest = this.builder.take().expect("checked above").finish().expect("writing to a String cannot fail");
+                Poll::Ready(if rest.is_empty() { None } else { Some(rest) })
+            }
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<S, T> FusedStream for SkeletonStream<S>
+    where S: Stream<Item=T> + Unpin, T: AsRef<str>
+{
+    fn is_terminated(&self) -> bool {
+        self.builder.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    #[cfg(feature = "std")]
+    use std::io::{self, Read};
+
+    use super::{SkeletonBuilder, MAX_NON_STARTERS};
+    #[cfg(feature = "std")]
+    use super::{skeleton_from_reader, TextEncoding};
+    use skeleton;
+
+    #[test]
+    fn chunked_matches_whole() {
+        // "é" followed by a combining grave accent below, which must be reordered before the acute accent.
+        let input = "ℝ𝓊\u{e9}\u{316}\u{301}m\u{1e3f}𝓉";
+        let boundaries: Vec<usize> = input.char_indices().map(|(i, _)| i).chain(Some(input.len())).collect();
+        for &a in boundaries.iter() {
+            for &b in boundaries.iter().filter(|&&b| b >= a) {
+                let mut builder = SkeletonBuilder::new(String::new());
+                builder.push_str(&input[..a]).unwrap();
+                builder.push_str(&input[a..b]).unwrap();
+                builder.push_str(&input[b..]).unwrap();
+                assert_eq!(builder.finish().unwrap(), skeleton(input));
+            }
+        }
+    }
+
+    #[test]
+    fn long_mark_runs_are_bounded() {
+        let marks = "\u{301}".repeat(100_000);
+        let mut builder = SkeletonBuilder::new(String::new());
+        builder.push_str("a").unwrap();
+        for piece in marks.as_bytes().chunks(1000) {
+            builder.push_str(::std::str::from_utf8(piece).unwrap()).unwrap();
+            assert!(builder.pending.chars().count() <= MAX_NON_STARTERS + 1);
+        }
+        let chunked = builder.finish().unwrap();
+
+        // The split falls in the same places however the input is chunked.
+        let input = format!("a{}", marks);
+        let mut whole = SkeletonBuilder::new(String::new());
+        whole.push_str(&input).unwrap();
+        assert_eq!(whole.finish().unwrap(), chunked);
+        assert_eq!(chunked, skeleton(&input));
+
+        // Runs up to the limit are reordered as a whole.
+        let input = format!("a{}\u{316}", "\u{301}".repeat(MAX_NON_STARTERS - 1));
+        let mut builder = SkeletonBuilder::new(String::new());
+        for c in input.chars() {
+            builder.push_str(c.encode_utf8(&mut [0; 4])).unwrap();
+        }
+        assert_eq!(builder.finish().unwrap(), skeleton(&input));
+    }
+
+    #[test]
+    fn output_is_incremental() {
+        let mut builder = SkeletonBuilder::new(String::new());
+        builder.push_str("ℝ𝓊𝓈").unwrap();
+        assert_eq!(builder.get_ref(), "Ru");
+        builder.get_mut().clear();
+        builder.push_str("𝓉").unwrap();
+        assert_eq!(builder.finish().unwrap(), "st");
+    }
+
+    /// Returns at most one byte per read, to exercise sequences split across reads.
+    #[cfg(feature = "std")]
+    struct OneByteReader<'a>(&'a [u8]);
+
+    #[cfg(feature = "std")]
+    impl<'a> Read for OneByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.0.len()).min(1);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn read_skeleton(bytes: &[u8]) -> (String, Option<TextEncoding>) {
+        let mut whole = String::new();
+        skeleton_from_reader(bytes).read_to_string(&mut whole).unwrap();
+
+        let mut reader = skeleton_from_reader(OneByteReader(bytes));
+        let mut piecewise = String::new();
+        reader.read_to_string(&mut piecewise).unwrap();
+        assert_eq!(whole, piecewise);
+        (piecewise, reader.encoding())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_encodings() {
+        let text = "ℝ𝓊\u{e9}\u{316}𝓈𝓉";
+
+        let mut utf8 = b"\xef\xbb\xbf".to_vec();
+        utf8.extend_from_slice(text.as_bytes());
+        assert_eq!(read_skeleton(text.as_bytes()), (skeleton(text), Some(TextEncoding::Utf8)));
+        assert_eq!(read_skeleton(&utf8), (skeleton(text), Some(TextEncoding::Utf8)));
+
+        let mut utf16le = vec![0xff, 0xfe];
+        let mut utf16be = vec![0xfe, 0xff];
+        for unit in text.encode_utf16() {
+            utf16le.extend_from_slice(&unit.to_le_bytes());
+            utf16be.extend_from_slice(&unit.to_be_bytes());
+        }
+        assert_eq!(read_skeleton(&utf16le), (skeleton(text), Some(TextEncoding::Utf16Le)));
+        assert_eq!(read_skeleton(&utf16be), (skeleton(text), Some(TextEncoding::Utf16Be)));
+
+        assert_eq!(read_skeleton(b"ab\xff\xe2\x84"), ("ab\u{fffd}\u{fffd}".to_string(), Some(TextEncoding::Utf8)));
+        assert_eq!(read_skeleton(b""), (String::new(), Some(TextEncoding::Utf8)));
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn async_stream() {
+        use futures::executor::block_on;
+        use futures::stream::{self, StreamExt};
+
+        use super::SkeletonStream;
+
+        let chunks = stream::iter(vec!["ℝ𝓊", "\u{e9}", "\u{316}𝓈", "𝓉"]);
+        let output: Vec<String> = block_on(SkeletonStream::new(chunks).collect());
+        assert_eq!(output.concat(), skeleton("ℝ𝓊\u{e9}\u{316}𝓈𝓉"));
+        assert_eq!(output[0], "R");
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Small summaries of skeletons, for rejecting most non-matches cheaply.
+
+use scripts::script_of;
+use UnicodeSkeleton;
+
+/// A few facts about a skeleton that are cheap to compare: its length, its
+/// first and last characters, and a fingerprint of the scripts of its
+/// characters.
+///
+/// Confusable strings always have equal summaries, so when comparing many
+/// strings whose summaries are already known, those with different
+/// summaries can be rejected without streaming either skeleton. Equal
+/// summaries do not imply the strings are confusable.
+///
+/// # Examples
+/// ```Rust
+/// let rust = Skeleton::of("Rust").summary();
+/// rust.may_match(&SkeletonSummary::of("ℝ𝓊𝓈𝓉")) // true
+/// rust.may_match(&SkeletonSummary::of("Go")) // false
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SkeletonSummary {
+    len: usize,
+    first: Option<char>,
+    last: Option<char>,
+    scripts: u64,
+}
+
+impl SkeletonSummary {
+    /// Summarize the skeleton of a string or other `char` sequence. This
+    /// streams the skeleton once, without allocating it.
+    pub fn of<S: UnicodeSkeleton>(s: S) -> SkeletonSummary {
+        SkeletonSummary::of_chars(s.skeleton_chars())
+    }
+
+    // Summarize the characters of a skeleton that has already been computed.
+    pub(crate) fn of_chars<I: Iterator<Item=char>>(skeleton: I) -> SkeletonSummary {
+        let mut summary = SkeletonSummary { len: 0, first: None, last: None, scripts: 0 };
+        for c in skeleton {
+            summary.len += 1;
+            summary.first = summary.first.or(Some(c));
+            summary.last = Some(c);
+            summary.scripts |= 1 << (script_of(c) as u32 % 64);
+        }
+        summary
+    }
+
+    /// The number of characters in the skeleton.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test whether the skeleton is empty, which it is only for the empty string.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The first character of the skeleton.
+    pub fn first(&self) -> Option<char> {
+        self.first
+    }
+
+    /// The last character of the skeleton.
+    pub fn last(&self) -> Option<char> {
+        self.last
+    }
+
+    /// A fingerprint of the scripts of the skeleton's characters, with one bit
+    /// set for each. Scripts share bits, so this only tells sets apart.
+    pub fn script_fingerprint(&self) -> u64 {
+        self.scripts
+    }
+
+    /// Test whether the skeleton summarized by `other` could be the same as
+    /// this one. If this returns `false`, the strings are not confusable.
+    pub fn may_match(&self, other: &SkeletonSummary) -> bool {
+        self == other
+    }
+
+    /// Test whether the skeleton of a string or other `char` sequence could
+    /// be the one summarized, looking only at its bounds on length and at its
+    /// first character, rather than streaming its whole skeleton. If this
+    /// returns `false`, `s` does not have the summarized skeleton.
+    pub fn may_match_str<S: UnicodeSkeleton>(&self, s: S) -> bool {
+        let mut skeleton = s.skeleton_chars();
+        let (lower, upper) = skeleton.size_hint();
+        if lower > self.len || upper.is_some_and(|upper| upper < self.len) {
+            return false;
+        }
+        skeleton.next() == self.first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkeletonSummary;
+    use {Script, Skeleton};
+
+    #[test]
+    fn summaries() {
+        let summary = SkeletonSummary::of("ℝ𝓊𝓈𝓉");
+        assert_eq!(summary.len(), 4);
+        assert_eq!(summary.first(), Some('R'));
+        assert_eq!(summary.last(), Some('t'));
+        assert_eq!(summary.script_fingerprint(), 1 << (Script::Latin as u32 % 64));
+        assert_eq!(Skeleton::of("Rust").summary(), summary);
+        assert!(summary.may_match(&SkeletonSummary::of("Rust")));
+        assert!(!summary.may_match(&SkeletonSummary::of("Rush")));
+        assert!(!summary.may_match(&SkeletonSummary::of("Rusτ")));
+        // Summaries are only a filter.
+        assert!(summary.may_match(&SkeletonSummary::of("Rest")));
+        assert!(!summary.may_match(&SkeletonSummary::of("Rusty")));
+
+        let empty = SkeletonSummary::of("");
+        assert!(empty.is_empty());
+        assert_eq!(empty.first(), None);
+        assert!(empty.may_match_str(""));
+        assert!(!empty.may_match_str("a"));
+    }
+
+    #[test]
+    fn rejects_strings_cheaply() {
+        let summary = Skeleton::of("paypal").summary();
+        assert!(summary.may_match_str("𝔭𝒶ỿ𝕡𝕒ℓ"));
+        assert!(summary.may_match_str("pork"));
+        assert!(!summary.may_match_str("rust"));
+        // Every character has at least one character of skeleton.
+        assert!(!summary.may_match_str("paypal-paypal-paypal-paypal"));
+        for s in &["paypal", "Paypal", "p", "", "𝔭𝒶ỿ𝕡𝕒ℓ𝔭𝒶ỿ𝕡𝕒ℓ"] {
+            if Skeleton::of("paypal").matches(*s) {
+                assert!(summary.may_match_str(*s), "{}", s);
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Confusables tables loaded at runtime, rather than compiled in.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::char;
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+
+use unicode_normalization::UnicodeNormalization;
+
+use data::{self, PrototypeTable, PROTOTYPE_INDEX_BITS};
+use {PrototypeCharsIterator, SkeletonChars, UnicodeSkeleton};
+
+/// A confusables table parsed from the text of a `confusables.txt` file.
+///
+/// This allows skeletons to be computed against a newer or customized table
+/// than the one compiled into the crate. Skeletons borrow the table only
+/// while they are computed, so it can be replaced when a new file is loaded.
+///
+/// # Examples
+/// ```Rust
+/// let text = fs::read_to_string("confusables.txt")?;
+/// let table = ConfusableTable::parse(&text)?;
+/// table.skeleton("ℝ𝓊𝓈𝓉") // "Rust"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfusableTable {
+    // In the same packed form as the generated tables; see `PrototypeTable`.
+    entries: Vec<u32>,
+    prototype_starts: Vec<u16>,
+    prototype_chars: Vec<char>,
+}
+
+impl ConfusableTable {
+    /// Parse the contents of a `confusables.txt` file.
+    ///
+    /// Each non-comment line has the form `source ; prototype ; type`, with
+    /// code points written in hex and separated by spaces. The type field is
+    /// ignored. Comments start with `#`.
+    ///
+    /// It is an error for a source to appear twice, or for any prototype to
+    /// contain a character that is itself a source, since skeletons would then
+    /// not be stable under being recomputed. Sources must be below U+40000,
+    /// which includes every character of confusables.txt.
+    pub fn parse(text: &str) -> Result<ConfusableTable, ParseError> {
+        let text = text.trim_start_matches('\u{feff}');
+
+        // Pairs of (source, (line number, prototype)), unique and sorted.
+        let mut entries = BTreeMap::new();
+        for (line_index, line) in text.lines().enumerate() {
+            let line_number = line_index + 1;
+            let error = |kind| ParseError { line: line_number, kind };
+
+            let line = match line.find('#') {
+                Some(comment_begin) => &line[..comment_begin],
+                None => line,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut fields = line.split(';').map(str::trim);
+            let source = fields.next().unwrap_or("");
+            let prototype = fields.next().ok_or_else(|| error(ParseErrorKind::MissingPrototype))?;
+            if prototype.is_empty() {
+                return Err(error(ParseErrorKind::MissingPrototype));
+            }
+
+            let source = parse_code_point(source).map_err(&error)?;
+            let prototype = prototype.split_whitespace()
+                .map(parse_code_point)
+                .collect::<Result<Vec<char>, _>>()
+                .map_err(&error)?;
+            if entries.insert(source, (line_number, prototype)).is_some() {
+                return Err(error(ParseErrorKind::DuplicateSource(source)));
+            }
+        }
+
+        // Each distinct prototype is stored once, with the index of each
+        // prototype that the entries refer to. Prototypes are stored in NFD,
+        // as in the generated tables, so that skeletons need not decompose
+        // them.
+        let mut prototype_indices: BTreeMap<Vec<char>, u32> = BTreeMap::new();
+        let mut table = ConfusableTable {
+            entries: Vec::with_capacity(entries.len()),
+            prototype_starts: vec![0],
+            prototype_chars: Vec::new(),
+        };
+        for (&source, &(line, ref prototype)) in entries.iter() {
+            let error = |kind| Err(ParseError { line, kind });
+            if let Some(&c) = prototype.iter().find(|c| entries.contains_key(c)) {
+                return error(ParseErrorKind::UnstablePrototype(c));
+            }
+            if source as u32 >= 1 << (32 - PROTOTYPE_INDEX_BITS) {
+                return error(ParseErrorKind::SourceOutOfRange(source));
+            }
+            let prototype: Vec<char> = prototype.iter().cloned().nfd().collect();
+            let next_index = prototype_indices.len() as u32;
+            let index = *prototype_indices.entry(prototype.clone()).or_insert(next_index);
+            if index == next_index {
+                if index >= 1 << PROTOTYPE_INDEX_BITS || table.prototype_chars.len() + prototype.len() > u16::MAX as usize {
+                    return error(ParseErrorKind::TooLarge);
+                }
+                table.prototype_chars.extend_from_slice(&prototype);
+                table.prototype_starts.push(table.prototype_chars.len() as u16);
+            }
+            table.entries.push((source as u32) << PROTOTYPE_INDEX_BITS | index);
+        }
+        table.entries = data::eytzinger(&table.entries);
+        Ok(table)
+    }
+
+    /// The number of characters with a prototype in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test whether the table maps no characters.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn table<'a>(&'a self) -> PrototypeTable<'a> {
+        PrototypeTable {
+            entries: &self.entries,
+            prototype_starts: &self.prototype_starts,
+            prototype_chars: &self.prototype_chars,
+            salts: &[],
+            hashed_entries: &[],
+            single_script: false,
+        }
+    }
+
+    /// Look up the prototype that a single character maps to, as with
+    /// `prototype_of`.
+    pub fn prototype_of(&self, c: char) -> Option<&[char]> {
+        self.table().prototype_of(c)
+    }
+
+    /// Retrieve the characters of the skeleton of a string or other `char`
+    /// sequence, computed with this table.
+    pub fn skeleton_chars<'t, S: UnicodeSkeleton>(&'t self, s: S) -> SkeletonChars<'t, S::Iter> {
+        SkeletonChars {
+            source: s.skeleton_chars().source,
+            prototype: PrototypeCharsIterator::empty(),
+            table: self.table(),
+        }
+    }
+
+    /// Compute the skeleton of a string or other `char` sequence with this table.
+    pub fn skeleton<S: UnicodeSkeleton>(&self, s: S) -> String {
+        self.skeleton_chars(s).collect()
+    }
+
+    /// Test if two strings have the same skeleton under this table.
+    pub fn confusable<A, B>(&self, a: A, b: B) -> bool
+        where A: UnicodeSkeleton, B: UnicodeSkeleton
+    {
+        self.skeleton_chars(a).eq(self.skeleton_chars(b))
+    }
+}
+
+fn parse_code_point(hex: &str) -> Result<char, ParseErrorKind> {
+    u32::from_str_radix(hex, 16).ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| ParseErrorKind::InvalidCodePoint(hex.to_string()))
+}
+
+/// An error from `ConfusableTable::parse`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line number the error was found on.
+    pub line: usize,
+    /// What was wrong with the line.
+    pub kind: ParseErrorKind,
+}
+
+/// The kinds of error in a `ParseError`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line has no prototype field, or it is empty.
+    MissingPrototype,
+    /// A field is not a hex code point of a valid `char`.
+    InvalidCodePoint(String),
+    /// The source character already has a prototype.
+    DuplicateSource(char),
+    /// The prototype contains a character that itself has a prototype.
+    UnstablePrototype(char),
+    /// The source is U+40000 or above.
+    SourceOutOfRange(char),
+    /// The prototypes are too long in total to be indexed.
+    TooLarge,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ParseErrorKind::MissingPrototype => write!(f, "missing prototype"),
+            ParseErrorKind::InvalidCodePoint(ref hex) => write!(f, "invalid code point {:?}", hex),
+            ParseErrorKind::DuplicateSource(c) => write!(f, "duplicate source U+{:04X}", c as u32),
+            ParseErrorKind::UnstablePrototype(c) => write!(f, "prototype contains U+{:04X}, which has its own prototype", c as u32),
+            ParseErrorKind::SourceOutOfRange(c) => write!(f, "source U+{:04X} is not below U+40000", c as u32),
+            ParseErrorKind::TooLarge => write!(f, "prototypes are too long in total"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use super::{ConfusableTable, ParseError, ParseErrorKind};
+    use {data_entries, prototype_of, skeleton};
+
+    #[test]
+    fn parses_bundled_format() {
+        let text = "\u{feff}# confusables.txt\n\
+                    # Version: test\n\
+                    \n\
+                    211D ;\t0052 ;\tMA\t# ( ℝ → R ) DOUBLE-STRUCK CAPITAL R → LATIN CAPITAL LETTER R\n\
+                    006D ;\t0072 006E ;\tMA\t# ( m → rn )\n";
+        let table = ConfusableTable::parse(text).unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.prototype_of('ℝ'), Some(&['R'][..]));
+        assert_eq!(table.prototype_of('m'), Some(&['r', 'n'][..]));
+        assert_eq!(table.prototype_of('a'), None);
+        assert_eq!(table.skeleton("ℝum"), "Rurn");
+        assert_eq!(table.skeleton("𝓊"), "𝓊");
+        assert!(table.confusable("ℝ", "R"));
+    }
+
+    #[test]
+    fn reloads() {
+        let mut table = ConfusableTable::parse("211D ; 0052
+").unwrap();
+        assert_eq!(table.skeleton_chars("ℝm").collect::<String>(), "Rm");
+        table = ConfusableTable::parse("006D ; 0072 006E
+").unwrap();
+        assert_eq!(table.skeleton_chars("ℝm").collect::<String>(), "ℝrn");
+    }
+
+    #[test]
+    fn shares_prototypes() {
+        let table = ConfusableTable::parse("211D ; 0052\n211B ; 0052\n006D ; 0072 006E\n").unwrap();
+        assert_eq!(table.prototype_of('ℛ'), Some(&['R'][..]));
+        assert_eq!(table.prototype_of('ℝ'), Some(&['R'][..]));
+        assert_eq!(table.prototype_chars, ['r', 'n', 'R']);
+    }
+
+    #[test]
+    fn round_trips_builtin_table() {
+        // The built-in prototypes are stored decomposed, and the
+        // decompositions of a few contain sources, which confusables.txt
+        // never does. Those entries are left out.
+        let entries: Vec<_> = data_entries()
+            .filter(|&(_, prototype)| prototype.iter().all(|&p| prototype_of(p).is_none()))
+            .collect();
+        let text: String = entries.iter()
+            .map(|&(c, prototype)| {
+                let prototype: Vec<String> = prototype.iter().map(|&p| format!("{:04X}", p as u32)).collect();
+                format!("{:04X} ;\t{} ;\tMA\n", c as u32, prototype.join(" "))
+            })
+            .collect();
+        let table = ConfusableTable::parse(&text).unwrap();
+        assert_eq!(table.len(), entries.len());
+        assert!(entries.len() + 100 > data_entries().len());
+        for s in &["ℝ𝓊𝓈𝓉", "𝔭𝒶ỿ𝕡𝕒ℓ", "ᑲ"] {
+            assert_eq!(table.skeleton(*s), skeleton(*s));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let error = |line, kind| Err(ParseError { line, kind });
+        assert_eq!(ConfusableTable::parse("0041\n"), error(1, ParseErrorKind::MissingPrototype));
+        assert_eq!(ConfusableTable::parse("# a\n0041 ; ; MA\n"), error(2, ParseErrorKind::MissingPrototype));
+        assert_eq!(ConfusableTable::parse("00G1 ; 0042\n"), error(1, ParseErrorKind::InvalidCodePoint("00G1".to_string())));
+        assert_eq!(ConfusableTable::parse("0041 ; D800\n"), error(1, ParseErrorKind::InvalidCodePoint("D800".to_string())));
+        assert_eq!(ConfusableTable::parse("0041 ; 0042\n0041 ; 0043\n"), error(2, ParseErrorKind::DuplicateSource('A')));
+        assert_eq!(ConfusableTable::parse("0041 ; 0042\n0042 ; 0043\n"), error(1, ParseErrorKind::UnstablePrototype('B')));
+        assert_eq!(
+            ConfusableTable::parse("0041 ; 0042\n0041 ; 0043\n").unwrap_err().to_string(),
+            "line 2: duplicate source U+0041"
+        );
+        assert_eq!(ConfusableTable::parse("E0041 ; 0041\n"), error(1, ParseErrorKind::SourceOutOfRange('\u{e0041}')));
+        assert!(ConfusableTable::parse("# nothing\n").unwrap().is_empty());
+    }
+}
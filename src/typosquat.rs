@@ -0,0 +1,223 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Recognizing typosquats: names made from another by a typing mistake,
+//! homoglyphs, or both.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use {case_fold, caseless_skeleton_chars};
+
+/// The way a typosquat differs from the name it imitates, once both are
+/// reduced to skeletons without regard to case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TyposquatKind {
+    /// The skeletons are the same, so the names differ only by homoglyphs,
+    /// as "ru𝓼t-lang.org" does from "rust-lang.org".
+    Homoglyph,
+    /// A character is replaced by one next to it on a QWERTY keyboard, as in
+    /// "rusr-lang.org".
+    AdjacentKey,
+    /// Two neighbouring characters are swapped, as in "rsut-lang.org".
+    Transposition,
+    /// A character is left out, as in "rst-lang.org".
+    Omission,
+    /// A character is repeated, as in "rust-langg.org".
+    Doubling,
+    /// A hyphen is added or left out, as in "rustlang.org" or
+    /// "rust-la-ng.org".
+    Hyphenation,
+}
+
+/// How a name imitates another. This is retrieved via `typosquat_score`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Typosquat {
+    /// How the skeletons differ.
+    pub kind: TyposquatKind,
+    /// Whether the name also uses homoglyphs, as "ru𝓼t-langg.org" does: its
+    /// typing mistake, if any, does not explain how it differs from the
+    /// name it imitates without regard to case.
+    pub homoglyphs: bool,
+}
+
+impl Typosquat {
+    /// A score between 0.0 and 1.0 for ranking review queues, higher being
+    /// more suspicious: 1.0 for names that differ only by homoglyphs, 0.75
+    /// for a typing mistake disguised with homoglyphs, and 0.5 for a typing
+    /// mistake alone.
+    pub fn score(&self) -> f64 {
+        match (self.kind, self.homoglyphs) {
+            (TyposquatKind::Homoglyph, _) => 1.0,
+            (_, true) => 0.75,
+            (_, false) => 0.5,
+        }
+    }
+}
+
+/// Classify how `candidate` imitates `target`, combining skeleton
+/// comparison with the transforms that typosquats are commonly made by: a
+/// neighbouring key, a swap, an omission, a doubling or a hyphen. Returns
+/// `None` if the names are the same apart from case, or differ in some other
+/// way.
+///
+/// Pure skeleton equality misses "rust-langg.org", and pure edit distance
+/// misses "ru𝓼t-lang.org"; this finds both, and their combination. The
+/// transforms are recognized in the skeletons, so a homoglyph of a
+/// neighbouring key counts as that key, and "m" counts as the two letters
+/// "rn".
+///
+/// # Examples
+/// ```Rust
+/// typosquat_score("ru𝓼t-lang.org", "rust-lang.org").unwrap().kind // TyposquatKind::Homoglyph
+/// typosquat_score("rust-langg.org", "rust-lang.org").unwrap().score() // 0.5
+/// typosquat_score("ru𝓼t-langg.org", "rust-lang.org").unwrap().score() // 0.75
+/// typosquat_score("Rust-Lang.org", "rust-lang.org") // None
+/// ```
+pub fn typosquat_score(candidate: &str, target: &str) -> Option<Typosquat> {
+    let folded_candidate: Vec<char> = candidate.chars().flat_map(case_fold).collect();
+    let folded_target: Vec<char> = target.chars().flat_map(case_fold).collect();
+    if folded_candidate == folded_target {
+        return None;
+    }
+    let a: Vec<char> = caseless_skeleton_chars(candidate).collect();
+    let b: Vec<char> = caseless_skeleton_chars(target).collect();
+    if a == b {
+        return Some(Typosquat { kind: TyposquatKind::Homoglyph, homoglyphs: true });
+    }
+    let kind = typo_kind(&a, &b)?;
+    // Names such as "amazonn.com" have characters with prototypes of their
+    // own, like "m" for "rn", but no homoglyphs if the typing mistake alone
+    // turns the target into them.
+    let homoglyphs = typo_kind(&folded_candidate, &folded_target) != Some(kind);
+    Some(Typosquat { kind, homoglyphs })
+}
+
+// The typing mistake that turns `b` into `a`, if it is one of those that
+// `TyposquatKind` names.
+fn typo_kind(a: &[char], b: &[char]) -> Option<TyposquatKind> {
+    let first = a.iter().zip(b).take_while(|&(x, y)| x == y).count();
+    if a.len() + 1 == b.len() {
+        if a[first..] != b[first + 1..] {
+            return None;
+        }
+        Some(if b[first] == '-' { TyposquatKind::Hyphenation } else { TyposquatKind::Omission })
+    } else if a.len() == b.len() + 1 {
+        if a[first + 1..] != b[first..] {
+            return None;
+        }
+        let inserted = a[first];
+        if inserted == '-' {
+            Some(TyposquatKind::Hyphenation)
+        } else if (first > 0 && a[first - 1] == inserted) || a.get(first + 1) == Some(&inserted) {
+            Some(TyposquatKind::Doubling)
+        } else {
+            None
+        }
+    } else if a.len() == b.len() {
+        if a[first + 1..] == b[first + 1..] {
+            if adjacent_keys(a[first], b[first]) { Some(TyposquatKind::AdjacentKey) } else { None }
+        } else if first + 1 < a.len() && a[first] == b[first + 1] && a[first + 1] == b[first] &&
+            a[first + 2..] == b[first + 2..] {
+            Some(TyposquatKind::Transposition)
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+// The rows of a QWERTY keyboard, each offset by half a key to the right of
+// the one above.
+const KEYBOARD_ROWS: [&str; 4] = ["1234567890-", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+fn key_position(c: char) -> Option<(usize, usize)> {
+    KEYBOARD_ROWS.iter().enumerate().filter_map(|(row, keys)| keys.find(c).map(|column| (row, column))).next()
+}
+
+// Whether two characters are on neighbouring keys, beside each other or
+// touching diagonally.
+fn adjacent_keys(a: char, b: char) -> bool {
+    match (key_position(a), key_position(b)) {
+        (Some((row_a, column_a)), Some((row_b, column_b))) => {
+            if row_a == row_b {
+                column_a.abs_diff(column_b) == 1
+            } else if row_a + 1 == row_b {
+                column_b == column_a || column_b + 1 == column_a
+            } else if row_b + 1 == row_a {
+                column_a == column_b || column_a + 1 == column_b
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adjacent_keys, typosquat_score, Typosquat, TyposquatKind};
+
+    fn kind(candidate: &str, target: &str) -> Option<TyposquatKind> {
+        typosquat_score(candidate, target).map(|typosquat| typosquat.kind)
+    }
+
+    #[test]
+    fn classifies_typos() {
+        assert_eq!(kind("ru𝓼t-lang.org", "rust-lang.org"), Some(TyposquatKind::Homoglyph));
+        assert_eq!(kind("rusr-lang.org", "rust-lang.org"), Some(TyposquatKind::AdjacentKey));
+        assert_eq!(kind("rsut-lang.org", "rust-lang.org"), Some(TyposquatKind::Transposition));
+        assert_eq!(kind("rst-lang.org", "rust-lang.org"), Some(TyposquatKind::Omission));
+        assert_eq!(kind("rust-lang.or", "rust-lang.org"), Some(TyposquatKind::Omission));
+        assert_eq!(kind("rust-langg.org", "rust-lang.org"), Some(TyposquatKind::Doubling));
+        assert_eq!(kind("rrust-lang.org", "rust-lang.org"), Some(TyposquatKind::Doubling));
+        assert_eq!(kind("rustlang.org", "rust-lang.org"), Some(TyposquatKind::Hyphenation));
+        assert_eq!(kind("rust-la-ng.org", "rust-lang.org"), Some(TyposquatKind::Hyphenation));
+
+        assert_eq!(typosquat_score("Rust-Lang.org", "rust-lang.org"), None);
+        assert_eq!(typosquat_score("rusx-lang.org", "rust-lang.org"), None);
+        assert_eq!(typosquat_score("rustx-lang.org", "rust-lang.org"), None);
+        assert_eq!(typosquat_score("rust-lang.com", "rust-lang.org"), None);
+        assert_eq!(typosquat_score("", ""), None);
+    }
+
+    #[test]
+    fn scores() {
+        let homoglyph = typosquat_score("ru𝓼t-lang.org", "rust-lang.org").unwrap();
+        assert_eq!(homoglyph, Typosquat { kind: TyposquatKind::Homoglyph, homoglyphs: true });
+        assert_eq!(homoglyph.score(), 1.0);
+        let both = typosquat_score("ru𝓼t-langg.org", "rust-lang.org").unwrap();
+        assert_eq!(both, Typosquat { kind: TyposquatKind::Doubling, homoglyphs: true });
+        assert_eq!(both.score(), 0.75);
+        assert_eq!(typosquat_score("rust-langg.org", "rust-lang.org").unwrap().score(), 0.5);
+        // Case alone is not a homoglyph.
+        assert!(!typosquat_score("Rust-Langg.org", "rust-lang.org").unwrap().homoglyphs);
+        // Nor are characters that have prototypes, such as "m" and "0", when
+        // the typing mistake accounts for the difference.
+        let doubling = typosquat_score("amazonn.com", "amazon.com").unwrap();
+        assert_eq!(doubling, Typosquat { kind: TyposquatKind::Doubling, homoglyphs: false });
+        assert_eq!(doubling.score(), 0.5);
+        assert_eq!(typosquat_score("microsofr", "microsoft"),
+                   Some(Typosquat { kind: TyposquatKind::AdjacentKey, homoglyphs: false }));
+        assert_eq!(typosquat_score("web30.com", "web3.0.com"),
+                   Some(Typosquat { kind: TyposquatKind::Omission, homoglyphs: false }));
+        assert_eq!(typosquat_score("fo0d.com", "food.com"), Some(Typosquat { kind: TyposquatKind::Homoglyph, homoglyphs: true }));
+        assert_eq!(typosquat_score("arnazonn.com", "amazon.com"),
+                   Some(Typosquat { kind: TyposquatKind::Doubling, homoglyphs: true }));
+    }
+
+    #[test]
+    fn keyboard() {
+        assert!(adjacent_keys('s', 'd') && adjacent_keys('s', 'w') && adjacent_keys('s', 'e'));
+        assert!(adjacent_keys('s', 'z') && adjacent_keys('s', 'x') && adjacent_keys('1', 'q'));
+        assert!(!adjacent_keys('s', 'c') && !adjacent_keys('s', 'q') && !adjacent_keys('s', 's'));
+        assert!(!adjacent_keys('s', 'é'));
+    }
+}
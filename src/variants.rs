@@ -0,0 +1,183 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Generating the lookalikes of a string.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use decompose::MAX_PROTOTYPE_SKELETON_LEN;
+use regex_class::for_each_char_skeleton;
+use {confusable, UnicodeSkeleton};
+
+/// The longest skeleton that `homoglyph_variants` finds variants of, which
+/// is far longer than any brand or domain name.
+pub const MAX_VARIANT_SKELETON_LEN: usize = 64;
+
+/// Up to `limit` strings with the same skeleton as `s`, other than `s`
+/// itself, for pre-registering or blocking the lookalikes of a brand name.
+///
+/// Variants are made by spelling the skeleton of `s` with other characters
+/// of the same skeletons, including characters that stand for several, such
+/// as "m" for "rn". Those with the fewest replaced characters come first, so
+/// a small `limit` gives the closest lookalikes.
+///
+/// The number of variants grows exponentially with the length of `s`, and so
+/// can the search for them when few are found before `limit` is reached, so
+/// strings whose skeletons are longer than `MAX_VARIANT_SKELETON_LEN` have no
+/// variants. Finding the replacements means examining every code point, as
+/// `confusable_regex` does.
+///
+/// # Examples
+/// ```Rust
+/// homoglyph_variants("rust", 4) // ["rus𝐭", "ruƽt", "rʋst", "гust"]
+/// homoglyph_variants("ℝ𝓊𝓈𝓉", 2) // ["Rust", "Rus𝐭"]
+/// ```
+pub fn homoglyph_variants(s: &str, limit: usize) -> Vec<String> {
+    let target: Vec<char> = s.skeleton_chars().collect();
+    if target.len() > MAX_VARIANT_SKELETON_LEN {
+        return Vec::new();
+    }
+    // The characters whose skeleton is each run of the target skeleton. No
+    // character has a skeleton longer than the longest prototype.
+    let mut runs: BTreeMap<&[char], Vec<char>> = BTreeMap::new();
+    for start in 0..target.len() {
+        for end in run_ends(start, target.len()) {
+            runs.insert(&target[start..end], Vec::new());
+        }
+    }
+    for_each_char_skeleton(|c, skeleton| {
+        if let Some(chars) = runs.get_mut(skeleton) {
+            chars.push(c);
+        }
+    });
+
+    // The ways to continue a variant at each position of the target: a
+    // character, the number of target characters it covers, and its rank
+    // among the replacements there, or `None` if it is the target character.
+    let mut pieces = vec![Vec::new(); target.len()];
+    for (start, pieces) in pieces.iter_mut().enumerate() {
+        let mut rank = 0;
+        for end in run_ends(start, target.len()) {
+            for &c in &runs[&target[start..end]] {
+                if end == start + 1 && c == target[start] {
+                    pieces.push((c, 1, None));
+                } else {
+                    pieces.push((c, end - start, Some(rank)));
+                    rank += 1;
+                }
+            }
+        }
+    }
+
+    // Variants are found in order of the number of replacements, then of the
+    // highest rank among them, so that the first replacements at every
+    // position are tried before the later ones at any.
+    let max_rank = pieces.iter().map(Vec::len).max().unwrap_or(0);
+    let mut variants = Variants { source: s, pieces, limit, found: Vec::new() };
+    variants.extend(0, 0, 0, true, &mut String::new());
+    for replacements in 1..=target.len() {
+        for rank in 0..max_rank {
+            variants.extend(0, replacements, rank, false, &mut String::new());
+        }
+    }
+    variants.found
+}
+
+// The ends of the runs of a skeleton of length `len` that start at `start`
+// and could be the skeleton of one character.
+fn run_ends(start: usize, len: usize) -> RangeInclusive<usize> {
+    start + 1..=len.min(start + MAX_PROTOTYPE_SKELETON_LEN)
+}
+
+struct Variants<'a> {
+    source: &'a str,
+    pieces: Vec<Vec<(char, usize, Option<usize>)>>,
+    limit: usize,
+    found: Vec<String>,
+}
+
+impl<'a> Variants<'a> {
+    // Find the variants that begin with `prefix`, which spells the target
+    // up to `position`, and make exactly `replacements` more replacements,
+    // none ranked above `rank`. `reached` is whether one is ranked `rank`.
+    fn extend(&mut self, position: usize, replacements: usize, rank: usize, reached: bool, prefix: &mut String) {
+        if self.found.len() >= self.limit || replacements > self.pieces.len() - position {
+            return;
+        }
+        if position == self.pieces.len() {
+            // Marks can reorder when characters are joined, so the skeleton
+            // of the whole variant is checked.
+            if replacements == 0 && reached && prefix != self.source && confusable(&prefix[..], self.source) {
+                self.found.push(prefix.clone());
+            }
+            return;
+        }
+        for i in 0..self.pieces[position].len() {
+            let (c, len, piece_rank) = self.pieces[position][i];
+            let (replacements, reached) = match piece_rank {
+                None => (replacements, reached),
+                Some(piece_rank) if replacements > 0 && piece_rank <= rank => (replacements - 1, reached || piece_rank == rank),
+                Some(_) => continue,
+            };
+            prefix.push(c);
+            self.extend(position + len, replacements, rank, reached, prefix);
+            prefix.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc_prelude::*;
+
+    use confusable;
+    use super::{homoglyph_variants, MAX_VARIANT_SKELETON_LEN};
+
+    #[test]
+    fn generates_variants() {
+        let variants = homoglyph_variants("rust", 50);
+        assert_eq!(variants.len(), 50);
+        for variant in &variants {
+            assert!(confusable(&variant[..], "rust"), "{}", variant);
+        }
+        assert!(!variants.contains(&"rust".to_string()));
+        // The first replacement at each position comes before any second one.
+        assert_eq!(&variants[..4], &["rus𝐭", "ruƽt", "rʋst", "гust"]);
+        assert_eq!(homoglyph_variants("rust", 3), &variants[..3]);
+        // A single replacement can stand for several characters.
+        assert!(homoglyph_variants("rust", 1000).contains(&"ruﬆ".to_string()));
+        assert_eq!(homoglyph_variants("", 10), Vec::<String>::new());
+        assert_eq!(homoglyph_variants("rust", 0), Vec::<String>::new());
+    }
+
+    #[test]
+    fn replaces_runs() {
+        let variants = homoglyph_variants("rn", 1000);
+        assert!(variants.contains(&"m".to_string()));
+        assert!(!variants.contains(&"rn".to_string()));
+        // The skeleton itself is a variant of a string that is not.
+        assert_eq!(homoglyph_variants("ℝ𝓊𝓈𝓉", 2), vec!["Rust", "Rus𝐭"]);
+    }
+
+    #[test]
+    fn long_strings() {
+        let s = "ab".repeat(MAX_VARIANT_SKELETON_LEN / 2);
+        let variants = homoglyph_variants(&s, 3);
+        assert_eq!(variants.len(), 3);
+        for variant in &variants {
+            assert!(confusable(&variant[..], &s[..]), "{}", variant);
+        }
+        assert!(homoglyph_variants(&"ab".repeat(1500), 3).is_empty());
+    }
+}
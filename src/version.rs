@@ -0,0 +1,174 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Selecting which version of the confusables table skeletons are computed with.
+
+#[cfg(not(feature = "std"))]
+use alloc_prelude::*;
+
+use data::{self, PrototypeTable};
+use {SkeletonChars, UnicodeSkeleton};
+
+/// A version of the confusables table compiled into the crate.
+///
+/// The latest version is always available. Older versions are compiled in by
+/// enabling their cargo feature, so that skeletons stored by an application
+/// keep their meaning when the crate's default table is updated.
+///
+/// # Examples
+/// ```Rust
+/// let version = DataVersion::from_unicode_version((10, 0, 0)).unwrap(); // requires `data-10`
+/// version.skeleton("ᑲ") // "ᑲ", where the latest version gives "b"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DataVersion {
+    /// Unicode 10.0.0. Requires the `data-10` feature.
+    #[cfg(feature = "data-10")]
+    V10_0_0,
+    /// Unicode 16.0.0, the bundled table, which the crate's free functions
+    /// use unless `UNICODE_SKELETON_CONFUSABLES` is set.
+    V16_0_0,
+    /// The table generated at build time from the confusables.txt named by
+    /// `UNICODE_SKELETON_CONFUSABLES`, which the crate's free functions then
+    /// use. `unicode_version` reports the version given in that file.
+    #[cfg(unicode_skeleton_custom_confusables)]
+    Custom,
+}
+
+static AVAILABLE: &[DataVersion] = &[
+    #[cfg(feature = "data-10")]
+    DataVersion::V10_0_0,
+    DataVersion::V16_0_0,
+    #[cfg(unicode_skeleton_custom_confusables)]
+    DataVersion::Custom,
+];
+
+impl DataVersion {
+    /// The version used by `skeleton`, `confusable` and the other free functions.
+    #[cfg(not(unicode_skeleton_custom_confusables))]
+    pub const LATEST: DataVersion = DataVersion::V16_0_0;
+    /// The version used by `skeleton`, `confusable` and the other free functions.
+    #[cfg(unicode_skeleton_custom_confusables)]
+    pub const LATEST: DataVersion = DataVersion::Custom;
+
+    /// The versions compiled into the crate, oldest first, followed by the
+    /// custom table if there is one.
+    pub fn available() -> &'static [DataVersion] {
+        AVAILABLE
+    }
+
+    /// Find the compiled-in version for a Unicode version, as
+    /// (major, minor, update), such as one stored alongside skeletons. A
+    /// bundled table is preferred to a custom one of the same version.
+    pub fn from_unicode_version(version: (u8, u8, u8)) -> Option<DataVersion> {
+        AVAILABLE.iter().cloned().find(|v| v.unicode_version() == version)
+    }
+
+    /// The Unicode version of this table, as (major, minor, update).
+    pub fn unicode_version(self) -> (u8, u8, u8) {
+        match self {
+            #[cfg(feature = "data-10")]
+            DataVersion::V10_0_0 => data::CONFUSABLES_10_VERSION,
+            DataVersion::V16_0_0 => data::CONFUSABLES_16_VERSION,
+            #[cfg(unicode_skeleton_custom_confusables)]
+            DataVersion::Custom => data::CUSTOM_CONFUSABLES_VERSION,
+        }
+    }
+
+    fn table(self) -> PrototypeTable<'static> {
+        match self {
+            #[cfg(feature = "data-10")]
+            DataVersion::V10_0_0 => data::CONFUSABLES_10,
+            DataVersion::V16_0_0 => data::CONFUSABLES_16,
+            #[cfg(unicode_skeleton_custom_confusables)]
+            DataVersion::Custom => data::CUSTOM_CONFUSABLES,
+        }
+    }
+
+    /// Look up the prototype that a single character maps to in this version's
+    /// table, as with `prototype_of`.
+    pub fn prototype_of(self, c: char) -> Option<&'static [char]> {
+        self.table().prototype_of(c)
+    }
+
+    /// Retrieve the characters of the skeleton of a string or other `char`
+    /// sequence, computed with this version's table.
+    pub fn skeleton_chars<S: UnicodeSkeleton>(self, s: S) -> SkeletonChars<'static, S::Iter> {
+        let mut chars = s.skeleton_chars();
+        chars.table = self.table();
+        chars
+    }
+
+    /// Compute the skeleton of a string or other `char` sequence with this
+    /// version's table.
+    pub fn skeleton<S: UnicodeSkeleton>(self, s: S) -> String {
+        self.skeleton_chars(s).collect()
+    }
+
+    /// Test if two strings have the same skeleton under this version's table.
+    pub fn confusable<A, B>(self, a: A, b: B) -> bool
+        where A: UnicodeSkeleton, B: UnicodeSkeleton
+    {
+        self.skeleton_chars(a).eq(self.skeleton_chars(b))
+    }
+}
+
+impl Default for DataVersion {
+    fn default() -> DataVersion {
+        DataVersion::LATEST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DataVersion;
+    use {skeleton, Skeleton, UNICODE_VERSION};
+
+    #[test]
+    fn latest_matches_free_functions() {
+        assert_eq!(DataVersion::LATEST.unicode_version(), UNICODE_VERSION);
+        assert_eq!(DataVersion::default(), DataVersion::LATEST);
+        assert_eq!(DataVersion::available().last(), Some(&DataVersion::LATEST));
+        for s in &["ℝ𝓊𝓈𝓉", "ᑲ", "𝔭𝒶ỿ𝕡𝕒ℓ"] {
+            assert_eq!(DataVersion::LATEST.skeleton(*s), skeleton(*s));
+            assert_eq!(Skeleton::of_version(s, DataVersion::LATEST), Skeleton::of(s));
+        }
+        assert!(DataVersion::LATEST.confusable("ᑲ", "b"));
+    }
+
+    #[test]
+    fn lookup_by_unicode_version() {
+        use UNICODE_VERSION;
+
+        assert_eq!(DataVersion::from_unicode_version((16, 0, 0)), Some(DataVersion::V16_0_0));
+        assert_eq!(DataVersion::V16_0_0.unicode_version(), (16, 0, 0));
+        assert!(DataVersion::from_unicode_version(UNICODE_VERSION).is_some());
+        assert_eq!(DataVersion::from_unicode_version((9, 0, 0)), None);
+    }
+
+    #[cfg(feature = "data-10")]
+    #[test]
+    fn older_version() {
+        use decompose::MAX_PROTOTYPE_SKELETON_LEN;
+
+        let v10 = DataVersion::from_unicode_version((10, 0, 0)).unwrap();
+        assert_eq!(v10, DataVersion::V10_0_0);
+        assert_eq!(&DataVersion::available()[..2], &[DataVersion::V10_0_0, DataVersion::V16_0_0]);
+        assert_eq!(v10.skeleton("ℝ𝓊𝓈𝓉"), "Rust");
+        assert_eq!(v10.prototype_of('ᑲ'), None);
+        assert_eq!(v10.skeleton("ᑲ"), "ᑲ");
+        assert!(!v10.confusable("ᑲ", "b"));
+
+        let table = v10.table();
+        let max = table.entries()
+            .map(|(_, prototype)| prototype.len())
+            .max();
+        assert!(max <= Some(MAX_PROTOTYPE_SKELETON_LEN));
+    }
+}
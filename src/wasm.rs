@@ -0,0 +1,106 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! JavaScript bindings to skeletons, confusability and `SpoofChecker`,
+//! through wasm-bindgen.
+//!
+//! Requires the `wasm` feature. Build for `wasm32-unknown-unknown` and run
+//! `wasm-bindgen` over the output to generate the JavaScript module, which
+//! exports `skeleton`, `confusable` and the `Checker` class. Sets of checks
+//! are passed as bits, with the same values as `Checks::bits` and the
+//! `USK_CHECK_*` constants of the C interface.
+
+use std::mem;
+
+use wasm_bindgen::prelude::*;
+
+use checker::{Check, RestrictionLevel, SpoofChecker};
+
+/// The skeleton of `s`.
+#[wasm_bindgen]
+pub fn skeleton(s: &str) -> String {
+    ::skeleton(s)
+}
+
+/// Test whether `a` and `b` are confusable.
+#[wasm_bindgen]
+pub fn confusable(a: &str, b: &str) -> bool {
+    ::confusable(a, b)
+}
+
+/// A `SpoofChecker`, exported to JavaScript as `Checker`.
+#[wasm_bindgen]
+#[derive(Debug, Default)]
+pub struct Checker(SpoofChecker);
+
+#[wasm_bindgen]
+impl Checker {
+    /// Create a checker with every check enabled, as `SpoofChecker::new` does.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Checker {
+        Checker(SpoofChecker::new())
+    }
+
+    /// Turn on the checks whose bits are set in `checks`.
+    pub fn enable(&mut self, checks: u32) {
+        self.0 = Check::all_in(checks).fold(mem::take(&mut self.0), SpoofChecker::enable);
+    }
+
+    /// Turn off the checks whose bits are set in `checks`.
+    pub fn disable(&mut self, checks: u32) {
+        self.0 = Check::all_in(checks).fold(mem::take(&mut self.0), SpoofChecker::disable);
+    }
+
+    /// Set the most permissive restriction level that passes, from 0 for
+    /// ASCII only to 5 for unrestricted. Throws for any other level.
+    #[wasm_bindgen(js_name = setRestrictionLevel)]
+    pub fn set_restriction_level(&mut self, level: u8) -> Result<(), String> {
+        let level = RestrictionLevel::from_number(level.into())
+            .ok_or_else(|| format!("unknown restriction level {}", level))?;
+        self.0 = mem::take(&mut self.0).restriction_level(level);
+        Ok(())
+    }
+
+    /// Run the enabled checks against `s`, returning the bits of those that
+    /// failed, or 0 if it passed them all.
+    pub fn check(&self, s: &str) -> u32 {
+        self.0.quick_check(s).bits()
+    }
+
+    /// Test whether `a` and `b` are confusable under this configuration.
+    #[wasm_bindgen(js_name = areConfusable)]
+    pub fn are_confusable(&self, a: &str, b: &str) -> bool {
+        self.0.are_confusable(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use checker::Checks;
+
+    #[test]
+    fn bindings() {
+        assert_eq!(skeleton("ℝ𝓊𝓈𝓉"), "Rust");
+        assert!(confusable("ℝ𝓊𝓈𝓉", "Rust"));
+        assert!(!confusable("Rest", "Rust"));
+
+        let mut checker = Checker::new();
+        assert_eq!(checker.check("pаypаl"), (Checks::MIXED_SCRIPT | Checks::RESTRICTION_LEVEL).bits());
+        checker.disable(Checks::MIXED_SCRIPT.bits());
+        assert_eq!(checker.set_restriction_level(4), Ok(()));
+        assert_eq!(checker.check("pаypаl"), 0);
+        assert!(checker.set_restriction_level(6).is_err());
+        assert!(checker.are_confusable("pаypаl", "paypal"));
+        checker.disable(Checks::CONFUSABLE.bits());
+        assert!(!checker.are_confusable("pаypаl", "paypal"));
+        checker.enable(Checks::CONFUSABLE.bits());
+        assert!(checker.are_confusable("pаypаl", "paypal"));
+    }
+}
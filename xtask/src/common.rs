@@ -0,0 +1,56 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing shared by the Unicode data file formats.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Read a file of the data directory.
+pub fn read(data: &Path, name: &str) -> io::Result<String> {
+    fs::read_to_string(data.join(name))
+}
+
+/// Split the contents of a UCD-style data file into the `;`-separated,
+/// trimmed fields of each line, skipping comments and blank lines.
+pub fn data_lines(contents: &str) -> Vec<Vec<String>> {
+    let mut lines = Vec::new();
+    for line in contents.lines() {
+        let line = match line.find('#') {
+            Some(comment_begin) => &line[..comment_begin],
+            None => line,
+        };
+        if !line.trim().is_empty() {
+            lines.push(line.split(';').map(|field| field.trim().to_string()).collect());
+        }
+    }
+    lines
+}
+
+/// Parse a code point or a `start..end` range of code points, in hex.
+pub fn parse_range(range: &str) -> (u32, u32) {
+    let mut bounds = range.split("..");
+    let start = u32::from_str_radix(bounds.next().expect("Failed to parse range"), 16).expect("Failed to parse range start as hex");
+    let end = bounds.next().map(|end| u32::from_str_radix(end, 16).expect("Failed to parse range end as hex")).unwrap_or(start);
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{data_lines, parse_range};
+
+    #[test]
+    fn lines_and_ranges() {
+        let lines = data_lines("# header\n\n0041..005A ; Latn # comment\n00AA;Latn\n");
+        assert_eq!(lines, vec![vec!["0041..005A", "Latn"], vec!["00AA", "Latn"]]);
+        assert_eq!(parse_range(&lines[0][0]), (0x41, 0x5a));
+        assert_eq!(parse_range(&lines[1][0]), (0xaa, 0xaa));
+    }
+}
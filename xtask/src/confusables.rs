@@ -0,0 +1,135 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The confusables tables, from confusables.txt.
+
+use std::char;
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use common::read;
+use table::{generate_char_ranges, generate_table, parse};
+
+pub fn generate_latest(data: &Path) -> io::Result<String> {
+    let confusables = read(data, "confusables.txt")?;
+    let mut out = generate("confusables.txt", &confusables);
+    out.push_str(&generate_char_ranges(&confusables));
+    Ok(out)
+}
+
+pub fn generate_10(data: &Path) -> io::Result<String> {
+    Ok(generate("confusables-10.0.0.txt", &read(data, "confusables-10.0.0.txt")?))
+}
+
+/// Generate the plain table of the `skeleton!` macro, in
+/// macros/src/confusables.rs, from confusables.txt.
+pub fn generate_macros(data: &Path) -> io::Result<String> {
+    Ok(generate_pairs("confusables.txt", &read(data, "confusables.txt")?))
+}
+
+/// Generate a table from the contents of a confusables file, named
+/// `file_name` in the data directory.
+pub fn generate(file_name: &str, confusables: &str) -> String {
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from data/{}", file_name).unwrap();
+    writeln!(out).unwrap();
+    out.push_str(&generate_table(confusables));
+    out
+}
+
+/// Generate a table of each source and its prototype, sorted by source, from
+/// the contents of a confusables file, named `file_name` in the data
+/// directory. This is simpler to read than the packed tables `generate`
+/// makes, for code that does not need them to be fast.
+pub fn generate_pairs(file_name: &str, confusables: &str) -> String {
+    let (_, inputs_to_outputs) = parse(confusables);
+    let pairs: Vec<(char, String)> = inputs_to_outputs.into_iter()
+        .map(|(from, tos)| (char::from_u32(from).unwrap(), tos.into_iter().collect()))
+        .collect();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from data/{}", file_name).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static PROTOTYPES: [(char, &str); {}] = {:?};", pairs.len(), pairs).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, generate_pairs};
+    use table::{eytzinger, generate_char_ranges, perfect_hash, phf_hash, PROTOTYPE_INDEX_BITS};
+
+    #[test]
+    fn packs_entries_and_shares_prototypes() {
+        let text = "\u{feff}# confusables.txt\n\
+                    # Version: 1.2.3\n\
+                    211D ;\t0052 ;\tMA\t# ( ℝ → R )\n\
+                    006D ;\t0072 006E ;\tMA\t# ( m → rn )\n\
+                    211B ;\t0052 ;\tMA\t# ( ℛ → R )\n";
+        let generated = generate("test.txt", text);
+        let lines: Vec<&str> = generated.lines().collect();
+        assert_eq!(lines[0], "// Generated by `cargo xtask` from data/test.txt");
+        assert_eq!(lines[2], "pub const VERSION: (u8, u8, u8) = (1, 2, 3);");
+        // Prototypes sort as ['R'] < ['r', 'n'], so 'ℛ' and 'ℝ' share index 0.
+        // The entries are laid out as a tree, with the middle one at the root.
+        assert_eq!(lines[3], format!("pub static ENTRIES: [u32; 3] = [{}, {}, {}];", 0x211b << 14, 0x6d << 14 | 1, 0x211d << 14));
+        assert_eq!(lines[4], "pub static PROTOTYPE_STARTS: [u16; 3] = [0, 1, 3];");
+        assert_eq!(lines[5], "pub static PROTOTYPE_CHARS: [char; 3] = ['R', 'r', 'n'];");
+    }
+
+    #[test]
+    fn pairs_are_sorted_and_decomposed() {
+        let text = "# Version: 1.2.3\n\
+                    211D ;\t0052 ;\tMA\t# ( ℝ → R )\n\
+                    01CF ;\t012C ;\tMA\t# ( Ǐ → Ĭ )\n\
+                    006D ;\t0072 006E ;\tMA\t# ( m → rn )\n";
+        let lines: Vec<String> = generate_pairs("test.txt", text).lines().map(String::from).collect();
+        assert_eq!(lines[2], "pub static PROTOTYPES: [(char, &str); 3] = [('m', \"rn\"), ('Ǐ', \"I\\u{306}\"), ('ℝ', \"R\")];");
+    }
+
+    #[test]
+    fn char_ranges_include_decompositions() {
+        // U+1E3F, U+1E41 and U+1E43 decompose to m and a combining mark.
+        // U+00F6 is a source, but decomposes to o and a combining diaeresis,
+        // which are not.
+        let text = "# Version: 1.2.3\n\
+                    006D ;\t0072 006E ;\tMA\t# ( m → rn )\n\
+                    00F6 ;\t006F 0308 ;\tMA\t# ( ö → ö )\n";
+        assert_eq!(generate_char_ranges(text), "pub static CONFUSABLE_CHAR_RANGES: [(u32, u32); 4] = [(109, 109), (7743, 7743), (7745, 7745), (7747, 7747)];\n");
+    }
+
+    #[test]
+    fn perfect_hash_finds_every_entry() {
+        let entries: Vec<u32> = (0..1000u32).map(|i| (0x400 + i * 7) << PROTOTYPE_INDEX_BITS | (i % 50)).collect();
+        let (salts, hashed_entries) = perfect_hash(&entries);
+        assert_eq!(salts.len(), entries.len());
+        let mut sorted = hashed_entries.clone();
+        sorted.sort();
+        assert_eq!(sorted, entries);
+        for &entry in entries.iter() {
+            let key = entry >> PROTOTYPE_INDEX_BITS;
+            let salt = salts[phf_hash(key, 0, salts.len())] as u32;
+            assert_eq!(hashed_entries[phf_hash(key, salt, salts.len())], entry);
+        }
+    }
+
+    #[test]
+    fn eytzinger_order() {
+        assert_eq!(eytzinger(&[]), vec![]);
+        assert_eq!(eytzinger(&[1, 2, 3, 4, 5, 6, 7]), vec![4, 2, 6, 1, 3, 5, 7]);
+        assert_eq!(eytzinger(&[1, 2, 3, 4, 5]), vec![4, 2, 5, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_unstable_prototypes() {
+        generate("test.txt", "# Version: 1.0.0\n0041 ; 0042 ; MA\n0042 ; 0043 ; MA\n");
+    }
+}
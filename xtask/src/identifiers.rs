@@ -0,0 +1,75 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The identifier status and type tables, from IdentifierStatus.txt and
+//! IdentifierType.txt.
+
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use common::{data_lines, parse_range, read};
+
+// Bit positions of each type in the generated masks. This must match the
+// order of `IdentifierType` in src/identifier.rs.
+const TYPES: [&str; 12] = [
+    "Not_Character", "Deprecated", "Default_Ignorable", "Not_NFKC", "Not_XID", "Exclusion",
+    "Obsolete", "Technical", "Uncommon_Use", "Limited_Use", "Inclusion", "Recommended",
+];
+
+pub fn generate(data: &Path) -> io::Result<String> {
+    Ok(generate_from(&read(data, "IdentifierStatus.txt")?, &read(data, "IdentifierType.txt")?))
+}
+
+fn generate_from(status: &str, types_text: &str) -> String {
+    let mut allowed = Vec::new();
+    for fields in data_lines(status) {
+        assert_eq!(fields[1], "Allowed");
+        allowed.push(parse_range(&fields[0]));
+    }
+    allowed.sort();
+
+    let mut types = Vec::new();
+    for fields in data_lines(types_text) {
+        let (start, end) = parse_range(&fields[0]);
+        let mut mask = 0u16;
+        for name in fields[1].split_whitespace() {
+            let bit = TYPES.iter().position(|&t| t == name).expect("Unknown identifier type");
+            mask |= 1 << bit;
+        }
+        types.push((start, end, mask));
+    }
+    types.sort();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from Unicode 14.0.0 data").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static ALLOWED_RANGES: [(u32, u32); {}] = {:?};", allowed.len(), allowed).unwrap();
+    writeln!(out, "pub static IDENTIFIER_TYPE_RANGES: [(u32, u32, u16); {}] = {:?};", types.len(), types).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_from;
+
+    #[test]
+    fn sorts_ranges_and_packs_types() {
+        let generated = generate_from("0061..007A ; Allowed\n0027 ; Allowed\n", "00B7 ; Technical Exclusion\n");
+        let lines: Vec<&str> = generated.lines().collect();
+        assert_eq!(lines[2], "pub static ALLOWED_RANGES: [(u32, u32); 2] = [(39, 39), (97, 122)];");
+        assert_eq!(lines[3], format!("pub static IDENTIFIER_TYPE_RANGES: [(u32, u32, u16); 1] = [(183, 183, {})];", 1 << 7 | 1 << 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown identifier type")]
+    fn rejects_unknown_types() {
+        generate_from("", "0041 ; Fancy\n");
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The intentional confusables table, from intentional.txt, or from the
+//! stand-in intentional-derived.txt where the official file is absent.
+
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use common::{data_lines, read};
+
+pub fn generate(data: &Path) -> io::Result<String> {
+    match read(data, "intentional.txt") {
+        Ok(intentional) => Ok(generate_from("intentional.txt", &intentional)),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound =>
+            Ok(generate_from("intentional-derived.txt", &read(data, "intentional-derived.txt")?)),
+        Err(e) => Err(e),
+    }
+}
+
+fn generate_from(file_name: &str, intentional: &str) -> String {
+    let mut pairs: Vec<(u32, u32)> = data_lines(intentional).iter()
+        .map(|fields| {
+            let a = u32::from_str_radix(&fields[0], 16).expect("Failed to parse code point as hex");
+            let b = u32::from_str_radix(&fields[1], 16).expect("Failed to parse code point as hex");
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    pairs.sort();
+    pairs.dedup();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from data/{}", file_name).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static INTENTIONAL_PAIRS: [(u32, u32); {}] = {:?};", pairs.len(), pairs).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_from;
+
+    #[test]
+    fn orders_and_dedups_pairs() {
+        let generated = generate_from("test.txt", "0410 ; 0041 # А A\n0041 ; 0410\n0042 ; 0392\n");
+        assert_eq!(generated.lines().nth(2).unwrap(), "pub static INTENTIONAL_PAIRS: [(u32, u32); 2] = [(65, 1040), (66, 914)];");
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Generates the tables in `src/data`, and the table of the `skeleton!` macro
+//! in `macros/src`, from the Unicode data files in `data`.
+//!
+//! ```text
+//! cargo xtask [--check] [TABLE...]
+//! ```
+//!
+//! With no tables named, every table is generated. With `--check`, nothing is
+//! written; instead, the command fails if any committed table differs from
+//! what would be generated.
+
+extern crate unicode_normalization;
+
+mod common;
+mod confusables;
+mod identifiers;
+mod intentional;
+mod properties;
+mod scripts;
+// Shared with build.rs, which generates the table for UNICODE_SKELETON_CONFUSABLES.
+#[path = "../../build/confusables.rs"]
+mod table;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// A generated table: its name on the command line, the file it is written
+/// to, and how to generate it from the contents of the data directory.
+struct Table {
+    name: &'static str,
+    output: &'static str,
+    generate: fn(&Path) -> io::Result<String>,
+}
+
+static TABLES: [Table; 8] = [
+    Table { name: "confusables", output: "src/data/confusables.rs", generate: confusables::generate_latest },
+    Table { name: "confusables-10", output: "src/data/confusables_10.rs", generate: confusables::generate_10 },
+    Table { name: "identifiers", output: "src/data/identifiers.rs", generate: identifiers::generate },
+    Table { name: "intentional", output: "src/data/intentional.rs", generate: intentional::generate },
+    Table { name: "properties", output: "src/data/properties.rs", generate: properties::generate },
+    Table { name: "scripts", output: "src/data/scripts.rs", generate: scripts::generate_scripts },
+    Table { name: "script-ext", output: "src/data/script_ext.rs", generate: scripts::generate_extensions },
+    Table { name: "macros", output: "macros/src/confusables.rs", generate: confusables::generate_macros },
+];
+
+fn usage() -> ! {
+    let names: Vec<&str> = TABLES.iter().map(|table| table.name).collect();
+    eprintln!("usage: cargo xtask [--check] [TABLE...]");
+    eprintln!("tables: {}", names.join(", "));
+    process::exit(2);
+}
+
+fn main() {
+    let mut check = false;
+    let mut selected = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            "-h" | "--help" => usage(),
+            name => match TABLES.iter().find(|table| table.name == name) {
+                Some(table) => selected.push(table),
+                None => {
+                    eprintln!("unknown table {:?}", name);
+                    usage();
+                }
+            },
+        }
+    }
+    if selected.is_empty() {
+        selected.extend(TABLES.iter());
+    }
+
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let data = root.join("data");
+    let mut stale = 0;
+    for table in selected {
+        let generated = (table.generate)(&data).unwrap_or_else(|e| {
+            eprintln!("failed to read the data for {}: {}", table.name, e);
+            process::exit(1);
+        });
+        let output = root.join(table.output);
+        if check {
+            if fs::read_to_string(&output).ok().as_ref() != Some(&generated) {
+                eprintln!("{} is out of date; run `cargo xtask {}`", table.output, table.name);
+                stale += 1;
+            }
+        } else if let Err(e) = fs::write(&output, generated) {
+            eprintln!("failed to write {}: {}", table.output, e);
+            process::exit(1);
+        }
+    }
+    if stale > 0 {
+        process::exit(1);
+    }
+}
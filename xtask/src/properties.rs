@@ -0,0 +1,64 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The decimal digit and default ignorable tables, from DecimalDigits.txt and
+//! DefaultIgnorable.txt.
+
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use common::{data_lines, parse_range, read};
+
+pub fn generate(data: &Path) -> io::Result<String> {
+    Ok(generate_from(&read(data, "DecimalDigits.txt")?, &read(data, "DefaultIgnorable.txt")?))
+}
+
+fn read_ranges(contents: &str, value: &str) -> Vec<(u32, u32)> {
+    let mut ranges: Vec<(u32, u32)> = data_lines(contents).iter()
+        .filter(|fields| fields[1] == value)
+        .map(|fields| parse_range(&fields[0]))
+        .collect();
+    ranges.sort();
+    ranges
+}
+
+fn generate_from(decimal_digits: &str, default_ignorables: &str) -> String {
+    let decimal_digits = read_ranges(decimal_digits, "Nd");
+    for &(start, end) in decimal_digits.iter() {
+        assert_eq!((end - start + 1) % 10, 0, "Incomplete sequence of decimal digits");
+    }
+    let default_ignorables = read_ranges(default_ignorables, "Default_Ignorable_Code_Point");
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from Unicode 16.0.0 data").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static DECIMAL_DIGIT_RANGES: [(u32, u32); {}] = {:?};", decimal_digits.len(), decimal_digits).unwrap();
+    writeln!(out, "pub static DEFAULT_IGNORABLE_RANGES: [(u32, u32); {}] = {:?};", default_ignorables.len(), default_ignorables).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_from;
+
+    #[test]
+    fn filters_by_value() {
+        let generated = generate_from("0660..0669 ; Nd\n0030..0039 ; Nd\n", "00AD ; Default_Ignorable_Code_Point\n034F ; Other\n");
+        let lines: Vec<&str> = generated.lines().collect();
+        assert_eq!(lines[2], "pub static DECIMAL_DIGIT_RANGES: [(u32, u32); 2] = [(48, 57), (1632, 1641)];");
+        assert_eq!(lines[3], "pub static DEFAULT_IGNORABLE_RANGES: [(u32, u32); 1] = [(173, 173)];");
+    }
+
+    #[test]
+    #[should_panic(expected = "Incomplete sequence")]
+    fn rejects_partial_digit_runs() {
+        generate_from("0030..0038 ; Nd\n", "");
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2017 Peter Reid. See the COPYRIGHT
+// directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The script and script extension tables, from ScriptAliases.txt,
+//! Scripts.txt and ScriptExtensions.txt.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use common::{data_lines, parse_range, read};
+
+// The writing systems that UTS #39 adds to augmented script sets, which are
+// not values of the Script property. They follow the real scripts in the enum.
+const AUGMENTED_SCRIPTS: [&str; 3] = ["HanWithBopomofo", "Japanese", "Korean"];
+
+fn variant_name(long_name: &str) -> String {
+    long_name.split('_').collect()
+}
+
+pub fn generate_scripts(data: &Path) -> io::Result<String> {
+    Ok(scripts_from(&read(data, "ScriptAliases.txt")?, &read(data, "Scripts.txt")?))
+}
+
+pub fn generate_extensions(data: &Path) -> io::Result<String> {
+    Ok(extensions_from(&read(data, "ScriptAliases.txt")?, &read(data, "ScriptExtensions.txt")?))
+}
+
+fn check_disjoint<T>(ranges: &[(u32, u32, T)]) {
+    for pair in ranges.windows(2) {
+        assert!(pair[0].1 < pair[1].0, "Overlapping ranges");
+    }
+}
+
+fn scripts_from(aliases: &str, scripts_text: &str) -> String {
+    // Full name -> short name, sorted by full name
+    let mut scripts = BTreeMap::new();
+    for fields in data_lines(aliases) {
+        assert_eq!(fields[0], "sc");
+        scripts.insert(fields[2].clone(), fields[1].clone());
+    }
+
+    let mut ranges = Vec::new();
+    for fields in data_lines(scripts_text) {
+        let (start, end) = parse_range(&fields[0]);
+        assert!(scripts.contains_key(&fields[1]), "Unknown script name");
+        ranges.push((start, end, variant_name(&fields[1])));
+    }
+    ranges.sort();
+    check_disjoint(&ranges);
+
+    let variants: Vec<String> = scripts.keys().map(|long_name| variant_name(long_name))
+        .chain(AUGMENTED_SCRIPTS.iter().map(|name| name.to_string()))
+        .collect();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from Unicode 16.0.0 data").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "#[cfg(feature = \"serde\")]").unwrap();
+    writeln!(out, "use serde::{{Deserialize, Serialize}};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use self::Script::*;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/// A value of the Unicode Script property, or one of the writing systems").unwrap();
+    writeln!(out, "/// that UTS #39 adds when augmenting script sets.").unwrap();
+    writeln!(out, "#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]").unwrap();
+    writeln!(out, "#[cfg_attr(feature = \"serde\", derive(Serialize, Deserialize))]").unwrap();
+    writeln!(out, "#[allow(clippy::enum_variant_names)]").unwrap();
+    writeln!(out, "pub enum Script {{").unwrap();
+    for variant in variants.iter() {
+        if variant == AUGMENTED_SCRIPTS[0] {
+            writeln!(out, "    // Augmented scripts, which characters never have as their Script property").unwrap();
+        }
+        writeln!(out, "    {},", variant).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static SCRIPTS: [Script; {}] = [{}];", variants.len(), variants.join(", ")).unwrap();
+    writeln!(out, "pub static SCRIPT_RANGES: [(u32, u32, Script); {}] = [{}];", ranges.len(),
+        ranges.iter().map(|&(start, end, ref script)| format!("({}, {}, {})", start, end, script)).collect::<Vec<_>>().join(", ")).unwrap();
+    out
+}
+
+fn extensions_from(aliases: &str, extensions: &str) -> String {
+    // Short name -> enum variant
+    let mut variants = HashMap::new();
+    for fields in data_lines(aliases) {
+        assert_eq!(fields[0], "sc");
+        variants.insert(fields[1].clone(), variant_name(&fields[2]));
+    }
+
+    let mut ranges = Vec::new();
+    for fields in data_lines(extensions) {
+        let (start, end) = parse_range(&fields[0]);
+        let mut scripts: Vec<&String> = fields[1].split_whitespace()
+            .map(|short_name| variants.get(short_name).expect("Unknown script name"))
+            .collect();
+        scripts.sort();
+        ranges.push((start, end, scripts));
+    }
+    ranges.sort();
+    check_disjoint(&ranges);
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask` from Unicode 16.0.0 data").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use super::Script;").unwrap();
+    writeln!(out, "use super::Script::*;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static SCRIPT_EXTENSIONS: [(u32, u32, &[Script]); {}] = [{}];", ranges.len(),
+        ranges.iter().map(|&(start, end, ref scripts)| {
+            let scripts: Vec<&str> = scripts.iter().map(|script| script.as_str()).collect();
+            format!("({}, {}, &[{}])", start, end, scripts.join(", "))
+        }).collect::<Vec<_>>().join(", ")).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extensions_from, scripts_from};
+
+    const ALIASES: &str = "sc ; Latn ; Latin\nsc ; Grek ; Greek\nsc ; Old_Italic ; Old_Italic\n";
+
+    #[test]
+    fn scripts_enum_and_ranges() {
+        let generated = scripts_from(ALIASES, "0370..0373 ; Greek\n0041..005A ; Latin\n");
+        assert!(generated.contains("pub static SCRIPTS: [Script; 6] = [Greek, Latin, OldItalic, HanWithBopomofo, Japanese, Korean];"));
+        assert!(generated.contains("pub static SCRIPT_RANGES: [(u32, u32, Script); 2] = [(65, 90, Latin), (880, 883, Greek)];"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlapping ranges")]
+    fn rejects_overlapping_ranges() {
+        scripts_from(ALIASES, "0041..005A ; Latin\n0050 ; Greek\n");
+    }
+
+    #[test]
+    fn extensions_are_sorted() {
+        let generated = extensions_from(ALIASES, "0342 ; Latn Grek\n");
+        assert!(generated.ends_with("pub static SCRIPT_EXTENSIONS: [(u32, u32, &[Script]); 1] = [(834, 834, &[Greek, Latin])];\n"));
+    }
+}